@@ -0,0 +1,69 @@
+use crate::{IndentOptions, IndentType, TrailingComma};
+use serde::{Deserialize, Serialize};
+
+/// Formatting settings read from a config file.
+///
+/// The keys match rustfmt's options where rustfmt has an equivalent, so this can be deserialized from a `rustfmt.toml`
+/// or the output of `cargo fmt -- --print-config current`. Unknown keys are ignored and missing keys keep the
+/// default of [`IndentOptions`].
+///
+/// ```toml
+/// max_width = 80
+/// hard_tabs = false
+/// tab_spaces = 2
+/// trailing_comma = "Never"
+/// max_inline_attributes = 2
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FormatConfig {
+    /// The maximum width of a line
+    pub max_width: Option<usize>,
+    /// Indent with tabs instead of spaces
+    pub hard_tabs: Option<bool>,
+    /// The number of spaces in an indent, or the width of a tab
+    pub tab_spaces: Option<usize>,
+    /// When to write a comma after the last attribute
+    pub trailing_comma: Option<TrailingComma>,
+    /// Always write each attribute on its own line
+    pub split_line_attributes: Option<bool>,
+    /// The most attributes an element can have before each attribute is written on its own line
+    pub max_inline_attributes: Option<usize>,
+}
+
+impl FormatConfig {
+    /// Combine two configs, preferring the values set in `other`
+    pub fn merge(self, other: FormatConfig) -> Self {
+        Self {
+            max_width: other.max_width.or(self.max_width),
+            hard_tabs: other.hard_tabs.or(self.hard_tabs),
+            tab_spaces: other.tab_spaces.or(self.tab_spaces),
+            trailing_comma: other.trailing_comma.or(self.trailing_comma),
+            split_line_attributes: other.split_line_attributes.or(self.split_line_attributes),
+            max_inline_attributes: other.max_inline_attributes.or(self.max_inline_attributes),
+        }
+    }
+}
+
+impl From<FormatConfig> for IndentOptions {
+    fn from(config: FormatConfig) -> Self {
+        let default = IndentOptions::default();
+        let typ = match config.hard_tabs {
+            Some(true) => IndentType::Tabs,
+            _ => IndentType::Spaces,
+        };
+
+        IndentOptions::new(
+            typ,
+            config.tab_spaces.unwrap_or(4),
+            config.split_line_attributes.unwrap_or(false),
+        )
+        .with_max_width(config.max_width.unwrap_or(default.max_width()))
+        .with_max_inline_attributes(
+            config
+                .max_inline_attributes
+                .unwrap_or(default.max_inline_attributes()),
+        )
+        .with_trailing_comma(config.trailing_comma.unwrap_or(default.trailing_comma()))
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{try_fmt_file, IndentOptions};
+
+/// A replacement of a range of the source, in byte offsets.
+///
+/// Unlike [`FormattedBlock`](crate::FormattedBlock), which replaces the whole body of an rsx! block, an edit only
+/// covers the lines that actually changed so editors can keep the cursor, selections and folds in the rest of the
+/// block. Every edit refers to offsets in the original source, and the edits are sorted and never overlap.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TextEdit {
+    /// The byte offset of the start of the replaced range
+    pub start: usize,
+
+    /// The byte offset of the end of the replaced range, exclusive
+    pub end: usize,
+
+    /// The text that replaces the range
+    pub new_text: String,
+}
+
+/// Format every rsx! block in a file and return the edits that turn the source into the formatted source.
+///
+/// This is the entry point for editor integrations. The source must be a complete rust file. If the file or one of
+/// its rsx! blocks can't be parsed, this returns the error and no edits so the user's code is never mangled.
+///
+/// ```rust
+/// use dioxus_autofmt::{apply_edits, format_source, IndentOptions};
+///
+/// let source = r#"
+/// fn app() -> Element {
+///     rsx! {
+///         h1 { "Title" }
+///         p {    "Unformatted" }
+///     }
+/// }
+/// "#;
+///
+/// let edits = format_source(source, IndentOptions::default()).unwrap();
+///
+/// // Only the line that changed is replaced
+/// assert_eq!(edits.len(), 1);
+/// assert_eq!(edits[0].new_text, "        p { \"Unformatted\" }\n");
+/// assert!(apply_edits(source, &edits).contains("\n        p { \"Unformatted\" }\n"));
+/// ```
+pub fn format_source(source: &str, options: IndentOptions) -> syn::Result<Vec<TextEdit>> {
+    let parsed = syn::parse_file(source)?;
+    let blocks = try_fmt_file(source, &parsed, options)?;
+
+    let mut edits = Vec::new();
+    for block in blocks {
+        diff_lines(
+            block.start,
+            &source[block.start..block.end],
+            &block.formatted,
+            &mut edits,
+        );
+    }
+    Ok(edits)
+}
+
+/// Apply edits returned by [`format_source`] to the source they were created from
+pub fn apply_edits(source: &str, edits: &[TextEdit]) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut last = 0;
+    for edit in edits {
+        out.push_str(&source[last..edit.start]);
+        out.push_str(&edit.new_text);
+        last = edit.end;
+    }
+    out.push_str(&source[last..]);
+    out
+}
+
+/// Blocks with more lines than this are replaced with a single edit instead of being diffed line by line
+const MAX_DIFF_LINES: usize = 2000;
+
+/// Push the edits that turn `old` into `new`, one for each run of changed lines
+fn diff_lines(offset: usize, old: &str, new: &str, edits: &mut Vec<TextEdit>) {
+    let old_lines: Vec<&str> = old.split_inclusive('\n').collect();
+    let new_lines: Vec<&str> = new.split_inclusive('\n').collect();
+
+    if old_lines.len() > MAX_DIFF_LINES || new_lines.len() > MAX_DIFF_LINES {
+        edits.push(TextEdit {
+            start: offset,
+            end: offset + old.len(),
+            new_text: new.to_string(),
+        });
+        return;
+    }
+
+    let mut old_offset = offset;
+    let mut pending: Option<TextEdit> = None;
+    for line in diff::slice(&old_lines, &new_lines) {
+        if let diff::Result::Both(old_line, _) = line {
+            edits.extend(pending.take());
+            old_offset += old_line.len();
+            continue;
+        }
+
+        let edit = pending.get_or_insert_with(|| TextEdit {
+            start: old_offset,
+            end: old_offset,
+            new_text: String::new(),
+        });
+        match line {
+            diff::Result::Left(old_line) => {
+                old_offset += old_line.len();
+                edit.end = old_offset;
+            }
+            diff::Result::Right(new_line) => edit.new_text.push_str(new_line),
+            diff::Result::Both(..) => unreachable!(),
+        }
+    }
+    edits.extend(pending);
+}
@@ -1,14 +1,33 @@
+use serde::{Deserialize, Serialize};
+
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum IndentType {
     Spaces,
     Tabs,
 }
 
+/// When to write a comma after the last attribute of an element. This mirrors rustfmt's `trailing_comma` option.
+///
+/// A comma is always written between the attributes and the children of an element.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub enum TrailingComma {
+    /// Always write a trailing comma
+    Always,
+    /// Never write a trailing comma
+    Never,
+    /// Only write a trailing comma when the attributes are split over multiple lines
+    #[default]
+    Vertical,
+}
+
 #[derive(Debug, Clone)]
 pub struct IndentOptions {
     width: usize,
     indent_string: String,
     split_line_attributes: bool,
+    max_width: usize,
+    max_inline_attributes: usize,
+    trailing_comma: TrailingComma,
 }
 
 impl IndentOptions {
@@ -21,9 +40,30 @@ impl IndentOptions {
                 IndentType::Spaces => " ".repeat(width),
             },
             split_line_attributes,
+            max_width: 100,
+            max_inline_attributes: 3,
+            trailing_comma: TrailingComma::Vertical,
         }
     }
 
+    /// Set the maximum width of a line. (defaults to 100)
+    pub fn with_max_width(mut self, max_width: usize) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Set the most attributes an element can have before each attribute is written on its own line. (defaults to 3)
+    pub fn with_max_inline_attributes(mut self, max_inline_attributes: usize) -> Self {
+        self.max_inline_attributes = max_inline_attributes;
+        self
+    }
+
+    /// Set when to write a comma after the last attribute. (defaults to [`TrailingComma::Vertical`])
+    pub fn with_trailing_comma(mut self, trailing_comma: TrailingComma) -> Self {
+        self.trailing_comma = trailing_comma;
+        self
+    }
+
     /// Gets a string containing one indent worth of whitespace
     pub fn indent_str(&self) -> &str {
         &self.indent_string
@@ -68,6 +108,26 @@ impl IndentOptions {
     pub fn split_line_attributes(&self) -> bool {
         self.split_line_attributes
     }
+
+    /// The maximum width of a line
+    pub fn max_width(&self) -> usize {
+        self.max_width
+    }
+
+    /// The width a list of attributes or a whole rsx block can take up before it is broken into multiple lines
+    pub fn short_width(&self) -> usize {
+        self.max_width * 4 / 5
+    }
+
+    /// The most attributes an element can have before each attribute is written on its own line
+    pub fn max_inline_attributes(&self) -> usize {
+        self.max_inline_attributes
+    }
+
+    /// When to write a comma after the last attribute
+    pub fn trailing_comma(&self) -> TrailingComma {
+        self.trailing_comma
+    }
 }
 
 impl Default for IndentOptions {
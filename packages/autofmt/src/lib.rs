@@ -9,11 +9,15 @@ use syn::parse::Parser;
 
 mod buffer;
 mod collect_macros;
+mod config;
+mod edits;
 mod indent;
 mod prettier_please;
 mod writer;
 
-pub use indent::{IndentOptions, IndentType};
+pub use config::FormatConfig;
+pub use edits::{apply_edits, format_source, TextEdit};
+pub use indent::{IndentOptions, IndentType, TrailingComma};
 
 /// A modification to the original file to be applied by an IDE
 ///
@@ -119,7 +123,7 @@ pub fn try_fmt_file(
             && matches!(body.body.roots[0], BodyNode::RawExpr(_) | BodyNode::Text(_));
 
         // If it's short, and it's not a single expression, and it's not empty, then we can collapse it
-        if formatted.len() <= 80
+        if formatted.len() <= writer.out.indent.short_width()
             && !formatted.contains('\n')
             && !body_is_solo_expr
             && !formatted.trim().is_empty()
@@ -1,4 +1,4 @@
-use crate::{buffer::Buffer, IndentOptions};
+use crate::{buffer::Buffer, IndentOptions, TrailingComma};
 use dioxus_rsx::*;
 use proc_macro2::{LineColumn, Span};
 use quote::ToTokens;
@@ -106,6 +106,7 @@ impl<'a> Writer<'a> {
             generics,
             spreads,
             brace,
+            fallback,
             ..
         }: &Component,
     ) -> Result {
@@ -124,6 +125,14 @@ impl<'a> Writer<'a> {
         write!(self.out, " ")?;
         self.write_rsx_block(fields, spreads, &children.roots, &brace.unwrap_or_default())?;
 
+        if let Some(fallback) = fallback {
+            write!(self.out, " else ")?;
+            if let Some((_, input, _)) = &fallback.input {
+                write!(self.out, "|{}| ", input.to_token_stream())?;
+            }
+            self.write_rsx_block(&[], &[], &fallback.body.roots, &fallback.brace)?;
+        }
+
         Ok(())
     }
 
@@ -142,7 +151,7 @@ impl<'a> Writer<'a> {
             forloop.pat.clone().into_token_stream(),
         )?;
 
-        self.write_inline_expr(&forloop.expr)?;
+        self.write_inline_expr_with_key(&forloop.expr, forloop.body.key.as_ref())?;
 
         if forloop.body.is_empty() {
             write!(self.out, "}}")?;
@@ -205,6 +214,15 @@ impl<'a> Writer<'a> {
 
     /// An expression within a for or if block that might need to be spread out across several lines
     fn write_inline_expr(&mut self, expr: &Expr) -> std::fmt::Result {
+        self.write_inline_expr_with_key(expr, None)
+    }
+
+    /// Write an inline expression followed by an optional for loop key and the opening brace
+    fn write_inline_expr_with_key(
+        &mut self,
+        expr: &Expr,
+        key: Option<&AttributeValue>,
+    ) -> std::fmt::Result {
         let unparsed = self.unparse_expr(expr);
         let mut lines = unparsed.lines();
         let first_line = lines.next().ok_or(std::fmt::Error)?;
@@ -219,6 +237,11 @@ impl<'a> Writer<'a> {
             write!(self.out, "{line}")?;
         }
 
+        if let Some(key) = key {
+            write!(self.out, " key: ")?;
+            self.write_attribute_value(key)?;
+        }
+
         if was_multiline {
             self.out.tabbed_line()?;
             write!(self.out, "{{")?;
@@ -296,7 +319,8 @@ impl<'a> Writer<'a> {
 
         // check if we have a lot of attributes
         let attr_len = self.is_short_attrs(attributes, spreads);
-        let is_short_attr_list = (attr_len + self.out.indent_level * 4) < 80;
+        let is_short_attr_list =
+            (attr_len + self.out.indent_level * 4) < self.out.indent.short_width();
         let children_len = self
             .is_short_children(children)
             .map_err(|_| std::fmt::Error)?;
@@ -319,7 +343,9 @@ impl<'a> Writer<'a> {
 
         // if we have few children and few attributes, make it a one-liner
         if is_short_attr_list && is_small_children {
-            if children_len.unwrap() + attr_len + self.out.indent_level * 4 < 100 {
+            if children_len.unwrap() + attr_len + self.out.indent_level * 4
+                < self.out.indent.max_width()
+            {
                 opt_level = ShortOptimization::Oneliner;
             } else {
                 opt_level = ShortOptimization::PropsOnTop;
@@ -466,7 +492,13 @@ impl<'a> Writer<'a> {
             };
 
             let has_more = attr_iter.peek().is_some();
-            let should_finish_comma = has_attributes && has_children || !props_same_line;
+            // The attributes always need to be separated from the children
+            let should_finish_comma = has_attributes && has_children
+                || match self.out.indent.trailing_comma() {
+                    TrailingComma::Always => true,
+                    TrailingComma::Never => false,
+                    TrailingComma::Vertical => !props_same_line,
+                };
 
             if has_more || should_finish_comma {
                 write!(self.out, ",")?;
@@ -517,6 +549,9 @@ impl<'a> Writer<'a> {
             AttributeValue::IfExpr(if_chain) => {
                 self.write_attribute_if_chain(if_chain)?;
             }
+            AttributeValue::ClassList(list) => {
+                self.write_class_list(list)?;
+            }
             AttributeValue::AttrLiteral(value) => {
                 write!(self.out, "{value}")?;
             }
@@ -538,6 +573,19 @@ impl<'a> Writer<'a> {
         Ok(())
     }
 
+    fn write_class_list(&mut self, list: &ClassList) -> Result {
+        write!(self.out, "{{ ")?;
+        for (idx, entry) in list.entries.iter().enumerate() {
+            if idx != 0 {
+                write!(self.out, ", ")?;
+            }
+            let condition = self.unparse_expr(&entry.condition);
+            write!(self.out, "{}: {condition}", entry.name.to_token_stream())?;
+        }
+        write!(self.out, " }}")?;
+        Ok(())
+    }
+
     fn write_attribute_if_chain(&mut self, if_chain: &IfAttributeValue) -> Result {
         let cond = self.unparse_expr(&if_chain.condition);
         write!(self.out, "if {cond} {{ ")?;
@@ -663,6 +711,18 @@ impl<'a> Writer<'a> {
                     .unwrap_or_default();
                 condition_len + value_len + if_len + brace_len + space_len + else_len
             }
+            AttributeValue::ClassList(list) => {
+                list.entries
+                    .iter()
+                    .map(|entry| {
+                        let name_len = entry.name.to_token_stream().to_string().len();
+                        let condition_len = self.retrieve_formatted_expr(&entry.condition).len();
+                        // `"name": condition, `
+                        name_len + condition_len + 4
+                    })
+                    .sum::<usize>()
+                    + 2
+            }
             AttributeValue::AttrLiteral(lit) => lit.to_string().len(),
             AttributeValue::Shorthand(expr) => {
                 let span = &expr.span();
@@ -691,8 +751,8 @@ impl<'a> Writer<'a> {
     fn is_short_attrs(&mut self, attributes: &[Attribute], spreads: &[Spread]) -> usize {
         let mut total = 0;
 
-        // No more than 3 attributes before breaking the line
-        if attributes.len() > 3 {
+        // Break the line if there are too many attributes
+        if attributes.len() > self.out.indent.max_inline_attributes() {
             return 100000;
         }
 
@@ -820,7 +880,7 @@ impl<'a> Writer<'a> {
     }
 
     // check if the children are short enough to be on the same line
-    // We don't have the notion of current line depth - each line tries to be < 80 total
+    // We don't have the notion of current line depth - each line tries to be shorter than the short width
     // returns the total line length if it's short
     // returns none if the length exceeds the limit
     // I think this eventually becomes quadratic :(
@@ -852,7 +912,8 @@ impl<'a> Writer<'a> {
             // basically if the component is completely empty, we can inline it
                 if comp.fields.is_empty()
                     && comp.children.is_empty()
-                    && comp.spreads.is_empty() =>
+                    && comp.spreads.is_empty()
+                    && comp.fallback.is_none() =>
             {
                 Some(
                     comp.name
@@ -955,6 +1016,7 @@ impl<'a> Writer<'a> {
             AttributeValue::AttrLiteral(l) => l.span(),
             AttributeValue::EventTokens(closure) => closure.body.span(),
             AttributeValue::AttrExpr(exp) => exp.span(),
+            AttributeValue::ClassList(list) => list.brace.span.close(),
             AttributeValue::IfExpr(ex) => ex
                 .else_value
                 .as_ref()
@@ -0,0 +1,58 @@
+use dioxus_autofmt::{apply_edits, apply_formats, format_source, try_fmt_file, IndentOptions};
+
+const UNFORMATTED: &str = r#"
+#[component]
+fn App() -> Element {
+    rsx! {
+        div {
+            h1 {    "Title" }
+            p { "unchanged" }
+            span {   "changed" }
+        }
+    }
+}
+"#;
+
+#[test]
+fn edits_match_whole_block_formatting() {
+    let edits = format_source(UNFORMATTED, IndentOptions::default()).unwrap();
+
+    let file = syn::parse_file(UNFORMATTED).unwrap();
+    let blocks = try_fmt_file(UNFORMATTED, &file, IndentOptions::default()).unwrap();
+
+    assert_eq!(
+        apply_edits(UNFORMATTED, &edits),
+        apply_formats(UNFORMATTED, blocks)
+    );
+}
+
+#[test]
+fn edits_only_cover_changed_lines() {
+    let edits = format_source(UNFORMATTED, IndentOptions::default()).unwrap();
+    assert_eq!(edits.len(), 2);
+
+    for edit in &edits {
+        let replaced = &UNFORMATTED[edit.start..edit.end];
+        assert!(!replaced.contains("unchanged"));
+    }
+    assert_eq!(edits[0].new_text, "            h1 { \"Title\" }\n");
+}
+
+#[test]
+fn formatted_source_has_no_edits() {
+    let edits = format_source(UNFORMATTED, IndentOptions::default()).unwrap();
+    let formatted = apply_edits(UNFORMATTED, &edits);
+    assert!(format_source(&formatted, IndentOptions::default())
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn invalid_source_is_an_error() {
+    assert!(format_source("fn app( {", IndentOptions::default()).is_err());
+    assert!(format_source(
+        include_str!("./partials/wrong.rsx"),
+        IndentOptions::default()
+    )
+    .is_err());
+}
@@ -27,6 +27,7 @@ macro_rules! twoway {
 twoway![
     attributes,
     basic_expr,
+    class_list,
     collapse_expr,
     comments,
     commentshard,
@@ -61,4 +62,6 @@ twoway![
     prop_rsx,
     asset,
     collapse,
+    event_shorthand,
+    boundaries,
 ];
@@ -1,6 +1,6 @@
 #![allow(deprecated)]
 
-use dioxus_autofmt::{IndentOptions, IndentType};
+use dioxus_autofmt::{IndentOptions, IndentType, TrailingComma};
 
 macro_rules! twoway {
     ($val:literal => $name:ident ($indent:expr)) => {
@@ -39,3 +39,7 @@ twoway!("oneline-expand" => online_expand (IndentOptions::new(IndentType::Spaces
 twoway!("shortened" => shortened (IndentOptions::new(IndentType::Spaces, 4, false)));
 twoway!("syntax_error" => syntax_error (IndentOptions::new(IndentType::Spaces, 4, false)));
 twoway!("skipfail" => skipfail (IndentOptions::new(IndentType::Spaces, 4, false)));
+
+twoway!("config-narrow" => config_narrow (IndentOptions::new(IndentType::Spaces, 4, false).with_max_width(40)));
+twoway!("config-trailing" => config_trailing (IndentOptions::new(IndentType::Spaces, 4, false).with_trailing_comma(TrailingComma::Never)));
+twoway!("config-inline-attrs" => config_inline_attrs (IndentOptions::new(IndentType::Spaces, 4, false).with_max_inline_attributes(1)));
@@ -1,9 +1,14 @@
 use super::*;
 use crate::DioxusCrate;
 use build::TargetArgs;
-use dioxus_autofmt::{IndentOptions, IndentType};
+use dioxus_autofmt::{FormatConfig, IndentOptions};
 use rayon::prelude::*;
-use std::{borrow::Cow, fs, path::Path, process::exit};
+use std::{
+    borrow::Cow,
+    fs,
+    path::{Path, PathBuf},
+    process::exit,
+};
 
 // For reference, the rustfmt main.rs file
 // https://github.com/rust-lang/rustfmt/blob/master/src/bin/main.rs
@@ -230,6 +235,10 @@ fn autoformat_project(
     Ok(())
 }
 
+/// The name of the config file with rsx specific formatting options. It is looked up from the formatted file upwards,
+/// like rustfmt.toml
+const FORMAT_CONFIG_FILE: &str = "dioxusfmt.toml";
+
 fn indentation_for(
     file_or_dir: impl AsRef<Path>,
     split_line_attributes: bool,
@@ -244,37 +253,44 @@ fn indentation_for(
         return Err(Error::CargoError("cargo fmt failed".into()));
     }
 
+    // Start with the rustfmt options that apply to rsx (indentation, max width, trailing commas)
     let config = String::from_utf8_lossy(&out.stdout);
+    let mut config = toml::from_str::<FormatConfig>(&config)
+        .map_err(|err| Error::RuntimeError(format!("Could not parse rustfmt config: {err}")))?;
+    if config.hard_tabs.is_none() {
+        return Err(Error::RuntimeError(
+            "Could not find hard_tabs option in rustfmt config".into(),
+        ));
+    }
+    if config.tab_spaces.is_none() {
+        return Err(Error::RuntimeError(
+            "Could not find tab_spaces option in rustfmt config".into(),
+        ));
+    }
 
-    let hard_tabs = config
-        .lines()
-        .find(|line| line.starts_with("hard_tabs "))
-        .and_then(|line| line.split_once('='))
-        .map(|(_, value)| value.trim() == "true")
-        .ok_or_else(|| {
-            Error::RuntimeError("Could not find hard_tabs option in rustfmt config".into())
-        })?;
-    let tab_spaces = config
-        .lines()
-        .find(|line| line.starts_with("tab_spaces "))
-        .and_then(|line| line.split_once('='))
-        .map(|(_, value)| value.trim().parse::<usize>())
-        .ok_or_else(|| {
-            Error::RuntimeError("Could not find tab_spaces option in rustfmt config".into())
-        })?
-        .map_err(|_| {
-            Error::RuntimeError("Could not parse tab_spaces option in rustfmt config".into())
+    // Then apply the rsx specific options
+    if let Some(path) = find_format_config(file_or_dir.as_ref()) {
+        let contents = fs::read_to_string(&path)?;
+        let rsx_config = toml::from_str::<FormatConfig>(&contents).map_err(|err| {
+            Error::RuntimeError(format!("Could not parse {}: {err}", path.display()))
         })?;
+        config = config.merge(rsx_config);
+    }
 
-    Ok(IndentOptions::new(
-        if hard_tabs {
-            IndentType::Tabs
-        } else {
-            IndentType::Spaces
-        },
-        tab_spaces,
-        split_line_attributes,
-    ))
+    if split_line_attributes {
+        config.split_line_attributes = Some(true);
+    }
+
+    Ok(config.into())
+}
+
+/// Find the closest rsx format config file to a file or directory
+fn find_format_config(file_or_dir: &Path) -> Option<PathBuf> {
+    let start = file_or_dir.canonicalize().ok()?;
+    start
+        .ancestors()
+        .map(|dir| dir.join(FORMAT_CONFIG_FILE))
+        .find(|path| path.is_file())
 }
 
 /// Format rust code using prettyplease
@@ -0,0 +1,218 @@
+//! The `css!` macro scopes a block of css to a class that is generated from the contents of the css at compile time.
+//!
+//! Declarations at the top level of the block apply to the scoped class itself. Every selector is scoped to the
+//! class: `&` is replaced with the class and other selectors match descendants of the class. Identical css creates
+//! the same class, so the styles are only injected once no matter how many components use them.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::LitStr;
+
+/// Scope the css string literal passed to the css! macro and expand it into a `ScopedStyle`
+pub(crate) fn scoped_css(input: LitStr) -> syn::Result<TokenStream> {
+    let source = strip_comments(&input.value());
+    let class = format!("dx-{:08x}", fnv1a(source.trim()) as u32);
+    let css = scope_block(&source, &format!(".{class}"))
+        .map_err(|err| syn::Error::new(input.span(), format!("Failed to parse css: {err}")))?;
+
+    Ok(quote! {
+        {
+            let __style = dioxus_elements::document::ScopedStyle::new(#class, #css);
+            __style.inject();
+            __style
+        }
+    })
+}
+
+/// A stable hash of the css. The hash of the standard library may change between compiler versions.
+fn fnv1a(source: &str) -> u64 {
+    source.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+fn strip_comments(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+    let mut quote = None;
+    while let Some(c) = chars.next() {
+        match quote {
+            Some(q) => {
+                if c == '\\' {
+                    out.push(c);
+                    out.extend(chars.next());
+                    continue;
+                }
+                if c == q {
+                    quote = None;
+                }
+                out.push(c);
+            }
+            None if c == '/' && chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut last = ' ';
+                for c in chars.by_ref() {
+                    if last == '*' && c == '/' {
+                        break;
+                    }
+                    last = c;
+                }
+            }
+            None => {
+                if c == '"' || c == '\'' {
+                    quote = Some(c);
+                }
+                out.push(c);
+            }
+        }
+    }
+    out
+}
+
+/// Split the source at the first `;`, `{` or `}` that is not inside a string or parentheses
+fn split_prelude(source: &str) -> (&str, Option<(char, &str)>) {
+    let mut quote = None;
+    let mut depth = 0usize;
+    let mut escaped = false;
+    for (idx, c) in source.char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' => quote = Some(c),
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth = depth.saturating_sub(1),
+            ';' | '{' | '}' if depth == 0 => {
+                return (&source[..idx], Some((c, &source[idx + 1..])))
+            }
+            _ => {}
+        }
+    }
+    (source, None)
+}
+
+/// Split the body of a block from the rest of the source. The source starts right after the opening brace.
+fn split_block(source: &str) -> Result<(&str, &str), String> {
+    let mut rest = source;
+    let mut depth = 1;
+    loop {
+        match split_prelude(rest) {
+            (_, Some(('{', after))) => {
+                depth += 1;
+                rest = after;
+            }
+            (_, Some(('}', after))) => {
+                depth -= 1;
+                rest = after;
+                if depth == 0 {
+                    let body_len = source.len() - after.len() - 1;
+                    return Ok((&source[..body_len], after));
+                }
+            }
+            (_, Some((_, after))) => rest = after,
+            (_, None) => return Err("unclosed `{`".to_string()),
+        }
+    }
+}
+
+/// At-rules that contain rules which need to be scoped. Other at-rules like `@keyframes` are kept as is.
+const SCOPED_AT_RULES: &[&str] = &["@media", "@supports", "@container", "@layer"];
+
+fn scope_block(source: &str, scope: &str) -> Result<String, String> {
+    let mut declarations = Vec::new();
+    let mut rules = Vec::new();
+    let mut rest = source;
+
+    loop {
+        let (prelude, next) = split_prelude(rest);
+        let prelude = prelude.trim();
+        match next {
+            Some(('{', after)) => {
+                let (body, after) = split_block(after)?;
+                rest = after;
+                if prelude.starts_with('@') {
+                    let scoped = SCOPED_AT_RULES
+                        .iter()
+                        .any(|rule| prelude.split_whitespace().next() == Some(*rule));
+                    let body = if scoped {
+                        scope_block(body, scope)?
+                    } else {
+                        collapse_whitespace(body)
+                    };
+                    rules.push(format!("{prelude}{{{body}}}"));
+                } else {
+                    if prelude.is_empty() {
+                        return Err("found a block without a selector".to_string());
+                    }
+                    let selector = scope_selector(prelude, scope);
+                    rules.push(format!("{selector}{{{}}}", collapse_whitespace(body)));
+                }
+            }
+            Some((';', after)) => {
+                rest = after;
+                if prelude.starts_with('@') {
+                    rules.insert(0, format!("{prelude};"));
+                } else if !prelude.is_empty() {
+                    declarations.push(collapse_whitespace(prelude));
+                }
+            }
+            Some(('}', _)) => return Err("unexpected `}`".to_string()),
+            _ => {
+                // The last declaration doesn't need a semicolon
+                if !prelude.is_empty() {
+                    declarations.push(collapse_whitespace(prelude));
+                }
+                break;
+            }
+        }
+    }
+
+    let mut out = String::new();
+    if !declarations.is_empty() {
+        out.push_str(&format!("{scope}{{{}}}", declarations.join(";")));
+    }
+    for rule in rules {
+        out.push_str(&rule);
+    }
+    Ok(out)
+}
+
+/// Scope each selector in a comma separated list of selectors
+fn scope_selector(selectors: &str, scope: &str) -> String {
+    let mut scoped = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (idx, c) in selectors.char_indices() {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                scoped.push(scope_single_selector(&selectors[start..idx], scope));
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    scoped.push(scope_single_selector(&selectors[start..], scope));
+    scoped.join(",")
+}
+
+fn scope_single_selector(selector: &str, scope: &str) -> String {
+    let selector = collapse_whitespace(selector);
+    if selector.contains('&') {
+        selector.replace('&', scope)
+    } else {
+        format!("{scope} {selector}")
+    }
+}
+
+fn collapse_whitespace(source: &str) -> String {
+    source.split_whitespace().collect::<Vec<_>>().join(" ")
+}
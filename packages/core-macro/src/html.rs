@@ -0,0 +1,111 @@
+//! The `html!` macro parses a literal HTML snippet at compile time and expands it into the same template `rsx!` would create.
+//!
+//! Instead of building the rsx nodes by hand, we write out the equivalent rsx tokens and parse them as a normal rsx call body.
+//! That keeps the html! macro in sync with every feature of rsx! (templates, hot reloading, diagnostics) for free.
+
+use dioxus_rsx::CallBody;
+use html_parser::{Dom, Node};
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{Ident, LitStr};
+
+/// Parse the html string literal passed to the html! macro and expand it into a rsx call body
+pub(crate) fn html_to_rsx(input: LitStr) -> syn::Result<CallBody> {
+    let source = input.value();
+    let dom = Dom::parse(&source)
+        .map_err(|err| syn::Error::new(input.span(), format!("Failed to parse html: {err}")))?;
+
+    let span = input.span();
+    let nodes = dom
+        .children
+        .iter()
+        .map(|node| html_node_to_rsx(node, span))
+        .collect::<syn::Result<Vec<_>>>()?;
+    syn::parse2(quote! { #(#nodes)* })
+}
+
+fn html_node_to_rsx(node: &Node, span: Span) -> syn::Result<TokenStream> {
+    Ok(match node {
+        Node::Text(text) => text_literal(text, span).into_token_stream(),
+
+        Node::Element(el) => {
+            let name = element_name(&el.name, span)?;
+
+            let mut attributes = Vec::new();
+            if let Some(id) = &el.id {
+                attributes.push(attribute("id", Some(id), span));
+            }
+            if !el.classes.is_empty() {
+                attributes.push(attribute("class", Some(&el.classes.join(" ")), span));
+            }
+            // The parser stores attributes in a hashmap, sort them so the template is the same between builds
+            let mut sorted: Vec<_> = el.attributes.iter().collect();
+            sorted.sort_by_key(|(name, _)| *name);
+            for (attr_name, value) in sorted {
+                attributes.push(attribute(attr_name, value.as_deref(), span));
+            }
+
+            let children = el
+                .children
+                .iter()
+                .map(|child| html_node_to_rsx(child, span))
+                .collect::<syn::Result<Vec<_>>>()?;
+
+            quote! { #name { #(#attributes,)* #(#children)* } }
+        }
+
+        // rsx doesn't have comments
+        Node::Comment(_) => TokenStream::new(),
+    })
+}
+
+/// Elements with a dash are web components. Everything else should exist in the element module so it gets the right namespace
+fn element_name(name: &str, span: Span) -> syn::Result<TokenStream> {
+    let segments = name
+        .split('-')
+        .map(|segment| match syn::parse_str::<Ident>(segment) {
+            Ok(mut ident) => {
+                ident.set_span(span);
+                Ok(ident)
+            }
+            // Keywords like `use` are defined with a raw identifier
+            Err(_) if syn::parse_str::<Ident>(&format!("r#{segment}")).is_ok() => {
+                Ok(Ident::new_raw(segment, span))
+            }
+            Err(_) => Err(syn::Error::new(
+                span,
+                format!("The html element <{name}> can't be used in rsx"),
+            )),
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! { #(#segments)-* })
+}
+
+/// Attributes are written with their html name so we don't need to map them to the rsx name. Attributes without a value are boolean attributes
+fn attribute(name: &str, value: Option<&str>, span: Span) -> TokenStream {
+    let name = LitStr::new(name, span);
+    let value = text_literal(value.unwrap_or("true"), span);
+    quote! { #name: #value }
+}
+
+/// Create a string literal that rsx will treat as plain text instead of a format string
+fn text_literal(text: &str, span: Span) -> LitStr {
+    let text = decode_entities(text).replace('{', "{{").replace('}', "}}");
+    LitStr::new(&text, span)
+}
+
+/// Decode the common html entities. Renderers escape text again when they write html
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", "\u{a0}")
+        .replace("&amp;", "&")
+}
@@ -8,6 +8,9 @@ use quote::ToTokens;
 use syn::parse_macro_input;
 
 mod component;
+mod css;
+#[cfg(feature = "html-macro")]
+mod html;
 mod props;
 mod utils;
 
@@ -32,6 +35,27 @@ pub fn rsx(tokens: TokenStream) -> TokenStream {
     }
 }
 
+#[doc = include_str!("../docs/html.md")]
+#[cfg(feature = "html-macro")]
+#[proc_macro]
+pub fn html(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as syn::LitStr);
+    match html::html_to_rsx(input) {
+        Err(err) => err.to_compile_error().into(),
+        Ok(body) => body.into_token_stream().into(),
+    }
+}
+
+#[doc = include_str!("../docs/css.md")]
+#[proc_macro]
+pub fn css(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as syn::LitStr);
+    match css::scoped_css(input) {
+        Err(err) => err.to_compile_error().into(),
+        Ok(style) => style.into(),
+    }
+}
+
 #[doc = include_str!("../docs/component.md")]
 #[proc_macro_attribute]
 pub fn component(_args: TokenStream, input: TokenStream) -> TokenStream {
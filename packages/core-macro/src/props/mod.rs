@@ -170,7 +170,7 @@ mod util {
 }
 
 mod field_info {
-    use crate::props::type_from_inside_option;
+    use crate::props::{expr_to_ident, looks_like_children_of_type, type_from_inside_option};
     use proc_macro2::TokenStream;
     use quote::quote;
     use syn::spanned::Spanned;
@@ -205,6 +205,14 @@ mod field_info {
                         Some(syn::parse(quote!(dioxus_core::VNode::empty()).into()).unwrap());
                 }
 
+                // typed children are converted from the element rsx creates
+                if looks_like_children_of_type(&field.ty) {
+                    builder_attr.auto_into = true;
+                    builder_attr.default = Some(
+                        syn::parse(quote!(::core::default::Default::default()).into()).unwrap(),
+                    );
+                }
+
                 // String fields automatically use impl Display
                 if field.ty == parse_quote!(::std::string::String)
                     || field.ty == parse_quote!(std::string::String)
@@ -251,6 +259,11 @@ mod field_info {
             }
         }
 
+        /// The name of the setter for this field on the builder. This is the name used in rsx
+        pub fn setter_name(&self) -> &syn::Ident {
+            self.builder_attr.rename.as_ref().unwrap_or(self.name)
+        }
+
         pub fn generic_ty_param(&self) -> syn::GenericParam {
             syn::GenericParam::Type(self.generic_ident.clone().into())
         }
@@ -282,6 +295,9 @@ mod field_info {
         pub strip_option: bool,
         pub ignore_option: bool,
         pub extends: Vec<Path>,
+        pub rename: Option<syn::Ident>,
+        pub aliases: Vec<syn::Ident>,
+        pub validate: Option<syn::Expr>,
     }
 
     impl FieldBuilderAttr {
@@ -354,6 +370,18 @@ mod field_info {
                             self.default = Some(*assign.right);
                             Ok(())
                         }
+                        "rename" => {
+                            self.rename = Some(expr_to_ident(*assign.right)?);
+                            Ok(())
+                        }
+                        "alias" => {
+                            self.aliases.push(expr_to_ident(*assign.right)?);
+                            Ok(())
+                        }
+                        "validate" => {
+                            self.validate = Some(*assign.right);
+                            Ok(())
+                        }
                         "default_code" => {
                             if let syn::Expr::Lit(syn::ExprLit {
                                 lit: syn::Lit::Str(code),
@@ -405,6 +433,14 @@ mod field_info {
                             Ok(())
                         }
 
+                        // #[props(slot)] marks an `Element` field as a named slot that renders nothing if it is not filled
+                        "slot" => {
+                            self.default = Some(
+                                syn::parse(quote!(dioxus_core::VNode::empty()).into()).unwrap(),
+                            );
+                            Ok(())
+                        }
+
                         _ => {
                             macro_rules! handle_fields {
                                 ( $( $flag:expr, $field:ident, $already:expr; )* ) => {
@@ -481,6 +517,15 @@ mod field_info {
     }
 }
 
+/// Parse the name of a setter from `rename = name` or `alias = name`
+fn expr_to_ident(expr: syn::Expr) -> Result<syn::Ident, syn::Error> {
+    match &expr {
+        syn::Expr::Path(path) => path.path.get_ident().cloned(),
+        _ => None,
+    }
+    .ok_or_else(|| syn::Error::new_spanned(expr, "Expected simple identifier"))
+}
+
 fn type_from_inside_option(ty: &Type) -> Option<&Type> {
     let Type::Path(type_path) = ty else {
         return None;
@@ -548,7 +593,10 @@ mod struct_info {
         empty_type, empty_type_tuple, expr_to_single_string, make_punctuated_single,
         modify_types_generics_hack, path_to_single_string, strip_raw_ident_prefix, type_tuple,
     };
-    use super::{child_owned_type, looks_like_callback_type, looks_like_signal_type};
+    use super::{
+        child_owned_type, looks_like_callback_type, looks_like_children_of_type,
+        looks_like_signal_type,
+    };
 
     #[derive(Debug)]
     pub struct StructInfo<'a> {
@@ -834,6 +882,21 @@ Finally, call `.build()` to create the instance of `{name}`.
 
             let memoize = self.memoize_impl()?;
 
+            // Let rsx check the children passed to the builder against the type of a typed children field
+            let typed_children = self
+                .included_fields()
+                .find(|f| f.name == "children" && looks_like_children_of_type(f.ty))
+                .map(|f| {
+                    let ty = f.ty;
+                    let (b_impl_generics, b_ty_generics, b_where_clause) =
+                        b_generics.split_for_impl();
+                    quote! {
+                        impl #b_impl_generics dioxus_core::internal::TypedChildrenBuilder for #builder_name #b_ty_generics #b_where_clause {
+                            type Children = #ty;
+                        }
+                    }
+                });
+
             let global_fields = self
                 .extend_fields()
                 .map(|f| {
@@ -875,6 +938,8 @@ Finally, call `.build()` to create the instance of `{name}`.
                     _phantom: (#( #phantom_generics ),*),
                 }
 
+                #typed_children
+
                 impl #impl_generics dioxus_core::prelude::Properties for #name #ty_generics
                 #b_generics_where
                 {
@@ -885,6 +950,10 @@ Finally, call `.build()` to create the instance of `{name}`.
                     fn memoize(&mut self, new: &Self) -> bool {
                         #memoize
                     }
+                    fn debug_snapshot(&self) -> Option<String> {
+                        use dioxus_core::internal::{DebugPropsSnapshot, NoDebugPropsSnapshot};
+                        (&&dioxus_core::internal::DebugPropsProbe(self)).debug_snapshot()
+                    }
                 }
             })
         }
@@ -1168,7 +1237,31 @@ Finally, call `.build()` to create the instance of `{name}`.
                 ),
                 builder_name.span(),
             );
-            let repeated_fields_error_message = format!("Repeated field {field_name}");
+            let setter_name = field.setter_name();
+            let repeated_fields_error_message = format!("Repeated field {setter_name}");
+
+            // Aliases forward to the main setter
+            let aliases = &field.builder_attr.aliases;
+            let alias_setters = aliases.iter().map(|alias| {
+                quote! {
+                    #( #docs )*
+                    #[allow(clippy::type_complexity)]
+                    pub fn #alias < #marker > (self, #field_name: #arg_type) -> #builder_name < #( #target_generics ),* > {
+                        self.#setter_name(#field_name)
+                    }
+                }
+            });
+            let repeated_alias_setters = aliases.iter().map(|alias| {
+                quote! {
+                    #[deprecated(
+                        note = #repeated_fields_error_message
+                    )]
+                    #[allow(clippy::type_complexity)]
+                    pub fn #alias< #marker > (self, _: #repeated_fields_error_type_name) -> #builder_name < #( #target_generics ),* > {
+                        self
+                    }
+                }
+            });
 
             let forward_fields = self
                 .extend_fields()
@@ -1186,7 +1279,7 @@ Finally, call `.build()` to create the instance of `{name}`.
                 impl #impl_generics #builder_name < #( #ty_generics ),* > #where_clause {
                     #( #docs )*
                     #[allow(clippy::type_complexity)]
-                    pub fn #field_name < #marker > (self, #field_name: #arg_type) -> #builder_name < #( #target_generics ),* > {
+                    pub fn #setter_name < #marker > (self, #field_name: #arg_type) -> #builder_name < #( #target_generics ),* > {
                         let #field_name = (#arg_expr,);
                         let ( #(#descructuring,)* ) = self.fields;
                         #builder_name {
@@ -1195,6 +1288,7 @@ Finally, call `.build()` to create the instance of `{name}`.
                             _phantom: self._phantom,
                         }
                     }
+                    #( #alias_setters )*
                 }
                 #[doc(hidden)]
                 #[allow(dead_code, non_camel_case_types, non_snake_case)]
@@ -1206,9 +1300,10 @@ Finally, call `.build()` to create the instance of `{name}`.
                         note = #repeated_fields_error_message
                     )]
                     #[allow(clippy::type_complexity)]
-                    pub fn #field_name< #marker > (self, _: #repeated_fields_error_type_name) -> #builder_name < #( #target_generics ),* > {
+                    pub fn #setter_name< #marker > (self, _: #repeated_fields_error_type_name) -> #builder_name < #( #target_generics ),* > {
                         self
                     }
+                    #( #repeated_alias_setters )*
                 }
             })
         }
@@ -1301,7 +1396,8 @@ Finally, call `.build()` to create the instance of `{name}`.
                 ),
                 builder_name.span(),
             );
-            let early_build_error_message = format!("Missing required field {field_name}");
+            let early_build_error_message =
+                format!("Missing required field {}", field.setter_name());
 
             Ok(quote! {
                 #[doc(hidden)]
@@ -1418,6 +1514,27 @@ Finally, call `.build()` to create the instance of `{name}`.
                     quote!(let #name = #name.0;)
                 }
             });
+            // Run the validators once every field has its final value. The panic is reported at the location the
+            // props were built from, which is the component in rsx
+            let validations = self
+                .fields
+                .iter()
+                .filter_map(|field| {
+                    let validate = field.builder_attr.validate.as_ref()?;
+                    let field_name = &field.name;
+                    let message = format!(
+                        "Invalid value for the prop `{}` of `{}`: {{}}",
+                        strip_raw_ident_prefix(field.setter_name().to_string()),
+                        name
+                    );
+                    Some(quote! {
+                        if let ::core::result::Result::Err(err) = (#validate)(&#field_name) {
+                            panic!(#message, err);
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+            let track_caller = (!validations.is_empty()).then(|| quote!(#[track_caller]));
             let field_names = self.fields.iter().map(|field| field.name);
             let doc = if self.builder_attr.doc {
                 match self.builder_attr.build_method_doc {
@@ -1458,6 +1575,7 @@ Finally, call `.build()` to create the instance of `{name}`.
 
                     impl #original_impl_generics #name #ty_generics #where_clause {
                         /// Create a component from the props.
+                        #[track_caller]
                         pub fn into_vcomponent<M: 'static>(
                             self,
                             render_fn: impl dioxus_core::prelude::ComponentFunction<#original_name #ty_generics, M>,
@@ -1476,14 +1594,19 @@ Finally, call `.build()` to create the instance of `{name}`.
                         fn memoize(&mut self, new: &Self) -> bool {
                             self.inner.memoize(&new.inner)
                         }
+                        fn debug_snapshot(&self) -> Option<String> {
+                            self.inner.debug_snapshot()
+                        }
                     }
 
                     #[allow(dead_code, non_camel_case_types, missing_docs)]
                     impl #impl_generics #builder_name #modified_ty_generics #where_clause {
                         #doc
+                        #track_caller
                         pub fn build(self) -> #name #ty_generics {
                             let ( #(#descructuring,)* ) = self.fields;
                             #( #assignments )*
+                            #( #validations )*
                             #name {
                                 inner: #original_name {
                                     #( #field_names ),*
@@ -1498,9 +1621,11 @@ Finally, call `.build()` to create the instance of `{name}`.
                     #[allow(dead_code, non_camel_case_types, missing_docs)]
                     impl #impl_generics #builder_name #modified_ty_generics #where_clause {
                         #doc
+                        #track_caller
                         pub fn build(self) -> #name #ty_generics {
                             let ( #(#descructuring,)* ) = self.fields;
                             #( #assignments )*
+                            #( #validations )*
                             #name {
                                 #( #field_names ),*
                             }
@@ -1715,6 +1840,18 @@ fn child_owned_type(ty: &Type) -> bool {
     looks_like_signal_type(ty) || looks_like_callback_type(ty)
 }
 
+/// Check if a type is a typed children type like `ChildrenOf<T>`
+fn looks_like_children_of_type(ty: &Type) -> bool {
+    match extract_base_type_without_generics(ty) {
+        Some(path_without_generics) => {
+            path_without_generics == parse_quote!(dioxus_core::prelude::ChildrenOf)
+                || path_without_generics == parse_quote!(prelude::ChildrenOf)
+                || path_without_generics == parse_quote!(ChildrenOf)
+        }
+        None => false,
+    }
+}
+
 fn looks_like_signal_type(ty: &Type) -> bool {
     match extract_base_type_without_generics(ty) {
         Some(path_without_generics) => {
@@ -0,0 +1,78 @@
+use dioxus::prelude::*;
+use std::cell::RefCell;
+
+thread_local! {
+    static STYLES: RefCell<Vec<ScopedStyle>> = const { RefCell::new(Vec::new()) };
+}
+
+fn render(app: fn() -> Element) -> String {
+    STYLES.with_borrow_mut(|styles| styles.clear());
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    dioxus_ssr::render(&dom)
+}
+
+#[test]
+fn scopes_css_to_a_class() {
+    fn app() -> Element {
+        let style = css!(
+            r#"
+            /* The card */
+            padding: 1rem;
+            color: red;
+
+            &:hover, &.active { color: blue; }
+            .title, p > span { font-weight: bold; }
+
+            @media (max-width: 600px) {
+                padding: 0.5rem;
+                .title { font-size: 12px; }
+            }
+
+            @keyframes fade {
+                from { opacity: 0; }
+                to { opacity: 1; }
+            }
+            "#
+        );
+        STYLES.with_borrow_mut(|styles| styles.push(style));
+        rsx! {
+            div { class: style, "Card" }
+        }
+    }
+
+    let html = render(app);
+    let style = STYLES.with_borrow(|styles| styles[0]);
+    let class = style.class();
+    assert!(class.starts_with("dx-"));
+    assert_eq!(html, format!("<div class=\"{class}\">Card</div>"));
+    assert_eq!(
+        style.css(),
+        format!(
+            ".{class}{{padding: 1rem;color: red}}\
+             .{class}:hover,.{class}.active{{color: blue;}}\
+             .{class} .title,.{class} p > span{{font-weight: bold;}}\
+             @media (max-width: 600px){{.{class}{{padding: 0.5rem}}.{class} .title{{font-size: 12px;}}}}\
+             @keyframes fade{{from {{ opacity: 0; }} to {{ opacity: 1; }}}}"
+        )
+    );
+}
+
+#[test]
+fn identical_css_shares_a_class() {
+    fn app() -> Element {
+        let first = css!("color: red;");
+        let second = css!("color: red;");
+        let other = css!("color: blue;");
+        STYLES.with_borrow_mut(|styles| styles.extend([first, second, other]));
+        rsx! {
+            div { class: "{first} extra" }
+        }
+    }
+
+    let html = render(app);
+    let [first, second, other] = STYLES.with_borrow(|styles| [styles[0], styles[1], styles[2]]);
+    assert_eq!(first, second);
+    assert_ne!(first.class(), other.class());
+    assert_eq!(html, format!("<div class=\"{first} extra\"></div>"));
+}
@@ -63,3 +63,31 @@ fn event_handlers_compile() {
         }
     }
 }
+
+// Handlers that only set a value can skip the closure and values can be cloned into a closure with `#[clone(...)]`
+#[test]
+#[allow(unused)]
+fn event_handler_shorthands_compile() {
+    fn app() -> Element {
+        let mut count = use_signal(|| 0);
+        let name = "Dioxus".to_string();
+        rsx! {
+            button { onclick: count += 1, "Increment" }
+            button { onclick: handlers::set(count, 0), "Reset" }
+            button {
+                onclick: #[clone(name)] move |_| println!("Hello {name}"),
+                "Greet"
+            }
+            TakesEventHandler { onclick: count -= 1 }
+            TakesEventHandler { onclick: #[clone(name)] move |_| println!("Bye {name}") }
+            "{name}"
+        }
+    }
+
+    #[component]
+    fn TakesEventHandler(onclick: EventHandler<MouseEvent>) -> Element {
+        rsx! {
+            button { onclick, "Click!" }
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use dioxus::prelude::*;
+
+#[test]
+fn html_matches_rsx() {
+    let from_html = dioxus_ssr::render_element(html!(
+        r#"
+        <div id="main" class="card big" data-x="1">
+            <h1>Hello {world} &amp; friends</h1>
+            <input disabled>
+        </div>
+        "#
+    ));
+
+    let from_rsx = dioxus_ssr::render_element(rsx! {
+        div { id: "main", class: "card big", "data-x": "1",
+            h1 { "Hello {{world}} & friends" }
+            input { disabled: "true" }
+        }
+    });
+
+    assert_eq!(from_html, from_rsx);
+}
+
+#[test]
+fn html_svg() {
+    assert_eq!(
+        dioxus_ssr::render_element(html!(
+            r#"<svg viewBox="0 0 10 10"><circle cx="5" cy="5" r="4"></circle></svg>"#
+        )),
+        r#"<svg viewBox="0 0 10 10"><circle cx="5" cy="5" r="4"></circle></svg>"#
+    );
+}
+
+#[test]
+fn html_web_components() {
+    assert_eq!(
+        dioxus_ssr::render_element(html!(
+            r#"<my-widget size="large"><!-- ignored --></my-widget>"#
+        )),
+        r#"<my-widget size="large"></my-widget>"#
+    );
+}
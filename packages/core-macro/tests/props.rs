@@ -0,0 +1,70 @@
+use dioxus::prelude::*;
+
+#[derive(Props, Clone, PartialEq)]
+struct InputProps {
+    /// `type` is a keyword, so the field is renamed in rsx
+    #[props(rename = kind)]
+    r#type: String,
+
+    #[props(alias = text, alias = label)]
+    value: String,
+
+    #[props(default = 10, validate = check_size)]
+    size: u32,
+
+    // Optional props accept the value, an option or anything that converts into the option
+    placeholder: Option<String>,
+}
+
+fn check_size(size: &u32) -> Result<(), String> {
+    match size {
+        1..=100 => Ok(()),
+        _ => Err(format!("{size} is not between 1 and 100")),
+    }
+}
+
+#[allow(non_snake_case)]
+fn Input(props: InputProps) -> Element {
+    rsx! {
+        input {
+            r#type: props.r#type,
+            value: props.value,
+            size: props.size as i64,
+            placeholder: props.placeholder,
+        }
+    }
+}
+
+#[test]
+fn renamed_and_aliased_props() {
+    fn app() -> Element {
+        let placeholder = Some("Name".to_string());
+        rsx! {
+            Input { kind: "text", value: "a" }
+            Input { kind: "text", text: "b", size: 5, placeholder: "Search" }
+            Input { kind: "text", label: "c", placeholder }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        "<input type=\"text\" value=\"a\" size=10/>\
+         <input type=\"text\" value=\"b\" size=5 placeholder=\"Search\"/>\
+         <input type=\"text\" value=\"c\" size=10 placeholder=\"Name\"/>"
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "Invalid value for the prop `size` of `InputProps`: 0 is not between 1 and 100"
+)]
+fn invalid_props_panic() {
+    // The props are checked when they are built from rsx
+    InputProps::builder()
+        .kind("text")
+        .value("a")
+        .size(0)
+        .build();
+}
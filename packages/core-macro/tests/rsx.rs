@@ -2,6 +2,8 @@
 fn rsx() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/rsx/trailing-comma-0.rs");
+    t.compile_fail("tests/rsx/typed-children.rs");
+    t.compile_fail("tests/rsx/unknown-attributes.rs");
 }
 
 /// This test ensures that automatic `into` conversion occurs for default values.
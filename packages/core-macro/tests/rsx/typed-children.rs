@@ -0,0 +1,30 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+
+#[component]
+fn MenuItem(label: String) -> Element {
+    rsx! { li { "{label}" } }
+}
+
+#[component]
+fn Menu(children: ChildrenOf<MenuItemProps>) -> Element {
+    rsx! { ul { {children} } }
+}
+
+#[component]
+fn Divider() -> Element {
+    rsx! { hr {} }
+}
+
+fn app() -> Element {
+    rsx! {
+        Menu {
+            MenuItem { label: "Home" }
+            Divider {}
+            div { "Not a menu item" }
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use dioxus::prelude::*;
+
+fn main() {
+    _ = rsx! {
+        div {
+            clas: "container",
+            onclck: move |_| {},
+            "data-custom": "allowed",
+            my-element { anything: "allowed" }
+        }
+    };
+}
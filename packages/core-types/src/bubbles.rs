@@ -98,3 +98,16 @@ pub fn event_bubbles(evt: &str) -> bool {
         _ => true,
     }
 }
+
+/// Pack the flags of a listener into the byte the interpreter reads when it creates or removes the listener
+///
+/// - Bit 0: the event bubbles
+/// - Bit 1: the listener sets `passive`
+/// - Bit 2: the value of `passive`
+pub fn listener_flags(evt: &str, passive: Option<bool>) -> u8 {
+    let mut flags = event_bubbles(evt) as u8;
+    if let Some(passive) = passive {
+        flags |= 0b10 | ((passive as u8) << 2);
+    }
+    flags
+}
@@ -15,12 +15,15 @@ pub(crate) trait AnyProps: 'static {
     fn props_mut(&mut self) -> &mut dyn Any;
     /// Duplicate this component into a new boxed component.
     fn duplicate(&self) -> BoxedAnyProps;
+    /// Get a debug representation of the props if they implement Debug.
+    fn debug_snapshot(&self) -> Option<String>;
 }
 
 /// A component along with the props the component uses to render.
 pub(crate) struct VProps<F: ComponentFunction<P, M>, P, M> {
     render_fn: F,
     memo: fn(&mut P, &P) -> bool,
+    debug: fn(&P) -> Option<String>,
     props: P,
     name: &'static str,
     phantom: std::marker::PhantomData<M>,
@@ -31,6 +34,7 @@ impl<F: ComponentFunction<P, M>, P: Clone, M> Clone for VProps<F, P, M> {
         Self {
             render_fn: self.render_fn.clone(),
             memo: self.memo,
+            debug: self.debug,
             props: self.props.clone(),
             name: self.name,
             phantom: std::marker::PhantomData,
@@ -43,12 +47,14 @@ impl<F: ComponentFunction<P, M> + Clone, P: Clone + 'static, M: 'static> VProps<
     pub fn new(
         render_fn: F,
         memo: fn(&mut P, &P) -> bool,
+        debug: fn(&P) -> Option<String>,
         props: P,
         name: &'static str,
     ) -> VProps<F, P, M> {
         VProps {
             render_fn,
             memo,
+            debug,
             props,
             name,
             phantom: std::marker::PhantomData,
@@ -70,6 +76,10 @@ impl<F: ComponentFunction<P, M> + Clone, P: Clone + 'static, M: 'static> AnyProp
         &self.props
     }
 
+    fn debug_snapshot(&self) -> Option<String> {
+        (self.debug)(&self.props)
+    }
+
     fn props_mut(&mut self) -> &mut dyn Any {
         &mut self.props
     }
@@ -94,6 +104,7 @@ impl<F: ComponentFunction<P, M> + Clone, P: Clone + 'static, M: 'static> AnyProp
         Box::new(Self {
             render_fn: self.render_fn.clone(),
             memo: self.memo,
+            debug: self.debug,
             props: self.props.clone(),
             name: self.name,
             phantom: std::marker::PhantomData,
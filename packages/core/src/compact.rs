@@ -0,0 +1,103 @@
+//! Release memory that the [`VirtualDom`] keeps around for reuse.
+//!
+//! The slabs that hold scopes, element ids and mounts never shrink on their own. After a large part of the tree is
+//! removed, the empty slots stay allocated so the next nodes can reuse them. Apps that run for a long time can call
+//! [`VirtualDom::compact`] once they are idle to give that memory back.
+
+use crate::{scope_context::Scope, scopes::ScopeId, virtual_dom::VirtualDom, Runtime};
+
+/// The number of slots [`VirtualDom::compact`] released
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompactStats {
+    /// Slots for the state of scopes
+    pub scopes: usize,
+    /// Slots for element ids
+    pub elements: usize,
+    /// Slots for the mounts of rsx blocks
+    pub mounts: usize,
+}
+
+impl CompactStats {
+    /// The total number of slots that were released
+    pub fn total(&self) -> usize {
+        self.scopes + self.elements + self.mounts
+    }
+}
+
+impl VirtualDom {
+    /// Shrink the arenas of the virtual dom and the buffers of every scope to what the current tree needs.
+    ///
+    /// Ids of live scopes and elements never change, so this is safe to call at any time between renders. It is not
+    /// free: the next renders that grow the tree allocate again. Call it after a large part of the tree was removed or
+    /// when the app has been idle for a while, not after every render.
+    ///
+    /// Signals and other generational boxes are not affected. Their storage is reused for new boxes instead of being
+    /// freed so handles to dropped boxes stay safe to check.
+    ///
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// # fn app() -> Element { rsx! { div {} } }
+    /// let mut dom = VirtualDom::new(app);
+    /// dom.rebuild_in_place();
+    /// let released = dom.compact();
+    /// println!("released {} slots", released.total());
+    /// ```
+    pub fn compact(&mut self) -> CompactStats {
+        let scopes_before = self.scopes.capacity();
+        self.scopes.shrink_to_fit();
+        self.resolved_scopes.shrink_to_fit();
+        let mut stats = self.runtime.compact();
+        stats.scopes += scopes_before - self.scopes.capacity();
+        stats
+    }
+}
+
+impl Runtime {
+    fn compact(&self) -> CompactStats {
+        let mut scope_states = self.scope_states.borrow_mut();
+        // Scopes are created at the index of their id, so the empty slots after the last live scope can be dropped
+        let live = scope_states
+            .iter()
+            .rposition(Option::is_some)
+            .map_or(0, |last| last + 1);
+        scope_states.truncate(live);
+        scope_states.shrink_to_fit();
+        for scope in scope_states.iter().flatten() {
+            scope.compact();
+        }
+        drop(scope_states);
+
+        let mut elements = self.elements.borrow_mut();
+        let elements_before = elements.capacity();
+        elements.shrink_to_fit();
+        let mut mounts = self.mounts.borrow_mut();
+        let mounts_before = mounts.capacity();
+        mounts.shrink_to_fit();
+
+        CompactStats {
+            scopes: 0,
+            elements: elements_before - elements.capacity(),
+            mounts: mounts_before - mounts.capacity(),
+        }
+    }
+}
+
+impl Scope {
+    fn compact(&self) {
+        self.hooks.borrow_mut().shrink_to_fit();
+        self.shared_contexts.borrow_mut().shrink_to_fit();
+        self.spawned_tasks.borrow_mut().shrink_to_fit();
+        self.before_render.borrow_mut().shrink_to_fit();
+        self.after_render.borrow_mut().shrink_to_fit();
+        self.keyed_diff.borrow_mut().shrink_to_fit();
+    }
+}
+
+impl ScopeId {
+    /// Shrink the buffers of this scope, like the list of its hooks, contexts and spawned tasks, to what it currently
+    /// needs. This is a hint for scopes that won't grow again for a while, like a scope that spawned many tasks that
+    /// have all finished. [`VirtualDom::compact`] compacts every scope.
+    pub fn compact(self) {
+        _ = Runtime::with_scope(self, |scope| scope.compact());
+    }
+}
@@ -6,12 +6,13 @@ use std::{
 use crate::{
     any_props::AnyProps,
     innerlude::{
-        ElementRef, MountId, ScopeOrder, SuspenseBoundaryProps, SuspenseBoundaryPropsWithOwner,
-        VComponent, WriteMutations,
+        ElementRef, MountId, RenderPhase, ScopeOrder, SuspenseBoundaryProps,
+        SuspenseBoundaryPropsWithOwner, VComponent, WriteMutations,
     },
     nodes::{AsVNode, VNode},
     prelude::SuspenseContext,
     scopes::ScopeId,
+    trace_spans::{self, RenderTrigger},
     virtual_dom::VirtualDom,
     Element,
 };
@@ -21,12 +22,14 @@ impl VirtualDom {
         &mut self,
         to: Option<&mut M>,
         scope_id: ScopeId,
+        trigger: RenderTrigger,
     ) {
         let scope = &mut self.scopes[scope_id.0];
         if SuspenseBoundaryProps::downcast_from_props(&mut *scope.props).is_some() {
+            let _span = trace_spans::diff(&self.runtime, scope_id).entered();
             SuspenseBoundaryProps::diff(scope_id, self, to)
         } else {
-            let new_nodes = self.run_scope(scope_id);
+            let new_nodes = self.run_scope(scope_id, trigger);
             self.diff_scope(to, scope_id, new_nodes);
         }
     }
@@ -38,6 +41,8 @@ impl VirtualDom {
         scope: ScopeId,
         new_nodes: Element,
     ) {
+        self.phase_started(scope, RenderPhase::Diff);
+        let span = trace_spans::diff(&self.runtime, scope).entered();
         self.runtime.clone().with_scope_on_stack(scope, || {
             // We don't diff the nodes if the scope is suspended or has an error
             let Ok(new_real_nodes) = &new_nodes else {
@@ -59,7 +64,9 @@ impl VirtualDom {
             if render_to.is_some() {
                 self.runtime.get_state(scope).unwrap().mount(&self.runtime);
             }
-        })
+        });
+        drop(span);
+        self.phase_finished(scope, RenderPhase::Diff);
     }
 
     /// Create a new [`ScopeState`] for a component that has been created with [`VirtualDom::create_scope`]
@@ -73,7 +80,9 @@ impl VirtualDom {
         new_nodes: Element,
         parent: Option<ElementRef>,
     ) -> usize {
-        self.runtime.clone().with_scope_on_stack(scope, || {
+        self.phase_started(scope, RenderPhase::Diff);
+        let span = trace_spans::diff(&self.runtime, scope).entered();
+        let nodes = self.runtime.clone().with_scope_on_stack(scope, || {
             // If there are suspended scopes, we need to check if the scope is suspended before we diff it
             // If it is suspended, we need to diff it but write the mutations nothing
             // Note: It is important that we still diff the scope even if it is suspended, because the scope may render other child components which may change between renders
@@ -92,7 +101,10 @@ impl VirtualDom {
             }
 
             nodes
-        })
+        });
+        drop(span);
+        self.phase_finished(scope, RenderPhase::Diff);
+        nodes
     }
 
     pub(crate) fn remove_component_node<M: WriteMutations>(
@@ -153,7 +165,7 @@ impl VNode {
         }
 
         // Now diff the scope
-        dom.run_and_diff_scope(to, scope_id);
+        dom.run_and_diff_scope(to, scope_id, RenderTrigger::PropsChanged);
 
         let height = dom.runtime.get_state(scope_id).unwrap().height;
         dom.dirty_scopes.remove(&ScopeOrder::new(height, scope_id));
@@ -200,7 +212,11 @@ impl VNode {
         // If the scopeid is a placeholder, we need to load up a new scope for this vcomponent. If it's already mounted, then we can just use that
         if scope_id.is_placeholder() {
             scope_id = dom
-                .new_scope(component.props.duplicate(), component.name)
+                .new_scope(
+                    component.props.duplicate(),
+                    component.name,
+                    component.location(),
+                )
                 .state()
                 .id;
 
@@ -208,7 +224,7 @@ impl VNode {
             dom.set_mounted_dyn_node(mount, idx, scope_id.0);
 
             // If this is a new scope, we also need to run it once to get the initial state
-            let new = dom.run_scope(scope_id);
+            let new = dom.run_scope(scope_id, RenderTrigger::Mount);
 
             // Then set the new node as the last rendered node
             dom.scopes[scope_id.0].last_rendered_node = Some(new);
@@ -26,7 +26,9 @@ impl VirtualDom {
         );
 
         if new_is_keyed && old_is_keyed {
+            let changes = self.keyed_changes_before_diff(old, new);
             self.diff_keyed_children(to, old, new, parent);
+            self.keyed_changes_after_diff(changes, new);
         } else {
             self.diff_non_keyed_children(to, old, new, parent);
         }
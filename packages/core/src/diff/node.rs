@@ -123,8 +123,20 @@ impl VNode {
                 let old_mount = dom.get_mounted_dyn_node(mount, idx);
                 dom.set_mounted_dyn_node(mount, idx, usize::MAX);
 
+                // A keyed list that replaces a placeholder or is replaced by one inserts or removes every child
+                if let Fragment(old) = old {
+                    dom.keyed_removals(old);
+                }
+                let insertions = match new {
+                    Fragment(new) => dom.keyed_insertions(new),
+                    _ => None,
+                };
+
                 let new_nodes_on_stack =
                     self.create_dynamic_node(new, mount, idx, dom, to.as_deref_mut());
+                if let Fragment(new) = new {
+                    dom.keyed_changes_after_diff(insertions, new);
+                }
 
                 // Restore the mount for the scope we are removing
                 let new_mount = dom.get_mounted_dyn_node(mount, idx);
@@ -439,7 +451,18 @@ impl VNode {
                                 let new = new_attributes_iter.next().unwrap();
                                 // Volatile attributes are attributes that the browser may override so we always update them
                                 let volatile = old.volatile;
-                                if volatile || old.value != new.value {
+                                if old.listener_options != new.listener_options {
+                                    // The renderer registers the listener with the options, so register it again
+                                    self.remove_attribute(old, attribute_id, to);
+                                    self.write_attribute(
+                                        path,
+                                        new,
+                                        attribute_id,
+                                        mount_id,
+                                        dom,
+                                        to,
+                                    );
+                                } else if volatile || old.value != new.value {
                                     self.write_attribute(
                                         path,
                                         new,
@@ -479,7 +502,11 @@ impl VNode {
     fn remove_attribute(&self, attribute: &Attribute, id: ElementId, to: &mut impl WriteMutations) {
         match &attribute.value {
             AttributeValue::Listener(_) => {
-                to.remove_event_listener(&attribute.name[2..], id);
+                to.remove_event_listener_with_options(
+                    &attribute.name[2..],
+                    id,
+                    attribute.listener_options,
+                );
             }
             _ => {
                 to.set_attribute(
@@ -509,7 +536,11 @@ impl VNode {
                 };
                 let mut elements = dom.runtime.elements.borrow_mut();
                 elements[id.0] = Some(element_ref);
-                to.create_event_listener(&attribute.name[2..], id);
+                to.create_event_listener_with_options(
+                    &attribute.name[2..],
+                    id,
+                    attribute.listener_options,
+                );
             }
             _ => {
                 to.set_attribute(attribute.name, attribute.namespace, &attribute.value, id);
@@ -3,7 +3,7 @@ use crate::{
     Properties, ScopeId, Template, TemplateAttribute, TemplateNode, VNode,
 };
 use std::{
-    any::{Any, TypeId},
+    any::Any,
     backtrace::Backtrace,
     cell::{Ref, RefCell},
     error::Error,
@@ -269,6 +269,96 @@ impl ErrorContext {
     pub fn clear_errors(&self) {
         self.errors.borrow_mut().clear();
     }
+
+    /// Clear all errors and render the children of the Error Boundary again. The children are created from scratch,
+    /// so this can be used to retry a part of the app that failed.
+    pub fn reset(&self) {
+        self.clear_errors();
+        self.id.needs_update();
+    }
+
+    /// Get the first error thrown from child components with the concrete error type `T`
+    ///
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// fn App() -> Element {
+    ///     rsx! {
+    ///         ErrorBoundary {
+    ///             handle_error: |errors: ErrorContext| {
+    ///                 if let Some(error) = errors.downcast::<std::num::ParseIntError>() {
+    ///                     rsx! { "Invalid number: {error}" }
+    ///                 } else {
+    ///                     rsx! { "Something went wrong" }
+    ///                 }
+    ///             },
+    ///             Counter {}
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// fn Counter() -> Element {
+    ///     let count: i32 = "not a number".parse()?;
+    ///     rsx! { "{count}" }
+    /// }
+    /// ```
+    pub fn downcast<T: 'static>(&self) -> Option<Ref<'_, T>> {
+        Ref::filter_map(self.errors.borrow(), |errors| {
+            errors.iter().find_map(|error| error.downcast::<T>())
+        })
+        .ok()
+    }
+}
+
+/// Throw errors into the nearest [`ErrorBoundary`] from code that can't return them with `?`, like event handlers that
+/// don't return a result or spawned futures.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn Component() -> Element {
+///     let mut count = use_signal(|| 0);
+///     rsx! {
+///         input {
+///             oninput: move |event| {
+///                 // Throws the error and skips the update if the value isn't a number
+///                 if let Some(value) = event.value().parse::<i32>().throw() {
+///                     count.set(value);
+///                 }
+///             }
+///         }
+///     }
+/// }
+/// ```
+pub trait Throw<T>: Sized {
+    /// Throw the error into the nearest error boundary of the current scope and return `None`, or return the value
+    /// if there is no error
+    fn throw(self) -> Option<T> {
+        self.throw_into(current_scope_id().unwrap_or_else(|e| panic!("{}", e)))
+    }
+
+    /// Throw the error into the nearest error boundary of a specific scope and return `None`, or return the value if
+    /// there is no error
+    fn throw_into(self, scope: ScopeId) -> Option<T>;
+}
+
+impl<T, E: Into<CapturedError>> Throw<T> for std::result::Result<T, E> {
+    fn throw_into(self, scope: ScopeId) -> Option<T> {
+        match self {
+            std::result::Result::Ok(value) => Some(value),
+            Err(error) => {
+                throw_into(error, scope);
+                None
+            }
+        }
+    }
+}
+
+impl<T> Throw<T> for Option<T> {
+    fn throw_into(self, scope: ScopeId) -> Option<T> {
+        if self.is_none() {
+            throw_into(CapturedError::from_display("Value was none"), scope);
+        }
+        self
+    }
 }
 
 /// Errors can have additional context added as they bubble up the render tree
@@ -485,11 +575,7 @@ impl Display for CapturedError {
 impl CapturedError {
     /// Downcast the error type into a concrete error type
     pub fn downcast<T: 'static>(&self) -> Option<&T> {
-        if TypeId::of::<T>() == (*self.error).type_id() {
-            self.error.as_any().downcast_ref::<T>()
-        } else {
-            None
-        }
+        self.error.as_any().downcast_ref::<T>()
     }
 }
 
@@ -654,6 +740,15 @@ impl<__children> ErrorBoundaryPropsBuilder<(__children, ())> {
             fields: (children, handle_error),
         }
     }
+
+    /// Set the handler that renders the errors. This is filled by the `else` block of an `ErrorBoundary` in rsx and
+    /// infers the type of the closure argument.
+    pub fn fallback(
+        self,
+        fallback: impl Fn(ErrorContext) -> Element + 'static,
+    ) -> ErrorBoundaryPropsBuilder<(__children, (ErrorHandler,))> {
+        self.handle_error(fallback)
+    }
 }
 #[doc(hidden)]
 #[allow(dead_code, non_camel_case_types, non_snake_case)]
@@ -668,6 +763,14 @@ impl<__children> ErrorBoundaryPropsBuilder<(__children, (ErrorHandler,))> {
     ) -> ErrorBoundaryPropsBuilder<(__children, (ErrorHandler,))> {
         self
     }
+
+    #[deprecated(note = "Repeated field handle_error")]
+    pub fn fallback(
+        self,
+        _: ErrorBoundaryPropsBuilder_Error_Repeated_field_handle_error,
+    ) -> ErrorBoundaryPropsBuilder<(__children, (ErrorHandler,))> {
+        self
+    }
 }
 #[allow(dead_code, non_camel_case_types, missing_docs)]
 impl<
@@ -733,6 +836,26 @@ impl<
 /// Error boundaries are an easy way to handle errors in your application.
 /// They are similar to `try/catch` in JavaScript, but they only catch errors in the tree below them.
 /// Error boundaries are quick to implement, but it can be useful to individually handle errors in your components to provide a better user experience when you know that an error is likely to occur.
+///
+/// ## Retrying
+///
+/// The fallback receives the [`ErrorContext`] of the boundary. Calling [`ErrorContext::reset`] clears the errors and renders the children again from scratch:
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # fn Counter() -> Element { unimplemented!() }
+/// fn App() -> Element {
+///     rsx! {
+///         ErrorBoundary {
+///             handle_error: |errors: ErrorContext| rsx! {
+///                 "Failed to load the counter"
+///                 button { onclick: move |_| errors.reset(), "Retry" }
+///             },
+///             Counter {}
+///         }
+///     }
+/// }
+/// ```
 #[allow(non_upper_case_globals, non_snake_case)]
 pub fn ErrorBoundary(props: ErrorBoundaryProps) -> Element {
     let error_boundary = use_hook(provide_error_boundary);
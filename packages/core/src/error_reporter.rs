@@ -0,0 +1,159 @@
+use crate::{
+    innerlude::VirtualDomPlugin, runtime::Runtime, scopes::ScopeId, virtual_dom::VirtualDom,
+};
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+use std::panic::Location;
+
+/// Where an [`ErrorReport`] came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorSource {
+    /// A component returned an error while rendering
+    Render,
+    /// An event handler panicked
+    EventHandler {
+        /// The name of the event, like `click`
+        event: String,
+    },
+    /// A task panicked while it was polled
+    Task {
+        /// The location of the code that spawned the task
+        spawned_at: &'static Location<'static>,
+    },
+    /// The renderer failed to hydrate the html that was rendered on the server
+    Hydration,
+}
+
+impl Display for ErrorSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorSource::Render => write!(f, "render"),
+            ErrorSource::EventHandler { event } => write!(f, "{event} handler"),
+            ErrorSource::Task { spawned_at } => write!(f, "task spawned at {spawned_at}"),
+            ErrorSource::Hydration => write!(f, "hydration"),
+        }
+    }
+}
+
+/// A component in the component stack of an [`ErrorReport`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentFrame {
+    /// The scope of the component
+    pub scope: ScopeId,
+    /// The name of the component
+    pub name: &'static str,
+    /// The location of the code that rendered the component. This is only tracked in debug builds.
+    pub rendered_at: Option<&'static Location<'static>>,
+}
+
+/// An error that was reported to the plugins of a [`VirtualDom`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorReport {
+    /// Where the error came from
+    pub source: ErrorSource,
+    /// The message of the error, or the payload of the panic
+    pub message: String,
+    /// The components the error happened in, starting with the innermost component. This is empty if the error is
+    /// not tied to a component.
+    pub component_stack: Vec<ComponentFrame>,
+}
+
+impl Display for ErrorReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Error in {}: {}", self.source, self.message)?;
+        for frame in &self.component_stack {
+            write!(f, "\n    in {}", frame.name)?;
+            if let Some(rendered_at) = frame.rendered_at {
+                write!(f, " (rendered at {rendered_at})")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Runtime {
+    /// Report an error to the [`VirtualDomPlugin::on_error`] hook of every plugin. The component stack is collected
+    /// from the scope the error happened in.
+    ///
+    /// Renderers use this to report errors the VirtualDom can't see, like hydration failures.
+    pub fn report_error(&self, source: ErrorSource, message: String, scope: Option<ScopeId>) {
+        if self.plugins.borrow().is_empty() {
+            return;
+        }
+        let report = ErrorReport {
+            source,
+            message,
+            component_stack: scope
+                .map(|scope| self.component_stack(scope))
+                .unwrap_or_default(),
+        };
+        self.notify_plugins(|plugin| plugin.on_error(&report));
+    }
+
+    /// Report a caught panic and return its payload so it can be resumed
+    pub(crate) fn report_panic(
+        &self,
+        source: ErrorSource,
+        scope: ScopeId,
+        payload: Box<dyn Any + Send>,
+    ) -> Box<dyn Any + Send> {
+        let message = if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "Box<dyn Any>".to_string()
+        };
+        self.report_error(source, message, Some(scope));
+        payload
+    }
+
+    /// The components from a scope up to the root of the VirtualDom
+    pub fn component_stack(&self, scope: ScopeId) -> Vec<ComponentFrame> {
+        let mut stack = Vec::new();
+        let mut current = Some(scope);
+        while let Some(scope) = current.and_then(|id| self.get_state(id)) {
+            stack.push(ComponentFrame {
+                scope: scope.id,
+                name: scope.name,
+                rendered_at: scope.created_at,
+            });
+            current = scope.parent_id;
+        }
+        stack
+    }
+}
+
+struct ErrorReporter<F>(F);
+
+impl<F: Fn(&ErrorReport) + 'static> VirtualDomPlugin for ErrorReporter<F> {
+    fn on_error(&self, report: &ErrorReport) {
+        (self.0)(report)
+    }
+}
+
+impl VirtualDom {
+    /// Build the virtualdom with a reporter that is called with every error components return while rendering, every
+    /// panic in an event handler or task and every hydration failure. This is a [`VirtualDomPlugin`] that only
+    /// implements [`VirtualDomPlugin::on_error`].
+    ///
+    /// Panics are reported before they continue to unwind, so they still crash the app like they would without a
+    /// reporter. Panics can only be reported on platforms that unwind, which excludes the web.
+    ///
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx! { "hello world" }
+    /// }
+    ///
+    /// let mut dom = VirtualDom::new(app).with_error_reporter(|report| {
+    ///     // Send the report to your telemetry service instead
+    ///     tracing::error!("{report}");
+    /// });
+    /// dom.rebuild_in_place();
+    /// ```
+    pub fn with_error_reporter(self, reporter: impl Fn(&ErrorReport) + 'static) -> Self {
+        self.with_plugin(ErrorReporter(reporter))
+    }
+}
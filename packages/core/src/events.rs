@@ -504,6 +504,62 @@ impl<Args: 'static, Ret: 'static> Callback<Args, Ret> {
         move |args| self.call(args)
     }
 
+    /// Create a new [`Callback`] that calls this callback and then `other` with the same arguments. The value
+    /// returned by `other` is returned.
+    ///
+    /// This is useful to run an internal handler in a component along with the handler passed in the props. The
+    /// default callback does nothing, so optional handlers don't need to be matched.
+    ///
+    /// Like [`Callback::new`], the new callback is owned by the current scope and is only dropped with the scope.
+    /// Create it once in a hook instead of in the body of the component. Callbacks passed in props keep the same
+    /// handle when the parent reruns, so the combined callback always calls the latest handler:
+    ///
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// #[component]
+    /// fn Toggle(#[props(default)] onclick: EventHandler<MouseEvent>) -> Element {
+    ///     let mut on = use_signal(|| false);
+    ///     let toggle = use_callback(move |_: MouseEvent| on.toggle());
+    ///     let onclick = use_hook(|| toggle.then(onclick));
+    ///     rsx! {
+    ///         button { onclick, if on() { "on" } else { "off" } }
+    ///     }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn then(self, other: Callback<Args, Ret>) -> Self
+    where
+        Args: Clone,
+    {
+        Callback::new::<Ret, ()>(move |args: Args| {
+            self.call(args.clone());
+            other.call(args)
+        })
+    }
+
+    /// Create a new [`Callback`] that accepts a different argument and converts it with `f` before calling this
+    /// callback.
+    ///
+    /// Like [`Callback::then`], the new callback is owned by the current scope and should be created once in a hook:
+    ///
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// #[component]
+    /// fn Search(onsearch: EventHandler<String>) -> Element {
+    ///     let onchange = use_hook(|| onsearch.map(|event: FormEvent| event.value()));
+    ///     rsx! {
+    ///         input { onchange }
+    ///     }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn map<NewArgs: 'static>(
+        self,
+        mut f: impl FnMut(NewArgs) -> Args + 'static,
+    ) -> Callback<NewArgs, Ret> {
+        Callback::new::<Ret, ()>(move |args: NewArgs| self.call(f(args)))
+    }
+
     /// Forcibly drop the internal handler callback, releasing memory
     ///
     /// This will force any future calls to "call" to not doing anything
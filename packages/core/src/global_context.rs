@@ -1,5 +1,10 @@
 use crate::runtime::RuntimeError;
-use crate::{innerlude::SuspendedFuture, runtime::Runtime, CapturedError, Element, ScopeId, Task};
+use crate::tasks::TaskType;
+use crate::{
+    innerlude::{LayoutPhase, SuspendedFuture},
+    runtime::Runtime,
+    CapturedError, Element, ScopeId, Task,
+};
 use std::future::Future;
 use std::sync::Arc;
 
@@ -116,8 +121,10 @@ pub fn suspend(task: Task) -> Element {
 /// ```
 ///
 #[doc = include_str!("../docs/common_spawn_errors.md")]
+#[track_caller]
 pub fn spawn_isomorphic(fut: impl Future<Output = ()> + 'static) -> Task {
-    Runtime::with_current_scope(|cx| cx.spawn_isomorphic(fut)).unwrap()
+    let spawned_at = std::panic::Location::caller();
+    Runtime::with_current_scope(|cx| cx.spawn_at(fut, TaskType::Isomorphic, spawned_at)).unwrap()
 }
 
 /// Spawns the future but does not return the [`Task`]. This task will automatically be canceled when the component is dropped.
@@ -142,8 +149,10 @@ pub fn spawn_isomorphic(fut: impl Future<Output = ()> + 'static) -> Task {
 /// ```
 ///
 #[doc = include_str!("../docs/common_spawn_errors.md")]
+#[track_caller]
 pub fn spawn(fut: impl Future<Output = ()> + 'static) -> Task {
-    Runtime::with_current_scope(|cx| cx.spawn(fut)).unwrap()
+    let spawned_at = std::panic::Location::caller();
+    Runtime::with_current_scope(|cx| cx.spawn_at(fut, TaskType::ClientOnly, spawned_at)).unwrap()
 }
 
 /// Queue an effect to run after the next render. You generally shouldn't need to interact with this function directly. [use_effect](https://docs.rs/dioxus-hooks/latest/dioxus_hooks/fn.use_effect.html) will call this function for you.
@@ -151,6 +160,21 @@ pub fn queue_effect(f: impl FnOnce() + 'static) {
     Runtime::with_current_scope(|cx| cx.queue_effect(f)).unwrap()
 }
 
+/// Queue a callback that measures the DOM after the next render. Read callbacks run once the mutations of the render
+/// are applied and before any [`queue_layout_write`] callback, so measuring does not force the layout to be
+/// recalculated between writes.
+///
+/// You generally shouldn't need to interact with this function directly. [use_layout_effect](https://docs.rs/dioxus-hooks/latest/dioxus_hooks/fn.use_layout_effect.html) will call this function for you.
+pub fn queue_layout_read(f: impl FnOnce() + 'static) {
+    Runtime::with_current_scope(|cx| cx.queue_layout(LayoutPhase::Read, f)).unwrap()
+}
+
+/// Queue a callback that mutates the DOM after the next render. Write callbacks run after every [`queue_layout_read`]
+/// callback of the same render.
+pub fn queue_layout_write(f: impl FnOnce() + 'static) {
+    Runtime::with_current_scope(|cx| cx.queue_layout(LayoutPhase::Write, f)).unwrap()
+}
+
 /// Spawn a future that Dioxus won't clean up when this component is unmounted
 ///
 /// This is good for tasks that need to be run after the component has been dropped.
@@ -203,8 +227,13 @@ pub fn queue_effect(f: impl FnOnce() + 'static) {
 /// ```
 ///
 #[doc = include_str!("../docs/common_spawn_errors.md")]
+#[track_caller]
 pub fn spawn_forever(fut: impl Future<Output = ()> + 'static) -> Option<Task> {
-    Runtime::with_scope(ScopeId::ROOT, |cx| cx.spawn(fut)).ok()
+    let spawned_at = std::panic::Location::caller();
+    Runtime::with_scope(ScopeId::ROOT, |cx| {
+        cx.spawn_at(fut, TaskType::ClientOnly, spawned_at)
+    })
+    .ok()
 }
 
 /// Informs the scheduler that this task is no longer needed and should be removed.
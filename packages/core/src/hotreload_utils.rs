@@ -273,6 +273,7 @@ impl DynamicValuePool {
             HotReloadDynamicNode::Formatted(segments) => DynamicNode::Text(VText {
                 value: self.literal_pool.render_formatted(segments),
             }),
+            HotReloadDynamicNode::Placeholder => DynamicNode::default(),
         }
     }
 
@@ -304,6 +305,7 @@ impl DynamicValuePool {
                     }
                 },
                 volatile: false,
+                listener_options: Default::default(),
             }]),
         }
     }
@@ -427,6 +429,8 @@ impl HotReloadedTemplate {
 pub enum HotReloadDynamicNode {
     Dynamic(usize),
     Formatted(FmtedSegments),
+    /// A placeholder for a body that no longer renders anything
+    Placeholder,
 }
 
 #[doc(hidden)]
@@ -0,0 +1,204 @@
+//! Notify components about the keyed children the diff inserts, moves and removes.
+//!
+//! Animation layers need to know when a keyed child is about to leave the DOM or is moved to a new position. A
+//! component can register a listener with [`use_keyed_diff`]. The listener is called for the keyed lists the
+//! component renders itself, not for the lists of child components.
+
+use crate::innerlude::use_hook;
+use crate::{ElementId, Runtime, ScopeId, VNode, VirtualDom};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// What the diff did with a keyed child
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyedChange {
+    /// The child was created and inserted into the list
+    Inserted,
+    /// The child was moved to a new position in the list. Children that stay in place while others are inserted or
+    /// removed around them are not moved.
+    Moved,
+    /// The child is about to be removed from the list. The listener is called before the nodes are removed, so the
+    /// element of the child is still mounted.
+    Removed,
+}
+
+/// A keyed child the diff inserted, moved or removed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyedDiffEvent {
+    /// The key of the child
+    pub key: String,
+    /// What happened to the child
+    pub change: KeyedChange,
+    /// The first root element of the child, if the child has one
+    pub element: Option<ElementId>,
+}
+
+/// Call `f` for every keyed child of this component that the diff inserts, moves or removes.
+///
+/// The listener runs while the diff is running. It must not read the DOM, but it can write signals or queue effects
+/// that run once the mutations are applied.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_core::KeyedChange;
+/// #[component]
+/// fn List(items: Vec<u32>) -> Element {
+///     use_keyed_diff(|event| {
+///         if event.change == KeyedChange::Removed {
+///             println!("row {} is leaving", event.key);
+///         }
+///     });
+///     rsx! {
+///         for item in items {
+///             li { key: "{item}", "{item}" }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_keyed_diff(f: impl FnMut(&KeyedDiffEvent) + 'static) {
+    use_hook(|| on_keyed_diff(f));
+}
+
+/// Push a listener for the keyed children of the current component. This is not a hook: every call adds another
+/// listener. See [`use_keyed_diff`].
+pub fn on_keyed_diff(f: impl FnMut(&KeyedDiffEvent) + 'static) {
+    let _ = Runtime::with_current_scope(|cx| cx.push_keyed_diff(f));
+}
+
+/// The changes of a keyed diff that are reported after the diff, once the new children are mounted
+pub(crate) struct PendingKeyedChanges {
+    scope: ScopeId,
+    changes: Vec<(usize, KeyedChange)>,
+}
+
+impl VirtualDom {
+    /// Report the children of `old` that are about to be removed and return the insertions and moves to report
+    /// once `new` is mounted. Returns `None` without comparing the lists if nobody listens.
+    pub(crate) fn keyed_changes_before_diff(
+        &self,
+        old: &[VNode],
+        new: &[VNode],
+    ) -> Option<PendingKeyedChanges> {
+        let scope = self.keyed_diff_listener()?;
+
+        let old_indexes = old
+            .iter()
+            .enumerate()
+            .filter_map(|(i, node)| Some((node.key.as_deref()?, i)))
+            .collect::<FxHashMap<_, _>>();
+        let new_keys = new
+            .iter()
+            .filter_map(|node| node.key.as_deref())
+            .collect::<FxHashSet<_>>();
+
+        for node in old {
+            if let Some(key) = node.key.as_deref().filter(|key| !new_keys.contains(key)) {
+                self.notify_keyed_diff(scope, node, key, KeyedChange::Removed);
+            }
+        }
+
+        // The children that keep their relative order are the longest increasing run of old indexes. Every other
+        // shared child is moved.
+        let shared = new
+            .iter()
+            .enumerate()
+            .filter_map(|(i, node)| Some((i, *old_indexes.get(node.key.as_deref()?)?)))
+            .collect::<Vec<_>>();
+        let old_order = shared.iter().map(|(_, old)| *old).collect::<Vec<_>>();
+        let mut stay = Vec::with_capacity(old_order.len());
+        let mut allocation = vec![0; old_order.len() * 2];
+        let (predecessors, starts) = allocation.split_at_mut(old_order.len());
+        longest_increasing_subsequence::lis_with(
+            &old_order,
+            &mut stay,
+            |a, b| a < b,
+            predecessors,
+            starts,
+        );
+        let stay = stay.into_iter().collect::<FxHashSet<_>>();
+
+        let mut changes = Vec::new();
+        let mut shared = shared.iter().enumerate().peekable();
+        for (i, node) in new.iter().enumerate() {
+            match shared.peek() {
+                Some((shared_idx, (new_idx, _))) if *new_idx == i => {
+                    if !stay.contains(shared_idx) {
+                        changes.push((i, KeyedChange::Moved));
+                    }
+                    shared.next();
+                }
+                _ if node.key.is_some() => changes.push((i, KeyedChange::Inserted)),
+                _ => {}
+            }
+        }
+
+        Some(PendingKeyedChanges { scope, changes })
+    }
+
+    /// Report that every keyed child in `old` is about to be removed
+    pub(crate) fn keyed_removals(&self, old: &[VNode]) {
+        let Some(scope) = self.keyed_diff_listener() else {
+            return;
+        };
+        for node in old {
+            if let Some(key) = node.key.as_deref() {
+                self.notify_keyed_diff(scope, node, key, KeyedChange::Removed);
+            }
+        }
+    }
+
+    /// Return the insertions to report once every keyed child in `new` is mounted
+    pub(crate) fn keyed_insertions(&self, new: &[VNode]) -> Option<PendingKeyedChanges> {
+        let scope = self.keyed_diff_listener()?;
+        let changes = new
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| node.key.is_some())
+            .map(|(i, _)| (i, KeyedChange::Inserted))
+            .collect();
+        Some(PendingKeyedChanges { scope, changes })
+    }
+
+    /// Report the changes that were waiting for `new` to be mounted
+    pub(crate) fn keyed_changes_after_diff(
+        &self,
+        pending: Option<PendingKeyedChanges>,
+        new: &[VNode],
+    ) {
+        let Some(pending) = pending else {
+            return;
+        };
+        for (i, change) in pending.changes {
+            let node = &new[i];
+            if let Some(key) = node.key.as_deref() {
+                self.notify_keyed_diff(pending.scope, node, key, change);
+            }
+        }
+    }
+
+    /// The scope that is being diffed if it listens for keyed changes
+    fn keyed_diff_listener(&self) -> Option<ScopeId> {
+        let scope = self.runtime.current_scope_id().ok()?;
+        let listening = !self
+            .scopes
+            .get(scope.0)?
+            .state()
+            .keyed_diff
+            .borrow()
+            .is_empty();
+        listening.then_some(scope)
+    }
+
+    fn notify_keyed_diff(&self, scope: ScopeId, node: &VNode, key: &str, change: KeyedChange) {
+        let Some(scope) = self.scopes.get(scope.0) else {
+            return;
+        };
+        let event = KeyedDiffEvent {
+            key: key.to_string(),
+            change,
+            element: node.mounted_root(0, self),
+        };
+        for listener in scope.state().keyed_diff.borrow_mut().iter_mut() {
+            listener(&event);
+        }
+    }
+}
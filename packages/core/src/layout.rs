@@ -0,0 +1,106 @@
+//! Separate layout reads from layout writes after the DOM is updated.
+//!
+//! Measuring an element right after another component changed the DOM forces the browser to recalculate the layout.
+//! If every component measures and then mutates in turn, the layout is recalculated once per component. Layout
+//! callbacks are split into two phases instead: every read queued during a render runs first, then every write. The
+//! layout is calculated once for all of the reads and the writes are batched before the next paint.
+
+use crate::innerlude::ScopeOrder;
+use crate::scope_context::ScopeStatus;
+use crate::{Runtime, ScopeId, VirtualDom};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+/// The phase a layout callback runs in. All [`LayoutPhase::Read`] callbacks run before any [`LayoutPhase::Write`]
+/// callback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LayoutPhase {
+    /// Measure the DOM. Read callbacks run after the mutations of the render are applied, but before the next paint.
+    Read,
+    /// Mutate the DOM based on the measurements taken in the read phase.
+    Write,
+}
+
+type LayoutCallbacks = BTreeMap<ScopeOrder, Vec<Box<dyn FnOnce() + 'static>>>;
+
+/// The layout callbacks that are waiting for the next render to be applied
+#[derive(Default)]
+pub(crate) struct LayoutQueue {
+    reads: RefCell<LayoutCallbacks>,
+    writes: RefCell<LayoutCallbacks>,
+}
+
+impl LayoutQueue {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.reads.borrow().is_empty() && self.writes.borrow().is_empty()
+    }
+
+    fn phase(&self, phase: LayoutPhase) -> &RefCell<LayoutCallbacks> {
+        match phase {
+            LayoutPhase::Read => &self.reads,
+            LayoutPhase::Write => &self.writes,
+        }
+    }
+
+    fn push(&self, order: ScopeOrder, phase: LayoutPhase, f: Box<dyn FnOnce() + 'static>) {
+        self.phase(phase)
+            .borrow_mut()
+            .entry(order)
+            .or_default()
+            .push(f);
+    }
+
+    /// Take all callbacks of a phase, parents first
+    fn take(&self, phase: LayoutPhase) -> LayoutCallbacks {
+        std::mem::take(&mut *self.phase(phase).borrow_mut())
+    }
+}
+
+impl Runtime {
+    /// Queue a layout callback to run after the next render
+    pub(crate) fn queue_layout(&self, id: ScopeId, phase: LayoutPhase, f: impl FnOnce() + 'static) {
+        let f = Box::new(f) as Box<dyn FnOnce() + 'static>;
+        let Some(scope) = self.get_state(id) else {
+            return;
+        };
+        let mut status = scope.status.borrow_mut();
+        match &mut *status {
+            ScopeStatus::Mounted => {
+                self.queue_layout_on_mounted_scope(id, phase, f);
+            }
+            ScopeStatus::Unmounted { layout_queued, .. } => {
+                layout_queued.push((phase, f));
+            }
+        }
+    }
+
+    /// Queue a layout callback to run after the next render without checking if the scope is mounted
+    pub(crate) fn queue_layout_on_mounted_scope(
+        &self,
+        id: ScopeId,
+        phase: LayoutPhase,
+        f: Box<dyn FnOnce() + 'static>,
+    ) {
+        self.pending_layout
+            .push(ScopeOrder::new(id.height(), id), phase, f);
+    }
+}
+
+impl VirtualDom {
+    /// Run every queued read callback and then every queued write callback. Callbacks of scopes that were removed
+    /// since they were queued are skipped.
+    pub(crate) fn run_layout(&mut self) {
+        self.runtime.rendering.set(false);
+        for phase in [LayoutPhase::Read, LayoutPhase::Write] {
+            for (order, callbacks) in self.runtime.pending_layout.take(phase) {
+                if !self.scopes.contains(order.id.0) {
+                    continue;
+                }
+                for f in callbacks {
+                    f();
+                }
+            }
+        }
+        self.runtime.rendering.set(true);
+    }
+}
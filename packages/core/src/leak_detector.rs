@@ -0,0 +1,188 @@
+use crate::{scopes::ScopeId, virtual_dom::VirtualDom, Runtime, Task};
+use generational_box::leak::{LeakTracker, LiveBox};
+use std::fmt::{Display, Formatter};
+use std::panic::Location;
+use std::rc::{Rc, Weak};
+
+/// A scope whose state was never dropped
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeakedScope {
+    /// The id of the scope
+    pub id: ScopeId,
+    /// The name of the component the scope rendered
+    pub name: &'static str,
+    /// The location of the code that rendered the component. This is only tracked in debug builds.
+    pub created_at: Option<&'static Location<'static>>,
+}
+
+/// A task that was never dropped
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeakedTask {
+    /// The id of the task
+    pub task: Task,
+    /// The scope the task was spawned in
+    pub scope: ScopeId,
+    /// The location of the code that spawned the task
+    pub spawned_at: &'static Location<'static>,
+}
+
+/// Everything that outlived a [`VirtualDom`] with a leak detector
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeakReport {
+    /// Scopes whose state is still in the runtime after every scope was dropped
+    pub scopes: Vec<LeakedScope>,
+    /// Tasks that are still in the runtime after every scope was dropped. These are usually tasks spawned with
+    /// [`Runtime::spawn`](crate::Runtime::spawn) that are not owned by a scope.
+    pub tasks: Vec<LeakedTask>,
+    /// Generational boxes, like signals, that were created in the runtime of the VirtualDom and are still alive after
+    /// the VirtualDom was dropped. These are usually held by a leaked task, a cloned owner or a `leak`ed value. Boxes
+    /// are only tracked in debug builds.
+    pub boxes: Vec<LiveBox>,
+    /// The number of references to the runtime of the VirtualDom that are still alive after the VirtualDom was
+    /// dropped. The runtime and everything it owns can't be dropped while these exist.
+    pub runtime_references: usize,
+}
+
+impl LeakReport {
+    /// Check if nothing leaked
+    pub fn is_empty(&self) -> bool {
+        self.scopes.is_empty()
+            && self.tasks.is_empty()
+            && self.boxes.is_empty()
+            && self.runtime_references == 0
+    }
+}
+
+impl Display for LeakReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "The VirtualDom leaked when it was dropped:")?;
+        for scope in &self.scopes {
+            write!(f, "- scope {:?} ({})", scope.id, scope.name)?;
+            match scope.created_at {
+                Some(created_at) => writeln!(f, " rendered at {created_at}")?,
+                None => writeln!(f)?,
+            }
+        }
+        for task in &self.tasks {
+            writeln!(
+                f,
+                "- task spawned at {} in scope {:?}",
+                task.spawned_at, task.scope
+            )?;
+        }
+        for live in &self.boxes {
+            writeln!(f, "- generational box created at {}", live.created_at)?;
+        }
+        if self.runtime_references > 0 {
+            writeln!(
+                f,
+                "- {} references to the runtime are still alive",
+                self.runtime_references
+            )?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) struct LeakDetector {
+    boxes: LeakTracker,
+    runtime: Weak<Runtime>,
+    scopes: Vec<LeakedScope>,
+    tasks: Vec<LeakedTask>,
+    on_leaks: Option<Box<dyn FnOnce(LeakReport)>>,
+}
+
+impl Drop for LeakDetector {
+    // The detector is dropped after the runtime of the VirtualDom, so everything the runtime owned has been dropped
+    fn drop(&mut self) {
+        let Some(on_leaks) = self.on_leaks.take() else {
+            return;
+        };
+        if std::thread::panicking() {
+            return;
+        }
+
+        let report = LeakReport {
+            scopes: std::mem::take(&mut self.scopes),
+            tasks: std::mem::take(&mut self.tasks),
+            boxes: self.boxes.live_boxes(),
+            runtime_references: self.runtime.strong_count(),
+        };
+        if !report.is_empty() {
+            on_leaks(report);
+        }
+    }
+}
+
+impl VirtualDom {
+    /// Build the virtualdom with a leak detector that logs a warning if scopes, tasks or generational boxes outlive
+    /// the VirtualDom when it is dropped.
+    ///
+    /// Only generational boxes created in the runtime of this VirtualDom after the leak detector is attached are
+    /// tracked.
+    pub fn with_leak_detection(self) -> Self {
+        self.with_leak_detector(|report| tracing::warn!("{report}"))
+    }
+
+    /// Build the virtualdom with a leak detector that calls a handler if scopes, tasks or generational boxes outlive
+    /// the VirtualDom when it is dropped. The handler is not called if nothing leaked.
+    ///
+    /// This is useful to catch leaks in tests:
+    ///
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// fn app() -> Element {
+    ///     let count = use_signal(|| 0);
+    ///     rsx! { "{count}" }
+    /// }
+    ///
+    /// let mut dom = VirtualDom::new(app).with_leak_detector(|report| panic!("{report}"));
+    /// dom.rebuild_in_place();
+    /// drop(dom);
+    /// ```
+    pub fn with_leak_detector(mut self, on_leaks: impl FnOnce(LeakReport) + 'static) -> Self {
+        // Only record the boxes created while this runtime is active, so boxes of other VirtualDoms on the same
+        // thread are not reported
+        let boxes = LeakTracker::start();
+        self.runtime.leak_scope.set(Some(boxes.scope()));
+        self.leak_detector = Some(LeakDetector {
+            boxes,
+            runtime: Rc::downgrade(&self.runtime),
+            scopes: Vec::new(),
+            tasks: Vec::new(),
+            on_leaks: Some(Box::new(on_leaks)),
+        });
+        self
+    }
+
+    /// Record the scopes and tasks that are still in the runtime. This is called after every scope is dropped.
+    pub(crate) fn record_leaks(&mut self) {
+        let Some(detector) = &mut self.leak_detector else {
+            return;
+        };
+
+        detector.scopes = self
+            .runtime
+            .scope_states
+            .borrow()
+            .iter()
+            .flatten()
+            .map(|scope| LeakedScope {
+                id: scope.id,
+                name: scope.name,
+                created_at: scope.created_at,
+            })
+            .collect();
+        detector.tasks = self
+            .runtime
+            .tasks
+            .borrow()
+            .iter()
+            .map(|(id, task)| LeakedTask {
+                task: Task::from_id(id),
+                scope: task.scope,
+                spawned_at: task.spawned_at,
+            })
+            .collect();
+    }
+}
@@ -5,18 +5,25 @@
 
 mod any_props;
 mod arena;
+mod compact;
 mod diff;
 mod effect;
 mod error_boundary;
+mod error_reporter;
 mod events;
 mod fragment;
 mod generational_box;
 mod global_context;
+mod keyed_diff;
+mod layout;
+mod leak_detector;
 mod mutations;
 mod nodes;
+mod plugin;
 mod properties;
 mod reactive_context;
 mod render_error;
+mod render_observer;
 mod root_wrapper;
 mod runtime;
 mod scheduler;
@@ -25,6 +32,9 @@ mod scope_context;
 mod scopes;
 mod suspense;
 mod tasks;
+mod template_registry;
+mod trace_spans;
+mod typed_children;
 mod virtual_dom;
 
 mod hotreload_utils;
@@ -34,6 +44,15 @@ mod hotreload_utils;
 pub mod internal {
     pub use crate::properties::verify_component_called_as_component;
 
+    #[doc(hidden)]
+    pub use crate::properties::{DebugPropsProbe, DebugPropsSnapshot, NoDebugPropsSnapshot};
+
+    #[doc(hidden)]
+    pub use crate::typed_children::{
+        AcceptsChild, AnyChildren, ChildrenCheck, ChildrenChecker, NonComponentNode,
+        TypedChildrenBuilder, TypedChildrenChecker, UntypedChildrenChecker,
+    };
+
     #[doc(hidden)]
     pub use crate::hotreload_utils::{
         DynamicLiteralPool, DynamicValuePool, FmtSegment, FmtedSegments, HotReloadAttributeValue,
@@ -45,22 +64,31 @@ pub mod internal {
 pub(crate) mod innerlude {
     pub(crate) use crate::any_props::*;
     pub use crate::arena::*;
+    pub use crate::compact::*;
     pub(crate) use crate::effect::*;
     pub use crate::error_boundary::*;
+    pub use crate::error_reporter::*;
     pub use crate::events::*;
     pub use crate::fragment::*;
     pub use crate::generational_box::*;
     pub use crate::global_context::*;
+    pub use crate::keyed_diff::*;
+    pub use crate::layout::*;
+    pub use crate::leak_detector::*;
     pub use crate::mutations::*;
     pub use crate::nodes::*;
+    pub use crate::plugin::*;
     pub use crate::properties::*;
     pub use crate::reactive_context::*;
     pub use crate::render_error::*;
+    pub use crate::render_observer::*;
     pub use crate::runtime::{Runtime, RuntimeGuard};
     pub use crate::scheduler::*;
     pub use crate::scopes::*;
     pub use crate::suspense::*;
     pub use crate::tasks::*;
+    pub use crate::template_registry::*;
+    pub use crate::typed_children::ChildrenOf;
     pub use crate::virtual_dom::*;
 
     /// An [`Element`] is a possibly-none [`VNode`] created by calling `render` on [`ScopeId`] or [`ScopeState`].
@@ -74,11 +102,14 @@ pub(crate) mod innerlude {
 
 pub use crate::innerlude::{
     fc_to_builder, generation, schedule_update, schedule_update_any, use_hook, vdom_is_rendering,
-    AnyValue, Attribute, AttributeValue, CapturedError, Component, ComponentFunction, DynamicNode,
-    Element, ElementId, Event, Fragment, HasAttributes, IntoDynNode, MarkerWrapper, Mutation,
-    Mutations, NoOpMutations, Ok, Properties, Result, Runtime, ScopeId, ScopeState, SpawnIfAsync,
-    Task, Template, TemplateAttribute, TemplateNode, VComponent, VNode, VNodeInner, VPlaceholder,
-    VText, VirtualDom, WriteMutations,
+    AnyValue, Attribute, AttributeValue, CapturedError, ChildrenOf, CompactStats, Component,
+    ComponentFrame, ComponentFunction, DynamicNode, Element, ElementId, ErrorReport, ErrorSource,
+    Event, Fragment, HasAttributes, IntoDynNode, KeyedChange, KeyedDiffEvent, LayoutPhase,
+    LeakReport, LeakedScope, LeakedTask, ListenerOptions, MarkerWrapper, Mutation, Mutations,
+    NoOpMutations, Ok, Properties, RenderObserver, RenderPhase, Result, Runtime, ScopeId,
+    ScopeState, SpawnIfAsync, Task, Template, TemplateAttribute, TemplateNode, TemplateRegistry,
+    VComponent, VNode, VNodeInner, VPlaceholder, VText, VirtualDom, VirtualDomPlugin,
+    WriteMutations,
 };
 
 /// The purpose of this module is to alleviate imports of many common types
@@ -88,16 +119,17 @@ pub mod prelude {
     pub use crate::innerlude::{
         consume_context, consume_context_from_scope, current_owner, current_scope_id,
         fc_to_builder, generation, has_context, needs_update, needs_update_any, parent_scope,
-        provide_context, provide_error_boundary, provide_root_context, queue_effect, remove_future,
-        schedule_update, schedule_update_any, spawn, spawn_forever, spawn_isomorphic, suspend,
-        throw_error, try_consume_context, use_after_render, use_before_render, use_drop, use_hook,
-        use_hook_with_cleanup, with_owner, AnyValue, Attribute, Callback, Component,
+        provide_context, provide_error_boundary, provide_root_context, queue_effect,
+        queue_layout_read, queue_layout_write, remove_future, schedule_update, schedule_update_any,
+        spawn, spawn_forever, spawn_isomorphic, suspend, throw_error, try_consume_context,
+        untracked, use_after_render, use_before_render, use_drop, use_hook, use_hook_with_cleanup,
+        use_keyed_diff, with_owner, AnyValue, Attribute, Callback, ChildrenOf, Component,
         ComponentFunction, Context, Element, ErrorBoundary, ErrorContext, Event, EventHandler,
-        Fragment, HasAttributes, IntoAttributeValue, IntoDynNode, OptionStringFromMarker,
-        Properties, ReactiveContext, RenderError, Runtime, RuntimeGuard, ScopeId, ScopeState,
-        SuperFrom, SuperInto, SuspendedFuture, SuspenseBoundary, SuspenseBoundaryProps,
-        SuspenseContext, SuspenseExtension, Task, Template, TemplateAttribute, TemplateNode, VNode,
-        VNodeInner, VirtualDom,
+        Fragment, HasAttributes, IntoAttributeValue, IntoDynNode, LayoutPhase,
+        OptionStringFromMarker, Properties, ReactiveContext, RenderError, Runtime, RuntimeGuard,
+        ScopeId, ScopeState, SuperFrom, SuperInto, SuspendedFuture, SuspenseBoundary,
+        SuspenseBoundaryProps, SuspenseContext, SuspenseExtension, Task, Template,
+        TemplateAttribute, TemplateNode, Throw, VNode, VNodeInner, VirtualDom,
     };
 }
 
@@ -1,4 +1,4 @@
-use crate::{arena::ElementId, AttributeValue, Template};
+use crate::{arena::ElementId, AttributeValue, ListenerOptions, Template};
 
 /// Something that can handle the mutations that are generated by the diffing process and apply them to the Real DOM
 ///
@@ -106,6 +106,31 @@ pub trait WriteMutations {
     /// Id: The ID of the node to remove.
     fn remove_event_listener(&mut self, name: &'static str, id: ElementId);
 
+    /// Create a new Event Listener with the [`ListenerOptions`] of the handler.
+    ///
+    /// Renderers that support listener options should override this method. By default, the options are ignored and
+    /// [`WriteMutations::create_event_listener`] is called.
+    fn create_event_listener_with_options(
+        &mut self,
+        name: &'static str,
+        id: ElementId,
+        _options: ListenerOptions,
+    ) {
+        self.create_event_listener(name, id);
+    }
+
+    /// Remove an existing Event Listener that was created with [`WriteMutations::create_event_listener_with_options`].
+    ///
+    /// By default, the options are ignored and [`WriteMutations::remove_event_listener`] is called.
+    fn remove_event_listener_with_options(
+        &mut self,
+        name: &'static str,
+        id: ElementId,
+        _options: ListenerOptions,
+    ) {
+        self.remove_event_listener(name, id);
+    }
+
     /// Remove a particular node from the DOM
     ///
     /// Id: The ID of the node to remove.
@@ -410,6 +410,72 @@ impl Template {
         use TemplateNode::*;
         self.roots.iter().all(|root| matches!(root, Dynamic { .. }))
     }
+
+    /// A content addressed id for this template.
+    ///
+    /// Unlike the [`std::hash::Hash`] implementation which hashes the addresses of the static template, this id is
+    /// derived from the structure of the template itself. Two binaries that contain the same `rsx!` call will produce
+    /// the same id, even if they are built with different feature flags or for different targets. This makes it
+    /// suitable for checking that the server and client agree on the templates they are hydrating.
+    pub fn stable_id(&self) -> u64 {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = StableHasher::default();
+        self.roots.hash(&mut hasher);
+        self.node_paths.hash(&mut hasher);
+        self.attr_paths.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Check if two templates have the same structure, even if they live at different addresses
+    pub fn same_structure(&self, other: &Self) -> bool {
+        self == other
+            || (self.roots == other.roots
+                && self.node_paths == other.node_paths
+                && self.attr_paths == other.attr_paths)
+    }
+}
+
+/// A FNV-1a hasher that produces the same output on every platform. Pointer sized integers are always hashed as 64 bit
+/// integers so 32 bit targets (like wasm) and 64 bit targets agree.
+struct StableHasher(u64);
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self(0xcbf29ce484222325)
+    }
+}
+
+impl std::hash::Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write(&(i as u64).to_le_bytes());
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write(&(i as i64).to_le_bytes());
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
 }
 
 /// A statically known node in a layout.
@@ -543,6 +609,10 @@ pub struct VComponent {
 
     /// The props for this component
     pub(crate) props: BoxedAnyProps,
+
+    /// The location of the code that created this component, usually the `rsx!` call that renders it
+    #[cfg(debug_assertions)]
+    pub(crate) location: &'static std::panic::Location<'static>,
 }
 
 impl Clone for VComponent {
@@ -551,12 +621,15 @@ impl Clone for VComponent {
             name: self.name,
             render_fn: self.render_fn,
             props: self.props.duplicate(),
+            #[cfg(debug_assertions)]
+            location: self.location,
         }
     }
 }
 
 impl VComponent {
     /// Create a new [`VComponent`] variant
+    #[track_caller]
     pub fn new<P, M: 'static>(
         component: impl ComponentFunction<P, M>,
         props: P,
@@ -569,6 +642,7 @@ impl VComponent {
         let props = Box::new(VProps::new(
             component,
             <P as Properties>::memoize,
+            <P as Properties>::debug_snapshot,
             props,
             fn_name,
         ));
@@ -577,9 +651,21 @@ impl VComponent {
             name: fn_name,
             props,
             render_fn,
+            #[cfg(debug_assertions)]
+            location: std::panic::Location::caller(),
         }
     }
 
+    /// The location of the code that created this component, usually the `rsx!` call that renders it.
+    ///
+    /// Locations are only tracked in debug builds. This always returns [`None`] in release builds.
+    pub fn location(&self) -> Option<&'static std::panic::Location<'static>> {
+        #[cfg(debug_assertions)]
+        return Some(self.location);
+        #[cfg(not(debug_assertions))]
+        return None;
+    }
+
     /// Get the [`ScopeId`] this node is mounted to if it's mounted
     ///
     /// This is useful for rendering nodes outside of the VirtualDom, such as in SSR
@@ -715,6 +801,9 @@ pub struct Attribute {
 
     /// An indication of we should always try and set the attribute. Used in controlled components to ensure changes are propagated
     pub volatile: bool,
+
+    /// How the listener is registered if this attribute is an event listener. Ignored for other attributes.
+    pub(crate) listener_options: ListenerOptions,
 }
 
 impl Attribute {
@@ -733,8 +822,47 @@ impl Attribute {
             namespace,
             volatile,
             value: value.into_value(),
+            listener_options: ListenerOptions::default(),
         }
     }
+
+    /// Set the [`ListenerOptions`] of this attribute. The rsx! macro calls this for event handlers with `#[capture]` or
+    /// `#[passive(...)]` attributes on the closure.
+    pub fn with_listener_options(mut self, listener_options: ListenerOptions) -> Attribute {
+        self.listener_options = listener_options;
+        self
+    }
+
+    /// Get the [`ListenerOptions`] of this attribute
+    pub fn listener_options(&self) -> ListenerOptions {
+        self.listener_options
+    }
+}
+
+/// Options for an event listener, set with attributes on the closure of the handler in rsx:
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// rsx! {
+///     div {
+///         // Runs before the listeners of the button
+///         onclick: #[capture] move |_| println!("clicked inside the div"),
+///         // Lets the handler call prevent_default to stop the page from scrolling
+///         onwheel: #[passive(false)] move |event| event.prevent_default(),
+///         button { onclick: move |_| println!("clicked the button") }
+///     }
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ListenerOptions {
+    /// Call the listener in the capture phase, while the event travels from the root down to the target. Capture
+    /// listeners run before any listener of the bubbling phase. Only events that bubble have a capture phase.
+    pub capture: bool,
+
+    /// Tell the renderer whether the listener may prevent the default action of the event. Renderers can start the
+    /// default action, like scrolling, without waiting for passive listeners. `None` uses the default of the
+    /// platform.
+    pub passive: Option<bool>,
 }
 
 /// Any of the built-in values that the Dioxus VirtualDom supports as dynamic attributes on elements
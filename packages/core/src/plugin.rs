@@ -0,0 +1,99 @@
+use crate::{
+    innerlude::ErrorReport, runtime::Runtime, scopes::ScopeId, virtual_dom::VirtualDom, ElementId,
+    Event, RenderPhase,
+};
+use std::any::Any;
+use std::rc::Rc;
+
+/// An extension that hooks into the lifecycle of a [`VirtualDom`]. Plugins let crates like analytics, error reporting
+/// and devtools integrate with every renderer without forking it.
+///
+/// Every method has an empty default implementation, so plugins only implement the hooks they need. Plugins are called
+/// in the order they were added. Hooks take `&self` because they may be called while another hook is running, for
+/// example when a listener triggers a nested event. Use a `Cell` or `RefCell` for mutable state.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_core::{ElementId, VirtualDomPlugin};
+/// use std::cell::Cell;
+///
+/// #[derive(Default)]
+/// struct ClickCounter(Cell<usize>);
+///
+/// impl VirtualDomPlugin for ClickCounter {
+///     fn on_event(&self, name: &str, _element: ElementId, _event: &Event<dyn std::any::Any>) {
+///         if name == "click" {
+///             self.0.set(self.0.get() + 1);
+///         }
+///     }
+/// }
+///
+/// fn app() -> Element {
+///     rsx! { button { "Click me" } }
+/// }
+///
+/// let mut dom = VirtualDom::new(app).with_plugin(ClickCounter::default());
+/// dom.rebuild_in_place();
+/// ```
+pub trait VirtualDomPlugin: 'static {
+    /// Called before the VirtualDom builds the tree for the first time with [`VirtualDom::rebuild`]. The root scope
+    /// exists, so this is a good place to provide root contexts with [`VirtualDom::provide_root_context`].
+    fn on_build(&self, dom: &VirtualDom) {
+        _ = dom;
+    }
+
+    /// Called before a phase of the work for a scope starts, like running its component. `name` is the name of the
+    /// component. Phases nest like the phases of a [`RenderObserver`](crate::RenderObserver).
+    fn phase_started(&self, scope: ScopeId, name: &'static str, phase: RenderPhase) {
+        _ = (scope, name, phase);
+    }
+
+    /// Called after a phase of the work for a scope finishes. Errors the component returned are passed to
+    /// [`VirtualDomPlugin::on_error`] before the render phase finishes.
+    fn phase_finished(&self, scope: ScopeId, name: &'static str, phase: RenderPhase) {
+        _ = (scope, name, phase);
+    }
+
+    /// Called before an event is dispatched to the listeners of an element and its parents
+    fn on_event(&self, name: &str, element: ElementId, event: &Event<dyn Any>) {
+        _ = (name, element, event);
+    }
+
+    /// Called when a component returns an error, an event handler or task panics or the renderer fails to hydrate.
+    /// See [`VirtualDom::with_error_reporter`].
+    fn on_error(&self, report: &ErrorReport) {
+        _ = report;
+    }
+
+    /// Called when the VirtualDom is dropped, before any scope is dropped
+    fn on_teardown(&self, dom: &VirtualDom) {
+        _ = dom;
+    }
+}
+
+impl VirtualDom {
+    /// Add a [`VirtualDomPlugin`] that is called after every plugin that was added before it
+    pub fn add_plugin(&mut self, plugin: impl VirtualDomPlugin) {
+        self.runtime.plugins.borrow_mut().push(Rc::new(plugin));
+    }
+
+    /// Build the virtualdom with a [`VirtualDomPlugin`]
+    pub fn with_plugin(mut self, plugin: impl VirtualDomPlugin) -> Self {
+        self.add_plugin(plugin);
+        self
+    }
+}
+
+impl Runtime {
+    /// Call a hook of every plugin. The plugins are cloned out of the runtime first so hooks can add plugins or
+    /// trigger other hooks.
+    pub(crate) fn notify_plugins(&self, mut f: impl FnMut(&dyn VirtualDomPlugin)) {
+        if self.plugins.borrow().is_empty() {
+            return;
+        }
+        let plugins = self.plugins.borrow().clone();
+        for plugin in &plugins {
+            f(&**plugin);
+        }
+    }
+}
@@ -56,7 +56,14 @@ pub trait Properties: Clone + Sized + 'static {
     /// Make the old props equal to the new props. Return if the props were equal and should be memoized.
     fn memoize(&mut self, other: &Self) -> bool;
 
+    /// A debug representation of the props that devtools show in the component inspector. The Props derive
+    /// implements this for props that implement [`Debug`](std::fmt::Debug).
+    fn debug_snapshot(&self) -> Option<String> {
+        None
+    }
+
     /// Create a component from the props.
+    #[track_caller]
     fn into_vcomponent<M: 'static>(self, render_fn: impl ComponentFunction<Self, M>) -> VComponent {
         let type_name = std::any::type_name_of_val(&render_fn);
         VComponent::new(render_fn, self, type_name)
@@ -98,6 +105,34 @@ where
     }
 }
 
+/// Probes if props implement [`Debug`](std::fmt::Debug) without requiring it. The Props derive calls
+/// `(&&DebugPropsProbe(props)).debug_snapshot()`, which resolves to [`DebugPropsSnapshot`] if the props implement
+/// Debug and to [`NoDebugPropsSnapshot`] otherwise.
+#[doc(hidden)]
+pub struct DebugPropsProbe<'a, T>(pub &'a T);
+
+#[doc(hidden)]
+pub trait DebugPropsSnapshot {
+    fn debug_snapshot(&self) -> Option<String>;
+}
+
+impl<T: std::fmt::Debug> DebugPropsSnapshot for &DebugPropsProbe<'_, T> {
+    fn debug_snapshot(&self) -> Option<String> {
+        Some(format!("{:#?}", self.0))
+    }
+}
+
+#[doc(hidden)]
+pub trait NoDebugPropsSnapshot {
+    fn debug_snapshot(&self) -> Option<String>;
+}
+
+impl<T> NoDebugPropsSnapshot for DebugPropsProbe<'_, T> {
+    fn debug_snapshot(&self) -> Option<String> {
+        None
+    }
+}
+
 // We allow components to use the () generic parameter if they have no props. This impl enables the "build" method
 // that the macros use to anonymously complete prop construction.
 pub struct EmptyBuilder;
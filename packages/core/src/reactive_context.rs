@@ -5,7 +5,7 @@ use crate::{
     Runtime,
 };
 use futures_channel::mpsc::UnboundedReceiver;
-use generational_box::{GenerationalBox, SyncStorage};
+use generational_box::{GenerationalBox, GenerationalBoxId, SyncStorage};
 use std::{
     cell::RefCell,
     collections::HashSet,
@@ -47,6 +47,44 @@ impl std::fmt::Display for ReactiveContext {
     }
 }
 
+/// Run a closure without subscribing the current reactive context to anything read inside of it.
+///
+/// This is useful when you need the latest value of a signal inside a memo or effect, but changes to that signal
+/// shouldn't rerun the memo or effect. To read a single signal without subscribing, you can also use `peek`.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// fn app() -> Element {
+///     let mut count = use_signal(|| 0);
+///     let step = use_signal(|| 1);
+///
+///     // The effect only reruns when the count changes
+///     use_effect(move || {
+///         let count = count();
+///         let step = untracked(|| step());
+///         println!("count: {count}, step: {step}");
+///     });
+///
+///     rsx! {
+///         button { onclick: move |_| count += step(), "Increment" }
+///     }
+/// }
+/// ```
+pub fn untracked<O>(f: impl FnOnce() -> O) -> O {
+    /// Restores the outer reactive contexts, even if the closure panics
+    struct RestoreOuter(Vec<ReactiveContext>);
+
+    impl Drop for RestoreOuter {
+        fn drop(&mut self) {
+            let outer = std::mem::take(&mut self.0);
+            CURRENT.with(|current| *current.borrow_mut() = outer);
+        }
+    }
+
+    let _outer = RestoreOuter(CURRENT.with(|current| std::mem::take(&mut *current.borrow_mut())));
+    f()
+}
+
 impl ReactiveContext {
     /// Create a new reactive context
     #[track_caller]
@@ -254,6 +292,34 @@ impl ReactiveContext {
     pub fn origin_scope(&self) -> ScopeId {
         self.scope
     }
+
+    /// Get the unique id of this reactive context
+    pub fn id(&self) -> GenerationalBoxId {
+        self.inner.id()
+    }
+
+    /// Get the location this reactive context was created at. This is only tracked in debug builds.
+    pub fn origin(&self) -> Option<&'static std::panic::Location<'static>> {
+        #[cfg(debug_assertions)]
+        {
+            if let Ok(read) = self.inner.try_read() {
+                return Some(read.origin);
+            }
+        }
+        None
+    }
+
+    /// Get the scope this reactive context re-renders if it was created for a component instead of a hook like a
+    /// memo or effect. This is only tracked in debug builds.
+    pub fn rendered_scope(&self) -> Option<ScopeId> {
+        #[cfg(debug_assertions)]
+        {
+            if let Ok(read) = self.inner.try_read() {
+                return read.scope;
+            }
+        }
+        None
+    }
 }
 
 impl Hash for ReactiveContext {
@@ -0,0 +1,89 @@
+use crate::{scopes::ScopeId, virtual_dom::VirtualDom, VirtualDomPlugin};
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::rc::Rc;
+
+/// A phase of the work the [`VirtualDom`] does for a scope
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RenderPhase {
+    /// Running the component of the scope
+    Render,
+    /// Creating or diffing the nodes the scope rendered and writing the mutations
+    Diff,
+}
+
+impl Display for RenderPhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RenderPhase::Render => write!(f, "render"),
+            RenderPhase::Diff => write!(f, "diff"),
+        }
+    }
+}
+
+/// Observe the work the [`VirtualDom`] does for each scope. Profilers and other tools can be attached to a VirtualDom
+/// with [`VirtualDom::set_render_observer`]. The observer is notified like a [`VirtualDomPlugin`] that was added
+/// after every other plugin.
+///
+/// Phases nest. Creating or diffing a scope renders, creates and diffs any child components that are added or
+/// changed, so [`RenderObserver::phase_finished`] for a diff is called after the phases of its children.
+pub trait RenderObserver {
+    /// Called before a phase of a scope starts
+    fn phase_started(&mut self, scope: ScopeId, name: &'static str, phase: RenderPhase);
+
+    /// Called after a phase of a scope finishes
+    fn phase_finished(&mut self, scope: ScopeId, name: &'static str, phase: RenderPhase);
+}
+
+/// The plugin that forwards the phases of a VirtualDom to its [`RenderObserver`]
+pub(crate) struct ObserverPlugin(RefCell<Box<dyn RenderObserver>>);
+
+impl VirtualDomPlugin for ObserverPlugin {
+    fn phase_started(&self, scope: ScopeId, name: &'static str, phase: RenderPhase) {
+        self.0.borrow_mut().phase_started(scope, name, phase);
+    }
+
+    fn phase_finished(&self, scope: ScopeId, name: &'static str, phase: RenderPhase) {
+        self.0.borrow_mut().phase_finished(scope, name, phase);
+    }
+}
+
+impl VirtualDom {
+    /// Attach a [`RenderObserver`] that is notified about every render and diff. This replaces any existing observer.
+    pub fn set_render_observer(&mut self, observer: impl RenderObserver + 'static) {
+        self.take_render_observer();
+        let plugin = Rc::new(ObserverPlugin(RefCell::new(Box::new(observer))));
+        self.runtime.plugins.borrow_mut().push(plugin.clone());
+        self.render_observer = Some(plugin);
+    }
+
+    /// Build the virtualdom with a [`RenderObserver`] attached
+    pub fn with_render_observer(mut self, observer: impl RenderObserver + 'static) -> Self {
+        self.set_render_observer(observer);
+        self
+    }
+
+    /// Remove the current [`RenderObserver`] if there is one
+    pub fn take_render_observer(&mut self) -> Option<Box<dyn RenderObserver>> {
+        let plugin = self.render_observer.take()?;
+        self.runtime
+            .plugins
+            .borrow_mut()
+            .retain(|other| !std::ptr::addr_eq(Rc::as_ptr(other), Rc::as_ptr(&plugin)));
+        Rc::try_unwrap(plugin)
+            .ok()
+            .map(|plugin| plugin.0.into_inner())
+    }
+
+    pub(crate) fn phase_started(&self, scope: ScopeId, phase: RenderPhase) {
+        let name = self.scopes.get(scope.0).map_or("", |s| s.state().name);
+        self.runtime
+            .notify_plugins(|plugin| plugin.phase_started(scope, name, phase));
+    }
+
+    pub(crate) fn phase_finished(&self, scope: ScopeId, phase: RenderPhase) {
+        let name = self.scopes.get(scope.0).map_or("", |s| s.state().name);
+        self.runtime
+            .notify_plugins(|plugin| plugin.phase_finished(scope, name, phase));
+    }
+}
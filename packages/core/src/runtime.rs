@@ -1,19 +1,24 @@
 use crate::arena::ElementRef;
-use crate::innerlude::{DirtyTasks, Effect};
+use crate::innerlude::{DirtyTasks, Effect, LayoutQueue};
 use crate::nodes::VNodeMount;
 use crate::scope_context::SuspenseLocation;
+use crate::trace_spans;
+use crate::{
+    innerlude::{ErrorSource, ListenerCb},
+    AttributeValue, ElementId, Event, VirtualDomPlugin,
+};
 use crate::{
     innerlude::{LocalTask, SchedulerMsg},
     scope_context::Scope,
     scopes::ScopeId,
     Task,
 };
-use crate::{AttributeValue, ElementId, Event};
 use slab::Slab;
 use slotmap::DefaultKey;
 use std::any::Any;
 use std::collections::BTreeSet;
 use std::fmt;
+use std::panic::AssertUnwindSafe;
 use std::{
     cell::{Cell, Ref, RefCell},
     rc::Rc,
@@ -52,6 +57,9 @@ pub struct Runtime {
     // The effects that need to be run after the next render
     pub(crate) pending_effects: RefCell<BTreeSet<Effect>>,
 
+    // The layout reads and writes that need to be run after the next render
+    pub(crate) pending_layout: LayoutQueue,
+
     // Tasks that are waiting to be polled
     pub(crate) dirty_tasks: RefCell<BTreeSet<DirtyTasks>>,
 
@@ -63,6 +71,12 @@ pub struct Runtime {
     // We need to store this information on the virtual dom so that we know what nodes are mounted where when we bubble events
     // Each mount is associated with a whole rsx block. [`VirtualDom::elements`] link to a specific node in the block
     pub(crate) mounts: RefCell<Slab<VNodeMount>>,
+
+    // The plugins added to the virtual dom in the order they were added
+    pub(crate) plugins: RefCell<Vec<Rc<dyn VirtualDomPlugin>>>,
+
+    // The leak tracker that records the generational boxes created while this runtime is active
+    pub(crate) leak_scope: Cell<Option<generational_box::leak::LeakScope>>,
 }
 
 impl Runtime {
@@ -81,9 +95,12 @@ impl Runtime {
             tasks: Default::default(),
             suspended_tasks: Default::default(),
             pending_effects: Default::default(),
+            pending_layout: Default::default(),
             dirty_tasks: Default::default(),
             elements: RefCell::new(elements),
             mounts: Default::default(),
+            plugins: Default::default(),
+            leak_scope: Default::default(),
         })
     }
 
@@ -222,12 +239,18 @@ impl Runtime {
 
     /// Pushes a new scope onto the stack
     pub(crate) fn push(runtime: Rc<Runtime>) {
+        if let Some(leak_scope) = runtime.leak_scope.get() {
+            leak_scope.enter();
+        }
         RUNTIMES.with(|stack| stack.borrow_mut().push(runtime));
     }
 
     /// Pops a scope off the stack
     pub(crate) fn pop() {
-        RUNTIMES.with(|stack| stack.borrow_mut().pop());
+        let runtime = RUNTIMES.with(|stack| stack.borrow_mut().pop());
+        if let Some(leak_scope) = runtime.and_then(|runtime| runtime.leak_scope.get()) {
+            leak_scope.exit();
+        }
     }
 
     /// Runs a function with the current runtime
@@ -261,7 +284,7 @@ impl Runtime {
     /// Finish a render. This will mark all effects as ready to run and send the render signal.
     pub(crate) fn finish_render(&self) {
         // If there are new effects we can run, send a message to the scheduler to run them (after the renderer has applied the mutations)
-        if !self.pending_effects.borrow().is_empty() {
+        if !self.pending_effects.borrow().is_empty() || !self.pending_layout.is_empty() {
             self.sender
                 .unbounded_send(SchedulerMsg::EffectQueued)
                 .expect("Scheduler should exist");
@@ -292,6 +315,8 @@ impl Runtime {
     #[instrument(skip(self, event), level = "trace", name = "Runtime::handle_event")]
     pub fn handle_event(self: &Rc<Self>, name: &str, event: Event<dyn Any>, element: ElementId) {
         let _runtime = RuntimeGuard::new(self.clone());
+        let _span = trace_spans::event(name, element, event.propagates()).entered();
+        self.notify_plugins(|plugin| plugin.on_event(name, element, &event));
         let elements = self.elements.borrow();
 
         if let Some(Some(parent_path)) = elements.get(element.0).copied() {
@@ -303,15 +328,31 @@ impl Runtime {
         }
     }
 
+    /// Call one listener of an event. If the listener panics, the panic is reported to the plugins before it
+    /// continues to unwind.
+    fn call_listener(&self, name: &str, listener: &ListenerCb, event: Event<dyn Any>) {
+        let _span = trace_spans::listener(self, name, listener.origin).entered();
+        self.rendering.set(false);
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| listener.call(event)));
+        self.rendering.set(true);
+        if let Err(payload) = result {
+            let source = ErrorSource::EventHandler {
+                event: name.to_string(),
+            };
+            std::panic::resume_unwind(self.report_panic(source, listener.origin, payload));
+        }
+    }
+
     /*
     ------------------------
     The algorithm works by walking through the list of dynamic attributes, checking their paths, and breaking when
     we find the target path.
 
-    With the target path, we try and move up to the parent until there is no parent.
-    Due to how bubbling works, we call the listeners before walking to the parent.
+    With the target path, we try and move up to the parent until there is no parent, collecting the listeners from the
+    target up to the root.
 
-    If we wanted to do capturing, then we would accumulate all the listeners and call them in reverse order.
+    Capture listeners are called first, in reverse order from the root down to the target. Then the other listeners are
+    called from the target up to the root.
     ----------------------
 
     For a visual demonstration, here we present a tree on the left and whether or not a listener is collected on the
@@ -332,11 +373,14 @@ impl Runtime {
     fn handle_bubbling_event(&self, parent: ElementRef, name: &str, uievent: Event<dyn Any>) {
         let mounts = self.mounts.borrow();
 
+        // The listeners from the target element up to the root
+        let mut listeners = vec![];
+
         // If the event bubbles, we traverse through the tree until we find the target element.
         // Loop through each dynamic attribute (in a depth first order) in this template before moving up to the template's parent.
         let mut parent = Some(parent);
         while let Some(path) = parent {
-            let mut listeners = vec![];
+            let mut template_listeners = vec![];
 
             let Some(mount) = mounts.get(path.mount.0) else {
                 // If the node is suspended and not mounted, we can just ignore the event
@@ -346,14 +390,14 @@ impl Runtime {
             let node_template = el_ref.template;
             let target_path = path.path;
 
-            // Accumulate listeners into the listener list bottom to top
+            // Accumulate listeners into the listener list top to bottom
             for (idx, this_path) in node_template.attr_paths.iter().enumerate() {
                 let attrs = &*el_ref.dynamic_attrs[idx];
 
                 for attr in attrs.iter() {
                     // Remove the "on" prefix if it exists, TODO, we should remove this and settle on one
                     if attr.name.get(2..) == Some(name) && target_path.is_descendant(this_path) {
-                        listeners.push(&attr.value);
+                        template_listeners.push(attr);
 
                         // Break if this is the exact target element.
                         // This means we won't call two listeners with the same name on the same element. This should be
@@ -364,30 +408,37 @@ impl Runtime {
                     }
                 }
             }
-
-            // Now that we've accumulated all the parent attributes for the target element, call them in reverse order
-            // We check the bubble state between each call to see if the event has been stopped from bubbling
-            tracing::event!(
-                tracing::Level::TRACE,
-                "Calling {} listeners",
-                listeners.len()
-            );
-            for listener in listeners.into_iter().rev() {
-                if let AttributeValue::Listener(listener) = listener {
-                    self.rendering.set(false);
-                    listener.call(uievent.clone());
-                    self.rendering.set(true);
-                    let metadata = uievent.metadata.borrow();
-
-                    if !metadata.propagates {
-                        return;
-                    }
-                }
-            }
+            listeners.extend(template_listeners.into_iter().rev());
 
             let mount = el_ref.mount.get().as_usize();
             parent = mount.and_then(|id| mounts.get(id).and_then(|el| el.parent));
         }
+
+        tracing::event!(
+            tracing::Level::TRACE,
+            "Calling {} listeners",
+            listeners.len()
+        );
+
+        // Call the capture listeners from the root down, then the other listeners from the target up
+        // We check the bubble state between each call to see if the event has been stopped from propagating
+        let capture = listeners
+            .iter()
+            .rev()
+            .filter(|attr| attr.listener_options.capture);
+        let bubble = listeners
+            .iter()
+            .filter(|attr| !attr.listener_options.capture);
+        for attr in capture.chain(bubble) {
+            if let AttributeValue::Listener(listener) = &attr.value {
+                self.call_listener(name, listener, uievent.clone());
+                let metadata = uievent.metadata.borrow();
+
+                if !metadata.propagates {
+                    return;
+                }
+            }
+        }
     }
 
     /// Call an event listener in the simplest way possible without bubbling upwards
@@ -414,9 +465,7 @@ impl Runtime {
                 // Only call the listener if this is the exact target element.
                 if attr.name.get(2..) == Some(name) && target_path == this_path {
                     if let AttributeValue::Listener(listener) = &attr.value {
-                        self.rendering.set(false);
-                        listener.call(uievent.clone());
-                        self.rendering.set(true);
+                        self.call_listener(name, listener, uievent.clone());
                         break;
                     }
                 }
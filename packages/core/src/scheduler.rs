@@ -63,7 +63,7 @@
 //!
 //! ## Implementation
 //!
-//! There are three different types of queued work that can be run by the virtualdom:
+//! There are four different types of queued work that can be run by the virtualdom:
 //! 1. Dirty Scopes:
 //!    Description: When a scope is marked dirty, a rerun of the scope will be scheduled. This will cause the scope to rerun and update the DOM if any changes are detected during the diffing phase.
 //!    Priority: These are the highest priority tasks. Dirty scopes will be rerun in order from the scope closest to the root to the scope furthest from the root. We follow this order to ensure that if a higher component reruns and drops a lower component, the lower component will not be run after it should be dropped.
@@ -72,7 +72,11 @@
 //!    Description: Futures spawned in the dioxus runtime each have an unique task id. When the waker for that future is called, the task is rerun.
 //!    Priority: These are the second highest priority tasks. They are run after all other dirty scopes have been resolved because those dirty scopes may cause children (and the tasks those children own) to drop which should cancel the futures.
 //!
-//! 3. Effects:
+//! 3. Layout reads and writes:
+//!    Description: Layout callbacks run after all changes to the DOM have been applied. Every read callback runs before any write callback so measuring the DOM only forces one layout.
+//!    Priority: These run once all dirty scopes and futures have been resolved, before effects.
+//!
+//! 4. Effects:
 //!    Description: Effects should always run after all changes to the DOM have been applied.
 //!    Priority: These are the lowest priority tasks in the scheduler. They are run after all other dirty scopes and futures have been resolved. Other tasks may cause components to rerun, which would update the DOM. These effects should only run after the DOM has been updated.
 
@@ -1,6 +1,7 @@
-use crate::innerlude::{throw_error, RenderError, ScopeOrder};
+use crate::innerlude::{throw_error, ErrorSource, RenderError, RenderPhase, ScopeOrder};
 use crate::prelude::ReactiveContext;
 use crate::scope_context::SuspenseLocation;
+use crate::trace_spans::{self, RenderTrigger};
 use crate::{
     any_props::{AnyProps, BoxedAnyProps},
     innerlude::ScopeState,
@@ -15,6 +16,7 @@ impl VirtualDom {
         &mut self,
         props: BoxedAnyProps,
         name: &'static str,
+        created_at: Option<&'static std::panic::Location<'static>>,
     ) -> &mut ScopeState {
         let parent_id = self.runtime.current_scope_id().ok();
         let height = match parent_id.and_then(|id| self.runtime.get_state(id)) {
@@ -28,7 +30,7 @@ impl VirtualDom {
         let entry = self.scopes.vacant_entry();
         let id = ScopeId(entry.key());
 
-        let scope_runtime = Scope::new(name, id, parent_id, height, suspense_boundary);
+        let scope_runtime = Scope::new(name, created_at, id, parent_id, height, suspense_boundary);
         let reactive_context = ReactiveContext::new_for_scope(&scope_runtime, &self.runtime);
 
         let scope = entry.insert(ScopeState {
@@ -48,11 +50,13 @@ impl VirtualDom {
     /// Run a scope and return the rendered nodes. This will not modify the DOM or update the last rendered node of the scope.
     #[tracing::instrument(skip(self), level = "trace", name = "VirtualDom::run_scope")]
     #[track_caller]
-    pub(crate) fn run_scope(&mut self, scope_id: ScopeId) -> Element {
+    pub(crate) fn run_scope(&mut self, scope_id: ScopeId, trigger: RenderTrigger) -> Element {
         // Ensure we are currently inside a `Runtime`.
         crate::Runtime::current().unwrap_or_else(|e| panic!("{}", e));
 
-        self.runtime.clone().with_scope_on_stack(scope_id, || {
+        self.phase_started(scope_id, RenderPhase::Render);
+        let span = trace_spans::render(&self.runtime, scope_id, trigger).entered();
+        let output = self.runtime.clone().with_scope_on_stack(scope_id, || {
             let scope = &self.scopes[scope_id.0];
             let output = {
                 let scope_state = scope.state();
@@ -87,7 +91,10 @@ impl VirtualDom {
             self.dirty_scopes
                 .remove(&ScopeOrder::new(scope_state.height, scope_id));
             output
-        })
+        });
+        drop(span);
+        self.phase_finished(scope_id, RenderPhase::Render);
+        output
     }
 
     /// Insert any errors, or suspended tasks from an element return into the runtime
@@ -98,6 +105,8 @@ impl VirtualDom {
                     "Error while rendering component `{}`:\n{e}",
                     scope_state.name
                 );
+                self.runtime
+                    .report_error(ErrorSource::Render, e.to_string(), Some(scope_id));
                 throw_error(e.clone());
                 e.render = VNode::placeholder();
             }
@@ -1,4 +1,6 @@
+use crate::innerlude::{KeyedDiffEvent, LayoutPhase};
 use crate::runtime::RuntimeError;
+use crate::tasks::TaskType;
 use crate::{innerlude::SchedulerMsg, Runtime, ScopeId, Task};
 use crate::{
     innerlude::{throw_into, CapturedError},
@@ -18,6 +20,8 @@ pub(crate) enum ScopeStatus {
     Unmounted {
         // Before the component is mounted, we need to keep track of effects that need to be run once the scope is mounted
         effects_queued: Vec<Box<dyn FnOnce() + 'static>>,
+        // Layout callbacks are held back for the same reason
+        layout_queued: Vec<(LayoutPhase, Box<dyn FnOnce() + 'static>)>,
     },
 }
 
@@ -58,16 +62,21 @@ pub(crate) struct Scope {
     pub(crate) spawned_tasks: RefCell<FxHashSet<Task>>,
     pub(crate) before_render: RefCell<Vec<Box<dyn FnMut()>>>,
     pub(crate) after_render: RefCell<Vec<Box<dyn FnMut()>>>,
+    pub(crate) keyed_diff: RefCell<Vec<Box<dyn FnMut(&KeyedDiffEvent)>>>,
 
     /// The suspense boundary that this scope is currently in (if any)
     suspense_boundary: SuspenseLocation,
 
     pub(crate) status: RefCell<ScopeStatus>,
+
+    /// The location of the code that rendered the component of this scope. This is only tracked in debug builds.
+    pub(crate) created_at: Option<&'static std::panic::Location<'static>>,
 }
 
 impl Scope {
     pub(crate) fn new(
         name: &'static str,
+        created_at: Option<&'static std::panic::Location<'static>>,
         id: ScopeId,
         parent_id: Option<ScopeId>,
         height: u32,
@@ -85,10 +94,13 @@ impl Scope {
             hook_index: Cell::new(0),
             before_render: RefCell::new(vec![]),
             after_render: RefCell::new(vec![]),
+            keyed_diff: RefCell::new(vec![]),
             status: RefCell::new(ScopeStatus::Unmounted {
                 effects_queued: Vec::new(),
+                layout_queued: Vec::new(),
             }),
             suspense_boundary,
+            created_at,
         }
     }
 
@@ -103,10 +115,17 @@ impl Scope {
     /// Mount the scope and queue any pending effects if it is not already mounted
     pub(crate) fn mount(&self, runtime: &Runtime) {
         let mut status = self.status.borrow_mut();
-        if let ScopeStatus::Unmounted { effects_queued } = &mut *status {
+        if let ScopeStatus::Unmounted {
+            effects_queued,
+            layout_queued,
+        } = &mut *status
+        {
             for f in effects_queued.drain(..) {
                 runtime.queue_effect_on_mounted_scope(self.id, f);
             }
+            for (phase, f) in layout_queued.drain(..) {
+                runtime.queue_layout_on_mounted_scope(self.id, phase, f);
+            }
             *status = ScopeStatus::Mounted;
         }
     }
@@ -316,40 +335,15 @@ impl Scope {
         .expect("Runtime to exist")
     }
 
-    /// Start a new future on the same thread as the rest of the VirtualDom.
-    ///
-    /// **You should generally use `spawn` instead of this method unless you specifically need to need to run a task during suspense**
-    ///
-    /// This future will not contribute to suspense resolving but it will run during suspense.
-    ///
-    /// Because this future runs during suspense, you need to be careful to work with hydration. It is not recommended to do any async IO work in this future, as it can easily cause hydration issues. However, you can use isomorphic tasks to do work that can be consistently replicated on the server and client like logging or responding to state changes.
-    ///
-    /// ```rust, no_run
-    /// # use dioxus::prelude::*;
-    /// // ❌ Do not do requests in isomorphic tasks. It may resolve at a different time on the server and client, causing hydration issues.
-    /// let mut state = use_signal(|| None);
-    /// spawn_isomorphic(async move {
-    ///     state.set(Some(reqwest::get("https://api.example.com").await));
-    /// });
-    ///
-    /// // ✅ You may wait for a signal to change and then log it
-    /// let mut state = use_signal(|| 0);
-    /// spawn_isomorphic(async move {
-    ///     loop {
-    ///         tokio::time::sleep(std::time::Duration::from_secs(1)).await;
-    ///         println!("State is {state}");
-    ///     }
-    /// });
-    /// ```
-    pub fn spawn_isomorphic(&self, fut: impl Future<Output = ()> + 'static) -> Task {
-        let id = Runtime::with(|rt| rt.spawn_isomorphic(self.id, fut)).expect("Runtime to exist");
-        self.spawned_tasks.borrow_mut().insert(id);
-        id
-    }
-
-    /// Spawns the future and returns the [`Task`]
-    pub fn spawn(&self, fut: impl Future<Output = ()> + 'static) -> Task {
-        let id = Runtime::with(|rt| rt.spawn(self.id, fut)).expect("Runtime to exist");
+    /// Spawn a future that is owned by this scope and remember the location of the code that spawned it
+    pub(crate) fn spawn_at(
+        &self,
+        fut: impl Future<Output = ()> + 'static,
+        ty: TaskType,
+        spawned_at: &'static std::panic::Location<'static>,
+    ) -> Task {
+        let id = Runtime::with(|rt| rt.spawn_task_of_type(self.id, fut, ty, spawned_at))
+            .expect("Runtime to exist");
         self.spawned_tasks.borrow_mut().insert(id);
         id
     }
@@ -359,6 +353,11 @@ impl Scope {
         Runtime::with(|rt| rt.queue_effect(self.id, f)).expect("Runtime to exist");
     }
 
+    /// Queue a layout callback to run in the given phase after the next render
+    pub fn queue_layout(&self, phase: LayoutPhase, f: impl FnOnce() + 'static) {
+        Runtime::with(|rt| rt.queue_layout(self.id, phase, f)).expect("Runtime to exist");
+    }
+
     /// Store a value between renders. The foundational hook for all other hooks.
     ///
     /// Accepts an `initializer` closure, which is run on the first use of the hook (typically the initial render).
@@ -438,6 +437,10 @@ impl Scope {
         self.after_render.borrow_mut().push(Box::new(f));
     }
 
+    pub fn push_keyed_diff(&self, f: impl FnMut(&KeyedDiffEvent) + 'static) {
+        self.keyed_diff.borrow_mut().push(Box::new(f));
+    }
+
     /// Get the current render since the inception of this component
     ///
     /// This can be used as a helpful diagnostic when debugging hooks/renders, etc
@@ -490,13 +493,23 @@ impl ScopeId {
     }
 
     /// Pushes the future onto the poll queue to be polled after the component renders.
+    #[track_caller]
     pub fn push_future(self, fut: impl Future<Output = ()> + 'static) -> Option<Task> {
-        Runtime::with_scope(self, |cx| cx.spawn(fut)).ok()
+        let spawned_at = std::panic::Location::caller();
+        Runtime::with_scope(self, |cx| {
+            cx.spawn_at(fut, TaskType::ClientOnly, spawned_at)
+        })
+        .ok()
     }
 
     /// Spawns the future but does not return the [`Task`]
+    #[track_caller]
     pub fn spawn(self, fut: impl Future<Output = ()> + 'static) {
-        Runtime::with_scope(self, |cx| cx.spawn(fut)).unwrap();
+        let spawned_at = std::panic::Location::caller();
+        Runtime::with_scope(self, |cx| {
+            cx.spawn_at(fut, TaskType::ClientOnly, spawned_at)
+        })
+        .unwrap();
     }
 
     /// Get the current render since the inception of this component
@@ -506,6 +519,11 @@ impl ScopeId {
         Runtime::with_scope(self, |cx| Some(cx.generation())).unwrap()
     }
 
+    /// Get the name of the component this scope renders if the scope exists
+    pub fn name(self) -> Option<&'static str> {
+        Runtime::with_scope(self, |cx| cx.name).ok()
+    }
+
     /// Get the parent of the current scope if it exists
     pub fn parent_scope(self) -> Option<ScopeId> {
         Runtime::with_scope(self, |cx| cx.parent_id())
@@ -106,6 +106,33 @@ impl ScopeState {
         self.context_id
     }
 
+    /// Returns the name of the component this scope renders
+    pub fn name(&self) -> &'static str {
+        self.state().name
+    }
+
+    /// Returns the scope id of the parent of this scope, if it has one
+    pub fn parent_id(&self) -> Option<ScopeId> {
+        self.state().parent_id
+    }
+
+    /// Returns the height of this scope in the tree. The root scope has a height of 0.
+    pub fn height(&self) -> u32 {
+        self.state().height
+    }
+
+    /// Returns the number of times this scope has been rendered
+    pub fn generation(&self) -> usize {
+        self.state().generation()
+    }
+
+    /// Returns a debug representation of the props of this scope if they implement [`Debug`](std::fmt::Debug).
+    ///
+    /// This is used by devtools to show the props of components in the inspector.
+    pub fn props_snapshot(&self) -> Option<String> {
+        self.props.debug_snapshot()
+    }
+
     pub(crate) fn state(&self) -> Ref<'_, Scope> {
         self.runtime.get_state(self.context_id).unwrap()
     }
@@ -175,6 +175,7 @@ impl PartialEq for SuspenseBoundaryPropsWithOwner {
 }
 impl SuspenseBoundaryPropsWithOwner {
     /// Create a component from the props.
+    #[track_caller]
     pub fn into_vcomponent<M: 'static>(
         self,
         render_fn: impl ComponentFunction<SuspenseBoundaryProps, M>,
@@ -289,7 +290,11 @@ impl SuspenseBoundaryProps {
                     .clone()
                     .with_suspense_location(suspense_boundary_location, || {
                         let scope_state = dom
-                            .new_scope(component.props.duplicate(), component.name)
+                            .new_scope(
+                                component.props.duplicate(),
+                                component.name,
+                                component.location(),
+                            )
                             .state();
                         suspense_context.mount(scope_state.id);
                         scope_id = scope_state.id;
@@ -1,8 +1,10 @@
 use crate::innerlude::Effect;
+use crate::innerlude::ErrorSource;
 use crate::innerlude::ScopeOrder;
 use crate::innerlude::{remove_future, spawn, Runtime};
 use crate::scope_context::ScopeStatus;
 use crate::scope_context::SuspenseLocation;
+use crate::trace_spans;
 use crate::ScopeId;
 use futures_util::task::ArcWake;
 use slotmap::DefaultKey;
@@ -136,12 +138,18 @@ impl Runtime {
     ///     }
     /// });
     /// ```
+    #[track_caller]
     pub fn spawn_isomorphic(
         &self,
         scope: ScopeId,
         task: impl Future<Output = ()> + 'static,
     ) -> Task {
-        self.spawn_task_of_type(scope, task, TaskType::Isomorphic)
+        self.spawn_task_of_type(
+            scope,
+            task,
+            TaskType::Isomorphic,
+            std::panic::Location::caller(),
+        )
     }
 
     /// Start a new future on the same thread as the rest of the VirtualDom.
@@ -153,15 +161,22 @@ impl Runtime {
     ///
     /// Spawning a future onto the root scope will cause it to be dropped when the root component is dropped - which
     /// will only occur when the VirtualDom itself has been dropped.
+    #[track_caller]
     pub fn spawn(&self, scope: ScopeId, task: impl Future<Output = ()> + 'static) -> Task {
-        self.spawn_task_of_type(scope, task, TaskType::ClientOnly)
+        self.spawn_task_of_type(
+            scope,
+            task,
+            TaskType::ClientOnly,
+            std::panic::Location::caller(),
+        )
     }
 
-    fn spawn_task_of_type(
+    pub(crate) fn spawn_task_of_type(
         &self,
         scope: ScopeId,
         task: impl Future<Output = ()> + 'static,
         ty: TaskType,
+        spawned_at: &'static std::panic::Location<'static>,
     ) -> Task {
         // Insert the task, temporarily holding a borrow on the tasks map
         let (task, task_id) = {
@@ -182,6 +197,7 @@ impl Runtime {
                         tx: self.sender.clone(),
                     })),
                     ty: RefCell::new(ty),
+                    spawned_at,
                 });
 
                 local_task = Some(new_task.clone());
@@ -272,13 +288,27 @@ impl Runtime {
         }
 
         let mut cx = std::task::Context::from_waker(&task.waker);
+        let _span = trace_spans::poll_task(self, id, task.scope, task.spawned_at).entered();
 
         // poll the future with the scope on the stack
         let poll_result = self.with_scope_on_stack(task.scope, || {
             self.rendering.set(false);
             self.current_task.set(Some(id));
 
-            let poll_result = task.task.borrow_mut().as_mut().poll(&mut cx);
+            let poll_result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+                task.task.borrow_mut().as_mut().poll(&mut cx)
+            }));
+            let poll_result = match poll_result {
+                Ok(poll_result) => poll_result,
+                Err(payload) => {
+                    self.rendering.set(true);
+                    self.current_task.set(None);
+                    let source = ErrorSource::Task {
+                        spawned_at: task.spawned_at,
+                    };
+                    panic::resume_unwind(self.report_panic(source, task.scope, payload));
+                }
+            };
 
             if poll_result.is_ready() {
                 // Remove it from the scope so we dont try to double drop it when the scope dropes
@@ -337,12 +367,13 @@ impl Runtime {
 
 /// the task itself is the waker
 pub(crate) struct LocalTask {
-    scope: ScopeId,
+    pub(crate) scope: ScopeId,
     parent: Option<Task>,
     task: RefCell<Pin<Box<dyn Future<Output = ()> + 'static>>>,
     waker: Waker,
     ty: RefCell<TaskType>,
     active: Cell<bool>,
+    pub(crate) spawned_at: &'static std::panic::Location<'static>,
 }
 
 impl LocalTask {
@@ -355,7 +386,7 @@ impl LocalTask {
 }
 
 #[derive(Clone)]
-enum TaskType {
+pub(crate) enum TaskType {
     ClientOnly,
     Suspended { boundary: SuspenseLocation },
     Isomorphic,
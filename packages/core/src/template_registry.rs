@@ -0,0 +1,83 @@
+use crate::Template;
+use rustc_hash::FxHashMap;
+
+/// Assigns the ids renderers use to refer to the templates they saved. This is renderer-side template interning.
+///
+/// The `rsx!` macro creates a new static for every call, so identical templates from different crates (or from
+/// different calls in the same crate) have different addresses. The registry interns templates at runtime by their
+/// [`Template::stable_id`] and structure the first time the renderer sees them, so each distinct template is only sent
+/// to the renderer once no matter how many copies of it exist in the binary. The copies are still compiled into the
+/// binary; the registry only saves the work and memory of creating the same template in the renderer twice.
+///
+/// ```rust
+/// use dioxus_core::{Template, TemplateNode, TemplateRegistry};
+///
+/// static HELLO: &[TemplateNode] = &[TemplateNode::Text { text: "hello" }];
+/// static HELLO_COPY: &[TemplateNode] = &[TemplateNode::Text { text: "hello" }];
+///
+/// let mut registry = TemplateRegistry::default();
+/// let template = Template { roots: HELLO, node_paths: &[], attr_paths: &[] };
+/// let copy = Template { roots: HELLO_COPY, node_paths: &[], attr_paths: &[] };
+///
+/// // The first template needs to be saved in the renderer
+/// assert_eq!(registry.register(template), (0, true));
+/// // The copy reuses the template that was already saved
+/// assert_eq!(registry.register(copy), (0, false));
+/// assert_eq!(registry.len(), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct TemplateRegistry {
+    /// The ids of every template that was registered by address. Looking a template up by address is much cheaper
+    /// than hashing its structure, so templates are only interned by content the first time they are seen.
+    by_address: FxHashMap<Template, u16>,
+    /// The interned templates with their id, by stable id
+    by_content: FxHashMap<u64, Vec<(Template, u16)>>,
+    len: u16,
+}
+
+impl TemplateRegistry {
+    /// Get the id of a template that was already registered
+    pub fn get(&self, template: &Template) -> Option<u16> {
+        self.by_address.get(template).copied()
+    }
+
+    /// Get the id of a template, registering it if it wasn't seen before. Returns `true` if the template is new and
+    /// the renderer needs to save it under the id.
+    pub fn register(&mut self, template: Template) -> (u16, bool) {
+        if let Some(id) = self.get(&template) {
+            return (id, false);
+        }
+
+        let interned = self.by_content.entry(template.stable_id()).or_default();
+        let existing = interned
+            .iter()
+            .find(|(other, _)| other.same_structure(&template))
+            .map(|(_, id)| *id);
+        let (id, new) = match existing {
+            Some(id) => (id, false),
+            None => {
+                let id = self.len;
+                self.len += 1;
+                interned.push((template, id));
+                (id, true)
+            }
+        };
+        self.by_address.insert(template, id);
+        (id, new)
+    }
+
+    /// The number of distinct templates that were registered
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Check if no template was registered yet
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Forget every template, for example when the renderer is reset
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
@@ -0,0 +1,110 @@
+//! Spans for the work the VirtualDom does that can be collected with any tracing subscriber.
+//!
+//! The spans are only created when the `tracing-spans` feature is enabled. Without the feature, every span is
+//! [`Span::none`] so instrumenting the hot paths of the VirtualDom is free.
+
+use crate::{innerlude::ScopeId, ElementId, Runtime, Task};
+use std::fmt::Display;
+use tracing::Span;
+
+/// Why a scope is rendered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RenderTrigger {
+    /// The scope is rendered for the first time
+    Mount,
+    /// The scope was marked dirty, usually because a signal it reads changed
+    Dirty,
+    /// The parent of the scope rendered it with props that changed
+    PropsChanged,
+}
+
+impl Display for RenderTrigger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RenderTrigger::Mount => write!(f, "mount"),
+            RenderTrigger::Dirty => write!(f, "dirty"),
+            RenderTrigger::PropsChanged => write!(f, "props_changed"),
+        }
+    }
+}
+
+/// The name of the component a scope renders
+#[cfg(feature = "tracing-spans")]
+fn component_name(runtime: &Runtime, scope: ScopeId) -> &'static str {
+    runtime.get_state(scope).map_or("", |scope| scope.name)
+}
+
+/// A span around running the component of a scope
+#[cfg(feature = "tracing-spans")]
+pub(crate) fn render(runtime: &Runtime, scope: ScopeId, trigger: RenderTrigger) -> Span {
+    let component = component_name(runtime, scope);
+    tracing::debug_span!("render", component, scope = ?scope, trigger = %trigger)
+}
+
+#[cfg(not(feature = "tracing-spans"))]
+pub(crate) fn render(_runtime: &Runtime, _scope: ScopeId, _trigger: RenderTrigger) -> Span {
+    Span::none()
+}
+
+/// A span around creating or diffing the nodes a scope rendered
+#[cfg(feature = "tracing-spans")]
+pub(crate) fn diff(runtime: &Runtime, scope: ScopeId) -> Span {
+    let component = component_name(runtime, scope);
+    tracing::debug_span!("diff", component, scope = ?scope)
+}
+
+#[cfg(not(feature = "tracing-spans"))]
+pub(crate) fn diff(_runtime: &Runtime, _scope: ScopeId) -> Span {
+    Span::none()
+}
+
+/// A span around dispatching an event to the listeners of an element and its parents
+#[cfg(feature = "tracing-spans")]
+pub(crate) fn event(name: &str, element: ElementId, bubbles: bool) -> Span {
+    tracing::debug_span!("event", name, element = element.0, bubbles)
+}
+
+#[cfg(not(feature = "tracing-spans"))]
+pub(crate) fn event(_name: &str, _element: ElementId, _bubbles: bool) -> Span {
+    Span::none()
+}
+
+/// A span around calling one listener of an event. The scope is the scope that created the listener.
+#[cfg(feature = "tracing-spans")]
+pub(crate) fn listener(runtime: &Runtime, name: &str, scope: ScopeId) -> Span {
+    let component = component_name(runtime, scope);
+    tracing::debug_span!("listener", name, component, scope = ?scope)
+}
+
+#[cfg(not(feature = "tracing-spans"))]
+pub(crate) fn listener(_runtime: &Runtime, _name: &str, _scope: ScopeId) -> Span {
+    Span::none()
+}
+
+/// A span around polling a task
+#[cfg(feature = "tracing-spans")]
+pub(crate) fn poll_task(
+    runtime: &Runtime,
+    task: Task,
+    scope: ScopeId,
+    spawned_at: &'static std::panic::Location<'static>,
+) -> Span {
+    let component = component_name(runtime, scope);
+    tracing::debug_span!(
+        "poll_task",
+        task = ?task,
+        component,
+        scope = ?scope,
+        spawned_at = %spawned_at
+    )
+}
+
+#[cfg(not(feature = "tracing-spans"))]
+pub(crate) fn poll_task(
+    _runtime: &Runtime,
+    _task: Task,
+    _scope: ScopeId,
+    _spawned_at: &'static std::panic::Location<'static>,
+) -> Span {
+    Span::none()
+}
@@ -0,0 +1,229 @@
+use crate::innerlude::*;
+use std::marker::PhantomData;
+
+/// Children that may only contain components with the props `P`.
+///
+/// Use this as the type of the `children` prop to restrict which components a parent accepts. `rsx!` checks the
+/// children at compile time: every root must be a component with the props `P`, or a `for` loop or `if` chain whose
+/// roots are components with the props `P`.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// #[component]
+/// fn MenuItem(label: String) -> Element {
+///     rsx! { li { "{label}" } }
+/// }
+///
+/// #[component]
+/// fn Menu(children: ChildrenOf<MenuItemProps>) -> Element {
+///     // The parent can read the typed props of each child
+///     let count = children.iter().count();
+///     rsx! {
+///         ul { "aria-label": "{count} items", {children} }
+///     }
+/// }
+///
+/// fn app() -> Element {
+///     rsx! {
+///         Menu {
+///             MenuItem { label: "Home" }
+///             for label in ["Blog", "About"] {
+///                 MenuItem { key: "{label}", label }
+///             }
+///         }
+///     }
+/// }
+/// ```
+pub struct ChildrenOf<P> {
+    element: Element,
+    _marker: PhantomData<fn() -> P>,
+}
+
+impl<P> ChildrenOf<P> {
+    /// Get the rendered children
+    pub fn element(&self) -> &Element {
+        &self.element
+    }
+
+    /// Convert the children into an untyped [`Element`]
+    pub fn into_element(self) -> Element {
+        self.element
+    }
+}
+
+impl<P: 'static> ChildrenOf<P> {
+    /// Iterate over the props of each child component in the order they are rendered
+    pub fn iter(&self) -> impl Iterator<Item = &P> {
+        let mut props = Vec::new();
+        if let std::result::Result::Ok(vnode) = &self.element {
+            collect_props(vnode, &mut props);
+        }
+        props.into_iter()
+    }
+}
+
+/// Walk the roots of a vnode and collect the props of every component root, looking through fragments created by
+/// loops and if chains
+fn collect_props<'a, P: 'static>(vnode: &'a VNode, props: &mut Vec<&'a P>) {
+    for root in vnode.template.roots {
+        let TemplateNode::Dynamic { id } = root else {
+            continue;
+        };
+        match &vnode.dynamic_nodes[*id] {
+            DynamicNode::Component(component) => {
+                if let Some(child) = component.props.props().downcast_ref::<P>() {
+                    props.push(child);
+                }
+            }
+            DynamicNode::Fragment(nodes) => {
+                for node in nodes {
+                    collect_props(node, props);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<P> Clone for ChildrenOf<P> {
+    fn clone(&self) -> Self {
+        Self {
+            element: self.element.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<P> PartialEq for ChildrenOf<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.element == other.element
+    }
+}
+
+impl<P> std::fmt::Debug for ChildrenOf<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ChildrenOf").field(&self.element).finish()
+    }
+}
+
+impl<P> Default for ChildrenOf<P> {
+    fn default() -> Self {
+        VNode::empty().into()
+    }
+}
+
+impl<P> From<Element> for ChildrenOf<P> {
+    fn from(element: Element) -> Self {
+        Self {
+            element,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<P> From<ChildrenOf<P>> for Element {
+    fn from(children: ChildrenOf<P>) -> Self {
+        children.element
+    }
+}
+
+impl<P> IntoDynNode for ChildrenOf<P> {
+    fn into_dyn_node(self) -> DynamicNode {
+        self.element.into_dyn_node()
+    }
+}
+
+impl<P> IntoDynNode for &ChildrenOf<P> {
+    fn into_dyn_node(self) -> DynamicNode {
+        (&self.element).into_dyn_node()
+    }
+}
+
+/// Implemented by the builder of props with a typed `children` field. Generated by the Props derive.
+#[doc(hidden)]
+pub trait TypedChildrenBuilder {
+    type Children;
+}
+
+/// Marks a builder that rsx is passing children to so it can find out which children the builder accepts
+#[doc(hidden)]
+pub struct ChildrenChecker<'a, B>(pub &'a B);
+
+/// Builders with typed children check children against the type of their children field
+#[doc(hidden)]
+pub trait TypedChildrenChecker {
+    type Children;
+    fn children_check(&self) -> ChildrenCheck<Self::Children>;
+}
+
+impl<B: TypedChildrenBuilder> TypedChildrenChecker for ChildrenChecker<'_, B> {
+    type Children = B::Children;
+    fn children_check(&self) -> ChildrenCheck<Self::Children> {
+        ChildrenCheck(PhantomData)
+    }
+}
+
+/// Any other builder accepts any children. This is only picked if [`TypedChildrenChecker`] is not implemented because
+/// the method requires an extra reference
+#[doc(hidden)]
+pub trait UntypedChildrenChecker {
+    fn children_check(&self) -> ChildrenCheck<AnyChildren>;
+}
+
+impl<B> UntypedChildrenChecker for &ChildrenChecker<'_, B> {
+    fn children_check(&self) -> ChildrenCheck<AnyChildren> {
+        ChildrenCheck(PhantomData)
+    }
+}
+
+/// The children accepted by a component that doesn't restrict its children
+#[doc(hidden)]
+pub struct AnyChildren;
+
+/// A node in the children of a component that isn't a component
+#[doc(hidden)]
+pub struct NonComponentNode;
+
+/// The check rsx runs on each root of the children passed to a component
+#[doc(hidden)]
+pub struct ChildrenCheck<C>(PhantomData<fn() -> C>);
+
+impl<C> Clone for ChildrenCheck<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for ChildrenCheck<C> {}
+
+impl<C> ChildrenCheck<C> {
+    /// Check a component root with the given props
+    pub fn accept<P>(self, _props: &P)
+    where
+        C: AcceptsChild<P>,
+    {
+    }
+
+    /// Check a root that isn't a component
+    pub fn accept_node(self)
+    where
+        C: AcceptsChild<NonComponentNode>,
+    {
+    }
+}
+
+/// Implemented for the children types that accept a child with the props `P`
+#[doc(hidden)]
+#[rustversion::attr(
+    since(1.78.0),
+    diagnostic::on_unimplemented(
+        message = "`{Self}` doesn't accept this child",
+        label = "not allowed here",
+        note = "Only components with the props in `ChildrenOf<Props>` can be passed as children, either directly or from a `for` loop or `if` chain."
+    )
+)]
+pub trait AcceptsChild<P> {}
+
+impl<P> AcceptsChild<P> for AnyChildren {}
+
+impl<P> AcceptsChild<P> for ChildrenOf<P> {}
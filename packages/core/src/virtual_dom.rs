@@ -3,11 +3,15 @@
 //! This module provides the primary mechanics to create a hook-based, concurrent VDOM for Rust.
 
 use crate::innerlude::Work;
+use crate::leak_detector::LeakDetector;
 use crate::properties::RootProps;
 use crate::root_wrapper::RootScopeWrapper;
+use crate::trace_spans::RenderTrigger;
 use crate::{
     arena::ElementId,
-    innerlude::{NoOpMutations, SchedulerMsg, ScopeOrder, ScopeState, VProps, WriteMutations},
+    innerlude::{
+        NoOpMutations, ObserverPlugin, SchedulerMsg, ScopeOrder, ScopeState, VProps, WriteMutations,
+    },
     runtime::{Runtime, RuntimeGuard},
     scopes::ScopeId,
     ComponentFunction, Element, Mutations,
@@ -213,6 +217,11 @@ pub struct VirtualDom {
     pub(crate) resolved_scopes: Vec<ScopeId>,
 
     rx: futures_channel::mpsc::UnboundedReceiver<SchedulerMsg>,
+
+    pub(crate) render_observer: Option<Rc<ObserverPlugin>>,
+
+    // This must be the last field so it is dropped after the runtime
+    pub(crate) leak_detector: Option<LeakDetector>,
 }
 
 impl VirtualDom {
@@ -238,6 +247,7 @@ impl VirtualDom {
     /// ```
     ///
     /// Note: the VirtualDom is not progressed, you must either "run_with_deadline" or use "rebuild" to progress it.
+    #[track_caller]
     pub fn new(app: fn() -> Element) -> Self {
         Self::new_with_props(
             move || {
@@ -290,16 +300,19 @@ impl VirtualDom {
     /// let mut dom = VirtualDom::new_with_props(Example, SomeProps { name: "jane" });
     /// dom.rebuild_in_place();
     /// ```
+    #[track_caller]
     pub fn new_with_props<P: Clone + 'static, M: 'static>(
         root: impl ComponentFunction<P, M>,
         root_props: P,
     ) -> Self {
         let render_fn = root.id();
-        let props = VProps::new(root, |_, _| true, root_props, "Root");
+        let props = VProps::new(root, |_, _| true, |_| None, root_props, "Root");
         Self::new_with_component(VComponent {
             name: "root",
             render_fn,
             props: Box::new(props),
+            #[cfg(debug_assertions)]
+            location: std::panic::Location::caller(),
         })
     }
 
@@ -321,15 +334,19 @@ impl VirtualDom {
             scopes: Default::default(),
             dirty_scopes: Default::default(),
             resolved_scopes: Default::default(),
+            render_observer: None,
+            leak_detector: None,
         };
 
+        let location = root.location();
         let root = VProps::new(
             RootScopeWrapper,
             |_, _| true,
+            |_| None,
             RootProps(root),
             "RootWrapper",
         );
-        dom.new_scope(Box::new(root), "app");
+        dom.new_scope(Box::new(root), "app", location);
 
         dom
     }
@@ -348,6 +365,13 @@ impl VirtualDom {
         self.get_scope(ScopeId::ROOT).unwrap()
     }
 
+    /// Iterate over every scope that is currently mounted in the VirtualDom
+    ///
+    /// This is useful for tools that inspect the component tree like devtools
+    pub fn scopes(&self) -> impl Iterator<Item = &ScopeState> {
+        self.scopes.iter().map(|(_, scope)| scope)
+    }
+
     /// Run a closure inside the dioxus runtime
     #[instrument(skip(self, f), level = "trace", name = "VirtualDom::in_runtime")]
     pub fn in_runtime<O>(&self, f: impl FnOnce() -> O) -> O {
@@ -496,6 +520,7 @@ impl VirtualDom {
         // Or until we have no more dirty scopes
         while !self.runtime.dirty_tasks.borrow().is_empty()
             || !self.runtime.pending_effects.borrow().is_empty()
+            || !self.runtime.pending_layout.is_empty()
         {
             // Next, run any queued tasks
             // We choose not to poll the deadline since we complete pretty quickly anyways
@@ -509,7 +534,14 @@ impl VirtualDom {
                 }
             }
 
-            // At this point, we have finished running all tasks that are pending and we haven't found any scopes to rerun. This means it is safe to run our lowest priority work: effects
+            // At this point, we have finished running all tasks that are pending and we haven't found any scopes to rerun. The DOM is up to date, so we measure and then mutate the layout before the next paint
+            self.run_layout();
+            self.queue_events();
+            if self.has_dirty_scopes() {
+                return;
+            }
+
+            // Then it is safe to run our lowest priority work: effects
             while let Some(effect) = self.pop_effect() {
                 effect.run(&self.runtime);
                 // Check if any new scopes are queued for rerun
@@ -562,7 +594,8 @@ impl VirtualDom {
     #[instrument(skip(self, to), level = "trace", name = "VirtualDom::rebuild")]
     pub fn rebuild(&mut self, to: &mut impl WriteMutations) {
         let _runtime = RuntimeGuard::new(self.runtime.clone());
-        let new_nodes = self.run_scope(ScopeId::ROOT);
+        self.runtime.notify_plugins(|plugin| plugin.on_build(self));
+        let new_nodes = self.run_scope(ScopeId::ROOT, RenderTrigger::Mount);
 
         self.scopes[ScopeId::ROOT.0].last_rendered_node = Some(new_nodes.clone());
 
@@ -593,7 +626,7 @@ impl VirtualDom {
                 }
                 Work::RerunScope(scope) => {
                     // If the scope is dirty, run the scope and get the mutations
-                    self.run_and_diff_scope(Some(to), scope.id);
+                    self.run_and_diff_scope(Some(to), scope.id, RenderTrigger::Dirty);
                 }
             }
         }
@@ -700,7 +733,11 @@ impl VirtualDom {
                         .is_some();
                     if run_scope {
                         // If the scope is dirty, run the scope and get the mutations
-                        self.run_and_diff_scope(None::<&mut NoOpMutations>, scope_id);
+                        self.run_and_diff_scope(
+                            None::<&mut NoOpMutations>,
+                            scope_id,
+                            RenderTrigger::Dirty,
+                        );
 
                         tracing::trace!("Ran scope {:?} during suspense", scope_id);
                     } else {
@@ -741,12 +778,17 @@ impl VirtualDom {
 
 impl Drop for VirtualDom {
     fn drop(&mut self) {
+        self.runtime
+            .notify_plugins(|plugin| plugin.on_teardown(self));
+
         // Drop all scopes in order of height
         let mut scopes = self.scopes.drain().collect::<Vec<_>>();
         scopes.sort_by_key(|scope| scope.state().height);
         for scope in scopes.into_iter().rev() {
             drop(scope);
         }
+
+        self.record_leaks();
     }
 }
 
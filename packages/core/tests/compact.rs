@@ -0,0 +1,98 @@
+use dioxus::prelude::*;
+use dioxus_core::{CompactStats, ElementId, Mutation, Mutations};
+use std::any::Any;
+use std::cell::Cell;
+use std::rc::Rc;
+
+thread_local! {
+    static COUNT: Cell<Option<Signal<usize>>> = const { Cell::new(None) };
+    static CLICKS: Cell<usize> = const { Cell::new(0) };
+}
+
+fn app() -> Element {
+    let count = use_signal(|| 500);
+    COUNT.with(|cell| cell.set(Some(count)));
+    rsx! {
+        for id in 0..count() {
+            Item { key: "{id}", id }
+        }
+    }
+}
+
+#[component]
+fn Item(id: usize) -> Element {
+    rsx! {
+        button { onclick: move |_| CLICKS.with(|clicks| clicks.set(clicks.get() + 1)), "{id}" }
+    }
+}
+
+fn set_count(dom: &mut VirtualDom, count: usize) -> Mutations {
+    dom.in_runtime(|| COUNT.with(|cell| cell.get()).unwrap().set(count));
+    dom.render_immediate_to_vec()
+}
+
+fn listeners(mutations: &Mutations) -> Vec<ElementId> {
+    mutations
+        .edits
+        .iter()
+        .filter_map(|edit| match edit {
+            Mutation::NewEventListener { id, .. } => Some(*id),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn compact_releases_slots_of_removed_nodes() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    set_count(&mut dom, 1);
+    let released = dom.compact();
+    assert!(released.scopes >= 450, "{released:?}");
+    assert!(released.elements > 0, "{released:?}");
+    assert!(released.mounts >= 450, "{released:?}");
+
+    // Nothing is left to release
+    assert_eq!(dom.compact(), CompactStats::default());
+}
+
+#[test]
+fn the_tree_keeps_working_after_compacting() {
+    set_event_converter(Box::new(dioxus::html::SerializedHtmlEventConverter));
+
+    let mut dom = VirtualDom::new(app);
+    let mut ids = listeners(&dom.rebuild_to_vec());
+    set_count(&mut dom, 3);
+    ids.truncate(3);
+    dom.compact();
+
+    ids.extend(listeners(&set_count(&mut dom, 5)));
+    assert_eq!(ids.len(), 5);
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        "<button>0</button><button>1</button><button>2</button><button>3</button><button>4</button>"
+    );
+
+    // Events still reach the listeners of nodes created before and after compacting
+    for id in ids {
+        let event = Event::new(
+            Rc::new(PlatformEventData::new(Box::<SerializedMouseData>::default())) as Rc<dyn Any>,
+            true,
+        );
+        dom.runtime().handle_event("click", event, id);
+    }
+    assert_eq!(CLICKS.with(|clicks| clicks.get()), 5);
+}
+
+#[test]
+fn scopes_can_be_compacted_alone() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    dom.in_runtime(|| ScopeId::APP.compact());
+    set_count(&mut dom, 2);
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        "<button>0</button><button>1</button>"
+    );
+}
@@ -445,3 +445,33 @@ fn old_middle_empty_right_pivot() {
         ]
     );
 }
+
+/// Keys set on the for loop itself should feed the keyed diff just like keys on the root node
+#[test]
+fn keyed_diffing_for_loop_key() {
+    let mut dom = VirtualDom::new(|| {
+        let order = match generation() % 2 {
+            0 => &[/**/ 4, 5, 6, 7, 8 /**/],
+            1 => &[/**/ 8, 7, 4, 5, 6 /**/],
+            _ => unreachable!(),
+        };
+
+        rsx! {
+            for i in order key: "{i}" {
+                div {}
+            }
+        }
+    });
+
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    dom.mark_dirty(ScopeId::APP);
+    assert_eq!(
+        dom.render_immediate_to_vec().edits,
+        [
+            PushRoot { id: ElementId(5,) },
+            PushRoot { id: ElementId(4,) },
+            InsertBefore { id: ElementId(1,), m: 2 },
+        ]
+    );
+}
@@ -30,3 +30,116 @@ fn ThrowChild() -> Element {
 
     rsx! { div {} }
 }
+
+#[test]
+fn else_block_renders_errors() {
+    fn app() -> Element {
+        rsx! {
+            ErrorBoundary {
+                ThrowChild {}
+            } else |errors| {
+                if errors.errors().is_empty() {
+                    "No errors"
+                } else {
+                    "Caught an error"
+                }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    // The boundary renders the fallback after it catches the error
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&dom), "Caught an error");
+}
+
+#[test]
+fn if_without_else_renders_nothing() {
+    fn app() -> Element {
+        let show = false;
+        rsx! {
+            div {
+                {if show { rsx! { "Hidden" } }}
+                {if let Some(text) = Some("Shown") { rsx! { "{text}" } } else if show { "Hidden" }}
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert_eq!(dioxus_ssr::render(&dom), "<div>Shown</div>");
+}
+
+#[test]
+fn fallback_downcasts_and_resets() {
+    use std::cell::Cell;
+
+    thread_local! {
+        static FAIL: Cell<bool> = const { Cell::new(true) };
+        static BOUNDARY: std::cell::RefCell<Option<ErrorContext>> = const { std::cell::RefCell::new(None) };
+    }
+
+    fn app() -> Element {
+        rsx! {
+            ErrorBoundary {
+                handle_error: |errors: ErrorContext| {
+                    BOUNDARY.with(|cell| *cell.borrow_mut() = Some(errors.clone()));
+                    let kind = errors.downcast::<std::io::Error>().map(|error| error.kind());
+                    rsx! { "{kind:?}" }
+                },
+                MaybeFail {}
+            }
+        }
+    }
+
+    fn MaybeFail() -> Element {
+        if FAIL.with(|cell| cell.get()) {
+            Err(std::io::Error::new(std::io::ErrorKind::NotFound, "missing"))?;
+        }
+        rsx! { "Loaded" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&dom), "Some(NotFound)");
+
+    // Resetting the boundary renders the children again
+    FAIL.with(|cell| cell.set(false));
+    let boundary = BOUNDARY.with(|cell| cell.borrow().clone()).unwrap();
+    dom.in_runtime(|| boundary.reset());
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&dom), "Loaded");
+}
+
+#[test]
+fn throw_sends_errors_to_the_boundary() {
+    fn app() -> Element {
+        rsx! {
+            ErrorBoundary {
+                handle_error: |errors: ErrorContext| {
+                    let error = errors.downcast::<std::num::ParseIntError>().map(|error| error.to_string());
+                    rsx! { "{error:?}" }
+                },
+                Thrower {}
+            }
+        }
+    }
+
+    fn Thrower() -> Element {
+        use_hook(|| {
+            assert_eq!(Ok::<_, dioxus_core::CapturedError>(1).throw(), Some(1));
+            assert_eq!("oops".parse::<i32>().throw(), None);
+        });
+        rsx! { "Rendered" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        "Some(&quot;invalid digit found in string&quot;)"
+    );
+}
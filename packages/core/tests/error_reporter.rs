@@ -0,0 +1,134 @@
+//! Errors and panics are reported to the error reporter of the VirtualDom
+
+use dioxus::prelude::*;
+use dioxus_core::{ElementId, ErrorReport, ErrorSource};
+use std::{any::Any, cell::RefCell, panic::AssertUnwindSafe, rc::Rc};
+
+fn reporting_dom(app: fn() -> Element) -> (VirtualDom, Rc<RefCell<Vec<ErrorReport>>>) {
+    let reports = Rc::new(RefCell::new(Vec::new()));
+    let dom = VirtualDom::new(app).with_error_reporter({
+        let reports = reports.clone();
+        move |report| reports.borrow_mut().push(report.clone())
+    });
+    (dom, reports)
+}
+
+fn stack_names(report: &ErrorReport) -> Vec<&str> {
+    report
+        .component_stack
+        .iter()
+        .map(|frame| frame.name.rsplit("::").next().unwrap())
+        .collect()
+}
+
+fn fail(message: &str) {
+    panic!("{message}")
+}
+
+#[test]
+fn reports_render_errors_with_the_component_stack() {
+    fn app() -> Element {
+        rsx! { Parent {} }
+    }
+
+    #[component]
+    fn Parent() -> Element {
+        rsx! { Child {} }
+    }
+
+    #[component]
+    fn Child() -> Element {
+        Err(std::io::Error::other("child failed"))?;
+        rsx! {}
+    }
+
+    let (mut dom, reports) = reporting_dom(app);
+    dom.rebuild_in_place();
+
+    let reports = reports.borrow();
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].source, ErrorSource::Render);
+    assert!(reports[0].message.contains("child failed"));
+    assert_eq!(
+        stack_names(&reports[0]),
+        [
+            "Child",
+            "Parent",
+            "root",
+            "SuspenseBoundary",
+            "ErrorBoundary",
+            "app"
+        ]
+    );
+}
+
+#[test]
+fn reports_panics_in_event_handlers() {
+    set_event_converter(Box::new(dioxus::html::SerializedHtmlEventConverter));
+
+    fn app() -> Element {
+        rsx! {
+            button {
+                onclick: |_| fail("handler failed")
+            }
+        }
+    }
+
+    let (mut dom, reports) = reporting_dom(app);
+    dom.rebuild_in_place();
+
+    let event = Event::new(
+        Rc::new(PlatformEventData::new(Box::<SerializedMouseData>::default())) as Rc<dyn Any>,
+        true,
+    );
+    let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+        dom.runtime().handle_event("click", event, ElementId(1))
+    }));
+    assert!(result.is_err(), "the panic should continue to unwind");
+
+    let reports = reports.borrow();
+    assert_eq!(reports.len(), 1);
+    assert_eq!(
+        reports[0].source,
+        ErrorSource::EventHandler { event: "click".to_string() }
+    );
+    assert_eq!(reports[0].message, "handler failed");
+    assert_eq!(stack_names(&reports[0])[0], "root");
+}
+
+#[tokio::test]
+async fn reports_panics_in_tasks() {
+    fn app() -> Element {
+        use_hook(|| spawn(async { fail("task failed") }));
+        rsx! {}
+    }
+
+    let (mut dom, reports) = reporting_dom(app);
+    dom.rebuild_in_place();
+
+    let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+        dom.render_immediate(&mut dioxus_core::NoOpMutations)
+    }));
+    assert!(result.is_err(), "the panic should continue to unwind");
+
+    let reports = reports.borrow();
+    assert_eq!(reports.len(), 1);
+    assert!(matches!(reports[0].source, ErrorSource::Task { .. }));
+    assert_eq!(reports[0].message, "task failed");
+    assert_eq!(stack_names(&reports[0])[0], "root");
+}
+
+#[test]
+fn renderers_can_report_errors() {
+    fn app() -> Element {
+        rsx! {}
+    }
+
+    let (dom, reports) = reporting_dom(app);
+    dom.runtime()
+        .report_error(ErrorSource::Hydration, "mismatch".to_string(), None);
+
+    let reports = reports.borrow();
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].to_string(), "Error in hydration: mismatch");
+}
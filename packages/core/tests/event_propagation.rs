@@ -77,3 +77,123 @@ fn problematic_child() -> Element {
             } }
     }
 }
+
+#[test]
+fn capture_listeners_run_before_bubbling_listeners() {
+    thread_local! {
+        static CALLS: std::cell::RefCell<Vec<&'static str>> = const { std::cell::RefCell::new(Vec::new()) };
+    }
+    fn record(name: &'static str) {
+        CALLS.with(|calls| calls.borrow_mut().push(name));
+    }
+
+    set_event_converter(Box::new(dioxus::html::SerializedHtmlEventConverter));
+
+    fn app() -> Element {
+        rsx! {
+            div { onclick: move |_| record("outer bubble"),
+                div { onclick: #[capture] move |_| record("outer capture"),
+                    div { onclick: #[capture] move |_| record("inner capture"),
+                        button { onclick: move |_| record("target") }
+                    }
+                }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    let click = || {
+        Event::new(
+            Rc::new(PlatformEventData::new(Box::<SerializedMouseData>::default())) as Rc<dyn Any>,
+            true,
+        )
+    };
+    dom.runtime().handle_event("click", click(), ElementId(4));
+    assert_eq!(
+        CALLS.with(|calls| calls.take()),
+        ["outer capture", "inner capture", "target", "outer bubble"]
+    );
+
+    // Stopping propagation in the capture phase skips the rest of the listeners
+    fn stops() -> Element {
+        rsx! {
+            div { onclick: #[capture] move |event| {
+                    record("capture");
+                    event.stop_propagation();
+                },
+                button { onclick: move |_| record("target") }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(stops);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+    dom.runtime().handle_event("click", click(), ElementId(2));
+    assert_eq!(CALLS.with(|calls| calls.take()), ["capture"]);
+}
+
+#[test]
+fn listener_options_are_passed_to_the_renderer() {
+    use dioxus_core::{ListenerOptions, Mutation};
+
+    fn app() -> Element {
+        // The options of the wheel listener on the button change after the first render
+        let options = match generation() {
+            0 => ListenerOptions { passive: Some(true), ..Default::default() },
+            _ => ListenerOptions::default(),
+        };
+        let wheel = vec![dioxus_elements::events::onwheel(move |_: WheelEvent| {})
+            .with_listener_options(options)];
+        rsx! {
+            div {
+                onwheel: #[passive(false)] move |event| event.prevent_default(),
+                onscroll: move |_| {},
+            }
+            div { onclick: #[capture] #[passive(true)] move |_| {} }
+            button { ..wheel }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    let vnode = dom.get_scope(ScopeId::APP).unwrap().root_node();
+    let options = |idx: usize| {
+        vnode.dynamic_attrs[idx]
+            .iter()
+            .map(|attr| (attr.name, attr.listener_options()))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        options(0),
+        [
+            (
+                "onwheel",
+                ListenerOptions { capture: false, passive: Some(false) }
+            ),
+            ("onscroll", ListenerOptions::default()),
+        ]
+    );
+    assert_eq!(
+        options(1),
+        [(
+            "onclick",
+            ListenerOptions { capture: true, passive: Some(true) }
+        )]
+    );
+
+    // The listener is registered again when its options change
+    dom.mark_dirty(ScopeId::APP);
+    let edits = dom.render_immediate_to_vec().edits;
+    let wheel = |edit: &Mutation| match edit {
+        Mutation::RemoveEventListener { name, .. } if name == "wheel" => Some("remove"),
+        Mutation::NewEventListener { name, .. } if name == "wheel" => Some("new"),
+        _ => None,
+    };
+    assert_eq!(
+        edits.iter().filter_map(wheel).collect::<Vec<_>>(),
+        ["remove", "new"]
+    );
+}
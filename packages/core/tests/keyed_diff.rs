@@ -0,0 +1,78 @@
+use dioxus::prelude::*;
+use dioxus_core::{KeyedChange, KeyedDiffEvent};
+use std::cell::{Cell, RefCell};
+
+thread_local! {
+    static ITEMS: Cell<Option<Signal<Vec<u32>>>> = const { Cell::new(None) };
+    static EVENTS: RefCell<Vec<(String, KeyedChange)>> = const { RefCell::new(Vec::new()) };
+}
+
+fn app() -> Element {
+    let items = use_signal(|| vec![1, 2, 3, 4]);
+    ITEMS.with(|cell| cell.set(Some(items)));
+    use_keyed_diff(|event: &KeyedDiffEvent| {
+        assert!(event.element.is_some());
+        EVENTS.with(|events| events.borrow_mut().push((event.key.clone(), event.change)));
+    });
+    rsx! {
+        for item in items() {
+            div { key: "{item}", "{item}" }
+        }
+    }
+}
+
+fn set_items(dom: &mut VirtualDom, items: Vec<u32>) -> Vec<(String, KeyedChange)> {
+    dom.in_runtime(|| ITEMS.with(|cell| cell.get()).unwrap().set(items));
+    dom.render_immediate_to_vec();
+    EVENTS.with(|events| std::mem::take(&mut *events.borrow_mut()))
+}
+
+fn events(expected: &[(&str, KeyedChange)]) -> Vec<(String, KeyedChange)> {
+    expected
+        .iter()
+        .map(|(key, change)| (key.to_string(), *change))
+        .collect()
+}
+
+#[test]
+fn keyed_diff_reports_inserts_moves_and_removals() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    // The first render creates the list without diffing it
+    EVENTS.with(|events| events.borrow_mut().clear());
+
+    assert_eq!(
+        set_items(&mut dom, vec![1, 2, 3, 4, 5]),
+        events(&[("5", KeyedChange::Inserted)])
+    );
+    assert_eq!(
+        set_items(&mut dom, vec![1, 3, 4, 5]),
+        events(&[("2", KeyedChange::Removed)])
+    );
+    assert_eq!(
+        set_items(&mut dom, vec![5, 1, 3, 4]),
+        events(&[("5", KeyedChange::Moved)])
+    );
+    assert_eq!(set_items(&mut dom, vec![5, 1, 3, 4]), events(&[]));
+}
+
+#[test]
+fn keyed_diff_reports_lists_that_become_empty() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    EVENTS.with(|events| events.borrow_mut().clear());
+
+    assert_eq!(
+        set_items(&mut dom, vec![]),
+        events(&[
+            ("1", KeyedChange::Removed),
+            ("2", KeyedChange::Removed),
+            ("3", KeyedChange::Removed),
+            ("4", KeyedChange::Removed),
+        ])
+    );
+    assert_eq!(
+        set_items(&mut dom, vec![7]),
+        events(&[("7", KeyedChange::Inserted)])
+    );
+}
@@ -43,7 +43,7 @@ fn dual_stream() {
             ReplacePlaceholder { path: &[0, 0], m: 1 },
             SetAttribute {
                 name: "class",
-                value: "asd 123 123 ".into_value(),
+                value: "asd 123 123".into_value(),
                 id: ElementId(1),
                 ns: None,
             },
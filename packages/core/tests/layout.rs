@@ -0,0 +1,81 @@
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type Log = Rc<RefCell<Vec<String>>>;
+
+fn app(log: Log) -> Element {
+    rsx! {
+        Measured { name: "first", log: log.clone() }
+        Measured { name: "second", log: log.clone() }
+    }
+}
+
+#[component]
+fn Measured(name: String, log: Log) -> Element {
+    use_hook(|| {
+        queue_effect({
+            let (name, log) = (name.clone(), log.clone());
+            move || log.borrow_mut().push(format!("effect {name}"))
+        });
+        queue_layout_write({
+            let (name, log) = (name.clone(), log.clone());
+            move || log.borrow_mut().push(format!("write {name}"))
+        });
+        queue_layout_read({
+            let (name, log) = (name.clone(), log.clone());
+            move || log.borrow_mut().push(format!("read {name}"))
+        });
+    });
+    rsx! { div {} }
+}
+
+#[tokio::test]
+async fn reads_run_before_writes_and_effects() {
+    let log = Log::default();
+    let mut dom = VirtualDom::new_with_props(app, log.clone());
+    dom.rebuild_in_place();
+
+    tokio::select! {
+        _ = dom.wait_for_work() => {}
+        _ = tokio::time::sleep(std::time::Duration::from_millis(100)) => {}
+    };
+
+    assert_eq!(
+        *log.borrow(),
+        [
+            "read first",
+            "read second",
+            "write first",
+            "write second",
+            "effect first",
+            "effect second",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn layout_of_removed_scopes_is_skipped() {
+    let log = Log::default();
+    let mut dom = VirtualDom::new_with_props(
+        |log: Log| {
+            let mut show = use_signal(|| true);
+            use_hook(move || show.set(false));
+            rsx! {
+                if show() {
+                    Measured { name: "removed", log: log.clone() }
+                }
+            }
+        },
+        log.clone(),
+    );
+    dom.rebuild_in_place();
+    dom.render_immediate_to_vec();
+
+    tokio::select! {
+        _ = dom.wait_for_work() => {}
+        _ = tokio::time::sleep(std::time::Duration::from_millis(100)) => {}
+    };
+
+    assert!(log.borrow().is_empty());
+}
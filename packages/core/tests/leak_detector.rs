@@ -0,0 +1,116 @@
+//! Leak detection for scopes, tasks and generational boxes when the VirtualDom drops
+
+use dioxus::prelude::*;
+use dioxus_core::{LeakReport, Runtime};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn detect_leaks(app: fn() -> Element) -> Option<LeakReport> {
+    let leaks = Rc::new(RefCell::new(None));
+    let mut dom = VirtualDom::new(app).with_leak_detector({
+        let leaks = leaks.clone();
+        move |report| *leaks.borrow_mut() = Some(report)
+    });
+    dom.rebuild_in_place();
+    drop(dom);
+    leaks.take()
+}
+
+#[test]
+fn dropped_app_does_not_leak() {
+    fn app() -> Element {
+        let mut count = use_signal(|| 0);
+        let doubled = use_memo(move || count() * 2);
+        use_context_provider(|| Signal::new("context"));
+        use_future(move || async move {
+            count += 1;
+            std::future::pending::<()>().await;
+        });
+        rsx! {
+            "{doubled}"
+            Child {}
+        }
+    }
+
+    #[component]
+    fn Child() -> Element {
+        let context: Signal<&str> = use_context();
+        let copy = use_hook(|| CopyValue::new(1));
+        rsx! { "{context} {copy}" }
+    }
+
+    assert_eq!(detect_leaks(app), None);
+}
+
+#[test]
+fn unowned_task_that_holds_the_runtime_leaks() {
+    fn app() -> Element {
+        use_hook(|| {
+            let runtime = Runtime::current().unwrap();
+            let value = generational_box::GenerationalBox::<i32>::leak(0);
+            runtime.clone().spawn(ScopeId::APP, async move {
+                let _runtime = runtime;
+                let _value = value;
+                std::future::pending::<()>().await;
+            });
+        });
+        rsx! {}
+    }
+
+    let report = detect_leaks(app).expect("the task should leak");
+    assert!(report.scopes.is_empty());
+    assert_eq!(report.tasks.len(), 1);
+    assert_eq!(report.tasks[0].scope, ScopeId::APP);
+    assert_eq!(report.tasks[0].spawned_at.file(), file!());
+    assert_eq!(report.runtime_references, 1);
+    // Everything the leaked runtime owns is leaked too, so only check for the box the task holds. Boxes are only
+    // tracked with debug assertions
+    if cfg!(debug_assertions) {
+        assert!(report
+            .boxes
+            .iter()
+            .any(|live| live.created_at.file() == file!()));
+    }
+
+    let message = report.to_string();
+    assert!(message.contains("task spawned at"), "{message}");
+    assert!(message.contains("1 references to the runtime"), "{message}");
+}
+
+#[test]
+fn boxes_leaked_by_other_virtual_doms_are_not_reported() {
+    fn app() -> Element {
+        rsx! { "tracked" }
+    }
+
+    fn other() -> Element {
+        use_hook(|| generational_box::GenerationalBox::<i32>::leak(0));
+        rsx! { "leaks" }
+    }
+
+    let leaks = Rc::new(RefCell::new(None));
+    let mut dom = VirtualDom::new(app).with_leak_detector({
+        let leaks = leaks.clone();
+        move |report| *leaks.borrow_mut() = Some(report)
+    });
+    dom.rebuild_in_place();
+
+    // Another VirtualDom on the same thread leaks a box while the tracked one is alive
+    let mut other = VirtualDom::new(other);
+    other.rebuild_in_place();
+    dom.in_runtime(|| {
+        let _ = generational_box::GenerationalBox::<i32>::leak(1);
+    });
+    drop(other);
+
+    drop(dom);
+    let report = leaks.take();
+    // Boxes are only tracked with debug assertions
+    if cfg!(debug_assertions) {
+        let report = report.expect("the box leaked in the runtime should be reported");
+        assert!(report.scopes.is_empty() && report.tasks.is_empty());
+        assert_eq!(report.boxes.len(), 1);
+    } else {
+        assert_eq!(report, None);
+    }
+}
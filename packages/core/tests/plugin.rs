@@ -0,0 +1,139 @@
+//! Plugins are notified about the lifecycle of the VirtualDom
+
+use dioxus::prelude::*;
+use dioxus_core::{ElementId, ErrorReport, RenderPhase, VirtualDomPlugin};
+use std::{any::Any, cell::RefCell, rc::Rc};
+
+#[derive(Clone, Default)]
+struct Recorder(Rc<RefCell<Vec<String>>>);
+
+impl Recorder {
+    fn take(&self) -> Vec<String> {
+        std::mem::take(&mut *self.0.borrow_mut())
+    }
+
+    fn push(&self, entry: String) {
+        self.0.borrow_mut().push(entry);
+    }
+}
+
+impl VirtualDomPlugin for Recorder {
+    fn on_build(&self, dom: &VirtualDom) {
+        dom.provide_root_context("from plugin");
+        self.push("build".to_string());
+    }
+
+    fn phase_started(&self, _scope: ScopeId, name: &'static str, phase: RenderPhase) {
+        self.push(format!("start {phase} {}", short_name(name)));
+    }
+
+    fn phase_finished(&self, _scope: ScopeId, name: &'static str, phase: RenderPhase) {
+        self.push(format!("finish {phase} {}", short_name(name)));
+    }
+
+    fn on_event(&self, name: &str, element: ElementId, _event: &Event<dyn Any>) {
+        self.push(format!("event {name} {}", element.0));
+    }
+
+    fn on_error(&self, report: &ErrorReport) {
+        self.push(format!("error {}", report.message));
+    }
+
+    fn on_teardown(&self, _dom: &VirtualDom) {
+        self.push("teardown".to_string());
+    }
+}
+
+fn short_name(name: &str) -> &str {
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+#[test]
+fn plugins_see_the_lifecycle() {
+    set_event_converter(Box::new(dioxus::html::SerializedHtmlEventConverter));
+
+    fn app() -> Element {
+        let context: &str = use_context();
+        let mut count = use_signal(|| 0);
+        rsx! {
+            button { onclick: move |_| count += 1, "{context} {count}" }
+        }
+    }
+
+    let recorder = Recorder::default();
+    let mut dom = VirtualDom::new(app).with_plugin(recorder.clone());
+    dom.rebuild_in_place();
+    let built = recorder.take();
+    assert_eq!(built.first().map(String::as_str), Some("build"));
+    assert!(built.contains(&"start render root".to_string()));
+    assert!(built.contains(&"finish render root".to_string()));
+
+    let event = Event::new(
+        Rc::new(PlatformEventData::new(Box::<SerializedMouseData>::default())) as Rc<dyn Any>,
+        true,
+    );
+    dom.runtime().handle_event("click", event, ElementId(1));
+    dom.render_immediate_to_vec();
+    assert_eq!(
+        recorder.take(),
+        [
+            "event click 1",
+            "start render root",
+            "finish render root",
+            "start diff root",
+            "finish diff root"
+        ]
+    );
+
+    drop(dom);
+    assert_eq!(recorder.take(), ["teardown"]);
+}
+
+#[test]
+fn plugins_see_render_errors() {
+    fn app() -> Element {
+        rsx! { Child {} }
+    }
+
+    #[component]
+    fn Child() -> Element {
+        Err(std::io::Error::other("child failed"))?;
+        rsx! {}
+    }
+
+    let recorder = Recorder::default();
+    let mut dom = VirtualDom::new(app).with_plugin(recorder.clone());
+    dom.rebuild_in_place();
+    let recorded = recorder.take();
+    let error = recorded
+        .iter()
+        .position(|entry| entry.starts_with("error") && entry.contains("child failed"))
+        .unwrap();
+    let finished = recorded
+        .iter()
+        .position(|entry| entry == "finish render Child")
+        .unwrap();
+    assert!(error < finished, "{recorded:?}");
+}
+
+#[test]
+fn plugins_run_in_order() {
+    struct Named(&'static str, Recorder);
+
+    impl VirtualDomPlugin for Named {
+        fn on_build(&self, _dom: &VirtualDom) {
+            self.1.push(self.0.to_string());
+        }
+    }
+
+    fn app() -> Element {
+        rsx! {}
+    }
+
+    let recorder = Recorder::default();
+    let mut dom = VirtualDom::new(app)
+        .with_plugin(Named("first", recorder.clone()))
+        .with_plugin(Named("second", recorder.clone()));
+    dom.rebuild_in_place();
+    assert_eq!(recorder.take(), ["first", "second"]);
+}
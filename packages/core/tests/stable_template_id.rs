@@ -0,0 +1,74 @@
+//! Content addressed template ids should only depend on the structure of the template
+
+use dioxus_core::{Template, TemplateAttribute, TemplateNode};
+
+static DIV: TemplateNode = TemplateNode::Element {
+    tag: "div",
+    namespace: None,
+    attrs: &[TemplateAttribute::Static { name: "class", value: "hello", namespace: None }],
+    children: &[TemplateNode::Dynamic { id: 0 }],
+};
+
+static DIV_COPY: TemplateNode = TemplateNode::Element {
+    tag: "div",
+    namespace: None,
+    attrs: &[TemplateAttribute::Static { name: "class", value: "hello", namespace: None }],
+    children: &[TemplateNode::Dynamic { id: 0 }],
+};
+
+static SPAN: TemplateNode = TemplateNode::Element {
+    tag: "span",
+    namespace: None,
+    attrs: &[TemplateAttribute::Static { name: "class", value: "hello", namespace: None }],
+    children: &[TemplateNode::Dynamic { id: 0 }],
+};
+
+#[test]
+fn stable_id_is_content_addressed() {
+    let template =
+        Template { roots: std::slice::from_ref(&DIV), node_paths: &[&[0, 0]], attr_paths: &[] };
+    let copy = Template {
+        roots: std::slice::from_ref(&DIV_COPY),
+        node_paths: &[&[0, 0]],
+        attr_paths: &[],
+    };
+    let different =
+        Template { roots: std::slice::from_ref(&SPAN), node_paths: &[&[0, 0]], attr_paths: &[] };
+
+    // The templates live in different statics, but have the same structure
+    assert_ne!(template, copy);
+    assert_eq!(template.stable_id(), copy.stable_id());
+    assert_ne!(template.stable_id(), different.stable_id());
+}
+
+#[test]
+fn registry_interns_templates_with_the_same_structure() {
+    use dioxus::prelude::*;
+    use dioxus_core::TemplateRegistry;
+
+    fn card() -> Element {
+        rsx! { div { class: "card", "hello" } }
+    }
+
+    // The same markup in another function or crate gets its own static template
+    fn other_card() -> Element {
+        rsx! { div { class: "card", "hello" } }
+    }
+
+    fn list() -> Element {
+        rsx! { ul { li { "hello" } } }
+    }
+
+    let card = card().unwrap().template;
+    let other_card = other_card().unwrap().template;
+    let list = list().unwrap().template;
+    assert_ne!(card, other_card);
+
+    let mut registry = TemplateRegistry::default();
+    assert_eq!(registry.register(card), (0, true));
+    assert_eq!(registry.register(other_card), (0, false));
+    assert_eq!(registry.register(list), (1, true));
+    assert_eq!(registry.register(card), (0, false));
+    assert_eq!(registry.get(&other_card), Some(0));
+    assert_eq!(registry.len(), 2);
+}
@@ -931,3 +931,39 @@ fn nested_suspense_resolves_client() {
             )
         });
 }
+
+#[test]
+fn use_await_suspends_until_every_child_is_ready() {
+    fn app() -> Element {
+        rsx! {
+            SuspenseBoundary {
+                fallback: |_| rsx! { "loading" },
+                Delayed { value: 1, delay: 10 }
+                Delayed { value: 2, delay: 30 }
+            }
+        }
+    }
+
+    #[component]
+    fn Delayed(value: u64, delay: u64) -> Element {
+        let value = use_await(move || async move {
+            tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
+            value * 10
+        })?;
+        rsx! { "{value} " }
+    }
+
+    tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .unwrap()
+        .block_on(async {
+            let mut dom = VirtualDom::new(app);
+            dom.rebuild_in_place();
+            assert_eq!(dioxus_ssr::render(&dom), "loading");
+
+            dom.wait_for_suspense().await;
+            dom.render_immediate(&mut dioxus_core::NoOpMutations);
+            assert_eq!(dioxus_ssr::render(&dom), "10 20 ");
+        });
+}
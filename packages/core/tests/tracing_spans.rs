@@ -0,0 +1,98 @@
+//! The spans emitted with the `tracing-spans` feature
+
+use dioxus::html::SerializedHtmlEventConverter;
+use dioxus::prelude::*;
+use dioxus_core::{ElementId, NoOpMutations};
+use std::any::Any;
+use std::fmt::Debug;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::Subscriber;
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::Registry;
+
+/// Records the name and fields of every span that is created
+#[derive(Clone, Default)]
+struct SpanRecorder(Arc<Mutex<Vec<String>>>);
+
+impl SpanRecorder {
+    fn take(&self) -> Vec<String> {
+        std::mem::take(&mut self.0.lock().unwrap())
+    }
+}
+
+impl<S: Subscriber> Layer<S> for SpanRecorder {
+    fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+        struct Fields(String);
+        impl Visit for Fields {
+            fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+                self.0 += &format!(" {}={:?}", field.name(), value);
+            }
+            fn record_str(&mut self, field: &Field, value: &str) {
+                self.0 += &format!(" {}={}", field.name(), value);
+            }
+        }
+
+        let mut fields = Fields(attrs.metadata().name().to_string());
+        attrs.record(&mut fields);
+        self.0.lock().unwrap().push(fields.0);
+    }
+}
+
+#[test]
+fn renders_events_and_tasks_have_spans() {
+    let recorder = SpanRecorder::default();
+    let subscriber = Registry::default().with(recorder.clone());
+    let _guard = tracing::subscriber::set_default(subscriber);
+    set_event_converter(Box::new(SerializedHtmlEventConverter));
+
+    fn app() -> Element {
+        let mut count = use_signal(|| 0);
+        use_hook(|| spawn(async {}));
+        rsx! {
+            button { onclick: move |_| count += 1, "+" }
+            Child { count: count() }
+        }
+    }
+
+    #[component]
+    fn Child(count: i32) -> Element {
+        rsx! { "{count}" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let spans = recorder.take();
+    assert_span(&spans, "render", &["component=root ", "trigger=mount"]);
+    assert_span(&spans, "render", &["::Child ", "trigger=mount"]);
+    assert_span(&spans, "diff", &["::Child "]);
+
+    dom.render_immediate(&mut NoOpMutations);
+    let spans = recorder.take();
+    assert_span(&spans, "poll_task", &["component=root ", "spawned_at="]);
+
+    let event = Event::new(
+        Rc::new(PlatformEventData::new(Box::<SerializedMouseData>::default())) as Rc<dyn Any>,
+        true,
+    );
+    dom.runtime().handle_event("click", event, ElementId(1));
+    dom.render_immediate(&mut NoOpMutations);
+    let spans = recorder.take();
+    assert_span(&spans, "event", &["name=click element=1 bubbles=true"]);
+    assert_span(&spans, "listener", &["name=click component=root "]);
+    assert_span(&spans, "render", &["component=root ", "trigger=dirty"]);
+    assert_span(&spans, "render", &["::Child ", "trigger=props_changed"]);
+}
+
+/// Assert that a span with a name was created with fields that contain every part
+#[track_caller]
+fn assert_span(spans: &[String], name: &str, parts: &[&str]) {
+    let found = spans.iter().any(|span| {
+        span.split_once(' ').is_some_and(|(span_name, fields)| {
+            span_name == name && parts.iter().all(|part| fields.contains(part))
+        })
+    });
+    assert!(found, "no {name} span with {parts:?} in {spans:#?}");
+}
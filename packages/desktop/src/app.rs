@@ -138,6 +138,9 @@ impl App {
     #[cfg(all(feature = "devtools", debug_assertions))]
     pub fn connect_hotreload(&self) {
         if let Some(endpoint) = dioxus_cli_config::devserver_ws_endpoint() {
+            // Pick up the state the last build saved before it was replaced
+            dioxus_devtools::restore_snapshot_from_disk();
+
             let proxy = self.shared.proxy.clone();
             dioxus_devtools::connect(endpoint, move |msg| {
                 _ = proxy.send_event(UserWindowEvent::HotReloadEvent(msg));
@@ -230,6 +233,7 @@ impl App {
             .rebuild(&mut *view.edits.wry_queue.mutation_state_mut());
 
         view.edits.wry_queue.send_edits();
+        view.update_inspector();
 
         view.desktop_context
             .window
@@ -277,12 +281,12 @@ impl App {
                     }
                 }
             }
-            DevserverMsg::FullReloadCommand
-            | DevserverMsg::FullReloadStart
-            | DevserverMsg::FullReloadFailed => {
-                // usually only web gets this message - what are we supposed to do?
-                // Maybe we could just binary patch ourselves in place without losing window state?
+            // The devserver will replace this process once the rebuild finishes. Save the state of the app so the
+            // new build can pick up where we left off
+            DevserverMsg::FullReloadStart | DevserverMsg::FullReloadCommand => {
+                dioxus_devtools::save_snapshot_to_disk();
             }
+            DevserverMsg::FullReloadFailed => {}
             DevserverMsg::Shutdown => {
                 self.control_flow = ControlFlow::Exit;
             }
@@ -124,6 +124,35 @@ impl RenderedElementBacking for DesktopElement {
             }
         })
     }
+
+    fn get_computed_style(
+        &self,
+        property: &str,
+    ) -> std::pin::Pin<Box<dyn futures_util::Future<Output = dioxus_html::MountedResult<String>>>>
+    {
+        let script = format!(
+            "const node = window.interpreter.nodes[{}]; return node instanceof Element ? window.getComputedStyle(node).getPropertyValue({}) : null;",
+            self.id.0,
+            serde_json::to_string(property).expect("Failed to serialize the property")
+        );
+
+        let fut = self
+            .query
+            .new_query::<Option<String>>(&script, self.webview.clone())
+            .resolve();
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(Some(value)) => Ok(value),
+                Ok(None) => MountedResult::Err(dioxus_html::MountedError::OperationFailed(
+                    Box::new(DesktopQueryError::FailedToQuery),
+                )),
+                Err(err) => {
+                    MountedResult::Err(dioxus_html::MountedError::OperationFailed(Box::new(err)))
+                }
+            }
+        })
+    }
 }
 
 #[derive(Debug)]
@@ -9,11 +9,17 @@ use crate::{
 };
 use dioxus_core::{Runtime, ScopeId, VirtualDom};
 use dioxus_hooks::to_owned;
+#[cfg(feature = "broadcast")]
+use dioxus_html::broadcast::{BroadcastBackend, ProcessBroadcastBackend};
+#[cfg(debug_assertions)]
+use dioxus_html::replay::EventRecorder;
 use dioxus_html::{
     native_bind::NativeFileEngine, prelude::Document, HasFileData, HtmlEvent, PlatformEventData,
 };
 use futures_util::{pin_mut, FutureExt};
 use std::cell::OnceCell;
+#[cfg(debug_assertions)]
+use std::cell::RefCell;
 use std::sync::Arc;
 use std::{rc::Rc, task::Waker};
 use wry::{RequestAsyncResponder, WebContext, WebViewBuilder};
@@ -23,6 +29,9 @@ pub(crate) struct WebviewEdits {
     runtime: Rc<Runtime>,
     pub wry_queue: WryQueue,
     desktop_context: Rc<OnceCell<DesktopContext>>,
+    /// Records every event into the file in the `DIOXUS_RECORD_EVENTS` environment variable
+    #[cfg(debug_assertions)]
+    recorder: Rc<RefCell<Option<EventRecorder>>>,
 }
 
 impl WebviewEdits {
@@ -31,6 +40,8 @@ impl WebviewEdits {
             runtime,
             wry_queue,
             desktop_context: Default::default(),
+            #[cfg(debug_assertions)]
+            recorder: Rc::new(RefCell::new(EventRecorder::from_env())),
         }
     }
 
@@ -71,6 +82,11 @@ impl WebviewEdits {
     }
 
     pub fn handle_html_event(&self, event: HtmlEvent) -> SynchronousEventResponse {
+        #[cfg(debug_assertions)]
+        if let Some(recorder) = &mut *self.recorder.borrow_mut() {
+            recorder.record(&event);
+        }
+
         let HtmlEvent {
             element,
             name,
@@ -127,6 +143,10 @@ pub(crate) struct WebviewInstance {
     pub desktop_context: DesktopContext,
     pub waker: Waker,
 
+    /// Streams the component tree to the component inspector
+    #[cfg(all(feature = "inspector", debug_assertions))]
+    inspector: Option<dioxus_devtools::Inspector>,
+
     // Wry assumes the webcontext is alive for the lifetime of the webview.
     // We need to keep the webcontext alive, otherwise the webview will crash
     _web_context: WebContext,
@@ -346,6 +366,14 @@ impl WebviewInstance {
         dom.in_runtime(|| {
             ScopeId::ROOT.provide_context(desktop_context.clone());
             ScopeId::ROOT.provide_context(provider);
+            // Every window of the app runs in this process, so channels are shared between windows
+            #[cfg(feature = "broadcast")]
+            {
+                let broadcast: Rc<dyn BroadcastBackend> = Rc::new(ProcessBroadcastBackend);
+                ScopeId::ROOT.provide_context(broadcast);
+            }
+            #[cfg(feature = "focus")]
+            dioxus_html::focus::init_autofocus();
         });
 
         WebviewInstance {
@@ -353,6 +381,8 @@ impl WebviewInstance {
             edits,
             waker: tao_waker(shared.proxy.clone(), desktop_context.window.id()),
             desktop_context,
+            #[cfg(all(feature = "inspector", debug_assertions))]
+            inspector: dioxus_devtools::Inspector::from_env(),
             _menu: menu,
             _web_context: web_context,
         }
@@ -384,6 +414,15 @@ impl WebviewInstance {
             self.dom
                 .render_immediate(&mut *self.edits.wry_queue.mutation_state_mut());
             self.edits.wry_queue.send_edits();
+            self.update_inspector();
+        }
+    }
+
+    /// Send the changes to the component tree to the component inspector
+    pub(crate) fn update_inspector(&mut self) {
+        #[cfg(all(feature = "inspector", debug_assertions))]
+        if let Some(inspector) = &mut self.inspector {
+            inspector.update(&self.dom);
         }
     }
 
@@ -39,3 +39,51 @@ pub struct HotReloadMsg {
     pub assets: Vec<PathBuf>,
     pub unknown_files: Vec<PathBuf>,
 }
+
+/// A message the component inspector sends to the inspectors that are attached to an app
+///
+/// An inspector receives the whole [`InspectorMsg::Tree`] when it connects and again every time components are
+/// mounted or unmounted. Between those, it receives [`InspectorMsg::Rendered`] with the components that changed.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum InspectorMsg {
+    /// Every scope that is mounted in the app, sorted by id
+    Tree(Vec<ScopeSnapshot>),
+
+    /// The scopes that re-rendered or whose inspected signals changed since the last message
+    Rendered(Vec<ScopeSnapshot>),
+}
+
+/// The state of a component in the app
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct ScopeSnapshot {
+    /// The id of the scope
+    pub id: usize,
+
+    /// The id of the parent scope. Only the root scope doesn't have a parent.
+    pub parent: Option<usize>,
+
+    /// The name of the component
+    pub name: String,
+
+    /// The depth of the scope in the tree
+    pub height: u32,
+
+    /// The number of times the scope has rendered
+    pub generation: usize,
+
+    /// The debug representation of the props if the props implement Debug
+    pub props: Option<String>,
+
+    /// The signals of the component that are inspected
+    pub signals: Vec<SignalSnapshot>,
+}
+
+/// The value of an inspected signal
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct SignalSnapshot {
+    /// The label the signal was inspected with
+    pub label: String,
+
+    /// The debug representation of the value
+    pub value: String,
+}
@@ -0,0 +1,260 @@
+//! Inspect the component tree of a running app.
+//!
+//! Renderers start an [`Inspector`] in debug builds and call [`Inspector::update`] after every render. The inspector
+//! serves a websocket that streams [`InspectorMsg`]s as json so a standalone inspector or browser extension can show
+//! the scopes of the app, their props and the signals that are inspected with [`use_inspect`].
+//!
+//! Props show up in the inspector if they implement `Debug`. Signals have to be registered with [`use_inspect`]
+//! because they aren't tracked by the component that created them.
+
+use dioxus_core::prelude::{
+    current_scope_id, provide_root_context, try_consume_context, use_hook_with_cleanup,
+};
+use dioxus_core::{ScopeId, VirtualDom};
+use dioxus_devtools_types::{InspectorMsg, ScopeSnapshot, SignalSnapshot};
+use dioxus_signals::Readable;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::rc::Rc;
+
+type InspectFn = Box<dyn Fn() -> Option<String>>;
+
+struct InspectedSignal {
+    id: usize,
+    scope: ScopeId,
+    label: String,
+    read: InspectFn,
+}
+
+/// The signals inspected in one VirtualDom. This is a root context so apps that share a thread, like liveview
+/// sessions, only see their own signals.
+#[derive(Clone, Default)]
+struct InspectedSignals(Rc<RefCell<Vec<InspectedSignal>>>);
+
+thread_local! {
+    static NEXT_ID: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Show the value of a signal in the component inspector under a label.
+///
+/// The value is read without subscribing to it, so this doesn't cause any extra renders. It is cheap to call in
+/// release builds, but the value is only read when an inspector is running.
+///
+/// ```rust, ignore
+/// # use dioxus::prelude::*;
+/// fn app() -> Element {
+///     let mut count = use_signal(|| 0);
+///     use_inspect("count", count);
+///     rsx! {
+///         button { onclick: move |_| count += 1, "{count}" }
+///     }
+/// }
+/// ```
+pub fn use_inspect<R>(label: impl ToString, signal: R)
+where
+    R: Readable + 'static,
+    R::Target: Debug,
+{
+    use_hook_with_cleanup(
+        move || {
+            let inspected = try_consume_context::<InspectedSignals>()
+                .unwrap_or_else(|| provide_root_context(InspectedSignals::default()));
+            let id = NEXT_ID.replace(NEXT_ID.get() + 1);
+            let read: InspectFn = Box::new(move || {
+                let value = signal.try_peek().ok()?;
+                Some(format!("{:?}", &*value))
+            });
+            inspected.0.borrow_mut().push(InspectedSignal {
+                id,
+                scope: current_scope_id().unwrap_or(ScopeId::ROOT),
+                label: label.to_string(),
+                read,
+            });
+            (inspected, id)
+        },
+        |(inspected, id)| inspected.0.borrow_mut().retain(|signal| signal.id != id),
+    );
+}
+
+/// Take a snapshot of every scope in the VirtualDom, sorted by id
+pub fn snapshot_scopes(dom: &VirtualDom) -> Vec<ScopeSnapshot> {
+    let mut signals: HashMap<ScopeId, Vec<SignalSnapshot>> = HashMap::new();
+    dom.in_runtime(|| {
+        let Some(inspected) = ScopeId::ROOT.consume_context::<InspectedSignals>() else {
+            return;
+        };
+        for signal in inspected.0.borrow().iter() {
+            if let Some(value) = (signal.read)() {
+                signals
+                    .entry(signal.scope)
+                    .or_default()
+                    .push(SignalSnapshot {
+                        label: signal.label.clone(),
+                        value,
+                    });
+            }
+        }
+    });
+
+    let mut scopes: Vec<_> = dom
+        .scopes()
+        .map(|scope| ScopeSnapshot {
+            id: scope.id().0,
+            parent: scope.parent_id().map(|id| id.0),
+            name: scope.name().to_string(),
+            height: scope.height(),
+            generation: scope.generation(),
+            props: scope.props_snapshot(),
+            signals: signals.remove(&scope.id()).unwrap_or_default(),
+        })
+        .collect();
+    scopes.sort_by_key(|scope| scope.id);
+    scopes
+}
+
+/// Find the message that brings an inspector from the last snapshot to the current one, if anything changed
+pub fn diff_snapshots(last: &[ScopeSnapshot], current: &[ScopeSnapshot]) -> Option<InspectorMsg> {
+    let same_tree = last.len() == current.len()
+        && last
+            .iter()
+            .zip(current)
+            .all(|(a, b)| a.id == b.id && a.name == b.name);
+    if !same_tree {
+        return Some(InspectorMsg::Tree(current.to_vec()));
+    }
+
+    let changed: Vec<_> = last
+        .iter()
+        .zip(current)
+        .filter(|(a, b)| a != b)
+        .map(|(_, b)| b.clone())
+        .collect();
+    (!changed.is_empty()).then_some(InspectorMsg::Rendered(changed))
+}
+
+#[cfg(all(feature = "inspector", not(target_arch = "wasm32")))]
+pub use server::*;
+
+#[cfg(all(feature = "inspector", not(target_arch = "wasm32")))]
+mod server {
+    use super::*;
+    use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+    use std::sync::mpsc::{channel, Receiver, Sender};
+    use tungstenite::{Message, WebSocket};
+
+    /// The address the inspector listens on if `DIOXUS_INSPECTOR_ADDR` isn't set
+    pub const DEFAULT_INSPECTOR_ADDR: &str = "127.0.0.1:8079";
+
+    enum Event {
+        Connected(Box<WebSocket<TcpStream>>),
+        Msg(InspectorMsg),
+    }
+
+    /// A websocket server that streams the component tree of a VirtualDom to inspectors.
+    ///
+    /// This doesn't use any form of security, so it should only be started in debug builds.
+    pub struct Inspector {
+        addr: SocketAddr,
+        events: Sender<Event>,
+        last: Vec<ScopeSnapshot>,
+    }
+
+    impl Inspector {
+        /// Start the inspector on the address in the `DIOXUS_INSPECTOR_ADDR` environment variable, or on
+        /// [`DEFAULT_INSPECTOR_ADDR`]. Returns `None` if the address is already in use, for example by another window
+        /// of the same app.
+        pub fn from_env() -> Option<Self> {
+            let addr = std::env::var("DIOXUS_INSPECTOR_ADDR")
+                .unwrap_or_else(|_| DEFAULT_INSPECTOR_ADDR.to_string());
+            match Self::start(&addr) {
+                Ok(inspector) => Some(inspector),
+                Err(err) => {
+                    tracing::debug!("Failed to start the component inspector on {addr}: {err}");
+                    None
+                }
+            }
+        }
+
+        /// Start the inspector on an address
+        pub fn start(addr: impl ToSocketAddrs) -> std::io::Result<Self> {
+            let listener = TcpListener::bind(addr)?;
+            let addr = listener.local_addr()?;
+            tracing::info!("Component inspector listening on ws://{addr}");
+
+            let (events, rx) = channel();
+            let connected = events.clone();
+            std::thread::spawn(move || {
+                for stream in listener.incoming().flatten() {
+                    match tungstenite::accept(stream) {
+                        Ok(socket) => {
+                            if connected.send(Event::Connected(Box::new(socket))).is_err() {
+                                return;
+                            }
+                        }
+                        Err(err) => tracing::debug!("Failed to accept an inspector: {err}"),
+                    }
+                }
+            });
+            std::thread::spawn(move || broadcast(rx));
+
+            Ok(Self {
+                addr,
+                events,
+                last: Vec::new(),
+            })
+        }
+
+        /// The address the inspector is listening on
+        pub fn local_addr(&self) -> SocketAddr {
+            self.addr
+        }
+
+        /// Send the changes since the last update to every attached inspector. Call this after each render.
+        pub fn update(&mut self, dom: &VirtualDom) {
+            let current = snapshot_scopes(dom);
+            if let Some(msg) = diff_snapshots(&self.last, &current) {
+                _ = self.events.send(Event::Msg(msg));
+            }
+            self.last = current;
+        }
+    }
+
+    /// Send every message to the connected inspectors. New inspectors get the whole tree first.
+    fn broadcast(events: Receiver<Event>) {
+        let mut clients: Vec<Box<WebSocket<TcpStream>>> = Vec::new();
+        let mut tree: Vec<ScopeSnapshot> = Vec::new();
+
+        for event in events {
+            let msg = match event {
+                Event::Connected(mut socket) => {
+                    if send(&mut socket, &InspectorMsg::Tree(tree.clone())) {
+                        clients.push(socket);
+                    }
+                    continue;
+                }
+                Event::Msg(msg) => msg,
+            };
+
+            match &msg {
+                InspectorMsg::Tree(scopes) => tree = scopes.clone(),
+                InspectorMsg::Rendered(scopes) => {
+                    for scope in scopes {
+                        if let Some(old) = tree.iter_mut().find(|old| old.id == scope.id) {
+                            *old = scope.clone();
+                        }
+                    }
+                }
+            }
+            clients.retain_mut(|socket| send(socket, &msg));
+        }
+    }
+
+    /// Send a message to an inspector. Returns false if the inspector disconnected.
+    fn send(socket: &mut WebSocket<TcpStream>, msg: &InspectorMsg) -> bool {
+        let Ok(json) = serde_json::to_string(msg) else {
+            return true;
+        };
+        socket.send(Message::Text(json)).is_ok()
+    }
+}
@@ -3,6 +3,17 @@ pub use dioxus_devtools_types::*;
 use dioxus_signals::Writable;
 use warnings::Warning;
 
+mod snapshot;
+pub use snapshot::*;
+
+mod inspector;
+pub use inspector::*;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod profiler;
+#[cfg(not(target_arch = "wasm32"))]
+pub use profiler::*;
+
 /// Applies template and literal changes to the VirtualDom
 ///
 /// Assets need to be handled by the renderer.
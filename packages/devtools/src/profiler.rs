@@ -0,0 +1,222 @@
+//! Record how long each scope takes to render and diff.
+//!
+//! A [`Profiler`] is a [`RenderObserver`] that can be attached to any VirtualDom. It records every render and diff
+//! for the rest of the session and exports them as a [Chrome trace](https://ui.perfetto.dev) or as folded stacks for
+//! [flamegraph](https://github.com/brendangregg/FlameGraph) tools like `inferno-flamegraph`:
+//!
+//! ```rust, ignore
+//! let profiler = Profiler::new();
+//! let mut dom = VirtualDom::new(app).with_render_observer(profiler.clone());
+//! dom.rebuild_in_place();
+//!
+//! std::fs::write("trace.json", profiler.chrome_trace()).unwrap();
+//! std::fs::write("renders.folded", profiler.folded_stacks()).unwrap();
+//! ```
+
+use dioxus_core::{RenderObserver, RenderPhase, ScopeId};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+/// One render or diff of a scope
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileSpan {
+    /// The scope the work was done for
+    pub scope: ScopeId,
+    /// The name of the component the scope renders
+    pub name: &'static str,
+    /// The kind of work
+    pub phase: RenderPhase,
+    /// The time the span started, relative to the start of the session
+    pub start: Duration,
+    /// The total time the span took, including any nested spans
+    pub duration: Duration,
+    /// The time the span took, excluding any nested spans
+    pub self_duration: Duration,
+    /// The folded stack of the span, from the outermost span to this one, separated by `;`
+    pub stack: String,
+}
+
+/// The total time spent on a scope during the session
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScopeProfile {
+    /// The scope
+    pub scope: ScopeId,
+    /// The name of the component the scope renders
+    pub name: &'static str,
+    /// The number of times the component ran
+    pub renders: usize,
+    /// The total time spent running the component
+    pub render_time: Duration,
+    /// The total time spent creating and diffing the nodes of the scope, excluding the work for child components
+    pub diff_time: Duration,
+}
+
+struct OpenSpan {
+    scope: ScopeId,
+    name: &'static str,
+    phase: RenderPhase,
+    start: Instant,
+    children: Duration,
+}
+
+struct Recording {
+    started: Instant,
+    open: Vec<OpenSpan>,
+    spans: Vec<ProfileSpan>,
+}
+
+/// A [`RenderObserver`] that records the duration of every render and diff.
+///
+/// Clones of a profiler share the same recording, so one clone can be attached to the VirtualDom while another is
+/// used to export the results.
+#[derive(Clone)]
+pub struct Profiler {
+    recording: Rc<RefCell<Recording>>,
+}
+
+impl Default for Profiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Profiler {
+    /// Create a new profiler. Span start times are relative to the time the profiler is created.
+    pub fn new() -> Self {
+        Self {
+            recording: Rc::new(RefCell::new(Recording {
+                started: Instant::now(),
+                open: Vec::new(),
+                spans: Vec::new(),
+            })),
+        }
+    }
+
+    /// Every finished span in the order they finished
+    pub fn spans(&self) -> Vec<ProfileSpan> {
+        self.recording.borrow().spans.clone()
+    }
+
+    /// Forget every recorded span
+    pub fn clear(&self) {
+        self.recording.borrow_mut().spans.clear();
+    }
+
+    /// The time spent on each scope, sorted by the total time with the most expensive scope first
+    pub fn scopes(&self) -> Vec<ScopeProfile> {
+        let mut scopes: HashMap<ScopeId, ScopeProfile> = HashMap::new();
+        for span in &self.recording.borrow().spans {
+            let profile = scopes.entry(span.scope).or_insert_with(|| ScopeProfile {
+                scope: span.scope,
+                name: span.name,
+                renders: 0,
+                render_time: Duration::ZERO,
+                diff_time: Duration::ZERO,
+            });
+            match span.phase {
+                RenderPhase::Render => {
+                    profile.renders += 1;
+                    profile.render_time += span.duration;
+                }
+                RenderPhase::Diff => profile.diff_time += span.self_duration,
+            }
+        }
+
+        let mut scopes: Vec<_> = scopes.into_values().collect();
+        scopes.sort_by(|a, b| {
+            (b.render_time + b.diff_time)
+                .cmp(&(a.render_time + a.diff_time))
+                .then(a.scope.0.cmp(&b.scope.0))
+        });
+        scopes
+    }
+
+    /// Export the session in the Chrome trace event format. The trace can be opened in `chrome://tracing` or
+    /// [Perfetto](https://ui.perfetto.dev).
+    pub fn chrome_trace(&self) -> String {
+        let events: Vec<_> = self
+            .recording
+            .borrow()
+            .spans
+            .iter()
+            .map(|span| {
+                serde_json::json!({
+                    "name": format!("{} ({})", span.name, span.phase),
+                    "cat": span.phase.to_string(),
+                    "ph": "X",
+                    "ts": span.start.as_secs_f64() * 1_000_000.0,
+                    "dur": span.duration.as_secs_f64() * 1_000_000.0,
+                    "pid": 0,
+                    "tid": 0,
+                    "args": { "scope": span.scope.0 },
+                })
+            })
+            .collect();
+
+        serde_json::json!({ "traceEvents": events, "displayTimeUnit": "ms" }).to_string()
+    }
+
+    /// Export the session as folded stacks. Each line is a stack followed by the total self time of the stack in
+    /// microseconds.
+    pub fn folded_stacks(&self) -> String {
+        let mut stacks: BTreeMap<&str, u128> = BTreeMap::new();
+        let recording = self.recording.borrow();
+        for span in &recording.spans {
+            *stacks.entry(&span.stack).or_default() += span.self_duration.as_micros();
+        }
+
+        let mut folded = String::new();
+        for (stack, micros) in stacks {
+            _ = writeln!(folded, "{stack} {micros}");
+        }
+        folded
+    }
+}
+
+impl RenderObserver for Profiler {
+    fn phase_started(&mut self, scope: ScopeId, name: &'static str, phase: RenderPhase) {
+        self.recording.borrow_mut().open.push(OpenSpan {
+            scope,
+            name,
+            phase,
+            start: Instant::now(),
+            children: Duration::ZERO,
+        });
+    }
+
+    fn phase_finished(&mut self, scope: ScopeId, _name: &'static str, phase: RenderPhase) {
+        let end = Instant::now();
+        let mut recording = self.recording.borrow_mut();
+        let Some(span) = recording.open.pop() else {
+            return;
+        };
+        debug_assert!(span.scope == scope && span.phase == phase);
+
+        let duration = end - span.start;
+        if let Some(parent) = recording.open.last_mut() {
+            parent.children += duration;
+        }
+
+        let mut stack = String::new();
+        for frame in recording.open.iter().chain(std::iter::once(&span)) {
+            if !stack.is_empty() {
+                stack.push(';');
+            }
+            _ = write!(stack, "{} ({})", frame.name, frame.phase);
+        }
+
+        let start = span.start.saturating_duration_since(recording.started);
+        recording.spans.push(ProfileSpan {
+            scope,
+            name: span.name,
+            phase,
+            start,
+            duration,
+            self_duration: duration.saturating_sub(span.children),
+            stack,
+        });
+    }
+}
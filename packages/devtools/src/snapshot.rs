@@ -0,0 +1,144 @@
+//! Preserve state across full rebuilds.
+//!
+//! Hot reloading can't handle every change. When the devserver has to rebuild the app, the renderer snapshots every
+//! signal created with [`use_snapshot_signal`] and restores it after the app starts again so the user doesn't lose
+//! their place in the app on every code change.
+
+use dioxus_core::prelude::use_hook_with_cleanup;
+use dioxus_signals::{Readable, Signal};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// The serialized values of the snapshot signals in the app, keyed by the key passed to [`use_snapshot_signal`]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateSnapshot {
+    /// The json value of each signal
+    pub values: HashMap<String, String>,
+}
+
+impl StateSnapshot {
+    /// Check if the snapshot doesn't contain any values
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+type SaveFn = Box<dyn Fn() -> Option<String>>;
+
+#[derive(Default)]
+struct SnapshotRegistry {
+    /// Values restored from the last build that haven't been claimed by a signal yet
+    pending: HashMap<String, String>,
+    /// The signals that are currently mounted
+    mounted: HashMap<String, SaveFn>,
+}
+
+thread_local! {
+    static REGISTRY: RefCell<SnapshotRegistry> = RefCell::new(SnapshotRegistry::default());
+}
+
+/// Create a signal that keeps its value when the devserver fully rebuilds the app.
+///
+/// The value is serialized with serde when a rebuild starts and restored the next time a signal with the same key is
+/// created. The key must be unique across the app. If there is no value to restore, or it can no longer be
+/// deserialized because the type changed, the signal is created with `init`.
+///
+/// ```rust, ignore
+/// # use dioxus::prelude::*;
+/// fn app() -> Element {
+///     // The count survives full rebuilds while developing
+///     let mut count = use_snapshot_signal("count", || 0);
+///     rsx! {
+///         button { onclick: move |_| count += 1, "{count}" }
+///     }
+/// }
+/// ```
+pub fn use_snapshot_signal<T: Serialize + DeserializeOwned + 'static>(
+    key: impl ToString,
+    init: impl FnOnce() -> T,
+) -> Signal<T> {
+    use_hook_with_cleanup(
+        move || {
+            let key = key.to_string();
+            let restored = REGISTRY
+                .with_borrow_mut(|registry| registry.pending.remove(&key))
+                .and_then(|json| serde_json::from_str(&json).ok());
+            let signal = Signal::new(restored.unwrap_or_else(init));
+
+            let save: SaveFn = Box::new(move || {
+                let value = signal.try_peek().ok()?;
+                serde_json::to_string(&*value).ok()
+            });
+            REGISTRY.with_borrow_mut(|registry| registry.mounted.insert(key.clone(), save));
+
+            (key, signal)
+        },
+        |(key, _)| {
+            REGISTRY.with_borrow_mut(|registry| registry.mounted.remove(&key));
+        },
+    )
+    .1
+}
+
+/// Serialize the current value of every mounted snapshot signal
+pub fn take_snapshot() -> StateSnapshot {
+    REGISTRY.with_borrow(|registry| StateSnapshot {
+        values: registry
+            .mounted
+            .iter()
+            .filter_map(|(key, save)| Some((key.clone(), save()?)))
+            .collect(),
+    })
+}
+
+/// Restore a snapshot taken before the last rebuild. Signals created after this will start with the restored values.
+///
+/// This should be called before the app is rendered for the first time.
+pub fn restore_snapshot(snapshot: StateSnapshot) {
+    REGISTRY.with_borrow_mut(|registry| registry.pending.extend(snapshot.values));
+}
+
+/// The file desktop apps write their snapshot to while they are rebuilt. It is unique to each executable so multiple
+/// apps can be served at once.
+#[cfg(not(target_arch = "wasm32"))]
+fn snapshot_path() -> Option<std::path::PathBuf> {
+    let exe = std::env::current_exe().ok()?;
+    let name = exe.file_stem()?.to_string_lossy();
+    Some(std::env::temp_dir().join(format!("dioxus-hot-state-{name}.json")))
+}
+
+/// Write a snapshot of the mounted signals to disk so it can be restored after the app is restarted
+#[cfg(not(target_arch = "wasm32"))]
+pub fn save_snapshot_to_disk() {
+    let snapshot = take_snapshot();
+    let Some(path) = snapshot_path() else {
+        return;
+    };
+    if snapshot.is_empty() {
+        _ = std::fs::remove_file(path);
+        return;
+    }
+    if let Ok(json) = serde_json::to_string(&snapshot) {
+        if let Err(err) = std::fs::write(&path, json) {
+            tracing::warn!("Failed to save state before rebuilding: {err}");
+        }
+    }
+}
+
+/// Restore the snapshot saved by the last run of the app, if there is one. The file is removed so the state is only
+/// restored once.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn restore_snapshot_from_disk() {
+    let Some(path) = snapshot_path() else {
+        return;
+    };
+    let Ok(json) = std::fs::read_to_string(&path) else {
+        return;
+    };
+    _ = std::fs::remove_file(&path);
+    match serde_json::from_str(&json) {
+        Ok(snapshot) => restore_snapshot(snapshot),
+        Err(err) => tracing::warn!("Failed to restore state from the last build: {err}"),
+    }
+}
@@ -0,0 +1,140 @@
+use dioxus::prelude::*;
+use dioxus_devtools::{diff_snapshots, snapshot_scopes, use_inspect, InspectorMsg};
+
+#[derive(Props, Clone, PartialEq, Debug)]
+struct DebugProps {
+    label: String,
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct OpaqueProps {
+    value: i32,
+}
+
+fn app() -> Element {
+    let count = use_signal(|| 1);
+    use_inspect("count", count);
+    rsx! {
+        Labeled { label: "hello" }
+        Opaque { value: 1 }
+    }
+}
+
+#[allow(non_snake_case)]
+fn Labeled(props: DebugProps) -> Element {
+    rsx! { "{props.label}" }
+}
+
+#[allow(non_snake_case)]
+fn Opaque(props: OpaqueProps) -> Element {
+    rsx! { "{props.value}" }
+}
+
+#[test]
+fn snapshots_scopes_props_and_signals() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    let scopes = snapshot_scopes(&dom);
+    let find = |name: &str| {
+        scopes
+            .iter()
+            .find(|scope| scope.name.ends_with(name))
+            .unwrap_or_else(|| panic!("{name} is not in {scopes:#?}"))
+    };
+
+    let app = scopes
+        .iter()
+        .find(|scope| scope.id == ScopeId::APP.0)
+        .unwrap();
+    assert_eq!(app.signals.len(), 1);
+    assert_eq!(app.signals[0].label, "count");
+    assert_eq!(app.signals[0].value, "1");
+
+    let labeled = find("Labeled");
+    assert_eq!(labeled.parent, Some(app.id));
+    assert_eq!(labeled.height, app.height + 1);
+    assert_eq!(labeled.generation, 1);
+    assert!(labeled.props.as_ref().unwrap().contains("\"hello\""));
+
+    assert_eq!(find("Opaque").props, None);
+}
+
+#[test]
+fn signals_are_only_inspected_in_their_own_dom() {
+    fn other() -> Element {
+        let name = use_signal(|| "other");
+        use_inspect("name", name);
+        rsx! { "{name}" }
+    }
+
+    let mut first = VirtualDom::new(app);
+    first.rebuild_in_place();
+    let mut second = VirtualDom::new(other);
+    second.rebuild_in_place();
+
+    let signals = |dom: &VirtualDom| {
+        snapshot_scopes(dom)
+            .into_iter()
+            .flat_map(|scope| scope.signals)
+            .map(|signal| signal.label)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(signals(&first), ["count"]);
+    assert_eq!(signals(&second), ["name"]);
+
+    drop(second);
+    assert_eq!(signals(&first), ["count"]);
+}
+
+#[test]
+fn diffs_only_send_changes() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let first = snapshot_scopes(&dom);
+
+    assert!(matches!(
+        diff_snapshots(&[], &first),
+        Some(InspectorMsg::Tree(_))
+    ));
+    assert_eq!(diff_snapshots(&first, &first), None);
+
+    dom.mark_dirty(ScopeId::APP);
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    let second = snapshot_scopes(&dom);
+
+    let Some(InspectorMsg::Rendered(rendered)) = diff_snapshots(&first, &second) else {
+        panic!("expected the app to re-render");
+    };
+    assert_eq!(rendered.len(), 1);
+    assert_eq!(rendered[0].id, ScopeId::APP.0);
+    assert_eq!(rendered[0].generation, 2);
+}
+
+#[cfg(feature = "inspector")]
+#[test]
+fn inspectors_receive_the_tree() {
+    use dioxus_devtools::Inspector;
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    let mut inspector = Inspector::start("127.0.0.1:0").unwrap();
+    inspector.update(&dom);
+    let addr = inspector.local_addr();
+
+    let (mut socket, _) = tungstenite::connect(format!("ws://{addr}")).unwrap();
+    let msg: InspectorMsg =
+        serde_json::from_str(&socket.read().unwrap().into_text().unwrap()).unwrap();
+    let InspectorMsg::Tree(scopes) = msg else {
+        panic!("expected the tree first");
+    };
+    assert!(scopes.iter().any(|scope| scope.name.ends_with("Labeled")));
+
+    dom.mark_dirty(ScopeId::APP);
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    inspector.update(&dom);
+    let msg: InspectorMsg =
+        serde_json::from_str(&socket.read().unwrap().into_text().unwrap()).unwrap();
+    assert!(matches!(msg, InspectorMsg::Rendered(_)));
+}
@@ -0,0 +1,56 @@
+use dioxus::prelude::*;
+use dioxus_core::{NoOpMutations, RenderPhase};
+use dioxus_devtools::Profiler;
+
+fn app() -> Element {
+    rsx! {
+        Child { count: generation() }
+    }
+}
+
+#[component]
+fn Child(count: usize) -> Element {
+    rsx! { "{count}" }
+}
+
+#[test]
+fn records_renders_and_diffs() {
+    let profiler = Profiler::new();
+    let mut dom = VirtualDom::new(app).with_render_observer(profiler.clone());
+    dom.rebuild_in_place();
+
+    dom.mark_dirty(ScopeId::APP);
+    dom.render_immediate(&mut NoOpMutations);
+
+    let child = profiler
+        .scopes()
+        .into_iter()
+        .find(|scope| scope.name.ends_with("Child"))
+        .expect("the child scope is profiled");
+    assert_eq!(child.renders, 2);
+
+    // The child is created while its parent is created, so its spans are nested in the parent's
+    let spans = profiler.spans();
+    let created = spans
+        .iter()
+        .find(|span| span.scope == child.scope && span.phase == RenderPhase::Diff)
+        .unwrap();
+    assert!(created.stack.starts_with("app (diff);"));
+    assert!(created.stack.ends_with("Child (diff)"));
+    for span in &spans {
+        assert!(span.self_duration <= span.duration);
+    }
+
+    let folded = profiler.folded_stacks();
+    assert!(folded
+        .lines()
+        .any(|line| line.contains("Child (render) ") && line.contains(';')));
+
+    let trace: serde_json::Value = serde_json::from_str(&profiler.chrome_trace()).unwrap();
+    let events = trace["traceEvents"].as_array().unwrap();
+    assert_eq!(events.len(), spans.len());
+    assert!(events.iter().all(|event| event["ph"] == "X"));
+
+    profiler.clear();
+    assert!(profiler.spans().is_empty());
+}
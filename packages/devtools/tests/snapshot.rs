@@ -0,0 +1,31 @@
+use dioxus_core::prelude::*;
+use dioxus_devtools::{restore_snapshot, take_snapshot, use_snapshot_signal};
+
+fn app() -> Element {
+    let mut count = use_snapshot_signal("count", || 0);
+    // Increment the count once each time the app starts
+    use_hook(move || count += 1);
+    VNode::empty()
+}
+
+#[test]
+fn snapshot_signals_survive_rebuilds() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    let snapshot = take_snapshot();
+    assert_eq!(snapshot.values.get("count").map(String::as_str), Some("1"));
+
+    // Dropping the dom unmounts the signal
+    drop(dom);
+    assert!(take_snapshot().is_empty());
+
+    // The next dom starts from the restored value
+    restore_snapshot(snapshot);
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert_eq!(
+        take_snapshot().values.get("count").map(String::as_str),
+        Some("2")
+    );
+}
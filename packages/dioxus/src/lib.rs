@@ -6,10 +6,33 @@
 //!
 //! - `signals`: (default) re-exports `dioxus-signals`
 //! - `macro`: (default) re-exports `dioxus-macro`
+//! - `html-macro`: exports the `html!` macro that converts literal html into rsx at compile time
+//! - `unkeyed-lint`: warns about components rendered in a `for` loop without a key in rsx
 //! - `html`: (default) exports `dioxus-html` as the default elements to use in rsx
 //! - `hooks`: (default) re-exports `dioxus-hooks`
+//! - `asset`: (default) exports the `asset!` macro that registers a static file with the build and returns its hashed url, like `asset!("./assets/logo.png")`. Images can be resized and converted at build time with `asset!("./assets/logo.png".size(52, 52).format(ImageType::Avif))`
 //! - `hot-reload`: (default) enables hot rsx reloading in all renderers that support it
 //! - `router`: exports the [router](https://dioxuslabs.com/learn/0.5/router) and enables any router features for the current platform
+//! - `i18n`: exports [dioxus-i18n](https://docs.rs/dioxus-i18n) which translates the app with fluent or json bundles and the compile time checked `t!` macro
+//! - `storage`: exports [dioxus-storage](https://docs.rs/dioxus-storage) with the `use_indexed_db` store of typed values and the `use_persistent_signal` hook that keeps signals across restarts of the app
+//! - `outbox`: exports the `use_outbox` hook from `dioxus-fullstack` that queues server function mutations while the app is offline and sends them when the connection returns
+//! - `openapi-schemas`: lets the OpenAPI description from `dioxus-fullstack` derive the schemas of server functions from their argument and return types with `schemars`
+//! - `inspector`: serves the component tree, props and inspected signals over a websocket in debug builds of desktop and liveview apps so a component inspector can attach to them. The address is read from `DIOXUS_INSPECTOR_ADDR`
+//! - `focus`: exports the `use_focus` hook, the `FocusTrap` component and roving tabindex helpers from `dioxus-html`, and focuses elements with the `autofocus` attribute in the web, desktop and liveview renderers
+//! - `toast`: exports the `Toaster` component and the `use_toast` hook from `dioxus-html` that show queued notifications on top of the app
+//! - `lazy-image`: exports the lazily loaded `Image` component with blurred previews from `dioxus-html`
+//! - `presence`: exports the `AnimatePresence` and `TransitionGroup` components from `dioxus-html` that keep elements mounted until their exit animation finishes
+//! - `webrtc`: exports the `use_webrtc` hook from `dioxus-html` that manages peer to peer connections and data channels
+//! - `canvas`: exports the `Canvas` component from `dioxus-html` that draws with a platform agnostic 2D command API
+//! - `media`: exports the `use_audio_player` and `use_audio_recorder` hooks from `dioxus-html`
+//! - `chart`: exports the SVG `Chart` component, axes and series from `dioxus-html`
+//! - `portal`: exports the `Portal` component from `dioxus-html` that renders its children into a different element of the page
+//! - `global-events`: exports the `WindowListener` and `DocumentListener` components from `dioxus-html`
+//! - `speech`: exports the `use_speech_synthesis` and `use_speech_recognition` hooks from `dioxus-html`
+//! - `broadcast`: exports the `use_broadcast_channel` hook from `dioxus-html` that sends typed messages to the other tabs and windows of the app
+//! - `a11y`: exports the accessibility audit from `dioxus-html` and lets the web and ssr renderers log problems like images without alt text in debug builds
+//! - `aria`: exports the typed ARIA roles and attribute helpers from `dioxus-html`
+//! - `controlled-input`: exports the `use_controlled_input` hook from `dioxus-html` that keeps the cursor in place when an input rewrites its value
 //! - `third-party-renderer`: Just disables warnings about no active platform when no renderers are enabled
 //!
 //! Platform features (the current platform determines what platform the [`launch()`] function runs):
@@ -79,6 +102,14 @@ pub mod prelude {
     #[allow(deprecated)]
     pub use dioxus_core_macro::{component, rsx, Props};
 
+    #[cfg(feature = "html-macro")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "html-macro")))]
+    pub use dioxus_core_macro::html;
+
+    #[cfg(all(feature = "macro", feature = "document"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "macro", feature = "document"))))]
+    pub use dioxus_core_macro::css;
+
     #[cfg(feature = "launch")]
     #[cfg_attr(docsrs, doc(cfg(feature = "launch")))]
     pub use dioxus_config_macro::*;
@@ -98,6 +129,10 @@ pub mod prelude {
     #[cfg_attr(docsrs, doc(cfg(feature = "devtools")))]
     pub use dioxus_devtools;
 
+    #[cfg(feature = "devtools")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "devtools")))]
+    pub use dioxus_devtools::use_snapshot_signal;
+
     pub use dioxus_core;
 
     #[cfg(feature = "fullstack")]
@@ -119,6 +154,18 @@ pub mod prelude {
     #[cfg_attr(docsrs, doc(cfg(feature = "router")))]
     pub use dioxus_router::prelude::*;
 
+    #[cfg(feature = "i18n")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "i18n")))]
+    pub use dioxus_i18n;
+
+    #[cfg(feature = "i18n")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "i18n")))]
+    pub use dioxus_i18n::prelude::*;
+
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    pub use dioxus_storage::prelude::*;
+
     #[cfg(feature = "axum")]
     #[cfg_attr(docsrs, doc(cfg(feature = "axum")))]
     pub use axum;
@@ -136,6 +183,14 @@ pub use dioxus_web as web;
 #[cfg_attr(docsrs, doc(cfg(feature = "router")))]
 pub use dioxus_router as router;
 
+#[cfg(feature = "i18n")]
+#[cfg_attr(docsrs, doc(cfg(feature = "i18n")))]
+pub use dioxus_i18n as i18n;
+
+#[cfg(feature = "storage")]
+#[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+pub use dioxus_storage as storage;
+
 #[cfg(feature = "fullstack")]
 #[cfg_attr(docsrs, doc(cfg(feature = "fullstack")))]
 pub use dioxus_fullstack as fullstack;
@@ -137,6 +137,49 @@ pub trait DioxusRouterExt<S> {
     where
         Self: Sized;
 
+    /// Serves a robots.txt and sitemap.xml generated from the [`SeoConfig`]. Files with the same name in the public directory take precedence.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// # use dioxus_lib::prelude::*;
+    /// # use dioxus_fullstack::prelude::*;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let addr = std::net::SocketAddr::from(([127, 0, 0, 1], 8080));
+    ///     let router = axum::Router::new()
+    ///         // Serve robots.txt and sitemap.xml for the listed routes
+    ///         .serve_seo_files(SeoConfig::new("https://example.com").routes(["/", "/blog"]))
+    ///         .into_make_service();
+    ///     let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
+    ///     axum::serve(listener, router).await.unwrap();
+    /// }
+    /// ```
+    fn serve_seo_files(self, seo: SeoConfig) -> Self
+    where
+        Self: Sized;
+
+    /// Serves an [OpenAPI](https://spec.openapis.org/oas/v3.1.0) description of all registered server functions as JSON at the given path.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// # use dioxus_lib::prelude::*;
+    /// # use dioxus_fullstack::prelude::*;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let addr = std::net::SocketAddr::from(([127, 0, 0, 1], 8080));
+    ///     let router = axum::Router::new()
+    ///         .register_server_functions()
+    ///         // Describe the server functions at /openapi.json
+    ///         .serve_openapi("/openapi.json", OpenApi::new("My API", "1.0.0"))
+    ///         .into_make_service();
+    ///     let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
+    ///     axum::serve(listener, router).await.unwrap();
+    /// }
+    /// ```
+    fn serve_openapi(self, path: &str, openapi: OpenApi) -> Self
+    where
+        Self: Sized;
+
     /// Serves the Dioxus application. This will serve a complete server side rendered application.
     /// This will serve static assets, server render the application, register server functions, and integrate with hot reloading.
     ///
@@ -243,13 +286,71 @@ where
         self
     }
 
+    fn serve_openapi(self, path: &str, openapi: OpenApi) -> Self {
+        // The server functions are all registered before the server starts, so the document only needs to be created once
+        let document = Arc::new(std::sync::OnceLock::new());
+        self.route(
+            path,
+            get(move || {
+                let document = document.clone();
+                async move {
+                    let document = document.get_or_init(|| openapi.to_json().to_string());
+                    ([(CONTENT_TYPE, "application/json")], document.clone())
+                }
+            }),
+        )
+    }
+
+    fn serve_seo_files(mut self, seo: SeoConfig) -> Self {
+        let public_path = crate::public_path();
+        let seo = Arc::new(seo);
+
+        if seo.robots_txt && !public_path.join("robots.txt").exists() {
+            let seo = seo.clone();
+            self = self.route(
+                "/robots.txt",
+                get(move || {
+                    let seo = seo.clone();
+                    async move { ([(CONTENT_TYPE, "text/plain")], seo.render_robots_txt()) }
+                }),
+            );
+        }
+
+        if seo.sitemap_xml && !public_path.join("sitemap.xml").exists() {
+            self = self.route(
+                "/sitemap.xml",
+                get(move || {
+                    let seo = seo.clone();
+                    async move {
+                        (
+                            [(CONTENT_TYPE, "application/xml")],
+                            seo.render_sitemap_xml(),
+                        )
+                    }
+                }),
+            );
+        }
+
+        self
+    }
+
     fn serve_dioxus_application(self, cfg: impl Into<ServeConfig>, app: fn() -> Element) -> Self {
         let cfg = cfg.into();
 
         let ssr_state = SSRState::new(&cfg);
 
+        let mut server = self;
+        if let Some(seo) = &cfg.seo {
+            server = server.serve_seo_files(seo.clone());
+        }
+
         // Add server functions and render index.html
-        let server = self.serve_static_assets().register_server_functions();
+        let server = server
+            .serve_static_assets()
+            .register_server_functions_with_context(incremental_cache_context(
+                &ssr_state,
+                Default::default(),
+            ));
 
         server.fallback(
             get(render_handler)
@@ -258,6 +359,24 @@ where
     }
 }
 
+/// Add the incremental cache from the [`SSRState`] (if any) to a set of context providers so server functions can invalidate cached routes
+pub(crate) fn incremental_cache_context(
+    ssr_state: &SSRState,
+    context_providers: ContextProviders,
+) -> ContextProviders {
+    let Some(cache) = ssr_state.incremental_cache() else {
+        return context_providers;
+    };
+    let mut providers: Vec<
+        Box<dyn Fn() -> Box<dyn std::any::Any + Send + Sync + 'static> + Send + Sync + 'static>,
+    > = vec![Box::new(move || Box::new(cache.clone()))];
+    for index in 0..context_providers.len() {
+        let context_providers = context_providers.clone();
+        providers.push(Box::new(move || (context_providers[index])()));
+    }
+    Arc::new(providers)
+}
+
 fn apply_request_parts_to_response<B>(
     headers: hyper::header::HeaderMap,
     response: &mut axum::response::Response<B>,
@@ -404,7 +523,7 @@ fn report_err<E: std::fmt::Display>(e: E) -> Response<axum::body::Body> {
 
 /// A handler for Dioxus server functions. This will run the server function and return the result.
 async fn handle_server_fns_inner(
-    path: &str,
+    path: &'static str,
     additional_context: impl Fn(&DioxusServerContext) + 'static + Clone + Send,
     req: Request<Body>,
 ) -> impl IntoResponse {
@@ -431,9 +550,20 @@ async fn handle_server_fns_inner(
                 .unwrap_or(false);
             let referrer = req.headers().get(REFERER).cloned();
 
+            let method = req.method().clone();
+            let start = std::time::Instant::now();
+
             // actually run the server fn (which may use the server context)
             let mut res = ProvideServerContext::new(service.run(req), server_context.clone()).await;
 
+            // if the server function set a status code (for example with a `CustomError`), apply it to the response.
+            // Errors must keep an error status so the client knows to decode the body as an error
+            let status = server_context.response_parts().status;
+            let is_error = res.headers().contains_key(server_fn::error::SERVER_FN_ERROR_HEADER);
+            if status != StatusCode::OK && (!is_error || status.is_client_error() || status.is_server_error()) {
+                *res.status_mut() = status;
+            }
+
             // it it accepts text/html (i.e., is a plain form post) and doesn't already have a
             // Location set, then redirect to Referer
             if accepts_html {
@@ -450,6 +580,14 @@ async fn handle_server_fns_inner(
             let mut res_options = server_context.response_parts_mut();
             res.headers_mut().extend(res_options.headers.drain());
 
+            crate::server_fn_metrics::report_server_fn_call(ServerFnMetrics {
+                path,
+                method,
+                status: res.status(),
+                latency: start.elapsed(),
+                error: is_error || res.status().is_server_error(),
+            });
+
             Ok(res)
         } else {
             Response::builder().status(StatusCode::BAD_REQUEST).body(
@@ -0,0 +1,412 @@
+//! The client server functions use to call the server. The client can be configured with a base URL, default headers,
+//! request/response interceptors and a retry policy with [`set_server_fn_client`].
+
+use server_fn::{client::Client, error::NoCustomError, response::ClientRes, ServerFnError};
+use std::{
+    future::Future,
+    sync::{Arc, RwLock},
+};
+
+#[cfg(feature = "desktop")]
+type InnerClient = server_fn::client::reqwest::ReqwestClient;
+#[cfg(not(feature = "desktop"))]
+type InnerClient = server_fn::client::browser::BrowserClient;
+
+/// The request type server functions send on the current platform. This is a `reqwest` request on desktop and a `fetch`
+/// request everywhere else.
+pub type ServerFnRequest = <InnerClient as Client<NoCustomError>>::Request;
+
+type RequestInterceptor = Arc<dyn Fn(&mut ServerFnRequest) + Send + Sync>;
+type ResponseInterceptor = Arc<dyn Fn(&ServerFnResponseInfo) + Send + Sync>;
+
+static CLIENT_CONFIG: RwLock<Option<Arc<ServerFnClientConfig>>> = RwLock::new(None);
+
+/// Information about a response to a server function call, passed to [`ServerFnClientConfig::on_response`].
+#[derive(Debug, Clone)]
+pub struct ServerFnResponseInfo {
+    /// The HTTP status code of the response
+    pub status: u16,
+    /// The status text of the response
+    pub status_text: String,
+}
+
+/// The configuration for the client server functions use to call the server.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// set_server_fn_client(
+///     ServerFnClientConfig::new()
+///         // The API is hosted on a different origin than the app
+///         .base_url("https://api.example.com")
+///         .header("x-api-version", "2")
+///         .include_credentials(true)
+///         // Retry requests that fail because of a network error
+///         .retries(2)
+///         .on_response(|res| {
+///             if res.status == 401 {
+///                 tracing::warn!("Session expired");
+///             }
+///         }),
+/// );
+/// ```
+#[derive(Clone, Default)]
+pub struct ServerFnClientConfig {
+    base_url: Option<String>,
+    headers: Vec<(String, String)>,
+    include_credentials: bool,
+    retries: usize,
+    on_request: Vec<RequestInterceptor>,
+    on_response: Vec<ResponseInterceptor>,
+}
+
+impl ServerFnClientConfig {
+    /// Create a new client configuration with the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the URL all server function paths are relative to. Defaults to the origin the app is served from.
+    ///
+    /// The base URL can only be set once. Calling [`set_server_fn_client`] again with a different base URL has no effect.
+    pub fn base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
+    /// Add a header that is sent with every server function request.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Send credentials (cookies, authorization headers) with requests to other origins.
+    ///
+    /// This is web only. It sets the credentials mode of the `fetch` request. Desktop requests are sent with `reqwest`,
+    /// which has no credentials mode, so this setting is ignored there and a warning is logged when the client is set.
+    pub fn include_credentials(mut self, include_credentials: bool) -> Self {
+        self.include_credentials = include_credentials;
+        self
+    }
+
+    /// Set the number of times a request is retried if it fails because of a network error. Defaults to 0.
+    ///
+    /// Requests that reach the server are never retried, even if the server function returns an error.
+    pub fn retries(mut self, retries: usize) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Add a callback that can modify every request before it is sent.
+    pub fn on_request(mut self, f: impl Fn(&mut ServerFnRequest) + Send + Sync + 'static) -> Self {
+        self.on_request.push(Arc::new(f));
+        self
+    }
+
+    /// Add a callback that is run with every response the server sends back.
+    pub fn on_response(
+        mut self,
+        f: impl Fn(&ServerFnResponseInfo) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_response.push(Arc::new(f));
+        self
+    }
+
+    fn prepare(&self, mut req: ServerFnRequest) -> ServerFnRequest {
+        if self.include_credentials {
+            req = platform::include_credentials(req);
+        }
+        for (name, value) in &self.headers {
+            platform::set_header(&mut req, name, value);
+        }
+        for on_request in &self.on_request {
+            on_request(&mut req);
+        }
+        req
+    }
+}
+
+/// Set the configuration for the client server functions use to call the server. This should be called before any
+/// server functions are called, typically at the start of `main`.
+pub fn set_server_fn_client(config: ServerFnClientConfig) {
+    #[cfg(feature = "desktop")]
+    if config.include_credentials {
+        tracing::warn!("`include_credentials` only affects server function requests on the web and is ignored on desktop");
+    }
+    if let Some(base_url) = &config.base_url {
+        let current = server_fn::client::get_server_url();
+        if current.is_empty() {
+            server_fn::client::set_server_url(Box::leak(base_url.clone().into_boxed_str()));
+        } else if current != base_url {
+            tracing::warn!(
+                "The server function base url is already set to {current:?}. Ignoring {base_url:?}"
+            );
+        }
+    }
+    *CLIENT_CONFIG.write().unwrap() = Some(Arc::new(config));
+}
+
+fn client_config() -> Option<Arc<ServerFnClientConfig>> {
+    CLIENT_CONFIG.read().unwrap().clone()
+}
+
+/// The client used by `#[server]` functions unless a different `client` is passed to the macro. It sends requests with
+/// the platform client and applies the configuration set with [`set_server_fn_client`].
+pub struct ServerFnClient;
+
+impl<CustErr> Client<CustErr> for ServerFnClient {
+    type Request = ServerFnRequest;
+    type Response = <InnerClient as Client<CustErr>>::Response;
+
+    fn send(
+        req: Self::Request,
+    ) -> impl Future<Output = Result<Self::Response, ServerFnError<CustErr>>> + Send {
+        send_with_config::<InnerClient, CustErr>(client_config(), req)
+    }
+}
+
+/// Send a request with a client after applying the interceptors, headers and retry policy of the configuration
+fn send_with_config<C, CustErr>(
+    config: Option<Arc<ServerFnClientConfig>>,
+    req: ServerFnRequest,
+) -> impl Future<Output = Result<C::Response, ServerFnError<CustErr>>> + Send
+where
+    C: Client<CustErr, Request = ServerFnRequest>,
+{
+    async move {
+        let Some(config) = config else {
+            return C::send(req).await;
+        };
+
+        let mut req = config.prepare(req);
+        let mut retries_left = config.retries;
+        let res = loop {
+            let retry = match retries_left {
+                0 => None,
+                _ => {
+                    let (original, copy) = platform::duplicate(req);
+                    req = original;
+                    copy
+                }
+            };
+            match C::send(req).await {
+                Err(ServerFnError::Request(err)) => match retry {
+                    Some(retry) => {
+                        tracing::trace!("Retrying server function request after error: {err}");
+                        retries_left -= 1;
+                        req = retry;
+                    }
+                    None => break Err(ServerFnError::Request(err)),
+                },
+                res => break res,
+            }
+        };
+
+        if let Ok(res) = &res {
+            let info = ServerFnResponseInfo {
+                status: <C::Response as ClientRes<CustErr>>::status(res),
+                status_text: <C::Response as ClientRes<CustErr>>::status_text(res),
+            };
+            for on_response in &config.on_response {
+                on_response(&info);
+            }
+        }
+
+        res
+    }
+}
+
+#[cfg(feature = "desktop")]
+mod platform {
+    use super::ServerFnRequest;
+
+    pub(super) fn set_header(req: &mut ServerFnRequest, name: &str, value: &str) {
+        match (
+            http::HeaderName::from_bytes(name.as_bytes()),
+            http::HeaderValue::from_str(value),
+        ) {
+            (Ok(name), Ok(value)) => {
+                req.headers_mut().insert(name, value);
+            }
+            _ => tracing::error!("Invalid server function header {name:?}: {value:?}"),
+        }
+    }
+
+    pub(super) fn include_credentials(req: ServerFnRequest) -> ServerFnRequest {
+        // Cookies are configured on the reqwest client itself
+        req
+    }
+
+    pub(super) fn duplicate(req: ServerFnRequest) -> (ServerFnRequest, Option<ServerFnRequest>) {
+        let copy = req.try_clone();
+        (req, copy)
+    }
+}
+
+#[cfg(not(feature = "desktop"))]
+mod platform {
+    use super::ServerFnRequest;
+
+    pub(super) fn set_header(req: &mut ServerFnRequest, name: &str, value: &str) {
+        req.headers().set(name, value);
+    }
+
+    #[cfg(feature = "web")]
+    pub(super) fn include_credentials(req: ServerFnRequest) -> ServerFnRequest {
+        let raw: web_sys::Request = req.into();
+        let init = web_sys::RequestInit::new();
+        init.set_credentials(web_sys::RequestCredentials::Include);
+        let raw = web_sys::Request::new_with_request_and_init(&raw, &init).unwrap_or(raw);
+        server_fn::request::browser::Request::from(raw).into()
+    }
+
+    #[cfg(not(feature = "web"))]
+    pub(super) fn include_credentials(req: ServerFnRequest) -> ServerFnRequest {
+        req
+    }
+
+    #[cfg(feature = "web")]
+    pub(super) fn duplicate(req: ServerFnRequest) -> (ServerFnRequest, Option<ServerFnRequest>) {
+        let raw: web_sys::Request = req.into();
+        let copy = web_sys::Request::clone(&raw)
+            .ok()
+            .map(|copy| server_fn::request::browser::Request::from(copy).into());
+        (server_fn::request::browser::Request::from(raw).into(), copy)
+    }
+
+    #[cfg(not(feature = "web"))]
+    pub(super) fn duplicate(req: ServerFnRequest) -> (ServerFnRequest, Option<ServerFnRequest>) {
+        (req, None)
+    }
+}
+
+#[cfg(all(test, feature = "desktop"))]
+mod tests {
+    use super::*;
+    use futures_util::FutureExt;
+    use server_fn::request::ClientReq;
+    use std::sync::Mutex;
+
+    type Response = <InnerClient as Client<NoCustomError>>::Response;
+
+    /// The headers of every request the fake client received
+    static REQUESTS: Mutex<Vec<http::HeaderMap>> = Mutex::new(Vec::new());
+
+    /// A client that fails with a network error for the first `x-failures` requests of the `x-test` test
+    struct FakeClient;
+
+    impl Client<NoCustomError> for FakeClient {
+        type Request = ServerFnRequest;
+        type Response = Response;
+
+        fn send(
+            req: ServerFnRequest,
+        ) -> impl Future<Output = Result<Response, ServerFnError>> + Send {
+            let headers = req.headers().clone();
+            let failures = headers
+                .get("x-failures")
+                .map_or(0, |failures| failures.to_str().unwrap().parse().unwrap());
+            let test = headers["x-test"].clone();
+            let attempt = {
+                let mut requests = REQUESTS.lock().unwrap();
+                requests.push(headers);
+                requests
+                    .iter()
+                    .filter(|headers| headers["x-test"] == test)
+                    .count()
+            };
+            let res = if attempt <= failures {
+                Err(ServerFnError::Request("offline".to_string()))
+            } else {
+                Ok(http::Response::builder()
+                    .status(201)
+                    .body(String::new())
+                    .unwrap()
+                    .into())
+            };
+            async move { res }
+        }
+    }
+
+    fn requests(test: &str) -> Vec<http::HeaderMap> {
+        let requests = REQUESTS.lock().unwrap();
+        requests
+            .iter()
+            .filter(|headers| headers["x-test"] == test)
+            .cloned()
+            .collect()
+    }
+
+    fn send(config: ServerFnClientConfig) -> Result<Response, ServerFnError> {
+        static SERVER_URL: std::sync::Once = std::sync::Once::new();
+        SERVER_URL.call_once(|| server_fn::client::set_server_url("http://localhost:8080"));
+        let req = <ServerFnRequest as ClientReq<NoCustomError>>::try_new_post(
+            "/api/test",
+            "application/json",
+            "application/json",
+            "{}".to_string(),
+        )
+        .unwrap();
+        send_with_config::<FakeClient, NoCustomError>(Some(Arc::new(config)), req)
+            .now_or_never()
+            .unwrap()
+    }
+
+    #[test]
+    fn headers_are_sent_with_every_request() {
+        let config = ServerFnClientConfig::new()
+            .header("x-test", "headers")
+            .header("x-api-version", "2")
+            .on_request(|req| {
+                req.headers_mut()
+                    .insert("x-intercepted", http::HeaderValue::from_static("yes"));
+            });
+        send(config).unwrap();
+
+        let requests = requests("headers");
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0]["x-api-version"], "2");
+        assert_eq!(requests[0]["x-intercepted"], "yes");
+    }
+
+    #[test]
+    fn network_errors_are_retried() {
+        let config = ServerFnClientConfig::new()
+            .header("x-test", "retried")
+            .header("x-failures", "2")
+            .retries(2);
+        assert!(send(config).is_ok());
+        assert_eq!(requests("retried").len(), 3);
+
+        // The error is returned once every retry failed
+        let config = ServerFnClientConfig::new()
+            .header("x-test", "exhausted")
+            .header("x-failures", "2")
+            .retries(1);
+        assert!(matches!(send(config), Err(ServerFnError::Request(_))));
+        assert_eq!(requests("exhausted").len(), 2);
+    }
+
+    #[test]
+    fn on_response_runs_for_responses() {
+        let statuses = Arc::new(Mutex::new(Vec::new()));
+        let config = ServerFnClientConfig::new()
+            .header("x-test", "on-response")
+            .on_response({
+                let statuses = statuses.clone();
+                move |res| statuses.lock().unwrap().push(res.status)
+            });
+        send(config).unwrap();
+        assert_eq!(*statuses.lock().unwrap(), [201]);
+
+        // Requests that never reach the server have no response
+        let config = ServerFnClientConfig::new()
+            .header("x-test", "on-response-failed")
+            .header("x-failures", "1")
+            .on_response({
+                let statuses = statuses.clone();
+                move |res| statuses.lock().unwrap().push(res.status)
+            });
+        assert!(send(config).is_err());
+        assert_eq!(*statuses.lock().unwrap(), [201]);
+    }
+}
@@ -0,0 +1,75 @@
+//! Client side versions of the server context extractors. These let components that extract server only data compile on
+//! the client and return a descriptive error if they are ever run there.
+
+/// A value from the server context. On the client, this can never be extracted. See the server version of
+/// [`FromContext`] for more details.
+pub struct FromContext<T>(pub T);
+
+/// An error returned from [`extract`] when it is called outside of a server render or server function.
+pub struct ExtractOnClientError {
+    type_name: &'static str,
+}
+
+impl std::fmt::Debug for ExtractOnClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::fmt::Display for ExtractOnClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "`{}` can only be extracted from the server context while rendering on the server or inside a server function, but `extract` was called on the client",
+            self.type_name
+        )
+    }
+}
+
+impl std::error::Error for ExtractOnClientError {}
+
+/// Extract some part from the current server request.
+///
+/// Server context is only available while rendering on the server or inside a server function. On the client, this
+/// always returns an [`ExtractOnClientError`].
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # #[derive(Clone)]
+/// # struct Locale(String);
+/// fn Greeting() -> Element {
+///     let locale = use_server_future(|| async move {
+///         let FromContext(locale): FromContext<Locale> = extract().await?;
+///         Ok::<_, ServerFnError>(locale.0)
+///     })?;
+///
+///     rsx! { "{locale:?}" }
+/// }
+/// ```
+pub async fn extract<E>() -> Result<E, ExtractOnClientError> {
+    Err(ExtractOnClientError {
+        type_name: std::any::type_name::<E>(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::FutureExt;
+
+    #[test]
+    fn extract_fails_on_the_client() {
+        let result = extract::<FromContext<String>>().now_or_never().unwrap();
+        let message = result.err().unwrap().to_string();
+        assert!(message.contains("FromContext"));
+        assert!(message.contains("String"));
+        assert!(message.contains("was called on the client"));
+    }
+
+    #[test]
+    fn debug_matches_display() {
+        let err = extract::<u32>().now_or_never().unwrap().unwrap_err();
+        assert_eq!(format!("{err:?}"), err.to_string());
+        assert!(err.to_string().starts_with("`u32` can only be extracted"));
+    }
+}
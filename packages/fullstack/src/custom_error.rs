@@ -0,0 +1,210 @@
+//! Typed errors for server functions that are serialized across the wire with an associated HTTP status code.
+
+use serde::{de::DeserializeOwned, Serialize};
+#[cfg(feature = "server")]
+use server_fn::ServerFnError;
+use std::{fmt::Display, str::FromStr};
+
+/// A user defined error that can be returned from a server function.
+///
+/// Implement this trait for your error type and use [`CustomError<E>`] as the custom error of [`ServerFnError`](server_fn::ServerFnError).
+/// The error is serialized with serde, so the client receives the same typed value the server returned.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+/// pub enum PostError {
+///     NotFound,
+///     Unauthorized { user: String },
+/// }
+///
+/// impl ServerFnCustomError for PostError {
+///     fn status_code(&self) -> u16 {
+///         match self {
+///             PostError::NotFound => 404,
+///             PostError::Unauthorized { .. } => 401,
+///         }
+///     }
+/// }
+///
+/// #[server]
+/// async fn get_post(id: u32) -> Result<String, ServerFnError<CustomError<PostError>>> {
+///     if id != 0 {
+///         return Err(CustomError::new(PostError::NotFound).into());
+///     }
+///     Ok("Hello world".to_string())
+/// }
+/// ```
+pub trait ServerFnCustomError: Serialize + DeserializeOwned {
+    /// The HTTP status code the server responds with when this error is returned. Defaults to 500.
+    fn status_code(&self) -> u16 {
+        500
+    }
+}
+
+/// A wrapper around a [`ServerFnCustomError`] that can be used as the custom error type of a [`ServerFnError`](server_fn::ServerFnError).
+///
+/// When the server function returns the error, the status code of the response is set to
+/// [`ServerFnCustomError::status_code`]. Errors that are created but never returned don't change the response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CustomError<E>(E);
+
+impl<E: ServerFnCustomError> CustomError<E> {
+    /// Create a new custom error
+    pub fn new(error: E) -> Self {
+        Self(error)
+    }
+
+    /// Set the status code of the current server response to the status code of the error
+    #[cfg(feature = "server")]
+    pub(crate) fn apply_status(&self) {
+        if let Ok(status) = http::StatusCode::from_u16(self.0.status_code()) {
+            crate::prelude::server_context().response_parts_mut().status = status;
+        }
+    }
+}
+
+impl<E> CustomError<E> {
+    /// Get a reference to the inner error
+    pub fn inner(&self) -> &E {
+        &self.0
+    }
+
+    /// Take the inner error
+    pub fn into_inner(self) -> E {
+        self.0
+    }
+}
+
+impl<E: ServerFnCustomError> From<E> for CustomError<E> {
+    fn from(error: E) -> Self {
+        Self::new(error)
+    }
+}
+
+impl<E> std::ops::Deref for CustomError<E> {
+    type Target = E;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+// Server functions encode custom errors with Display and decode them with FromStr, so we use JSON as the wire format.
+impl<E: ServerFnCustomError> Display for CustomError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let json = serde_json::to_string(&self.0).map_err(|_| std::fmt::Error)?;
+        f.write_str(&json)
+    }
+}
+
+impl<E: DeserializeOwned> FromStr for CustomError<E> {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s).map(Self)
+    }
+}
+
+/// The result a server function returned. The `#[server]` macro calls [`ApplyErrorStatus::apply_error_status`] on it
+/// to set the status code of the response when the server function returns a [`CustomError`]. Results with any other
+/// error type fall back to [`IgnoreErrorStatus`] which leaves the response alone.
+#[doc(hidden)]
+#[cfg(feature = "server")]
+pub struct ReturnedResult<'a, R>(pub &'a R);
+
+#[doc(hidden)]
+#[cfg(feature = "server")]
+pub trait ApplyErrorStatus {
+    fn apply_error_status(&self);
+}
+
+#[cfg(feature = "server")]
+impl<T, E: ServerFnCustomError> ApplyErrorStatus
+    for ReturnedResult<'_, Result<T, ServerFnError<CustomError<E>>>>
+{
+    fn apply_error_status(&self) {
+        if let Err(ServerFnError::WrappedServerError(error)) = self.0 {
+            error.apply_status();
+        }
+    }
+}
+
+#[doc(hidden)]
+#[cfg(feature = "server")]
+pub trait IgnoreErrorStatus {
+    fn apply_error_status(&self) {}
+}
+
+#[cfg(feature = "server")]
+impl<R> IgnoreErrorStatus for &ReturnedResult<'_, R> {}
+
+/// Give the body of a server function the return type of the server function, so `?` in the body converts errors
+/// into that type
+#[doc(hidden)]
+#[cfg(feature = "server")]
+pub fn server_fn_output<R, F: std::future::Future<Output = R>>(future: F) -> F {
+    future
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    enum PostError {
+        NotFound,
+        Unauthorized { user: String },
+    }
+
+    impl ServerFnCustomError for PostError {
+        fn status_code(&self) -> u16 {
+            match self {
+                PostError::NotFound => 404,
+                PostError::Unauthorized { .. } => 401,
+            }
+        }
+    }
+
+    #[test]
+    fn errors_round_trip_as_json() {
+        let error = CustomError::new(PostError::Unauthorized {
+            user: "jane".to_string(),
+        });
+        let encoded = error.to_string();
+        assert_eq!(encoded, r#"{"Unauthorized":{"user":"jane"}}"#);
+        assert_eq!(encoded.parse::<CustomError<PostError>>().unwrap(), error);
+    }
+
+    #[cfg(feature = "server")]
+    #[test]
+    fn status_code_is_applied_when_the_error_is_returned() {
+        use crate::prelude::{with_server_context, DioxusServerContext};
+
+        let context = DioxusServerContext::default();
+        with_server_context(context.clone(), || {
+            // Creating, formatting or encoding an error doesn't change the response
+            let error = CustomError::new(PostError::NotFound);
+            let returned: Result<(), ServerFnError<CustomError<PostError>>> = Err(error.into());
+            let encoded = returned.as_ref().unwrap_err();
+            server_fn::error::ServerFnErrorSerde::ser(encoded).unwrap();
+            assert_eq!(context.response_parts().status, http::StatusCode::OK);
+
+            // The server macro applies the status code of the error the server function returns
+            (&ReturnedResult(&returned)).apply_error_status();
+            assert_eq!(context.response_parts().status, http::StatusCode::NOT_FOUND);
+        });
+
+        let context = DioxusServerContext::default();
+        with_server_context(context.clone(), || {
+            // Other errors keep the status of the response
+            let returned: Result<(), ServerFnError> =
+                Err(ServerFnError::ServerError("oops".into()));
+            (&ReturnedResult(&returned)).apply_error_status();
+            let ok: Result<(), ServerFnError<CustomError<PostError>>> = Ok(());
+            (&ReturnedResult(&ok)).apply_error_status();
+            assert_eq!(context.response_parts().status, http::StatusCode::OK);
+        });
+    }
+}
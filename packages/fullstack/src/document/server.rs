@@ -16,8 +16,9 @@ static RENDERER: Lazy<RwLock<Renderer>> = Lazy::new(|| RwLock::new(Renderer::new
 struct ServerDocumentInner {
     streaming: bool,
     title: Option<String>,
-    meta: Vec<Element>,
+    meta: Vec<MetaProps>,
     link: Vec<Element>,
+    style: Vec<Element>,
     script: Vec<Element>,
 }
 
@@ -38,8 +39,17 @@ impl ServerDocument {
     pub(crate) fn render(&self, to: &mut impl std::fmt::Write) -> std::fmt::Result {
         let myself = self.0.borrow();
         let element = rsx! {
-            {myself.meta.iter().map(|m| rsx! { {m} })}
+            {myself.meta.iter().map(|props| rsx! {
+                meta {
+                    name: props.name.clone(),
+                    charset: props.charset.clone(),
+                    http_equiv: props.http_equiv.clone(),
+                    content: props.content.clone(),
+                    property: props.property.clone(),
+                }
+            })}
             {myself.link.iter().map(|l| rsx! { {l} })}
+            {myself.style.iter().map(|s| rsx! { {s} })}
             {myself.script.iter().map(|s| rsx! { {s} })}
         };
 
@@ -84,15 +94,19 @@ impl Document for ServerDocument {
     fn create_meta(&self, props: MetaProps) {
         self.warn_if_streaming();
         self.serialize_for_hydration();
-        self.0.borrow_mut().meta.push(rsx! {
-            meta {
-                name: props.name,
-                charset: props.charset,
-                http_equiv: props.http_equiv,
-                content: props.content,
-                property: props.property,
-            }
-        });
+        self.0.borrow_mut().meta.push(props);
+    }
+
+    fn set_meta(&self, props: MetaProps) {
+        self.warn_if_streaming();
+        self.serialize_for_hydration();
+        let mut myself = self.0.borrow_mut();
+        if let Some(key) = props.key() {
+            myself.meta.retain(|meta| meta.key() != Some(key));
+        }
+        if props.content.is_some() {
+            myself.meta.push(props);
+        }
     }
 
     fn create_script(&self, props: ScriptProps) {
@@ -115,6 +129,33 @@ impl Document for ServerDocument {
         });
     }
 
+    fn create_style(&self, props: StyleProps) {
+        self.warn_if_streaming();
+        self.serialize_for_hydration();
+        // Styles with an href are loaded from a stylesheet instead of inlined
+        if let Some(href) = props.href {
+            self.0.borrow_mut().link.push(rsx! {
+                link {
+                    rel: "stylesheet",
+                    href,
+                    media: props.media,
+                    nonce: props.nonce,
+                    title: props.title,
+                }
+            });
+            return;
+        }
+        let contents = props.style_contents();
+        self.0.borrow_mut().style.push(rsx! {
+            style {
+                media: props.media,
+                nonce: props.nonce,
+                title: props.title,
+                {contents}
+            }
+        });
+    }
+
     fn create_link(&self, props: head::LinkProps) {
         self.warn_if_streaming();
         self.serialize_for_hydration();
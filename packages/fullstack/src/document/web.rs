@@ -35,6 +35,13 @@ impl Document for FullstackWebDocument {
         WebDocument.create_meta(props);
     }
 
+    fn set_meta(&self, props: dioxus_lib::prelude::MetaProps) {
+        if head_element_written_on_server() {
+            return;
+        }
+        WebDocument.set_meta(props);
+    }
+
     fn create_script(&self, props: dioxus_lib::prelude::ScriptProps) {
         if head_element_written_on_server() {
             return;
@@ -26,7 +26,7 @@ pub fn use_server_cached<O: 'static + Clone + Serialize + DeserializeOwned>(
     use_hook(|| server_cached(server_fn))
 }
 
-pub(crate) fn server_cached<O: 'static + Clone + Serialize + DeserializeOwned>(
+pub(crate) fn server_cached<O: 'static + Serialize + DeserializeOwned>(
     value: impl FnOnce() -> O,
 ) -> O {
     #[cfg(feature = "server")]
@@ -0,0 +1,66 @@
+use dioxus_lib::prelude::*;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Create a signal whose initial value is computed on the server. The value is serialized into the server rendered HTML
+/// and the signal is hydrated with the same value on the client, so the initializer only ever runs on the server
+/// (unless the data is missing on the client, in which case it runs again).
+///
+/// After hydration, the signal is a normal client side [`Signal`]. Writes on the client are not sent back to the server.
+///
+/// When you run this function on the client, you need to be careful to insure the order you run it initially is the same order you run it on the server.
+///
+/// # Example
+/// ```rust
+/// use dioxus_lib::prelude::*;
+/// use dioxus_fullstack::prelude::*;
+///
+/// fn app() -> Element {
+///     // The visit count is read on the server and sent down to the client with the html
+///     let mut visits = use_server_signal(|| 1234);
+///
+///     rsx! {
+///         button { onclick: move |_| visits += 1, "Visits: {visits}" }
+///     }
+/// }
+/// ```
+#[track_caller]
+pub fn use_server_signal<T: Serialize + DeserializeOwned + 'static>(
+    init: impl FnOnce() -> T,
+) -> Signal<T> {
+    let caller = std::panic::Location::caller();
+    use_hook(|| Signal::new_with_caller(super::server_cached::server_cached(init), caller))
+}
+
+#[cfg(all(test, not(feature = "web")))]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn initializer_runs_once_and_the_signal_is_writable() {
+        thread_local! {
+            static INITS: Cell<usize> = const { Cell::new(0) };
+            static VISITS: Cell<Option<Signal<u32>>> = const { Cell::new(None) };
+        }
+
+        fn app() -> Element {
+            let visits = use_server_signal(|| {
+                INITS.with(|inits| inits.set(inits.get() + 1));
+                1234
+            });
+            VISITS.with(|cell| cell.set(Some(visits)));
+            rsx! { "{visits}" }
+        }
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let mut visits = VISITS.with(|cell| cell.get()).unwrap();
+        dom.in_runtime(|| assert_eq!(visits(), 1234));
+
+        // Writes on the client update the signal without running the initializer again
+        dom.in_runtime(|| visits += 1);
+        dom.render_immediate(&mut dioxus_core::NoOpMutations);
+        dom.in_runtime(|| assert_eq!(visits(), 1235));
+        assert_eq!(INITS.with(|inits| inits.get()), 1);
+    }
+}
@@ -0,0 +1,91 @@
+//! Utilities for interacting with the incremental static regeneration cache from components and server functions.
+
+use std::sync::Arc;
+
+use dioxus_isrg::IncrementalRenderer;
+use parking_lot::{Mutex, RwLock};
+
+use crate::prelude::*;
+
+/// A shared handle to the incremental renderer cache used by the fullstack server.
+///
+/// The handle is inserted into the [`DioxusServerContext`] of every render and every server function registered with
+/// [`DioxusRouterExt::serve_dioxus_application`](crate::prelude::DioxusRouterExt::serve_dioxus_application), so it can be extracted with [`FromContext`].
+#[derive(Clone)]
+pub struct IncrementalCache(pub(crate) Arc<RwLock<IncrementalRenderer>>);
+
+impl IncrementalCache {
+    pub(crate) fn new(renderer: IncrementalRenderer) -> Self {
+        Self(Arc::new(RwLock::new(renderer)))
+    }
+
+    /// Remove a route from the cache. The next request for the route will render it again.
+    pub fn invalidate(&self, route: &str) {
+        self.0.write().invalidate(route);
+    }
+
+    /// Remove every route that was rendered with the given tag. Tags are added to a render with [`cache_tags`].
+    pub fn invalidate_tag(&self, tag: &str) {
+        self.0.write().invalidate_tag(tag);
+    }
+
+    /// Remove all routes from the cache.
+    pub fn invalidate_all(&self) {
+        self.0.write().invalidate_all();
+    }
+}
+
+/// The cache tags collected while rendering a single route
+#[derive(Clone, Default)]
+pub(crate) struct RenderCacheTags(Arc<Mutex<Vec<String>>>);
+
+impl RenderCacheTags {
+    pub(crate) fn take(&self) -> Vec<String> {
+        std::mem::take(&mut *self.0.lock())
+    }
+}
+
+/// Tag the route that is currently being rendered on the server. When the route is stored in the incremental cache, it
+/// can later be removed along with every other route that shares the tag with [`invalidate_cache_tag`].
+///
+/// This does nothing on the client or if the route is not being rendered on the server.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn BlogPost(id: u32) -> Element {
+///     #[cfg(feature = "server")]
+///     cache_tags(["posts", &format!("post:{id}")]);
+///
+///     rsx! { "Post {id}" }
+/// }
+/// ```
+pub fn cache_tags(tags: impl IntoIterator<Item = impl Into<String>>) {
+    if let Some(render_tags) = server_context().get::<RenderCacheTags>() {
+        render_tags
+            .0
+            .lock()
+            .extend(tags.into_iter().map(Into::into));
+    }
+}
+
+/// Remove every route in the incremental cache that was rendered with the given tag.
+///
+/// This should be called from a server function or while rendering on the server. It returns an error if the
+/// incremental cache is not available in the current [`DioxusServerContext`].
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// #[server]
+/// async fn publish_post(id: u32) -> Result<(), ServerFnError> {
+///     // ...save the post
+///     invalidate_cache_tag("posts")?;
+///     Ok(())
+/// }
+/// ```
+pub fn invalidate_cache_tag(tag: &str) -> Result<(), NotFoundInServerContext<IncrementalCache>> {
+    let cache = server_context()
+        .get::<IncrementalCache>()
+        .ok_or(NotFoundInServerContext(std::marker::PhantomData))?;
+    cache.invalidate_tag(tag);
+    Ok(())
+}
@@ -134,30 +134,47 @@ async fn launch_server(
     {
         use crate::axum_adapter::DioxusRouterExt;
 
-        #[allow(unused_mut)]
-        let mut router =
-            axum::Router::new().register_server_functions_with_context(context_providers);
+        #[cfg(any(feature = "desktop", feature = "mobile"))]
+        let router = axum::Router::new().register_server_functions_with_context(context_providers);
 
         #[cfg(not(any(feature = "desktop", feature = "mobile")))]
-        {
+        let router = {
             use crate::prelude::RenderHandleState;
             use crate::prelude::SSRState;
 
             match platform_config.server_cfg.build() {
                 Ok(cfg) => {
-                    router = router.serve_static_assets();
-
-                    router = router.fallback(
-                        axum::routing::get(crate::axum_adapter::render_handler).with_state(
-                            RenderHandleState::new_with_virtual_dom_factory(cfg, build_virtual_dom),
-                        ),
+                    let ssr_state = SSRState::new(&cfg);
+                    // Server functions can invalidate routes in the incremental cache, so they need access to the same cache the renderer uses
+                    let context_providers = crate::axum_adapter::incremental_cache_context(
+                        &ssr_state,
+                        context_providers,
                     );
+
+                    let mut router = axum::Router::new();
+                    if let Some(seo) = &cfg.seo {
+                        router = router.serve_seo_files(seo.clone());
+                    }
+
+                    router
+                        .register_server_functions_with_context(context_providers)
+                        .serve_static_assets()
+                        .fallback(
+                            axum::routing::get(crate::axum_adapter::render_handler).with_state(
+                                RenderHandleState::new_with_virtual_dom_factory(
+                                    cfg,
+                                    build_virtual_dom,
+                                )
+                                .with_ssr_state(ssr_state),
+                            ),
+                        )
                 }
                 Err(err) => {
                     tracing::trace!("Failed to create render handler. This is expected if you are only using fullstack for desktop/mobile server functions: {}", err);
+                    axum::Router::new().register_server_functions_with_context(context_providers)
                 }
             }
-        }
+        };
 
         let router = router.into_make_service();
         let listener = tokio::net::TcpListener::bind(address).await.unwrap();
@@ -12,14 +12,23 @@ mod html_storage;
 #[cfg_attr(docsrs, doc(cfg(feature = "axum")))]
 mod axum_adapter;
 
+mod client;
 mod config;
+mod custom_error;
+#[cfg(feature = "server")]
+#[doc(hidden)]
+pub use custom_error::{server_fn_output, ApplyErrorStatus, IgnoreErrorStatus, ReturnedResult};
 mod hooks;
 pub mod launch;
+#[cfg(feature = "outbox")]
+mod outbox;
 
 pub use config::*;
 
 pub mod document;
 #[cfg(feature = "server")]
+mod incremental;
+#[cfg(feature = "server")]
 mod render;
 #[cfg(feature = "server")]
 mod streaming;
@@ -32,10 +41,38 @@ pub use serve_config::*;
 #[cfg(feature = "server")]
 mod server_context;
 
+#[cfg(feature = "server")]
+mod seo;
+
+#[cfg(feature = "axum")]
+mod openapi;
+#[cfg(feature = "axum")]
+pub use openapi::*;
+
+#[cfg(feature = "axum")]
+mod server_fn_metrics;
+#[cfg(feature = "axum")]
+pub use server_fn_metrics::*;
+
+#[cfg(not(feature = "server"))]
+mod client_extract;
+
 /// A prelude of commonly used items in dioxus-fullstack.
 pub mod prelude {
+    pub use crate::client::{
+        set_server_fn_client, ServerFnClient, ServerFnClientConfig, ServerFnRequest,
+        ServerFnResponseInfo,
+    };
+    pub use crate::custom_error::{CustomError, ServerFnCustomError};
     use crate::hooks;
-    pub use hooks::{server_cached::use_server_cached, server_future::use_server_future};
+    #[cfg(feature = "outbox")]
+    pub use crate::outbox::{
+        use_online, use_outbox, Conflict, ConflictResolution, Delivery, Outbox, OutboxEntry,
+    };
+    pub use hooks::{
+        server_cached::use_server_cached, server_future::use_server_future,
+        server_signal::use_server_signal,
+    };
 
     #[cfg(feature = "axum")]
     #[cfg_attr(docsrs, doc(cfg(feature = "axum")))]
@@ -49,6 +86,18 @@ pub mod prelude {
     #[cfg_attr(docsrs, doc(cfg(feature = "server")))]
     pub use crate::serve_config::{ServeConfig, ServeConfigBuilder};
 
+    #[cfg(feature = "server")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "server")))]
+    pub use crate::seo::{ChangeFrequency, RouteSeo, SeoConfig};
+
+    #[cfg(feature = "axum")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "axum")))]
+    pub use crate::openapi::{OpenApi, ServerFnDoc};
+
+    #[cfg(feature = "axum")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "axum")))]
+    pub use crate::server_fn_metrics::{on_server_fn_call, ServerFnMetrics};
+
     #[cfg(all(feature = "server", feature = "axum"))]
     #[cfg_attr(docsrs, doc(cfg(all(feature = "server", feature = "axum"))))]
     pub use crate::server_context::Axum;
@@ -57,13 +106,25 @@ pub mod prelude {
     #[cfg_attr(docsrs, doc(cfg(feature = "server")))]
     pub use crate::server_context::{
         extract, server_context, with_server_context, DioxusServerContext, FromContext,
-        FromServerContext, ProvideServerContext,
+        FromServerContext, NotFoundInServerContext, ProvideServerContext,
     };
 
+    #[cfg(not(feature = "server"))]
+    pub use crate::client_extract::{extract, ExtractOnClientError, FromContext};
+
+    #[cfg(feature = "server")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "server")))]
+    pub use crate::incremental::{cache_tags, invalidate_cache_tag, IncrementalCache};
+
     #[cfg(feature = "server")]
     #[cfg_attr(docsrs, doc(cfg(feature = "server")))]
     pub use dioxus_isrg::{IncrementalRenderer, IncrementalRendererConfig};
 
     pub use dioxus_server_macro::*;
     pub use server_fn::{self, ServerFn as _, ServerFnError};
+
+    // The server macro refers to the default client as `dioxus_fullstack::prelude::ServerFnClient`. This makes that
+    // path resolve in crates that only import the `dioxus` prelude
+    #[doc(hidden)]
+    pub use crate as dioxus_fullstack;
 }
@@ -0,0 +1,415 @@
+//! Generate an [OpenAPI](https://spec.openapis.org/oas/v3.1.0) description of the registered server functions.
+
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+
+/// Documentation for a single server function in the [`OpenApi`] description.
+///
+/// Schemas are plain [JSON schemas](https://json-schema.org/). With the `schemars` feature, they can be derived from
+/// the argument and return types of the server function with [schemars](https://docs.rs/schemars):
+///
+/// ```rust, ignore
+/// #[derive(Serialize, Deserialize, JsonSchema)]
+/// struct Post {
+///     title: String,
+/// }
+///
+/// #[derive(Serialize, Deserialize, JsonSchema)]
+/// struct PostsOf {
+///     user: u32,
+/// }
+///
+/// let doc = ServerFnDoc::new()
+///     .summary("Get the posts of a user")
+///     .request_type::<PostsOf>()
+///     .response_type::<Vec<Post>>();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ServerFnDoc {
+    summary: Option<String>,
+    description: Option<String>,
+    tags: Vec<String>,
+    request_schema: Option<Value>,
+    response_schema: Option<Value>,
+    request_content_type: Option<String>,
+    response_content_type: Option<String>,
+}
+
+impl ServerFnDoc {
+    /// Create new empty documentation for a server function
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set a short summary of what the server function does
+    pub fn summary(mut self, summary: impl Into<String>) -> Self {
+        self.summary = Some(summary.into());
+        self
+    }
+
+    /// Set a longer description of the server function
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Add a tag to group the server function with other operations
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Set the schema of the arguments of the server function
+    pub fn request_schema(mut self, schema: Value) -> Self {
+        self.request_schema = Some(schema);
+        self
+    }
+
+    /// Set the schema of the value the server function returns
+    pub fn response_schema(mut self, schema: Value) -> Self {
+        self.response_schema = Some(schema);
+        self
+    }
+
+    /// Set the content type of the request body. Defaults to `application/x-www-form-urlencoded`, the default input
+    /// encoding of server functions. Arguments of `GET` server functions are always described as query parameters.
+    pub fn request_content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.request_content_type = Some(content_type.into());
+        self
+    }
+
+    /// Set the content type of the response body. Defaults to `application/json`, the default output encoding of
+    /// server functions.
+    pub fn response_content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.response_content_type = Some(content_type.into());
+        self
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl ServerFnDoc {
+    /// Derive the schemas from the struct the `#[server]` macro generates for the arguments of a server function and
+    /// from the type it returns. The argument struct needs to implement [`schemars::JsonSchema`].
+    pub fn for_server_fn<F>() -> Self
+    where
+        F: server_fn::ServerFn + schemars::JsonSchema,
+        F::Output: schemars::JsonSchema,
+    {
+        Self::new().request_type::<F>().response_type::<F::Output>()
+    }
+
+    /// Derive the schema of the arguments of the server function from a type with the same fields
+    pub fn request_type<T: schemars::JsonSchema>(self) -> Self {
+        self.request_schema(derive_schema::<T>())
+    }
+
+    /// Derive the schema of the value the server function returns from its type
+    pub fn response_type<T: schemars::JsonSchema>(self) -> Self {
+        self.response_schema(derive_schema::<T>())
+    }
+}
+
+/// Derive the JSON schema of a type. Other types the type refers to are inlined because references to definitions
+/// would be resolved against the root of the OpenAPI document.
+#[cfg(feature = "schemars")]
+fn derive_schema<T: schemars::JsonSchema>() -> Value {
+    let generator = schemars::gen::SchemaSettings::draft2019_09()
+        .with(|settings| settings.inline_subschemas = true)
+        .into_generator();
+    let mut schema = generator.into_root_schema_for::<T>();
+    // Recursive types can't be inlined, so their definitions are kept with the schema. The id makes references
+    // resolve against the schema instead of the OpenAPI document.
+    if !schema.definitions.is_empty() {
+        schema.schema.extensions.insert(
+            "$id".to_string(),
+            Value::String(format!("urn:dioxus:schema:{}", T::schema_id())),
+        );
+        schema.schema.extensions.insert(
+            "$defs".to_string(),
+            serde_json::to_value(&schema.definitions).unwrap_or_default(),
+        );
+    }
+    serde_json::to_value(schema.schema).unwrap_or_default()
+}
+
+/// An [OpenAPI 3.1](https://spec.openapis.org/oas/v3.1.0) description of all server functions registered with the
+/// server. Serve it from your router with [`DioxusRouterExt::serve_openapi`](crate::prelude::DioxusRouterExt::serve_openapi).
+///
+/// Every server function is listed with its path and method. Add schemas and descriptions for a server function with
+/// [`OpenApi::document`]:
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// #[server]
+/// async fn get_post(id: u32) -> Result<String, ServerFnError> {
+///     Ok(format!("Post {id}"))
+/// }
+///
+/// let openapi = OpenApi::new("Blog", "1.0.0").document(
+///     GetPost::PATH,
+///     ServerFnDoc::new()
+///         .summary("Get the contents of a post")
+///         .response_schema(serde_json::json!({ "type": "string" })),
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct OpenApi {
+    title: String,
+    version: String,
+    description: Option<String>,
+    servers: Vec<String>,
+    docs: HashMap<String, ServerFnDoc>,
+}
+
+impl OpenApi {
+    /// Create a new description of the API with a title and version
+    pub fn new(title: impl Into<String>, version: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            version: version.into(),
+            description: None,
+            servers: Vec::new(),
+            docs: HashMap::new(),
+        }
+    }
+
+    /// Set the description of the API
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Add the url of a server the API is hosted on
+    pub fn server(mut self, url: impl Into<String>) -> Self {
+        self.servers.push(url.into());
+        self
+    }
+
+    /// Document the server function registered at a path
+    pub fn document(mut self, path: impl Into<String>, doc: ServerFnDoc) -> Self {
+        self.docs.insert(path.into(), doc);
+        self
+    }
+
+    /// Create the OpenAPI document for every server function registered with the server
+    pub fn to_json(&self) -> Value {
+        self.to_json_for(
+            server_fn::axum::server_fn_paths().map(|(path, method)| (path, method.to_string())),
+        )
+    }
+
+    /// Create the OpenAPI document for a list of server function paths and methods
+    pub(crate) fn to_json_for<'a>(
+        &self,
+        server_fns: impl IntoIterator<Item = (&'a str, String)>,
+    ) -> Value {
+        let mut server_fns: Vec<_> = server_fns.into_iter().collect();
+        server_fns.sort();
+
+        let mut paths = Map::new();
+        for (path, method) in server_fns {
+            let default_doc = ServerFnDoc::default();
+            let doc = self.docs.get(path).unwrap_or(&default_doc);
+            let method = method.to_lowercase();
+            let operation = operation(path, &method, doc);
+            paths
+                .entry(path.to_string())
+                .or_insert_with(|| json!({}))
+                .as_object_mut()
+                .unwrap()
+                .insert(method, operation);
+        }
+
+        let mut info = json!({ "title": self.title, "version": self.version });
+        if let Some(description) = &self.description {
+            info["description"] = json!(description);
+        }
+        let mut document = json!({
+            "openapi": "3.1.0",
+            "info": info,
+            "paths": paths,
+        });
+        if !self.servers.is_empty() {
+            document["servers"] = self
+                .servers
+                .iter()
+                .map(|url| json!({ "url": url }))
+                .collect();
+        }
+        document
+    }
+}
+
+/// Describe a single server function call
+fn operation(path: &str, method: &str, doc: &ServerFnDoc) -> Value {
+    let operation_id = path.trim_matches('/').replace('/', "_");
+    let response_content_type = doc
+        .response_content_type
+        .as_deref()
+        .unwrap_or("application/json");
+    let mut operation = json!({
+        "operationId": operation_id,
+        "responses": {
+            "200": {
+                "description": "The value the server function returned",
+                "content": {
+                    response_content_type: {
+                        "schema": doc.response_schema.clone().unwrap_or(json!({})),
+                    }
+                }
+            },
+            "500": {
+                "description": "The server function returned an error",
+                "content": { "text/plain": { "schema": { "type": "string" } } }
+            }
+        }
+    });
+    if let Some(summary) = &doc.summary {
+        operation["summary"] = json!(summary);
+    }
+    if let Some(description) = &doc.description {
+        operation["description"] = json!(description);
+    }
+    if !doc.tags.is_empty() {
+        operation["tags"] = json!(doc.tags);
+    }
+
+    let request_schema = doc
+        .request_schema
+        .clone()
+        .unwrap_or(json!({ "type": "object" }));
+    if method == "get" {
+        operation["parameters"] = json!([{
+            "name": "args",
+            "in": "query",
+            "style": "form",
+            "explode": true,
+            "schema": request_schema,
+        }]);
+    } else {
+        let content_type = doc
+            .request_content_type
+            .as_deref()
+            .unwrap_or("application/x-www-form-urlencoded");
+        operation["requestBody"] = json!({
+            "required": true,
+            "content": { content_type: { "schema": request_schema } }
+        });
+    }
+    operation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn server_functions_are_listed_by_path_and_method() {
+        let openapi = OpenApi::new("Blog", "1.0.0")
+            .description("The blog API")
+            .server("https://example.com")
+            .document(
+                "/api/get_post",
+                ServerFnDoc::new()
+                    .summary("Get a post")
+                    .tag("posts")
+                    .request_schema(
+                        json!({ "type": "object", "properties": { "id": { "type": "integer" } } }),
+                    )
+                    .response_schema(json!({ "type": "string" })),
+            );
+        let document = openapi.to_json_for([
+            ("/api/get_post", "GET".to_string()),
+            ("/api/create_post", "POST".to_string()),
+        ]);
+
+        assert_eq!(document["openapi"], "3.1.0");
+        assert_eq!(
+            document["info"],
+            json!({ "title": "Blog", "version": "1.0.0", "description": "The blog API" })
+        );
+        assert_eq!(
+            document["servers"],
+            json!([{ "url": "https://example.com" }])
+        );
+
+        // Documented GET server functions take their arguments from the query
+        let get_post = &document["paths"]["/api/get_post"]["get"];
+        assert_eq!(get_post["operationId"], "api_get_post");
+        assert_eq!(get_post["summary"], "Get a post");
+        assert_eq!(get_post["tags"], json!(["posts"]));
+        assert_eq!(get_post["parameters"][0]["in"], "query");
+        assert_eq!(
+            get_post["parameters"][0]["schema"]["properties"]["id"]["type"],
+            "integer"
+        );
+        assert_eq!(
+            get_post["responses"]["200"]["content"]["application/json"]["schema"],
+            json!({ "type": "string" })
+        );
+        assert!(get_post.get("requestBody").is_none());
+
+        // Undocumented POST server functions fall back to the default encodings
+        let create_post = &document["paths"]["/api/create_post"]["post"];
+        assert!(create_post.get("summary").is_none());
+        assert_eq!(
+            create_post["requestBody"]["content"]["application/x-www-form-urlencoded"]["schema"],
+            json!({ "type": "object" })
+        );
+        assert_eq!(
+            create_post["responses"]["200"]["content"]["application/json"]["schema"],
+            json!({})
+        );
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn schemas_are_derived_from_types() {
+        #[derive(schemars::JsonSchema)]
+        #[allow(unused)]
+        struct Author {
+            name: String,
+        }
+
+        #[derive(schemars::JsonSchema)]
+        #[allow(unused)]
+        struct Post {
+            id: u32,
+            author: Author,
+        }
+
+        let doc = ServerFnDoc::new()
+            .request_type::<Post>()
+            .response_type::<Vec<String>>();
+        let request = doc.request_schema.unwrap();
+        assert_eq!(request["properties"]["id"]["type"], "integer");
+        // Nested types are inlined instead of referring to a definition
+        assert_eq!(
+            request["properties"]["author"]["properties"]["name"]["type"],
+            "string"
+        );
+        let response = doc.response_schema.unwrap();
+        assert_eq!(response["type"], "array");
+        assert_eq!(response["items"]["type"], "string");
+    }
+
+    #[test]
+    fn custom_content_types_are_used() {
+        let openapi = OpenApi::new("Files", "0.1.0").document(
+            "/api/upload",
+            ServerFnDoc::new()
+                .request_content_type("multipart/form-data")
+                .response_content_type("application/cbor"),
+        );
+        let document = openapi.to_json_for([("/api/upload", "POST".to_string())]);
+        let upload = &document["paths"]["/api/upload"]["post"];
+
+        assert!(upload["requestBody"]["content"]
+            .get("multipart/form-data")
+            .is_some());
+        assert!(upload["responses"]["200"]["content"]
+            .get("application/cbor")
+            .is_some());
+        assert!(document.get("servers").is_none());
+    }
+}
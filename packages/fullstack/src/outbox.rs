@@ -0,0 +1,425 @@
+//! Queue mutations while offline and send them to the server when the connection returns.
+
+use dioxus_lib::html::document::{document, EvalError, UseEval};
+use dioxus_lib::prelude::*;
+use dioxus_storage::{IndexedDbBackend, StorageBackend};
+use futures_util::future::{FutureExt, LocalBoxFuture, Shared};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use server_fn::ServerFnError;
+use std::collections::HashMap;
+use std::future::Future;
+use std::rc::Rc;
+
+/// The store queued mutations are persisted in
+const STORE: &str = "outbox";
+
+/// A mutation waiting in the [`Outbox`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutboxEntry {
+    /// A unique id for the entry. Entries are sent in the order of their ids.
+    pub id: u64,
+    /// The name the mutation was registered with in [`Outbox::register`]
+    pub name: String,
+    /// The serialized arguments of the mutation
+    pub payload: Value,
+    /// The number of times sending the mutation failed with an error from the server
+    pub attempts: u32,
+}
+
+/// How to handle a queued mutation that the server rejected
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConflictResolution {
+    /// Keep the mutation and stop syncing. It is sent again in the next sync.
+    Retry,
+    /// Drop the mutation and continue with the next one
+    Discard,
+    /// Replace the arguments of the mutation and send it again immediately
+    Replace(Value),
+}
+
+/// A queued mutation that the server rejected while syncing the [`Outbox`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conflict {
+    /// The mutation that was rejected
+    pub entry: OutboxEntry,
+    /// The error the server returned
+    pub error: ServerFnError,
+}
+
+/// Whether [`Outbox::send`] reached the server
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delivery {
+    /// The mutation ran on the server
+    Sent,
+    /// The app is offline or the server couldn't be reached. The mutation is sent when the connection returns.
+    Queued,
+}
+
+type Handler = Rc<dyn Fn(Value) -> LocalBoxFuture<'static, Result<(), ServerFnError>>>;
+type ConflictHandler = Rc<dyn Fn(Conflict) -> ConflictResolution>;
+
+struct OutboxInner {
+    backend: Rc<dyn StorageBackend>,
+    handlers: HashMap<String, Handler>,
+    on_conflict: ConflictHandler,
+    next_id: u64,
+    loaded: Shared<LocalBoxFuture<'static, ()>>,
+}
+
+/// Marks the [`Outbox`] as not syncing anymore when it is dropped
+struct SyncingGuard(Signal<bool>);
+
+impl Drop for SyncingGuard {
+    fn drop(&mut self) {
+        // The signal is already dropped if the outbox was dropped while syncing
+        if let Ok(mut syncing) = self.0.try_write() {
+            *syncing = false;
+        }
+    }
+}
+
+/// A queue of mutations that are persisted while the app is offline and sent to the server when the connection
+/// returns. Create it with [`use_outbox`].
+///
+/// The outbox doesn't cache query results itself. Read [`Outbox::synced`] in a
+/// [`use_resource`](dioxus_lib::prelude::use_resource) that loads data from the server to refetch it once queued
+/// mutations reach the server.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Outbox {
+    inner: CopyValue<OutboxInner>,
+    queue: Signal<Vec<OutboxEntry>>,
+    online: Signal<bool>,
+    syncing: Signal<bool>,
+    synced: Signal<u64>,
+}
+
+impl Outbox {
+    /// Register the server function that runs the mutations queued with `name`. Mutations are persisted with only their
+    /// name and arguments, so the handlers need to be registered again every time the app starts.
+    pub fn register<A, F>(&self, name: impl Into<String>, handler: impl Fn(A) -> F + 'static)
+    where
+        A: DeserializeOwned + 'static,
+        F: Future<Output = Result<(), ServerFnError>> + 'static,
+    {
+        let handler: Handler = Rc::new(move |payload| match serde_json::from_value::<A>(payload) {
+            Ok(args) => handler(args).boxed_local(),
+            Err(err) => {
+                let error = ServerFnError::Deserialization(err.to_string());
+                async move { Err(error) }.boxed_local()
+            }
+        });
+        self.inner
+            .write_unchecked()
+            .handlers
+            .insert(name.into(), handler);
+    }
+
+    /// Decide what happens to queued mutations that the server rejects while syncing. By default the mutation is
+    /// discarded.
+    pub fn on_conflict(&self, handler: impl Fn(Conflict) -> ConflictResolution + 'static) {
+        self.inner.write_unchecked().on_conflict = Rc::new(handler);
+    }
+
+    /// Run a mutation registered with [`Outbox::register`]. If the app is offline, or the server can't be reached, the
+    /// mutation is persisted and sent in order with the other queued mutations when the connection returns.
+    ///
+    /// Errors from the server are returned instead of queuing the mutation.
+    pub async fn send(
+        &self,
+        name: impl Into<String>,
+        args: &impl Serialize,
+    ) -> Result<Delivery, ServerFnError> {
+        let name = name.into();
+        let payload = match serde_json::to_value(args) {
+            Ok(payload) => payload,
+            Err(err) => return Err(ServerFnError::Serialization(err.to_string())),
+        };
+        let loaded = self.inner.peek().loaded.clone();
+        loaded.await;
+
+        // Send the mutation directly if nothing is waiting before it
+        if *self.online.peek() && self.queue.peek().is_empty() {
+            let handler = self.handler(&name)?;
+            match handler(payload.clone()).await {
+                Ok(()) => {
+                    let mut synced = self.synced;
+                    synced += 1;
+                    return Ok(Delivery::Sent);
+                }
+                Err(ServerFnError::Request(err)) => {
+                    tracing::debug!("Queuing {name} because the server couldn't be reached: {err}");
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        self.enqueue(name, payload).await;
+        Ok(Delivery::Queued)
+    }
+
+    fn handler(&self, name: &str) -> Result<Handler, ServerFnError> {
+        self.inner
+            .peek()
+            .handlers
+            .get(name)
+            .cloned()
+            .ok_or_else(|| {
+                ServerFnError::Registration(format!("no mutation is registered as {name:?}"))
+            })
+    }
+
+    async fn enqueue(&self, name: String, payload: Value) {
+        let (entry, backend) = {
+            let mut inner = self.inner.write_unchecked();
+            let entry = OutboxEntry {
+                id: inner.next_id,
+                name,
+                payload,
+                attempts: 0,
+            };
+            inner.next_id += 1;
+            (entry, inner.backend.clone())
+        };
+        let value = serde_json::to_value(&entry).unwrap_or_default();
+        let mut queue = self.queue;
+        queue.write().push(entry.clone());
+        if let Err(err) = backend.put(STORE, &key(entry.id), value).await {
+            tracing::error!(
+                "Failed to persist the queued mutation {}: {err}",
+                entry.name
+            );
+        }
+    }
+
+    /// Send the queued mutations in order. This runs automatically when the app comes back online, but it can be
+    /// called manually to retry after a conflict or a failed request.
+    pub async fn sync(&self) {
+        let mut syncing = self.syncing;
+        if *syncing.peek() {
+            return;
+        }
+        syncing.set(true);
+        // Reset the flag even if the future is dropped before it finishes, like when the component that polls it is
+        // dropped
+        let _guard = SyncingGuard(syncing);
+        let loaded = self.inner.peek().loaded.clone();
+        loaded.await;
+
+        let mut queue = self.queue;
+        let backend = self.inner.peek().backend.clone();
+        let mut sent_any = false;
+        loop {
+            let next = queue.peek().first().cloned();
+            let Some(mut entry) = next else {
+                break;
+            };
+            let Ok(handler) = self.handler(&entry.name) else {
+                tracing::warn!(
+                    "The queued mutation {} is not registered yet, waiting until it is",
+                    entry.name
+                );
+                break;
+            };
+            let resolution = match handler(entry.payload.clone()).await {
+                Ok(()) => ConflictResolution::Discard,
+                // The server still can't be reached, so try again later
+                Err(ServerFnError::Request(_)) => break,
+                Err(error) => {
+                    entry.attempts += 1;
+                    let on_conflict = self.inner.peek().on_conflict.clone();
+                    on_conflict(Conflict {
+                        entry: entry.clone(),
+                        error,
+                    })
+                }
+            };
+            match resolution {
+                ConflictResolution::Discard => {
+                    queue.write().remove(0);
+                    sent_any = true;
+                    if let Err(err) = backend.delete(STORE, &key(entry.id)).await {
+                        tracing::error!("Failed to remove the sent mutation {}: {err}", entry.name);
+                    }
+                }
+                ConflictResolution::Retry => {
+                    queue.write()[0].attempts = entry.attempts;
+                    break;
+                }
+                ConflictResolution::Replace(payload) => {
+                    entry.payload = payload;
+                    queue.write()[0] = entry.clone();
+                    let value = serde_json::to_value(&entry).unwrap_or_default();
+                    if let Err(err) = backend.put(STORE, &key(entry.id), value).await {
+                        tracing::error!(
+                            "Failed to persist the queued mutation {}: {err}",
+                            entry.name
+                        );
+                    }
+                }
+            }
+        }
+
+        if sent_any {
+            let mut synced = self.synced;
+            synced += 1;
+        }
+    }
+
+    /// Get the mutations that haven't reached the server yet, oldest first
+    pub fn pending(&self) -> Vec<OutboxEntry> {
+        self.queue.read().clone()
+    }
+
+    /// Check if the app is online
+    pub fn is_online(&self) -> bool {
+        (self.online)()
+    }
+
+    /// Check if the outbox is sending queued mutations
+    pub fn is_syncing(&self) -> bool {
+        (self.syncing)()
+    }
+
+    /// A counter that increases every time mutations reach the server. Reading it in a resource refetches the
+    /// resource after each sync.
+    pub fn synced(&self) -> u64 {
+        (self.synced)()
+    }
+}
+
+fn key(id: u64) -> String {
+    // Pad the id so backends that sort keys as strings keep the order of the queue
+    format!("{id:020}")
+}
+
+const ONLINE_SCRIPT: &str = r#"
+dioxus.send(navigator.onLine);
+window.addEventListener("online", () => dioxus.send(true));
+window.addEventListener("offline", () => dioxus.send(false));
+await new Promise(() => {});
+"#;
+
+/// Track if the browser or webview is online. Platforms that can't report their connection, like the server, are
+/// always online.
+pub fn use_online() -> ReadOnlySignal<bool> {
+    use_hook(|| {
+        let mut online = Signal::new(true);
+        let mut eval = UseEval::new(document().new_evaluator(ONLINE_SCRIPT.to_string()));
+        spawn(async move {
+            loop {
+                match eval.recv().await {
+                    Ok(status) => online.set(status.as_bool().unwrap_or(true)),
+                    Err(EvalError::Finished) => break,
+                    Err(err) => {
+                        tracing::debug!("Connection status is unavailable: {err}");
+                        break;
+                    }
+                }
+            }
+        });
+        online.into()
+    })
+}
+
+/// Get the outbox of the app. The outbox is shared by every component, and persisted in the
+/// [`StorageBackend`] provided with [`provide_storage_backend`](dioxus_storage::provide_storage_backend),
+/// or in IndexedDB if no backend was provided.
+///
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+/// use dioxus_fullstack::prelude::*;
+///
+/// #[server]
+/// async fn save_note(text: String) -> Result<(), ServerFnError> {
+///     Ok(())
+/// }
+///
+/// fn app() -> Element {
+///     let outbox = use_outbox();
+///     use_hook(|| outbox.register("save_note", save_note));
+///
+///     rsx! {
+///         if !outbox.is_online() {
+///             "Offline, {outbox.pending().len()} notes will be saved when the connection returns"
+///         }
+///         button {
+///             onclick: move |_| async move {
+///                 _ = outbox.send("save_note", &"Buy milk".to_string()).await;
+///             },
+///             "Save"
+///         }
+///     }
+/// }
+/// ```
+pub fn use_outbox() -> Outbox {
+    let online = use_online();
+    let outbox = use_hook(|| {
+        try_consume_context::<Outbox>().unwrap_or_else(|| {
+            let outbox = ScopeId::ROOT.in_runtime(create_outbox);
+            provide_root_context(outbox)
+        })
+    });
+    // Send queued mutations whenever the app comes back online
+    use_effect(move || {
+        let is_online = online();
+        let mut status = outbox.online;
+        if *status.peek() != is_online {
+            status.set(is_online);
+        }
+        if is_online {
+            spawn(async move { outbox.sync().await });
+        }
+    });
+    outbox
+}
+
+fn create_outbox() -> Outbox {
+    let backend = try_consume_context::<Rc<dyn StorageBackend>>()
+        .unwrap_or_else(|| Rc::new(IndexedDbBackend::new()));
+    let mut queue = Signal::new(Vec::new());
+    let inner = CopyValue::new(OutboxInner {
+        backend: backend.clone(),
+        handlers: HashMap::new(),
+        on_conflict: Rc::new(|conflict: Conflict| {
+            tracing::warn!(
+                "Discarding the queued mutation {} after the server rejected it: {}",
+                conflict.entry.name,
+                conflict.error
+            );
+            ConflictResolution::Discard
+        }),
+        next_id: 0,
+        loaded: async {}.boxed_local().shared(),
+    });
+
+    // Restore the mutations queued in a previous session
+    let load = async move {
+        match backend.entries(STORE).await {
+            Ok(entries) => {
+                let mut restored: Vec<OutboxEntry> = entries
+                    .into_iter()
+                    .filter_map(|(_, value)| serde_json::from_value(value).ok())
+                    .collect();
+                restored.sort_by_key(|entry| entry.id);
+                if let Some(last) = restored.last() {
+                    inner.write_unchecked().next_id = last.id + 1;
+                }
+                queue.set(restored);
+            }
+            Err(err) => tracing::debug!("Failed to restore the outbox: {err}"),
+        }
+    }
+    .boxed_local()
+    .shared();
+    inner.write_unchecked().loaded = load.clone();
+    spawn(load);
+
+    Outbox {
+        inner,
+        queue,
+        online: Signal::new(true),
+        syncing: Signal::new(false),
+        synced: Signal::new(0),
+    }
+}
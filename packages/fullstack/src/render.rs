@@ -1,4 +1,5 @@
 //! A shared pool of renderers for efficient server side rendering.
+use crate::incremental::{IncrementalCache, RenderCacheTags};
 use crate::streaming::{Mount, StreamingRenderer};
 use dioxus_interpreter_js::INITIALIZE_STREAMING_JS;
 use dioxus_isrg::{CachedRender, RenderFreshness};
@@ -46,7 +47,7 @@ where
 
 struct SsrRendererPool {
     renderers: RwLock<Vec<Renderer>>,
-    incremental_cache: Option<RwLock<dioxus_isrg::IncrementalRenderer>>,
+    incremental_cache: Option<IncrementalCache>,
 }
 
 impl SsrRendererPool {
@@ -57,7 +58,7 @@ impl SsrRendererPool {
         let renderers = RwLock::new((0..initial_size).map(|_| pre_renderer()).collect());
         Self {
             renderers,
-            incremental_cache: incremental.map(|cache| RwLock::new(cache.build())),
+            incremental_cache: incremental.map(|cache| IncrementalCache::new(cache.build())),
         }
     }
 
@@ -68,26 +69,25 @@ impl SsrRendererPool {
         render_into: &mut Sender<Result<String, dioxus_isrg::IncrementalRendererError>>,
     ) -> Option<RenderFreshness> {
         if let Some(incremental) = &self.incremental_cache {
-            if let Ok(mut incremental) = incremental.write() {
-                match incremental.get(route) {
-                    Ok(Some(cached_render)) => {
-                        let CachedRender {
-                            freshness,
-                            response,
-                            ..
-                        } = cached_render;
-                        _ = render_into.start_send(String::from_utf8(response.to_vec()).map_err(
-                            |err| dioxus_isrg::IncrementalRendererError::Other(Box::new(err)),
-                        ));
-                        return Some(freshness);
-                    }
-                    Err(e) => {
-                        tracing::error!(
-                            "Failed to get route \"{route}\" from incremental cache: {e}"
-                        );
-                    }
-                    _ => {}
+            let mut incremental = incremental.0.write();
+            match incremental.get(route) {
+                Ok(Some(cached_render)) => {
+                    let CachedRender {
+                        freshness,
+                        response,
+                        ..
+                    } = cached_render;
+                    _ = render_into.start_send(
+                        String::from_utf8(response.to_vec()).map_err(|err| {
+                            dioxus_isrg::IncrementalRendererError::Other(Box::new(err))
+                        }),
+                    );
+                    return Some(freshness);
                 }
+                Err(e) => {
+                    tracing::error!("Failed to get route \"{route}\" from incremental cache: {e}");
+                }
+                _ => {}
             }
         }
         None
@@ -150,9 +150,15 @@ impl SsrRendererPool {
             ));
         }
 
-        let wrapper = FullstackHTMLTemplate { cfg: cfg.clone() };
+        let wrapper = FullstackHTMLTemplate::new(cfg).with_route(route.clone());
 
         let server_context = server_context.clone();
+        // Collect any cache tags the route adds while rendering and let server functions called during the render invalidate the cache
+        let cache_tags = RenderCacheTags::default();
+        server_context.insert(cache_tags.clone());
+        if let Some(incremental) = &self.incremental_cache {
+            server_context.insert(incremental.clone());
+        }
         let mut renderer = self
             .renderers
             .write()
@@ -162,6 +168,9 @@ impl SsrRendererPool {
 
         let myself = self.clone();
 
+        let render_timeout = cfg.render_timeout;
+        let report_interrupted = into.clone();
+
         let join_handle = spawn_platform(move || async move {
             let mut virtual_dom = virtual_dom_factory();
             let document = std::rc::Rc::new(crate::document::server::ServerDocument::default());
@@ -256,6 +265,11 @@ impl SsrRendererPool {
             // Render the initial frame with loading placeholders
             let mut initial_frame = renderer.render(&virtual_dom);
 
+            // Send down the fingerprint of the templates we rendered so the client can check it is hydrating the same templates
+            if let Err(err) = wrapper.render_hydration_fingerprint(&mut initial_frame, &renderer) {
+                throw_error!(err);
+            }
+
             // Along with the initial frame, we render the html after the main element, but before the body tag closes. This should include the script that starts loading the wasm bundle.
             if let Err(err) = wrapper.render_after_main(&mut initial_frame, &virtual_dom) {
                 throw_error!(err);
@@ -333,11 +347,25 @@ impl SsrRendererPool {
                 if let Err(err) = wrapper.render_head(&mut cached_render, &virtual_dom) {
                     throw_error!(err);
                 }
+                // All suspense boundaries are resolved, so we can render the final body in one pass
+                renderer.reset_hydration();
+                if let Err(err) = renderer.render_to(&mut cached_render, &virtual_dom) {
+                    throw_error!(dioxus_isrg::IncrementalRendererError::RenderError(err));
+                }
+                if let Err(err) =
+                    wrapper.render_hydration_fingerprint(&mut cached_render, &renderer)
+                {
+                    throw_error!(err);
+                }
+                if let Err(err) = wrapper.render_after_main(&mut cached_render, &virtual_dom) {
+                    throw_error!(err);
+                }
                 cached_render.push_str(&post_streaming);
 
-                if let Ok(mut incremental) = incremental.write() {
-                    let _ = incremental.cache(route, cached_render);
-                }
+                let _ = incremental
+                    .0
+                    .write()
+                    .cache_with_tags(route, cached_render, cache_tags.take());
             }
 
             stream.render(post_streaming);
@@ -346,16 +374,70 @@ impl SsrRendererPool {
             myself.renderers.write().unwrap().push(renderer);
         });
 
+        let watchdog = spawn_watchdog(join_handle, render_timeout, report_interrupted);
+
         Ok((
             RenderFreshness::now(None),
             ReceiverWithDrop {
                 receiver: rx,
-                cancel_task: Some(join_handle),
+                cancel_task: Some(watchdog),
             },
         ))
     }
 }
 
+/// An error that interrupted a render before it finished streaming
+#[derive(Debug, thiserror::Error)]
+enum RenderInterrupted {
+    #[error("Rendering the page took longer than {0:?}")]
+    TimedOut(std::time::Duration),
+    #[error("Rendering the page panicked")]
+    Panicked,
+}
+
+/// Aborts a render task when dropped
+struct AbortRenderOnDrop(tokio::task::AbortHandle);
+
+impl Drop for AbortRenderOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// Watch a render task so a panic or timeout in one request closes that request's stream with an error instead of leaving it hanging.
+/// Dropping the returned handle (or aborting it) cancels the render.
+fn spawn_watchdog(
+    render: JoinHandle<()>,
+    render_timeout: Option<std::time::Duration>,
+    mut report_interrupted: Sender<Result<String, dioxus_isrg::IncrementalRendererError>>,
+) -> JoinHandle<()> {
+    let watchdog = async move {
+        let _abort_render = AbortRenderOnDrop(render.abort_handle());
+        let interrupted = match render_timeout {
+            Some(timeout) => match tokio::time::timeout(timeout, render).await {
+                Ok(result) => result.err().map(|_| RenderInterrupted::Panicked),
+                Err(_) => Some(RenderInterrupted::TimedOut(timeout)),
+            },
+            None => render.await.err().map(|_| RenderInterrupted::Panicked),
+        };
+        if let Some(interrupted) = interrupted {
+            tracing::error!("{interrupted}");
+            _ = report_interrupted.start_send(Err(dioxus_isrg::IncrementalRendererError::Other(
+                Box::new(interrupted),
+            )));
+        }
+    };
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        tokio::spawn(watchdog)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        tokio::task::spawn_local(watchdog)
+    }
+}
+
 /// Start capturing errors at a suspense boundary. If the parent suspense boundary is frozen, we need to capture the errors in the suspense boundary
 /// and send them to the client to continue bubbling up
 fn start_capturing_errors(suspense_scope: ScopeId) {
@@ -388,6 +470,11 @@ impl SSRState {
         }
     }
 
+    /// Get a handle to the incremental cache if incremental rendering is enabled in the [`ServeConfig`].
+    pub fn incremental_cache(&self) -> Option<IncrementalCache> {
+        self.renderers.incremental_cache.clone()
+    }
+
     /// Render the application to HTML.
     pub async fn render<'a>(
         &'a self,
@@ -412,12 +499,22 @@ impl SSRState {
 /// The template that wraps the body of the HTML for a fullstack page. This template contains the data needed to hydrate server functions that were run on the server.
 pub struct FullstackHTMLTemplate {
     cfg: ServeConfig,
+    route: Option<String>,
 }
 
 impl FullstackHTMLTemplate {
     /// Create a new [`FullstackHTMLTemplate`].
     pub fn new(cfg: &ServeConfig) -> Self {
-        Self { cfg: cfg.clone() }
+        Self {
+            cfg: cfg.clone(),
+            route: None,
+        }
+    }
+
+    /// Set the route this template is rendering. The route is used to generate the canonical url if SEO is enabled in the [`ServeConfig`].
+    pub fn with_route(mut self, route: impl Into<String>) -> Self {
+        self.route = Some(route.into());
+        self
     }
 }
 
@@ -461,6 +558,10 @@ impl FullstackHTMLTemplate {
             document.start_streaming();
         }
 
+        if let (Some(seo), Some(route)) = (&self.cfg.seo, &self.route) {
+            seo.render_head(to, route)?;
+        }
+
         self.render_before_body(to)?;
 
         Ok(())
@@ -480,6 +581,21 @@ impl FullstackHTMLTemplate {
         Ok(())
     }
 
+    /// Render the fingerprint of the templates the renderer rendered. The client compares this with the templates it hydrates to detect mismatched server and client builds.
+    pub fn render_hydration_fingerprint<R: std::fmt::Write>(
+        &self,
+        to: &mut R,
+        renderer: &Renderer,
+    ) -> Result<(), dioxus_isrg::IncrementalRendererError> {
+        write!(
+            to,
+            r#"<script>window.dx_hydration_fingerprint="{:x}";</script>"#,
+            renderer.hydration_fingerprint()
+        )?;
+
+        Ok(())
+    }
+
     /// Render all content after the main element of the page.
     pub fn render_after_main<R: std::fmt::Write>(
         &self,
@@ -533,3 +649,69 @@ fn pre_renderer() -> Renderer {
     renderer.pre_render = true;
     renderer
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_channel::mpsc::channel;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn finished_renders_close_the_stream() {
+        let (mut tx, rx) = channel(10);
+        let watchdog = tx.clone();
+        let render = tokio::spawn(async move {
+            _ = tx.start_send(Ok("<div></div>".to_string()));
+        });
+        spawn_watchdog(render, Some(Duration::from_secs(10)), watchdog)
+            .await
+            .unwrap();
+
+        let rendered: Vec<_> = rx.collect().await;
+        assert_eq!(rendered.len(), 1);
+        assert_eq!(rendered[0].as_ref().unwrap(), "<div></div>");
+    }
+
+    #[tokio::test]
+    async fn panicking_renders_report_an_error() {
+        let (tx, mut rx) = channel(10);
+        let render = tokio::spawn(async { panic!("render failed") });
+        spawn_watchdog(render, None, tx).await.unwrap();
+
+        let err = rx.next().await.unwrap().unwrap_err();
+        assert!(err.to_string().contains("panicked"));
+        assert!(rx.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn slow_renders_are_canceled_after_the_timeout() {
+        let (mut tx, mut rx) = channel(10);
+        let watchdog = tx.clone();
+        let render = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            _ = tx.start_send(Ok("too late".to_string()));
+        });
+        spawn_watchdog(render, Some(Duration::from_millis(50)), watchdog)
+            .await
+            .unwrap();
+
+        let err = rx.next().await.unwrap().unwrap_err();
+        assert!(err.to_string().contains("took longer than 50ms"));
+        // The render task was aborted, so its sender is dropped and the stream ends
+        assert!(rx.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn canceling_the_watchdog_cancels_the_render() {
+        let (mut tx, mut rx) = channel::<Result<String, dioxus_isrg::IncrementalRendererError>>(10);
+        let watchdog = tx.clone();
+        let render = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            _ = tx.start_send(Ok("too late".to_string()));
+        });
+        let watchdog = spawn_watchdog(render, None, watchdog);
+        watchdog.abort();
+
+        assert!(rx.next().await.is_none());
+    }
+}
@@ -0,0 +1,372 @@
+//! Generate robots.txt, sitemap.xml, and canonical URLs for a server rendered application
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// How frequently a page is likely to change. This is used as a hint for search engines in the sitemap.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChangeFrequency {
+    /// The page changes every time it is accessed
+    Always,
+    /// The page changes every hour
+    Hourly,
+    /// The page changes every day
+    Daily,
+    /// The page changes every week
+    Weekly,
+    /// The page changes every month
+    Monthly,
+    /// The page changes every year
+    Yearly,
+    /// The page is archived and will never change
+    Never,
+}
+
+impl ChangeFrequency {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ChangeFrequency::Always => "always",
+            ChangeFrequency::Hourly => "hourly",
+            ChangeFrequency::Daily => "daily",
+            ChangeFrequency::Weekly => "weekly",
+            ChangeFrequency::Monthly => "monthly",
+            ChangeFrequency::Yearly => "yearly",
+            ChangeFrequency::Never => "never",
+        }
+    }
+}
+
+/// SEO settings for a single route. Routes without any settings use the defaults from [`SeoConfig`].
+#[derive(Clone, Debug)]
+pub struct RouteSeo {
+    pub(crate) canonical: Option<String>,
+    pub(crate) meta: Vec<(String, String)>,
+    pub(crate) in_sitemap: bool,
+    pub(crate) indexable: bool,
+    pub(crate) priority: Option<f32>,
+    pub(crate) change_frequency: Option<ChangeFrequency>,
+}
+
+impl Default for RouteSeo {
+    fn default() -> Self {
+        Self {
+            canonical: None,
+            meta: Vec::new(),
+            in_sitemap: true,
+            indexable: true,
+            priority: None,
+            change_frequency: None,
+        }
+    }
+}
+
+impl RouteSeo {
+    /// Create a new [`RouteSeo`] with the default settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the canonical URL for this route. Relative paths are joined with the base url of the [`SeoConfig`]. (defaults to the route itself)
+    pub fn canonical(mut self, canonical: impl Into<String>) -> Self {
+        self.canonical = Some(canonical.into());
+        self
+    }
+
+    /// Add an open graph meta tag like `og:title` or `og:image` to the head of this route
+    pub fn og(mut self, property: impl Into<String>, content: impl Into<String>) -> Self {
+        self.meta.push((property.into(), content.into()));
+        self
+    }
+
+    /// Include this route in the sitemap. (defaults to true)
+    pub fn in_sitemap(mut self, in_sitemap: bool) -> Self {
+        self.in_sitemap = in_sitemap;
+        self
+    }
+
+    /// Allow search engines to crawl this route. If this is false, the route is disallowed in robots.txt and left out of the sitemap. (defaults to true)
+    pub fn indexable(mut self, indexable: bool) -> Self {
+        self.indexable = indexable;
+        self
+    }
+
+    /// Set the priority of this route relative to other routes in the sitemap. The value is clamped between 0.0 and 1.0
+    pub fn priority(mut self, priority: f32) -> Self {
+        self.priority = Some(priority.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Set how frequently this route is likely to change
+    pub fn change_frequency(mut self, change_frequency: ChangeFrequency) -> Self {
+        self.change_frequency = Some(change_frequency);
+        self
+    }
+}
+
+/// Configuration for the robots.txt and sitemap.xml files served by the fullstack server and the canonical and open graph meta tags injected into each page during SSR.
+///
+/// With the router, you can list every static route in the sitemap with [`SeoConfig::routes`]:
+///
+/// ```rust, ignore
+/// let seo = SeoConfig::new("https://example.com")
+///     .routes(Route::static_routes())
+///     .route("/admin", RouteSeo::new().indexable(false));
+/// let cfg = ServeConfig::builder().seo(seo);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SeoConfig {
+    pub(crate) base_url: String,
+    pub(crate) routes: Vec<String>,
+    pub(crate) route_seo: HashMap<String, RouteSeo>,
+    pub(crate) default_seo: RouteSeo,
+    pub(crate) robots_txt: bool,
+    pub(crate) sitemap_xml: bool,
+}
+
+impl SeoConfig {
+    /// Create a new [`SeoConfig`] for a site hosted at the given base url (for example `https://example.com`)
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            routes: Vec::new(),
+            route_seo: HashMap::new(),
+            default_seo: RouteSeo::default(),
+            robots_txt: true,
+            sitemap_xml: true,
+        }
+    }
+
+    /// Add routes to the sitemap. Any type that implements [`std::fmt::Display`], like a router `Routable` enum, can be used.
+    pub fn routes<R: std::fmt::Display>(mut self, routes: impl IntoIterator<Item = R>) -> Self {
+        for route in routes {
+            let route = normalize_route(&route.to_string());
+            if !self.routes.contains(&route) {
+                self.routes.push(route);
+            }
+        }
+        self
+    }
+
+    /// Set the SEO settings for a single route. The route is also added to the sitemap if it isn't already listed.
+    pub fn route(mut self, route: impl std::fmt::Display, seo: RouteSeo) -> Self {
+        let route = normalize_route(&route.to_string());
+        if !self.routes.contains(&route) {
+            self.routes.push(route.clone());
+        }
+        self.route_seo.insert(route, seo);
+        self
+    }
+
+    /// Set the SEO settings used for routes without their own settings
+    pub fn default_route_seo(mut self, seo: RouteSeo) -> Self {
+        self.default_seo = seo;
+        self
+    }
+
+    /// Serve a generated robots.txt file. If a robots.txt file exists in the public directory, that file is served instead. (defaults to true)
+    pub fn robots_txt(mut self, robots_txt: bool) -> Self {
+        self.robots_txt = robots_txt;
+        self
+    }
+
+    /// Serve a generated sitemap.xml file. If a sitemap.xml file exists in the public directory, that file is served instead. (defaults to true)
+    pub fn sitemap_xml(mut self, sitemap_xml: bool) -> Self {
+        self.sitemap_xml = sitemap_xml;
+        self
+    }
+
+    /// Get the SEO settings for a route
+    pub(crate) fn seo_for(&self, route: &str) -> &RouteSeo {
+        self.route_seo.get(route).unwrap_or(&self.default_seo)
+    }
+
+    /// Get the absolute url for a path on this site
+    fn absolute_url(&self, path: &str) -> String {
+        if path.starts_with("http://") || path.starts_with("https://") {
+            return path.to_string();
+        }
+        format!("{}{}", self.base_url, normalize_route(path))
+    }
+
+    /// Get the canonical url for a route
+    pub fn canonical_url(&self, route: &str) -> String {
+        let route = normalize_route(route);
+        match &self.seo_for(&route).canonical {
+            Some(canonical) => self.absolute_url(canonical),
+            None => self.absolute_url(&route),
+        }
+    }
+
+    /// Render the contents of the robots.txt file
+    pub fn render_robots_txt(&self) -> String {
+        let mut robots = String::from("User-agent: *\n");
+        let mut disallowed_any = false;
+        for route in &self.routes {
+            if !self.seo_for(route).indexable {
+                _ = writeln!(robots, "Disallow: {route}");
+                disallowed_any = true;
+            }
+        }
+        if !disallowed_any {
+            robots.push_str("Allow: /\n");
+        }
+        if self.sitemap_xml {
+            _ = write!(robots, "\nSitemap: {}/sitemap.xml\n", self.base_url);
+        }
+        robots
+    }
+
+    /// Render the contents of the sitemap.xml file
+    pub fn render_sitemap_xml(&self) -> String {
+        let mut sitemap = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+        );
+        for route in &self.routes {
+            let seo = self.seo_for(route);
+            if !seo.in_sitemap || !seo.indexable {
+                continue;
+            }
+            sitemap.push_str("  <url>\n");
+            _ = writeln!(
+                sitemap,
+                "    <loc>{}</loc>",
+                escape_xml(&self.canonical_url(route))
+            );
+            if let Some(change_frequency) = seo.change_frequency {
+                _ = writeln!(
+                    sitemap,
+                    "    <changefreq>{}</changefreq>",
+                    change_frequency.as_str()
+                );
+            }
+            if let Some(priority) = seo.priority {
+                _ = writeln!(sitemap, "    <priority>{priority:.1}</priority>");
+            }
+            sitemap.push_str("  </url>\n");
+        }
+        sitemap.push_str("</urlset>\n");
+        sitemap
+    }
+
+    /// Render the canonical link and open graph meta tags for a route into the head of the page
+    pub(crate) fn render_head<R: std::fmt::Write>(
+        &self,
+        to: &mut R,
+        route: &str,
+    ) -> std::fmt::Result {
+        let route = normalize_route(route);
+        let seo = self.seo_for(&route);
+        let canonical = escape_xml(&self.canonical_url(&route));
+        write!(to, r#"<link rel="canonical" href="{canonical}">"#)?;
+        write!(to, r#"<meta property="og:url" content="{canonical}">"#)?;
+        for (property, content) in &seo.meta {
+            write!(
+                to,
+                r#"<meta property="{}" content="{}">"#,
+                escape_xml(property),
+                escape_xml(content)
+            )?;
+        }
+        if !seo.indexable {
+            to.write_str(r#"<meta name="robots" content="noindex">"#)?;
+        }
+        Ok(())
+    }
+}
+
+/// Strip the query, fragment and trailing slash from a route and make sure it starts with a slash
+fn normalize_route(route: &str) -> String {
+    let route = route.split(['?', '#']).next().unwrap_or_default();
+    let route = route.trim_end_matches('/');
+    if route.starts_with('/') {
+        route.to_string()
+    } else {
+        format!("/{route}")
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical_urls_are_normalized() {
+        let seo = SeoConfig::new("https://example.com/")
+            .route("/blog", RouteSeo::new())
+            .route("/old-post", RouteSeo::new().canonical("/blog/post"))
+            .route(
+                "/mirror",
+                RouteSeo::new().canonical("https://other.com/post"),
+            );
+
+        assert_eq!(seo.canonical_url("/"), "https://example.com/");
+        assert_eq!(seo.canonical_url(""), "https://example.com/");
+        assert_eq!(seo.canonical_url("/blog"), "https://example.com/blog");
+        assert_eq!(seo.canonical_url("/blog/"), "https://example.com/blog");
+        assert_eq!(
+            seo.canonical_url("blog?page=2#top"),
+            "https://example.com/blog"
+        );
+        assert_eq!(
+            seo.canonical_url("/old-post/"),
+            "https://example.com/blog/post"
+        );
+        assert_eq!(seo.canonical_url("/mirror"), "https://other.com/post");
+    }
+
+    #[test]
+    fn robots_txt_disallows_routes_that_are_not_indexable() {
+        let seo = SeoConfig::new("https://example.com")
+            .routes(["/", "/blog"])
+            .route("/admin/", RouteSeo::new().indexable(false))
+            .route("/drafts", RouteSeo::new().indexable(false));
+        assert_eq!(
+            seo.render_robots_txt(),
+            "User-agent: *\nDisallow: /admin\nDisallow: /drafts\n\nSitemap: https://example.com/sitemap.xml\n"
+        );
+
+        // Everything is allowed if no route is disallowed
+        let seo = SeoConfig::new("https://example.com")
+            .routes(["/"])
+            .sitemap_xml(false);
+        assert_eq!(seo.render_robots_txt(), "User-agent: *\nAllow: /\n");
+    }
+
+    #[test]
+    fn sitemap_escapes_route_urls() {
+        let seo = SeoConfig::new("https://example.com")
+            .routes(["/search/a&b", "/quotes/\"hi\"/<it's>"])
+            .route("/hidden", RouteSeo::new().in_sitemap(false))
+            .route(
+                "/news",
+                RouteSeo::new()
+                    .priority(2.0)
+                    .change_frequency(ChangeFrequency::Daily),
+            );
+        let sitemap = seo.render_sitemap_xml();
+
+        assert!(sitemap.contains("<loc>https://example.com/search/a&amp;b</loc>"));
+        assert!(sitemap
+            .contains("<loc>https://example.com/quotes/&quot;hi&quot;/&lt;it&apos;s&gt;</loc>"));
+        assert!(!sitemap.contains("/hidden"));
+        assert!(sitemap.contains(
+            "    <loc>https://example.com/news</loc>\n    <changefreq>daily</changefreq>\n    <priority>1.0</priority>\n"
+        ));
+        assert!(sitemap.ends_with("</urlset>\n"));
+    }
+}
@@ -4,6 +4,7 @@
 use std::fs::File;
 use std::io::Read;
 use std::path::PathBuf;
+use std::time::Duration;
 
 /// A ServeConfig is used to configure how to serve a Dioxus application. It contains information about how to serve static assets, and what content to render with [`dioxus-ssr`].
 #[derive(Clone, Default)]
@@ -12,6 +13,8 @@ pub struct ServeConfigBuilder {
     pub(crate) index_html: Option<String>,
     pub(crate) index_path: Option<PathBuf>,
     pub(crate) incremental: Option<dioxus_isrg::IncrementalRendererConfig>,
+    pub(crate) seo: Option<crate::seo::SeoConfig>,
+    pub(crate) render_timeout: Option<Duration>,
 }
 
 impl ServeConfigBuilder {
@@ -22,6 +25,8 @@ impl ServeConfigBuilder {
             index_html: None,
             index_path: None,
             incremental: None,
+            seo: None,
+            render_timeout: None,
         }
     }
 
@@ -31,6 +36,18 @@ impl ServeConfigBuilder {
         self
     }
 
+    /// Serve a generated robots.txt and sitemap.xml and inject canonical and open graph meta tags into each page
+    pub fn seo(mut self, cfg: crate::seo::SeoConfig) -> Self {
+        self.seo = Some(cfg);
+        self
+    }
+
+    /// Set the maximum amount of time rendering a single request can take, including resolving suspense. Renders that take longer are canceled and the response is closed with an error. (defaults to no timeout)
+    pub fn render_timeout(mut self, render_timeout: Duration) -> Self {
+        self.render_timeout = Some(render_timeout);
+        self
+    }
+
     /// Set the contents of the index.html file to be served. (precedence over index_path)
     pub fn index_html(mut self, index_html: String) -> Self {
         self.index_html = Some(index_html);
@@ -71,6 +88,8 @@ impl ServeConfigBuilder {
         Ok(ServeConfig {
             index,
             incremental: self.incremental,
+            seo: self.seo,
+            render_timeout: self.render_timeout,
         })
     }
 }
@@ -167,6 +186,8 @@ pub(crate) struct IndexHtml {
 pub struct ServeConfig {
     pub(crate) index: IndexHtml,
     pub(crate) incremental: Option<dioxus_isrg::IncrementalRendererConfig>,
+    pub(crate) seo: Option<crate::seo::SeoConfig>,
+    pub(crate) render_timeout: Option<Duration>,
 }
 
 impl ServeConfig {
@@ -182,7 +182,7 @@ pub trait FromServerContext<I = ()>: Sized {
 }
 
 /// A type was not found in the server context
-pub struct NotFoundInServerContext<T: 'static>(std::marker::PhantomData<T>);
+pub struct NotFoundInServerContext<T: 'static>(pub(crate) std::marker::PhantomData<T>);
 
 impl<T: 'static> std::fmt::Debug for NotFoundInServerContext<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -0,0 +1,94 @@
+//! Hooks to monitor the latency and errors of server function calls.
+
+use http::{Method, StatusCode};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+type MetricsHook = Arc<dyn Fn(&ServerFnMetrics) + Send + Sync>;
+
+static METRICS_HOOKS: RwLock<Vec<MetricsHook>> = RwLock::new(Vec::new());
+
+/// Information about a finished server function call, passed to the hooks registered with [`on_server_fn_call`].
+#[derive(Debug, Clone)]
+pub struct ServerFnMetrics {
+    /// The path of the server function
+    pub path: &'static str,
+    /// The HTTP method the server function was called with
+    pub method: Method,
+    /// The status code of the response
+    pub status: StatusCode,
+    /// How long it took to run the server function and build the response
+    pub latency: Duration,
+    /// If the server function returned an error
+    pub error: bool,
+}
+
+/// Run a hook after every server function call the server handles. Use it to record latency and error metrics in your
+/// monitoring system.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// on_server_fn_call(|call| {
+///     tracing::info!(
+///         path = call.path,
+///         latency_ms = call.latency.as_millis() as u64,
+///         error = call.error,
+///         "server function called"
+///     );
+/// });
+/// ```
+pub fn on_server_fn_call(hook: impl Fn(&ServerFnMetrics) + Send + Sync + 'static) {
+    METRICS_HOOKS.write().unwrap().push(Arc::new(hook));
+}
+
+/// Pass the metrics of a server function call to all registered hooks
+pub(crate) fn report_server_fn_call(metrics: ServerFnMetrics) {
+    let hooks = METRICS_HOOKS.read().unwrap().clone();
+    for hook in hooks {
+        hook(&metrics);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn every_hook_receives_the_call() {
+        static CALLS: Mutex<Vec<(&'static str, u16, bool)>> = Mutex::new(Vec::new());
+        static CALL_COUNT: Mutex<usize> = Mutex::new(0);
+
+        on_server_fn_call(|call| {
+            CALLS
+                .lock()
+                .unwrap()
+                .push((call.path, call.status.as_u16(), call.error));
+        });
+        on_server_fn_call(|_| *CALL_COUNT.lock().unwrap() += 1);
+
+        report_server_fn_call(ServerFnMetrics {
+            path: "/api/get_post",
+            method: Method::POST,
+            status: StatusCode::OK,
+            latency: Duration::from_millis(3),
+            error: false,
+        });
+        report_server_fn_call(ServerFnMetrics {
+            path: "/api/create_post",
+            method: Method::POST,
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            latency: Duration::from_millis(5),
+            error: true,
+        });
+
+        assert_eq!(
+            *CALLS.lock().unwrap(),
+            [
+                ("/api/get_post", 200, false),
+                ("/api/create_post", 500, true)
+            ]
+        );
+        assert_eq!(*CALL_COUNT.lock().unwrap(), 2);
+    }
+}
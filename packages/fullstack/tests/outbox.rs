@@ -0,0 +1,298 @@
+#![cfg(feature = "outbox")]
+
+use dioxus::dioxus_core::NoOpMutations;
+use dioxus::prelude::*;
+use dioxus_fullstack::prelude::*;
+use dioxus_storage::{provide_storage_backend, StorageBackend, StorageFuture};
+use serde_json::{json, Value};
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// A storage backend that keeps the values in memory so the test can inspect them
+#[derive(Clone, Default)]
+struct MemoryBackend(Rc<RefCell<BTreeMap<(String, String), Value>>>);
+
+impl MemoryBackend {
+    fn stored(&self) -> Vec<Value> {
+        self.0.borrow().values().cloned().collect()
+    }
+}
+
+impl StorageBackend for MemoryBackend {
+    fn get(&self, store: &str, key: &str) -> StorageFuture<Option<Value>> {
+        let value = self.0.borrow().get(&(store.into(), key.into())).cloned();
+        Box::pin(std::future::ready(Ok(value)))
+    }
+
+    fn put(&self, store: &str, key: &str, value: Value) -> StorageFuture<()> {
+        self.0
+            .borrow_mut()
+            .insert((store.into(), key.into()), value);
+        Box::pin(std::future::ready(Ok(())))
+    }
+
+    fn delete(&self, store: &str, key: &str) -> StorageFuture<()> {
+        self.0.borrow_mut().remove(&(store.into(), key.into()));
+        Box::pin(std::future::ready(Ok(())))
+    }
+
+    fn entries(&self, store: &str) -> StorageFuture<Vec<(String, Value)>> {
+        let entries = self
+            .0
+            .borrow()
+            .iter()
+            .filter(|((entry_store, _), _)| entry_store == store)
+            .map(|((_, key), value)| (key.clone(), value.clone()))
+            .collect();
+        Box::pin(std::future::ready(Ok(entries)))
+    }
+}
+
+/// How the fake server responds to the next mutations
+#[derive(Clone, Copy, PartialEq)]
+enum Server {
+    Unreachable,
+    Accepting,
+    Rejecting,
+    /// The request never finishes
+    Hanging,
+}
+
+thread_local! {
+    static BACKEND: RefCell<MemoryBackend> = RefCell::new(MemoryBackend::default());
+    static SERVER: Cell<Server> = const { Cell::new(Server::Accepting) };
+    static RECEIVED: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    static OUTBOX: Cell<Option<Outbox>> = const { Cell::new(None) };
+}
+
+async fn save_note(text: String) -> Result<(), ServerFnError> {
+    match SERVER.with(|server| server.get()) {
+        Server::Unreachable => Err(ServerFnError::Request("connection refused".into())),
+        Server::Rejecting => Err(ServerFnError::ServerError("conflict".into())),
+        Server::Hanging => std::future::pending().await,
+        Server::Accepting => {
+            RECEIVED.with(|received| received.borrow_mut().push(text));
+            Ok(())
+        }
+    }
+}
+
+fn app() -> Element {
+    use_hook(|| provide_storage_backend(BACKEND.with(|backend| backend.borrow().clone())));
+    let outbox = use_outbox();
+    use_hook(|| outbox.register("save_note", save_note));
+    OUTBOX.with(|cell| cell.set(Some(outbox)));
+    rsx! {}
+}
+
+/// Create the app with a fresh backend and server
+fn setup(stored: Vec<Value>) -> (VirtualDom, Outbox, MemoryBackend) {
+    let backend = MemoryBackend::default();
+    for entry in stored {
+        let key = format!("{:020}", entry["id"].as_u64().unwrap());
+        backend
+            .0
+            .borrow_mut()
+            .insert(("outbox".to_string(), key), entry);
+    }
+    BACKEND.with(|cell| *cell.borrow_mut() = backend.clone());
+    SERVER.with(|server| server.set(Server::Accepting));
+    RECEIVED.with(|received| received.borrow_mut().clear());
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let outbox = OUTBOX.with(|cell| cell.get()).unwrap();
+    (dom, outbox, backend)
+}
+
+/// Run a future in the app and wait for it and any work it causes to finish
+async fn run<T: 'static>(dom: &mut VirtualDom, future: impl Future<Output = T> + 'static) -> T {
+    let output = Rc::new(RefCell::new(None));
+    dom.in_runtime(|| {
+        let output = output.clone();
+        ScopeId::ROOT.in_runtime(|| {
+            spawn(async move {
+                *output.borrow_mut() = Some(future.await);
+            })
+        });
+    });
+    while tokio::time::timeout(Duration::from_millis(50), dom.wait_for_work())
+        .await
+        .is_ok()
+    {
+        dom.render_immediate(&mut NoOpMutations);
+    }
+    let output = output.borrow_mut().take();
+    output.expect("the future didn't finish")
+}
+
+fn received() -> Vec<String> {
+    RECEIVED.with(|received| received.borrow().clone())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn mutations_are_queued_until_the_server_can_be_reached() {
+    let (mut dom, outbox, backend) = setup(Vec::new());
+
+    let delivery = run(&mut dom, async move {
+        outbox.send("save_note", &"first".to_string()).await
+    })
+    .await;
+    assert_eq!(delivery, Ok(Delivery::Sent));
+    assert_eq!(received(), ["first"]);
+
+    SERVER.with(|server| server.set(Server::Unreachable));
+    for note in ["second", "third"] {
+        let delivery = run(&mut dom, async move {
+            outbox.send("save_note", &note.to_string()).await
+        })
+        .await;
+        assert_eq!(delivery, Ok(Delivery::Queued));
+    }
+    dom.in_runtime(|| {
+        let pending: Vec<_> = outbox
+            .pending()
+            .into_iter()
+            .map(|entry| entry.payload)
+            .collect();
+        assert_eq!(pending, [json!("second"), json!("third")]);
+    });
+    assert_eq!(backend.stored().len(), 2);
+
+    // Syncing while the server is still unreachable keeps the queue
+    run(&mut dom, async move { outbox.sync().await }).await;
+    dom.in_runtime(|| assert_eq!(outbox.pending().len(), 2));
+
+    SERVER.with(|server| server.set(Server::Accepting));
+    run(&mut dom, async move { outbox.sync().await }).await;
+    assert_eq!(received(), ["first", "second", "third"]);
+    dom.in_runtime(|| {
+        assert!(outbox.pending().is_empty());
+        assert_eq!(outbox.synced(), 2);
+    });
+    assert!(backend.stored().is_empty());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn dropping_a_sync_lets_the_next_sync_run() {
+    let (mut dom, outbox, _) = setup(Vec::new());
+    SERVER.with(|server| server.set(Server::Unreachable));
+    let delivery = run(&mut dom, async move {
+        outbox.send("save_note", &"queued".to_string()).await
+    })
+    .await;
+    assert_eq!(delivery, Ok(Delivery::Queued));
+
+    SERVER.with(|server| server.set(Server::Hanging));
+    let task =
+        dom.in_runtime(|| ScopeId::ROOT.in_runtime(|| spawn(async move { outbox.sync().await })));
+    run(&mut dom, async {}).await;
+    dom.in_runtime(|| assert!(outbox.is_syncing()));
+
+    dom.in_runtime(|| task.cancel());
+    dom.in_runtime(|| assert!(!outbox.is_syncing()));
+
+    SERVER.with(|server| server.set(Server::Accepting));
+    run(&mut dom, async move { outbox.sync().await }).await;
+    assert_eq!(received(), ["queued"]);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn queued_mutations_are_restored_in_order() {
+    let entry = |id: u64, text: &str| {
+        json!({
+            "id": id,
+            "name": "save_note",
+            "payload": text,
+            "attempts": 0,
+        })
+    };
+    let (mut dom, outbox, backend) = setup(vec![entry(7, "later"), entry(3, "earlier")]);
+    SERVER.with(|server| server.set(Server::Unreachable));
+
+    // New mutations are queued after the restored ones while there is a queue
+    let delivery = run(&mut dom, async move {
+        outbox.send("save_note", &"new".to_string()).await
+    })
+    .await;
+    assert_eq!(delivery, Ok(Delivery::Queued));
+    assert!(received().is_empty());
+    dom.in_runtime(|| {
+        let ids: Vec<_> = outbox.pending().into_iter().map(|entry| entry.id).collect();
+        assert_eq!(ids, [3, 7, 8]);
+    });
+
+    SERVER.with(|server| server.set(Server::Accepting));
+    run(&mut dom, async move { outbox.sync().await }).await;
+    assert_eq!(received(), ["earlier", "later", "new"]);
+    assert!(backend.stored().is_empty());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn conflicts_are_resolved_by_the_handler() {
+    let (mut dom, outbox, backend) = setup(Vec::new());
+    let resolutions = Rc::new(RefCell::new(vec![
+        ConflictResolution::Retry,
+        ConflictResolution::Replace(json!("resolved")),
+    ]));
+    dom.in_runtime(|| {
+        let resolutions = resolutions.clone();
+        outbox.on_conflict(move |conflict| {
+            assert_eq!(
+                conflict.error,
+                ServerFnError::ServerError("conflict".into())
+            );
+            let resolution = resolutions.borrow_mut().remove(0);
+            // The server accepts the replaced mutation
+            if matches!(resolution, ConflictResolution::Replace(_)) {
+                SERVER.with(|server| server.set(Server::Accepting));
+            }
+            resolution
+        });
+    });
+
+    // Errors from the server are returned directly instead of queuing the mutation
+    SERVER.with(|server| server.set(Server::Rejecting));
+    let delivery = run(&mut dom, async move {
+        outbox.send("save_note", &"direct".to_string()).await
+    })
+    .await;
+    assert_eq!(delivery, Err(ServerFnError::ServerError("conflict".into())));
+    dom.in_runtime(|| assert!(outbox.pending().is_empty()));
+
+    SERVER.with(|server| server.set(Server::Unreachable));
+    run(&mut dom, async move {
+        outbox.send("save_note", &"queued".to_string()).await
+    })
+    .await
+    .unwrap();
+
+    // A retried conflict stays in the queue with the failed attempt counted
+    SERVER.with(|server| server.set(Server::Rejecting));
+    run(&mut dom, async move { outbox.sync().await }).await;
+    dom.in_runtime(|| {
+        let pending = outbox.pending();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].attempts, 1);
+    });
+
+    // A replaced conflict is sent again with the new arguments
+    run(&mut dom, async move { outbox.sync().await }).await;
+    assert_eq!(received(), ["resolved"]);
+    dom.in_runtime(|| assert!(outbox.pending().is_empty()));
+    assert!(backend.stored().is_empty());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn unregistered_mutations_are_rejected() {
+    let (mut dom, outbox, _) = setup(Vec::new());
+    let delivery = run(
+        &mut dom,
+        async move { outbox.send("delete_note", &1).await },
+    )
+    .await;
+    assert!(matches!(delivery, Err(ServerFnError::Registration(_))));
+}
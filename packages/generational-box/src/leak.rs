@@ -0,0 +1,173 @@
+//! Find generational boxes that are never dropped.
+//!
+//! Start a [`LeakTracker`] and [enter](LeakTracker::enter) it while creating the boxes you want to check. Every box
+//! created on the thread while the tracker is entered is recorded until it is dropped, so any box
+//! [`LeakTracker::live_boxes`] returns after its owners should have been dropped is a leak. Boxes created by other
+//! code on the same thread, like another VirtualDom with its own tracker, are not recorded by the tracker.
+//!
+//! Boxes are only tracked in debug builds. In release builds the tracker never finds any boxes.
+
+use crate::GenerationalBoxId;
+use parking_lot::Mutex;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::panic::Location;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+
+/// The number of trackers that are alive
+static TRACKERS: AtomicUsize = AtomicUsize::new(0);
+/// If any tracker has ever been started. Recycled boxes only need to be removed from the registry after this is set.
+static TRACKED: AtomicBool = AtomicBool::new(false);
+/// A counter that orders the boxes by the time they were created
+static NEXT_BOX: AtomicU64 = AtomicU64::new(0);
+/// A counter for the ids of the trackers
+static NEXT_TRACKER: AtomicU64 = AtomicU64::new(0);
+/// Every box that was created while a tracker was alive and hasn't been dropped yet
+static LIVE_BOXES: Mutex<Option<HashMap<GenerationalBoxId, TrackedBox>>> = Mutex::new(None);
+
+thread_local! {
+    /// The trackers that are entered on this thread. New boxes are recorded by the last one.
+    static ENTERED: RefCell<Vec<LeakScope>> = const { RefCell::new(Vec::new()) };
+}
+
+struct TrackedBox {
+    order: u64,
+    created_at: &'static Location<'static>,
+    tracker: LeakScope,
+}
+
+/// A generational box that is still alive
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LiveBox {
+    /// The id of the box
+    pub id: GenerationalBoxId,
+    /// The location of the code that created the box
+    pub created_at: &'static Location<'static>,
+}
+
+/// The id of a [`LeakTracker`] that can be entered and exited without a reference to the tracker
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LeakScope(u64);
+
+impl LeakScope {
+    /// Record the boxes created on this thread with the tracker until [`LeakScope::exit`] is called
+    pub fn enter(self) {
+        ENTERED.with(|entered| entered.borrow_mut().push(self));
+    }
+
+    /// Stop recording the boxes created on this thread with the tracker
+    pub fn exit(self) {
+        ENTERED.with(|entered| {
+            let mut entered = entered.borrow_mut();
+            if let Some(index) = entered.iter().rposition(|scope| *scope == self) {
+                entered.remove(index);
+            }
+        });
+    }
+}
+
+/// Records the generational boxes created while it is entered until they are dropped
+pub struct LeakTracker {
+    scope: LeakScope,
+}
+
+impl LeakTracker {
+    /// Start a tracker. Boxes are only recorded while the tracker is [entered](LeakTracker::enter).
+    pub fn start() -> Self {
+        TRACKERS.fetch_add(1, Ordering::SeqCst);
+        TRACKED.store(true, Ordering::SeqCst);
+        Self {
+            scope: LeakScope(NEXT_TRACKER.fetch_add(1, Ordering::SeqCst)),
+        }
+    }
+
+    /// Get the id of the tracker to enter it later, for example every time the runtime that owns the boxes is
+    /// entered
+    pub fn scope(&self) -> LeakScope {
+        self.scope
+    }
+
+    /// Record the boxes created on this thread with the tracker until the guard is dropped
+    pub fn enter(&self) -> LeakTrackerGuard {
+        self.scope.enter();
+        LeakTrackerGuard(self.scope)
+    }
+
+    /// The boxes recorded by the tracker that are still alive, in the order they were created
+    pub fn live_boxes(&self) -> Vec<LiveBox> {
+        let live_boxes = LIVE_BOXES.lock();
+        let Some(live_boxes) = live_boxes.as_ref() else {
+            return Vec::new();
+        };
+        let mut boxes: Vec<_> = live_boxes
+            .iter()
+            .filter(|(_, tracked)| tracked.tracker == self.scope)
+            .map(|(id, tracked)| {
+                (
+                    tracked.order,
+                    LiveBox {
+                        id: *id,
+                        created_at: tracked.created_at,
+                    },
+                )
+            })
+            .collect();
+        boxes.sort_by_key(|(order, _)| *order);
+        boxes.into_iter().map(|(_, live)| live).collect()
+    }
+}
+
+impl Drop for LeakTracker {
+    fn drop(&mut self) {
+        let mut live_boxes = LIVE_BOXES.lock();
+        if TRACKERS.fetch_sub(1, Ordering::SeqCst) == 1 {
+            // Forget every box once the last tracker stops
+            *live_boxes = None;
+        } else if let Some(live_boxes) = live_boxes.as_mut() {
+            live_boxes.retain(|_, tracked| tracked.tracker != self.scope);
+        }
+    }
+}
+
+/// Exits the tracker when dropped. Created with [`LeakTracker::enter`].
+pub struct LeakTrackerGuard(LeakScope);
+
+impl Drop for LeakTrackerGuard {
+    fn drop(&mut self) {
+        self.0.exit();
+    }
+}
+
+/// Record a box that was just created
+#[allow(unused)]
+pub(crate) fn claimed(id: GenerationalBoxId, created_at: &'static Location<'static>) {
+    if TRACKERS.load(Ordering::Relaxed) == 0 {
+        return;
+    }
+    let Some(tracker) = ENTERED.with(|entered| entered.borrow().last().copied()) else {
+        return;
+    };
+    let mut live_boxes = LIVE_BOXES.lock();
+    if TRACKERS.load(Ordering::SeqCst) == 0 {
+        return;
+    }
+    live_boxes.get_or_insert_with(HashMap::new).insert(
+        id,
+        TrackedBox {
+            order: NEXT_BOX.fetch_add(1, Ordering::SeqCst),
+            created_at,
+            tracker,
+        },
+    );
+}
+
+/// Forget a box that was just dropped
+#[allow(unused)]
+pub(crate) fn recycled(id: GenerationalBoxId) {
+    if !TRACKED.load(Ordering::Relaxed) {
+        return;
+    }
+    if let Some(live_boxes) = LIVE_BOXES.lock().as_mut() {
+        live_boxes.remove(&id);
+    }
+}
@@ -16,6 +16,7 @@ pub use unsync::UnsyncStorage;
 
 mod entry;
 mod error;
+pub mod leak;
 mod references;
 mod sync;
 mod unsync;
@@ -74,7 +74,7 @@ impl AnyStorage for SyncStorage {
     #[track_caller]
     #[allow(unused)]
     fn claim(caller: &'static std::panic::Location<'static>) -> GenerationalPointer<Self> {
-        match sync_runtime().lock().pop() {
+        let pointer = match sync_runtime().lock().pop() {
             Some(mut storage) => {
                 let location = GenerationalLocation {
                     generation: storage.data.read().generation(),
@@ -94,7 +94,10 @@ impl AnyStorage for SyncStorage {
 
                 GenerationalPointer { storage, location }
             }
-        }
+        };
+        #[cfg(debug_assertions)]
+        crate::leak::claimed(pointer.id(), caller);
+        pointer
     }
 
     fn recycle(pointer: GenerationalPointer<Self>) -> Option<Box<dyn std::any::Any>> {
@@ -103,6 +106,8 @@ impl AnyStorage for SyncStorage {
         if !borrow_mut.valid(&pointer.location) {
             return None;
         }
+        #[cfg(debug_assertions)]
+        crate::leak::recycled(pointer.id());
         borrow_mut.increment_generation();
         let old_data = borrow_mut.data.take();
         sync_runtime().lock().push(pointer.storage);
@@ -83,7 +83,7 @@ impl AnyStorage for UnsyncStorage {
 
     #[allow(unused)]
     fn claim(caller: &'static std::panic::Location<'static>) -> GenerationalPointer<Self> {
-        UNSYNC_RUNTIME.with(|runtime| {
+        let pointer = UNSYNC_RUNTIME.with(|runtime| {
             if let Some(storage) = runtime.borrow_mut().pop() {
                 let location = GenerationalLocation {
                     generation: storage.data.borrow().generation(),
@@ -103,7 +103,10 @@ impl AnyStorage for UnsyncStorage {
                     location,
                 }
             }
-        })
+        });
+        #[cfg(debug_assertions)]
+        crate::leak::claimed(pointer.id(), caller);
+        pointer
     }
 
     fn recycle(pointer: GenerationalPointer<Self>) -> Option<Box<dyn std::any::Any>> {
@@ -113,6 +116,8 @@ impl AnyStorage for UnsyncStorage {
         if !borrow_mut.valid(&pointer.location) {
             return None;
         }
+        #[cfg(debug_assertions)]
+        crate::leak::recycled(pointer.id());
 
         borrow_mut.increment_generation();
         let old_data = borrow_mut.data.take();
@@ -0,0 +1,76 @@
+use generational_box::{
+    leak::LeakTracker, AnyStorage, GenerationalBox, SyncStorage, UnsyncStorage,
+};
+
+// Boxes are only tracked with debug assertions
+#[cfg(debug_assertions)]
+#[test]
+fn dropped_owners_do_not_leak() {
+    let tracker = LeakTracker::start();
+    let guard = tracker.enter();
+    let owner = UnsyncStorage::owner();
+    owner.insert(1);
+    let sync_owner = SyncStorage::owner();
+    sync_owner.insert(2);
+    drop(guard);
+    assert_eq!(tracker.live_boxes().len(), 2);
+
+    drop(owner);
+    drop(sync_owner);
+    assert_eq!(tracker.live_boxes(), vec![]);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn leaked_boxes_are_reported_with_their_location() {
+    let tracker = LeakTracker::start();
+    let guard = tracker.enter();
+    let line = line!() + 1;
+    let leaked = GenerationalBox::<_, UnsyncStorage>::leak("leaked");
+    drop(guard);
+
+    let live = tracker.live_boxes();
+    assert_eq!(live.len(), 1);
+    assert_eq!(live[0].id, leaked.id());
+    assert_eq!(live[0].created_at.file(), file!());
+    assert_eq!(live[0].created_at.line(), line);
+
+    leaked.manually_drop();
+    assert_eq!(tracker.live_boxes(), vec![]);
+}
+
+#[test]
+fn boxes_created_outside_the_tracker_are_ignored() {
+    let owner = UnsyncStorage::owner();
+    owner.insert(1);
+    let tracker = LeakTracker::start();
+    owner.insert(2);
+    assert_eq!(tracker.live_boxes(), vec![]);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn boxes_are_reported_by_the_tracker_they_were_created_in() {
+    let first = LeakTracker::start();
+    let second = LeakTracker::start();
+    let first_owner = UnsyncStorage::owner();
+    let second_owner = UnsyncStorage::owner();
+
+    let guard = first.enter();
+    let first_box = first_owner.insert(1);
+    {
+        // The innermost tracker records the boxes
+        let _guard = second.enter();
+        second_owner.insert(2);
+    }
+    drop(guard);
+
+    let live = first.live_boxes();
+    assert_eq!(live.len(), 1);
+    assert_eq!(live[0].id, first_box.id());
+    assert_eq!(second.live_boxes().len(), 1);
+
+    drop(second_owner);
+    assert_eq!(second.live_boxes(), vec![]);
+    assert_eq!(first.live_boxes().len(), 1);
+}
@@ -78,9 +78,15 @@ pub use use_reactive::*;
 mod use_resource;
 pub use use_resource::*;
 
+mod use_await;
+pub use use_await::*;
+
 mod use_effect;
 pub use use_effect::*;
 
+mod use_layout_effect;
+pub use use_layout_effect::*;
+
 mod use_memo;
 pub use use_memo::*;
 
@@ -95,3 +101,15 @@ pub use use_signal::*;
 
 mod use_set_compare;
 pub use use_set_compare::*;
+
+pub mod time;
+pub use time::{Clock, ClockContext};
+
+mod use_interval;
+pub use use_interval::*;
+
+mod use_debounce;
+pub use use_debounce::*;
+
+mod throttle;
+pub use throttle::*;
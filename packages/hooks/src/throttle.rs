@@ -0,0 +1,46 @@
+use crate::time::sleep;
+use dioxus_core::prelude::*;
+use dioxus_signals::{CopyValue, Readable, Writable};
+use std::time::Duration;
+
+/// Limit how often a [`Callback`] runs. See [`Throttle::throttled`].
+pub trait Throttle<Args> {
+    /// Create a new [`Callback`] that calls this callback at most once per `interval`.
+    ///
+    /// The first call runs immediately and the calls during the following `interval` are dropped. The interval is
+    /// measured with the [`Clock`](crate::Clock) of the component, so throttled callbacks can be controlled in tests.
+    ///
+    /// Like [`Callback::new`], the new callback is owned by the current scope. Create it once in a hook so the
+    /// interval is not reset when the component reruns:
+    ///
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// # use std::time::Duration;
+    /// #[component]
+    /// fn List(onscroll: EventHandler<ScrollEvent>) -> Element {
+    ///     let throttled = use_hook(|| onscroll.throttled(Duration::from_millis(100)));
+    ///     rsx! {
+    ///         div { onscroll: throttled, "..." }
+    ///     }
+    /// }
+    /// ```
+    fn throttled(self, interval: Duration) -> Callback<Args>;
+}
+
+impl<Args: 'static> Throttle<Args> for Callback<Args> {
+    #[track_caller]
+    fn throttled(self, interval: Duration) -> Callback<Args> {
+        let mut waiting = CopyValue::new(false);
+        Callback::new(move |args: Args| {
+            if *waiting.peek() {
+                return;
+            }
+            waiting.set(true);
+            self.call(args);
+            spawn(async move {
+                sleep(interval).await;
+                waiting.set(false);
+            });
+        })
+    }
+}
@@ -0,0 +1,192 @@
+use dioxus_core::prelude::try_consume_context;
+use std::{future::Future, pin::Pin, rc::Rc, time::Duration};
+
+/// A source of time for the timers in dioxus hooks like [`sleep`], [`use_interval`](crate::use_interval) and
+/// [`use_debounce`](crate::use_debounce).
+///
+/// Timers use the system clock unless a [`ClockContext`] is provided in a parent component or as a root context.
+/// Test harnesses provide a mock clock that only moves forward when the test advances it, so components that depend
+/// on time can be tested without waiting.
+pub trait Clock {
+    /// The time that has passed since the clock started
+    fn now(&self) -> Duration;
+
+    /// Create a future that completes once a duration has passed on the clock
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()>>>;
+}
+
+/// A context that replaces the system clock for every timer in the components below it
+#[derive(Clone)]
+pub struct ClockContext(Rc<dyn Clock>);
+
+impl ClockContext {
+    /// Create a new clock context
+    pub fn new(clock: impl Clock + 'static) -> Self {
+        Self(Rc::new(clock))
+    }
+}
+
+/// Wait for a duration to pass.
+///
+/// This uses the [`ClockContext`] of the current component if there is one, or the system clock otherwise. Prefer
+/// this to the timers of your async runtime in components so time can be controlled in tests.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus::hooks::time::sleep;
+/// # use std::time::Duration;
+/// fn app() -> Element {
+///     let mut visible = use_signal(|| true);
+///     use_future(move || async move {
+///         sleep(Duration::from_secs(5)).await;
+///         visible.set(false);
+///     });
+///     rsx! {
+///         if visible() {
+///             "This message disappears after 5 seconds"
+///         }
+///     }
+/// }
+/// ```
+pub fn sleep(duration: Duration) -> impl Future<Output = ()> {
+    let clock = try_consume_context::<ClockContext>();
+    async move {
+        match clock {
+            Some(clock) => clock.0.sleep(duration).await,
+            None => system::sleep(duration).await,
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod system {
+    pub(super) async fn sleep(duration: std::time::Duration) {
+        gloo_timers::future::sleep(duration).await
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod system {
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::mpsc::{channel, RecvTimeoutError, Sender};
+    use std::sync::{Arc, Mutex, OnceLock};
+    use std::task::{Context, Poll, Waker};
+    use std::time::{Duration, Instant};
+
+    #[derive(Default)]
+    struct TimerState {
+        done: bool,
+        waker: Option<Waker>,
+    }
+
+    struct Timer {
+        deadline: Instant,
+        state: Arc<Mutex<TimerState>>,
+    }
+
+    impl PartialEq for Timer {
+        fn eq(&self, other: &Self) -> bool {
+            self.deadline == other.deadline
+        }
+    }
+
+    impl Eq for Timer {}
+
+    impl PartialOrd for Timer {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Timer {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.deadline.cmp(&other.deadline)
+        }
+    }
+
+    /// Start the thread that wakes every timer once its deadline passes
+    fn timers() -> &'static Mutex<Sender<Timer>> {
+        static TIMERS: OnceLock<Mutex<Sender<Timer>>> = OnceLock::new();
+        TIMERS.get_or_init(|| {
+            let (tx, rx) = channel::<Timer>();
+            std::thread::Builder::new()
+                .name("dioxus-timers".into())
+                .spawn(move || {
+                    let mut queue: BinaryHeap<Reverse<Timer>> = BinaryHeap::new();
+                    loop {
+                        let now = Instant::now();
+                        while let Some(Reverse(timer)) = queue.peek() {
+                            if timer.deadline > now {
+                                break;
+                            }
+                            let Reverse(timer) = queue.pop().unwrap();
+                            let mut state = timer.state.lock().unwrap();
+                            state.done = true;
+                            if let Some(waker) = state.waker.take() {
+                                waker.wake();
+                            }
+                        }
+
+                        let next = match queue.peek() {
+                            Some(Reverse(timer)) => rx.recv_timeout(timer.deadline - now),
+                            None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+                        };
+                        match next {
+                            Ok(timer) => queue.push(Reverse(timer)),
+                            Err(RecvTimeoutError::Timeout) => {}
+                            Err(RecvTimeoutError::Disconnected) => return,
+                        }
+                    }
+                })
+                .expect("failed to spawn the timer thread");
+            Mutex::new(tx)
+        })
+    }
+
+    struct Sleep {
+        deadline: Instant,
+        state: Option<Arc<Mutex<TimerState>>>,
+    }
+
+    impl Future for Sleep {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if Instant::now() >= self.deadline {
+                return Poll::Ready(());
+            }
+            match &self.state {
+                Some(state) => {
+                    let mut state = state.lock().unwrap();
+                    if state.done {
+                        return Poll::Ready(());
+                    }
+                    state.waker = Some(cx.waker().clone());
+                }
+                None => {
+                    let state = Arc::new(Mutex::new(TimerState {
+                        done: false,
+                        waker: Some(cx.waker().clone()),
+                    }));
+                    let timer = Timer {
+                        deadline: self.deadline,
+                        state: state.clone(),
+                    };
+                    _ = timers().lock().unwrap().send(timer);
+                    self.state = Some(state);
+                }
+            }
+            Poll::Pending
+        }
+    }
+
+    pub(super) fn sleep(duration: Duration) -> impl Future<Output = ()> {
+        Sleep {
+            deadline: Instant::now() + duration,
+            state: None,
+        }
+    }
+}
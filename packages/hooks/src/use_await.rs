@@ -0,0 +1,44 @@
+use crate::use_resource;
+use dioxus_core::prelude::*;
+use dioxus_signals::Readable;
+use std::future::Future;
+
+/// Await a future while rendering a component. Until the future finishes, the component is suspended and the closest
+/// [`SuspenseBoundary`] shows its fallback. Once every suspended component under the boundary is ready, the boundary
+/// renders its children with the values of the futures.
+///
+/// The future runs again when any signal read in the closure changes, like [`crate::use_resource()`]. While it reruns,
+/// the component is suspended again.
+///
+/// On the server, suspended boundaries are sent as their fallback first. Fullstack streams the children of each
+/// boundary to the client once their futures finish.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # async fn fetch_user(id: i32) -> String { format!("User {id}") }
+/// fn app() -> Element {
+///     rsx! {
+///         SuspenseBoundary {
+///             fallback: |_| rsx! { "Loading..." },
+///             Profile { id: 1 }
+///         }
+///     }
+/// }
+///
+/// #[component]
+/// fn Profile(id: ReadOnlySignal<i32>) -> Element {
+///     let name = use_await(move || fetch_user(id()))?;
+///     rsx! { "{name}" }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[doc(alias = "use_suspense")]
+pub fn use_await<T, F>(future: impl FnMut() -> F + 'static) -> Result<T, RenderError>
+where
+    T: Clone + 'static,
+    F: Future<Output = T> + 'static,
+{
+    let value = use_resource(future).suspend()?;
+    let value = value.cloned();
+    Ok(value)
+}
@@ -2,6 +2,9 @@ use dioxus_core::{
     prelude::{consume_context, provide_context, try_consume_context},
     use_hook,
 };
+use dioxus_signals::{Memo, Readable, Signal};
+
+use crate::use_memo;
 
 /// Consume some context in the tree, providing a sharable handle to the value
 ///
@@ -65,3 +68,37 @@ pub fn use_context<T: 'static + Clone>() -> T {
 pub fn use_context_provider<T: 'static + Clone>(f: impl FnOnce() -> T) -> T {
     use_hook(|| provide_context(f()))
 }
+
+/// Consume a [`Signal`] from context and subscribe only to the part of it that `selector` picks
+///
+/// The component reruns when the selected value changes, not on every write to the context. This lets many
+/// components share one large app state without all of them rerunning when an unrelated field changes.
+/// ```rust
+/// # use dioxus::prelude::*;
+/// #[derive(Clone, Default)]
+/// struct AppState {
+///     user: String,
+///     unread: usize,
+/// }
+///
+/// fn app() -> Element {
+///     use_context_provider(|| Signal::new(AppState::default()));
+///     rsx! { UserName {} }
+/// }
+///
+/// #[component]
+/// fn UserName() -> Element {
+///     // Changes to `unread` don't rerun this component
+///     let user = use_context_selector(|state: &AppState| state.user.clone());
+///     rsx! { "{user}" }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[track_caller]
+#[must_use]
+pub fn use_context_selector<T: 'static, R: PartialEq + 'static>(
+    mut selector: impl FnMut(&T) -> R + 'static,
+) -> Memo<R> {
+    let context = use_context::<Signal<T>>();
+    use_memo(move || selector(&context.read()))
+}
@@ -0,0 +1,87 @@
+use crate::{time::sleep, use_callback};
+use dioxus_core::prelude::*;
+use dioxus_signals::{CopyValue, Readable, Writable};
+use std::time::Duration;
+
+/// Call a callback once a delay has passed without another call to [`UseDebounce::action`].
+///
+/// Each call to `action` restarts the delay, and only the value of the last call is passed to the callback. This is
+/// useful to wait for the user to stop typing before searching. The delay is measured with the [`Clock`](crate::Clock)
+/// of the component, so debounced callbacks can be controlled in tests.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use std::time::Duration;
+/// fn app() -> Element {
+///     let mut query = use_signal(String::new);
+///     let mut search = use_debounce(Duration::from_millis(300), move |text: String| query.set(text));
+///     rsx! {
+///         input { oninput: move |event| search.action(event.value()) }
+///         "Searching for {query}"
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+pub fn use_debounce<T: 'static>(
+    delay: Duration,
+    callback: impl FnMut(T) + 'static,
+) -> UseDebounce<T> {
+    let callback = use_callback(callback);
+    let pending = use_hook(|| CopyValue::new(None));
+    let scope =
+        use_hook(|| current_scope_id().expect("use_debounce must be called in a component"));
+
+    UseDebounce {
+        delay,
+        callback,
+        pending,
+        scope,
+    }
+}
+
+/// A handle to a debounced callback created with [`use_debounce`]
+pub struct UseDebounce<T: 'static> {
+    delay: Duration,
+    callback: Callback<T>,
+    pending: CopyValue<Option<Task>>,
+    scope: ScopeId,
+}
+
+impl<T: 'static> UseDebounce<T> {
+    /// Restart the delay and call the callback with this value once it passes
+    pub fn action(&mut self, value: T) {
+        self.cancel();
+        let delay = self.delay;
+        let callback = self.callback;
+        let mut pending = self.pending;
+        let task = self.scope.push_future(async move {
+            sleep(delay).await;
+            pending.set(None);
+            callback.call(value);
+        });
+        self.pending.set(task);
+    }
+
+    /// Cancel the pending call, if there is one
+    pub fn cancel(&mut self) {
+        if let Some(task) = self.pending.take() {
+            task.cancel();
+        }
+    }
+
+    /// Check if a call is waiting for the delay to pass
+    pub fn is_pending(&self) -> bool {
+        self.pending
+            .try_read()
+            .map(|pending| pending.is_some())
+            .unwrap_or(false)
+    }
+}
+
+impl<T> Clone for UseDebounce<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for UseDebounce<T> {}
@@ -53,7 +53,7 @@ pub fn use_effect(mut callback: impl FnMut() + 'static) -> Effect {
 /// A handle to an effect.
 #[derive(Clone, Copy)]
 pub struct Effect {
-    rc: ReactiveContext,
+    pub(crate) rc: ReactiveContext,
 }
 
 impl Effect {
@@ -0,0 +1,40 @@
+use crate::{time::sleep, use_callback};
+use dioxus_core::prelude::*;
+use dioxus_signals::{CopyValue, Readable, Writable};
+use std::time::Duration;
+
+/// Run a callback every time a period passes, until the component is dropped.
+///
+/// The first call happens one period after the component is created. The period is measured with the [`Clock`](crate::Clock)
+/// of the component, so intervals can be controlled in tests. The returned [`Task`] can be paused, resumed or
+/// cancelled.
+///
+/// The callback and period are updated every time the component re-renders. A new period takes effect once the
+/// current wait has finished.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use std::time::Duration;
+/// fn app() -> Element {
+///     let mut seconds = use_signal(|| 0);
+///     use_interval(Duration::from_secs(1), move || seconds += 1);
+///     rsx! { "{seconds} seconds since the app started" }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+pub fn use_interval(period: Duration, mut callback: impl FnMut() + 'static) -> Task {
+    let callback = use_callback(move |_| callback());
+    let mut current_period = use_hook(|| CopyValue::new(period));
+    if *current_period.peek() != period {
+        current_period.set(period);
+    }
+
+    use_hook(|| {
+        spawn(async move {
+            loop {
+                sleep(*current_period.peek()).await;
+                callback.call(());
+            }
+        })
+    })
+}
@@ -0,0 +1,99 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use dioxus_core::prelude::*;
+use futures_util::StreamExt;
+
+use crate::{use_callback, Effect};
+
+/// Measure the DOM and then mutate it after the component renders, without forcing the browser to recalculate the
+/// layout for every component.
+///
+/// `read` runs after the mutations of the render are applied. The reads of every layout effect run before any
+/// `write`, so all of the measurements share a single layout pass. `write` then receives the value `read` returned.
+/// Both run before regular [`use_effect`](crate::use_effect) callbacks.
+///
+/// The layout effect runs after the first render. Like [`use_effect`](crate::use_effect), it runs again after the
+/// next render whenever a signal read inside `read` changes. Signals read inside `write` are not tracked.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn Notes() -> Element {
+///     let mut text = use_signal(String::new);
+///
+///     use_layout_effect(
+///         // Measure the height of the content once every element has been updated. Reading the text reruns the
+///         // effect when it changes
+///         move || {
+///             let _ = text.read();
+///             let notes = web_sys::window()?.document()?.get_element_by_id("notes")?;
+///             let height = notes.scroll_height();
+///             Some((notes, height))
+///         },
+///         // Then grow the textarea to fit the content
+///         move |measured| {
+///             if let Some((notes, height)) = measured {
+///                 _ = notes.set_attribute("style", &format!("height: {height}px"));
+///             }
+///         },
+///     );
+///
+///     rsx! {
+///         textarea { id: "notes", value: "{text}", oninput: move |event| text.set(event.value()) }
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[track_caller]
+pub fn use_layout_effect<T: 'static>(
+    mut read: impl FnMut() -> T + 'static,
+    mut write: impl FnMut(T) + 'static,
+) -> Effect {
+    let read = use_callback(move |_| read());
+    let write = use_callback(move |measurement| write(measurement));
+
+    let location = std::panic::Location::caller();
+
+    use_hook(|| {
+        // Only the read phase is tracked. Writes that read a signal should not rerun the measurement
+        let (rc, mut changed) = ReactiveContext::new_with_origin(location);
+
+        // Deduplicate queued layout effects
+        let layout_queued = Rc::new(Cell::new(false));
+
+        let queue_layout_for_next_render = move || {
+            if layout_queued.get() {
+                return;
+            }
+            layout_queued.set(true);
+            let measurement = Rc::new(RefCell::new(None));
+            queue_layout_read({
+                let measurement = measurement.clone();
+                move || {
+                    *measurement.borrow_mut() = Some(rc.reset_and_run_in(|| read(())));
+                }
+            });
+            let layout_queued = layout_queued.clone();
+            queue_layout_write(move || {
+                layout_queued.set(false);
+                if let Some(measurement) = measurement.borrow_mut().take() {
+                    write(measurement);
+                }
+            });
+        };
+
+        queue_layout_for_next_render();
+        spawn(async move {
+            loop {
+                // Wait for the measurement to change
+                let _ = changed.next().await;
+
+                // Measure and write again after the next render
+                queue_layout_for_next_render();
+            }
+        });
+        Effect { rc }
+    })
+}
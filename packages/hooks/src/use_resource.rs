@@ -423,6 +423,57 @@ impl<T> Resource<T> {
     }
 }
 
+/// The result of a [`Resource`] whose future returns a [`Result`]. See [`Resource::result`].
+#[derive(Clone, Copy, PartialEq, Hash, Eq, Debug)]
+pub enum ResourceResult<T, E> {
+    /// The future hasn't finished for the first time yet
+    Loading,
+
+    /// The future finished successfully
+    Ready(T),
+
+    /// The future returned an error
+    Error(E),
+}
+
+impl<T: Clone, E: Clone> Resource<Result<T, E>> {
+    /// Get the result of a resource whose future can fail as [`ResourceResult::Loading`], [`ResourceResult::Ready`] or
+    /// [`ResourceResult::Error`]. This subscribes to the value of the resource.
+    ///
+    /// When the future restarts because its dependencies changed, the result of the previous run stays available
+    /// until the new run finishes. Use [`Resource::state`] to check if the future is running again.
+    ///
+    /// ## Example
+    /// ```rust, no_run
+    /// # use dioxus::prelude::*;
+    /// fn App() -> Element {
+    ///     let mut revision = use_signal(|| "1d03b42");
+    ///     // The request restarts automatically when the revision changes
+    ///     let resource = use_resource(move || async move {
+    ///         let response = reqwest::get(format!("https://github.com/DioxusLabs/awesome-dioxus/blob/{revision}/awesome.json")).await;
+    ///         // The value and error need to be Clone to read them with `result`
+    ///         match response {
+    ///             Ok(response) => response.text().await.map_err(|err| err.to_string()),
+    ///             Err(err) => Err(err.to_string()),
+    ///         }
+    ///     });
+    ///
+    ///     match resource.result() {
+    ///         ResourceResult::Loading => rsx! { "Loading..." },
+    ///         ResourceResult::Ready(text) => rsx! { "{text}" },
+    ///         ResourceResult::Error(err) => rsx! { "Error: {err}" },
+    ///     }
+    /// }
+    /// ```
+    pub fn result(&self) -> ResourceResult<T, E> {
+        match &*self.value.read() {
+            None => ResourceResult::Loading,
+            Some(Ok(value)) => ResourceResult::Ready(value.clone()),
+            Some(Err(err)) => ResourceResult::Error(err.clone()),
+        }
+    }
+}
+
 impl<T> From<Resource<T>> for ReadOnlySignal<Option<T>> {
     fn from(val: Resource<T>) -> Self {
         val.value.into()
@@ -120,3 +120,67 @@ async fn use_memo_only_triggers_one_update() {
 
     assert_eq!(VEC_SIGNAL.with(|v| v.borrow().clone()), vec![0, 20]);
 }
+
+#[tokio::test]
+async fn context_selector_only_reruns_for_the_selected_value() {
+    use dioxus::prelude::*;
+    use std::cell::Cell;
+
+    #[derive(Default)]
+    struct AppState {
+        user: String,
+        unread: usize,
+    }
+
+    thread_local! {
+        static STATE: Cell<Option<Signal<AppState>>> = const { Cell::new(None) };
+        static USER_RENDERS: Cell<usize> = const { Cell::new(0) };
+        static UNREAD_RENDERS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn app() -> Element {
+        let state = use_context_provider(|| Signal::new(AppState::default()));
+        STATE.with(|cell| cell.set(Some(state)));
+        rsx! {
+            UserName {}
+            Unread {}
+        }
+    }
+
+    #[component]
+    fn UserName() -> Element {
+        USER_RENDERS.with(|renders| renders.set(renders.get() + 1));
+        let user = use_context_selector(|state: &AppState| state.user.clone());
+        rsx! { "{user}" }
+    }
+
+    #[component]
+    fn Unread() -> Element {
+        UNREAD_RENDERS.with(|renders| renders.set(renders.get() + 1));
+        let unread = use_context_selector(|state: &AppState| state.unread);
+        rsx! { "{unread}" }
+    }
+
+    async fn settle(dom: &mut VirtualDom) {
+        tokio::select! {
+            _ = dom.wait_for_work() => {}
+            _ = tokio::time::sleep(std::time::Duration::from_millis(100)) => {}
+        };
+        dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let mut state = STATE.with(|cell| cell.get()).unwrap();
+    assert_eq!(USER_RENDERS.with(|renders| renders.get()), 1);
+
+    dom.in_runtime(|| state.write().unread += 1);
+    settle(&mut dom).await;
+    assert_eq!(USER_RENDERS.with(|renders| renders.get()), 1);
+    assert_eq!(UNREAD_RENDERS.with(|renders| renders.get()), 2);
+
+    dom.in_runtime(|| state.write().user = "Ada".to_string());
+    settle(&mut dom).await;
+    assert_eq!(USER_RENDERS.with(|renders| renders.get()), 2);
+    assert_eq!(UNREAD_RENDERS.with(|renders| renders.get()), 2);
+}
@@ -0,0 +1,56 @@
+use dioxus::prelude::*;
+use std::cell::{Cell, RefCell};
+use std::time::Duration;
+
+#[tokio::test]
+async fn resources_restart_when_their_dependencies_change() {
+    thread_local! {
+        static INPUT: Cell<Option<Signal<i32>>> = const { Cell::new(None) };
+        static RESULT: RefCell<Option<ResourceResult<i32, String>>> = const { RefCell::new(None) };
+        static RUNS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn app() -> Element {
+        let input = use_signal(|| 1);
+        INPUT.with(|cell| cell.set(Some(input)));
+        let resource = use_resource(move || async move {
+            let input = input();
+            RUNS.with(|runs| runs.set(runs.get() + 1));
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            if input < 0 {
+                Err(format!("{input} is negative"))
+            } else {
+                Ok(input * 2)
+            }
+        });
+        RESULT.with(|cell| *cell.borrow_mut() = Some(resource.result()));
+        rsx! {}
+    }
+
+    async fn settle(dom: &mut VirtualDom) {
+        while tokio::time::timeout(Duration::from_millis(100), dom.wait_for_work())
+            .await
+            .is_ok()
+        {
+            dom.render_immediate(&mut dioxus_core::NoOpMutations);
+        }
+    }
+
+    let result = || RESULT.with(|cell| cell.borrow().clone()).unwrap();
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert_eq!(result(), ResourceResult::Loading);
+
+    settle(&mut dom).await;
+    assert_eq!(result(), ResourceResult::Ready(2));
+
+    let mut input = INPUT.with(|cell| cell.get()).unwrap();
+    dom.in_runtime(|| input.set(-1));
+    settle(&mut dom).await;
+    assert_eq!(
+        result(),
+        ResourceResult::Error("-1 is negative".to_string())
+    );
+    assert_eq!(RUNS.with(|runs| runs.get()), 2);
+}
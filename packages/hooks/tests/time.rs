@@ -0,0 +1,13 @@
+use dioxus_hooks::time::sleep;
+use std::time::{Duration, Instant};
+
+#[tokio::test]
+async fn sleep_uses_the_system_clock_without_a_clock_context() {
+    let start = Instant::now();
+    let (short, long) = (
+        sleep(Duration::from_millis(10)),
+        sleep(Duration::from_millis(50)),
+    );
+    tokio::join!(long, short);
+    assert!(start.elapsed() >= Duration::from_millis(50));
+}
@@ -0,0 +1,490 @@
+//! Audit the rendered tree for common accessibility problems.
+//!
+//! The audit walks every element a [`VirtualDom`] rendered and checks for:
+//! - images without alt text
+//! - form fields without a label
+//! - unknown ARIA roles and attributes, ARIA attributes the role of the element doesn't support, and roles that are
+//!   missing a required attribute
+//! - ids that are used by more than one element
+//!
+//! Each issue points at the component that rendered the element and, in debug builds, the `rsx!` call that rendered
+//! that component. The web renderer and dioxus-ssr can run the audit automatically in debug builds, or you can call
+//! [`audit`] yourself in a test:
+//!
+//! ```rust, ignore
+//! let mut dom = VirtualDom::new(app);
+//! dom.rebuild_in_place();
+//! assert_eq!(dioxus_html::a11y::audit(&dom), vec![]);
+//! ```
+
+use crate::aria::AriaRole;
+use dioxus_core::{
+    AttributeValue, DynamicNode, ScopeId, TemplateAttribute, TemplateNode, VNode, VirtualDom,
+};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::panic::Location;
+
+/// An accessibility problem found by [`audit`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct A11yIssue {
+    /// The problem
+    pub kind: A11yIssueKind,
+    /// The tag of the element with the problem
+    pub tag: &'static str,
+    /// The name of the component that rendered the element
+    pub component: &'static str,
+    /// The location of the code that rendered the component. This is only tracked in debug builds.
+    pub location: Option<&'static Location<'static>>,
+}
+
+impl Display for A11yIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} on <{}> in {}", self.kind, self.tag, self.component)?;
+        if let Some(location) = self.location {
+            write!(f, " (rendered at {location})")?;
+        }
+        Ok(())
+    }
+}
+
+/// The kinds of problems [`audit`] looks for
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum A11yIssueKind {
+    /// An image has no `alt` text. Use `alt: ""` for decorative images.
+    MissingAlt,
+    /// A form field has no label, `aria-label`, `aria-labelledby` or `title`
+    UnlabeledInput,
+    /// The `role` attribute is not an ARIA role
+    InvalidRole {
+        /// The value of the role attribute
+        role: String,
+    },
+    /// An `aria-*` attribute is not an ARIA attribute
+    UnknownAriaAttribute {
+        /// The name of the attribute
+        attribute: String,
+    },
+    /// An ARIA attribute is not supported by the role of the element
+    UnsupportedAriaAttribute {
+        /// The name of the attribute
+        attribute: String,
+        /// The explicit or implicit role of the element, if it has one
+        role: Option<String>,
+    },
+    /// A role is missing an attribute it requires
+    MissingRequiredAria {
+        /// The role of the element
+        role: String,
+        /// The attribute the role requires
+        attribute: &'static str,
+    },
+    /// More than one element has the same id
+    DuplicateId {
+        /// The id
+        id: String,
+    },
+}
+
+impl Display for A11yIssueKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            A11yIssueKind::MissingAlt => write!(f, "Image without alt text"),
+            A11yIssueKind::UnlabeledInput => write!(f, "Form field without a label"),
+            A11yIssueKind::InvalidRole { role } => write!(f, "Invalid ARIA role {role:?}"),
+            A11yIssueKind::UnknownAriaAttribute { attribute } => {
+                write!(f, "Unknown ARIA attribute {attribute:?}")
+            }
+            A11yIssueKind::UnsupportedAriaAttribute { attribute, role } => match role {
+                Some(role) => write!(f, "{attribute:?} is not supported by the role {role:?}"),
+                None => write!(
+                    f,
+                    "{attribute:?} is not supported by an element without a role"
+                ),
+            },
+            A11yIssueKind::MissingRequiredAria { role, attribute } => {
+                write!(f, "The role {role:?} requires {attribute:?}")
+            }
+            A11yIssueKind::DuplicateId { id } => write!(f, "Duplicate id {id:?}"),
+        }
+    }
+}
+
+/// Audit every element the VirtualDom rendered, in document order
+pub fn audit(dom: &VirtualDom) -> Vec<A11yIssue> {
+    let mut elements = Vec::new();
+    if let Some(scope) = dom.get_scope(ScopeId::ROOT) {
+        let owner = Owner {
+            component: scope.name(),
+            location: None,
+        };
+        collect_vnode(dom, scope.root_node(), owner, false, &mut elements);
+    }
+    audit_elements(&elements)
+}
+
+/// Logs the issues of a VirtualDom as warnings. Issues are only logged the first time they are found, so the audit
+/// can run after every render.
+#[derive(Default)]
+pub struct A11yAuditor {
+    reported: HashSet<A11yIssue>,
+}
+
+impl A11yAuditor {
+    /// Create a new auditor that hasn't reported any issues
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Audit the VirtualDom and log every issue that wasn't reported before
+    pub fn report(&mut self, dom: &VirtualDom) {
+        for issue in audit(dom) {
+            if !self.reported.contains(&issue) {
+                tracing::warn!("Accessibility: {issue}");
+                self.reported.insert(issue);
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Owner {
+    component: &'static str,
+    location: Option<&'static Location<'static>>,
+}
+
+struct AuditedElement {
+    tag: &'static str,
+    attributes: Vec<(&'static str, String)>,
+    owner: Owner,
+    in_label: bool,
+}
+
+impl AuditedElement {
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(attribute, _)| *attribute == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn has_non_empty(&self, name: &str) -> bool {
+        self.attribute(name)
+            .is_some_and(|value| !value.trim().is_empty())
+    }
+
+    fn explicit_role(&self) -> Option<&str> {
+        self.attribute("role")
+            .and_then(|role| role.split_whitespace().next())
+    }
+
+    fn input_type(&self) -> &str {
+        self.attribute("type").unwrap_or("text")
+    }
+
+    /// The role of the element, from its `role` attribute or its tag
+    fn role(&self) -> Option<&str> {
+        if let Some(role) = self.explicit_role() {
+            return Some(role);
+        }
+        let role = match self.tag {
+            "button" => "button",
+            "a" | "area" if self.attribute("href").is_some() => "link",
+            "input" => match self.input_type() {
+                "button" | "submit" | "reset" | "image" => "button",
+                "checkbox" => "checkbox",
+                "radio" => "radio",
+                "range" => "slider",
+                "number" => "spinbutton",
+                "search" => "searchbox",
+                "hidden" => return None,
+                _ => "textbox",
+            },
+            "textarea" => "textbox",
+            "select" => "combobox",
+            "option" => "option",
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => "heading",
+            "ul" | "ol" => "list",
+            "li" => "listitem",
+            "img" => "img",
+            "nav" => "navigation",
+            "main" => "main",
+            "header" => "banner",
+            "footer" => "contentinfo",
+            "form" => "form",
+            "dialog" => "dialog",
+            "table" => "table",
+            "tr" => "row",
+            "td" => "cell",
+            "th" => "columnheader",
+            "progress" => "progressbar",
+            "details" => "group",
+            "summary" => "button",
+            _ => return None,
+        };
+        Some(role)
+    }
+}
+
+fn collect_vnode(
+    dom: &VirtualDom,
+    vnode: &VNode,
+    owner: Owner,
+    in_label: bool,
+    elements: &mut Vec<AuditedElement>,
+) {
+    for root in vnode.template.roots {
+        collect_template_node(dom, vnode, root, owner, in_label, elements);
+    }
+}
+
+fn collect_template_node(
+    dom: &VirtualDom,
+    vnode: &VNode,
+    node: &TemplateNode,
+    owner: Owner,
+    in_label: bool,
+    elements: &mut Vec<AuditedElement>,
+) {
+    match node {
+        TemplateNode::Element {
+            tag,
+            attrs,
+            children,
+            ..
+        } => {
+            let mut attributes = Vec::new();
+            for attr in attrs.iter() {
+                match attr {
+                    TemplateAttribute::Static {
+                        name,
+                        value,
+                        namespace: None,
+                    } => attributes.push((*name, value.to_string())),
+                    TemplateAttribute::Static { .. } => {}
+                    TemplateAttribute::Dynamic { id } => {
+                        for attribute in vnode.dynamic_attrs[*id].iter() {
+                            if attribute.namespace.is_some() {
+                                continue;
+                            }
+                            let value = match &attribute.value {
+                                AttributeValue::Text(value) => value.clone(),
+                                AttributeValue::Float(value) => value.to_string(),
+                                AttributeValue::Int(value) => value.to_string(),
+                                AttributeValue::Bool(true) => "true".to_string(),
+                                _ => continue,
+                            };
+                            attributes.push((attribute.name, value));
+                        }
+                    }
+                }
+            }
+            elements.push(AuditedElement {
+                tag,
+                attributes,
+                owner,
+                in_label,
+            });
+
+            let in_label = in_label || *tag == "label";
+            for child in children.iter() {
+                collect_template_node(dom, vnode, child, owner, in_label, elements);
+            }
+        }
+        TemplateNode::Text { .. } => {}
+        TemplateNode::Dynamic { id } => match &vnode.dynamic_nodes[*id] {
+            DynamicNode::Fragment(fragment) => {
+                for child in fragment {
+                    collect_vnode(dom, child, owner, in_label, elements);
+                }
+            }
+            DynamicNode::Component(component) => {
+                if let Some(scope) = component.mounted_scope(*id, vnode, dom) {
+                    let owner = Owner {
+                        component: component.name,
+                        location: component.location(),
+                    };
+                    collect_vnode(dom, scope.root_node(), owner, in_label, elements);
+                }
+            }
+            DynamicNode::Text(_) | DynamicNode::Placeholder(_) => {}
+        },
+    }
+}
+
+fn audit_elements(elements: &[AuditedElement]) -> Vec<A11yIssue> {
+    let labelled_ids: HashSet<&str> = elements
+        .iter()
+        .filter(|element| element.tag == "label")
+        .filter_map(|element| element.attribute("for"))
+        .collect();
+    let mut ids: HashMap<&str, usize> = HashMap::new();
+
+    let mut issues = Vec::new();
+    for element in elements {
+        let mut issue = |kind| {
+            issues.push(A11yIssue {
+                kind,
+                tag: element.tag,
+                component: element.owner.component,
+                location: element.owner.location,
+            })
+        };
+
+        if let Some(id) = element.attribute("id") {
+            let count = ids.entry(id).or_default();
+            *count += 1;
+            if *count == 2 {
+                issue(A11yIssueKind::DuplicateId { id: id.to_string() });
+            }
+        }
+
+        let hidden = element.attribute("aria-hidden") == Some("true")
+            || matches!(element.explicit_role(), Some("presentation" | "none"));
+        let named = element.has_non_empty("aria-label") || element.has_non_empty("aria-labelledby");
+
+        if !hidden {
+            let is_image = matches!(element.tag, "img" | "area")
+                || (element.tag == "input" && element.input_type() == "image");
+            if is_image && element.attribute("alt").is_none() && !named {
+                issue(A11yIssueKind::MissingAlt);
+            }
+
+            let is_field = match element.tag {
+                "select" | "textarea" => true,
+                "input" => !matches!(
+                    element.input_type(),
+                    "hidden" | "submit" | "reset" | "button" | "image"
+                ),
+                _ => false,
+            };
+            let labelled = named
+                || element.has_non_empty("title")
+                || element.in_label
+                || element
+                    .attribute("id")
+                    .is_some_and(|id| labelled_ids.contains(id));
+            if is_field && !labelled {
+                issue(A11yIssueKind::UnlabeledInput);
+            }
+        }
+
+        if let Some(role) = element.explicit_role() {
+            if role.parse::<AriaRole>().is_err() {
+                issue(A11yIssueKind::InvalidRole {
+                    role: role.to_string(),
+                });
+            } else {
+                for (required_role, attribute) in REQUIRED_ATTRIBUTES {
+                    if *required_role == role && element.attribute(attribute).is_none() {
+                        issue(A11yIssueKind::MissingRequiredAria {
+                            role: role.to_string(),
+                            attribute,
+                        });
+                    }
+                }
+            }
+        }
+
+        let role = element.role();
+        for (attribute, _) in &element.attributes {
+            if !attribute.starts_with("aria-") {
+                continue;
+            }
+            if GLOBAL_ATTRIBUTES.contains(attribute) {
+                continue;
+            }
+            match ROLE_ATTRIBUTES.iter().find(|(name, _)| name == attribute) {
+                None => issue(A11yIssueKind::UnknownAriaAttribute {
+                    attribute: attribute.to_string(),
+                }),
+                Some((_, roles)) => {
+                    // Invalid roles are reported on their own
+                    if role.is_some_and(|role| {
+                        role.parse::<AriaRole>().is_err() || roles.contains(&role)
+                    }) {
+                        continue;
+                    }
+                    issue(A11yIssueKind::UnsupportedAriaAttribute {
+                        attribute: attribute.to_string(),
+                        role: role.map(str::to_string),
+                    });
+                }
+            }
+        }
+    }
+    issues
+}
+
+/// ARIA attributes that are allowed on every element
+const GLOBAL_ATTRIBUTES: &[&str] = &[
+    "aria-atomic",
+    "aria-busy",
+    "aria-controls",
+    "aria-current",
+    "aria-describedby",
+    "aria-description",
+    "aria-details",
+    "aria-disabled",
+    "aria-dropeffect",
+    "aria-errormessage",
+    "aria-flowto",
+    "aria-grabbed",
+    "aria-haspopup",
+    "aria-hidden",
+    "aria-invalid",
+    "aria-keyshortcuts",
+    "aria-label",
+    "aria-labelledby",
+    "aria-live",
+    "aria-owns",
+    "aria-relevant",
+    "aria-roledescription",
+];
+
+/// ARIA attributes that are only allowed on some roles
+#[rustfmt::skip]
+const ROLE_ATTRIBUTES: &[(&str, &[&str])] = &[
+    ("aria-activedescendant", &["application", "combobox", "grid", "group", "listbox", "menu", "menubar", "radiogroup", "row", "searchbox", "spinbutton", "tablist", "textbox", "toolbar", "tree", "treegrid"]),
+    ("aria-autocomplete", &["combobox", "searchbox", "textbox"]),
+    ("aria-checked", &["checkbox", "menuitemcheckbox", "menuitemradio", "option", "radio", "switch", "treeitem"]),
+    ("aria-colcount", &["grid", "table", "treegrid"]),
+    ("aria-colindex", &["cell", "columnheader", "gridcell", "row", "rowheader"]),
+    ("aria-colspan", &["cell", "columnheader", "gridcell", "rowheader"]),
+    ("aria-expanded", &["application", "button", "checkbox", "combobox", "gridcell", "link", "listbox", "menuitem", "menuitemcheckbox", "menuitemradio", "row", "rowheader", "switch", "tab", "treeitem"]),
+    ("aria-level", &["heading", "listitem", "row", "treeitem"]),
+    ("aria-modal", &["alertdialog", "dialog"]),
+    ("aria-multiline", &["searchbox", "textbox"]),
+    ("aria-multiselectable", &["grid", "listbox", "tablist", "tree", "treegrid"]),
+    ("aria-orientation", &["listbox", "menu", "menubar", "radiogroup", "scrollbar", "separator", "slider", "tablist", "toolbar", "tree", "treegrid"]),
+    ("aria-placeholder", &["searchbox", "textbox"]),
+    ("aria-posinset", &["article", "listitem", "menuitem", "menuitemcheckbox", "menuitemradio", "option", "radio", "row", "tab", "treeitem"]),
+    ("aria-pressed", &["button"]),
+    ("aria-readonly", &["checkbox", "combobox", "grid", "gridcell", "listbox", "radiogroup", "searchbox", "slider", "spinbutton", "textbox", "treegrid"]),
+    ("aria-required", &["checkbox", "combobox", "gridcell", "listbox", "radiogroup", "searchbox", "spinbutton", "textbox", "tree", "treegrid"]),
+    ("aria-rowcount", &["grid", "table", "treegrid"]),
+    ("aria-rowindex", &["cell", "columnheader", "gridcell", "row", "rowheader"]),
+    ("aria-rowspan", &["cell", "columnheader", "gridcell", "rowheader"]),
+    ("aria-selected", &["gridcell", "option", "row", "tab", "treeitem"]),
+    ("aria-setsize", &["article", "listitem", "menuitem", "menuitemcheckbox", "menuitemradio", "option", "radio", "row", "tab", "treeitem"]),
+    ("aria-sort", &["columnheader", "rowheader"]),
+    ("aria-valuemax", &["meter", "progressbar", "scrollbar", "separator", "slider", "spinbutton"]),
+    ("aria-valuemin", &["meter", "progressbar", "scrollbar", "separator", "slider", "spinbutton"]),
+    ("aria-valuenow", &["meter", "progressbar", "scrollbar", "separator", "slider", "spinbutton"]),
+    ("aria-valuetext", &["meter", "progressbar", "scrollbar", "separator", "slider", "spinbutton"]),
+];
+
+/// Attributes that elements with an explicit role must set
+const REQUIRED_ATTRIBUTES: &[(&str, &str)] = &[
+    ("checkbox", "aria-checked"),
+    ("combobox", "aria-expanded"),
+    ("heading", "aria-level"),
+    ("menuitemcheckbox", "aria-checked"),
+    ("menuitemradio", "aria-checked"),
+    ("meter", "aria-valuenow"),
+    ("radio", "aria-checked"),
+    ("scrollbar", "aria-controls"),
+    ("scrollbar", "aria-valuenow"),
+    ("slider", "aria-valuenow"),
+    ("switch", "aria-checked"),
+];
@@ -0,0 +1,504 @@
+//! Typed values for the `role` and `aria-*` attributes.
+//!
+//! The `role` and `aria-*` attributes accept any value, so a typo only shows up in a screen reader. The enums in this
+//! module can be passed to those attributes instead of strings:
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_html::aria::{AriaLive, AriaRole};
+//!
+//! fn app() -> Element {
+//!     rsx! {
+//!         div { role: AriaRole::Status, aria_live: AriaLive::Polite, "Saved" }
+//!     }
+//! }
+//! ```
+//!
+//! Some roles don't work without an attribute, like a `checkbox` without `aria-checked`. The functions at the end of
+//! this module take the attributes a role requires and return all of them, so forgetting one is a compile error:
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_html::aria::{self, AriaChecked};
+//!
+//! fn app() -> Element {
+//!     rsx! {
+//!         div { tabindex: 0, ..aria::checkbox(AriaChecked::Mixed), "Select all" }
+//!     }
+//! }
+//! ```
+
+use dioxus_core::prelude::IntoAttributeValue;
+use dioxus_core::{Attribute, AttributeValue};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A value that is not one of the values of an ARIA enum
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownAriaValue(pub String);
+
+impl Display for UnknownAriaValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} is not a valid ARIA value", self.0)
+    }
+}
+
+impl std::error::Error for UnknownAriaValue {}
+
+macro_rules! aria_enum {
+    (
+        $(#[$attr:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[$variant_attr:meta])*
+                $variant:ident => $value:literal,
+            )*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $(
+                $(#[$variant_attr])*
+                $variant,
+            )*
+        }
+
+        impl $name {
+            /// Every value of the enum
+            pub const ALL: &'static [Self] = &[$(Self::$variant),*];
+
+            /// The value of the attribute
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $value,)*
+                }
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = UnknownAriaValue;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                match value {
+                    $($value => Ok(Self::$variant),)*
+                    _ => Err(UnknownAriaValue(value.to_string())),
+                }
+            }
+        }
+
+        impl IntoAttributeValue for $name {
+            fn into_value(self) -> AttributeValue {
+                AttributeValue::Text(self.as_str().to_string())
+            }
+        }
+    };
+}
+
+aria_enum! {
+    /// Every concrete ARIA role. Abstract roles like `widget` are not allowed in the `role` attribute.
+    ///
+    /// <https://www.w3.org/TR/wai-aria-1.2/#role_definitions>
+    pub enum AriaRole {
+        /// `alert`
+        Alert => "alert",
+        /// `alertdialog`
+        AlertDialog => "alertdialog",
+        /// `application`
+        Application => "application",
+        /// `article`
+        Article => "article",
+        /// `banner`
+        Banner => "banner",
+        /// `blockquote`
+        Blockquote => "blockquote",
+        /// `button`
+        Button => "button",
+        /// `caption`
+        Caption => "caption",
+        /// `cell`
+        Cell => "cell",
+        /// `checkbox`. Requires `aria-checked`, see [`checkbox`].
+        Checkbox => "checkbox",
+        /// `code`
+        Code => "code",
+        /// `columnheader`
+        ColumnHeader => "columnheader",
+        /// `combobox`. Requires `aria-expanded`, see [`combobox`].
+        Combobox => "combobox",
+        /// `complementary`
+        Complementary => "complementary",
+        /// `contentinfo`
+        ContentInfo => "contentinfo",
+        /// `definition`
+        Definition => "definition",
+        /// `deletion`
+        Deletion => "deletion",
+        /// `dialog`
+        Dialog => "dialog",
+        /// `directory`
+        Directory => "directory",
+        /// `document`
+        Document => "document",
+        /// `emphasis`
+        Emphasis => "emphasis",
+        /// `feed`
+        Feed => "feed",
+        /// `figure`
+        Figure => "figure",
+        /// `form`
+        Form => "form",
+        /// `generic`
+        Generic => "generic",
+        /// `grid`
+        Grid => "grid",
+        /// `gridcell`
+        GridCell => "gridcell",
+        /// `group`
+        Group => "group",
+        /// `heading`. Requires `aria-level`, see [`heading`].
+        Heading => "heading",
+        /// `img`
+        Img => "img",
+        /// `insertion`
+        Insertion => "insertion",
+        /// `link`
+        Link => "link",
+        /// `list`
+        List => "list",
+        /// `listbox`
+        Listbox => "listbox",
+        /// `listitem`
+        ListItem => "listitem",
+        /// `log`
+        Log => "log",
+        /// `main`
+        Main => "main",
+        /// `mark`
+        Mark => "mark",
+        /// `marquee`
+        Marquee => "marquee",
+        /// `math`
+        Math => "math",
+        /// `menu`
+        Menu => "menu",
+        /// `menubar`
+        Menubar => "menubar",
+        /// `menuitem`
+        MenuItem => "menuitem",
+        /// `menuitemcheckbox`. Requires `aria-checked`, see [`menuitemcheckbox`].
+        MenuItemCheckbox => "menuitemcheckbox",
+        /// `menuitemradio`. Requires `aria-checked`, see [`menuitemradio`].
+        MenuItemRadio => "menuitemradio",
+        /// `meter`. Requires `aria-valuenow`, see [`meter`].
+        Meter => "meter",
+        /// `navigation`
+        Navigation => "navigation",
+        /// `none`
+        None => "none",
+        /// `note`
+        Note => "note",
+        /// `option`
+        Option => "option",
+        /// `paragraph`
+        Paragraph => "paragraph",
+        /// `presentation`
+        Presentation => "presentation",
+        /// `progressbar`
+        ProgressBar => "progressbar",
+        /// `radio`. Requires `aria-checked`, see [`radio`].
+        Radio => "radio",
+        /// `radiogroup`
+        RadioGroup => "radiogroup",
+        /// `region`
+        Region => "region",
+        /// `row`
+        Row => "row",
+        /// `rowgroup`
+        RowGroup => "rowgroup",
+        /// `rowheader`
+        RowHeader => "rowheader",
+        /// `scrollbar`. Requires `aria-controls` and `aria-valuenow`, see [`scrollbar`].
+        Scrollbar => "scrollbar",
+        /// `search`
+        Search => "search",
+        /// `searchbox`
+        Searchbox => "searchbox",
+        /// `separator`
+        Separator => "separator",
+        /// `slider`. Requires `aria-valuenow`, see [`slider`].
+        Slider => "slider",
+        /// `spinbutton`
+        SpinButton => "spinbutton",
+        /// `status`
+        Status => "status",
+        /// `strong`
+        Strong => "strong",
+        /// `subscript`
+        Subscript => "subscript",
+        /// `superscript`
+        Superscript => "superscript",
+        /// `switch`. Requires `aria-checked`, see [`switch`].
+        Switch => "switch",
+        /// `tab`
+        Tab => "tab",
+        /// `table`
+        Table => "table",
+        /// `tablist`
+        TabList => "tablist",
+        /// `tabpanel`
+        TabPanel => "tabpanel",
+        /// `term`
+        Term => "term",
+        /// `textbox`
+        Textbox => "textbox",
+        /// `time`
+        Time => "time",
+        /// `timer`
+        Timer => "timer",
+        /// `toolbar`
+        Toolbar => "toolbar",
+        /// `tooltip`
+        Tooltip => "tooltip",
+        /// `tree`
+        Tree => "tree",
+        /// `treegrid`
+        TreeGrid => "treegrid",
+        /// `treeitem`
+        TreeItem => "treeitem",
+    }
+}
+
+aria_enum! {
+    /// The value of `aria-checked` and `aria-pressed`
+    pub enum AriaChecked {
+        /// The element is checked or pressed
+        True => "true",
+        /// The element is not checked or pressed
+        False => "false",
+        /// The element is partially checked or pressed, like a checkbox that selects a group that is partly selected
+        Mixed => "mixed",
+    }
+}
+
+aria_enum! {
+    /// The value of `aria-current`
+    pub enum AriaCurrent {
+        /// The current page in a set of pages
+        Page => "page",
+        /// The current step in a process
+        Step => "step",
+        /// The current location in a flow chart or map
+        Location => "location",
+        /// The current date in a calendar
+        Date => "date",
+        /// The current time in a timetable
+        Time => "time",
+        /// The current item in a set
+        True => "true",
+        /// Not the current item
+        False => "false",
+    }
+}
+
+aria_enum! {
+    /// The value of `aria-live`
+    pub enum AriaLive {
+        /// Updates are not announced
+        Off => "off",
+        /// Updates are announced when the user is idle
+        Polite => "polite",
+        /// Updates are announced immediately
+        Assertive => "assertive",
+    }
+}
+
+aria_enum! {
+    /// The value of `aria-autocomplete`
+    pub enum AriaAutocomplete {
+        /// No suggestions are shown
+        None => "none",
+        /// The rest of the suggestion is inserted after the cursor
+        Inline => "inline",
+        /// Suggestions are shown in a list
+        List => "list",
+        /// Suggestions are shown in a list and inserted after the cursor
+        Both => "both",
+    }
+}
+
+aria_enum! {
+    /// The value of `aria-orientation`
+    pub enum AriaOrientation {
+        /// The element is horizontal
+        Horizontal => "horizontal",
+        /// The element is vertical
+        Vertical => "vertical",
+    }
+}
+
+aria_enum! {
+    /// The value of `aria-sort`
+    pub enum AriaSort {
+        /// Sorted in ascending order
+        Ascending => "ascending",
+        /// Sorted in descending order
+        Descending => "descending",
+        /// Not sorted
+        None => "none",
+        /// Sorted by another algorithm
+        Other => "other",
+    }
+}
+
+aria_enum! {
+    /// The value of `aria-haspopup`
+    pub enum AriaHasPopup {
+        /// The element opens a menu
+        True => "true",
+        /// The element doesn't open a popup
+        False => "false",
+        /// The element opens a menu
+        Menu => "menu",
+        /// The element opens a listbox
+        Listbox => "listbox",
+        /// The element opens a tree
+        Tree => "tree",
+        /// The element opens a grid
+        Grid => "grid",
+        /// The element opens a dialog
+        Dialog => "dialog",
+    }
+}
+
+aria_enum! {
+    /// The value of `aria-invalid`
+    pub enum AriaInvalid {
+        /// The value is invalid
+        True => "true",
+        /// The value is valid
+        False => "false",
+        /// The value has a grammar error
+        Grammar => "grammar",
+        /// The value has a spelling error
+        Spelling => "spelling",
+    }
+}
+
+macro_rules! from_bool {
+    ($($name:ident),*) => {
+        $(
+            impl From<bool> for $name {
+                fn from(value: bool) -> Self {
+                    if value {
+                        Self::True
+                    } else {
+                        Self::False
+                    }
+                }
+            }
+        )*
+    };
+}
+
+from_bool!(AriaChecked, AriaCurrent, AriaHasPopup, AriaInvalid);
+
+/// ARIA states are the strings `"true"` and `"false"`, not boolean attributes that are removed when they are false
+fn aria_bool(value: bool) -> &'static str {
+    if value {
+        "true"
+    } else {
+        "false"
+    }
+}
+
+fn attribute(name: &'static str, value: impl IntoAttributeValue) -> Attribute {
+    Attribute::new(name, value, None, false)
+}
+
+/// The attributes of a `checkbox`
+pub fn checkbox(checked: impl Into<AriaChecked>) -> Vec<Attribute> {
+    vec![
+        attribute("role", AriaRole::Checkbox),
+        attribute("aria-checked", checked.into()),
+    ]
+}
+
+/// The attributes of a `switch`
+pub fn switch(checked: bool) -> Vec<Attribute> {
+    vec![
+        attribute("role", AriaRole::Switch),
+        attribute("aria-checked", aria_bool(checked)),
+    ]
+}
+
+/// The attributes of a `radio` button
+pub fn radio(checked: bool) -> Vec<Attribute> {
+    vec![
+        attribute("role", AriaRole::Radio),
+        attribute("aria-checked", aria_bool(checked)),
+    ]
+}
+
+/// The attributes of a `menuitemcheckbox`
+pub fn menuitemcheckbox(checked: impl Into<AriaChecked>) -> Vec<Attribute> {
+    vec![
+        attribute("role", AriaRole::MenuItemCheckbox),
+        attribute("aria-checked", checked.into()),
+    ]
+}
+
+/// The attributes of a `menuitemradio`
+pub fn menuitemradio(checked: bool) -> Vec<Attribute> {
+    vec![
+        attribute("role", AriaRole::MenuItemRadio),
+        attribute("aria-checked", aria_bool(checked)),
+    ]
+}
+
+/// The attributes of a `combobox`
+pub fn combobox(expanded: bool) -> Vec<Attribute> {
+    vec![
+        attribute("role", AriaRole::Combobox),
+        attribute("aria-expanded", aria_bool(expanded)),
+    ]
+}
+
+/// The attributes of a `heading`. Levels start at 1 like `h1`.
+pub fn heading(level: u8) -> Vec<Attribute> {
+    vec![
+        attribute("role", AriaRole::Heading),
+        attribute("aria-level", level as i64),
+    ]
+}
+
+/// The attributes of a `meter` with its current value
+pub fn meter(value: f64) -> Vec<Attribute> {
+    vec![
+        attribute("role", AriaRole::Meter),
+        attribute("aria-valuenow", value),
+    ]
+}
+
+/// The attributes of a `slider` with its current value
+pub fn slider(value: f64) -> Vec<Attribute> {
+    vec![
+        attribute("role", AriaRole::Slider),
+        attribute("aria-valuenow", value),
+    ]
+}
+
+/// The attributes of a `scrollbar` with the id of the element it scrolls and its current value
+pub fn scrollbar(controls: impl ToString, value: f64) -> Vec<Attribute> {
+    vec![
+        attribute("role", AriaRole::Scrollbar),
+        attribute("aria-controls", controls.to_string()),
+        attribute("aria-valuenow", value),
+    ]
+}
@@ -87,6 +87,31 @@ macro_rules! html_to_rsx_attribute_mapping {
     };
 }
 
+/// Create a typed method on [`crate::InlineStyle`] for every attribute in the style namespace. Style is the only
+/// namespace attributes are declared in, and forwarded literals can't be matched against `"style"`, so any namespaced
+/// attribute is treated as a style
+macro_rules! inline_style_method {
+    (
+        $(#[$attr:meta])*
+        $name:ident: $(no-$alias:ident)? $lit:literal in $ns:literal;
+    ) => {
+        $(#[$attr])*
+        pub fn $name(self, value: impl IntoAttributeValue) -> Self {
+            self.property($lit, value)
+        }
+    };
+    (
+        $(#[$attr:meta])*
+        $name:ident in $ns:literal;
+    ) => {
+        $(#[$attr])*
+        pub fn $name(self, value: impl IntoAttributeValue) -> Self {
+            self.property(stringify!($name), value)
+        }
+    };
+    ($($rest:tt)*) => {};
+}
+
 macro_rules! mod_methods {
     (
         @base
@@ -135,6 +160,15 @@ macro_rules! mod_methods {
         }
 
         impl_extension_attributes![$mod { $($name,)* }];
+
+        impl crate::InlineStyle {
+            $(
+                inline_style_method! {
+                    $(#[$attr])*
+                    $name $(: $(no-$alias)? $js_name)? $(in $ns)?;
+                }
+            )+
+        }
     };
 
     (
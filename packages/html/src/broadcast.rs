@@ -0,0 +1,291 @@
+//! Send typed messages to the other tabs and windows of the app.
+//!
+//! [`use_broadcast_channel`] connects to a named channel. Every message posted on the channel is delivered to every
+//! other connection to a channel with the same name. On the web and in webviews the channel is a
+//! [BroadcastChannel](https://developer.mozilla.org/en-US/docs/Web/API/BroadcastChannel) that reaches every tab of the
+//! same origin. The windows of a desktop app run in the same process and use the [`ProcessBroadcastBackend`] instead.
+//! Other platforms can provide a different [`BroadcastBackend`] with [`provide_broadcast_backend`].
+
+use crate::document::{document, UseEval};
+use dioxus_core::prelude::*;
+use dioxus_signals::{CopyValue, Readable};
+use futures_channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+use futures_util::StreamExt;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::{Mutex, OnceLock};
+
+/// An error posting a message to a channel
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum BroadcastError {
+    /// The connection to the channel was closed
+    Closed,
+    /// The message couldn't be converted to JSON
+    Serialization(String),
+}
+
+impl Display for BroadcastError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BroadcastError::Closed => write!(f, "the broadcast channel is closed"),
+            BroadcastError::Serialization(err) => write!(f, "failed to serialize message: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for BroadcastError {}
+
+/// A way to deliver messages between the contexts of an app, like tabs or windows. [`use_broadcast_channel`] uses the
+/// backend provided with [`provide_broadcast_backend`], or a [`ScriptBroadcastBackend`] if no backend was provided.
+pub trait BroadcastBackend {
+    /// Connect to the channel with a name
+    fn open(&self, channel: &str) -> Rc<dyn BroadcastConnection>;
+}
+
+/// A connection to a channel opened by a [`BroadcastBackend`]
+pub trait BroadcastConnection {
+    /// Post a message to every other connection to the channel. The connection doesn't receive its own messages.
+    fn post(&self, message: Value) -> Result<(), BroadcastError>;
+
+    /// Wait for the next message another connection posted, or `None` once the connection is closed
+    fn recv(&self) -> Pin<Box<dyn Future<Output = Option<Value>>>>;
+
+    /// Close the connection. It doesn't receive messages anymore.
+    fn close(&self);
+}
+
+const BROADCAST_SCRIPT: &str = r#"
+const channel = new BroadcastChannel({NAME});
+channel.onmessage = (event) => dioxus.send(event.data);
+while (true) {
+    // A message to post, or null once the connection is closed
+    const command = await dioxus.recv();
+    if (!command) break;
+    channel.postMessage(command.post);
+}
+channel.close();
+"#;
+
+/// A [`BroadcastBackend`] that posts messages with the BroadcastChannel of the browser or webview
+#[derive(Clone, Copy, Default)]
+pub struct ScriptBroadcastBackend;
+
+impl BroadcastBackend for ScriptBroadcastBackend {
+    fn open(&self, channel: &str) -> Rc<dyn BroadcastConnection> {
+        let name = Value::String(channel.to_string()).to_string();
+        let script = BROADCAST_SCRIPT.replace("{NAME}", &name);
+        Rc::new(ScriptConnection {
+            eval: UseEval::new(document().new_evaluator(script)),
+        })
+    }
+}
+
+struct ScriptConnection {
+    eval: UseEval,
+}
+
+impl BroadcastConnection for ScriptConnection {
+    fn post(&self, message: Value) -> Result<(), BroadcastError> {
+        self.eval
+            .send(json!({ "post": message }))
+            .map_err(|_| BroadcastError::Closed)
+    }
+
+    fn recv(&self) -> Pin<Box<dyn Future<Output = Option<Value>>>> {
+        let mut eval = self.eval;
+        Box::pin(async move { eval.recv().await.ok() })
+    }
+
+    fn close(&self) {
+        _ = self.eval.send(Value::Null);
+    }
+}
+
+type Subscribers = HashMap<String, Vec<(usize, UnboundedSender<Value>)>>;
+
+fn process_subscribers() -> &'static Mutex<(usize, Subscribers)> {
+    static SUBSCRIBERS: OnceLock<Mutex<(usize, Subscribers)>> = OnceLock::new();
+    SUBSCRIBERS.get_or_init(Default::default)
+}
+
+/// A [`BroadcastBackend`] that delivers messages to every connection in the same process, like the other windows of
+/// a desktop app. The desktop renderer provides this backend for every window if its `broadcast` feature is enabled.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// use dioxus_html::broadcast::{provide_broadcast_backend, ProcessBroadcastBackend};
+///
+/// fn app() -> Element {
+///     use_hook(|| provide_broadcast_backend(ProcessBroadcastBackend));
+///     rsx! {}
+/// }
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct ProcessBroadcastBackend;
+
+impl BroadcastBackend for ProcessBroadcastBackend {
+    fn open(&self, channel: &str) -> Rc<dyn BroadcastConnection> {
+        let (sender, receiver) = unbounded();
+        let mut subscribers = process_subscribers().lock().unwrap();
+        let (next_id, channels) = &mut *subscribers;
+        let id = *next_id;
+        *next_id += 1;
+        channels
+            .entry(channel.to_string())
+            .or_default()
+            .push((id, sender));
+        Rc::new(ProcessConnection {
+            channel: channel.to_string(),
+            id,
+            receiver: Rc::new(RefCell::new(receiver)),
+        })
+    }
+}
+
+struct ProcessConnection {
+    channel: String,
+    id: usize,
+    receiver: Rc<RefCell<UnboundedReceiver<Value>>>,
+}
+
+impl BroadcastConnection for ProcessConnection {
+    fn post(&self, message: Value) -> Result<(), BroadcastError> {
+        let subscribers = process_subscribers().lock().unwrap();
+        let connections = subscribers.1.get(&self.channel);
+        if !connections.is_some_and(|connections| connections.iter().any(|(id, _)| *id == self.id))
+        {
+            return Err(BroadcastError::Closed);
+        }
+        for (id, sender) in connections.into_iter().flatten() {
+            if *id != self.id {
+                _ = sender.unbounded_send(message.clone());
+            }
+        }
+        Ok(())
+    }
+
+    fn recv(&self) -> Pin<Box<dyn Future<Output = Option<Value>>>> {
+        let receiver = self.receiver.clone();
+        Box::pin(async move {
+            std::future::poll_fn(|cx| receiver.borrow_mut().poll_next_unpin(cx)).await
+        })
+    }
+
+    fn close(&self) {
+        let mut subscribers = process_subscribers().lock().unwrap();
+        if let Some(connections) = subscribers.1.get_mut(&self.channel) {
+            // Dropping the sender ends the receiver
+            connections.retain(|(id, _)| *id != self.id);
+            if connections.is_empty() {
+                subscribers.1.remove(&self.channel);
+            }
+        }
+    }
+}
+
+/// Use a different [`BroadcastBackend`] for every channel opened with [`use_broadcast_channel`] in the app. Call this
+/// before opening any channels, for example in a `use_hook` in the root component.
+pub fn provide_broadcast_backend(backend: impl BroadcastBackend + 'static) {
+    provide_root_context(Rc::new(backend) as Rc<dyn BroadcastBackend>);
+}
+
+/// A typed connection to a channel created with [`use_broadcast_channel`]
+pub struct UseBroadcastChannel<T: 'static> {
+    connection: CopyValue<Rc<dyn BroadcastConnection>>,
+    phantom: PhantomData<fn(T)>,
+}
+
+impl<T: Serialize + 'static> UseBroadcastChannel<T> {
+    /// Post a message to every other connection to the channel
+    pub fn post(&self, message: &T) -> Result<(), BroadcastError> {
+        let message = serde_json::to_value(message)
+            .map_err(|err| BroadcastError::Serialization(err.to_string()))?;
+        self.connection.read().post(message)
+    }
+}
+
+impl<T: 'static> Clone for UseBroadcastChannel<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for UseBroadcastChannel<T> {}
+
+impl<T: 'static> PartialEq for UseBroadcastChannel<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.connection == other.connection
+    }
+}
+
+/// Connect to a channel to post typed messages to the other tabs and windows of the app, and receive theirs.
+///
+/// `on_message` is called with every message another connection posts to the channel. Messages that can't be
+/// deserialized into `T` are ignored. The connection is closed when the component is dropped.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_html::broadcast::use_broadcast_channel;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// enum Session {
+///     LoggedOut,
+/// }
+///
+/// fn app() -> Element {
+///     let mut logged_in = use_signal(|| true);
+///     // Log out in every tab when the user logs out in one of them
+///     let session = use_broadcast_channel("session", move |message: Session| match message {
+///         Session::LoggedOut => logged_in.set(false),
+///     });
+///
+///     rsx! {
+///         if logged_in() {
+///             button {
+///                 onclick: move |_| {
+///                     logged_in.set(false);
+///                     _ = session.post(&Session::LoggedOut);
+///                 },
+///                 "Log out everywhere"
+///             }
+///         } else {
+///             "Logged out"
+///         }
+///     }
+/// }
+/// ```
+pub fn use_broadcast_channel<T: Serialize + DeserializeOwned + 'static>(
+    channel: impl Into<String>,
+    mut on_message: impl FnMut(T) + 'static,
+) -> UseBroadcastChannel<T> {
+    let channel = channel.into();
+    let connection = use_hook(move || {
+        let backend = try_consume_context::<Rc<dyn BroadcastBackend>>()
+            .unwrap_or_else(|| Rc::new(ScriptBroadcastBackend));
+        let connection = backend.open(&channel);
+        let messages = connection.clone();
+        spawn(async move {
+            while let Some(message) = messages.recv().await {
+                if let Ok(message) = serde_json::from_value(message) {
+                    on_message(message);
+                }
+            }
+        });
+        CopyValue::new(connection)
+    });
+    use_drop(move || connection.read().close());
+
+    UseBroadcastChannel {
+        connection,
+        phantom: PhantomData,
+    }
+}
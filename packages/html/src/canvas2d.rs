@@ -0,0 +1,480 @@
+//! Draw on a `canvas` with a platform agnostic 2D command API.
+
+use crate::document::document;
+use crate::{self as dioxus_elements};
+use dioxus_core::prelude::*;
+use dioxus_core_macro::{component, rsx, Props};
+use dioxus_hooks::{use_effect, use_memo, use_reactive};
+use dioxus_signals::{GlobalSignal, Owner, Readable};
+use serde_json::{json, Value};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// A single drawing operation recorded by a [`DrawContext`]. The commands mirror the methods and properties of the
+/// browser's [`CanvasRenderingContext2D`](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D).
+///
+/// Renderers without a browser canvas can draw the commands from [`DrawContext::commands`] themselves.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum DrawCommand {
+    /// Set the color, gradient or pattern shapes are filled with
+    FillStyle(String),
+    /// Set the color, gradient or pattern of lines
+    StrokeStyle(String),
+    /// Set the width of lines
+    LineWidth(f64),
+    /// Set the font of text, like `16px sans-serif`
+    Font(String),
+    /// Set the horizontal alignment of text: `left`, `right`, `center`, `start` or `end`
+    TextAlign(String),
+    /// Set the opacity of everything drawn after this command
+    GlobalAlpha(f64),
+    /// Fill a rectangle
+    FillRect {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
+    /// Outline a rectangle
+    StrokeRect {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
+    /// Make a rectangle transparent
+    ClearRect {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
+    /// Start a new path
+    BeginPath,
+    /// Draw a line back to the start of the current path
+    ClosePath,
+    /// Move the start of the next line of the path without drawing
+    MoveTo { x: f64, y: f64 },
+    /// Add a straight line to the path
+    LineTo { x: f64, y: f64 },
+    /// Add an arc around a center point to the path. The angles are in radians
+    Arc {
+        x: f64,
+        y: f64,
+        radius: f64,
+        start_angle: f64,
+        end_angle: f64,
+    },
+    /// Add a rectangle to the path
+    Rect {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
+    /// Add a quadratic Bézier curve to the path
+    QuadraticCurveTo { cpx: f64, cpy: f64, x: f64, y: f64 },
+    /// Add a cubic Bézier curve to the path
+    BezierCurveTo {
+        cp1x: f64,
+        cp1y: f64,
+        cp2x: f64,
+        cp2y: f64,
+        x: f64,
+        y: f64,
+    },
+    /// Fill the current path
+    Fill,
+    /// Outline the current path
+    Stroke,
+    /// Fill text at a position
+    FillText { text: String, x: f64, y: f64 },
+    /// Outline text at a position
+    StrokeText { text: String, x: f64, y: f64 },
+    /// Save the styles and transform
+    Save,
+    /// Restore the last saved styles and transform
+    Restore,
+    /// Move the origin
+    Translate { x: f64, y: f64 },
+    /// Rotate around the origin. The angle is in radians
+    Rotate(f64),
+    /// Scale everything drawn after this command
+    Scale { x: f64, y: f64 },
+}
+
+impl DrawCommand {
+    /// Convert the command to the JSON form the canvas script replays: `["method", ...args]` for methods and
+    /// `["=property", value]` for properties.
+    fn to_json(&self) -> Value {
+        match self {
+            DrawCommand::FillStyle(style) => json!(["=fillStyle", style]),
+            DrawCommand::StrokeStyle(style) => json!(["=strokeStyle", style]),
+            DrawCommand::LineWidth(width) => json!(["=lineWidth", width]),
+            DrawCommand::Font(font) => json!(["=font", font]),
+            DrawCommand::TextAlign(align) => json!(["=textAlign", align]),
+            DrawCommand::GlobalAlpha(alpha) => json!(["=globalAlpha", alpha]),
+            DrawCommand::FillRect {
+                x,
+                y,
+                width,
+                height,
+            } => json!(["fillRect", x, y, width, height]),
+            DrawCommand::StrokeRect {
+                x,
+                y,
+                width,
+                height,
+            } => {
+                json!(["strokeRect", x, y, width, height])
+            }
+            DrawCommand::ClearRect {
+                x,
+                y,
+                width,
+                height,
+            } => {
+                json!(["clearRect", x, y, width, height])
+            }
+            DrawCommand::BeginPath => json!(["beginPath"]),
+            DrawCommand::ClosePath => json!(["closePath"]),
+            DrawCommand::MoveTo { x, y } => json!(["moveTo", x, y]),
+            DrawCommand::LineTo { x, y } => json!(["lineTo", x, y]),
+            DrawCommand::Arc {
+                x,
+                y,
+                radius,
+                start_angle,
+                end_angle,
+            } => json!(["arc", x, y, radius, start_angle, end_angle]),
+            DrawCommand::Rect {
+                x,
+                y,
+                width,
+                height,
+            } => json!(["rect", x, y, width, height]),
+            DrawCommand::QuadraticCurveTo { cpx, cpy, x, y } => {
+                json!(["quadraticCurveTo", cpx, cpy, x, y])
+            }
+            DrawCommand::BezierCurveTo {
+                cp1x,
+                cp1y,
+                cp2x,
+                cp2y,
+                x,
+                y,
+            } => json!(["bezierCurveTo", cp1x, cp1y, cp2x, cp2y, x, y]),
+            DrawCommand::Fill => json!(["fill"]),
+            DrawCommand::Stroke => json!(["stroke"]),
+            DrawCommand::FillText { text, x, y } => json!(["fillText", text, x, y]),
+            DrawCommand::StrokeText { text, x, y } => json!(["strokeText", text, x, y]),
+            DrawCommand::Save => json!(["save"]),
+            DrawCommand::Restore => json!(["restore"]),
+            DrawCommand::Translate { x, y } => json!(["translate", x, y]),
+            DrawCommand::Rotate(angle) => json!(["rotate", angle]),
+            DrawCommand::Scale { x, y } => json!(["scale", x, y]),
+        }
+    }
+}
+
+/// Records the drawing commands of one frame of a [`Canvas`]. Clones of the context record into the same frame.
+#[derive(Clone, Default)]
+pub struct DrawContext {
+    width: f64,
+    height: f64,
+    commands: Rc<RefCell<Vec<DrawCommand>>>,
+}
+
+impl DrawContext {
+    /// Create an empty context for a canvas with a size in pixels
+    pub fn new(width: f64, height: f64) -> Self {
+        Self {
+            width,
+            height,
+            commands: Default::default(),
+        }
+    }
+
+    /// Get the width of the canvas in pixels
+    pub fn width(&self) -> f64 {
+        self.width
+    }
+
+    /// Get the height of the canvas in pixels
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    /// Get the commands that were recorded so far
+    pub fn commands(&self) -> Vec<DrawCommand> {
+        self.commands.borrow().clone()
+    }
+
+    /// Record a command
+    pub fn push(&self, command: DrawCommand) -> &Self {
+        self.commands.borrow_mut().push(command);
+        self
+    }
+
+    /// Set the color, gradient or pattern shapes are filled with
+    pub fn fill_style(&self, style: impl Into<String>) -> &Self {
+        self.push(DrawCommand::FillStyle(style.into()))
+    }
+
+    /// Set the color, gradient or pattern of lines
+    pub fn stroke_style(&self, style: impl Into<String>) -> &Self {
+        self.push(DrawCommand::StrokeStyle(style.into()))
+    }
+
+    /// Set the width of lines
+    pub fn line_width(&self, width: f64) -> &Self {
+        self.push(DrawCommand::LineWidth(width))
+    }
+
+    /// Set the font of text, like `16px sans-serif`
+    pub fn font(&self, font: impl Into<String>) -> &Self {
+        self.push(DrawCommand::Font(font.into()))
+    }
+
+    /// Set the horizontal alignment of text: `left`, `right`, `center`, `start` or `end`
+    pub fn text_align(&self, align: impl Into<String>) -> &Self {
+        self.push(DrawCommand::TextAlign(align.into()))
+    }
+
+    /// Set the opacity of everything drawn after this call
+    pub fn global_alpha(&self, alpha: f64) -> &Self {
+        self.push(DrawCommand::GlobalAlpha(alpha))
+    }
+
+    /// Fill a rectangle
+    pub fn fill_rect(&self, x: f64, y: f64, width: f64, height: f64) -> &Self {
+        self.push(DrawCommand::FillRect {
+            x,
+            y,
+            width,
+            height,
+        })
+    }
+
+    /// Outline a rectangle
+    pub fn stroke_rect(&self, x: f64, y: f64, width: f64, height: f64) -> &Self {
+        self.push(DrawCommand::StrokeRect {
+            x,
+            y,
+            width,
+            height,
+        })
+    }
+
+    /// Make a rectangle transparent
+    pub fn clear_rect(&self, x: f64, y: f64, width: f64, height: f64) -> &Self {
+        self.push(DrawCommand::ClearRect {
+            x,
+            y,
+            width,
+            height,
+        })
+    }
+
+    /// Start a new path
+    pub fn begin_path(&self) -> &Self {
+        self.push(DrawCommand::BeginPath)
+    }
+
+    /// Draw a line back to the start of the current path
+    pub fn close_path(&self) -> &Self {
+        self.push(DrawCommand::ClosePath)
+    }
+
+    /// Move the start of the next line of the path without drawing
+    pub fn move_to(&self, x: f64, y: f64) -> &Self {
+        self.push(DrawCommand::MoveTo { x, y })
+    }
+
+    /// Add a straight line to the path
+    pub fn line_to(&self, x: f64, y: f64) -> &Self {
+        self.push(DrawCommand::LineTo { x, y })
+    }
+
+    /// Add an arc around a center point to the path. The angles are in radians
+    pub fn arc(&self, x: f64, y: f64, radius: f64, start_angle: f64, end_angle: f64) -> &Self {
+        self.push(DrawCommand::Arc {
+            x,
+            y,
+            radius,
+            start_angle,
+            end_angle,
+        })
+    }
+
+    /// Add a rectangle to the path
+    pub fn rect(&self, x: f64, y: f64, width: f64, height: f64) -> &Self {
+        self.push(DrawCommand::Rect {
+            x,
+            y,
+            width,
+            height,
+        })
+    }
+
+    /// Add a quadratic Bézier curve to the path
+    pub fn quadratic_curve_to(&self, cpx: f64, cpy: f64, x: f64, y: f64) -> &Self {
+        self.push(DrawCommand::QuadraticCurveTo { cpx, cpy, x, y })
+    }
+
+    /// Add a cubic Bézier curve to the path
+    pub fn bezier_curve_to(
+        &self,
+        cp1x: f64,
+        cp1y: f64,
+        cp2x: f64,
+        cp2y: f64,
+        x: f64,
+        y: f64,
+    ) -> &Self {
+        self.push(DrawCommand::BezierCurveTo {
+            cp1x,
+            cp1y,
+            cp2x,
+            cp2y,
+            x,
+            y,
+        })
+    }
+
+    /// Fill the current path
+    pub fn fill(&self) -> &Self {
+        self.push(DrawCommand::Fill)
+    }
+
+    /// Outline the current path
+    pub fn stroke(&self) -> &Self {
+        self.push(DrawCommand::Stroke)
+    }
+
+    /// Fill text at a position
+    pub fn fill_text(&self, text: impl Into<String>, x: f64, y: f64) -> &Self {
+        self.push(DrawCommand::FillText {
+            text: text.into(),
+            x,
+            y,
+        })
+    }
+
+    /// Outline text at a position
+    pub fn stroke_text(&self, text: impl Into<String>, x: f64, y: f64) -> &Self {
+        self.push(DrawCommand::StrokeText {
+            text: text.into(),
+            x,
+            y,
+        })
+    }
+
+    /// Save the styles and transform
+    pub fn save(&self) -> &Self {
+        self.push(DrawCommand::Save)
+    }
+
+    /// Restore the last saved styles and transform
+    pub fn restore(&self) -> &Self {
+        self.push(DrawCommand::Restore)
+    }
+
+    /// Move the origin
+    pub fn translate(&self, x: f64, y: f64) -> &Self {
+        self.push(DrawCommand::Translate { x, y })
+    }
+
+    /// Rotate around the origin. The angle is in radians
+    pub fn rotate(&self, angle: f64) -> &Self {
+        self.push(DrawCommand::Rotate(angle))
+    }
+
+    /// Scale everything drawn after this call
+    pub fn scale(&self, x: f64, y: f64) -> &Self {
+        self.push(DrawCommand::Scale { x, y })
+    }
+}
+
+const DRAW_SCRIPT: &str = r#"
+const canvas = document.querySelector('[data-dioxus-canvas="{ID}"]');
+const ctx = canvas && canvas.getContext("2d");
+if (ctx) {
+    const commands = {COMMANDS};
+    ctx.reset ? ctx.reset() : ctx.clearRect(0, 0, canvas.width, canvas.height);
+    for (const [name, ...args] of commands) {
+        if (name.startsWith("=")) {
+            ctx[name.slice(1)] = args[0];
+        } else {
+            ctx[name](...args);
+        }
+    }
+}
+"#;
+
+/// A `canvas` that is drawn by a closure. The closure receives a [`DrawContext`] that records the drawing commands
+/// of a frame. It runs again whenever a signal it reads changes, and the canvas is redrawn with the new commands.
+///
+/// On the web and desktop, the commands are drawn with the canvas 2D API of the webview. Renderers without a
+/// webview can draw the commands of a [`DrawContext`] themselves.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_html::canvas2d::Canvas;
+///
+/// fn app() -> Element {
+///     let mut progress = use_signal(|| 0.25);
+///     rsx! {
+///         Canvas {
+///             width: 200,
+///             height: 20,
+///             draw: move |ctx: dioxus_html::canvas2d::DrawContext| {
+///                 ctx.fill_style("#eee").fill_rect(0.0, 0.0, ctx.width(), ctx.height());
+///                 ctx.fill_style("teal").fill_rect(0.0, 0.0, ctx.width() * progress(), ctx.height());
+///             },
+///         }
+///         button { onclick: move |_| progress.set(f64::min(progress() + 0.25, 1.0)), "More" }
+///     }
+/// }
+/// ```
+#[component]
+pub fn Canvas(
+    /// The width of the canvas in pixels
+    width: u32,
+    /// The height of the canvas in pixels
+    height: u32,
+    /// Draw a frame of the canvas
+    draw: Callback<DrawContext>,
+    /// The class of the `canvas`
+    #[props(into)]
+    class: Option<String>,
+    /// A text description of the drawing for screen readers
+    #[props(into)]
+    label: Option<String>,
+) -> Element {
+    let id = use_hook(|| current_scope_id().map(|id| id.0).unwrap_or_default());
+    let commands = use_memo(use_reactive!(|(width, height)| {
+        let ctx = DrawContext::new(width as f64, height as f64);
+        draw.call(ctx.clone());
+        ctx.commands()
+    }));
+    use_effect(move || {
+        let commands: Vec<Value> = commands.read().iter().map(DrawCommand::to_json).collect();
+        let script = DRAW_SCRIPT
+            .replace("{ID}", &id.to_string())
+            .replace("{COMMANDS}", &Value::from(commands).to_string());
+        document().new_evaluator(script);
+    });
+
+    rsx! {
+        canvas {
+            "data-dioxus-canvas": "{id}",
+            width: "{width}",
+            height: "{height}",
+            class,
+            role: label.as_ref().map(|_| "img"),
+            aria_label: label,
+        }
+    }
+}
@@ -0,0 +1,542 @@
+//! Draw charts with SVG.
+//!
+//! A [`Chart`] renders an `svg` and provides its [`ChartScale`] to the [`XAxis`], [`YAxis`], [`LineSeries`],
+//! [`BarSeries`], [`AreaSeries`] and [`Tooltip`] inside it. Series read their points from a [`SignalVec`], and every
+//! point is drawn by its own component. Changing a point only rerenders the shapes that touch it, and adding a point
+//! only renders the new shapes.
+//!
+//! Charts are plain SVG elements, so they render the same on the web, desktop, liveview and the server.
+
+use crate::{self as dioxus_elements};
+use dioxus_core::prelude::*;
+use dioxus_core_macro::{component, rsx, Props};
+use dioxus_hooks::{use_context_provider, use_effect, use_reactive};
+use dioxus_signals::{GlobalSignal, Owner, Readable, Signal, SignalVec, Writable};
+
+/// Maps the values of a [`Chart`] to positions in its `svg`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChartScale {
+    /// The width of the chart in pixels
+    pub width: f64,
+    /// The height of the chart in pixels
+    pub height: f64,
+    /// The space between the edges of the chart and the plot area in pixels. The axes are drawn in this space.
+    pub padding: f64,
+    /// The smallest and largest x values that are shown
+    pub x_domain: (f64, f64),
+    /// The smallest and largest y values that are shown
+    pub y_domain: (f64, f64),
+}
+
+impl ChartScale {
+    /// Get the horizontal position of an x value
+    pub fn x(&self, value: f64) -> f64 {
+        self.padding + fraction(self.x_domain, value) * self.plot_width()
+    }
+
+    /// Get the vertical position of a y value. Larger values are higher up in the chart.
+    pub fn y(&self, value: f64) -> f64 {
+        self.height - self.padding - fraction(self.y_domain, value) * self.plot_height()
+    }
+
+    /// Get the vertical position bars and areas start from. This is zero, or the edge of the y domain that is closest
+    /// to zero.
+    pub fn baseline(&self) -> f64 {
+        let (min, max) = self.y_domain;
+        self.y(0f64.clamp(min.min(max), min.max(max)))
+    }
+
+    /// Get the width of the plot area inside the padding
+    pub fn plot_width(&self) -> f64 {
+        (self.width - 2.0 * self.padding).max(0.0)
+    }
+
+    /// Get the height of the plot area inside the padding
+    pub fn plot_height(&self) -> f64 {
+        (self.height - 2.0 * self.padding).max(0.0)
+    }
+
+    /// Get `count + 1` evenly spaced x values from the start to the end of the x domain
+    pub fn x_ticks(&self, count: usize) -> Vec<f64> {
+        ticks(self.x_domain, count)
+    }
+
+    /// Get `count + 1` evenly spaced y values from the start to the end of the y domain
+    pub fn y_ticks(&self, count: usize) -> Vec<f64> {
+        ticks(self.y_domain, count)
+    }
+}
+
+fn fraction((start, end): (f64, f64), value: f64) -> f64 {
+    let span = end - start;
+    if span == 0.0 {
+        return 0.5;
+    }
+    (value - start) / span
+}
+
+fn ticks((start, end): (f64, f64), count: usize) -> Vec<f64> {
+    if count == 0 {
+        return vec![start];
+    }
+    (0..=count)
+        .map(|step| start + (end - start) * step as f64 / count as f64)
+        .collect()
+}
+
+/// Format a value with at most two decimals
+fn format_value(value: f64) -> String {
+    // Adding zero turns -0 into 0
+    let rounded = (value * 100.0).round() / 100.0 + 0.0;
+    rounded.to_string()
+}
+
+/// The point of a series the pointer is over
+#[derive(Debug, Clone, PartialEq)]
+pub struct HoveredPoint {
+    /// The name of the series
+    pub series: String,
+    /// The x value of the point
+    pub x: f64,
+    /// The y value of the point
+    pub y: f64,
+}
+
+/// The scale and hovered point of a [`Chart`]. Get it with [`use_chart`] to draw custom shapes in a chart.
+#[derive(Clone, Copy, PartialEq)]
+pub struct ChartContext {
+    scale: Signal<ChartScale>,
+    hovered: Signal<Option<HoveredPoint>>,
+}
+
+impl ChartContext {
+    /// Get the scale of the chart. This subscribes to the scale, so the component reruns when the size or domains of
+    /// the chart change.
+    pub fn scale(&self) -> ChartScale {
+        (self.scale)()
+    }
+
+    /// Get the point the pointer is over
+    pub fn hovered(&self) -> Option<HoveredPoint> {
+        self.hovered.read().clone()
+    }
+
+    /// Set the point the pointer is over, or `None` to hide the [`Tooltip`]
+    pub fn hover(&self, point: Option<HoveredPoint>) {
+        let mut hovered = self.hovered;
+        if *hovered.peek() != point {
+            hovered.set(point);
+        }
+    }
+}
+
+/// Get the [`ChartContext`] of the closest [`Chart`].
+///
+/// # Panics
+///
+/// This panics if there is no [`Chart`] above the component.
+pub fn use_chart() -> ChartContext {
+    use_hook(|| {
+        try_consume_context::<ChartContext>()
+            .expect("use_chart must be called in a component inside of a Chart")
+    })
+}
+
+/// An `svg` that draws the axes and series inside it.
+///
+/// The chart has the `dioxus-chart` class. Axes, series and the tooltip are `g` elements with the
+/// `dioxus-chart-axis`, `dioxus-chart-series` and `dioxus-chart-tooltip` classes you can use to style them. Shapes
+/// use `currentColor` unless a color is set.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_html::chart::*;
+///
+/// fn app() -> Element {
+///     let temperatures = use_hook(|| SignalVec::from_iter([(0.0, 12.0), (1.0, 15.5), (2.0, 14.0), (3.0, 18.0)]));
+///     rsx! {
+///         Chart {
+///             x_domain: (0.0, 3.0),
+///             y_domain: (0.0, 20.0),
+///             label: "Temperature this week",
+///             XAxis { ticks: 3 }
+///             YAxis { format: |value| format!("{value}°C") }
+///             AreaSeries { name: "Temperature", data: temperatures, color: "orange" }
+///             LineSeries { name: "Temperature", data: temperatures, color: "orange" }
+///             Tooltip {}
+///         }
+///         button { onclick: move |_| temperatures.set(3, (3.0, 19.0)), "Warmer" }
+///     }
+/// }
+/// ```
+#[component]
+pub fn Chart(
+    children: Element,
+    /// The smallest and largest x values that are shown
+    x_domain: (f64, f64),
+    /// The smallest and largest y values that are shown
+    y_domain: (f64, f64),
+    /// The width of the chart in pixels
+    #[props(default = 400.0)]
+    width: f64,
+    /// The height of the chart in pixels
+    #[props(default = 300.0)]
+    height: f64,
+    /// The space around the plot area in pixels. The axes are drawn in this space.
+    #[props(default = 32.0)]
+    padding: f64,
+    /// A text description of the chart for screen readers
+    #[props(into)]
+    label: Option<String>,
+) -> Element {
+    let scale = ChartScale {
+        width,
+        height,
+        padding,
+        x_domain,
+        y_domain,
+    };
+    let chart = use_context_provider(|| ChartContext {
+        scale: Signal::new(scale),
+        hovered: Signal::new(None),
+    });
+    use_effect(use_reactive!(|scale| {
+        let mut current = chart.scale;
+        if *current.peek() != scale {
+            current.set(scale);
+        }
+    }));
+
+    rsx! {
+        svg {
+            class: "dioxus-chart",
+            width: "{width}",
+            height: "{height}",
+            view_box: "0 0 {width} {height}",
+            role: "img",
+            "aria-label": label,
+            {children}
+        }
+    }
+}
+
+/// The horizontal axis of a [`Chart`], drawn below the plot area with evenly spaced ticks
+#[component]
+pub fn XAxis(
+    /// The number of intervals between the ticks
+    #[props(default = 5)]
+    ticks: usize,
+    /// Format the label of a tick
+    format: Option<Callback<f64, String>>,
+) -> Element {
+    let scale = use_chart().scale();
+    let bottom = scale.height - scale.padding;
+    let labels: Vec<(f64, String)> = scale
+        .x_ticks(ticks)
+        .into_iter()
+        .map(|value| (scale.x(value), tick_label(format, value)))
+        .collect();
+
+    rsx! {
+        g { class: "dioxus-chart-axis", "data-axis": "x", fill: "currentColor",
+            line {
+                x1: scale.padding,
+                y1: bottom,
+                x2: scale.width - scale.padding,
+                y2: bottom,
+                stroke: "currentColor",
+            }
+            for (x , label) in labels {
+                line { x1: x, y1: bottom, x2: x, y2: bottom + 6.0, stroke: "currentColor" }
+                text {
+                    x,
+                    y: bottom + 9.0,
+                    dominant_baseline: "hanging",
+                    text_anchor: "middle",
+                    font_size: "10",
+                    "{label}"
+                }
+            }
+        }
+    }
+}
+
+/// The vertical axis of a [`Chart`], drawn left of the plot area with evenly spaced ticks
+#[component]
+pub fn YAxis(
+    /// The number of intervals between the ticks
+    #[props(default = 5)]
+    ticks: usize,
+    /// Format the label of a tick
+    format: Option<Callback<f64, String>>,
+) -> Element {
+    let scale = use_chart().scale();
+    let left = scale.padding;
+    let labels: Vec<(f64, String)> = scale
+        .y_ticks(ticks)
+        .into_iter()
+        .map(|value| (scale.y(value), tick_label(format, value)))
+        .collect();
+
+    rsx! {
+        g { class: "dioxus-chart-axis", "data-axis": "y", fill: "currentColor",
+            line {
+                x1: left,
+                y1: scale.padding,
+                x2: left,
+                y2: scale.height - scale.padding,
+                stroke: "currentColor",
+            }
+            for (y , label) in labels {
+                line { x1: left - 6.0, y1: y, x2: left, y2: y, stroke: "currentColor" }
+                text {
+                    x: left - 9.0,
+                    y,
+                    dominant_baseline: "middle",
+                    text_anchor: "end",
+                    font_size: "10",
+                    "{label}"
+                }
+            }
+        }
+    }
+}
+
+fn tick_label(format: Option<Callback<f64, String>>, value: f64) -> String {
+    match format {
+        Some(format) => format.call(value),
+        None => format_value(value),
+    }
+}
+
+/// The points of a series with the point before each of them
+type Segments = Vec<(u64, Option<Signal<(f64, f64)>>, Signal<(f64, f64)>)>;
+
+fn segments(data: &SignalVec<(f64, f64)>) -> Segments {
+    let items = data.items();
+    let previous = std::iter::once(None).chain(items.iter().map(|(_, point)| Some(*point)));
+    items
+        .iter()
+        .zip(previous)
+        .map(|((key, point), previous)| (*key, previous, *point))
+        .collect()
+}
+
+/// A series of `(x, y)` points connected by straight lines, with a marker on every point
+#[component]
+pub fn LineSeries(
+    /// The name of the series, shown in the [`Tooltip`]
+    #[props(into)]
+    name: String,
+    /// The points of the series in the order they are connected
+    data: SignalVec<(f64, f64)>,
+    /// The color of the line
+    #[props(into, default = "currentColor".to_string())]
+    color: String,
+    /// The width of the line in pixels
+    #[props(default = 2.0)]
+    stroke_width: f64,
+) -> Element {
+    let segments = segments(&data);
+
+    rsx! {
+        g {
+            class: "dioxus-chart-series",
+            "data-kind": "line",
+            "data-series": "{name}",
+            stroke: "{color}",
+            fill: "{color}",
+            stroke_width,
+            for (key , from , to) in segments.iter().copied() {
+                LineSegment { key: "{key}", from, to }
+            }
+            for (key , _ , point) in segments {
+                Marker { key: "{key}", series: name.clone(), point }
+            }
+        }
+    }
+}
+
+#[component]
+fn LineSegment(from: Option<Signal<(f64, f64)>>, to: Signal<(f64, f64)>) -> Element {
+    let scale = use_chart().scale();
+    let Some(from) = from else {
+        return rsx! {};
+    };
+    let ((x1, y1), (x2, y2)) = (from(), to());
+
+    rsx! {
+        line {
+            x1: scale.x(x1),
+            y1: scale.y(y1),
+            x2: scale.x(x2),
+            y2: scale.y(y2),
+        }
+    }
+}
+
+/// A series of `(x, y)` points with the area between the line through the points and the baseline filled
+#[component]
+pub fn AreaSeries(
+    /// The name of the series, shown in the [`Tooltip`]
+    #[props(into)]
+    name: String,
+    /// The points of the series in the order they are connected
+    data: SignalVec<(f64, f64)>,
+    /// The color of the area
+    #[props(into, default = "currentColor".to_string())]
+    color: String,
+    /// The opacity of the area
+    #[props(default = 0.3)]
+    opacity: f64,
+) -> Element {
+    let segments = segments(&data);
+
+    rsx! {
+        g {
+            class: "dioxus-chart-series",
+            "data-kind": "area",
+            "data-series": "{name}",
+            fill: "{color}",
+            fill_opacity: opacity,
+            for (key , from , to) in segments.iter().copied() {
+                AreaSegment { key: "{key}", from, to }
+            }
+            for (key , _ , point) in segments {
+                Marker { key: "{key}", series: name.clone(), point }
+            }
+        }
+    }
+}
+
+#[component]
+fn AreaSegment(from: Option<Signal<(f64, f64)>>, to: Signal<(f64, f64)>) -> Element {
+    let scale = use_chart().scale();
+    let Some(from) = from else {
+        return rsx! {};
+    };
+    let ((x1, y1), (x2, y2)) = (from(), to());
+    let (x1, y1, x2, y2) = (scale.x(x1), scale.y(y1), scale.x(x2), scale.y(y2));
+    let base = scale.baseline();
+
+    rsx! {
+        polygon { points: "{x1},{base} {x1},{y1} {x2},{y2} {x2},{base}" }
+    }
+}
+
+/// A transparent circle that shows the point in the [`Tooltip`] while the pointer is over it
+#[component]
+fn Marker(series: String, point: Signal<(f64, f64)>) -> Element {
+    let chart = use_chart();
+    let scale = chart.scale();
+    let (x, y) = point();
+    let hovered = HoveredPoint { series, x, y };
+
+    rsx! {
+        circle {
+            cx: scale.x(x),
+            cy: scale.y(y),
+            r: 4,
+            fill_opacity: 0,
+            stroke: "none",
+            onmouseenter: move |_| chart.hover(Some(hovered.clone())),
+            onmouseleave: move |_| chart.hover(None),
+        }
+    }
+}
+
+/// A series of `(x, y)` points drawn as bars from the baseline to each point
+#[component]
+pub fn BarSeries(
+    /// The name of the series, shown in the [`Tooltip`]
+    #[props(into)]
+    name: String,
+    /// The points of the series
+    data: SignalVec<(f64, f64)>,
+    /// The color of the bars
+    #[props(into, default = "currentColor".to_string())]
+    color: String,
+    /// The width of each bar in x units. Bars are centered on their x value.
+    #[props(default = 0.8)]
+    bar_width: f64,
+) -> Element {
+    let items = data.items();
+
+    rsx! {
+        g {
+            class: "dioxus-chart-series",
+            "data-kind": "bar",
+            "data-series": "{name}",
+            fill: "{color}",
+            for (key , point) in items {
+                Bar { key: "{key}", series: name.clone(), point, bar_width }
+            }
+        }
+    }
+}
+
+#[component]
+fn Bar(series: String, point: Signal<(f64, f64)>, bar_width: f64) -> Element {
+    let chart = use_chart();
+    let scale = chart.scale();
+    let (x, y) = point();
+    let left = scale.x(x - bar_width / 2.0);
+    let right = scale.x(x + bar_width / 2.0);
+    let (top, base) = (scale.y(y), scale.baseline());
+    let hovered = HoveredPoint { series, x, y };
+
+    rsx! {
+        rect {
+            x: left.min(right),
+            y: top.min(base),
+            width: (right - left).abs(),
+            height: (base - top).abs(),
+            onmouseenter: move |_| chart.hover(Some(hovered.clone())),
+            onmouseleave: move |_| chart.hover(None),
+        }
+    }
+}
+
+/// Show the series and values of the point the pointer is over. Put it after the series so it is drawn on top of
+/// them.
+#[component]
+pub fn Tooltip(
+    /// Format the text of the tooltip. Defaults to `{series}: {x}, {y}`.
+    format: Option<Callback<HoveredPoint, String>>,
+) -> Element {
+    let chart = use_chart();
+    let scale = chart.scale();
+    let Some(hovered) = chart.hovered() else {
+        return rsx! {};
+    };
+    let (x, y) = (scale.x(hovered.x), scale.y(hovered.y));
+    let text = match format {
+        Some(format) => format.call(hovered),
+        None => format!(
+            "{}: {}, {}",
+            hovered.series,
+            format_value(hovered.x),
+            format_value(hovered.y)
+        ),
+    };
+
+    rsx! {
+        g {
+            class: "dioxus-chart-tooltip",
+            role: "tooltip",
+            pointer_events: "none",
+            text {
+                x,
+                y: y - 10.0,
+                text_anchor: "middle",
+                font_size: "12",
+                fill: "currentColor",
+                // Outline the text with the background color so it stays readable on top of the series
+                stroke: "white",
+                stroke_width: 3,
+                paint_order: "stroke",
+                "{text}"
+            }
+        }
+    }
+}
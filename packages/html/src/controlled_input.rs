@@ -0,0 +1,94 @@
+//! Bind a text input to a value that is normalized as the user types.
+//!
+//! Writing a different value back into an input usually moves the cursor to the end. The renderers keep the cursor at
+//! the same distance from the end when they write the value of a focused input, and defer the write until an IME
+//! composition ends, so the value can be reformatted on every keystroke.
+
+use crate::FormEvent;
+use dioxus_core::prelude::*;
+use dioxus_hooks::{use_callback, use_signal};
+use dioxus_signals::{Readable, Signal, Writable};
+
+/// A text value that is bound to an input and normalized every time it changes. See [`use_controlled_input`].
+pub struct ControlledInput {
+    value: Signal<String>,
+    normalize: Callback<String, String>,
+}
+
+impl Clone for ControlledInput {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for ControlledInput {}
+
+impl PartialEq for ControlledInput {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl ControlledInput {
+    /// The normalized value. Reading it subscribes the current component.
+    pub fn value(&self) -> String {
+        self.value.cloned()
+    }
+
+    /// The signal that holds the normalized value
+    pub fn signal(&self) -> Signal<String> {
+        self.value
+    }
+
+    /// Normalize a value and write it to the input
+    pub fn set(&mut self, value: impl Into<String>) {
+        let normalized = self.normalize.call(value.into());
+        self.value.set(normalized);
+    }
+
+    /// Update the value from an `oninput` event
+    pub fn oninput(&mut self, event: FormEvent) {
+        let raw = event.value();
+        let normalized = self.normalize.call(raw.clone());
+        if normalized == raw || normalized != *self.value.peek() {
+            self.value.set(normalized);
+            return;
+        }
+
+        // The input shows the raw value, but the value didn't change, so the next render wouldn't write it back.
+        // Render the raw value first, and the normalized value once the raw value is mounted.
+        let mut value = self.value;
+        value.set(raw);
+        queue_effect(move || value.set(normalized));
+    }
+}
+
+/// Bind a text input to a value that is normalized every time the user types, without moving the cursor.
+///
+/// `normalize` receives the text of the input and returns the value to show instead, for example to mask a phone
+/// number or to uppercase a code. It is also applied to the initial value.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_html::controlled_input::use_controlled_input;
+///
+/// fn CouponCode() -> Element {
+///     let mut code = use_controlled_input(String::new, |code| {
+///         code.chars()
+///             .filter(|c| c.is_ascii_alphanumeric())
+///             .map(|c| c.to_ascii_uppercase())
+///             .collect()
+///     });
+///     rsx! {
+///         input { value: code.value(), oninput: move |event| code.oninput(event) }
+///     }
+/// }
+/// ```
+pub fn use_controlled_input(
+    initial: impl FnOnce() -> String,
+    mut normalize: impl FnMut(String) -> String + 'static,
+) -> ControlledInput {
+    let normalize = use_callback(move |value| normalize(value));
+    let value = use_signal(|| normalize.call(initial()));
+    ControlledInput { value, normalize }
+}
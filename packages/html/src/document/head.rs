@@ -2,7 +2,7 @@
 
 use std::{cell::RefCell, collections::HashSet, rc::Rc};
 
-use dioxus_core::{prelude::*, DynamicNode};
+use dioxus_core::{prelude::*, DynamicNode, VText};
 use dioxus_core_macro::*;
 
 /// Warn the user if they try to change props on a element that is injected into the head
@@ -142,6 +142,19 @@ impl MetaProps {
         }
         attributes
     }
+
+    /// The attribute that identifies the meta tag in the head and its value
+    pub fn key(&self) -> Option<(&'static str, &str)> {
+        if let Some(property) = &self.property {
+            Some(("property", property))
+        } else if let Some(name) = &self.name {
+            Some(("name", name))
+        } else {
+            self.http_equiv
+                .as_deref()
+                .map(|http_equiv| ("http-equiv", http_equiv))
+        }
+    }
 }
 
 /// Render a [`meta`](crate::elements::meta) tag into the head of the page.
@@ -355,6 +368,80 @@ pub fn Style(props: StyleProps) -> Element {
     VNode::empty()
 }
 
+/// A block of css scoped to a generated class. This is created by the `css!` macro.
+///
+/// The scoped style can be used as the value of a `class` attribute or formatted into a class string:
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn Button() -> Element {
+///     let style = css!("padding: 4px; &:hover { color: red; }");
+///     rsx! {
+///         button { class: style, "Hover me" }
+///         button { class: "{style} primary", "Or me" }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScopedStyle {
+    class: &'static str,
+    css: &'static str,
+}
+
+impl ScopedStyle {
+    #[doc(hidden)]
+    pub const fn new(class: &'static str, css: &'static str) -> Self {
+        Self { class, css }
+    }
+
+    /// The class the css is scoped to
+    pub fn class(&self) -> &'static str {
+        self.class
+    }
+
+    /// The scoped css
+    pub fn css(&self) -> &'static str {
+        self.css
+    }
+
+    /// Insert the css into the head of the page if it hasn't been inserted yet. The `css!` macro calls this for you.
+    pub fn inject(&self) {
+        if !should_insert_scoped_style(self.class) {
+            return;
+        }
+        // The contents of a style must be a single text node
+        let contents = VNode::new(
+            None,
+            Template {
+                roots: &[TemplateNode::Dynamic { id: 0 }],
+                node_paths: &[&[0]],
+                attr_paths: &[],
+            },
+            Box::new([DynamicNode::Text(VText::new(self.css))]),
+            Box::new([]),
+        );
+        document().create_style(StyleProps {
+            href: None,
+            media: None,
+            nonce: None,
+            title: None,
+            children: Ok(contents),
+        });
+    }
+}
+
+impl std::fmt::Display for ScopedStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.class)
+    }
+}
+
+impl IntoAttributeValue for ScopedStyle {
+    fn into_value(self) -> dioxus_core::AttributeValue {
+        dioxus_core::AttributeValue::Text(self.class.to_string())
+    }
+}
+
 use super::*;
 
 #[derive(Clone, Props, PartialEq)]
@@ -506,6 +593,15 @@ fn should_insert_style(href: &str) -> bool {
         .should_insert(href)
 }
 
+#[derive(Default, Clone)]
+struct ScopedStyleContext(DeduplicationContext);
+
+fn should_insert_scoped_style(class: &str) -> bool {
+    get_or_insert_root_context::<ScopedStyleContext>()
+        .0
+        .should_insert(class)
+}
+
 #[derive(Default, Clone)]
 struct DeduplicationContext(Rc<RefCell<HashSet<String>>>);
 
@@ -14,7 +14,9 @@ mod eval;
 pub use eval::*;
 
 pub mod head;
-pub use head::{Meta, MetaProps, Script, ScriptProps, Style, StyleProps, Title, TitleProps};
+pub use head::{
+    Meta, MetaProps, ScopedStyle, Script, ScriptProps, Style, StyleProps, Title, TitleProps,
+};
 
 fn format_attributes(attributes: &[(&str, String)]) -> String {
     let mut formatted = String::from("[");
@@ -58,6 +60,24 @@ pub trait Document {
         self.new_evaluator(js);
     }
 
+    /// Create a meta tag, or update the meta tag with the same `property`, `name` or `http-equiv`. If `content` is
+    /// `None`, the existing meta tag is removed. Meta tags without a key are always created.
+    fn set_meta(&self, props: MetaProps) {
+        let Some((key, value)) = props.key() else {
+            self.create_meta(props);
+            return;
+        };
+        let helpers = include_str!("../js/head.js");
+        let content = props
+            .content
+            .as_ref()
+            .map(|content| format!("{content:?}"))
+            .unwrap_or("null".to_string());
+        self.new_evaluator(format!(
+            r#"{helpers};window.setMetaInHead({key:?}, {value:?}, {content});"#
+        ));
+    }
+
     /// Create a new script tag
     fn create_script(&self, props: ScriptProps) {
         let attributes = props.attributes();
@@ -65,9 +85,15 @@ pub trait Document {
         self.new_evaluator(js);
     }
 
-    /// Create a new style tag
+    /// Create a new style tag, or a stylesheet link if the style has an href
     fn create_style(&self, props: StyleProps) {
-        let attributes = props.attributes();
+        let mut attributes = props.attributes();
+        if props.href.is_some() {
+            attributes.insert(0, ("rel", "stylesheet".to_string()));
+            let js = create_element_in_head("link", &attributes, None);
+            self.new_evaluator(js);
+            return;
+        }
         let js = create_element_in_head("style", &attributes, props.style_contents());
         self.new_evaluator(js);
     }
@@ -112,6 +112,10 @@ pub struct SerializedKeyboardData {
     location: usize,
     repeat: bool,
     which: usize,
+    /// The names of every active modifier, including lock keys like `CapsLock`. Older clients only send the four
+    /// `*_key` fields.
+    #[serde(default)]
+    modifier_state: Vec<String>,
 }
 
 #[cfg(feature = "serialize")]
@@ -142,6 +146,7 @@ impl SerializedKeyboardData {
             repeat: is_auto_repeating,
             which: std::convert::TryInto::try_into(key.legacy_charcode())
                 .expect("could not convert charcode to usize"),
+            modifier_state: crate::input_data::encode_modifier_state(modifiers),
         }
     }
 }
@@ -190,7 +195,9 @@ impl HasKeyboardData for SerializedKeyboardData {
 #[cfg(feature = "serialize")]
 impl ModifiersInteraction for SerializedKeyboardData {
     fn modifiers(&self) -> Modifiers {
-        let mut modifiers = Modifiers::empty();
+        let mut modifiers = crate::input_data::decode_modifier_state(
+            self.modifier_state.iter().map(String::as_str),
+        );
 
         if self.alt_key {
             modifiers.insert(Modifiers::ALT);
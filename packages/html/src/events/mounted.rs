@@ -43,6 +43,14 @@ pub trait RenderedElementBacking: std::any::Any {
     fn set_focus(&self, _focus: bool) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
         Box::pin(async { Err(MountedError::NotSupported) })
     }
+
+    /// Get the computed value of a CSS property of the element, including custom properties
+    fn get_computed_style(
+        &self,
+        _property: &str,
+    ) -> Pin<Box<dyn Future<Output = MountedResult<String>>>> {
+        Box::pin(async { Err(MountedError::NotSupported) })
+    }
 }
 
 impl RenderedElementBacking for () {
@@ -120,6 +128,20 @@ impl MountedData {
         self.inner.set_focus(focus)
     }
 
+    /// Get the computed value of a CSS property of the element, including custom properties
+    #[doc(alias = "getComputedStyle")]
+    #[doc(alias = "getPropertyValue")]
+    pub async fn get_computed_style(&self, property: &str) -> MountedResult<String> {
+        self.inner.get_computed_style(property).await
+    }
+
+    /// Get the computed value of a CSS custom property of the element. The value is inherited from the closest
+    /// ancestor that sets the property.
+    pub async fn get_css_var(&self, var: crate::CssVar) -> MountedResult<String> {
+        let value = self.get_computed_style(var.name()).await?;
+        Ok(value.trim().to_string())
+    }
+
     /// Downcast this event to a concrete event type
     #[inline(always)]
     pub fn downcast<T: 'static>(&self) -> Option<&T> {
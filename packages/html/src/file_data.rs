@@ -6,6 +6,137 @@ pub trait HasFileData: std::any::Any {
     fn files(&self) -> Option<std::sync::Arc<dyn FileEngine>> {
         None
     }
+
+    /// Get the files of the event with their names, sizes and types
+    ///
+    /// ```rust, no_run
+    /// # use dioxus::prelude::*;
+    /// fn app() -> Element {
+    ///     let mut uploaded = use_signal(Vec::new);
+    ///     rsx! {
+    ///         input {
+    ///             r#type: "file",
+    ///             multiple: true,
+    ///             onchange: move |event| async move {
+    ///                 for file in event.file_list() {
+    ///                     let kind = file.content_type().await.unwrap_or_default();
+    ///                     let size = file.size().await.unwrap_or_default();
+    ///                     uploaded.push(format!("{} ({kind}, {size} bytes)", file.name()));
+    ///                 }
+    ///             }
+    ///         }
+    ///         for file in uploaded.iter() {
+    ///             p { "{file}" }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "file_engine")]
+    fn file_list(&self) -> Vec<FileData> {
+        let Some(engine) = self.files() else {
+            return Vec::new();
+        };
+        engine
+            .files()
+            .into_iter()
+            .map(|path| FileData {
+                engine: engine.clone(),
+                path,
+            })
+            .collect()
+    }
+}
+
+/// A file from a file input or a drop event
+#[cfg(feature = "file_engine")]
+#[derive(Clone)]
+pub struct FileData {
+    engine: std::sync::Arc<dyn FileEngine>,
+    path: String,
+}
+
+#[cfg(feature = "file_engine")]
+impl FileData {
+    /// The name of the file without the directories leading to it
+    pub fn name(&self) -> &str {
+        self.path.rsplit(['/', '\\']).next().unwrap_or(&self.path)
+    }
+
+    /// The path of the file. On the web, this is the same as the name of the file.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The size of the file in bytes
+    pub async fn size(&self) -> Option<u64> {
+        self.engine.file_size(&self.path).await
+    }
+
+    /// The MIME type of the file, like `image/png`
+    pub async fn content_type(&self) -> Option<String> {
+        self.engine.file_type(&self.path).await
+    }
+
+    /// Read the contents of the file
+    pub async fn read_bytes(&self) -> Option<Vec<u8>> {
+        self.engine.read_file(&self.path).await
+    }
+
+    /// Read the contents of the file as text
+    pub async fn read_string(&self) -> Option<String> {
+        self.engine.read_file_to_string(&self.path).await
+    }
+
+    /// The file in the representation of the platform, like a `web_sys::File` on the web or a `tokio::fs::File` on
+    /// desktop
+    pub async fn native(&self) -> Option<Box<dyn std::any::Any>> {
+        self.engine.get_native_file(&self.path).await
+    }
+}
+
+#[cfg(feature = "file_engine")]
+impl std::fmt::Debug for FileData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileData")
+            .field("path", &self.path)
+            .finish()
+    }
+}
+
+/// Guess the MIME type of a file from the extension of its name
+#[cfg(feature = "file_engine")]
+pub fn guess_content_type(name: &str) -> Option<&'static str> {
+    let (_, extension) = name.rsplit_once('.')?;
+    let content_type = match extension.to_ascii_lowercase().as_str() {
+        "txt" => "text/plain",
+        "html" | "htm" => "text/html",
+        "css" => "text/css",
+        "csv" => "text/csv",
+        "md" => "text/markdown",
+        "js" | "mjs" => "text/javascript",
+        "json" => "application/json",
+        "xml" => "application/xml",
+        "pdf" => "application/pdf",
+        "zip" => "application/zip",
+        "wasm" => "application/wasm",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "avif" => "image/avif",
+        "svg" => "image/svg+xml",
+        "ico" => "image/x-icon",
+        "mp3" => "audio/mpeg",
+        "wav" => "audio/wav",
+        "ogg" => "audio/ogg",
+        "mp4" => "video/mp4",
+        "webm" => "video/webm",
+        "woff" => "font/woff",
+        "woff2" => "font/woff2",
+        "ttf" => "font/ttf",
+        _ => return None,
+    };
+    Some(content_type)
 }
 
 #[cfg(feature = "serialize")]
@@ -55,6 +186,11 @@ pub trait FileEngine {
     // get the size of a file
     async fn file_size(&self, file: &str) -> Option<u64>;
 
+    // get the MIME type of a file
+    async fn file_type(&self, file: &str) -> Option<String> {
+        guess_content_type(file).map(str::to_string)
+    }
+
     // read a file to bytes
     async fn read_file(&self, file: &str) -> Option<Vec<u8>>;
 
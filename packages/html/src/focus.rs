@@ -0,0 +1,322 @@
+//! Move the keyboard focus between elements.
+//!
+//! - [`use_focus`] focuses or blurs an element from Rust.
+//! - [`FocusTrap`] keeps the focus inside of a modal while it is open.
+//! - [`use_roving_tabindex`] moves the focus through a group of elements, like the items of a toolbar or menu, with
+//!   the arrow keys.
+//! - Elements with the `autofocus` attribute are focused whenever they are added to the page, not only on the first
+//!   load of the page like browsers do. The web, desktop and liveview renderers install [`AUTOFOCUS_SCRIPT`] when
+//!   they start.
+
+use crate::document::document;
+use crate::events::{KeyboardEvent, MountedEvent};
+use crate::{self as dioxus_elements, MountedData, MountedError, MountedResult};
+use dioxus_core::prelude::*;
+use dioxus_core_macro::{component, rsx, Props};
+use dioxus_signals::{CopyValue, GlobalSignal, Readable, Signal, Writable};
+use keyboard_types::Key;
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+
+/// JavaScript that focuses elements with the `autofocus` attribute when they are added to the page or the attribute
+/// is set. Renderers evaluate this once when they start.
+pub const AUTOFOCUS_SCRIPT: &str = r#"
+if (!window.__dioxusAutofocus) {
+    window.__dioxusAutofocus = true;
+    const focus = (node) => {
+        if (!(node instanceof Element)) return;
+        const target = node.hasAttribute("autofocus") ? node : node.querySelector("[autofocus]");
+        if (target && target.isConnected) target.focus();
+    };
+    new MutationObserver((records) => {
+        for (const record of records) {
+            if (record.type === "attributes") {
+                focus(record.target);
+            } else {
+                record.addedNodes.forEach(focus);
+            }
+        }
+    }).observe(document.documentElement, {
+        childList: true,
+        subtree: true,
+        attributes: true,
+        attributeFilter: ["autofocus"],
+    });
+}
+"#;
+
+/// Start focusing elements with the `autofocus` attribute when they are added to the page. This must be called in the
+/// runtime of a VirtualDom after the renderer provided its [`Document`](crate::document::Document).
+pub fn init_autofocus() {
+    document().new_evaluator(AUTOFOCUS_SCRIPT.to_string());
+}
+
+#[derive(Debug)]
+struct NotMounted;
+
+impl Display for NotMounted {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "the element is not mounted")
+    }
+}
+
+impl std::error::Error for NotMounted {}
+
+/// Focus or blur an element. Attach the handle to an element with [`UseFocus::onmounted`].
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_html::focus::use_focus;
+///
+/// fn app() -> Element {
+///     let name = use_focus();
+///     rsx! {
+///         input { onmounted: move |event| name.onmounted(event) }
+///         button {
+///             onclick: move |_| async move {
+///                 _ = name.focus().await;
+///             },
+///             "Edit name"
+///         }
+///     }
+/// }
+/// ```
+pub fn use_focus() -> UseFocus {
+    use_hook(|| UseFocus {
+        element: CopyValue::new(None),
+    })
+}
+
+/// A handle to focus or blur an element created with [`use_focus`]
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseFocus {
+    element: CopyValue<Option<Rc<MountedData>>>,
+}
+
+impl UseFocus {
+    /// Attach the handle to the element that was mounted
+    pub fn onmounted(&self, event: MountedEvent) {
+        *self.element.write_unchecked() = Some(event.data());
+    }
+
+    /// Check if the handle is attached to an element
+    pub fn is_mounted(&self) -> bool {
+        self.element.read().is_some()
+    }
+
+    /// Focus the element
+    pub async fn focus(&self) -> MountedResult<()> {
+        self.set_focus(true).await
+    }
+
+    /// Remove the focus from the element
+    pub async fn blur(&self) -> MountedResult<()> {
+        self.set_focus(false).await
+    }
+
+    async fn set_focus(&self, focus: bool) -> MountedResult<()> {
+        let element = self.element.read().clone();
+        match element {
+            Some(element) => element.set_focus(focus).await,
+            None => Err(MountedError::OperationFailed(Box::new(NotMounted))),
+        }
+    }
+}
+
+const FOCUS_TRAP_SCRIPT: &str = r#"
+const container = document.querySelector('[data-dioxus-focus-trap="{ID}"]');
+if (container) {
+    const selector = 'a[href], area[href], button:not([disabled]), input:not([disabled]):not([type="hidden"]), select:not([disabled]), textarea:not([disabled]), iframe, [contenteditable], [tabindex]:not([tabindex="-1"])';
+    const focusable = () => Array.from(container.querySelectorAll(selector)).filter((el) => el.getClientRects().length > 0);
+    const previous = document.activeElement;
+    const handler = (event) => {
+        if (event.key !== "Tab") return;
+        const elements = focusable();
+        if (elements.length === 0) {
+            event.preventDefault();
+            return;
+        }
+        const first = elements[0];
+        const last = elements[elements.length - 1];
+        const outside = !container.contains(document.activeElement);
+        if (event.shiftKey && (outside || document.activeElement === first)) {
+            event.preventDefault();
+            last.focus();
+        } else if (!event.shiftKey && (outside || document.activeElement === last)) {
+            event.preventDefault();
+            first.focus();
+        }
+    };
+    document.addEventListener("keydown", handler, true);
+    window.__dioxusFocusTraps = window.__dioxusFocusTraps || {};
+    window.__dioxusFocusTraps["{ID}"] = { handler, previous };
+    if (!container.contains(document.activeElement)) {
+        const elements = focusable();
+        if (elements.length > 0) elements[0].focus();
+    }
+}
+"#;
+
+const RELEASE_FOCUS_TRAP_SCRIPT: &str = r#"
+const trap = (window.__dioxusFocusTraps || {})["{ID}"];
+if (trap) {
+    document.removeEventListener("keydown", trap.handler, true);
+    delete window.__dioxusFocusTraps["{ID}"];
+    if (trap.previous && trap.previous.isConnected) trap.previous.focus();
+}
+"#;
+
+/// Keep the keyboard focus inside of the children while the trap is rendered. This is useful for modals and dialogs.
+///
+/// When the trap is mounted, the first focusable child is focused. Tab and Shift+Tab cycle through the focusable
+/// children instead of leaving the trap. When the trap is removed, the element that was focused before the trap was
+/// mounted is focused again.
+///
+/// The children are wrapped in a `div`.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_html::focus::FocusTrap;
+///
+/// fn app() -> Element {
+///     let mut open = use_signal(|| false);
+///     rsx! {
+///         button { onclick: move |_| open.set(true), "Delete" }
+///         if open() {
+///             FocusTrap {
+///                 div { role: "dialog", aria_label: "Delete the file?",
+///                     button { onclick: move |_| open.set(false), "Cancel" }
+///                     button { onclick: move |_| open.set(false), "Delete" }
+///                 }
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn FocusTrap(children: Element) -> Element {
+    let id = use_hook(|| current_scope_id().map(|id| id.0).unwrap_or_default());
+    let document = use_hook(document);
+    use_drop({
+        let document = document.clone();
+        move || {
+            document.new_evaluator(RELEASE_FOCUS_TRAP_SCRIPT.replace("{ID}", &id.to_string()));
+        }
+    });
+
+    rsx! {
+        div {
+            "data-dioxus-focus-trap": "{id}",
+            onmounted: move |_| {
+                document.new_evaluator(FOCUS_TRAP_SCRIPT.replace("{ID}", &id.to_string()));
+            },
+            {children}
+        }
+    }
+}
+
+/// Move the keyboard focus through a group of `count` elements with the arrow keys. Only the active element can be
+/// reached with Tab, so the group takes a single stop in the tab order.
+///
+/// Each element needs the [`RovingTabindex::tabindex`] of its index and forwards its mounted and keydown events to
+/// the group. The arrow keys move to the next or previous element and Home and End move to the first or last
+/// element.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_html::focus::use_roving_tabindex;
+///
+/// fn app() -> Element {
+///     let tools = ["Bold", "Italic", "Underline"];
+///     let mut toolbar = use_roving_tabindex(tools.len());
+///     rsx! {
+///         div { role: "toolbar",
+///             for (index, tool) in tools.iter().enumerate() {
+///                 button {
+///                     tabindex: toolbar.tabindex(index),
+///                     onmounted: move |event| toolbar.onmounted(index, event),
+///                     onkeydown: move |event| toolbar.onkeydown(event),
+///                     onfocus: move |_| toolbar.set_active(index),
+///                     "{tool}"
+///                 }
+///             }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_roving_tabindex(count: usize) -> RovingTabindex {
+    let roving = use_hook(|| RovingTabindex {
+        active: Signal::new(0),
+        elements: CopyValue::new(Vec::new()),
+    });
+    roving.elements.write_unchecked().resize(count, None);
+    roving
+}
+
+/// A group of elements the focus moves through with the arrow keys created with [`use_roving_tabindex`]
+#[derive(Clone, Copy, PartialEq)]
+pub struct RovingTabindex {
+    active: Signal<usize>,
+    elements: CopyValue<Vec<Option<Rc<MountedData>>>>,
+}
+
+impl RovingTabindex {
+    /// The index of the element that can be reached with Tab
+    pub fn active(&self) -> usize {
+        let count = self.elements.read().len();
+        (self.active)().min(count.saturating_sub(1))
+    }
+
+    /// The tabindex of the element at an index: `0` for the active element and `-1` for every other element
+    pub fn tabindex(&self, index: usize) -> i64 {
+        if index == self.active() {
+            0
+        } else {
+            -1
+        }
+    }
+
+    /// Make an element the active element without focusing it. Call this when an element gets focused in another
+    /// way, like with a click.
+    pub fn set_active(&mut self, index: usize) {
+        if (self.active)() != index {
+            self.active.set(index);
+        }
+    }
+
+    /// Register the element at an index
+    pub fn onmounted(&mut self, index: usize, event: MountedEvent) {
+        if let Some(element) = self.elements.write_unchecked().get_mut(index) {
+            *element = Some(event.data());
+        }
+    }
+
+    /// Move the focus with the arrow keys, Home and End. Other keys are ignored.
+    pub fn onkeydown(&mut self, event: KeyboardEvent) {
+        let count = self.elements.read().len();
+        if count == 0 {
+            return;
+        }
+        let active = self.active();
+        let next = match event.key() {
+            Key::ArrowRight | Key::ArrowDown => (active + 1) % count,
+            Key::ArrowLeft | Key::ArrowUp => (active + count - 1) % count,
+            Key::Home => 0,
+            Key::End => count - 1,
+            _ => return,
+        };
+        event.prevent_default();
+        self.focus(next);
+    }
+
+    /// Make the element at an index the active element and focus it
+    pub fn focus(&mut self, index: usize) {
+        self.set_active(index);
+        let element = self.elements.read().get(index).cloned().flatten();
+        if let Some(element) = element {
+            spawn(async move {
+                _ = element.set_focus(true).await;
+            });
+        }
+    }
+}
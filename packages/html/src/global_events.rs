@@ -0,0 +1,309 @@
+//! Listen to events on the window or the document with components.
+//!
+//! - [`WindowListener`] listens to keyboard, resize, scroll, focus and network events on the window.
+//! - [`DocumentListener`] listens to keyboard and visibility events on the document.
+//!
+//! The listeners are added with a script that works on every renderer with a [`Document`](crate::document::Document),
+//! and removed when the component is dropped. The handlers run after the event was dispatched in the page, so they
+//! can't prevent the default action of the event.
+
+use crate::document::{document, UseEval};
+use crate::events::{KeyboardData, KeyboardEvent, SerializedKeyboardData};
+use crate::geometry::{PixelsSize, PixelsVector2D};
+use dioxus_core::prelude::*;
+use dioxus_core_macro::{component, Props};
+use dioxus_signals::{CopyValue, Readable, Writable};
+use serde::Deserialize;
+use serde_json::Value;
+use std::rc::Rc;
+
+const LISTENER_SCRIPT: &str = r#"
+const target = {TARGET};
+const modifiers = ["Alt", "AltGraph", "CapsLock", "Control", "Fn", "FnLock", "Meta", "NumLock", "ScrollLock", "Shift",
+    "Symbol", "SymbolLock", "Hyper", "Super"];
+const serialize = (event) => {
+    if (event instanceof KeyboardEvent) {
+        return {
+            char_code: event.charCode,
+            is_composing: event.isComposing,
+            key: event.key,
+            alt_key: event.altKey,
+            ctrl_key: event.ctrlKey,
+            meta_key: event.metaKey,
+            key_code: event.keyCode,
+            shift_key: event.shiftKey,
+            location: event.location,
+            repeat: event.repeat,
+            which: event.which,
+            code: event.code,
+            modifier_state: modifiers.filter((name) => event.getModifierState(name)),
+        };
+    }
+    switch (event.type) {
+        case "resize":
+            return { width: window.innerWidth, height: window.innerHeight };
+        case "scroll":
+            return { x: window.scrollX, y: window.scrollY };
+        case "visibilitychange":
+            return { visible: document.visibilityState === "visible" };
+        default:
+            return {};
+    }
+};
+const listeners = new Map();
+while (true) {
+    // The names of the events to listen to, or null once the listener is dropped
+    const names = await dioxus.recv();
+    const next = new Set(names || []);
+    for (const [name, listener] of listeners) {
+        if (!next.has(name)) {
+            target.removeEventListener(name, listener);
+            listeners.delete(name);
+        }
+    }
+    for (const name of next) {
+        if (!listeners.has(name)) {
+            const listener = (event) => dioxus.send({ name, data: serialize(event) });
+            target.addEventListener(name, listener);
+            listeners.set(name, listener);
+        }
+    }
+    if (!names) return;
+}
+"#;
+
+#[derive(Deserialize)]
+struct Message {
+    name: String,
+    data: Value,
+}
+
+#[derive(Deserialize)]
+struct Size {
+    width: f64,
+    height: f64,
+}
+
+#[derive(Deserialize)]
+struct Scroll {
+    x: f64,
+    y: f64,
+}
+
+#[derive(Deserialize)]
+struct Visibility {
+    visible: bool,
+}
+
+fn keyboard_event(data: Value) -> Option<KeyboardEvent> {
+    let data = SerializedKeyboardData::deserialize(data).ok()?;
+    Some(Event::new(Rc::new(KeyboardData::new(data)), false))
+}
+
+fn call<T: 'static>(handler: Option<EventHandler<T>>, value: Option<T>) {
+    if let (Some(handler), Some(value)) = (handler, value) {
+        handler.call(value);
+    }
+}
+
+/// A target that events can be listened to on
+trait GlobalListener: Clone + 'static {
+    /// The javascript expression of the target
+    const TARGET: &'static str;
+
+    /// The names of the events that have a handler
+    fn events(&self) -> Vec<&'static str>;
+
+    /// Call the handler of an event
+    fn dispatch(&self, name: &str, data: Value);
+}
+
+/// Keep the listeners of the target in sync with the handlers of the props
+fn use_global_listener<L: GlobalListener>(props: L) -> Element {
+    let events = props.events();
+    let mut latest = use_hook(|| CopyValue::new(props.clone()));
+    latest.set(props);
+
+    let mut listening = use_hook(|| CopyValue::new(None::<Vec<&'static str>>));
+    let eval = use_hook(|| {
+        let script = LISTENER_SCRIPT.replace("{TARGET}", L::TARGET);
+        let mut eval = UseEval::new(document().new_evaluator(script));
+        spawn(async move {
+            while let Ok(message) = eval.recv().await {
+                if let Ok(message) = Message::deserialize(message) {
+                    latest.cloned().dispatch(&message.name, message.data);
+                }
+            }
+        });
+        eval
+    });
+    use_drop(move || {
+        _ = eval.send(Value::Null);
+    });
+
+    if listening.peek().as_ref() != Some(&events) {
+        _ = eval.send(events.clone().into());
+        listening.set(Some(events));
+    }
+
+    VNode::empty()
+}
+
+macro_rules! listener_events {
+    ($($handler:ident => $name:literal),* $(,)?) => {
+        fn events(&self) -> Vec<&'static str> {
+            let mut events = Vec::new();
+            $(
+                if self.$handler.is_some() {
+                    events.push($name);
+                }
+            )*
+            events
+        }
+    };
+}
+
+/// The props of a [`WindowListener`]
+#[derive(Props, Clone, PartialEq)]
+pub struct WindowListenerProps {
+    /// Called when a key is pressed anywhere in the window
+    pub onkeydown: Option<EventHandler<KeyboardEvent>>,
+    /// Called when a key is released anywhere in the window
+    pub onkeyup: Option<EventHandler<KeyboardEvent>>,
+    /// Called with the new inner size of the window when it is resized
+    pub onresize: Option<EventHandler<PixelsSize>>,
+    /// Called with the scroll offset of the page when it is scrolled
+    pub onscroll: Option<EventHandler<PixelsVector2D>>,
+    /// Called when the window gains the focus
+    pub onfocus: Option<EventHandler>,
+    /// Called when the window loses the focus
+    pub onblur: Option<EventHandler>,
+    /// Called when the network connection comes back
+    pub ononline: Option<EventHandler>,
+    /// Called when the network connection is lost
+    pub onoffline: Option<EventHandler>,
+}
+
+impl GlobalListener for WindowListenerProps {
+    const TARGET: &'static str = "window";
+
+    listener_events! {
+        onkeydown => "keydown",
+        onkeyup => "keyup",
+        onresize => "resize",
+        onscroll => "scroll",
+        onfocus => "focus",
+        onblur => "blur",
+        ononline => "online",
+        onoffline => "offline",
+    }
+
+    fn dispatch(&self, name: &str, data: Value) {
+        match name {
+            "keydown" => call(self.onkeydown, keyboard_event(data)),
+            "keyup" => call(self.onkeyup, keyboard_event(data)),
+            "resize" => call(
+                self.onresize,
+                Size::deserialize(data)
+                    .ok()
+                    .map(|size| PixelsSize::new(size.width, size.height)),
+            ),
+            "scroll" => call(
+                self.onscroll,
+                Scroll::deserialize(data)
+                    .ok()
+                    .map(|scroll| PixelsVector2D::new(scroll.x, scroll.y)),
+            ),
+            "focus" => call(self.onfocus, Some(())),
+            "blur" => call(self.onblur, Some(())),
+            "online" => call(self.ononline, Some(())),
+            "offline" => call(self.onoffline, Some(())),
+            _ => {}
+        }
+    }
+}
+
+/// Listen to events on the window while the component is rendered. Only the events with a handler are listened to.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_html::global_events::WindowListener;
+///
+/// fn app() -> Element {
+///     let mut open = use_signal(|| true);
+///     let mut width = use_signal(|| 0.0);
+///     rsx! {
+///         WindowListener {
+///             onkeydown: move |event: KeyboardEvent| {
+///                 if event.key() == Key::Escape {
+///                     open.set(false);
+///                 }
+///             },
+///             onresize: move |size: dioxus_html::geometry::PixelsSize| width.set(size.width),
+///         }
+///         if open() {
+///             div { class: "modal", "The window is {width}px wide" }
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn WindowListener(props: WindowListenerProps) -> Element {
+    use_global_listener(props)
+}
+
+/// The props of a [`DocumentListener`]
+#[derive(Props, Clone, PartialEq)]
+pub struct DocumentListenerProps {
+    /// Called when a key is pressed in the document
+    pub onkeydown: Option<EventHandler<KeyboardEvent>>,
+    /// Called when a key is released in the document
+    pub onkeyup: Option<EventHandler<KeyboardEvent>>,
+    /// Called with `true` when the page becomes visible and `false` when it is hidden, for example when the user
+    /// switches tabs
+    pub onvisibilitychange: Option<EventHandler<bool>>,
+}
+
+impl GlobalListener for DocumentListenerProps {
+    const TARGET: &'static str = "document";
+
+    listener_events! {
+        onkeydown => "keydown",
+        onkeyup => "keyup",
+        onvisibilitychange => "visibilitychange",
+    }
+
+    fn dispatch(&self, name: &str, data: Value) {
+        match name {
+            "keydown" => call(self.onkeydown, keyboard_event(data)),
+            "keyup" => call(self.onkeyup, keyboard_event(data)),
+            "visibilitychange" => call(
+                self.onvisibilitychange,
+                Visibility::deserialize(data)
+                    .ok()
+                    .map(|visibility| visibility.visible),
+            ),
+            _ => {}
+        }
+    }
+}
+
+/// Listen to events on the document while the component is rendered. Only the events with a handler are listened
+/// to.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_html::global_events::DocumentListener;
+///
+/// fn app() -> Element {
+///     let mut paused = use_signal(|| false);
+///     rsx! {
+///         DocumentListener { onvisibilitychange: move |visible: bool| paused.set(!visible) }
+///         if paused() { "Paused" } else { "Playing" }
+///     }
+/// }
+/// ```
+#[component]
+pub fn DocumentListener(props: DocumentListenerProps) -> Element {
+    use_global_listener(props)
+}
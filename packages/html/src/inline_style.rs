@@ -0,0 +1,139 @@
+use dioxus_core::prelude::IntoAttributeValue;
+use dioxus_core::Attribute;
+
+/// A typed builder for the inline style of an element.
+///
+/// Each property is a separate attribute in the `style` namespace, just like the `width` or `color` attributes on an
+/// element. Renderers diff and update each property individually instead of rewriting the whole `style` string.
+/// Spread the builder into an element to apply it:
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # let is_active = true;
+/// let style = InlineStyle::new()
+///     .width("100px")
+///     .color(if is_active { "red" } else { "black" })
+///     .property("--accent", "blue");
+///
+/// rsx! {
+///     div { ..style, "Hello" }
+/// };
+/// ```
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct InlineStyle {
+    properties: Vec<Attribute>,
+}
+
+impl InlineStyle {
+    /// Create a new empty style
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set a property that doesn't have a typed method, like a css custom property
+    pub fn property(mut self, name: &'static str, value: impl IntoAttributeValue) -> Self {
+        self.properties.push(Attribute::new(
+            name,
+            value.into_value(),
+            Some("style"),
+            false,
+        ));
+        self
+    }
+
+    /// Set a css custom property
+    pub fn var(self, var: CssVar, value: impl IntoAttributeValue) -> Self {
+        self.property(var.name(), value)
+    }
+
+    /// Convert the style into the attributes spread into an element
+    pub fn into_boxed_slice(self) -> Box<[Attribute]> {
+        self.properties.into_boxed_slice()
+    }
+}
+
+impl From<InlineStyle> for Vec<Attribute> {
+    fn from(style: InlineStyle) -> Self {
+        style.properties
+    }
+}
+
+/// The name of a css custom property, like `--accent`.
+///
+/// Declare the custom properties of a theme once and use them to both set and read the properties. Each property is
+/// set as its own attribute in the `style` namespace, so changing one property only updates that property in the
+/// renderer:
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// const ACCENT: CssVar = CssVar::new("--accent");
+///
+/// fn app() -> Element {
+///     let accent = use_signal(|| "rebeccapurple".to_string());
+///     rsx! {
+///         div { ..ACCENT.set(accent()),
+///             button { color: ACCENT.var(), "Themed" }
+///         }
+///     }
+/// }
+/// ```
+///
+/// The computed value of a property can be read from a mounted element with
+/// [`MountedData::get_css_var`](crate::MountedData::get_css_var).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CssVar(&'static str);
+
+impl CssVar {
+    /// Create a custom property from its name. Panics if the name doesn't start with `--`.
+    pub const fn new(name: &'static str) -> Self {
+        let bytes = name.as_bytes();
+        assert!(
+            bytes.len() > 2 && bytes[0] == b'-' && bytes[1] == b'-',
+            "css custom properties must start with `--`"
+        );
+        Self(name)
+    }
+
+    /// The name of the property, including the leading `--`
+    pub const fn name(self) -> &'static str {
+        self.0
+    }
+
+    /// Set the property on an element
+    pub fn set(self, value: impl IntoAttributeValue) -> InlineStyle {
+        InlineStyle::new().var(self, value)
+    }
+
+    /// A `var()` expression that reads the property, to use in the value of another property
+    pub fn var(self) -> String {
+        format!("var({})", self.0)
+    }
+
+    /// A `var()` expression that reads the property, or the fallback if the property isn't set
+    pub fn var_or(self, fallback: impl std::fmt::Display) -> String {
+        format!("var({}, {fallback})", self.0)
+    }
+}
+
+impl std::fmt::Display for CssVar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+/// Set a css custom property on an element. Spread the property into the element:
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// fn app() -> Element {
+///     let accent = use_signal(|| "tomato".to_string());
+///     rsx! {
+///         div { ..css_var("--accent", accent), "Hello" }
+///     }
+/// }
+/// ```
+///
+/// Panics if the name doesn't start with `--`.
+pub fn css_var(name: &'static str, value: impl IntoAttributeValue) -> InlineStyle {
+    CssVar::new(name).set(value)
+}
@@ -3,7 +3,7 @@ use enumset::{EnumSet, EnumSetType};
 
 /// A re-export of keyboard_types
 pub use keyboard_types;
-use keyboard_types::Location;
+use keyboard_types::{Location, Modifiers};
 
 /// A mouse button type (such as Primary/Secondary)
 // note: EnumSetType also derives Copy and Clone for some reason
@@ -143,3 +143,51 @@ pub fn encode_key_location(location: Location) -> usize {
         Location::Numpad => 3,
     }
 }
+
+/// The names of the modifiers in the `getModifierState` method of web keyboard and mouse events
+const MODIFIER_NAMES: &[(&str, Modifiers)] = &[
+    ("Alt", Modifiers::ALT),
+    ("AltGraph", Modifiers::ALT_GRAPH),
+    ("CapsLock", Modifiers::CAPS_LOCK),
+    ("Control", Modifiers::CONTROL),
+    ("Fn", Modifiers::FN),
+    ("FnLock", Modifiers::FN_LOCK),
+    ("Meta", Modifiers::META),
+    ("NumLock", Modifiers::NUM_LOCK),
+    ("ScrollLock", Modifiers::SCROLL_LOCK),
+    ("Shift", Modifiers::SHIFT),
+    ("Symbol", Modifiers::SYMBOL),
+    ("SymbolLock", Modifiers::SYMBOL_LOCK),
+    ("Hyper", Modifiers::HYPER),
+    ("Super", Modifiers::SUPER),
+];
+
+/// Build the modifiers from the names of the active modifiers. Unknown names are ignored.
+pub fn decode_modifier_state<'a>(names: impl IntoIterator<Item = &'a str>) -> Modifiers {
+    let mut modifiers = Modifiers::empty();
+    for name in names {
+        if let Some((_, modifier)) = MODIFIER_NAMES.iter().find(|(known, _)| *known == name) {
+            modifiers.insert(*modifier);
+        }
+    }
+    modifiers
+}
+
+/// Get the names of the active modifiers, as used by `getModifierState` on the web
+pub fn encode_modifier_state(modifiers: Modifiers) -> Vec<String> {
+    MODIFIER_NAMES
+        .iter()
+        .filter(|(_, modifier)| modifiers.contains(*modifier))
+        .map(|(name, _)| name.to_string())
+        .collect()
+}
+
+/// Get the modifiers of a web event from its `getModifierState` method
+pub fn modifiers_from_state(mut is_active: impl FnMut(&str) -> bool) -> Modifiers {
+    decode_modifier_state(
+        MODIFIER_NAMES
+            .iter()
+            .map(|(name, _)| *name)
+            .filter(|name| is_active(name)),
+    )
+}
@@ -1 +1 @@
-var createElementInHead=function(tag,attributes,children){const element=document.createElement(tag);for(let[key,value]of attributes)element.setAttribute(key,value);if(children)element.appendChild(document.createTextNode(children));document.head.appendChild(element)};window.createElementInHead=createElementInHead;
+var createElementInHead=function(tag,attributes,children){const element=document.createElement(tag);for(let[key,value]of attributes)element.setAttribute(key,value);if(children)element.appendChild(document.createTextNode(children));document.head.appendChild(element)};window.createElementInHead=createElementInHead;var setMetaInHead=function(key,value,content){let element=document.head.querySelector(`meta[${key}="${CSS.escape(value)}"]`);if(content===null){element?.remove();return}if(!element)element=document.createElement("meta"),element.setAttribute(key,value),document.head.appendChild(element);element.setAttribute("content",content)};window.setMetaInHead=setMetaInHead;
@@ -0,0 +1,283 @@
+//! An image component that loads lazily, shows a blurred preview while it loads and can suspend until the image is
+//! decoded.
+
+use crate::document::{document, eval};
+use crate::{self as dioxus_elements};
+use dioxus_core::prelude::*;
+use dioxus_core_macro::{component, rsx, Props};
+use dioxus_hooks::{use_reactive, use_resource};
+use dioxus_signals::{CopyValue, GlobalSignal, Readable, Writable};
+use std::fmt::{Display, Formatter};
+
+/// The source of an [`Image`]: the url of the image and an optional low quality preview that is shown while the
+/// image loads.
+///
+/// Sources can be created from a string, or from an asset built by the `asset!` macro with the `manganis` feature.
+/// Assets built with `.low_quality_preview()` use their preview automatically.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ImageSource {
+    src: String,
+    preview: Option<String>,
+}
+
+impl ImageSource {
+    /// Create a new image source from a url
+    pub fn new(src: impl ToString) -> Self {
+        Self {
+            src: src.to_string(),
+            preview: None,
+        }
+    }
+
+    /// Show a preview of the image, like a tiny or url encoded version of the image, while the image loads. The
+    /// preview is blurred until the image is swapped in.
+    pub fn preview(mut self, preview: impl ToString) -> Self {
+        self.preview = Some(preview.to_string());
+        self
+    }
+}
+
+impl From<&str> for ImageSource {
+    fn from(src: &str) -> Self {
+        Self::new(src)
+    }
+}
+
+impl From<String> for ImageSource {
+    fn from(src: String) -> Self {
+        Self::new(src)
+    }
+}
+
+#[cfg(feature = "manganis")]
+impl From<manganis::ImageAsset> for ImageSource {
+    fn from(asset: manganis::ImageAsset) -> Self {
+        Self {
+            src: asset.path().to_string(),
+            preview: asset.preview().map(str::to_string),
+        }
+    }
+}
+
+/// The candidates of the `srcset` of an [`Image`]. Each candidate is a version of the image with a different width,
+/// for example assets built with different `.size(..)`s. The browser picks the candidate that fits the `sizes` of the
+/// image best.
+///
+/// ```rust
+/// # use dioxus_html::lazy_image::SrcSet;
+/// let srcset = SrcSet::new()
+///     .width("/photo-480.webp", 480)
+///     .width("/photo-960.webp", 960);
+/// assert_eq!(srcset.to_string(), "/photo-480.webp 480w, /photo-960.webp 960w");
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SrcSet {
+    candidates: Vec<(String, u32)>,
+}
+
+impl SrcSet {
+    /// Create an empty srcset
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a candidate that is a number of pixels wide
+    pub fn width(mut self, src: impl Into<ImageSource>, width: u32) -> Self {
+        self.candidates.push((src.into().src, width));
+        self
+    }
+
+    /// Check if the srcset has no candidates
+    pub fn is_empty(&self) -> bool {
+        self.candidates.is_empty()
+    }
+}
+
+impl Display for SrcSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (index, (src, width)) in self.candidates.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{src} {width}w")?;
+        }
+        Ok(())
+    }
+}
+
+const LOAD_IMAGE_SCRIPT: &str = r#"
+const img = document.querySelector('[data-dioxus-image="{ID}"]');
+{RESET}
+if (img && !img.dataset.loaded) {
+    const load = async () => {
+        const src = img.dataset.src;
+        const full = new Image();
+        if (img.dataset.sizes) full.sizes = img.dataset.sizes;
+        if (img.dataset.srcset) full.srcset = img.dataset.srcset;
+        full.src = img.dataset.src;
+        try {
+            await full.decode();
+        } catch (e) {}
+        // The source changed while the image was loading
+        if (img.dataset.src !== src) return;
+        if (img.dataset.sizes) img.sizes = img.dataset.sizes;
+        if (img.dataset.srcset) img.srcset = img.dataset.srcset;
+        img.src = img.dataset.src;
+        img.style.filter = "";
+        img.dataset.loaded = "true";
+    };
+    if (img.dataset.lazy === "true" && "IntersectionObserver" in window) {
+        const observer = new IntersectionObserver((entries) => {
+            if (entries.some((entry) => entry.isIntersecting)) {
+                observer.disconnect();
+                load();
+            }
+        }, { rootMargin: "200px" });
+        observer.observe(img);
+    } else {
+        load();
+    }
+}
+"#;
+
+const RESET_IMAGE_SCRIPT: &str = r#"
+if (img) {
+    delete img.dataset.loaded;
+    if (img.dataset.preview) {
+        img.removeAttribute("srcset");
+        img.removeAttribute("sizes");
+        img.src = img.dataset.preview;
+        img.style.filter = "blur(12px)";
+    }
+}
+"#;
+
+/// The script that swaps in the image once it is loaded. Reset the image to load a new source after the source of a
+/// mounted image changed.
+fn load_image_script(id: usize, reset: bool) -> String {
+    LOAD_IMAGE_SCRIPT
+        .replace("{ID}", &id.to_string())
+        .replace("{RESET}", if reset { RESET_IMAGE_SCRIPT } else { "" })
+}
+
+const DECODE_IMAGE_SCRIPT: &str = r#"
+const img = new Image();
+img.src = {SRC};
+try {
+    await img.decode();
+} catch (e) {}
+return true;
+"#;
+
+const PREVIEW_STYLE: &str = "filter: blur(12px); transition: filter 0.3s;";
+
+/// An `img` that loads when it scrolls into view and swaps in the image only after it is decoded, so the image never
+/// paints half loaded.
+///
+/// - Until the image is loaded, the [`ImageSource::preview`] is shown blurred in its place.
+/// - With `lazy` (the default), the image starts loading when it is close to the viewport.
+/// - With `suspend`, the component suspends until the image is decoded, so the closest `SuspenseBoundary` shows its
+///   fallback instead of an empty image. Suspending images load eagerly. Platforms that can't run javascript, like
+///   ssr, don't wait for the image.
+///
+/// The image is swapped in by a script, so lazy images without a preview stay empty until the page is hydrated. When
+/// the `src` changes, the preview is shown again until the new image is loaded.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_html::lazy_image::{Image, ImageSource, SrcSet};
+///
+/// fn app() -> Element {
+///     rsx! {
+///         Image {
+///             src: ImageSource::new("/photo.webp").preview("data:image/webp;base64,..."),
+///             srcset: SrcSet::new().width("/photo-480.webp", 480).width("/photo.webp", 960),
+///             sizes: "(max-width: 600px) 480px, 960px",
+///             alt: "A photo of a crab on the beach",
+///             width: 960,
+///             height: 640,
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn Image(
+    /// The image to show
+    #[props(into)]
+    src: ImageSource,
+    /// A text description of the image for screen readers and for when the image can't be loaded
+    #[props(into)]
+    alt: String,
+    /// Other versions of the image the browser can pick from
+    #[props(default)]
+    srcset: SrcSet,
+    /// The width the image is rendered at for different viewports. This is used to pick a candidate of the srcset.
+    #[props(into)]
+    sizes: Option<String>,
+    /// The width of the image in pixels. Set the width and height to reserve space for the image while it loads.
+    width: Option<u32>,
+    /// The height of the image in pixels
+    height: Option<u32>,
+    /// The class of the `img`
+    #[props(into)]
+    class: Option<String>,
+    /// Wait until the image is close to the viewport to load it
+    #[props(default = true)]
+    lazy: bool,
+    /// Suspend the component until the image is decoded
+    #[props(default)]
+    suspend: bool,
+) -> Element {
+    let id = use_hook(|| current_scope_id().map(|id| id.0).unwrap_or_default());
+    let decoded = use_resource(use_reactive(
+        (&src.src, &suspend),
+        |(src, suspend)| async move {
+            if suspend {
+                let src = serde_json::to_string(&src).unwrap_or_default();
+                _ = eval(&DECODE_IMAGE_SCRIPT.replace("{SRC}", &src))
+                    .join()
+                    .await;
+            }
+        },
+    ));
+    if suspend {
+        decoded.suspend()?;
+    }
+
+    // The script already swapped in the old image, so it needs to load the new image after it is rendered
+    let mut rendered_src = use_hook(|| CopyValue::new(src.src.clone()));
+    if *rendered_src.peek() != src.src {
+        rendered_src.set(src.src.clone());
+        queue_effect(move || {
+            document().new_evaluator(load_image_script(id, true));
+        });
+    }
+
+    let lazy = lazy && !suspend;
+    let preview = src.preview.clone().filter(|_| !suspend);
+    let eager_src = (!lazy && preview.is_none()).then(|| src.src.clone());
+    let srcset = (!srcset.is_empty()).then(|| srcset.to_string());
+
+    rsx! {
+        img {
+            "data-dioxus-image": "{id}",
+            "data-src": "{src.src}",
+            "data-srcset": srcset.clone(),
+            "data-sizes": sizes.clone(),
+            "data-lazy": "{lazy}",
+            "data-preview": preview.clone(),
+            src: preview.clone().or(eager_src.clone()),
+            srcset: eager_src.as_ref().and(srcset),
+            "sizes": eager_src.as_ref().and(sizes),
+            style: preview.as_ref().map(|_| PREVIEW_STYLE),
+            alt,
+            width: width.map(|width| width.to_string()),
+            height: height.map(|height| height.to_string()),
+            class,
+            decoding: "async",
+            onmounted: move |_| {
+                document().new_evaluator(load_image_script(id, false));
+            },
+        }
+    }
+}
@@ -16,6 +16,18 @@
 //!
 //! Currently, we don't validate for structures, but do validate attributes.
 
+#[cfg(feature = "a11y")]
+pub mod a11y;
+#[cfg(feature = "aria")]
+pub mod aria;
+#[cfg(feature = "broadcast")]
+pub mod broadcast;
+#[cfg(feature = "canvas")]
+pub mod canvas2d;
+#[cfg(feature = "chart")]
+pub mod chart;
+#[cfg(feature = "controlled-input")]
+pub mod controlled_input;
 pub mod elements;
 #[cfg(feature = "hot-reload-context")]
 pub use elements::HtmlCtx;
@@ -23,16 +35,38 @@ pub use elements::HtmlCtx;
 pub use elements::{map_html_attribute_to_rsx, map_html_element_to_rsx};
 pub mod events;
 pub(crate) mod file_data;
+#[cfg(feature = "focus")]
+pub mod focus;
 pub use file_data::*;
 mod attribute_groups;
 pub mod geometry;
+#[cfg(feature = "global-events")]
+pub mod global_events;
+mod inline_style;
+#[cfg(feature = "lazy-image")]
+pub mod lazy_image;
+#[cfg(feature = "markdown")]
+pub mod markdown;
+#[cfg(feature = "media")]
+pub mod media;
+#[cfg(feature = "portal")]
+pub mod portal;
+pub use inline_style::*;
 pub mod input_data;
 #[cfg(feature = "native-bind")]
 pub mod native_bind;
 pub mod point_interaction;
+#[cfg(feature = "presence")]
+pub mod presence;
 mod render_template;
+#[cfg(feature = "speech")]
+pub mod speech;
+#[cfg(feature = "toast")]
+pub mod toast;
 #[cfg(feature = "wasm-bind")]
 mod web_sys_bind;
+#[cfg(feature = "webrtc")]
+pub mod webrtc;
 #[cfg(feature = "wasm-bind")]
 pub use web_sys_bind::*;
 
@@ -42,6 +76,9 @@ mod transit;
 #[cfg(feature = "serialize")]
 pub use transit::*;
 
+#[cfg(feature = "serialize")]
+pub mod replay;
+
 pub use attribute_groups::*;
 pub use elements::*;
 pub use events::*;
@@ -59,11 +96,15 @@ pub mod prelude {
     pub use crate::attribute_groups::{GlobalAttributesExtension, SvgAttributesExtension};
     #[cfg(feature = "document")]
     pub use crate::document::{
-        self, document, eval, head, Document, Meta, MetaProps, Script, ScriptProps, Style,
-        StyleProps, Title, TitleProps, UseEval,
+        self, document, eval, head, Document, Meta, MetaProps, ScopedStyle, Script, ScriptProps,
+        Style, StyleProps, Title, TitleProps, UseEval,
     };
     pub use crate::elements::extensions::*;
     pub use crate::events::*;
+    #[cfg(feature = "file_engine")]
+    pub use crate::file_data::FileData;
+    pub use crate::file_data::HasFileData;
+    pub use crate::inline_style::{css_var, CssVar, InlineStyle};
     pub use crate::point_interaction::*;
     pub use keyboard_types::{self, Code, Key, Location, Modifiers};
 }
@@ -0,0 +1,413 @@
+//! Render markdown to elements.
+//!
+//! The [`Markdown`] component parses [CommonMark](https://commonmark.org) with
+//! [pulldown-cmark](https://docs.rs/pulldown-cmark): headings, paragraphs, emphasis, strong text, inline code, code
+//! blocks, block quotes, ordered and unordered lists, thematic breaks, links and images. The markdown is rendered to
+//! real elements instead of inner html, so it can't break out of the component:
+//!
+//! - Embedded html is rendered as text.
+//! - Links to urls that could run code, like `javascript:` urls, are rendered as plain text. Only relative urls and
+//!   `http`, `https`, `mailto` and `tel` urls are linked. Images only load relative, `http` and `https` urls.
+
+use crate::{self as dioxus_elements};
+use dioxus_core::prelude::*;
+use dioxus_core_macro::{component, rsx, Props};
+use dioxus_signals::{GlobalSignal, Readable};
+use pulldown_cmark::{CodeBlockKind, Event, Parser, Tag};
+
+/// A block of markdown
+#[derive(Debug, Clone, PartialEq)]
+pub enum Block {
+    /// A heading with a level from 1 to 6
+    Heading(u8, Vec<Inline>),
+    /// A paragraph
+    Paragraph(Vec<Inline>),
+    /// A code block
+    Code {
+        /// The language after the opening fence of a fenced code block
+        language: Option<String>,
+        /// The code inside of the fence
+        code: String,
+    },
+    /// A block quote
+    Quote(Vec<Block>),
+    /// A list of items
+    List {
+        /// The number of the first item of an ordered list, or `None` for an unordered list
+        start: Option<u64>,
+        /// The blocks of each item
+        items: Vec<Vec<Block>>,
+    },
+    /// A thematic break
+    Rule,
+}
+
+/// Text inside of a [`Block`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Inline {
+    /// Plain text
+    Text(String),
+    /// Inline code
+    Code(String),
+    /// Emphasized text
+    Emphasis(Vec<Inline>),
+    /// Strong text
+    Strong(Vec<Inline>),
+    /// A link
+    Link {
+        /// The url of the link
+        href: String,
+        /// The text of the link
+        children: Vec<Inline>,
+    },
+    /// An image
+    Image {
+        /// The url of the image
+        src: String,
+        /// The text description of the image
+        alt: String,
+    },
+    /// A hard line break
+    LineBreak,
+}
+
+/// Parse markdown into blocks
+pub fn parse(source: &str) -> Vec<Block> {
+    parse_blocks(&mut Parser::new(source))
+}
+
+/// Split markdown into the source of its top level blocks. The [`Markdown`] component only parses and renders blocks
+/// again when their source changes.
+pub fn split_blocks(source: &str) -> Vec<&str> {
+    let mut starts = Vec::new();
+    let mut depth = 0;
+    for (event, range) in Parser::new(source).into_offset_iter() {
+        match event {
+            Event::Start(_) => {
+                if depth == 0 {
+                    starts.push(range.start);
+                }
+                depth += 1;
+            }
+            Event::End(_) => depth -= 1,
+            // Rules are top level blocks without a start and an end
+            _ if depth == 0 => starts.push(range.start),
+            _ => {}
+        }
+    }
+    starts
+        .iter()
+        .enumerate()
+        .map(|(index, &start)| {
+            let end = starts.get(index + 1).copied().unwrap_or(source.len());
+            trim_blank_lines(&source[start..end])
+        })
+        .collect()
+}
+
+/// Remove the blank lines after a block, but keep the newline that ends its last line
+fn trim_blank_lines(block: &str) -> &str {
+    let content = block.trim_end().len();
+    let newline = block[content..].find('\n').map_or(0, |newline| newline + 1);
+    &block[..content + newline]
+}
+
+/// Parse the blocks until the end of the current container
+fn parse_blocks<'a>(events: &mut impl Iterator<Item = Event<'a>>) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    // The text of tight list items isn't wrapped in a paragraph
+    let mut text = Vec::new();
+    while let Some(event) = events.next() {
+        let block = match event {
+            Event::End(_) => break,
+            Event::Start(Tag::Paragraph) => Block::Paragraph(parse_inlines(events)),
+            Event::Start(Tag::Heading { level, .. }) => {
+                Block::Heading(level as u8, parse_inlines(events))
+            }
+            Event::Start(Tag::BlockQuote(_)) => Block::Quote(parse_blocks(events)),
+            Event::Start(Tag::CodeBlock(kind)) => {
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => {
+                        info.split_whitespace().next().map(str::to_string)
+                    }
+                    CodeBlockKind::Indented => None,
+                };
+                let code = plain_text(&parse_inlines(events));
+                Block::Code { language, code }
+            }
+            // Embedded html is rendered as text
+            Event::Start(Tag::HtmlBlock) => {
+                let html = plain_text(&parse_inlines(events));
+                Block::Paragraph(vec![Inline::Text(html.trim_end().to_string())])
+            }
+            Event::Start(Tag::List(start)) => {
+                let mut items = Vec::new();
+                while let Some(Event::Start(Tag::Item)) = events.next() {
+                    items.push(parse_blocks(events));
+                }
+                Block::List { start, items }
+            }
+            Event::Rule => Block::Rule,
+            event => {
+                push_inline(&mut text, event, events);
+                continue;
+            }
+        };
+        if !text.is_empty() {
+            blocks.push(Block::Paragraph(std::mem::take(&mut text)));
+        }
+        blocks.push(block);
+    }
+    if !text.is_empty() {
+        blocks.push(Block::Paragraph(text));
+    }
+    blocks
+}
+
+/// Parse the text inside of a block
+pub fn parse_inline(text: &str) -> Vec<Inline> {
+    let mut inlines = Vec::new();
+    let mut events = Parser::new(text);
+    while let Some(event) = events.next() {
+        match event {
+            Event::Start(Tag::Paragraph) | Event::End(_) => {}
+            event => push_inline(&mut inlines, event, &mut events),
+        }
+    }
+    inlines
+}
+
+/// Parse the inlines until the end of the current tag
+fn parse_inlines<'a>(events: &mut impl Iterator<Item = Event<'a>>) -> Vec<Inline> {
+    let mut inlines = Vec::new();
+    while let Some(event) = events.next() {
+        if let Event::End(_) = event {
+            break;
+        }
+        push_inline(&mut inlines, event, events);
+    }
+    inlines
+}
+
+fn push_inline<'a>(
+    inlines: &mut Vec<Inline>,
+    event: Event<'a>,
+    events: &mut impl Iterator<Item = Event<'a>>,
+) {
+    let inline = match event {
+        // Embedded html is rendered as text
+        Event::Text(text) | Event::Html(text) | Event::InlineHtml(text) => {
+            Inline::Text(text.to_string())
+        }
+        Event::Code(code) => Inline::Code(code.to_string()),
+        Event::SoftBreak => Inline::Text("\n".to_string()),
+        Event::HardBreak => Inline::LineBreak,
+        Event::Start(Tag::Emphasis) => Inline::Emphasis(parse_inlines(events)),
+        Event::Start(Tag::Strong) => Inline::Strong(parse_inlines(events)),
+        Event::Start(Tag::Link { dest_url, .. }) => {
+            let children = parse_inlines(events);
+            if !safe_url(&dest_url, true) {
+                // Links that could run code are rendered as their text
+                for child in children {
+                    push(inlines, child);
+                }
+                return;
+            }
+            Inline::Link {
+                href: dest_url.to_string(),
+                children,
+            }
+        }
+        Event::Start(Tag::Image { dest_url, .. }) => {
+            let alt = plain_text(&parse_inlines(events));
+            if !safe_url(&dest_url, false) {
+                Inline::Text(alt)
+            } else {
+                Inline::Image {
+                    src: dest_url.to_string(),
+                    alt,
+                }
+            }
+        }
+        // Only the tags of the extensions that are not enabled are left
+        Event::Start(_) => {
+            for child in parse_inlines(events) {
+                push(inlines, child);
+            }
+            return;
+        }
+        _ => return,
+    };
+    push(inlines, inline);
+}
+
+/// Push an inline and merge it with the text before it. The parser splits text around escapes and special characters.
+fn push(inlines: &mut Vec<Inline>, inline: Inline) {
+    match (inlines.last_mut(), inline) {
+        (Some(Inline::Text(last)), Inline::Text(text)) => last.push_str(&text),
+        (_, inline) => inlines.push(inline),
+    }
+}
+
+/// Get the text of inlines without their formatting
+fn plain_text(inlines: &[Inline]) -> String {
+    let mut text = String::new();
+    for inline in inlines {
+        match inline {
+            Inline::Text(content) | Inline::Code(content) => text.push_str(content),
+            Inline::Emphasis(children)
+            | Inline::Strong(children)
+            | Inline::Link { children, .. } => text.push_str(&plain_text(children)),
+            Inline::Image { alt, .. } => text.push_str(alt),
+            Inline::LineBreak => text.push('\n'),
+        }
+    }
+    text
+}
+
+/// Check if a url is relative or uses a scheme that can't run code
+fn safe_url(url: &str, link: bool) -> bool {
+    let url = url.trim();
+    let scheme_end = url.find(':');
+    let path_start = url.find(['/', '?', '#']);
+    match (scheme_end, path_start) {
+        (Some(scheme_end), Some(path_start)) if path_start < scheme_end => true,
+        (Some(scheme_end), _) => {
+            let scheme = url[..scheme_end].to_ascii_lowercase();
+            let allowed: &[&str] = if link {
+                &["http", "https", "mailto", "tel"]
+            } else {
+                &["http", "https"]
+            };
+            allowed.contains(&scheme.as_str())
+        }
+        (None, _) => true,
+    }
+}
+
+/// Render markdown to elements.
+///
+/// The markdown is split into blocks, and only the blocks that changed are parsed and rendered again. Append to the
+/// content as it streams in, like the response of a chat bot, and only the last block is updated. See the
+/// [module documentation](self) for the supported syntax and how untrusted markdown is sanitized.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_html::markdown::Markdown;
+///
+/// fn app() -> Element {
+///     let mut response = use_signal(String::new);
+///     use_future(move || async move {
+///         for token in ["# Hello", " world\n\nThis is **bold**"] {
+///             response.write().push_str(token);
+///         }
+///     });
+///     rsx! {
+///         Markdown { class: "response", content: response() }
+///     }
+/// }
+/// ```
+#[component]
+pub fn Markdown(
+    /// The markdown to render
+    #[props(into)]
+    content: String,
+    /// The class of the `div` around the markdown
+    #[props(into)]
+    class: Option<String>,
+) -> Element {
+    rsx! {
+        div { class,
+            for (index, source) in split_blocks(&content).into_iter().enumerate() {
+                MarkdownBlock { key: "{index}", source: source.to_string() }
+            }
+        }
+    }
+}
+
+#[component]
+fn MarkdownBlock(source: String) -> Element {
+    rsx! {
+        for block in parse(&source) {
+            {render_block(&block)}
+        }
+    }
+}
+
+fn render_block(block: &Block) -> Element {
+    match block {
+        Block::Heading(1, inlines) => rsx! { h1 { {render_inlines(inlines)} } },
+        Block::Heading(2, inlines) => rsx! { h2 { {render_inlines(inlines)} } },
+        Block::Heading(3, inlines) => rsx! { h3 { {render_inlines(inlines)} } },
+        Block::Heading(4, inlines) => rsx! { h4 { {render_inlines(inlines)} } },
+        Block::Heading(5, inlines) => rsx! { h5 { {render_inlines(inlines)} } },
+        Block::Heading(_, inlines) => rsx! { h6 { {render_inlines(inlines)} } },
+        Block::Paragraph(inlines) => rsx! { p { {render_inlines(inlines)} } },
+        Block::Code { language, code } => rsx! {
+            pre {
+                code { class: language.as_ref().map(|language| format!("language-{language}")), "{code}" }
+            }
+        },
+        Block::Quote(blocks) => rsx! {
+            blockquote {
+                for block in blocks {
+                    {render_block(block)}
+                }
+            }
+        },
+        Block::List { start: None, items } => rsx! {
+            ul {
+                for item in items {
+                    {render_item(item)}
+                }
+            }
+        },
+        Block::List {
+            start: Some(start),
+            items,
+        } => rsx! {
+            ol { start: (*start != 1).then(|| start.to_string()),
+                for item in items {
+                    {render_item(item)}
+                }
+            }
+        },
+        Block::Rule => rsx! { hr {} },
+    }
+}
+
+fn render_item(blocks: &[Block]) -> Element {
+    match blocks {
+        // Items with a single paragraph are rendered without the paragraph
+        [Block::Paragraph(inlines)] => rsx! { li { {render_inlines(inlines)} } },
+        blocks => rsx! {
+            li {
+                for block in blocks {
+                    {render_block(block)}
+                }
+            }
+        },
+    }
+}
+
+fn render_inlines(inlines: &[Inline]) -> Element {
+    rsx! {
+        for inline in inlines {
+            {render_inline(inline)}
+        }
+    }
+}
+
+fn render_inline(inline: &Inline) -> Element {
+    match inline {
+        Inline::Text(text) => rsx! { "{text}" },
+        Inline::Code(code) => rsx! { code { "{code}" } },
+        Inline::Emphasis(inlines) => rsx! { em { {render_inlines(inlines)} } },
+        Inline::Strong(inlines) => rsx! { strong { {render_inlines(inlines)} } },
+        Inline::Link { href, children } => {
+            rsx! { a { href: "{href}", {render_inlines(children)} } }
+        }
+        Inline::Image { src, alt } => rsx! { img { src: "{src}", alt: "{alt}" } },
+        Inline::LineBreak => rsx! { br {} },
+    }
+}
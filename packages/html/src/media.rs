@@ -0,0 +1,525 @@
+//! Play and record audio.
+//!
+//! - [`use_audio_player`] plays an audio file and exposes the playback state and output level as signals.
+//! - [`use_audio_recorder`] records the microphone and exposes the input level and the finished [`Recording`]s.
+//!
+//! Both hooks use the [Web Audio](https://developer.mozilla.org/en-US/docs/Web/API/Web_Audio_API) and
+//! [MediaStream Recording](https://developer.mozilla.org/en-US/docs/Web/API/MediaStream_Recording_API) APIs of the
+//! browser or webview the app is rendered in.
+
+use crate::document::{document, EvalError, UseEval};
+use dioxus_core::prelude::*;
+use dioxus_signals::{CopyValue, Readable, Signal, Writable};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+/// Forward messages from a script to a handler until the script exits
+fn listen(
+    mut eval: UseEval,
+    mut error: Signal<Option<String>>,
+    mut handle: impl FnMut(Value) + 'static,
+) {
+    spawn(async move {
+        loop {
+            match eval.recv().await {
+                Ok(message) => {
+                    if let Some(message) = message.get("error").and_then(Value::as_str) {
+                        error.set(Some(message.to_string()));
+                    } else {
+                        handle(message);
+                    }
+                }
+                Err(EvalError::Finished) => break,
+                Err(err) => {
+                    error.set(Some(err.to_string()));
+                    break;
+                }
+            }
+        }
+    });
+}
+
+/// Send a command to a script and report any errors
+fn command(eval: UseEval, mut error: Signal<Option<String>>, command: Value) {
+    if let Err(err) = eval.send(command) {
+        error.set(Some(err.to_string()));
+    }
+}
+
+const PLAYER_SCRIPT: &str = r#"
+const audio = new Audio();
+let context = null;
+let analyser = null;
+let frame = null;
+const send = (message) => dioxus.send(message);
+const state = () => send({
+    playing: !audio.paused,
+    current_time: audio.currentTime,
+    duration: Number.isFinite(audio.duration) ? audio.duration : null,
+    volume: audio.volume,
+    ended: audio.ended,
+});
+const meter = () => {
+    const samples = new Float32Array(analyser.fftSize);
+    let last = 0;
+    const tick = (time) => {
+        if (time - last > 50) {
+            last = time;
+            analyser.getFloatTimeDomainData(samples);
+            let sum = 0;
+            for (const sample of samples) sum += sample * sample;
+            send({ level: Math.sqrt(sum / samples.length) });
+        }
+        frame = requestAnimationFrame(tick);
+    };
+    frame = requestAnimationFrame(tick);
+};
+for (const event of ["play", "pause", "ended", "timeupdate", "loadedmetadata", "volumechange", "seeked"]) {
+    audio.addEventListener(event, state);
+}
+audio.addEventListener("pause", () => {
+    cancelAnimationFrame(frame);
+    send({ level: 0 });
+});
+audio.addEventListener("error", () => send({ error: audio.error ? audio.error.message || "The audio could not be loaded" : "The audio could not be loaded" }));
+
+while (true) {
+    const command = await dioxus.recv();
+    try {
+        switch (command.type) {
+            case "src":
+                audio.src = command.src;
+                break;
+            case "play":
+                // Browsers only allow audio contexts to start after a user interaction, so the meter is created on the first play
+                if (!context) {
+                    context = new AudioContext();
+                    analyser = context.createAnalyser();
+                    context.createMediaElementSource(audio).connect(analyser);
+                    analyser.connect(context.destination);
+                }
+                await context.resume();
+                await audio.play();
+                meter();
+                break;
+            case "pause":
+                audio.pause();
+                break;
+            case "seek":
+                audio.currentTime = command.time;
+                break;
+            case "volume":
+                audio.volume = command.volume;
+                break;
+            case "close":
+                audio.pause();
+                audio.src = "";
+                if (context) context.close();
+                return;
+        }
+    } catch (error) {
+        send({ error: String(error) });
+    }
+}
+"#;
+
+#[derive(Deserialize)]
+struct PlayerState {
+    playing: bool,
+    current_time: f64,
+    duration: Option<f64>,
+    volume: f64,
+    ended: bool,
+}
+
+/// A handle to an audio player created with [`use_audio_player`]. Every getter subscribes to the value it reads.
+#[derive(Clone, Copy)]
+pub struct AudioPlayer {
+    eval: UseEval,
+    src: CopyValue<String>,
+    playing: Signal<bool>,
+    current_time: Signal<f64>,
+    duration: Signal<Option<f64>>,
+    volume: Signal<f64>,
+    ended: Signal<bool>,
+    level: Signal<f64>,
+    error: Signal<Option<String>>,
+}
+
+impl AudioPlayer {
+    /// Start or resume playing
+    pub fn play(&self) {
+        command(self.eval, self.error, json!({ "type": "play" }));
+    }
+
+    /// Pause playing
+    pub fn pause(&self) {
+        command(self.eval, self.error, json!({ "type": "pause" }));
+    }
+
+    /// Pause if the audio is playing, or play if it is paused
+    pub fn toggle(&self) {
+        if *self.playing.peek() {
+            self.pause();
+        } else {
+            self.play();
+        }
+    }
+
+    /// Jump to a time in seconds
+    pub fn seek(&self, time: f64) {
+        command(
+            self.eval,
+            self.error,
+            json!({ "type": "seek", "time": time }),
+        );
+    }
+
+    /// Set the volume between `0.0` (muted) and `1.0` (full volume)
+    pub fn set_volume(&self, volume: f64) {
+        let volume = volume.clamp(0.0, 1.0);
+        command(
+            self.eval,
+            self.error,
+            json!({ "type": "volume", "volume": volume }),
+        );
+    }
+
+    /// Check if the audio is playing
+    pub fn playing(&self) -> bool {
+        (self.playing)()
+    }
+
+    /// Get the current playback position in seconds
+    pub fn current_time(&self) -> f64 {
+        (self.current_time)()
+    }
+
+    /// Get the length of the audio in seconds, or `None` if it isn't known yet
+    pub fn duration(&self) -> Option<f64> {
+        (self.duration)()
+    }
+
+    /// Get the volume between `0.0` and `1.0`
+    pub fn volume(&self) -> f64 {
+        (self.volume)()
+    }
+
+    /// Check if the audio played until the end
+    pub fn ended(&self) -> bool {
+        (self.ended)()
+    }
+
+    /// Get the loudness of the output between `0.0` and `1.0`. The level updates about 20 times a second while the
+    /// audio is playing.
+    pub fn level(&self) -> f64 {
+        (self.level)()
+    }
+
+    /// Get the last error, like a file that couldn't be loaded or a platform that can't play audio
+    pub fn error(&self) -> Option<String> {
+        (self.error)()
+    }
+}
+
+/// Play an audio file. The player starts loading the file immediately, but only plays after [`AudioPlayer::play`] is
+/// called. If the `src` changes, the player switches to the new file.
+///
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+/// use dioxus_html::media::use_audio_player;
+///
+/// fn app() -> Element {
+///     let player = use_audio_player("/assets/song.mp3");
+///     rsx! {
+///         button { onclick: move |_| player.toggle(), if player.playing() { "Pause" } else { "Play" } }
+///         progress { value: player.current_time(), max: player.duration().unwrap_or(1.0) }
+///         meter { value: player.level() }
+///     }
+/// }
+/// ```
+pub fn use_audio_player(src: impl Into<String>) -> AudioPlayer {
+    let src = src.into();
+    let player = use_hook(|| {
+        let eval = UseEval::new(document().new_evaluator(PLAYER_SCRIPT.to_string()));
+        let player = AudioPlayer {
+            eval,
+            src: CopyValue::new(String::new()),
+            playing: Signal::new(false),
+            current_time: Signal::new(0.0),
+            duration: Signal::new(None),
+            volume: Signal::new(1.0),
+            ended: Signal::new(false),
+            level: Signal::new(0.0),
+            error: Signal::new(None),
+        };
+        let AudioPlayer {
+            mut playing,
+            mut current_time,
+            mut duration,
+            mut volume,
+            mut ended,
+            mut level,
+            ..
+        } = player;
+        listen(eval, player.error, move |message| {
+            if let Some(new_level) = message.get("level").and_then(Value::as_f64) {
+                level.set(new_level.min(1.0));
+            } else if let Ok(state) = serde_json::from_value::<PlayerState>(message) {
+                playing.set(state.playing);
+                current_time.set(state.current_time);
+                duration.set(state.duration);
+                volume.set(state.volume);
+                ended.set(state.ended);
+            }
+        });
+        player
+    });
+    use_drop(move || command(player.eval, player.error, json!({ "type": "close" })));
+
+    if *player.src.peek() != src {
+        let mut current_src = player.src;
+        command(
+            player.eval,
+            player.error,
+            json!({ "type": "src", "src": src }),
+        );
+        current_src.set(src);
+    }
+
+    player
+}
+
+const RECORDER_SCRIPT: &str = r#"
+let recorder = null;
+let stream = null;
+let context = null;
+let frame = null;
+let started = 0;
+const send = (message) => dioxus.send(message);
+const bytesToBase64 = (bytes) => {
+    let binary = "";
+    for (let i = 0; i < bytes.length; i += 0x8000) {
+        binary += String.fromCharCode(...bytes.subarray(i, i + 0x8000));
+    }
+    return btoa(binary);
+};
+
+while (true) {
+    const command = await dioxus.recv();
+    try {
+        switch (command.type) {
+            case "start": {
+                if (recorder) break;
+                stream = await navigator.mediaDevices.getUserMedia({ audio: true });
+                context = new AudioContext();
+                const analyser = context.createAnalyser();
+                context.createMediaStreamSource(stream).connect(analyser);
+                const samples = new Float32Array(analyser.fftSize);
+                let last = 0;
+                const tick = (time) => {
+                    if (time - last > 50) {
+                        last = time;
+                        analyser.getFloatTimeDomainData(samples);
+                        let sum = 0;
+                        for (const sample of samples) sum += sample * sample;
+                        send({ level: Math.sqrt(sum / samples.length) });
+                    }
+                    frame = requestAnimationFrame(tick);
+                };
+                frame = requestAnimationFrame(tick);
+
+                const chunks = [];
+                recorder = new MediaRecorder(stream);
+                recorder.ondataavailable = (event) => chunks.push(event.data);
+                recorder.onstop = async () => {
+                    const blob = new Blob(chunks, { type: recorder.mimeType });
+                    const duration = (performance.now() - started) / 1000;
+                    const data = bytesToBase64(new Uint8Array(await blob.arrayBuffer()));
+                    send({ recording: { mime_type: blob.type, data, duration, url: URL.createObjectURL(blob) } });
+                    recorder = null;
+                };
+                started = performance.now();
+                recorder.start();
+                send({ recording_state: true });
+                break;
+            }
+            case "stop":
+            case "close": {
+                if (recorder) recorder.stop();
+                if (stream) stream.getTracks().forEach((track) => track.stop());
+                if (context) context.close();
+                cancelAnimationFrame(frame);
+                stream = null;
+                context = null;
+                send({ level: 0 });
+                send({ recording_state: false });
+                if (command.type === "close") return;
+                break;
+            }
+        }
+    } catch (error) {
+        send({ error: String(error) });
+        send({ recording_state: false });
+    }
+}
+"#;
+
+/// A finished recording from [`use_audio_recorder`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recording {
+    /// The MIME type of the recording, like `audio/webm;codecs=opus`
+    pub mime_type: String,
+    /// The encoded audio
+    pub data: Vec<u8>,
+    /// The length of the recording in seconds
+    pub duration: f64,
+    /// A `blob:` url of the recording that can be used as the `src` of an `audio` element or [`use_audio_player`]
+    pub url: String,
+}
+
+#[derive(Deserialize)]
+struct RawRecording {
+    mime_type: String,
+    data: String,
+    duration: f64,
+    url: String,
+}
+
+/// A handle to an audio recorder created with [`use_audio_recorder`]. Every getter subscribes to the value it reads.
+#[derive(Clone, Copy)]
+pub struct AudioRecorder {
+    eval: UseEval,
+    recording: Signal<bool>,
+    level: Signal<f64>,
+    recordings: Signal<Vec<Recording>>,
+    error: Signal<Option<String>>,
+}
+
+impl AudioRecorder {
+    /// Ask for access to the microphone and start recording
+    pub fn start(&self) {
+        command(self.eval, self.error, json!({ "type": "start" }));
+    }
+
+    /// Stop recording. The recording is added to [`AudioRecorder::recordings`] once it is encoded.
+    pub fn stop(&self) {
+        command(self.eval, self.error, json!({ "type": "stop" }));
+    }
+
+    /// Check if the recorder is recording
+    pub fn recording(&self) -> bool {
+        (self.recording)()
+    }
+
+    /// Get the loudness of the microphone between `0.0` and `1.0`. The level updates about 20 times a second while
+    /// recording.
+    pub fn level(&self) -> f64 {
+        (self.level)()
+    }
+
+    /// Get the finished recordings, oldest first
+    pub fn recordings(&self) -> Signal<Vec<Recording>> {
+        self.recordings
+    }
+
+    /// Get the last error, like a denied microphone permission or a platform that can't record audio
+    pub fn error(&self) -> Option<String> {
+        (self.error)()
+    }
+}
+
+/// Record audio from the microphone.
+///
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+/// use dioxus_html::media::use_audio_recorder;
+///
+/// fn app() -> Element {
+///     let recorder = use_audio_recorder();
+///     rsx! {
+///         if recorder.recording() {
+///             button { onclick: move |_| recorder.stop(), "Stop" }
+///             meter { value: recorder.level() }
+///         } else {
+///             button { onclick: move |_| recorder.start(), "Record" }
+///         }
+///         for recording in recorder.recordings().iter() {
+///             audio { controls: true, src: "{recording.url}" }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_audio_recorder() -> AudioRecorder {
+    let recorder = use_hook(|| {
+        let eval = UseEval::new(document().new_evaluator(RECORDER_SCRIPT.to_string()));
+        let recorder = AudioRecorder {
+            eval,
+            recording: Signal::new(false),
+            level: Signal::new(0.0),
+            recordings: Signal::new(Vec::new()),
+            error: Signal::new(None),
+        };
+        let AudioRecorder {
+            mut recording,
+            mut level,
+            mut recordings,
+            mut error,
+            ..
+        } = recorder;
+        listen(eval, recorder.error, move |message| {
+            if let Some(new_level) = message.get("level").and_then(Value::as_f64) {
+                level.set(new_level.min(1.0));
+            } else if let Some(state) = message.get("recording_state").and_then(Value::as_bool) {
+                recording.set(state);
+            } else if let Some(raw) = message.get("recording") {
+                match serde_json::from_value::<RawRecording>(raw.clone()) {
+                    Ok(raw) => match decode_base64(&raw.data) {
+                        Some(data) => recordings.write().push(Recording {
+                            mime_type: raw.mime_type,
+                            data,
+                            duration: raw.duration,
+                            url: raw.url,
+                        }),
+                        None => error.set(Some("The recording could not be decoded".to_string())),
+                    },
+                    Err(err) => error.set(Some(err.to_string())),
+                }
+            }
+        });
+        recorder
+    });
+    use_drop(move || command(recorder.eval, recorder.error, json!({ "type": "close" })));
+    recorder
+}
+
+/// Decode standard base64 with padding
+fn decode_base64(encoded: &str) -> Option<Vec<u8>> {
+    fn value(byte: u8) -> Option<u32> {
+        match byte {
+            b'A'..=b'Z' => Some((byte - b'A') as u32),
+            b'a'..=b'z' => Some((byte - b'a' + 26) as u32),
+            b'0'..=b'9' => Some((byte - b'0' + 52) as u32),
+            b'+' => Some(62),
+            b'/' => Some(63),
+            _ => None,
+        }
+    }
+
+    let encoded = encoded.as_bytes();
+    if !encoded.len().is_multiple_of(4) {
+        return None;
+    }
+    let mut decoded = Vec::with_capacity(encoded.len() / 4 * 3);
+    for chunk in encoded.chunks(4) {
+        let padding = chunk.iter().rev().take_while(|byte| **byte == b'=').count();
+        let mut bits = 0;
+        for byte in &chunk[..4 - padding] {
+            bits = bits << 6 | value(*byte)?;
+        }
+        bits <<= 6 * padding;
+        let bytes = bits.to_be_bytes();
+        decoded.extend_from_slice(&bytes[1..4 - padding]);
+    }
+    Some(decoded)
+}
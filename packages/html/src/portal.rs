@@ -0,0 +1,159 @@
+//! Render children into a different element of the page.
+//!
+//! A [`Portal`] moves its children under a target element, like the `body`, so modals, tooltips and toasts can
+//! escape ancestors with `overflow: hidden` or a `z-index`. The children are still part of the component tree of the
+//! portal: they read the context of the portal's parents, their signal subscriptions belong to their own components,
+//! and events bubble from the children to the parents of the portal instead of the parents of the target.
+
+use crate::document::{document, Document};
+use crate::{self as dioxus_elements};
+use dioxus_core::prelude::*;
+use dioxus_core_macro::{component, rsx, Props};
+use dioxus_hooks::{use_effect, use_reactive};
+use dioxus_signals::{GlobalSignal, Readable};
+use std::rc::Rc;
+
+/// The element a [`Portal`] renders its children into
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum PortalTarget {
+    /// The `body` of the page
+    #[default]
+    Body,
+    /// The element with an id
+    Id(String),
+    /// The first element that matches a CSS selector
+    Selector(String),
+}
+
+impl PortalTarget {
+    /// JavaScript that finds the target element
+    fn to_js(&self) -> String {
+        match self {
+            PortalTarget::Body => "document.body".to_string(),
+            PortalTarget::Id(id) => format!("document.getElementById({})", js_string(id)),
+            PortalTarget::Selector(selector) => {
+                format!("document.querySelector({})", js_string(selector))
+            }
+        }
+    }
+}
+
+impl From<&str> for PortalTarget {
+    fn from(id: &str) -> Self {
+        PortalTarget::Id(id.to_string())
+    }
+}
+
+impl From<String> for PortalTarget {
+    fn from(id: String) -> Self {
+        PortalTarget::Id(id)
+    }
+}
+
+fn js_string(value: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '<' => escaped.push_str("\\u003c"),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// Events that bubble in the browser. The renderers listen for them on the root of the app, so they are also
+/// listened for on portal targets outside of the root.
+const BUBBLING_EVENTS: &str = r#"["click", "dblclick", "contextmenu", "auxclick", "mousedown", "mouseup", "mousemove",
+"mouseover", "mouseout", "pointerdown", "pointerup", "pointermove", "pointerover", "pointerout", "pointercancel",
+"gotpointercapture", "lostpointercapture", "touchstart", "touchmove", "touchend", "touchcancel", "wheel", "keydown",
+"keyup", "keypress", "input", "change", "submit", "reset", "invalid", "select", "focusin", "focusout", "copy", "cut",
+"paste", "drag", "dragstart", "dragend", "dragenter", "dragleave", "dragover", "drop", "compositionstart",
+"compositionupdate", "compositionend", "animationstart", "animationend", "animationiteration", "transitionend"]"#;
+
+const MOUNT_SCRIPT: &str = r#"
+const content = document.querySelector('[data-dioxus-portal-content="{ID}"]');
+const target = {TARGET};
+if (content && target) {
+    target.appendChild(content);
+    if (!target.__dioxusPortalTarget) {
+        target.__dioxusPortalTarget = true;
+        const events = {EVENTS};
+        const interpreter = window.interpreter;
+        if (interpreter && interpreter.handler) {
+            // Desktop and liveview handle events with the interpreter
+            if (!interpreter.root.contains(target)) {
+                for (const name of events) target.addEventListener(name, interpreter.handler);
+            }
+        } else {
+            // The web renderer listens for portal targets on the window
+            window.dispatchEvent(new CustomEvent("dioxus-portal-target", { detail: { target, events } }));
+        }
+    }
+}
+"#;
+
+const UNMOUNT_SCRIPT: &str = r#"
+const content = document.querySelector('[data-dioxus-portal-content="{ID}"]');
+if (content) content.remove();
+"#;
+
+/// Render the children into a different element of the page, like the `body`.
+///
+/// The children are wrapped in a `div` with the `data-dioxus-portal-content` attribute that is moved to the end of
+/// the target after the portal is mounted. The portal leaves an empty `div` with `display: contents` in its place.
+/// When the target is not in the page yet, the children stay in the portal.
+///
+/// Renderers without a page, like SSR, render the children in place. The children are moved once the page hydrates.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_html::portal::Portal;
+///
+/// fn app() -> Element {
+///     let mut open = use_signal(|| false);
+///     rsx! {
+///         div { overflow: "hidden", height: "20px",
+///             button { onclick: move |_| open.set(true), "Open" }
+///             if open() {
+///                 Portal {
+///                     // Clicks in the dialog still bubble to the div above
+///                     div { class: "modal", role: "dialog",
+///                         button { onclick: move |_| open.set(false), "Close" }
+///                     }
+///                 }
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn Portal(
+    children: Element,
+    /// The element the children are rendered into. Strings are the id of the target. Defaults to the `body`.
+    #[props(into, default)]
+    target: PortalTarget,
+) -> Element {
+    let id = use_hook(|| current_scope_id().map(|id| id.0).unwrap_or_default());
+    let renderer: Rc<dyn Document> = use_hook(document);
+    use_effect(use_reactive!(|target| {
+        let script = MOUNT_SCRIPT
+            .replace("{ID}", &id.to_string())
+            .replace("{TARGET}", &target.to_js())
+            .replace("{EVENTS}", BUBBLING_EVENTS);
+        document().new_evaluator(script);
+    }));
+    use_drop(move || {
+        renderer.new_evaluator(UNMOUNT_SCRIPT.replace("{ID}", &id.to_string()));
+    });
+
+    rsx! {
+        div { "data-dioxus-portal": "{id}", style: "display: contents",
+            div { "data-dioxus-portal-content": "{id}", {children} }
+        }
+    }
+}
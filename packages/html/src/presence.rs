@@ -0,0 +1,258 @@
+//! Keep elements mounted until their exit animation finishes.
+
+use crate::{self as dioxus_elements};
+use dioxus_core::prelude::*;
+use dioxus_core::{DynamicNode, KeyedDiffEvent};
+use dioxus_core_macro::{component, rsx, Props};
+use dioxus_hooks::{time::sleep, use_signal};
+use dioxus_signals::{CopyValue, GlobalSignal, Readable, Writable};
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+/// Render the children while they are present, and keep them mounted after they stop being present until their
+/// exit animation or transition ends.
+///
+/// The children are wrapped in a `div` with the `class` and a `data-state` attribute that is `open` while the
+/// children are present and `closed` while they exit. While the children exit, the `exit_class` is added to the
+/// `div`. Start a CSS animation or transition with the exit class or the `closed` state. The children are removed
+/// when the first animation or transition in the `div` ends, or when the `timeout` passes if no animation runs.
+///
+/// Enter animations don't need any help: CSS animations on the `div` run when it is added.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_html::presence::AnimatePresence;
+///
+/// const STYLE: &str = r#"
+/// .toast { animation: fade-in 200ms; }
+/// .toast.leaving { animation: fade-out 200ms forwards; }
+/// @keyframes fade-in { from { opacity: 0; } }
+/// @keyframes fade-out { to { opacity: 0; } }
+/// "#;
+///
+/// fn app() -> Element {
+///     let mut open = use_signal(|| true);
+///     rsx! {
+///         style { {STYLE} }
+///         button { onclick: move |_| open.toggle(), "Toggle" }
+///         AnimatePresence { present: open(), class: "toast", exit_class: "leaving",
+///             "Saved!"
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn AnimatePresence(
+    /// If the children should be rendered. When this changes to `false`, the children exit.
+    present: bool,
+    children: Element,
+    /// The class of the `div` around the children
+    #[props(into)]
+    class: Option<String>,
+    /// The class added to the `div` while the children exit
+    #[props(into)]
+    exit_class: Option<String>,
+    /// How long to wait for the exit animation before removing the children anyway, or `None` to wait for the
+    /// animation forever
+    #[props(default = Some(Duration::from_secs(1)))]
+    timeout: Option<Duration>,
+) -> Element {
+    let mut was_present = use_hook(|| CopyValue::new(present));
+    // Each exit gets a number, so an animation or timeout of an earlier exit doesn't end the current exit
+    let mut exit = use_hook(|| CopyValue::new(0u64));
+    let mut exited = use_signal(|| None::<u64>);
+
+    if *was_present.peek() && !present {
+        *exit.write() += 1;
+        if let Some(timeout) = timeout {
+            let current = *exit.peek();
+            spawn(async move {
+                sleep(timeout).await;
+                exited.set(Some(current));
+            });
+        }
+    }
+    *was_present.write() = present;
+
+    let current = *exit.peek();
+    let exiting = !present && current > 0 && exited() != Some(current);
+    if !present && !exiting {
+        return rsx! {};
+    }
+
+    let class = wrapper_class(class, exit_class, exiting);
+    let mut finish = move || {
+        if exiting {
+            exited.set(Some(current));
+        }
+    };
+
+    rsx! {
+        div {
+            class,
+            "data-state": if exiting { "closed" } else { "open" },
+            onanimationend: move |_| finish(),
+            ontransitionend: move |_| finish(),
+            {children}
+        }
+    }
+}
+
+/// The class of the `div` around children, with the exit class while they exit
+fn wrapper_class(
+    class: Option<String>,
+    exit_class: Option<String>,
+    exiting: bool,
+) -> Option<String> {
+    match (class, exit_class.filter(|_| exiting)) {
+        (Some(class), Some(exit_class)) => Some(format!("{class} {exit_class}")),
+        (class, exit_class) => class.or(exit_class),
+    }
+}
+
+#[derive(Default)]
+struct TransitionGroupState {
+    /// The keyed children of the last render in order, including the children that are exiting
+    rendered: Vec<(String, VNode)>,
+    /// The children that are exiting with the number of their exit
+    exiting: HashMap<String, u64>,
+    /// The exits whose animation ended or timed out
+    finished: HashSet<u64>,
+    next_exit: u64,
+}
+
+/// Animate a keyed list. Every keyed child is wrapped in a `div` like the children of [`AnimatePresence`], and
+/// children that are removed from the list stay in place until their exit animation or transition ends.
+///
+/// The children should be a keyed `for` loop. The `div` around each child gets the key of the child, the `class`
+/// and a `data-state` attribute that is `open`, or `closed` while the child exits. Children that are added again
+/// while they exit stop exiting.
+///
+/// `onchange` receives the keyed diff events of the list, so moved children can be animated to their new position.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_html::presence::TransitionGroup;
+///
+/// fn app() -> Element {
+///     let mut items = use_signal(|| vec![1, 2, 3]);
+///     rsx! {
+///         button { onclick: move |_| { items.write().pop(); }, "Remove" }
+///         ul {
+///             TransitionGroup { class: "row", exit_class: "leaving",
+///                 for item in items() {
+///                     li { key: "{item}", "{item}" }
+///                 }
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn TransitionGroup(
+    children: Element,
+    /// The class of the `div` around each child
+    #[props(into)]
+    class: Option<String>,
+    /// The class added to the `div` around a child while it exits
+    #[props(into)]
+    exit_class: Option<String>,
+    /// How long to wait for the exit animation before removing a child anyway, or `None` to wait for the animation
+    /// forever
+    #[props(default = Some(Duration::from_secs(1)))]
+    timeout: Option<Duration>,
+    /// Called for every child the diff inserts, moves or removes
+    onchange: Option<EventHandler<KeyedDiffEvent>>,
+) -> Element {
+    let mut state = use_hook(|| CopyValue::new(TransitionGroupState::default()));
+    let mut exited = use_signal(|| 0u64);
+    let mut latest_onchange = use_hook(|| CopyValue::new(onchange));
+    latest_onchange.set(onchange);
+    use_keyed_diff(move |event| {
+        if let Some(onchange) = *latest_onchange.peek() {
+            onchange.call(event.clone());
+        }
+    });
+
+    let mut finish = move |exit: Option<u64>| {
+        if let Some(exit) = exit {
+            state.write().finished.insert(exit);
+            exited += 1;
+        }
+    };
+
+    // Rerender when an exit finishes
+    _ = exited();
+    let children = children?;
+    let current = keyed_children(&children);
+    let items = {
+        let mut state = state.write();
+        let state = &mut *state;
+        let previous = std::mem::take(&mut state.rendered);
+        let finished = std::mem::take(&mut state.finished);
+        let mut exiting = HashMap::new();
+
+        let mut items = current
+            .into_iter()
+            .map(|(key, node)| (key, node, None))
+            .collect::<Vec<_>>();
+        // Children that left the list stay after the child they followed in the last render
+        let mut insert_at = 0;
+        for (key, node) in previous {
+            if let Some(position) = items.iter().position(|(current, ..)| *current == key) {
+                insert_at = position + 1;
+                continue;
+            }
+            let exit = match state.exiting.get(&key) {
+                Some(exit) if finished.contains(exit) => continue,
+                Some(exit) => *exit,
+                None => {
+                    state.next_exit += 1;
+                    let exit = state.next_exit;
+                    if let Some(timeout) = timeout {
+                        spawn(async move {
+                            sleep(timeout).await;
+                            finish(Some(exit));
+                        });
+                    }
+                    exit
+                }
+            };
+            exiting.insert(key.clone(), exit);
+            items.insert(insert_at, (key, node, Some(exit)));
+            insert_at += 1;
+        }
+
+        state.exiting = exiting;
+        state.rendered = items
+            .iter()
+            .map(|(key, node, _)| (key.clone(), node.clone()))
+            .collect();
+        items
+    };
+
+    rsx! {
+        for (key, node, exit) in items {
+            div {
+                key: "{key}",
+                class: wrapper_class(class.clone(), exit_class.clone(), exit.is_some()),
+                "data-state": if exit.is_some() { "closed" } else { "open" },
+                onanimationend: move |_| finish(exit),
+                ontransitionend: move |_| finish(exit),
+                {node}
+            }
+        }
+    }
+}
+
+/// The keyed children of the lists at the roots of `children`
+fn keyed_children(children: &VNode) -> Vec<(String, VNode)> {
+    (0..children.template.roots.len())
+        .filter_map(|root| match children.dynamic_root(root) {
+            Some(DynamicNode::Fragment(nodes)) => Some(nodes),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|node| Some((node.key.clone()?, node.clone())))
+        .collect()
+}
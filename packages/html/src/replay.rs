@@ -0,0 +1,203 @@
+//! Record the events a user triggers and replay them against a fresh [`VirtualDom`].
+//!
+//! Recordings are stored as [JSON lines](https://jsonlines.org), one [`RecordedEvent`] per line. Each event is written
+//! as soon as it is recorded, so the recording is complete even if the app crashes.
+//!
+//! The desktop and liveview renderers record every event into the file in the `DIOXUS_RECORD_EVENTS` environment
+//! variable in debug builds:
+//!
+//! ```sh
+//! DIOXUS_RECORD_EVENTS=events.jsonl cargo run
+//! ```
+//!
+//! The recording can then be replayed in a test to reproduce a bug:
+//!
+//! ```rust, no_run
+//! # use dioxus::prelude::*;
+//! # use dioxus_html::replay::EventRecording;
+//! # fn app() -> Element { rsx! {} }
+//! dioxus_html::set_event_converter(Box::new(dioxus_html::SerializedHtmlEventConverter));
+//!
+//! let recording = EventRecording::load("events.jsonl").unwrap();
+//! let mut dom = VirtualDom::new(app);
+//! dom.rebuild_in_place();
+//! recording.replay(&mut dom, &mut dioxus_core::NoOpMutations);
+//! ```
+
+use crate::HtmlEvent;
+use dioxus_core::{prelude::Event, VirtualDom, WriteMutations};
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+/// The environment variable with the path of the file renderers record events into
+pub const RECORD_EVENTS_ENV: &str = "DIOXUS_RECORD_EVENTS";
+
+/// An event that was recorded
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RecordedEvent {
+    /// The number of milliseconds between the start of the recording and the event
+    pub at_ms: u64,
+    /// The event
+    pub event: HtmlEvent,
+}
+
+impl RecordedEvent {
+    /// The time between the start of the recording and the event
+    pub fn at(&self) -> Duration {
+        Duration::from_millis(self.at_ms)
+    }
+}
+
+/// Records events into a writer as they happen
+pub struct EventRecorder {
+    started: Instant,
+    writer: Box<dyn Write>,
+}
+
+impl EventRecorder {
+    /// Record events into a writer
+    pub fn new(writer: impl Write + 'static) -> Self {
+        Self {
+            started: Instant::now(),
+            writer: Box::new(writer),
+        }
+    }
+
+    /// Record events into a file. The file is replaced if it already exists.
+    pub fn create(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        Ok(Self::new(std::fs::File::create(path)?))
+    }
+
+    /// Record events into the file in the `DIOXUS_RECORD_EVENTS` environment variable. Returns `None` if the variable
+    /// is not set or the file can't be created.
+    ///
+    /// The file is created once per process. Every recorder created with this function afterwards, like the one of
+    /// each liveview session or desktop window, shares the file and the start of the recording.
+    pub fn from_env() -> Option<Self> {
+        static RECORDING: OnceLock<Option<(Instant, SharedFile)>> = OnceLock::new();
+        let (started, file) = RECORDING
+            .get_or_init(|| {
+                let path = std::env::var_os(RECORD_EVENTS_ENV)?;
+                match std::fs::File::create(&path) {
+                    Ok(file) => {
+                        tracing::info!("Recording events into {}", path.to_string_lossy());
+                        Some((Instant::now(), SharedFile(Arc::new(Mutex::new(file)))))
+                    }
+                    Err(err) => {
+                        tracing::error!(
+                            "Failed to create the event recording {}: {err}",
+                            path.to_string_lossy()
+                        );
+                        None
+                    }
+                }
+            })
+            .clone()?;
+        Some(Self {
+            started,
+            writer: Box::new(file),
+        })
+    }
+
+    /// Record an event. Errors are logged instead of returned so recording never interrupts the app.
+    pub fn record(&mut self, event: &HtmlEvent) {
+        let recorded = RecordedEvent {
+            at_ms: self.started.elapsed().as_millis() as u64,
+            event: event.clone(),
+        };
+        // Write the line at once so recorders that share a file don't interleave their events
+        let result = serde_json::to_string(&recorded)
+            .map_err(std::io::Error::from)
+            .and_then(|line| self.writer.write_all(format!("{line}\n").as_bytes()))
+            .and_then(|_| self.writer.flush());
+        if let Err(err) = result {
+            tracing::error!("Failed to record {} event: {err}", event.name);
+        }
+    }
+}
+
+/// A file that is shared by several recorders
+#[derive(Clone)]
+struct SharedFile(Arc<Mutex<std::fs::File>>);
+
+impl Write for SharedFile {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        self.0.lock().unwrap().write_all(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.lock().unwrap().flush()
+    }
+}
+
+/// Events that were recorded with an [`EventRecorder`]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EventRecording {
+    /// The events in the order they were recorded
+    pub events: Vec<RecordedEvent>,
+}
+
+impl EventRecording {
+    /// Load a recording from a file
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        Self::from_reader(std::fs::File::open(path)?)
+    }
+
+    /// Read a recording with one event per line. Empty lines are skipped.
+    pub fn from_reader(reader: impl std::io::Read) -> std::io::Result<Self> {
+        let mut events = Vec::new();
+        for line in BufReader::new(reader).lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            events.push(serde_json::from_str(&line)?);
+        }
+        Ok(Self { events })
+    }
+
+    /// Write the recording with one event per line
+    pub fn write_to(&self, mut writer: impl Write) -> std::io::Result<()> {
+        for event in &self.events {
+            writeln!(writer, "{}", serde_json::to_string(event)?)?;
+        }
+        writer.flush()
+    }
+
+    /// Save the recording to a file
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        self.write_to(std::io::BufWriter::new(std::fs::File::create(path)?))
+    }
+
+    /// Send every event to a VirtualDom and render the changes after each event. The time between events is not
+    /// waited for.
+    ///
+    /// The VirtualDom must have rendered the same tree it rendered when the events were recorded, so the events
+    /// target the same elements. The data of the events is converted with the global event converter, so set it to
+    /// [`SerializedHtmlEventConverter`](crate::SerializedHtmlEventConverter) before replaying outside of a renderer.
+    pub fn replay(&self, dom: &mut VirtualDom, to: &mut impl WriteMutations) {
+        for recorded in &self.events {
+            replay_event(dom, recorded.event.clone());
+            dom.render_immediate(to);
+        }
+    }
+}
+
+/// Send one recorded event to a VirtualDom without rendering the changes
+pub fn replay_event(dom: &VirtualDom, event: HtmlEvent) {
+    let HtmlEvent {
+        element,
+        name,
+        bubbles,
+        data,
+    } = event;
+    let event = Event::new(data.into_any(), bubbles);
+    dom.runtime().handle_event(&name, event, element);
+}
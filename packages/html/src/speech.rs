@@ -0,0 +1,720 @@
+//! Speak text and transcribe speech for voice enabled apps.
+//!
+//! - [`use_speech_synthesis`] reads text aloud and exposes the available [`Voice`]s and the speaking state as signals.
+//! - [`use_speech_recognition`] listens to the microphone and exposes the transcript as a signal.
+//!
+//! On the web and in webviews both hooks use the [Web Speech API](https://developer.mozilla.org/en-US/docs/Web/API/Web_Speech_API).
+//! Platforms without the API, or apps that want to use the speech services of the operating system, can provide a
+//! different [`SpeechBackend`] with [`provide_speech_backend`].
+
+use crate::document::{document, UseEval};
+use dioxus_core::prelude::*;
+use dioxus_signals::{CopyValue, Readable, Signal, Writable};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::json;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::rc::Rc;
+
+/// A voice that [`use_speech_synthesis`] can speak with
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Voice {
+    /// The name of the voice, like `Google UK English Female`
+    pub name: String,
+    /// The BCP 47 language tag of the voice, like `en-GB`
+    pub lang: String,
+    /// If the voice is synthesized on the device instead of by a remote service
+    pub local: bool,
+    /// If this is the default voice of the platform
+    pub default: bool,
+}
+
+/// Text to speak with [`SpeechSynthesis::speak_utterance`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Utterance {
+    /// The text to speak
+    pub text: String,
+    /// The [`Voice::name`] of the voice to speak with, or `None` for the default voice
+    pub voice: Option<String>,
+    /// The BCP 47 language tag of the text, or `None` for the language of the document
+    pub lang: Option<String>,
+    /// The speed between `0.1` and `10.0`, where `1.0` is the normal speed
+    pub rate: f32,
+    /// The pitch between `0.0` and `2.0`, where `1.0` is the normal pitch
+    pub pitch: f32,
+    /// The volume between `0.0` and `1.0`
+    pub volume: f32,
+}
+
+impl Utterance {
+    /// Speak a text with the default voice, speed, pitch and volume
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            voice: None,
+            lang: None,
+            rate: 1.0,
+            pitch: 1.0,
+            volume: 1.0,
+        }
+    }
+
+    /// Speak with the voice with a [`Voice::name`]
+    pub fn voice(mut self, name: impl Into<String>) -> Self {
+        self.voice = Some(name.into());
+        self
+    }
+
+    /// Set the BCP 47 language tag of the text
+    pub fn lang(mut self, lang: impl Into<String>) -> Self {
+        self.lang = Some(lang.into());
+        self
+    }
+
+    /// Set the speed between `0.1` and `10.0`
+    pub fn rate(mut self, rate: f32) -> Self {
+        self.rate = rate.clamp(0.1, 10.0);
+        self
+    }
+
+    /// Set the pitch between `0.0` and `2.0`
+    pub fn pitch(mut self, pitch: f32) -> Self {
+        self.pitch = pitch.clamp(0.0, 2.0);
+        self
+    }
+
+    /// Set the volume between `0.0` and `1.0`
+    pub fn volume(mut self, volume: f32) -> Self {
+        self.volume = volume.clamp(0.0, 1.0);
+        self
+    }
+}
+
+/// How [`use_speech_recognition`] listens
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecognitionOptions {
+    /// The BCP 47 language tag of the speech, or `None` for the language of the document
+    pub lang: Option<String>,
+    /// Keep listening after the first pause until [`SpeechRecognition::stop`] is called
+    pub continuous: bool,
+    /// Report the words that were heard so far in [`SpeechRecognition::interim`] before they are final
+    pub interim_results: bool,
+}
+
+impl Default for RecognitionOptions {
+    fn default() -> Self {
+        Self {
+            lang: None,
+            continuous: false,
+            interim_results: true,
+        }
+    }
+}
+
+/// A command sent to the speech synthesis of a [`SpeechBackend`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum SynthesisCommand {
+    /// Add an utterance to the queue
+    Speak(Utterance),
+    /// Pause speaking
+    Pause,
+    /// Resume speaking after a pause
+    Resume,
+    /// Stop speaking and clear the queue
+    Cancel,
+}
+
+/// An update from the speech synthesis of a [`SpeechBackend`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum SynthesisEvent {
+    /// The voices that are available changed
+    Voices {
+        /// Every available voice
+        voices: Vec<Voice>,
+    },
+    /// The synthesis started or stopped speaking, or was paused or resumed
+    State {
+        /// If an utterance is being spoken or waiting in the queue
+        speaking: bool,
+        /// If speaking is paused
+        paused: bool,
+    },
+    /// The synthesis reached a word in the current utterance
+    Boundary {
+        /// The index of the first character of the word in [`Utterance::text`]
+        char_index: usize,
+    },
+    /// The synthesis failed
+    Error {
+        /// A description of the error
+        message: String,
+    },
+}
+
+/// A command sent to the speech recognition of a [`SpeechBackend`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum RecognitionCommand {
+    /// Start listening
+    Start(RecognitionOptions),
+    /// Stop listening and report the final result for the speech that was heard
+    Stop,
+    /// Stop listening without reporting a result
+    Abort,
+}
+
+/// An update from the speech recognition of a [`SpeechBackend`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum RecognitionEvent {
+    /// The recognition started or stopped listening
+    State {
+        /// If the recognition is listening
+        listening: bool,
+    },
+    /// The recognition heard some speech
+    Result {
+        /// The words that were heard
+        transcript: String,
+        /// If the words are final. Interim results are replaced by the next result.
+        is_final: bool,
+        /// How confident the recognition is in the words between `0.0` and `1.0`
+        confidence: f32,
+    },
+    /// The recognition failed, for example because the microphone permission was denied
+    Error {
+        /// A description of the error
+        message: String,
+    },
+}
+
+/// A connection to the speech synthesis or recognition of a [`SpeechBackend`]
+pub trait SpeechConnection<C, E> {
+    /// Send a command to the backend
+    fn send(&self, command: C) -> Result<(), String>;
+
+    /// Wait for the next update from the backend, or `None` once the connection is closed
+    fn recv(&self) -> Pin<Box<dyn Future<Output = Option<E>>>>;
+
+    /// Stop speaking or listening and close the connection
+    fn close(&self);
+}
+
+/// The speech services used by [`use_speech_synthesis`] and [`use_speech_recognition`]. The hooks use the backend
+/// provided with [`provide_speech_backend`], or a [`ScriptSpeechBackend`] if no backend was provided.
+pub trait SpeechBackend {
+    /// Open a connection to the speech synthesis
+    fn synthesis(&self) -> Rc<dyn SpeechConnection<SynthesisCommand, SynthesisEvent>>;
+
+    /// Open a connection to the speech recognition
+    fn recognition(&self) -> Rc<dyn SpeechConnection<RecognitionCommand, RecognitionEvent>>;
+}
+
+const SYNTHESIS_SCRIPT: &str = r#"
+const synth = window.speechSynthesis;
+const send = (event) => dioxus.send(event);
+// The number of utterances that were queued and didn't finish yet
+let queued = 0;
+const state = () => send({ type: "state", speaking: queued > 0, paused: !!synth && synth.paused });
+const voices = () => send({
+    type: "voices",
+    voices: synth.getVoices().map((voice) => ({
+        name: voice.name,
+        lang: voice.lang,
+        local: voice.localService,
+        default: voice.default,
+    })),
+});
+if (synth) {
+    voices();
+    synth.addEventListener("voiceschanged", voices);
+}
+
+while (true) {
+    const command = await dioxus.recv();
+    if (command.type === "close") {
+        if (synth) synth.cancel();
+        return;
+    }
+    if (!synth) {
+        send({ type: "error", message: "Speech synthesis is not supported on this platform" });
+        continue;
+    }
+    switch (command.type) {
+        case "speak": {
+            const utterance = new SpeechSynthesisUtterance(command.text);
+            if (command.lang) utterance.lang = command.lang;
+            if (command.voice) utterance.voice = synth.getVoices().find((voice) => voice.name === command.voice) || null;
+            utterance.rate = command.rate;
+            utterance.pitch = command.pitch;
+            utterance.volume = command.volume;
+            const finish = () => {
+                queued = Math.max(queued - 1, 0);
+                state();
+            };
+            utterance.onend = finish;
+            utterance.onerror = (event) => {
+                // Cancelling the queue interrupts the utterances, which isn't an error
+                if (event.error !== "canceled" && event.error !== "interrupted") {
+                    send({ type: "error", message: event.error });
+                }
+                finish();
+            };
+            utterance.onpause = state;
+            utterance.onresume = state;
+            utterance.onboundary = (event) => send({ type: "boundary", char_index: event.charIndex });
+            queued += 1;
+            synth.speak(utterance);
+            state();
+            break;
+        }
+        case "pause":
+            synth.pause();
+            state();
+            break;
+        case "resume":
+            synth.resume();
+            state();
+            break;
+        case "cancel":
+            queued = 0;
+            synth.cancel();
+            state();
+            break;
+    }
+}
+"#;
+
+const RECOGNITION_SCRIPT: &str = r#"
+const Recognition = window.SpeechRecognition || window.webkitSpeechRecognition;
+const send = (event) => dioxus.send(event);
+let recognition = null;
+
+while (true) {
+    const command = await dioxus.recv();
+    if (command.type === "close") {
+        if (recognition) recognition.abort();
+        return;
+    }
+    if (!Recognition) {
+        send({ type: "error", message: "Speech recognition is not supported on this platform" });
+        continue;
+    }
+    try {
+        switch (command.type) {
+            case "start": {
+                if (recognition) recognition.abort();
+                recognition = new Recognition();
+                if (command.lang) recognition.lang = command.lang;
+                recognition.continuous = command.continuous;
+                recognition.interimResults = command.interim_results;
+                recognition.onstart = () => send({ type: "state", listening: true });
+                recognition.onend = () => send({ type: "state", listening: false });
+                recognition.onerror = (event) => send({ type: "error", message: event.error });
+                recognition.onresult = (event) => {
+                    // Final results are sent one by one, the interim results are combined into one
+                    let interim = "";
+                    for (let i = event.resultIndex; i < event.results.length; i++) {
+                        const result = event.results[i];
+                        const { transcript, confidence } = result[0];
+                        if (result.isFinal) {
+                            send({ type: "result", transcript, is_final: true, confidence });
+                        } else {
+                            interim += transcript;
+                        }
+                    }
+                    send({ type: "result", transcript: interim, is_final: false, confidence: 0 });
+                };
+                recognition.start();
+                break;
+            }
+            case "stop":
+                if (recognition) recognition.stop();
+                break;
+            case "abort":
+                if (recognition) recognition.abort();
+                break;
+        }
+    } catch (error) {
+        send({ type: "error", message: String(error) });
+    }
+}
+"#;
+
+/// A [`SpeechBackend`] that uses the Web Speech API of the browser or webview
+#[derive(Clone, Copy, Default)]
+pub struct ScriptSpeechBackend;
+
+impl SpeechBackend for ScriptSpeechBackend {
+    fn synthesis(&self) -> Rc<dyn SpeechConnection<SynthesisCommand, SynthesisEvent>> {
+        Rc::new(ScriptConnection::new(SYNTHESIS_SCRIPT))
+    }
+
+    fn recognition(&self) -> Rc<dyn SpeechConnection<RecognitionCommand, RecognitionEvent>> {
+        Rc::new(ScriptConnection::new(RECOGNITION_SCRIPT))
+    }
+}
+
+struct ScriptConnection<C, E> {
+    eval: UseEval,
+    phantom: PhantomData<fn(C) -> E>,
+}
+
+impl<C, E> ScriptConnection<C, E> {
+    fn new(script: &str) -> Self {
+        Self {
+            eval: UseEval::new(document().new_evaluator(script.to_string())),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<C: Serialize, E: DeserializeOwned + 'static> SpeechConnection<C, E>
+    for ScriptConnection<C, E>
+{
+    fn send(&self, command: C) -> Result<(), String> {
+        let command = serde_json::to_value(command).map_err(|err| err.to_string())?;
+        self.eval.send(command).map_err(|err| err.to_string())
+    }
+
+    fn recv(&self) -> Pin<Box<dyn Future<Output = Option<E>>>> {
+        let mut eval = self.eval;
+        Box::pin(async move {
+            loop {
+                let message = eval.recv().await.ok()?;
+                if let Ok(event) = serde_json::from_value(message) {
+                    return Some(event);
+                }
+            }
+        })
+    }
+
+    fn close(&self) {
+        _ = self.eval.send(json!({ "type": "close" }));
+    }
+}
+
+/// Use a different [`SpeechBackend`] for the speech hooks in the app. Call this before using the hooks, for example
+/// in a `use_hook` in the root component.
+pub fn provide_speech_backend(backend: impl SpeechBackend + 'static) {
+    provide_root_context(Rc::new(backend) as Rc<dyn SpeechBackend>);
+}
+
+fn speech_backend() -> Rc<dyn SpeechBackend> {
+    try_consume_context::<Rc<dyn SpeechBackend>>().unwrap_or_else(|| Rc::new(ScriptSpeechBackend))
+}
+
+/// Call a handler with every update from a connection until it is closed
+fn listen<C: 'static, E: 'static>(
+    connection: Rc<dyn SpeechConnection<C, E>>,
+    mut handle: impl FnMut(E) + 'static,
+) {
+    spawn(async move {
+        while let Some(event) = connection.recv().await {
+            handle(event);
+        }
+    });
+}
+
+/// Send a command to a connection and report any errors
+fn command<C: 'static, E: 'static>(
+    connection: &CopyValue<Rc<dyn SpeechConnection<C, E>>>,
+    mut error: Signal<Option<String>>,
+    command: C,
+) {
+    if let Err(err) = connection.read().send(command) {
+        error.set(Some(err));
+    }
+}
+
+/// A handle to the speech synthesis created with [`use_speech_synthesis`]. Every getter subscribes to the value it
+/// reads.
+pub struct SpeechSynthesis {
+    connection: CopyValue<Rc<dyn SpeechConnection<SynthesisCommand, SynthesisEvent>>>,
+    voices: Signal<Vec<Voice>>,
+    speaking: Signal<bool>,
+    paused: Signal<bool>,
+    char_index: Signal<Option<usize>>,
+    error: Signal<Option<String>>,
+}
+
+impl Clone for SpeechSynthesis {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for SpeechSynthesis {}
+
+impl SpeechSynthesis {
+    /// Speak a text with the default voice. The text is queued after any text that is still being spoken.
+    pub fn speak(&self, text: impl Into<String>) {
+        self.speak_utterance(Utterance::new(text));
+    }
+
+    /// Speak an utterance with a custom voice, speed, pitch or volume
+    pub fn speak_utterance(&self, utterance: Utterance) {
+        command(
+            &self.connection,
+            self.error,
+            SynthesisCommand::Speak(utterance),
+        );
+    }
+
+    /// Pause speaking
+    pub fn pause(&self) {
+        command(&self.connection, self.error, SynthesisCommand::Pause);
+    }
+
+    /// Resume speaking after a pause
+    pub fn resume(&self) {
+        command(&self.connection, self.error, SynthesisCommand::Resume);
+    }
+
+    /// Stop speaking and drop any queued text
+    pub fn cancel(&self) {
+        command(&self.connection, self.error, SynthesisCommand::Cancel);
+    }
+
+    /// Get the voices of the platform. The list may be empty until the platform has loaded its voices.
+    pub fn voices(&self) -> Vec<Voice> {
+        self.voices.read().clone()
+    }
+
+    /// Check if text is being spoken or waiting to be spoken
+    pub fn speaking(&self) -> bool {
+        (self.speaking)()
+    }
+
+    /// Check if speaking is paused
+    pub fn paused(&self) -> bool {
+        (self.paused)()
+    }
+
+    /// Get the index of the first character of the word that is being spoken, or `None` if nothing is spoken. Use it
+    /// to highlight the word as it is read.
+    pub fn char_index(&self) -> Option<usize> {
+        (self.char_index)()
+    }
+
+    /// Get the last error, like a platform without speech synthesis
+    pub fn error(&self) -> Option<String> {
+        (self.error)()
+    }
+}
+
+/// Read text aloud.
+///
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+/// use dioxus_html::speech::{use_speech_synthesis, Utterance};
+///
+/// fn app() -> Element {
+///     let speech = use_speech_synthesis();
+///     let text = "Dioxus can read this sentence aloud";
+///     rsx! {
+///         if speech.speaking() {
+///             button { onclick: move |_| speech.cancel(), "Stop" }
+///         } else {
+///             button { onclick: move |_| speech.speak(text), "Read aloud" }
+///         }
+///         select {
+///             onchange: move |event| speech.speak_utterance(Utterance::new(text).voice(event.value())),
+///             for voice in speech.voices() {
+///                 option { value: "{voice.name}", "{voice.name} ({voice.lang})" }
+///             }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_speech_synthesis() -> SpeechSynthesis {
+    let synthesis = use_hook(|| {
+        let connection = speech_backend().synthesis();
+        let synthesis = SpeechSynthesis {
+            connection: CopyValue::new(connection.clone()),
+            voices: Signal::new(Vec::new()),
+            speaking: Signal::new(false),
+            paused: Signal::new(false),
+            char_index: Signal::new(None),
+            error: Signal::new(None),
+        };
+        let SpeechSynthesis {
+            mut voices,
+            mut speaking,
+            mut paused,
+            mut char_index,
+            mut error,
+            ..
+        } = synthesis;
+        listen(connection, move |event| match event {
+            SynthesisEvent::Voices { voices: new_voices } => voices.set(new_voices),
+            SynthesisEvent::State {
+                speaking: new_speaking,
+                paused: new_paused,
+            } => {
+                speaking.set(new_speaking);
+                paused.set(new_paused);
+                if !new_speaking {
+                    char_index.set(None);
+                }
+            }
+            SynthesisEvent::Boundary { char_index: index } => char_index.set(Some(index)),
+            SynthesisEvent::Error { message } => error.set(Some(message)),
+        });
+        synthesis
+    });
+    use_drop(move || synthesis.connection.read().close());
+    synthesis
+}
+
+/// A handle to the speech recognition created with [`use_speech_recognition`]. Every getter subscribes to the value
+/// it reads.
+pub struct SpeechRecognition {
+    connection: CopyValue<Rc<dyn SpeechConnection<RecognitionCommand, RecognitionEvent>>>,
+    listening: Signal<bool>,
+    transcript: Signal<String>,
+    interim: Signal<String>,
+    confidence: Signal<Option<f32>>,
+    error: Signal<Option<String>>,
+}
+
+impl Clone for SpeechRecognition {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for SpeechRecognition {}
+
+impl SpeechRecognition {
+    /// Ask for access to the microphone and start listening with the default [`RecognitionOptions`]
+    pub fn start(&self) {
+        self.start_with(RecognitionOptions::default());
+    }
+
+    /// Ask for access to the microphone and start listening
+    pub fn start_with(&self, options: RecognitionOptions) {
+        let mut error = self.error;
+        error.set(None);
+        command(&self.connection, error, RecognitionCommand::Start(options));
+    }
+
+    /// Stop listening. The speech that was heard so far is added to the transcript.
+    pub fn stop(&self) {
+        command(&self.connection, self.error, RecognitionCommand::Stop);
+    }
+
+    /// Stop listening and drop the speech that isn't final yet
+    pub fn abort(&self) {
+        let mut interim = self.interim;
+        interim.set(String::new());
+        command(&self.connection, self.error, RecognitionCommand::Abort);
+    }
+
+    /// Clear the transcript
+    pub fn clear(&self) {
+        let (mut transcript, mut interim, mut confidence) =
+            (self.transcript, self.interim, self.confidence);
+        transcript.set(String::new());
+        interim.set(String::new());
+        confidence.set(None);
+    }
+
+    /// Check if the recognition is listening
+    pub fn listening(&self) -> bool {
+        (self.listening)()
+    }
+
+    /// Get the final transcript of everything that was heard since the last [`SpeechRecognition::clear`]
+    pub fn transcript(&self) -> String {
+        self.transcript.read().clone()
+    }
+
+    /// Get the words that were heard after the final transcript. They can still change as the recognition hears more.
+    pub fn interim(&self) -> String {
+        self.interim.read().clone()
+    }
+
+    /// Get how confident the recognition was in the last final words between `0.0` and `1.0`
+    pub fn confidence(&self) -> Option<f32> {
+        (self.confidence)()
+    }
+
+    /// Get the last error, like a denied microphone permission or a platform without speech recognition
+    pub fn error(&self) -> Option<String> {
+        (self.error)()
+    }
+}
+
+/// Transcribe speech from the microphone.
+///
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+/// use dioxus_html::speech::use_speech_recognition;
+///
+/// fn app() -> Element {
+///     let recognition = use_speech_recognition();
+///     rsx! {
+///         if recognition.listening() {
+///             button { onclick: move |_| recognition.stop(), "Stop" }
+///         } else {
+///             button { onclick: move |_| recognition.start(), "Dictate" }
+///         }
+///         p { "{recognition.transcript()} " em { "{recognition.interim()}" } }
+///     }
+/// }
+/// ```
+pub fn use_speech_recognition() -> SpeechRecognition {
+    let recognition = use_hook(|| {
+        let connection = speech_backend().recognition();
+        let recognition = SpeechRecognition {
+            connection: CopyValue::new(connection.clone()),
+            listening: Signal::new(false),
+            transcript: Signal::new(String::new()),
+            interim: Signal::new(String::new()),
+            confidence: Signal::new(None),
+            error: Signal::new(None),
+        };
+        let SpeechRecognition {
+            mut listening,
+            mut transcript,
+            mut interim,
+            mut confidence,
+            mut error,
+            ..
+        } = recognition;
+        listen(connection, move |event| match event {
+            RecognitionEvent::State {
+                listening: new_listening,
+            } => listening.set(new_listening),
+            RecognitionEvent::Result {
+                transcript: text,
+                is_final: true,
+                confidence: new_confidence,
+            } => {
+                let mut transcript = transcript.write();
+                let text = text.trim();
+                if !transcript.is_empty() && !text.is_empty() {
+                    transcript.push(' ');
+                }
+                transcript.push_str(text);
+                interim.set(String::new());
+                confidence.set(Some(new_confidence));
+            }
+            RecognitionEvent::Result { transcript, .. } => {
+                interim.set(transcript.trim().to_string())
+            }
+            RecognitionEvent::Error { message } => error.set(Some(message)),
+        });
+        recognition
+    });
+    use_drop(move || recognition.connection.read().close());
+    recognition
+}
@@ -0,0 +1,307 @@
+//! Show short notifications on top of the app.
+//!
+//! Wrap your app in a [`Toaster`] and call [`use_toast`] in any component below it to show a [`Toast`]. The toaster
+//! queues toasts that don't fit on the screen, dismisses them after a timeout and announces them to screen readers.
+
+use crate::portal::{Portal, PortalTarget};
+use crate::{self as dioxus_elements};
+use dioxus_core::prelude::*;
+use dioxus_core_macro::{component, rsx, Props};
+use dioxus_hooks::{time::sleep, use_context, use_context_provider};
+use dioxus_signals::{CopyValue, GlobalSignal, Readable, Signal, Writable};
+use std::cell::RefCell;
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+use std::time::Duration;
+
+/// The style of a [`Toast`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ToastKind {
+    /// A neutral message
+    #[default]
+    Info,
+    /// An operation finished successfully
+    Success,
+    /// Something needs the attention of the user
+    Warning,
+    /// An operation failed. Errors are announced to screen readers immediately instead of waiting for the user to
+    /// be idle.
+    Error,
+}
+
+impl Display for ToastKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ToastKind::Info => write!(f, "info"),
+            ToastKind::Success => write!(f, "success"),
+            ToastKind::Warning => write!(f, "warning"),
+            ToastKind::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// A button in a [`Toast`] that runs a callback and dismisses the toast
+#[derive(Clone)]
+struct ToastAction {
+    label: String,
+    callback: Rc<RefCell<dyn FnMut()>>,
+}
+
+/// A notification shown by a [`Toaster`]
+///
+/// ```rust
+/// # use dioxus_html::toast::{Toast, ToastKind};
+/// # use std::time::Duration;
+/// let toast = Toast::new("The file was deleted")
+///     .kind(ToastKind::Success)
+///     .duration(Some(Duration::from_secs(10)))
+///     .action("Undo", || println!("restoring the file"));
+/// ```
+#[derive(Clone)]
+pub struct Toast {
+    message: String,
+    kind: ToastKind,
+    duration: Option<Option<Duration>>,
+    action: Option<ToastAction>,
+}
+
+impl Toast {
+    /// Create a new toast with a message
+    pub fn new(message: impl ToString) -> Self {
+        Self {
+            message: message.to_string(),
+            kind: ToastKind::default(),
+            duration: None,
+            action: None,
+        }
+    }
+
+    /// Set the kind of the toast. This is [`ToastKind::Info`] by default.
+    pub fn kind(mut self, kind: ToastKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Set how long the toast is visible before it is dismissed, or `None` to keep it until the user dismisses it.
+    /// The timeout starts when the toast is shown, not when it is queued. This is the default duration of the
+    /// [`Toaster`] if it is not set.
+    pub fn duration(mut self, duration: Option<Duration>) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Add a button to the toast that runs a callback and dismisses the toast
+    pub fn action(mut self, label: impl ToString, callback: impl FnMut() + 'static) -> Self {
+        self.action = Some(ToastAction {
+            label: label.to_string(),
+            callback: Rc::new(RefCell::new(callback)),
+        });
+        self
+    }
+}
+
+/// The id of a toast that was shown with [`ToastContext::show`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ToastId(usize);
+
+/// The toasts of a [`Toaster`]. Get it with [`use_toast`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct ToastContext {
+    toasts: Signal<Vec<(ToastId, Toast)>>,
+    next_id: CopyValue<usize>,
+    max_visible: usize,
+    default_duration: Option<Duration>,
+}
+
+impl ToastContext {
+    /// Show a toast. If the toaster already shows as many toasts as it can, the toast is queued until another toast
+    /// is dismissed.
+    pub fn show(&self, toast: Toast) -> ToastId {
+        let mut next_id = self.next_id;
+        let id = ToastId(*next_id.peek());
+        *next_id.write() += 1;
+        let mut toasts = self.toasts;
+        toasts.write().push((id, toast));
+        id
+    }
+
+    /// Dismiss a toast, whether it is visible or queued
+    pub fn dismiss(&self, id: ToastId) {
+        let mut toasts = self.toasts;
+        if toasts.peek().iter().any(|(toast, _)| *toast == id) {
+            toasts.write().retain(|(toast, _)| *toast != id);
+        }
+    }
+
+    /// Dismiss every toast
+    pub fn dismiss_all(&self) {
+        let mut toasts = self.toasts;
+        toasts.write().clear();
+    }
+
+    /// The ids of the toasts that are visible, from oldest to newest
+    pub fn visible(&self) -> Vec<ToastId> {
+        let toasts = self.toasts.read();
+        toasts
+            .iter()
+            .take(self.max_visible)
+            .map(|(id, _)| *id)
+            .collect()
+    }
+
+    /// The ids of the toasts that are waiting to be shown, from oldest to newest
+    pub fn queued(&self) -> Vec<ToastId> {
+        let toasts = self.toasts.read();
+        toasts
+            .iter()
+            .skip(self.max_visible)
+            .map(|(id, _)| *id)
+            .collect()
+    }
+}
+
+/// Get the [`ToastContext`] of the closest [`Toaster`].
+///
+/// # Panics
+///
+/// This panics if there is no [`Toaster`] above the component.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_html::toast::{use_toast, Toast, ToastKind, Toaster};
+///
+/// fn app() -> Element {
+///     rsx! {
+///         Toaster { SaveButton {} }
+///     }
+/// }
+///
+/// #[component]
+/// fn SaveButton() -> Element {
+///     let toaster = use_toast();
+///     rsx! {
+///         button {
+///             onclick: move |_| {
+///                 toaster.show(Toast::new("Saved").kind(ToastKind::Success));
+///             },
+///             "Save"
+///         }
+///     }
+/// }
+/// ```
+pub fn use_toast() -> ToastContext {
+    use_hook(|| {
+        try_consume_context::<ToastContext>()
+            .expect("use_toast must be called in a component inside of a Toaster")
+    })
+}
+
+const TOASTER_STYLE: &str = "position: fixed; bottom: 1rem; right: 1rem; z-index: 2147483647; display: flex; flex-direction: column; gap: 0.5rem; margin: 0; padding: 0; list-style: none;";
+
+/// Provide a [`ToastContext`] to the children and render the toasts on top of them.
+///
+/// The toasts are rendered through a [`Portal`] into the `body`, so they are not clipped by the containers of the
+/// toaster. They are in an `ol` with the `dioxus-toaster` class that is fixed to the bottom right corner of the window. Each toast is an `li` with the `dioxus-toast` class and a `data-kind`
+/// attribute you can use to style it.
+///
+/// The list is a live region, so screen readers announce new toasts. [`ToastKind::Error`] toasts are alerts that
+/// are announced immediately.
+#[component]
+pub fn Toaster(
+    children: Element,
+    /// How many toasts are visible at once. Other toasts wait in a queue.
+    #[props(default = 3)]
+    max_visible: usize,
+    /// How long toasts are visible if they don't set a [`Toast::duration`]
+    #[props(default = Some(Duration::from_secs(5)))]
+    default_duration: Option<Duration>,
+    /// The element the toasts are rendered into. Defaults to the `body`.
+    #[props(into, default)]
+    target: PortalTarget,
+) -> Element {
+    let toaster = use_context_provider(|| ToastContext {
+        toasts: Signal::new(Vec::new()),
+        next_id: CopyValue::new(0),
+        max_visible,
+        default_duration,
+    });
+    let visible: Vec<(ToastId, Toast)> = toaster
+        .toasts
+        .read()
+        .iter()
+        .take(toaster.max_visible)
+        .cloned()
+        .collect();
+
+    rsx! {
+        {children}
+        Portal { target,
+            ol {
+                class: "dioxus-toaster",
+                aria_label: "Notifications",
+                aria_live: "polite",
+                style: TOASTER_STYLE,
+                for (id, toast) in visible {
+                    ToastItem { key: "{id.0}", id, toast }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone)]
+struct ToastItemProps {
+    id: ToastId,
+    toast: Toast,
+}
+
+impl PartialEq for ToastItemProps {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+#[allow(non_snake_case)]
+fn ToastItem(props: ToastItemProps) -> Element {
+    let ToastItemProps { id, toast } = props;
+    let toaster = use_context::<ToastContext>();
+    use_hook(|| {
+        let duration = toast.duration.unwrap_or(toaster.default_duration);
+        if let Some(duration) = duration {
+            spawn(async move {
+                sleep(duration).await;
+                toaster.dismiss(id);
+            });
+        }
+    });
+    let role = match toast.kind {
+        ToastKind::Error => "alert",
+        _ => "status",
+    };
+
+    rsx! {
+        li {
+            class: "dioxus-toast",
+            role,
+            aria_atomic: "true",
+            "data-kind": "{toast.kind}",
+            span { class: "dioxus-toast-message", "{toast.message}" }
+            if let Some(action) = toast.action {
+                button {
+                    class: "dioxus-toast-action",
+                    onclick: move |_| {
+                        (action.callback.borrow_mut())();
+                        toaster.dismiss(id);
+                    },
+                    "{action.label}"
+                }
+            }
+            button {
+                class: "dioxus-toast-dismiss",
+                aria_label: "Dismiss",
+                onclick: move |_| toaster.dismiss(id),
+                "×"
+            }
+        }
+    }
+}
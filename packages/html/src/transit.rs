@@ -5,7 +5,7 @@ use dioxus_core::ElementId;
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "serialize")]
-#[derive(Serialize, Debug, PartialEq)]
+#[derive(Serialize, Debug, PartialEq, Clone)]
 pub struct HtmlEvent {
     pub element: ElementId,
     pub name: String,
@@ -147,7 +147,7 @@ impl HtmlEvent {
     }
 }
 
-#[derive(Deserialize, Serialize, Debug, PartialEq)]
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
 #[serde(untagged)]
 #[non_exhaustive]
 pub enum EventData {
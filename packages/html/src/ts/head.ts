@@ -17,3 +17,26 @@ function createElementInHead(
 
 // @ts-ignore
 window.createElementInHead = createElementInHead;
+
+function setMetaInHead(
+  key: string,
+  value: string,
+  content: string | null
+): void {
+  let element = document.head.querySelector(
+    `meta[${key}="${CSS.escape(value)}"]`
+  );
+  if (content === null) {
+    element?.remove();
+    return;
+  }
+  if (!element) {
+    element = document.createElement("meta");
+    element.setAttribute(key, value);
+    document.head.appendChild(element);
+  }
+  element.setAttribute("content", content);
+}
+
+// @ts-ignore
+window.setMetaInHead = setMetaInHead;
@@ -107,22 +107,7 @@ impl HasKeyboardData for KeyboardEvent {
 
 impl ModifiersInteraction for KeyboardEvent {
     fn modifiers(&self) -> Modifiers {
-        let mut modifiers = Modifiers::empty();
-
-        if self.alt_key() {
-            modifiers.insert(Modifiers::ALT);
-        }
-        if self.ctrl_key() {
-            modifiers.insert(Modifiers::CONTROL);
-        }
-        if self.meta_key() {
-            modifiers.insert(Modifiers::META);
-        }
-        if self.shift_key() {
-            modifiers.insert(Modifiers::SHIFT);
-        }
-
-        modifiers
+        crate::input_data::modifiers_from_state(|name| self.get_modifier_state(name))
     }
 }
 
@@ -587,6 +572,38 @@ impl crate::RenderedElementBacking for web_sys::Element {
             });
         Box::pin(async { result })
     }
+
+    fn get_computed_style(
+        &self,
+        property: &str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = crate::MountedResult<String>>>> {
+        #[derive(Debug)]
+        struct ComputedStyleError(wasm_bindgen::JsValue);
+
+        impl std::fmt::Display for ComputedStyleError {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "failed to get the computed style {:?}", self.0)
+            }
+        }
+
+        impl std::error::Error for ComputedStyleError {}
+
+        let result = web_sys::window()
+            .ok_or(crate::MountedError::NotSupported)
+            .and_then(|window| {
+                window
+                    .get_computed_style(self)
+                    .and_then(|style| {
+                        style
+                            .ok_or_else(|| self.into())
+                            .and_then(|style| style.get_property_value(property))
+                    })
+                    .map_err(|err| {
+                        crate::MountedError::OperationFailed(Box::new(ComputedStyleError(err)))
+                    })
+            });
+        Box::pin(async { result })
+    }
 }
 
 fn extract_first_size(resize_observer_output: js_sys::Array) -> ResizeResult<PixelsSize> {
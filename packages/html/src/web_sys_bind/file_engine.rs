@@ -49,6 +49,12 @@ impl FileEngine for WebFileEngine {
         Some(file.size() as u64)
     }
 
+    async fn file_type(&self, file: &str) -> Option<String> {
+        let file = self.find(file)?;
+        // The browser leaves the type empty when it doesn't know it
+        Some(file.type_()).filter(|content_type| !content_type.is_empty())
+    }
+
     // read a file to bytes
     async fn read_file(&self, file: &str) -> Option<Vec<u8>> {
         let file = self.find(file)?;
@@ -0,0 +1,442 @@
+//! Peer to peer connections with [WebRTC](https://developer.mozilla.org/en-US/docs/Web/API/WebRTC_API).
+//!
+//! [`use_webrtc`] manages an `RTCPeerConnection` in the renderer. Peers find each other through a signaling channel
+//! you provide: every [`SignalingMessage`] the connection creates is passed to [`WebRtcConfig::on_signal`] to be sent
+//! to the other peer (for example with a server function or a websocket), and every message the other peer sends back
+//! is passed to [`UseWebRtc::receive_signal`].
+//!
+//! ```rust, no_run
+//! use dioxus::prelude::*;
+//! use dioxus_html::webrtc::{use_webrtc, SignalingMessage, WebRtcConfig};
+//!
+//! fn app() -> Element {
+//!     let rtc = use_webrtc(
+//!         WebRtcConfig::new()
+//!             .ice_server("stun:stun.l.google.com:19302")
+//!             .on_signal(|message: SignalingMessage| async move {
+//!                 // Send the message to the other peer through your signaling server
+//!                 _ = message;
+//!             }),
+//!     );
+//!     let mut messages = use_signal(Vec::new);
+//!     use_future(move || async move {
+//!         let mut chat = rtc.channel("chat");
+//!         while let Some(message) = chat.recv().await {
+//!             messages.push(message);
+//!         }
+//!     });
+//!
+//!     rsx! {
+//!         "State: {rtc.state()}"
+//!         button { onclick: move |_| rtc.connect(), "Call" }
+//!         button { onclick: move |_| rtc.attach_local_media("local", true, true), "Share camera" }
+//!         video { id: "local", autoplay: true, muted: true }
+//!         video { id: "remote", autoplay: true }
+//!         for message in messages.iter() {
+//!             p { "{message}" }
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::document::{document, EvalError, UseEval};
+use dioxus_core::prelude::*;
+use dioxus_core::SpawnIfAsync;
+use dioxus_signals::{CopyValue, Readable, Signal, Writable};
+use futures_channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+use futures_util::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+
+/// The JavaScript that manages the peer connection. It receives commands from Rust with `dioxus.recv` and sends
+/// signaling messages, state changes and data channel messages back with `dioxus.send`.
+const WEBRTC_SCRIPT: &str = r#"
+const config = await dioxus.recv();
+const peer = new RTCPeerConnection({ iceServers: config.ice_servers.map((urls) => ({ urls })) });
+const channels = new Map();
+let remoteElement = null;
+let remoteStream = null;
+
+const send = (message) => dioxus.send(message);
+const setupChannel = (channel) => {
+    channels.set(channel.label, channel);
+    channel.onopen = () => send({ type: "channel_open", label: channel.label });
+    channel.onclose = () => {
+        channels.delete(channel.label);
+        send({ type: "channel_close", label: channel.label });
+    };
+    channel.onmessage = (event) => send({ type: "message", label: channel.label, data: String(event.data) });
+};
+const attachRemote = () => {
+    const element = remoteElement && document.getElementById(remoteElement);
+    if (element && remoteStream && element.srcObject !== remoteStream) element.srcObject = remoteStream;
+};
+const report = (error) => send({ type: "error", message: String(error) });
+
+peer.onicecandidate = (event) => {
+    if (event.candidate) send({ type: "signal", signal: { type: "candidate", candidate: event.candidate.toJSON() } });
+};
+peer.onconnectionstatechange = () => send({ type: "state", state: peer.connectionState });
+peer.ondatachannel = (event) => setupChannel(event.channel);
+peer.ontrack = (event) => {
+    remoteStream = event.streams[0] || new MediaStream([event.track]);
+    attachRemote();
+};
+
+while (true) {
+    const command = await dioxus.recv();
+    try {
+        switch (command.type) {
+            case "connect": {
+                await peer.setLocalDescription(await peer.createOffer());
+                send({ type: "signal", signal: { type: "offer", sdp: peer.localDescription.sdp } });
+                break;
+            }
+            case "signal": {
+                const signal = command.signal;
+                if (signal.type === "candidate") {
+                    await peer.addIceCandidate(signal.candidate);
+                } else if (signal.type === "offer") {
+                    await peer.setRemoteDescription({ type: "offer", sdp: signal.sdp });
+                    await peer.setLocalDescription(await peer.createAnswer());
+                    send({ type: "signal", signal: { type: "answer", sdp: peer.localDescription.sdp } });
+                } else if (signal.type === "answer") {
+                    await peer.setRemoteDescription({ type: "answer", sdp: signal.sdp });
+                }
+                break;
+            }
+            case "open_channel": {
+                if (!channels.has(command.label)) setupChannel(peer.createDataChannel(command.label));
+                break;
+            }
+            case "send": {
+                const channel = channels.get(command.label);
+                if (channel && channel.readyState === "open") {
+                    channel.send(command.data);
+                } else {
+                    report(`The data channel ${command.label} is not open`);
+                }
+                break;
+            }
+            case "local_media": {
+                const stream = await navigator.mediaDevices.getUserMedia({ audio: command.audio, video: command.video });
+                stream.getTracks().forEach((track) => peer.addTrack(track, stream));
+                const element = document.getElementById(command.element);
+                if (element) element.srcObject = stream;
+                break;
+            }
+            case "remote_media": {
+                remoteElement = command.element;
+                attachRemote();
+                break;
+            }
+            case "close": {
+                peer.close();
+                send({ type: "state", state: "closed" });
+                return;
+            }
+        }
+    } catch (error) {
+        report(error);
+    }
+}
+"#;
+
+/// A message peers exchange through the signaling channel to set up a connection.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SignalingMessage {
+    /// An offer to connect from the peer that called [`UseWebRtc::connect`]
+    Offer {
+        /// The session description of the peer
+        sdp: String,
+    },
+    /// The answer to an offer
+    Answer {
+        /// The session description of the peer
+        sdp: String,
+    },
+    /// A network address the peer can be reached at
+    Candidate {
+        /// The `RTCIceCandidateInit` of the candidate
+        candidate: serde_json::Value,
+    },
+}
+
+/// The state of a peer connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PeerState {
+    /// The connection was created, but the peers haven't started connecting
+    #[default]
+    New,
+    /// The peers are connecting
+    Connecting,
+    /// The peers are connected
+    Connected,
+    /// The connection was lost. It may recover on its own
+    Disconnected,
+    /// The connection failed
+    Failed,
+    /// The connection was closed
+    Closed,
+}
+
+impl Display for PeerState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let state = match self {
+            PeerState::New => "new",
+            PeerState::Connecting => "connecting",
+            PeerState::Connected => "connected",
+            PeerState::Disconnected => "disconnected",
+            PeerState::Failed => "failed",
+            PeerState::Closed => "closed",
+        };
+        f.write_str(state)
+    }
+}
+
+/// The configuration of a peer connection created with [`use_webrtc`].
+#[derive(Clone, Default)]
+pub struct WebRtcConfig {
+    ice_servers: Vec<String>,
+    on_signal: Option<Rc<RefCell<dyn FnMut(SignalingMessage)>>>,
+}
+
+impl WebRtcConfig {
+    /// Create a new configuration without any ICE servers
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a STUN or TURN server the connection uses to find a route between the peers, like
+    /// `stun:stun.l.google.com:19302`
+    pub fn ice_server(mut self, url: impl Into<String>) -> Self {
+        self.ice_servers.push(url.into());
+        self
+    }
+
+    /// Set the handler that sends signaling messages to the other peer. The handler can be async, for example to call
+    /// a server function. Like the rest of the configuration, only the handler from the first render is used.
+    pub fn on_signal<MaybeAsync: SpawnIfAsync<Marker>, Marker>(
+        mut self,
+        mut on_signal: impl FnMut(SignalingMessage) -> MaybeAsync + 'static,
+    ) -> Self {
+        self.on_signal = Some(Rc::new(RefCell::new(move |message: SignalingMessage| {
+            on_signal(message).spawn()
+        })));
+        self
+    }
+}
+
+/// A message from the JavaScript that manages the peer connection
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Event {
+    Signal { signal: SignalingMessage },
+    State { state: PeerState },
+    ChannelOpen { label: String },
+    ChannelClose { label: String },
+    Message { label: String, data: String },
+    Error { message: String },
+}
+
+/// A handle to a peer connection created with [`use_webrtc`].
+#[derive(Clone, Copy)]
+pub struct UseWebRtc {
+    eval: UseEval,
+    state: Signal<PeerState>,
+    open_channels: Signal<Vec<String>>,
+    error: Signal<Option<String>>,
+    channels: CopyValue<HashMap<String, Vec<UnboundedSender<String>>>>,
+}
+
+impl UseWebRtc {
+    /// Get the current state of the connection
+    pub fn state(&self) -> PeerState {
+        (self.state)()
+    }
+
+    /// Get the last error the connection ran into, like a failed `getUserMedia` call
+    pub fn error(&self) -> Option<String> {
+        (self.error)()
+    }
+
+    /// Start connecting to the other peer. This creates an offer that is sent to the other peer through the signaling
+    /// channel. Only one of the peers should call this.
+    pub fn connect(&self) {
+        self.command(serde_json::json!({ "type": "connect" }));
+    }
+
+    /// Pass a signaling message the other peer sent to this connection
+    pub fn receive_signal(&self, signal: SignalingMessage) {
+        self.command(serde_json::json!({ "type": "signal", "signal": signal }));
+    }
+
+    /// Get a stream of the messages received on the data channel with this label. The channel is created if neither
+    /// peer has created it yet. Channels should be created before calling [`UseWebRtc::connect`] so they are included
+    /// in the offer.
+    pub fn channel(&self, label: impl Into<String>) -> DataChannel {
+        let label = label.into();
+        let (sender, receiver) = unbounded();
+        let mut channels = self.channels;
+        channels
+            .write()
+            .entry(label.clone())
+            .or_default()
+            .push(sender);
+        self.command(serde_json::json!({ "type": "open_channel", "label": label }));
+        DataChannel {
+            label,
+            rtc: *self,
+            receiver,
+        }
+    }
+
+    /// Check if the data channel with this label is open
+    pub fn is_channel_open(&self, label: &str) -> bool {
+        self.open_channels.read().iter().any(|open| open == label)
+    }
+
+    /// Capture the camera and/or microphone, send the tracks to the other peer and show them in the `video` element
+    /// with this id
+    pub fn attach_local_media(&self, element_id: &str, audio: bool, video: bool) {
+        self.command(serde_json::json!({
+            "type": "local_media",
+            "element": element_id,
+            "audio": audio,
+            "video": video,
+        }));
+    }
+
+    /// Show the media the other peer sends in the `video` element with this id
+    pub fn attach_remote_media(&self, element_id: &str) {
+        self.command(serde_json::json!({ "type": "remote_media", "element": element_id }));
+    }
+
+    /// Close the connection. The connection is also closed when the component that created it is dropped
+    pub fn close(&self) {
+        self.command(serde_json::json!({ "type": "close" }));
+    }
+
+    fn command(&self, command: serde_json::Value) {
+        if let Err(err) = self.eval.send(command) {
+            let mut error = self.error;
+            error.set(Some(err.to_string()));
+        }
+    }
+}
+
+/// A stream of the messages received on a data channel. Create one with [`UseWebRtc::channel`].
+pub struct DataChannel {
+    label: String,
+    rtc: UseWebRtc,
+    receiver: UnboundedReceiver<String>,
+}
+
+impl DataChannel {
+    /// Get the label of the channel
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Check if the channel is open
+    pub fn is_open(&self) -> bool {
+        self.rtc.is_channel_open(&self.label)
+    }
+
+    /// Send a message to the other peer. Messages sent before the channel is open are dropped and reported as an
+    /// [error](UseWebRtc::error).
+    pub fn send(&self, message: impl Into<String>) {
+        self.rtc.command(serde_json::json!({
+            "type": "send",
+            "label": self.label,
+            "data": message.into(),
+        }));
+    }
+
+    /// Wait for the next message from the other peer. Returns `None` once the connection is closed
+    pub async fn recv(&mut self) -> Option<String> {
+        self.receiver.next().await
+    }
+}
+
+impl Stream for DataChannel {
+    type Item = String;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_next_unpin(cx)
+    }
+}
+
+/// Create a WebRTC peer connection that lives as long as the component. See the [module level docs](self) for an
+/// example.
+pub fn use_webrtc(config: WebRtcConfig) -> UseWebRtc {
+    let rtc = use_hook(|| {
+        let eval = UseEval::new(document().new_evaluator(WEBRTC_SCRIPT.to_string()));
+        let rtc = UseWebRtc {
+            eval,
+            state: Signal::new(PeerState::New),
+            open_channels: Signal::new(Vec::new()),
+            error: Signal::new(None),
+            channels: CopyValue::new(HashMap::new()),
+        };
+        rtc.command(serde_json::json!({ "ice_servers": config.ice_servers }));
+        let on_signal = config.on_signal.map(|on_signal| {
+            Callback::new(move |message: SignalingMessage| (on_signal.borrow_mut())(message))
+        });
+        spawn(listen(rtc, eval, on_signal));
+        rtc
+    });
+    use_drop(move || rtc.close());
+    rtc
+}
+
+/// Handle the messages from the JavaScript that manages the peer connection until it exits
+async fn listen(rtc: UseWebRtc, mut eval: UseEval, on_signal: Option<Callback<SignalingMessage>>) {
+    let UseWebRtc {
+        mut state,
+        mut open_channels,
+        mut error,
+        mut channels,
+        ..
+    } = rtc;
+    loop {
+        let event = match eval.recv().await {
+            Ok(event) => event,
+            Err(EvalError::Finished) => break,
+            Err(err) => {
+                error.set(Some(err.to_string()));
+                break;
+            }
+        };
+        let Ok(event) = serde_json::from_value::<Event>(event) else {
+            continue;
+        };
+        match event {
+            Event::Signal { signal } => match on_signal {
+                Some(on_signal) => on_signal.call(signal),
+                None => tracing::warn!("A WebRTC connection created a signaling message, but there is no signaling handler. Set one with WebRtcConfig::on_signal"),
+            },
+            Event::State { state: new_state } => state.set(new_state),
+            Event::ChannelOpen { label } => open_channels.write().push(label),
+            Event::ChannelClose { label } => {
+                open_channels.write().retain(|open| *open != label);
+                channels.write().remove(&label);
+            }
+            Event::Message { label, data } => {
+                if let Some(senders) = channels.write().get_mut(&label) {
+                    senders.retain(|sender| sender.unbounded_send(data.clone()).is_ok());
+                }
+            }
+            Event::Error { message } => error.set(Some(message)),
+        }
+    }
+    // Close all of the streams so readers stop waiting for messages
+    channels.write().clear();
+}
@@ -0,0 +1,154 @@
+#![cfg(feature = "a11y")]
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use dioxus_html::a11y::{audit, A11yIssueKind};
+
+fn issues(app: fn() -> Element) -> Vec<(A11yIssueKind, &'static str)> {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    audit(&dom)
+        .into_iter()
+        .map(|issue| (issue.kind, issue.tag))
+        .collect()
+}
+
+#[test]
+fn accessible_markup_has_no_issues() {
+    fn app() -> Element {
+        let name = "name";
+        rsx! {
+            img { src: "logo.png", alt: "Logo" }
+            img { src: "divider.png", alt: "" }
+            label { "Email" input { r#type: "email" } }
+            label { r#for: name, "Name" }
+            input { id: name }
+            input { r#type: "submit" }
+            input { r#type: "hidden", name: "token" }
+            textarea { aria_label: "Comment" }
+            div { role: "checkbox", aria_checked: "false", "Agree" }
+            input { r#type: "checkbox", aria_checked: "true", title: "Agree" }
+            h2 { aria_level: "2", "Title" }
+            button { aria_pressed: "true", "Bold" }
+        }
+    }
+
+    assert_eq!(issues(app), vec![]);
+}
+
+#[test]
+fn missing_alt_and_labels() {
+    fn app() -> Element {
+        rsx! {
+            img { src: "photo.png" }
+            img { src: "icon.png", aria_hidden: "true" }
+            input { r#type: "text" }
+            select {}
+            input { r#type: "image", src: "go.png" }
+        }
+    }
+
+    assert_eq!(
+        issues(app),
+        vec![
+            (A11yIssueKind::MissingAlt, "img"),
+            (A11yIssueKind::UnlabeledInput, "input"),
+            (A11yIssueKind::UnlabeledInput, "select"),
+            (A11yIssueKind::MissingAlt, "input"),
+        ]
+    );
+}
+
+#[test]
+fn invalid_aria() {
+    fn app() -> Element {
+        rsx! {
+            div { role: "buton", "Save" }
+            div { role: "slider", aria_label: "Volume" }
+            span { "aria-colour": "red" }
+            div { aria_checked: "true" }
+            a { href: "/", aria_selected: "true", "Home" }
+        }
+    }
+
+    assert_eq!(
+        issues(app),
+        vec![
+            (
+                A11yIssueKind::InvalidRole {
+                    role: "buton".to_string()
+                },
+                "div"
+            ),
+            (
+                A11yIssueKind::MissingRequiredAria {
+                    role: "slider".to_string(),
+                    attribute: "aria-valuenow"
+                },
+                "div"
+            ),
+            (
+                A11yIssueKind::UnknownAriaAttribute {
+                    attribute: "aria-colour".to_string()
+                },
+                "span"
+            ),
+            (
+                A11yIssueKind::UnsupportedAriaAttribute {
+                    attribute: "aria-checked".to_string(),
+                    role: None
+                },
+                "div"
+            ),
+            (
+                A11yIssueKind::UnsupportedAriaAttribute {
+                    attribute: "aria-selected".to_string(),
+                    role: Some("link".to_string())
+                },
+                "a"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn duplicate_ids_point_at_the_component() {
+    fn app() -> Element {
+        rsx! {
+            Field {}
+            Field {}
+        }
+    }
+
+    #[component]
+    fn Field() -> Element {
+        rsx! { input { id: "field", aria_label: "Field" } }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let issues = audit(&dom);
+    assert_eq!(issues.len(), 1);
+
+    let issue = &issues[0];
+    assert_eq!(
+        issue.kind,
+        A11yIssueKind::DuplicateId {
+            id: "field".to_string()
+        }
+    );
+    assert!(issue.component.ends_with("Field"), "{}", issue.component);
+
+    // Locations are only tracked in debug builds
+    if cfg!(debug_assertions) {
+        let location = issue
+            .location
+            .expect("locations are tracked in debug builds");
+        assert_eq!(location.file(), file!());
+        assert!(issue
+            .to_string()
+            .contains(&format!("{}:{}", file!(), location.line())));
+    } else {
+        assert!(issue.location.is_none());
+    }
+}
@@ -0,0 +1,40 @@
+#![cfg(all(feature = "aria", feature = "a11y"))]
+
+use dioxus::prelude::*;
+use dioxus_html::a11y::audit;
+use dioxus_html::aria::{self, AriaChecked, AriaCurrent, AriaLive, AriaRole};
+
+#[test]
+fn roles_round_trip_through_strings() {
+    for role in AriaRole::ALL {
+        assert_eq!(role.as_str().parse::<AriaRole>(), Ok(*role));
+    }
+    assert!("widget".parse::<AriaRole>().is_err());
+}
+
+#[test]
+fn typed_attributes_render_and_pass_the_audit() {
+    fn app() -> Element {
+        rsx! {
+            div { role: AriaRole::Status, aria_live: AriaLive::Polite, "Saved" }
+            a { href: "/", aria_current: AriaCurrent::Page, "Home" }
+            div { ..aria::checkbox(AriaChecked::Mixed), "Select all" }
+            div { ..aria::switch(false), "Dark mode" }
+            div { ..aria::heading(2), "Title" }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert_eq!(audit(&dom), vec![]);
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        concat!(
+            r#"<div role="status" aria-live="polite">Saved</div>"#,
+            r#"<a href="/" aria-current="page">Home</a>"#,
+            r#"<div role="checkbox" aria-checked="mixed">Select all</div>"#,
+            r#"<div role="switch" aria-checked="false">Dark mode</div>"#,
+            r#"<div role="heading" aria-level=2>Title</div>"#,
+        )
+    );
+}
@@ -0,0 +1,186 @@
+#![cfg(feature = "broadcast")]
+
+use dioxus::prelude::*;
+use dioxus_html::broadcast::{
+    provide_broadcast_backend, use_broadcast_channel, BroadcastBackend, BroadcastConnection,
+    BroadcastError, ProcessBroadcastBackend, UseBroadcastChannel,
+};
+use dioxus_testing::TestDom;
+use futures_channel::mpsc::{unbounded, UnboundedReceiver};
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+enum Session {
+    LoggedIn(String),
+    LoggedOut,
+}
+
+thread_local! {
+    static CHANNELS: RefCell<Vec<UseBroadcastChannel<Session>>> = const { RefCell::new(Vec::new()) };
+    static RECEIVED: RefCell<Vec<(usize, Session)>> = const { RefCell::new(Vec::new()) };
+    static NEXT_WINDOW: Cell<usize> = const { Cell::new(0) };
+}
+
+fn window() -> Element {
+    let id = use_hook(|| {
+        provide_broadcast_backend(ProcessBroadcastBackend);
+        NEXT_WINDOW.with(|next| next.replace(next.get() + 1))
+    });
+    let session = use_broadcast_channel("session", move |message: Session| {
+        RECEIVED.with(|received| received.borrow_mut().push((id, message)));
+    });
+    use_hook(|| CHANNELS.with(|channels| channels.borrow_mut().push(session)));
+    rsx! {}
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn messages_are_delivered_to_other_windows() {
+    let mut first = TestDom::new(window);
+    let mut second = TestDom::new(window);
+    let channels = CHANNELS.with(|channels| channels.borrow().clone());
+
+    first.dom().in_runtime(|| {
+        channels[0]
+            .post(&Session::LoggedIn("ada".to_string()))
+            .unwrap()
+    });
+    first.settle().await;
+    second.settle().await;
+    // The window that posted the message doesn't receive it
+    assert_eq!(
+        RECEIVED.with(|received| received.take()),
+        vec![(1, Session::LoggedIn("ada".to_string()))]
+    );
+
+    second
+        .dom()
+        .in_runtime(|| channels[1].post(&Session::LoggedOut).unwrap());
+    first.settle().await;
+    second.settle().await;
+    assert_eq!(
+        RECEIVED.with(|received| received.take()),
+        vec![(0, Session::LoggedOut)]
+    );
+
+    // Closing a window closes its connection
+    drop(second);
+    first
+        .dom()
+        .in_runtime(|| channels[0].post(&Session::LoggedOut).unwrap());
+    first.settle().await;
+    assert!(RECEIVED.with(|received| received.take()).is_empty());
+}
+
+/// A connection that records the posted messages and lets the test send messages from other tabs
+struct RecordingConnection {
+    posted: RefCell<Vec<Value>>,
+    incoming: Rc<RefCell<UnboundedReceiver<Value>>>,
+}
+
+impl BroadcastConnection for RecordingConnection {
+    fn post(&self, message: Value) -> Result<(), BroadcastError> {
+        self.posted.borrow_mut().push(message);
+        Ok(())
+    }
+
+    fn recv(&self) -> Pin<Box<dyn Future<Output = Option<Value>>>> {
+        let incoming = self.incoming.clone();
+        Box::pin(async move {
+            std::future::poll_fn(|cx| incoming.borrow_mut().poll_next_unpin(cx)).await
+        })
+    }
+
+    fn close(&self) {
+        self.incoming.borrow_mut().close();
+    }
+}
+
+/// A backend that records the messages of the session channel
+struct RecordingBackend(Rc<RecordingConnection>);
+
+impl BroadcastBackend for RecordingBackend {
+    fn open(&self, channel: &str) -> Rc<dyn BroadcastConnection> {
+        if channel == "session" {
+            return self.0.clone();
+        }
+        // Other channels never receive messages
+        Rc::new(RecordingConnection {
+            posted: RefCell::new(Vec::new()),
+            incoming: Rc::new(RefCell::new(unbounded().1)),
+        })
+    }
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn typed_messages_are_serialized_as_json() {
+    thread_local! {
+        static CONNECTION: RefCell<Option<Rc<RecordingConnection>>> = const { RefCell::new(None) };
+        static SESSION: Cell<Option<UseBroadcastChannel<Session>>> = const { Cell::new(None) };
+        static SESSIONS: RefCell<Vec<Session>> = const { RefCell::new(Vec::new()) };
+        static INVALID: Cell<Option<UseBroadcastChannel<HashMap<Vec<u8>, u8>>>> = const { Cell::new(None) };
+    }
+
+    fn app() -> Element {
+        use_hook(|| {
+            let connection = CONNECTION
+                .with(|connection| connection.borrow().clone())
+                .unwrap();
+            provide_broadcast_backend(RecordingBackend(connection));
+        });
+        let session = use_broadcast_channel("session", |message: Session| {
+            SESSIONS.with(|sessions| sessions.borrow_mut().push(message));
+        });
+        SESSION.with(|cell| cell.set(Some(session)));
+        // JSON objects can only have string keys, so these messages can't be serialized
+        let invalid = use_broadcast_channel("invalid", |_: HashMap<Vec<u8>, u8>| {});
+        INVALID.with(|cell| cell.set(Some(invalid)));
+        rsx! {}
+    }
+
+    let (sender, receiver) = unbounded();
+    let connection = Rc::new(RecordingConnection {
+        posted: RefCell::new(Vec::new()),
+        incoming: Rc::new(RefCell::new(receiver)),
+    });
+    CONNECTION.with(|cell| *cell.borrow_mut() = Some(connection.clone()));
+
+    let mut dom = TestDom::new(app);
+    let session = SESSION.with(|cell| cell.get()).unwrap();
+    let invalid = INVALID.with(|cell| cell.get()).unwrap();
+
+    dom.dom().in_runtime(|| {
+        session.post(&Session::LoggedIn("ada".to_string())).unwrap();
+        session.post(&Session::LoggedOut).unwrap();
+        let message = HashMap::from([(vec![1], 2)]);
+        assert!(matches!(
+            invalid.post(&message),
+            Err(BroadcastError::Serialization(_))
+        ));
+    });
+    assert_eq!(
+        connection.posted.take(),
+        [json!({ "LoggedIn": "ada" }), json!("LoggedOut")]
+    );
+
+    // Messages from other tabs are deserialized, and messages of a different type are ignored
+    for message in [
+        json!({ "LoggedIn": "grace" }),
+        json!({ "Unknown": 1 }),
+        json!(42),
+        json!("LoggedOut"),
+    ] {
+        sender.unbounded_send(message).unwrap();
+    }
+    dom.settle().await;
+    assert_eq!(
+        SESSIONS.with(|sessions| sessions.take()),
+        [Session::LoggedIn("grace".to_string()), Session::LoggedOut]
+    );
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "canvas")]
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_html::canvas2d::{Canvas, DrawCommand, DrawContext};
+use std::cell::{Cell, RefCell};
+
+#[test]
+fn records_commands() {
+    let ctx = DrawContext::new(100.0, 50.0);
+    ctx.fill_style("red")
+        .fill_rect(0.0, 0.0, ctx.width(), ctx.height())
+        .begin_path()
+        .move_to(0.0, 0.0)
+        .line_to(10.0, 10.0)
+        .stroke();
+    // Clones record into the same frame
+    ctx.clone().fill_text("Hi", 5.0, 5.0);
+
+    assert_eq!(
+        ctx.commands(),
+        [
+            DrawCommand::FillStyle("red".to_string()),
+            DrawCommand::FillRect {
+                x: 0.0,
+                y: 0.0,
+                width: 100.0,
+                height: 50.0
+            },
+            DrawCommand::BeginPath,
+            DrawCommand::MoveTo { x: 0.0, y: 0.0 },
+            DrawCommand::LineTo { x: 10.0, y: 10.0 },
+            DrawCommand::Stroke,
+            DrawCommand::FillText {
+                text: "Hi".to_string(),
+                x: 5.0,
+                y: 5.0
+            },
+        ]
+    );
+}
+
+#[test]
+fn redraws_when_signals_change() {
+    thread_local! {
+        static SIGNALS: Cell<Option<(Signal<f64>, Signal<u32>)>> = const { Cell::new(None) };
+        static FRAMES: RefCell<Vec<Vec<DrawCommand>>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn app() -> Element {
+        let progress = use_signal(|| 0.5);
+        let unrelated = use_signal(|| 0);
+        SIGNALS.with(|cell| cell.set(Some((progress, unrelated))));
+        rsx! {
+            "{unrelated}"
+            Canvas {
+                width: 100,
+                height: 10,
+                label: "Progress",
+                draw: move |ctx: DrawContext| {
+                    ctx.fill_rect(0.0, 0.0, ctx.width() * progress(), ctx.height());
+                    FRAMES.with(|frames| frames.borrow_mut().push(ctx.commands()));
+                },
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        r#"0<canvas data-dioxus-canvas="4" width="100" height="10" role="img" aria-label="Progress"></canvas>"#
+    );
+    let (mut progress, mut unrelated) = SIGNALS.with(|cell| cell.get()).unwrap();
+
+    dom.in_runtime(|| unrelated.set(1));
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(FRAMES.with(|frames| frames.borrow().len()), 1);
+
+    dom.in_runtime(|| progress.set(1.0));
+    dom.render_immediate(&mut NoOpMutations);
+    FRAMES.with(|frames| {
+        let frames = frames.borrow();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(
+            frames[1],
+            [DrawCommand::FillRect {
+                x: 0.0,
+                y: 0.0,
+                width: 100.0,
+                height: 10.0
+            }]
+        );
+    });
+}
@@ -0,0 +1,168 @@
+#![cfg(feature = "chart")]
+
+use dioxus::prelude::*;
+use dioxus_core::{AttributeValue, Mutation, NoOpMutations};
+use dioxus_html::chart::*;
+use std::cell::Cell;
+
+thread_local! {
+    static DATA: Cell<Option<SignalVec<(f64, f64)>>> = const { Cell::new(None) };
+    static CHART: Cell<Option<ChartContext>> = const { Cell::new(None) };
+}
+
+#[component]
+fn Capture() -> Element {
+    let chart = use_chart();
+    CHART.with(|cell| cell.set(Some(chart)));
+    rsx! {}
+}
+
+fn app() -> Element {
+    let data = use_hook(|| SignalVec::from_iter([(0.0, 0.0), (1.0, 5.0), (2.0, 10.0), (3.0, 5.0)]));
+    DATA.with(|cell| cell.set(Some(data)));
+    rsx! {
+        Chart {
+            x_domain: (0.0, 4.0),
+            y_domain: (0.0, 10.0),
+            width: 100.0,
+            height: 120.0,
+            padding: 10.0,
+            label: "Sales",
+            XAxis { ticks: 4 }
+            YAxis { ticks: 2, format: |value| format!("{value}k") }
+            LineSeries { name: "sales", data, color: "teal" }
+            Tooltip {}
+            Capture {}
+        }
+    }
+}
+
+#[test]
+fn maps_values_to_the_plot_area() {
+    let scale = ChartScale {
+        width: 100.0,
+        height: 120.0,
+        padding: 10.0,
+        x_domain: (0.0, 4.0),
+        y_domain: (-5.0, 5.0),
+    };
+    assert_eq!(scale.x(0.0), 10.0);
+    assert_eq!(scale.x(2.0), 50.0);
+    assert_eq!(scale.x(4.0), 90.0);
+    assert_eq!(scale.y(-5.0), 110.0);
+    assert_eq!(scale.y(5.0), 10.0);
+    assert_eq!(scale.baseline(), 60.0);
+    assert_eq!(scale.x_ticks(4), [0.0, 1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(scale.y_ticks(2), [-5.0, 0.0, 5.0]);
+
+    let positive = ChartScale {
+        y_domain: (2.0, 10.0),
+        ..scale
+    };
+    assert_eq!(positive.baseline(), positive.y(2.0));
+}
+
+#[test]
+fn renders_axes_and_series_as_svg() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let html = dioxus_ssr::render(&dom);
+
+    assert!(html.starts_with(r#"<svg class="dioxus-chart" width="100" height="120" viewBox="0 0 100 120" role="img" aria-label="Sales">"#));
+    // The y axis uses the custom format
+    for label in ["0k", "5k", "10k"] {
+        assert!(html.contains(&format!(">{label}</text>")), "{html}");
+    }
+    // The line is split into a segment between each pair of points
+    assert!(html.contains(r#"<line x1=10 y1=110 x2=30 y2=60></line>"#));
+    assert!(html.contains(r#"<line x1=50 y1=10 x2=70 y2=60></line>"#));
+    assert_eq!(html.matches("<circle").count(), 4);
+    assert!(!html.contains("dioxus-chart-tooltip"));
+}
+
+#[test]
+fn changing_a_point_only_updates_its_shapes() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    dom.in_runtime(|| DATA.with(|cell| cell.get()).unwrap().set(2, (2.0, 0.0)));
+    let edits = dom.render_immediate_to_vec().edits;
+
+    // The segments on either side of the point and its marker move. Nothing else is touched.
+    let mut changed: Vec<_> = edits
+        .iter()
+        .map(|edit| match edit {
+            Mutation::SetAttribute { name, value, .. } => (*name, value.clone()),
+            other => panic!("unexpected edit {other:?}"),
+        })
+        .collect();
+    changed.sort_by_key(|(name, _)| *name);
+    assert_eq!(
+        changed,
+        [
+            ("cy", AttributeValue::Float(110.0)),
+            ("y1", AttributeValue::Float(110.0)),
+            ("y2", AttributeValue::Float(110.0)),
+        ]
+    );
+}
+
+#[test]
+fn shows_the_hovered_point() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    dom.in_runtime(|| {
+        CHART
+            .with(|cell| cell.get())
+            .unwrap()
+            .hover(Some(HoveredPoint {
+                series: "sales".to_string(),
+                x: 1.0,
+                y: 5.0,
+            }))
+    });
+    dom.render_immediate(&mut NoOpMutations);
+    let html = dioxus_ssr::render(&dom);
+    assert!(html.contains(r#"class="dioxus-chart-tooltip""#));
+    assert!(html.contains(">sales: 1, 5</text>"), "{html}");
+
+    dom.in_runtime(|| CHART.with(|cell| cell.get()).unwrap().hover(None));
+    dom.render_immediate(&mut NoOpMutations);
+    assert!(!dioxus_ssr::render(&dom).contains("dioxus-chart-tooltip"));
+}
+
+#[test]
+fn draws_bars_and_areas_from_the_baseline() {
+    fn app() -> Element {
+        let data = use_hook(|| SignalVec::from_iter([(1.0, 4.0), (2.0, -2.0)]));
+        rsx! {
+            Chart {
+                x_domain: (0.0, 3.0),
+                y_domain: (-4.0, 4.0),
+                width: 80.0,
+                height: 100.0,
+                padding: 10.0,
+                BarSeries { name: "bars", data, bar_width: 1.0 }
+                AreaSeries { name: "area", data }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let html = dioxus_ssr::render(&dom);
+
+    assert!(
+        html.contains(r#"<rect x=20 y=10 width=20 height=40>"#),
+        "{html}"
+    );
+    assert!(
+        html.contains(r#"<rect x=40 y=50 width=20 height=20>"#),
+        "{html}"
+    );
+    assert!(
+        html.contains(r#"<polygon points="30,50 30,10 50,70 50,50"></polygon>"#),
+        "{html}"
+    );
+}
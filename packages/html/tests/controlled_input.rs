@@ -0,0 +1,77 @@
+#![cfg(feature = "controlled-input")]
+
+use dioxus::prelude::*;
+use dioxus_core::{AttributeValue, Mutation, Mutations};
+use dioxus_html::controlled_input::{use_controlled_input, ControlledInput};
+use dioxus_html::{FormData, SerializedFormData};
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::Duration;
+
+thread_local! {
+    static INPUT: Cell<Option<ControlledInput>> = const { Cell::new(None) };
+}
+
+fn app() -> Element {
+    let input = use_controlled_input(
+        || "1a2".to_string(),
+        |value| value.chars().filter(char::is_ascii_digit).collect(),
+    );
+    INPUT.with(|cell| cell.set(Some(input)));
+    rsx! {
+        input { value: input.value() }
+    }
+}
+
+fn type_text(dom: &mut VirtualDom, text: &str) -> Vec<String> {
+    let event = Event::new(
+        Rc::new(FormData::new(SerializedFormData::new(
+            text.to_string(),
+            HashMap::new(),
+        ))),
+        true,
+    );
+    dom.in_runtime(|| {
+        ScopeId::ROOT.in_runtime(|| INPUT.with(|cell| cell.get()).unwrap().oninput(event))
+    });
+    written_values(dom.render_immediate_to_vec())
+}
+
+fn written_values(mutations: Mutations) -> Vec<String> {
+    mutations
+        .edits
+        .into_iter()
+        .filter_map(|edit| match edit {
+            Mutation::SetAttribute {
+                name: "value",
+                value: AttributeValue::Text(value),
+                ..
+            } => Some(value),
+            _ => None,
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn normalizes_the_typed_value() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    dom.in_runtime(|| assert_eq!(INPUT.with(|cell| cell.get()).unwrap().value(), "12"));
+
+    assert_eq!(type_text(&mut dom, "12-3"), ["123"]);
+}
+
+#[tokio::test]
+async fn rejected_characters_are_written_back() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    // The value stays "12", so the input is written twice to remove the "a" the user typed
+    assert_eq!(type_text(&mut dom, "12a"), ["12a"]);
+    tokio::select! {
+        _ = dom.wait_for_work() => {}
+        _ = tokio::time::sleep(Duration::from_millis(100)) => {}
+    };
+    assert_eq!(written_values(dom.render_immediate_to_vec()), ["12"]);
+}
@@ -0,0 +1,42 @@
+#![cfg(all(feature = "file_engine", feature = "serialize"))]
+
+use dioxus_html::{FormData, HasFileData, SerializedFileEngine, SerializedFormData};
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn file_list_exposes_names_sizes_types_and_contents() {
+    let engine = SerializedFileEngine {
+        files: HashMap::from([
+            ("docs/notes.txt".to_string(), b"hello".to_vec()),
+            ("photo.PNG".to_string(), vec![0x89, 0x50, 0x4e, 0x47]),
+            ("data.unknown".to_string(), vec![]),
+        ]),
+    };
+    let event =
+        FormData::new(SerializedFormData::new(String::new(), HashMap::new()).with_files(engine));
+
+    let mut files = event.file_list();
+    files.sort_by(|a, b| a.path().cmp(b.path()));
+    let names: Vec<_> = files.iter().map(|file| file.name()).collect();
+    assert_eq!(names, ["data.unknown", "notes.txt", "photo.PNG"]);
+
+    let [data, notes, photo] = &files[..] else {
+        unreachable!()
+    };
+    assert_eq!(notes.path(), "docs/notes.txt");
+    assert_eq!(notes.size().await, Some(5));
+    assert_eq!(notes.content_type().await.as_deref(), Some("text/plain"));
+    assert_eq!(notes.read_string().await.as_deref(), Some("hello"));
+
+    assert_eq!(photo.content_type().await.as_deref(), Some("image/png"));
+    assert_eq!(photo.read_bytes().await, Some(vec![0x89, 0x50, 0x4e, 0x47]));
+
+    assert_eq!(data.content_type().await, None);
+    assert_eq!(data.size().await, Some(0));
+}
+
+#[test]
+fn events_without_files_have_an_empty_file_list() {
+    let event = FormData::new(SerializedFormData::new(String::new(), HashMap::new()));
+    assert!(event.file_list().is_empty());
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "focus")]
+
+use dioxus::prelude::*;
+use dioxus_core::ElementId;
+use dioxus_html::focus::{use_focus, use_roving_tabindex};
+use dioxus_html::prelude::{Code, Key, Location, Modifiers};
+use dioxus_html::SerializedKeyboardData;
+use std::any::Any;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+fn key_down(dom: &mut VirtualDom, element: ElementId, key: Key) {
+    let data = SerializedKeyboardData::new(
+        key,
+        Code::Unidentified,
+        Location::Standard,
+        false,
+        Modifiers::empty(),
+        false,
+    );
+    let event = Event::new(
+        Rc::new(PlatformEventData::new(Box::new(data))) as Rc<dyn Any>,
+        true,
+    );
+    dom.runtime().handle_event("keydown", event, element);
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+}
+
+#[test]
+fn roving_tabindex_moves_with_the_arrow_keys() {
+    set_event_converter(Box::new(dioxus_html::SerializedHtmlEventConverter));
+
+    static ACTIVE: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+    fn app() -> Element {
+        let mut toolbar = use_roving_tabindex(3);
+        ACTIVE.store(toolbar.active(), Ordering::SeqCst);
+        assert_eq!(toolbar.tabindex(toolbar.active()), 0);
+        rsx! {
+            div { role: "toolbar",
+                for index in 0..3 {
+                    button {
+                        tabindex: toolbar.tabindex(index),
+                        onkeydown: move |event| toolbar.onkeydown(event),
+                        "{index}"
+                    }
+                }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert_eq!(ACTIVE.load(Ordering::SeqCst), 0);
+
+    let steps = [
+        (Key::ArrowRight, 1),
+        (Key::ArrowDown, 2),
+        (Key::ArrowRight, 0),
+        (Key::ArrowLeft, 2),
+        (Key::Home, 0),
+        (Key::End, 2),
+        (Key::Character("a".into()), 2),
+    ];
+    // The first button
+    let button = ElementId(2);
+    for (key, active) in steps {
+        key_down(&mut dom, button, key.clone());
+        assert_eq!(ACTIVE.load(Ordering::SeqCst), active, "after {key:?}");
+    }
+}
+
+#[test]
+fn focusing_an_unmounted_element_fails() {
+    static FAILED: AtomicBool = AtomicBool::new(false);
+
+    fn app() -> Element {
+        let input = use_focus();
+        use_hook(|| {
+            spawn(async move {
+                assert!(!input.is_mounted());
+                FAILED.store(input.focus().await.is_err(), Ordering::SeqCst);
+            })
+        });
+        rsx! {
+            input { onmounted: move |event| input.onmounted(event) }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert!(FAILED.load(Ordering::SeqCst));
+}
@@ -0,0 +1,122 @@
+#![cfg(feature = "global-events")]
+
+use dioxus::prelude::*;
+use dioxus_html::document::{Document, EvalError, Evaluator};
+use dioxus_html::geometry::PixelsSize;
+use dioxus_html::global_events::WindowListener;
+use dioxus_testing::TestDom;
+use generational_box::{GenerationalBox, UnsyncStorage};
+use serde_json::{json, Value};
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+thread_local! {
+    static SENT: RefCell<Vec<Value>> = const { RefCell::new(Vec::new()) };
+    static INBOX: RefCell<VecDeque<Value>> = const { RefCell::new(VecDeque::new()) };
+    static WAKER: RefCell<Option<Waker>> = const { RefCell::new(None) };
+}
+
+/// An evaluator that records the messages sent to the script and receives the messages in the inbox
+struct ChannelEvaluator;
+
+impl Evaluator for ChannelEvaluator {
+    fn send(&self, data: Value) -> Result<(), EvalError> {
+        SENT.with(|sent| sent.borrow_mut().push(data));
+        Ok(())
+    }
+
+    fn poll_recv(&mut self, context: &mut Context<'_>) -> Poll<Result<Value, EvalError>> {
+        match INBOX.with(|inbox| inbox.borrow_mut().pop_front()) {
+            Some(message) => Poll::Ready(Ok(message)),
+            None => {
+                WAKER.with(|waker| *waker.borrow_mut() = Some(context.waker().clone()));
+                Poll::Pending
+            }
+        }
+    }
+
+    fn poll_join(&mut self, _context: &mut Context<'_>) -> Poll<Result<Value, EvalError>> {
+        Poll::Pending
+    }
+}
+
+struct ChannelDocument;
+
+impl Document for ChannelDocument {
+    fn new_evaluator(&self, _js: String) -> GenerationalBox<Box<dyn Evaluator>> {
+        current_owner::<UnsyncStorage>().insert(Box::new(ChannelEvaluator) as Box<dyn Evaluator>)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+fn receive(message: Value) {
+    INBOX.with(|inbox| inbox.borrow_mut().push_back(message));
+    if let Some(waker) = WAKER.with(|waker| waker.borrow_mut().take()) {
+        waker.wake();
+    }
+}
+
+#[tokio::test]
+async fn window_listener_dispatches_events_and_stops_listening_on_unmount() {
+    thread_local! {
+        static LISTENING: Cell<Option<Signal<bool>>> = const { Cell::new(None) };
+    }
+
+    fn app() -> Element {
+        use_hook(|| provide_context(Rc::new(ChannelDocument) as Rc<dyn Document>));
+        let listening = use_signal(|| true);
+        LISTENING.with(|cell| cell.set(Some(listening)));
+        let mut key = use_signal(String::new);
+        let mut size = use_signal(|| None);
+        rsx! {
+            if listening() {
+                WindowListener {
+                    onkeydown: move |event: KeyboardEvent| key.set(event.key().to_string()),
+                    onresize: move |new_size: PixelsSize| size.set(Some(new_size.width)),
+                }
+            }
+            p { "key: {key}" }
+            p { "size: {size:?}" }
+        }
+    }
+
+    let mut dom = TestDom::new(app);
+    dom.settle().await;
+    assert_eq!(
+        SENT.with(|sent| sent.take()),
+        [json!(["keydown", "resize"])]
+    );
+
+    receive(json!({
+        "name": "keydown",
+        "data": {
+            "char_code": 0,
+            "is_composing": false,
+            "key": "Escape",
+            "key_code": 27,
+            "code": "Escape",
+            "alt_key": false,
+            "ctrl_key": false,
+            "meta_key": false,
+            "shift_key": false,
+            "location": 0,
+            "repeat": false,
+            "which": 27
+        }
+    }));
+    receive(json!({ "name": "resize", "data": { "width": 800.0, "height": 600.0 } }));
+    dom.settle().await;
+    let html = dioxus_ssr::render(dom.dom());
+    assert!(html.contains("key: Escape"), "{html}");
+    assert!(html.contains("size: Some(800.0)"), "{html}");
+
+    dom.dom()
+        .in_runtime(|| LISTENING.with(|cell| cell.get()).unwrap().set(false));
+    dom.settle().await;
+    assert_eq!(SENT.with(|sent| sent.take()), [Value::Null]);
+}
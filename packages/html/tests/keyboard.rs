@@ -0,0 +1,60 @@
+#![cfg(feature = "serialize")]
+
+use dioxus_html::input_data::{decode_modifier_state, encode_modifier_state};
+use dioxus_html::prelude::*;
+use dioxus_html::{KeyboardData, SerializedKeyboardData};
+use serde_json::json;
+
+#[test]
+fn modifier_state_round_trips() {
+    let modifiers = Modifiers::SHIFT | Modifiers::CAPS_LOCK | Modifiers::ALT_GRAPH;
+    let names = encode_modifier_state(modifiers);
+    assert_eq!(names, ["AltGraph", "CapsLock", "Shift"]);
+    assert_eq!(
+        decode_modifier_state(names.iter().map(String::as_str)),
+        modifiers
+    );
+    assert_eq!(decode_modifier_state(["NotAModifier"]), Modifiers::empty());
+}
+
+#[test]
+fn keyboard_data_keeps_every_field_across_serialization() {
+    let data = SerializedKeyboardData::new(
+        Key::Character("A".to_string()),
+        Code::KeyA,
+        Location::Standard,
+        true,
+        Modifiers::SHIFT | Modifiers::CAPS_LOCK,
+        true,
+    );
+    let event = KeyboardData::new(data);
+    let json = serde_json::to_value(&event).unwrap();
+    let event: KeyboardData = serde_json::from_value(json).unwrap();
+
+    assert_eq!(event.key(), Key::Character("A".to_string()));
+    assert_eq!(event.code(), Code::KeyA);
+    assert_eq!(event.location(), Location::Standard);
+    assert!(event.is_auto_repeating());
+    assert!(event.is_composing());
+    assert_eq!(event.modifiers(), Modifiers::SHIFT | Modifiers::CAPS_LOCK);
+}
+
+#[test]
+fn keyboard_data_from_older_clients_only_has_the_basic_modifiers() {
+    let event: KeyboardData = serde_json::from_value(json!({
+        "char_code": 0,
+        "is_composing": false,
+        "key": "Enter",
+        "key_code": 13,
+        "code": "Enter",
+        "alt_key": false,
+        "ctrl_key": true,
+        "meta_key": false,
+        "shift_key": false,
+        "location": 0,
+        "repeat": false,
+        "which": 13
+    }))
+    .unwrap();
+    assert_eq!(event.modifiers(), Modifiers::CONTROL);
+}
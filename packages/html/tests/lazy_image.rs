@@ -0,0 +1,116 @@
+#![cfg(feature = "lazy-image")]
+
+use dioxus::prelude::*;
+use dioxus_html::document::{Document, Evaluator, NoOpDocument};
+use dioxus_html::lazy_image::{Image, ImageSource, SrcSet};
+use dioxus_testing::TestDom;
+use generational_box::GenerationalBox;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+fn render(app: fn() -> Element) -> String {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    dioxus_ssr::render(&dom)
+}
+
+#[test]
+fn lazy_images_show_a_blurred_preview() {
+    fn app() -> Element {
+        rsx! {
+            Image {
+                src: ImageSource::new("/photo.webp").preview("/preview.webp"),
+                srcset: SrcSet::new().width("/photo-480.webp", 480).width("/photo.webp", 960),
+                alt: "A crab",
+                width: 960,
+            }
+        }
+    }
+
+    let html = render(app);
+    assert!(html.contains(r#"data-src="/photo.webp""#), "{html}");
+    assert!(
+        html.contains(r#"data-srcset="/photo-480.webp 480w, /photo.webp 960w""#),
+        "{html}"
+    );
+    assert!(html.contains(r#"data-lazy="true""#), "{html}");
+    assert!(html.contains(r#"src="/preview.webp""#), "{html}");
+    assert!(html.contains("filter: blur"), "{html}");
+    assert!(html.contains(r#"alt="A crab""#), "{html}");
+    assert!(html.contains(r#"width="960""#), "{html}");
+}
+
+#[test]
+fn eager_images_without_a_preview_render_the_image() {
+    fn app() -> Element {
+        rsx! {
+            Image {
+                src: "/photo.webp",
+                srcset: SrcSet::new().width("/photo-480.webp", 480),
+                sizes: "480px",
+                alt: "A crab",
+                lazy: false,
+            }
+        }
+    }
+
+    let html = render(app);
+    assert!(html.contains(r#" src="/photo.webp""#), "{html}");
+    assert!(html.contains(r#" srcset="/photo-480.webp 480w""#), "{html}");
+    assert!(html.contains(r#" sizes="480px""#), "{html}");
+    assert!(html.contains(r#"data-lazy="false""#), "{html}");
+    assert!(!html.contains("blur"), "{html}");
+}
+
+#[tokio::test]
+async fn changing_the_source_loads_the_new_image() {
+    thread_local! {
+        static SCRIPTS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+        static SRC: Cell<Option<Signal<&'static str>>> = const { Cell::new(None) };
+    }
+
+    /// A document that records the scripts it evaluates
+    struct RecordingDocument;
+
+    impl Document for RecordingDocument {
+        fn new_evaluator(&self, js: String) -> GenerationalBox<Box<dyn Evaluator>> {
+            SCRIPTS.with(|scripts| scripts.borrow_mut().push(js));
+            NoOpDocument.new_evaluator(String::new())
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+    }
+
+    fn app() -> Element {
+        use_hook(|| provide_context(Rc::new(RecordingDocument) as Rc<dyn Document>));
+        let src = use_signal(|| "/first.webp");
+        SRC.with(|cell| cell.set(Some(src)));
+        rsx! {
+            Image { src: ImageSource::new(src()).preview("/preview.webp"), alt: "A crab", suspend: true }
+        }
+    }
+
+    let mut dom = TestDom::new(app);
+    dom.settle().await;
+    let scripts = SCRIPTS.with(|scripts| scripts.take());
+    assert!(scripts
+        .iter()
+        .any(|script| script.contains(r#""/first.webp""#)));
+
+    dom.dom()
+        .in_runtime(|| SRC.with(|cell| cell.get()).unwrap().set("/second.webp"));
+    dom.settle().await;
+    let html = dioxus_ssr::render(dom.dom());
+    assert!(html.contains(r#"data-src="/second.webp""#), "{html}");
+    let scripts = SCRIPTS.with(|scripts| scripts.take());
+    // The new image is decoded before the component resumes
+    assert!(scripts
+        .iter()
+        .any(|script| script.contains(r#""/second.webp""#)));
+    // And the script that swapped in the first image loads the new one
+    assert!(scripts
+        .iter()
+        .any(|script| script.contains("delete img.dataset.loaded;")));
+}
@@ -0,0 +1,156 @@
+#![cfg(feature = "markdown")]
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_html::markdown::{parse, parse_inline, split_blocks, Block, Inline, Markdown};
+use std::cell::Cell;
+
+fn text(text: &str) -> Inline {
+    Inline::Text(text.to_string())
+}
+
+fn render(content: &str) -> String {
+    let mut dom = VirtualDom::new_with_props(
+        |content: String| rsx! { Markdown { content } },
+        content.to_string(),
+    );
+    dom.rebuild_in_place();
+    dioxus_ssr::render(&dom)
+}
+
+#[test]
+fn parses_blocks() {
+    let blocks = parse(
+        "# Title\nSome *text*\n\n> quoted\n\n- one\n- two\n  - nested\n\n3. three\n\n---\n\n```rust\nfn main() {}\n```",
+    );
+    assert_eq!(
+        blocks,
+        [
+            Block::Heading(1, vec![text("Title")]),
+            Block::Paragraph(vec![text("Some "), Inline::Emphasis(vec![text("text")])]),
+            Block::Quote(vec![Block::Paragraph(vec![text("quoted")])]),
+            Block::List {
+                start: None,
+                items: vec![
+                    vec![Block::Paragraph(vec![text("one")])],
+                    vec![
+                        Block::Paragraph(vec![text("two")]),
+                        Block::List {
+                            start: None,
+                            items: vec![vec![Block::Paragraph(vec![text("nested")])]],
+                        },
+                    ],
+                ],
+            },
+            Block::List {
+                start: Some(3),
+                items: vec![vec![Block::Paragraph(vec![text("three")])]],
+            },
+            Block::Rule,
+            Block::Code {
+                language: Some("rust".to_string()),
+                code: "fn main() {}\n".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn parses_inline_text() {
+    assert_eq!(
+        parse_inline("a **b *c* d** `d` [e](/f \"title\") ![g](h.png) \\*i\\*"),
+        [
+            text("a "),
+            Inline::Strong(vec![
+                text("b "),
+                Inline::Emphasis(vec![text("c")]),
+                text(" d"),
+            ]),
+            text(" "),
+            Inline::Code("d".to_string()),
+            text(" "),
+            Inline::Link {
+                href: "/f".to_string(),
+                children: vec![text("e")],
+            },
+            text(" "),
+            Inline::Image {
+                src: "h.png".to_string(),
+                alt: "g".to_string(),
+            },
+            text(" *i*"),
+        ]
+    );
+    assert_eq!(parse_inline("snake_case_name"), [text("snake_case_name")]);
+    assert_eq!(
+        parse_inline("line  \nbreak"),
+        [text("line"), Inline::LineBreak, text("break")]
+    );
+}
+
+#[test]
+fn renders_elements() {
+    assert_eq!(
+        render("## Hi\n\n- **a**\n- <https://dioxuslabs.com>"),
+        "<div><h2>Hi</h2><ul><li><strong>a</strong></li><li><a href=\"https://dioxuslabs.com\">https://dioxuslabs.com</a></li></ul></div>"
+    );
+    assert_eq!(
+        render("```\n<b>code</b>\n```"),
+        "<div><pre><code>&lt;b&gt;code&lt;/b&gt;\n</code></pre></div>"
+    );
+}
+
+#[test]
+fn sanitizes_html_and_urls() {
+    assert_eq!(
+        render(
+            "Hi <script>alert(1)</script> [click](javascript:alert(1)) ![x](data:image/png;base64,AA)"
+        ),
+        "<div><p>Hi &lt;script&gt;alert(1)&lt;/script&gt; click x</p></div>"
+    );
+    assert_eq!(
+        render("<div onclick=\"alert(1)\">hi</div>"),
+        "<div><p>&lt;div onclick=&quot;alert(1)&quot;&gt;hi&lt;/div&gt;</p></div>"
+    );
+}
+
+#[test]
+fn splits_blocks_outside_of_code() {
+    assert_eq!(
+        split_blocks("# a\n\nb\nc\n\n```\nd\n\ne\n```\n\n"),
+        ["# a\n", "b\nc\n", "```\nd\n\ne\n```\n"]
+    );
+}
+
+#[test]
+fn only_changed_blocks_render_again() {
+    thread_local! {
+        static CONTENT: Cell<Option<Signal<String>>> = const { Cell::new(None) };
+    }
+
+    fn app() -> Element {
+        let content = use_signal(|| "# Title\n\nstream".to_string());
+        CONTENT.with(|cell| cell.set(Some(content)));
+        rsx! { Markdown { content: content() } }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let mut content = CONTENT.with(|cell| cell.get()).unwrap();
+
+    dom.in_runtime(|| content.write().push_str("ing *text*"));
+    let mutations = dom.render_immediate_to_vec();
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        "<div><h1>Title</h1><p>streaming <em>text</em></p></div>"
+    );
+    // The heading wasn't touched
+    assert!(!format!("{:?}", mutations.edits).contains("Title"));
+
+    dom.in_runtime(|| content.write().push_str("\n\nmore"));
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        "<div><h1>Title</h1><p>streaming <em>text</em></p><p>more</p></div>"
+    );
+}
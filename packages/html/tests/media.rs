@@ -0,0 +1,56 @@
+#![cfg(feature = "media")]
+
+use dioxus::prelude::*;
+use dioxus_html::media::{use_audio_player, use_audio_recorder, AudioPlayer, AudioRecorder};
+use std::cell::Cell;
+
+#[test]
+fn player_reports_an_error_without_a_renderer() {
+    thread_local! {
+        static PLAYER: Cell<Option<AudioPlayer>> = const { Cell::new(None) };
+    }
+
+    fn app() -> Element {
+        let player = use_audio_player("/assets/song.mp3");
+        PLAYER.with(|cell| cell.set(Some(player)));
+        rsx! {}
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let player = PLAYER.with(|cell| cell.get()).unwrap();
+    dom.in_runtime(|| {
+        assert!(!player.playing());
+        assert_eq!(player.current_time(), 0.0);
+        assert_eq!(player.duration(), None);
+        assert_eq!(player.volume(), 1.0);
+        assert_eq!(player.level(), 0.0);
+        assert!(player.error().is_some());
+    });
+}
+
+#[test]
+fn recorder_reports_an_error_when_started_without_a_renderer() {
+    thread_local! {
+        static RECORDER: Cell<Option<AudioRecorder>> = const { Cell::new(None) };
+    }
+
+    fn app() -> Element {
+        let recorder = use_audio_recorder();
+        RECORDER.with(|cell| cell.set(Some(recorder)));
+        rsx! {}
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let recorder = RECORDER.with(|cell| cell.get()).unwrap();
+    dom.in_runtime(|| {
+        assert!(!recorder.recording());
+        assert!(recorder.recordings().read().is_empty());
+        assert!(recorder.error().is_none());
+
+        recorder.start();
+        assert!(recorder.error().is_some());
+        assert!(!recorder.recording());
+    });
+}
@@ -0,0 +1,100 @@
+#![cfg(feature = "portal")]
+
+use dioxus::prelude::*;
+use dioxus_html::document::{Document, Evaluator, NoOpDocument};
+use dioxus_html::portal::{Portal, PortalTarget};
+use dioxus_testing::TestDom;
+use generational_box::GenerationalBox;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+thread_local! {
+    static SCRIPTS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A document that records the scripts it evaluates
+struct RecordingDocument;
+
+impl Document for RecordingDocument {
+    fn new_evaluator(&self, js: String) -> GenerationalBox<Box<dyn Evaluator>> {
+        SCRIPTS.with(|scripts| scripts.borrow_mut().push(js));
+        NoOpDocument.new_evaluator(String::new())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[test]
+fn children_keep_the_context_of_the_portal() {
+    #[derive(Clone)]
+    struct Theme(&'static str);
+
+    fn app() -> Element {
+        use_context_provider(|| Theme("dark"));
+        rsx! {
+            div { overflow: "hidden",
+                Portal { target: "modals", Themed {} }
+            }
+        }
+    }
+
+    #[component]
+    fn Themed() -> Element {
+        let theme = use_context::<Theme>();
+        rsx! { "{theme.0}" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let html = dioxus_ssr::render(&dom);
+    assert!(
+        html.contains(r#"style="display: contents"><div data-dioxus-portal-content="#),
+        "{html}"
+    );
+    assert!(html.contains(">dark</div></div></div>"), "{html}");
+}
+
+#[test]
+fn targets_are_found_by_id_or_selector() {
+    assert_eq!(PortalTarget::default(), PortalTarget::Body);
+    assert_eq!(
+        PortalTarget::from("modals"),
+        PortalTarget::Id("modals".to_string())
+    );
+}
+
+#[tokio::test]
+async fn moves_children_to_the_target_and_removes_them_on_unmount() {
+    thread_local! {
+        static OPEN: Cell<Option<Signal<bool>>> = const { Cell::new(None) };
+    }
+
+    fn app() -> Element {
+        use_hook(|| provide_context(Rc::new(RecordingDocument) as Rc<dyn Document>));
+        let open = use_signal(|| true);
+        OPEN.with(|cell| cell.set(Some(open)));
+        rsx! {
+            if open() {
+                Portal { target: PortalTarget::Selector("#modals > .layer".to_string()), "dialog" }
+            }
+        }
+    }
+
+    let mut dom = TestDom::new(app);
+    dom.settle().await;
+
+    let mounted = SCRIPTS.with(|scripts| scripts.take());
+    assert_eq!(mounted.len(), 1);
+    assert!(mounted[0].contains(r##"const target = document.querySelector("#modals > .layer");"##));
+    assert!(mounted[0].contains("target.appendChild(content);"));
+
+    dom.dom()
+        .in_runtime(|| OPEN.with(|cell| cell.get()).unwrap().set(false));
+    dom.settle().await;
+
+    let unmounted = SCRIPTS.with(|scripts| scripts.take());
+    assert_eq!(unmounted.len(), 1);
+    assert!(unmounted[0].contains("content.remove();"));
+}
@@ -0,0 +1,168 @@
+#![cfg(feature = "presence")]
+
+use dioxus::prelude::*;
+use dioxus_core::{Mutation, NoOpMutations};
+use dioxus_html::presence::{AnimatePresence, TransitionGroup};
+use dioxus_html::SerializedAnimationData;
+use std::any::Any;
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+thread_local! {
+    static OPEN: Cell<Option<Signal<bool>>> = const { Cell::new(None) };
+    static ITEMS: Cell<Option<Signal<Vec<u32>>>> = const { Cell::new(None) };
+}
+
+fn app() -> Element {
+    let open = use_signal(|| true);
+    OPEN.with(|cell| cell.set(Some(open)));
+    rsx! {
+        AnimatePresence { present: open(), class: "toast", exit_class: "leaving", timeout: None,
+            "Saved!"
+        }
+    }
+}
+
+fn set_open(dom: &mut VirtualDom, value: bool) {
+    let mut open = OPEN.with(|cell| cell.get()).unwrap();
+    dom.in_runtime(|| open.set(value));
+    dom.render_immediate(&mut NoOpMutations);
+}
+
+#[test]
+fn children_stay_mounted_until_the_exit_animation_ends() {
+    set_event_converter(Box::new(dioxus_html::SerializedHtmlEventConverter));
+
+    let mut dom = VirtualDom::new(app);
+    let mutations = dom.rebuild_to_vec();
+    let wrapper = mutations
+        .edits
+        .iter()
+        .find_map(|edit| match edit {
+            Mutation::NewEventListener { name, id } if name == "animationend" => Some(*id),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        r#"<div class="toast" data-state="open">Saved!</div>"#
+    );
+
+    set_open(&mut dom, false);
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        r#"<div class="toast leaving" data-state="closed">Saved!</div>"#
+    );
+
+    let data: SerializedAnimationData = serde_json::from_value(serde_json::json!({
+        "animation_name": "fade-out",
+        "pseudo_element": "",
+        "elapsed_time": 0.2,
+    }))
+    .unwrap();
+    let event = Event::new(
+        Rc::new(PlatformEventData::new(Box::new(data))) as Rc<dyn Any>,
+        true,
+    );
+    dom.runtime().handle_event("animationend", event, wrapper);
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&dom), "");
+
+    // The children enter again
+    set_open(&mut dom, true);
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        r#"<div class="toast" data-state="open">Saved!</div>"#
+    );
+}
+
+#[test]
+fn children_are_removed_after_the_timeout() {
+    fn app() -> Element {
+        let open = use_signal(|| true);
+        OPEN.with(|cell| cell.set(Some(open)));
+        rsx! {
+            AnimatePresence { present: open(), timeout: Duration::ZERO, "Saved!" }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    set_open(&mut dom, false);
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        r#"<div data-state="closed">Saved!</div>"#
+    );
+
+    dom.process_events();
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&dom), "");
+}
+
+fn animation_end() -> Event<dyn Any> {
+    let data: SerializedAnimationData = serde_json::from_value(serde_json::json!({
+        "animation_name": "fade-out",
+        "pseudo_element": "",
+        "elapsed_time": 0.2,
+    }))
+    .unwrap();
+    Event::new(
+        Rc::new(PlatformEventData::new(Box::new(data))) as Rc<dyn Any>,
+        true,
+    )
+}
+
+#[test]
+fn removed_list_items_stay_until_their_exit_animation_ends() {
+    set_event_converter(Box::new(dioxus_html::SerializedHtmlEventConverter));
+
+    fn app() -> Element {
+        let items = use_signal(|| vec![1, 2, 3]);
+        ITEMS.with(|cell| cell.set(Some(items)));
+        rsx! {
+            TransitionGroup { exit_class: "leaving", timeout: None,
+                for item in items() {
+                    span { key: "{item}", "{item}" }
+                }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    let mutations = dom.rebuild_to_vec();
+    let wrappers = mutations
+        .edits
+        .iter()
+        .filter_map(|edit| match edit {
+            Mutation::NewEventListener { name, id } if name == "animationend" => Some(*id),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(wrappers.len(), 3);
+
+    let mut items = ITEMS.with(|cell| cell.get()).unwrap();
+    dom.in_runtime(|| items.set(vec![1, 3, 4]));
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        concat!(
+            r#"<div data-state="open"><span>1</span></div>"#,
+            r#"<div class="leaving" data-state="closed"><span>2</span></div>"#,
+            r#"<div data-state="open"><span>3</span></div>"#,
+            r#"<div data-state="open"><span>4</span></div>"#,
+        )
+    );
+
+    dom.runtime()
+        .handle_event("animationend", animation_end(), wrappers[1]);
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        concat!(
+            r#"<div data-state="open"><span>1</span></div>"#,
+            r#"<div data-state="open"><span>3</span></div>"#,
+            r#"<div data-state="open"><span>4</span></div>"#,
+        )
+    );
+}
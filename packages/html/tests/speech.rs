@@ -0,0 +1,215 @@
+#![cfg(feature = "speech")]
+
+use dioxus::prelude::*;
+use dioxus_html::speech::*;
+use dioxus_testing::TestDom;
+use futures_channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+use futures_util::StreamExt;
+use std::cell::{Cell, RefCell};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+#[test]
+fn synthesis_reports_an_error_without_a_renderer() {
+    thread_local! {
+        static SYNTHESIS: Cell<Option<SpeechSynthesis>> = const { Cell::new(None) };
+    }
+
+    fn app() -> Element {
+        let synthesis = use_speech_synthesis();
+        SYNTHESIS.with(|cell| cell.set(Some(synthesis)));
+        rsx! {}
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let synthesis = SYNTHESIS.with(|cell| cell.get()).unwrap();
+    dom.in_runtime(|| {
+        assert!(!synthesis.speaking());
+        assert!(synthesis.voices().is_empty());
+        synthesis.speak("Hello");
+        assert!(synthesis.error().is_some());
+    });
+}
+
+#[test]
+fn recognition_reports_an_error_without_a_renderer() {
+    thread_local! {
+        static RECOGNITION: Cell<Option<SpeechRecognition>> = const { Cell::new(None) };
+    }
+
+    fn app() -> Element {
+        let recognition = use_speech_recognition();
+        RECOGNITION.with(|cell| cell.set(Some(recognition)));
+        rsx! {}
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let recognition = RECOGNITION.with(|cell| cell.get()).unwrap();
+    dom.in_runtime(|| {
+        assert!(!recognition.listening());
+        recognition.start();
+        assert!(recognition.error().is_some());
+        assert_eq!(recognition.transcript(), "");
+    });
+}
+
+/// A backend that records the commands and lets the test send the events
+struct FakeConnection<C, E> {
+    commands: Rc<RefCell<Vec<C>>>,
+    events: Rc<RefCell<UnboundedReceiver<E>>>,
+}
+
+impl<C, E> FakeConnection<C, E> {
+    fn new() -> (Rc<Self>, UnboundedSender<E>) {
+        let (sender, receiver) = unbounded();
+        let connection = Rc::new(Self {
+            commands: Default::default(),
+            events: Rc::new(RefCell::new(receiver)),
+        });
+        (connection, sender)
+    }
+}
+
+impl<C, E: 'static> SpeechConnection<C, E> for FakeConnection<C, E> {
+    fn send(&self, command: C) -> Result<(), String> {
+        self.commands.borrow_mut().push(command);
+        Ok(())
+    }
+
+    fn recv(&self) -> Pin<Box<dyn Future<Output = Option<E>>>> {
+        let events = self.events.clone();
+        Box::pin(
+            async move { std::future::poll_fn(|cx| events.borrow_mut().poll_next_unpin(cx)).await },
+        )
+    }
+
+    fn close(&self) {
+        self.events.borrow_mut().close();
+    }
+}
+
+type Synthesis = Rc<FakeConnection<SynthesisCommand, SynthesisEvent>>;
+type Recognition = Rc<FakeConnection<RecognitionCommand, RecognitionEvent>>;
+
+struct FakeBackend {
+    synthesis: Synthesis,
+    recognition: Recognition,
+}
+
+impl SpeechBackend for FakeBackend {
+    fn synthesis(&self) -> Rc<dyn SpeechConnection<SynthesisCommand, SynthesisEvent>> {
+        self.synthesis.clone()
+    }
+
+    fn recognition(&self) -> Rc<dyn SpeechConnection<RecognitionCommand, RecognitionEvent>> {
+        self.recognition.clone()
+    }
+}
+
+thread_local! {
+    static BACKEND: RefCell<Option<(Synthesis, Recognition)>> = const { RefCell::new(None) };
+    static HOOKS: Cell<Option<(SpeechSynthesis, SpeechRecognition)>> = const { Cell::new(None) };
+}
+
+fn app() -> Element {
+    use_hook(|| {
+        let (synthesis, recognition) = BACKEND.with(|backend| backend.borrow().clone()).unwrap();
+        provide_speech_backend(FakeBackend {
+            synthesis,
+            recognition,
+        });
+    });
+    let synthesis = use_speech_synthesis();
+    let recognition = use_speech_recognition();
+    HOOKS.with(|cell| cell.set(Some((synthesis, recognition))));
+    rsx! {}
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn hooks_follow_the_backend() {
+    let (synthesis_connection, synthesis_events) = FakeConnection::new();
+    let (recognition_connection, recognition_events) = FakeConnection::new();
+    BACKEND.with(|backend| {
+        *backend.borrow_mut() = Some((synthesis_connection.clone(), recognition_connection.clone()))
+    });
+
+    let mut dom = TestDom::new(app);
+    let (synthesis, recognition) = HOOKS.with(|cell| cell.get()).unwrap();
+
+    // Speaking sends the utterance and follows the state of the synthesis
+    dom.dom()
+        .in_runtime(|| synthesis.speak_utterance(Utterance::new("Hello").rate(2.0)));
+    assert_eq!(
+        synthesis_connection.commands.take(),
+        [SynthesisCommand::Speak(Utterance::new("Hello").rate(2.0))]
+    );
+    synthesis_events
+        .unbounded_send(SynthesisEvent::State {
+            speaking: true,
+            paused: false,
+        })
+        .unwrap();
+    synthesis_events
+        .unbounded_send(SynthesisEvent::Boundary { char_index: 0 })
+        .unwrap();
+    dom.settle().await;
+    dom.dom().in_runtime(|| {
+        assert!(synthesis.speaking());
+        assert_eq!(synthesis.char_index(), Some(0));
+    });
+    synthesis_events
+        .unbounded_send(SynthesisEvent::State {
+            speaking: false,
+            paused: false,
+        })
+        .unwrap();
+    dom.settle().await;
+    dom.dom().in_runtime(|| {
+        assert!(!synthesis.speaking());
+        assert_eq!(synthesis.char_index(), None);
+    });
+
+    // Final results are added to the transcript and interim results replace each other
+    dom.dom().in_runtime(|| recognition.start());
+    assert_eq!(
+        recognition_connection.commands.take(),
+        [RecognitionCommand::Start(RecognitionOptions::default())]
+    );
+    let result = |transcript: &str, is_final| RecognitionEvent::Result {
+        transcript: transcript.to_string(),
+        is_final,
+        confidence: 0.9,
+    };
+    for event in [
+        RecognitionEvent::State { listening: true },
+        result("hello", false),
+        result("hello world", true),
+        result("how", false),
+    ] {
+        recognition_events.unbounded_send(event).unwrap();
+    }
+    dom.settle().await;
+    dom.dom().in_runtime(|| {
+        assert!(recognition.listening());
+        assert_eq!(recognition.transcript(), "hello world");
+        assert_eq!(recognition.interim(), "how");
+        assert_eq!(recognition.confidence(), Some(0.9));
+    });
+    for event in [
+        result(" how are you", true),
+        RecognitionEvent::State { listening: false },
+    ] {
+        recognition_events.unbounded_send(event).unwrap();
+    }
+    dom.settle().await;
+    dom.dom().in_runtime(|| {
+        assert!(!recognition.listening());
+        assert_eq!(recognition.transcript(), "hello world how are you");
+        assert_eq!(recognition.interim(), "");
+        recognition.clear();
+        assert_eq!(recognition.transcript(), "");
+    });
+}
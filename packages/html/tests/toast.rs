@@ -0,0 +1,119 @@
+#![cfg(feature = "toast")]
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_html::toast::{use_toast, Toast, ToastContext, Toaster};
+use std::any::Any;
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+thread_local! {
+    static TOASTER: Cell<Option<ToastContext>> = const { Cell::new(None) };
+}
+
+#[component]
+fn Capture() -> Element {
+    let toaster = use_toast();
+    TOASTER.with(|cell| cell.set(Some(toaster)));
+    rsx! {}
+}
+
+fn toaster() -> ToastContext {
+    TOASTER.with(|cell| cell.get()).unwrap()
+}
+
+#[test]
+fn queues_toasts_that_do_not_fit() {
+    fn app() -> Element {
+        rsx! {
+            Toaster { max_visible: 2, default_duration: None, Capture {} }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    dom.in_runtime(|| {
+        let toaster = toaster();
+        let first = toaster.show(Toast::new("first"));
+        let second = toaster.show(Toast::new("second"));
+        let third = toaster.show(Toast::new("third"));
+        assert_eq!(toaster.visible(), [first, second]);
+        assert_eq!(toaster.queued(), [third]);
+
+        toaster.dismiss(first);
+        assert_eq!(toaster.visible(), [second, third]);
+        assert!(toaster.queued().is_empty());
+
+        toaster.dismiss_all();
+        assert!(toaster.visible().is_empty());
+    });
+    dom.render_immediate(&mut NoOpMutations);
+}
+
+#[test]
+fn dismisses_toasts_after_their_duration() {
+    fn app() -> Element {
+        rsx! {
+            Toaster { Capture {} }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    let (sticky, _) = dom.in_runtime(|| {
+        let toaster = toaster();
+        (
+            toaster.show(Toast::new("sticky").duration(None)),
+            toaster.show(Toast::new("gone").duration(Some(Duration::ZERO))),
+        )
+    });
+    dom.render_immediate(&mut NoOpMutations);
+    dom.process_events();
+    dom.render_immediate(&mut NoOpMutations);
+
+    dom.in_runtime(|| assert_eq!(toaster().visible(), [sticky]));
+}
+
+#[test]
+fn actions_run_and_dismiss_the_toast() {
+    set_event_converter(Box::new(dioxus_html::SerializedHtmlEventConverter));
+
+    thread_local! {
+        static UNDONE: Cell<bool> = const { Cell::new(false) };
+    }
+
+    fn app() -> Element {
+        rsx! {
+            Toaster { default_duration: None, Capture {} }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    dom.in_runtime(|| {
+        toaster().show(Toast::new("deleted").action("Undo", || UNDONE.with(|u| u.set(true))));
+    });
+    let mutations = dom.render_immediate_to_vec();
+    // The action is the first button of the toast
+    let action = mutations
+        .edits
+        .iter()
+        .find_map(|edit| match edit {
+            dioxus_core::Mutation::NewEventListener { name, id } if name == "click" => Some(*id),
+            _ => None,
+        })
+        .unwrap();
+
+    let event = Event::new(
+        Rc::new(PlatformEventData::new(Box::<SerializedMouseData>::default())) as Rc<dyn Any>,
+        true,
+    );
+    dom.runtime().handle_event("click", event, action);
+    dom.render_immediate(&mut NoOpMutations);
+
+    assert!(UNDONE.with(|u| u.get()));
+    dom.in_runtime(|| assert!(toaster().visible().is_empty()));
+}
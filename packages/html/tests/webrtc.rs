@@ -0,0 +1,51 @@
+#![cfg(feature = "webrtc")]
+
+use dioxus::prelude::*;
+use dioxus_html::webrtc::{use_webrtc, PeerState, SignalingMessage, UseWebRtc, WebRtcConfig};
+use serde_json::json;
+use std::cell::Cell;
+
+#[test]
+fn signaling_messages_match_the_browser_format() {
+    let offer = SignalingMessage::Offer {
+        sdp: "v=0".to_string(),
+    };
+    assert_eq!(
+        serde_json::to_value(&offer).unwrap(),
+        json!({ "type": "offer", "sdp": "v=0" })
+    );
+
+    let candidate: SignalingMessage = serde_json::from_value(json!({
+        "type": "candidate",
+        "candidate": { "candidate": "candidate:1 1 udp 1 127.0.0.1 9 typ host", "sdpMid": "0" },
+    }))
+    .unwrap();
+    assert_eq!(
+        candidate,
+        SignalingMessage::Candidate {
+            candidate: json!({ "candidate": "candidate:1 1 udp 1 127.0.0.1 9 typ host", "sdpMid": "0" })
+        }
+    );
+}
+
+#[test]
+fn reports_an_error_without_a_renderer() {
+    thread_local! {
+        static RTC: Cell<Option<UseWebRtc>> = const { Cell::new(None) };
+    }
+
+    fn app() -> Element {
+        let rtc = use_webrtc(WebRtcConfig::new().ice_server("stun:stun.example.com"));
+        RTC.with(|cell| cell.set(Some(rtc)));
+        rsx! {}
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let rtc = RTC.with(|cell| cell.get()).unwrap();
+    dom.in_runtime(|| {
+        assert_eq!(rtc.state(), PeerState::New);
+        assert!(rtc.error().is_some());
+        assert!(!rtc.channel("chat").is_open());
+    });
+}
@@ -0,0 +1,101 @@
+//! Read the keys of the bundles in the `locales` directory of the crate that is being compiled
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// A bundle in the locales directory
+struct BundleKeys {
+    file: String,
+    path: PathBuf,
+    keys: HashSet<String>,
+}
+
+/// Check that every bundle in the locales directory contains the key. Returns the paths of the bundles that were
+/// read, so the macro can make the crate depend on them.
+pub(crate) fn check_key(key: &str) -> Result<Vec<PathBuf>, String> {
+    let Some(dir) = locales_dir() else {
+        return Ok(Vec::new());
+    };
+    let bundles = read_bundles(&dir)?;
+
+    let missing: Vec<_> = bundles
+        .iter()
+        .filter(|bundle| !bundle.keys.contains(key))
+        .map(|bundle| bundle.file.as_str())
+        .collect();
+
+    match missing.as_slice() {
+        [] => Ok(bundles.into_iter().map(|bundle| bundle.path).collect()),
+        missing => Err(format!(
+            "The message `{key}` is missing from {}",
+            missing.join(", ")
+        )),
+    }
+}
+
+fn locales_dir() -> Option<PathBuf> {
+    let dir = Path::new(&std::env::var("CARGO_MANIFEST_DIR").ok()?).join("locales");
+    dir.is_dir().then_some(dir)
+}
+
+/// Read the keys of every bundle in the directory, sorted by the file name
+fn read_bundles(dir: &Path) -> Result<Vec<BundleKeys>, String> {
+    let entries =
+        std::fs::read_dir(dir).map_err(|err| format!("Failed to read {}: {err}", dir.display()))?;
+
+    let mut bundles = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let file = entry.file_name().to_string_lossy().to_string();
+        let extension = path.extension().and_then(|ext| ext.to_str());
+        if !matches!(extension, Some("ftl" | "json")) {
+            continue;
+        }
+        let source = std::fs::read_to_string(&path)
+            .map_err(|err| format!("Failed to read {}: {err}", path.display()))?;
+        let keys = match extension {
+            Some("json") => {
+                json_keys(&source).map_err(|err| format!("Failed to parse {file}: {err}"))?
+            }
+            _ => fluent_keys(&source),
+        };
+        bundles.push(BundleKeys { file, path, keys });
+    }
+    bundles.sort_by(|a, b| a.file.cmp(&b.file));
+    Ok(bundles)
+}
+
+/// Nested objects are flattened into keys separated with a dot, like the bundles at runtime
+fn json_keys(source: &str) -> Result<HashSet<String>, serde_json::Error> {
+    fn flatten(prefix: &str, value: &serde_json::Value, keys: &mut HashSet<String>) {
+        match value {
+            serde_json::Value::Object(object) => {
+                for (key, value) in object {
+                    let key = match prefix {
+                        "" => key.clone(),
+                        _ => format!("{prefix}.{key}"),
+                    };
+                    flatten(&key, value, keys);
+                }
+            }
+            _ => {
+                keys.insert(prefix.to_string());
+            }
+        }
+    }
+
+    let mut keys = HashSet::new();
+    flatten("", &serde_json::from_str(source)?, &mut keys);
+    Ok(keys)
+}
+
+/// Every line that is not indented or a comment starts a message. Syntax errors are reported when the bundle is
+/// loaded at runtime.
+fn fluent_keys(source: &str) -> HashSet<String> {
+    source
+        .lines()
+        .filter(|line| !line.starts_with([' ', '\t', '#']))
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, _)| key.trim().to_string())
+        .collect()
+}
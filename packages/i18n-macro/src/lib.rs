@@ -0,0 +1,99 @@
+#![doc(html_logo_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![doc(html_favicon_url = "https://avatars.githubusercontent.com/u/79236386")]
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    Expr, Ident, LitStr, Token,
+};
+
+mod keys;
+
+/// Translate a message in the current locale.
+///
+/// The first argument is the key of the message. Named arguments fill in the `{name}` placeholders of the message. An
+/// argument can be written as `name` if a variable with the same name is in scope.
+///
+/// ```rust, ignore
+/// let name = "Dioxus";
+/// rsx! {
+///     p { {t!("greeting", name)} }
+///     p { {t!("unread", count = messages.len())} }
+/// }
+/// ```
+///
+/// The key is checked at compile time against every `.ftl` and `.json` bundle in the `locales` directory next to the
+/// `Cargo.toml` of the crate that calls the macro. If a bundle is missing the key, the macro fails to compile. If
+/// there is no `locales` directory, the key is not checked.
+///
+/// The macro expands to a call to `dioxus_i18n::translate`, which resolves either to the `dioxus_i18n` crate or to the
+/// re-export in the `dioxus` prelude.
+#[proc_macro]
+pub fn t(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as Translate);
+    let key = &input.key;
+
+    let bundles = match keys::check_key(&key.value()) {
+        Ok(bundles) => bundles,
+        Err(err) => return syn::Error::new(key.span(), err).to_compile_error().into(),
+    };
+    // Include the bundles so cargo recompiles the crate when one of them changes
+    let bundles = bundles
+        .iter()
+        .map(|path| path.to_string_lossy().to_string());
+
+    let args = input.args.iter().map(|arg| {
+        let name = arg.name.to_string();
+        let value = match &arg.value {
+            Some(value) => quote! { #value },
+            None => {
+                let name = &arg.name;
+                quote! { #name }
+            }
+        };
+        quote! { (#name, &(#value) as &dyn ::std::fmt::Display) }
+    });
+
+    quote! {
+        {
+            #(const _: &[u8] = include_bytes!(#bundles);)*
+            dioxus_i18n::translate(#key, &[#(#args),*])
+        }
+    }
+    .into()
+}
+
+struct Translate {
+    key: LitStr,
+    args: Punctuated<Argument, Token![,]>,
+}
+
+impl Parse for Translate {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key = input.parse()?;
+        let args = match input.parse::<Option<Token![,]>>()? {
+            Some(_) => Punctuated::parse_terminated(input)?,
+            None => Punctuated::new(),
+        };
+        Ok(Self { key, args })
+    }
+}
+
+struct Argument {
+    name: Ident,
+    value: Option<Expr>,
+}
+
+impl Parse for Argument {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        let value = match input.parse::<Option<Token![=]>>()? {
+            Some(_) => Some(input.parse()?),
+            None => None,
+        };
+        Ok(Self { name, value })
+    }
+}
@@ -0,0 +1,6 @@
+# The messages used by the tests of dioxus-i18n
+greeting = Hello { $name }!
+unread = You have { $count } unread messages
+farewell =
+    Goodbye,
+    see you soon
@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+/// An error that occurred while loading a bundle
+#[derive(Debug, thiserror::Error)]
+pub enum BundleError {
+    /// The json bundle could not be parsed
+    #[error("Failed to parse the json bundle: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// A value in the json bundle is not a string or an object of strings
+    #[error("The message `{0}` must be a string or an object")]
+    InvalidJsonMessage(String),
+
+    /// A line in the fluent bundle could not be parsed
+    #[error("Failed to parse line {line} of the fluent bundle: {message}")]
+    Fluent {
+        /// The line the error occurred on, starting at 1
+        line: usize,
+        /// What went wrong
+        message: String,
+    },
+}
+
+/// The messages of one locale.
+///
+/// Messages can contain named arguments in braces like `Hello {name}`. Bundles can be loaded from json or a subset of
+/// [fluent](https://projectfluent.org/):
+///
+/// ```json
+/// {
+///     "greeting": "Hello {name}!",
+///     "menu": { "home": "Home" }
+/// }
+/// ```
+///
+/// ```ftl
+/// # Comments start with a hash
+/// greeting = Hello { $name }!
+/// menu-home = Home
+/// ```
+///
+/// Nested json objects are flattened into keys separated with a dot, so the second message above is `menu.home`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bundle {
+    locale: String,
+    messages: HashMap<String, String>,
+}
+
+impl Bundle {
+    /// Create a bundle from a list of messages
+    pub fn new(
+        locale: impl Into<String>,
+        messages: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>,
+    ) -> Self {
+        Self {
+            locale: locale.into(),
+            messages: messages
+                .into_iter()
+                .map(|(key, message)| (key.into(), message.into()))
+                .collect(),
+        }
+    }
+
+    /// Load a bundle from a json object of messages
+    pub fn from_json(locale: impl Into<String>, json: &str) -> Result<Self, BundleError> {
+        fn flatten(
+            prefix: &str,
+            value: serde_json::Value,
+            messages: &mut HashMap<String, String>,
+        ) -> Result<(), BundleError> {
+            match value {
+                serde_json::Value::String(message) => {
+                    messages.insert(prefix.to_string(), message);
+                }
+                serde_json::Value::Object(object) => {
+                    for (key, value) in object {
+                        let key = match prefix {
+                            "" => key,
+                            _ => format!("{prefix}.{key}"),
+                        };
+                        flatten(&key, value, messages)?;
+                    }
+                }
+                _ => return Err(BundleError::InvalidJsonMessage(prefix.to_string())),
+            }
+            Ok(())
+        }
+
+        let mut messages = HashMap::new();
+        flatten("", serde_json::from_str(json)?, &mut messages)?;
+        Ok(Self {
+            locale: locale.into(),
+            messages,
+        })
+    }
+
+    /// Load a bundle from a fluent file. Messages, comments, multiline messages and variables are supported. Terms,
+    /// attributes and selectors are not.
+    pub fn from_fluent(locale: impl Into<String>, source: &str) -> Result<Self, BundleError> {
+        let mut messages = HashMap::new();
+        let mut current: Option<(String, String)> = None;
+
+        for (idx, line) in source.lines().enumerate() {
+            let error = |message: &str| BundleError::Fluent {
+                line: idx + 1,
+                message: message.to_string(),
+            };
+
+            // Indented lines continue the last message
+            if line.starts_with([' ', '\t']) && !line.trim().is_empty() {
+                let Some((_, message)) = current.as_mut() else {
+                    return Err(error("an indented line must follow a message"));
+                };
+                if !message.is_empty() {
+                    message.push('\n');
+                }
+                message.push_str(line.trim());
+                continue;
+            }
+
+            messages.extend(current.take());
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((key, message)) = line.split_once('=') else {
+                return Err(error("expected `key = message`"));
+            };
+            let key = key.trim();
+            if key.is_empty()
+                || !key
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            {
+                return Err(error(
+                    "message keys may only contain letters, numbers, `-` and `_`",
+                ));
+            }
+            current = Some((key.to_string(), message.trim().to_string()));
+        }
+        messages.extend(current);
+
+        // Fluent variables are written as `{ $name }`
+        let messages = messages
+            .into_iter()
+            .map(|(key, message)| (key, normalize_fluent_variables(&message)))
+            .collect();
+
+        Ok(Self {
+            locale: locale.into(),
+            messages,
+        })
+    }
+
+    /// The locale of the messages, like `en-US`
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// Get the raw message for a key
+    pub fn message(&self, key: &str) -> Option<&str> {
+        self.messages.get(key).map(String::as_str)
+    }
+
+    /// Format the message for a key with the given arguments. Arguments that are missing from `args` are left as is.
+    pub fn format(&self, key: &str, args: &[(&str, &dyn Display)]) -> Option<String> {
+        let message = self.message(key)?;
+        let mut out = String::with_capacity(message.len());
+        let mut rest = message;
+        while let Some(start) = rest.find('{') {
+            out.push_str(&rest[..start]);
+            let Some(len) = rest[start..].find('}') else {
+                out.push_str(&rest[start..]);
+                return Some(out);
+            };
+            let name = &rest[start + 1..start + len];
+            match args.iter().find(|(arg, _)| *arg == name) {
+                Some((_, value)) => out.push_str(&value.to_string()),
+                None => out.push_str(&rest[start..=start + len]),
+            }
+            rest = &rest[start + len + 1..];
+        }
+        out.push_str(rest);
+        Some(out)
+    }
+}
+
+fn normalize_fluent_variables(message: &str) -> String {
+    let mut out = String::with_capacity(message.len());
+    let mut rest = message;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let Some(len) = rest[start..].find('}') else {
+            out.push_str(&rest[start..]);
+            return out;
+        };
+        let inner = rest[start + 1..start + len].trim();
+        match inner.strip_prefix('$') {
+            Some(name) => out.push_str(&format!("{{{name}}}")),
+            // String literals like `{ "{" }` are used to escape braces
+            None => out.push_str(inner.trim_matches('"')),
+        }
+        rest = &rest[start + len + 1..];
+    }
+    out.push_str(rest);
+    out
+}
@@ -0,0 +1,149 @@
+use crate::Bundle;
+use dioxus_core::prelude::{consume_context, provide_context, try_consume_context, use_hook};
+use dioxus_signals::{CopyValue, Readable, Signal, Writable};
+use std::fmt::Display;
+
+/// The translations of the app and the current locale. Create it once near the root of the app with
+/// [`use_init_i18n`] and read it anywhere below that with [`use_i18n`] or the [`t!`](crate::t) macro.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct I18n {
+    locale: Signal<String>,
+    fallback: CopyValue<String>,
+    bundles: CopyValue<Vec<Bundle>>,
+}
+
+impl I18n {
+    /// Create a new set of translations. The fallback locale is used for any message that is missing from the
+    /// current locale.
+    pub fn new(
+        locale: impl Into<String>,
+        fallback: impl Into<String>,
+        bundles: impl IntoIterator<Item = Bundle>,
+    ) -> Self {
+        Self {
+            locale: Signal::new(locale.into()),
+            fallback: CopyValue::new(fallback.into()),
+            bundles: CopyValue::new(bundles.into_iter().collect()),
+        }
+    }
+
+    /// The signal that holds the current locale. Writing to it re-renders every component that translated a message.
+    pub fn locale(&self) -> Signal<String> {
+        self.locale
+    }
+
+    /// The locale used for messages that are missing from the current locale
+    pub fn fallback(&self) -> String {
+        self.fallback.cloned()
+    }
+
+    /// Switch to a different locale
+    pub fn set_locale(&mut self, locale: impl Into<String>) {
+        self.locale.set(locale.into());
+    }
+
+    /// Add a bundle or replace the bundle with the same locale
+    pub fn add_bundle(&mut self, bundle: Bundle) {
+        let mut bundles = self.bundles.write();
+        bundles.retain(|existing| existing.locale() != bundle.locale());
+        bundles.push(bundle);
+        // Components that already translated a message need to pick up the new messages
+        drop(bundles);
+        self.locale.write();
+    }
+
+    /// The locales that have a bundle
+    pub fn locales(&self) -> Vec<String> {
+        self.bundles
+            .read()
+            .iter()
+            .map(|bundle| bundle.locale().to_string())
+            .collect()
+    }
+
+    /// Translate the message for a key in the current locale. If the message is missing, the fallback locale is
+    /// tried before returning the key itself.
+    ///
+    /// Reading the message subscribes the current component to changes of the locale.
+    pub fn translate(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        let locale = self.locale.read();
+        let fallback = self.fallback.read();
+        let bundles = self.bundles.read();
+
+        let translated = [locale.as_str(), fallback.as_str()]
+            .into_iter()
+            .filter_map(|locale| bundles.iter().find(|bundle| bundle.locale() == locale))
+            .find_map(|bundle| bundle.format(key, args));
+
+        translated.unwrap_or_else(|| {
+            tracing::warn!("Missing translation for `{key}` in the locale `{locale}`");
+            key.to_string()
+        })
+    }
+}
+
+/// Create the translations for the app and provide them to every component below this one.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_i18n::prelude::*;
+///
+/// fn app() -> Element {
+///     use_init_i18n("en-US", "en-US", || {
+///         vec![
+///             Bundle::from_fluent("en-US", "greeting = Hello { $name }!").unwrap(),
+///             Bundle::from_json("fr", r#"{ "greeting": "Bonjour {name} !" }"#).unwrap(),
+///         ]
+///     });
+///
+///     rsx! { Greeting {} }
+/// }
+///
+/// #[component]
+/// fn Greeting() -> Element {
+///     let mut locale = use_locale();
+///     rsx! {
+///         button { onclick: move |_| locale.set("fr".to_string()), "Français" }
+///         p { {translate("greeting", &[("name", &"Dioxus")])} }
+///     }
+/// }
+/// ```
+pub fn use_init_i18n(
+    locale: impl Into<String>,
+    fallback: impl Into<String>,
+    bundles: impl FnOnce() -> Vec<Bundle>,
+) -> I18n {
+    use_hook(|| provide_context(I18n::new(locale, fallback, bundles())))
+}
+
+/// Get the translations provided by [`use_init_i18n`]
+///
+/// # Panics
+///
+/// This panics if no component above this one called [`use_init_i18n`]
+pub fn use_i18n() -> I18n {
+    use_hook(consume_context)
+}
+
+/// Get the signal that holds the current locale. Setting it switches the language of the whole app.
+///
+/// # Panics
+///
+/// This panics if no component above this one called [`use_init_i18n`]
+pub fn use_locale() -> Signal<String> {
+    use_i18n().locale()
+}
+
+/// Translate a message with the translations of the current component. This is what the [`t!`](crate::t) macro
+/// expands to.
+///
+/// If there are no translations in scope, the key is returned as is.
+pub fn translate(key: &str, args: &[(&str, &dyn Display)]) -> String {
+    match try_consume_context::<I18n>() {
+        Some(i18n) => i18n.translate(key, args),
+        None => {
+            tracing::warn!("`{key}` was translated outside of use_init_i18n");
+            key.to_string()
+        }
+    }
+}
@@ -0,0 +1,493 @@
+use crate::I18n;
+use dioxus_core::prelude::{try_consume_context, use_hook};
+use dioxus_signals::{Readable, Signal};
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The languages the [`Formatter`] has formatting rules for
+pub const FORMAT_LANGUAGES: &[&str] = &["en", "fr", "de", "es"];
+
+/// The [`Formatter`] has no formatting rules for a locale
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("There are no formatting rules for the locale `{0}`")]
+pub struct UnsupportedLocale(pub String);
+
+/// How much detail a formatted date includes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateStyle {
+    /// Only numbers, like `1/15/24`
+    Short,
+    /// An abbreviated month, like `Jan 15, 2024`
+    #[default]
+    Medium,
+    /// The full month, like `January 15, 2024`
+    Long,
+}
+
+/// The formatting rules of a language
+struct LocaleData {
+    decimal: &'static str,
+    group: &'static str,
+    /// The number of digits the integer part needs before it is grouped
+    min_grouping: usize,
+    percent: &'static str,
+    months: [&'static str; 12],
+    short_months: [&'static str; 12],
+    date: fn(&LocaleData, DateStyle, i64, usize, u32) -> String,
+    twelve_hour: bool,
+    relative: fn(RelativeUnit, u64, bool) -> String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RelativeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+const EN: LocaleData = LocaleData {
+    decimal: ".",
+    group: ",",
+    min_grouping: 1,
+    percent: "%",
+    months: [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    short_months: [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ],
+    date: |data, style, year, month, day| match style {
+        DateStyle::Short => format!("{}/{day}/{:02}", month + 1, year.rem_euclid(100)),
+        DateStyle::Medium => format!("{} {day}, {year}", data.short_months[month]),
+        DateStyle::Long => format!("{} {day}, {year}", data.months[month]),
+    },
+    twelve_hour: true,
+    relative: |unit, count, future| {
+        let unit = match unit {
+            RelativeUnit::Second => "second",
+            RelativeUnit::Minute => "minute",
+            RelativeUnit::Hour => "hour",
+            RelativeUnit::Day => "day",
+            RelativeUnit::Week => "week",
+            RelativeUnit::Month => "month",
+            RelativeUnit::Year => "year",
+        };
+        let plural = if count == 1 { "" } else { "s" };
+        match future {
+            true => format!("in {count} {unit}{plural}"),
+            false => format!("{count} {unit}{plural} ago"),
+        }
+    },
+};
+
+const FR: LocaleData = LocaleData {
+    decimal: ",",
+    group: "\u{202f}",
+    min_grouping: 1,
+    percent: "\u{a0}%",
+    months: [
+        "janvier",
+        "février",
+        "mars",
+        "avril",
+        "mai",
+        "juin",
+        "juillet",
+        "août",
+        "septembre",
+        "octobre",
+        "novembre",
+        "décembre",
+    ],
+    short_months: [
+        "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.",
+        "déc.",
+    ],
+    date: |data, style, year, month, day| match style {
+        DateStyle::Short => format!("{day:02}/{:02}/{year}", month + 1),
+        DateStyle::Medium => format!("{day} {} {year}", data.short_months[month]),
+        DateStyle::Long => format!("{day} {} {year}", data.months[month]),
+    },
+    twelve_hour: false,
+    relative: |unit, count, future| {
+        let (singular, plural) = match unit {
+            RelativeUnit::Second => ("seconde", "secondes"),
+            RelativeUnit::Minute => ("minute", "minutes"),
+            RelativeUnit::Hour => ("heure", "heures"),
+            RelativeUnit::Day => ("jour", "jours"),
+            RelativeUnit::Week => ("semaine", "semaines"),
+            RelativeUnit::Month => ("mois", "mois"),
+            RelativeUnit::Year => ("an", "ans"),
+        };
+        // French uses the singular for 0 and 1
+        let unit = if count <= 1 { singular } else { plural };
+        match future {
+            true => format!("dans {count} {unit}"),
+            false => format!("il y a {count} {unit}"),
+        }
+    },
+};
+
+const DE: LocaleData = LocaleData {
+    decimal: ",",
+    group: ".",
+    min_grouping: 1,
+    percent: "\u{a0}%",
+    months: [
+        "Januar",
+        "Februar",
+        "März",
+        "April",
+        "Mai",
+        "Juni",
+        "Juli",
+        "August",
+        "September",
+        "Oktober",
+        "November",
+        "Dezember",
+    ],
+    short_months: [
+        "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.",
+        "Dez.",
+    ],
+    date: |data, style, year, month, day| match style {
+        DateStyle::Short => format!("{day:02}.{:02}.{:02}", month + 1, year.rem_euclid(100)),
+        DateStyle::Medium => format!("{day:02}.{:02}.{year}", month + 1),
+        DateStyle::Long => format!("{day}. {} {year}", data.months[month]),
+    },
+    twelve_hour: false,
+    relative: |unit, count, future| {
+        // Both "in" and "vor" take the dative
+        let (singular, plural) = match unit {
+            RelativeUnit::Second => ("Sekunde", "Sekunden"),
+            RelativeUnit::Minute => ("Minute", "Minuten"),
+            RelativeUnit::Hour => ("Stunde", "Stunden"),
+            RelativeUnit::Day => ("Tag", "Tagen"),
+            RelativeUnit::Week => ("Woche", "Wochen"),
+            RelativeUnit::Month => ("Monat", "Monaten"),
+            RelativeUnit::Year => ("Jahr", "Jahren"),
+        };
+        let unit = if count == 1 { singular } else { plural };
+        match future {
+            true => format!("in {count} {unit}"),
+            false => format!("vor {count} {unit}"),
+        }
+    },
+};
+
+const ES: LocaleData = LocaleData {
+    decimal: ",",
+    group: ".",
+    min_grouping: 2,
+    percent: "\u{a0}%",
+    months: [
+        "enero",
+        "febrero",
+        "marzo",
+        "abril",
+        "mayo",
+        "junio",
+        "julio",
+        "agosto",
+        "septiembre",
+        "octubre",
+        "noviembre",
+        "diciembre",
+    ],
+    short_months: [
+        "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+    ],
+    date: |data, style, year, month, day| match style {
+        DateStyle::Short => format!("{day}/{}/{:02}", month + 1, year.rem_euclid(100)),
+        DateStyle::Medium => format!("{day} {} {year}", data.short_months[month]),
+        DateStyle::Long => format!("{day} de {} de {year}", data.months[month]),
+    },
+    twelve_hour: false,
+    relative: |unit, count, future| {
+        let (singular, plural) = match unit {
+            RelativeUnit::Second => ("segundo", "segundos"),
+            RelativeUnit::Minute => ("minuto", "minutos"),
+            RelativeUnit::Hour => ("hora", "horas"),
+            RelativeUnit::Day => ("día", "días"),
+            RelativeUnit::Week => ("semana", "semanas"),
+            RelativeUnit::Month => ("mes", "meses"),
+            RelativeUnit::Year => ("año", "años"),
+        };
+        let unit = if count == 1 { singular } else { plural };
+        match future {
+            true => format!("dentro de {count} {unit}"),
+            false => format!("hace {count} {unit}"),
+        }
+    },
+};
+
+/// Find the rules for a locale like `fr-CA` by its language
+fn locale_data(locale: &str) -> Option<&'static LocaleData> {
+    let language = locale.split(['-', '_']).next().unwrap_or_default();
+    match language.to_ascii_lowercase().as_str() {
+        "en" => Some(&EN),
+        "fr" => Some(&FR),
+        "de" => Some(&DE),
+        "es" => Some(&ES),
+        _ => None,
+    }
+}
+
+/// Warn once per locale that it is formatted with the rules of the fallback locale
+fn warn_unsupported(locale: &str, fallback: &str) {
+    thread_local! {
+        static WARNED: RefCell<HashSet<String>> = RefCell::new(HashSet::new());
+    }
+    if WARNED.with_borrow_mut(|warned| warned.insert(locale.to_string())) {
+        tracing::warn!(
+            "There are no formatting rules for the locale `{locale}`, it is formatted like `{fallback}`. Supported languages: {}",
+            FORMAT_LANGUAGES.join(", ")
+        );
+    }
+}
+
+/// Formats numbers, dates and relative times in the current locale.
+///
+/// The formatting rules are built into the crate instead of coming from the platform, so the server and the client
+/// render exactly the same text during hydration. The languages in [`FORMAT_LANGUAGES`] are supported. Other locales
+/// are formatted with the rules of the fallback locale, which is English unless it is set with
+/// [`Formatter::with_fallback`], and a warning is logged the first time each locale is formatted. Use
+/// [`Formatter::is_supported`] to check the current locale. Dates are formatted in UTC unless an offset is set with
+/// [`Formatter::with_utc_offset`].
+///
+/// Every method reads the locale signal, so components that format a value re-render when the locale changes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Formatter {
+    locale: Signal<String>,
+    fallback: &'static str,
+    utc_offset: i32,
+}
+
+impl Formatter {
+    /// Create a formatter that follows a locale signal
+    pub fn new(locale: Signal<String>) -> Self {
+        Self {
+            locale,
+            fallback: "en",
+            utc_offset: 0,
+        }
+    }
+
+    /// Format locales without formatting rules like `fallback` instead of English. Returns an error if there are no
+    /// rules for `fallback` either.
+    pub fn with_fallback(self, fallback: &str) -> Result<Self, UnsupportedLocale> {
+        let language = fallback.split(['-', '_']).next().unwrap_or_default();
+        let language = FORMAT_LANGUAGES
+            .iter()
+            .find(|supported| supported.eq_ignore_ascii_case(language))
+            .copied()
+            .ok_or_else(|| UnsupportedLocale(fallback.to_string()))?;
+        Ok(Self {
+            fallback: language,
+            ..self
+        })
+    }
+
+    /// Check if there are formatting rules for the current locale. If there aren't, values are formatted like the
+    /// fallback locale.
+    pub fn is_supported(&self) -> bool {
+        locale_data(&self.locale.read()).is_some()
+    }
+
+    /// Format dates and times with an offset from UTC in seconds
+    pub fn with_utc_offset(self, seconds: i32) -> Self {
+        Self {
+            utc_offset: seconds,
+            ..self
+        }
+    }
+
+    fn data(&self) -> &'static LocaleData {
+        let locale = self.locale.read();
+        locale_data(&locale).unwrap_or_else(|| {
+            warn_unsupported(&locale, self.fallback);
+            locale_data(self.fallback).unwrap_or(&EN)
+        })
+    }
+
+    /// Format a number with up to three fraction digits, like `1,234.5`
+    pub fn number(&self, value: f64) -> String {
+        let fixed = format!("{:.3}", value.abs());
+        let trimmed = fixed.trim_end_matches('0').trim_end_matches('.');
+        self.localize(value, trimmed)
+    }
+
+    /// Format a number with exactly `fraction_digits` fraction digits, like `1,234.50`
+    pub fn decimal(&self, value: f64, fraction_digits: usize) -> String {
+        self.localize(value, &format!("{:.*}", fraction_digits, value.abs()))
+    }
+
+    /// Format a ratio as a whole percentage, like `45%` for `0.45`
+    pub fn percent(&self, ratio: f64) -> String {
+        let number = self.localize(ratio, &format!("{:.0}", (ratio * 100.0).abs()));
+        format!("{number}{}", self.data().percent)
+    }
+
+    /// Add the sign, the group separators and the decimal separator to a formatted absolute value
+    fn localize(&self, value: f64, absolute: &str) -> String {
+        let data = self.data();
+        let (integer, fraction) = absolute.split_once('.').unwrap_or((absolute, ""));
+        let negative = value < 0.0 && absolute.bytes().any(|digit| matches!(digit, b'1'..=b'9'));
+
+        let mut formatted = String::new();
+        if negative {
+            formatted.push('-');
+        }
+        if integer.len() > 3 + data.min_grouping - 1 {
+            for (i, digit) in integer.chars().enumerate() {
+                if i > 0 && (integer.len() - i) % 3 == 0 {
+                    formatted.push_str(data.group);
+                }
+                formatted.push(digit);
+            }
+        } else {
+            formatted.push_str(integer);
+        }
+        if !fraction.is_empty() {
+            formatted.push_str(data.decimal);
+            formatted.push_str(fraction);
+        }
+        formatted
+    }
+
+    /// Split a time into the local date and the seconds since midnight
+    fn civil(&self, time: SystemTime) -> (i64, usize, u32, i64) {
+        let seconds = match time.duration_since(UNIX_EPOCH) {
+            Ok(since) => since.as_secs() as i64,
+            Err(before) => -(before.duration().as_secs_f64().ceil() as i64),
+        } + self.utc_offset as i64;
+        let (days, seconds) = (seconds.div_euclid(86400), seconds.rem_euclid(86400));
+
+        // Convert days since the epoch to a date in the proleptic Gregorian calendar
+        // https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let z = days + 719468;
+        let era = z.div_euclid(146097);
+        let doe = z.rem_euclid(146097);
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 2 } else { mp - 10 } as usize;
+        let year = yoe + era * 400 + i64::from(month < 2);
+        (year, month, day, seconds)
+    }
+
+    /// Format the date of a time, like `Jan 15, 2024`
+    pub fn date(&self, time: SystemTime, style: DateStyle) -> String {
+        let data = self.data();
+        let (year, month, day, _) = self.civil(time);
+        (data.date)(data, style, year, month, day)
+    }
+
+    /// Format the hours and minutes of a time, like `3:04 PM` or `15:04`
+    pub fn time(&self, time: SystemTime) -> String {
+        let (_, _, _, seconds) = self.civil(time);
+        let (hour, minute) = (seconds / 3600, seconds % 3600 / 60);
+        if self.data().twelve_hour {
+            let period = if hour < 12 { "AM" } else { "PM" };
+            let hour = if hour % 12 == 0 { 12 } else { hour % 12 };
+            format!("{hour}:{minute:02}\u{202f}{period}")
+        } else {
+            format!("{hour:02}:{minute:02}")
+        }
+    }
+
+    /// Format the date and time of a time, like `Jan 15, 2024, 3:04 PM`
+    pub fn date_time(&self, time: SystemTime, style: DateStyle) -> String {
+        let separator = if self.data().twelve_hour { ", " } else { " " };
+        format!("{}{separator}{}", self.date(time, style), self.time(time))
+    }
+
+    /// Format an offset in seconds from now, like `in 3 days` for positive offsets or `5 minutes ago` for negative
+    /// offsets. The largest unit that fits is used, with months of 30 days and years of 365 days.
+    pub fn relative_time(&self, seconds: i64) -> String {
+        const UNITS: [(RelativeUnit, u64); 6] = [
+            (RelativeUnit::Year, 365 * 86400),
+            (RelativeUnit::Month, 30 * 86400),
+            (RelativeUnit::Week, 7 * 86400),
+            (RelativeUnit::Day, 86400),
+            (RelativeUnit::Hour, 3600),
+            (RelativeUnit::Minute, 60),
+        ];
+        let magnitude = seconds.unsigned_abs();
+        let (unit, count) = UNITS
+            .iter()
+            .find(|(_, size)| magnitude >= *size)
+            .map(|(unit, size)| (*unit, magnitude / size))
+            .unwrap_or((RelativeUnit::Second, magnitude));
+        (self.data().relative)(unit, count, seconds >= 0)
+    }
+
+    /// Format a time relative to `now`, like `2 hours ago`. Pass the same `now` on the server and the client to get
+    /// the same text in both.
+    pub fn relative_to(&self, time: SystemTime, now: SystemTime) -> String {
+        let seconds = match time.duration_since(now) {
+            Ok(after) => after.as_secs() as i64,
+            Err(before) => -(before.duration().as_secs() as i64),
+        };
+        self.relative_time(seconds)
+    }
+
+    /// Format a duration from now, like `in 3 days`
+    pub fn relative_duration(&self, duration: Duration) -> String {
+        self.relative_time(duration.as_secs() as i64)
+    }
+}
+
+/// Get a [`Formatter`] that follows the locale of [`use_locale`](crate::use_locale). Outside of
+/// [`use_init_i18n`](crate::use_init_i18n), values are formatted in `en-US`. Locales without formatting rules are
+/// formatted like the fallback locale of [`use_init_i18n`](crate::use_init_i18n) if it has rules, or English otherwise.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_i18n::prelude::*;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// fn app() -> Element {
+///     use_init_i18n("fr", "en-US", Vec::new);
+///     let format = use_formatter();
+///     let launch = UNIX_EPOCH + Duration::from_secs(1_705_276_800);
+///
+///     rsx! {
+///         // 1 234,5
+///         p { {format.number(1234.5)} }
+///         // 15 janvier 2024
+///         p { {format.date(launch, DateStyle::Long)} }
+///         // il y a 3 jours
+///         p { {format.relative_time(-3 * 86400)} }
+///     }
+/// }
+/// ```
+pub fn use_formatter() -> Formatter {
+    use_hook(|| {
+        let Some(i18n) = try_consume_context::<I18n>() else {
+            return Formatter::new(Signal::new("en-US".to_string()));
+        };
+        let formatter = Formatter::new(i18n.locale());
+        formatter
+            .with_fallback(&i18n.fallback())
+            .unwrap_or(formatter)
+    })
+}
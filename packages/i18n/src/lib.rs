@@ -0,0 +1,23 @@
+#![doc = include_str!("../README.md")]
+#![doc(html_logo_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![doc(html_favicon_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![deny(missing_docs)]
+
+mod bundle;
+pub use bundle::*;
+
+mod context;
+pub use context::*;
+
+mod format;
+pub use format::*;
+
+pub use dioxus_i18n_macro::t;
+
+/// A collection of useful items most applications might need.
+pub mod prelude {
+    pub use crate::bundle::Bundle;
+    pub use crate::context::*;
+    pub use crate::format::*;
+    pub use dioxus_i18n_macro::t;
+}
@@ -0,0 +1,164 @@
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_i18n::prelude::*;
+use std::time::{Duration, UNIX_EPOCH};
+
+fn format_in<O>(locale: &str, f: impl FnOnce(Formatter) -> O) -> O {
+    let dom = VirtualDom::new(|| rsx! {});
+    dom.in_runtime(|| {
+        ScopeId::ROOT.in_runtime(|| f(Formatter::new(Signal::new(locale.to_string()))))
+    })
+}
+
+#[test]
+fn formats_numbers() {
+    assert_eq!(
+        format_in("en-US", |f| f.number(1234567.891)),
+        "1,234,567.891"
+    );
+    assert_eq!(format_in("en-US", |f| f.number(-0.5)), "-0.5");
+    assert_eq!(format_in("en-US", |f| f.number(42.0)), "42");
+    assert_eq!(format_in("en-US", |f| f.decimal(1234.5, 2)), "1,234.50");
+    assert_eq!(format_in("en-US", |f| f.percent(0.456)), "46%");
+    assert_eq!(format_in("fr-FR", |f| f.number(1234.5)), "1\u{202f}234,5");
+    assert_eq!(format_in("fr", |f| f.percent(0.45)), "45\u{a0}%");
+    assert_eq!(format_in("de", |f| f.number(1234.5)), "1.234,5");
+    // Spanish only groups numbers with five or more digits
+    assert_eq!(format_in("es", |f| f.number(1234.0)), "1234");
+    assert_eq!(format_in("es", |f| f.number(12345.0)), "12.345");
+}
+
+#[test]
+fn unsupported_locales_use_the_fallback() {
+    assert!(format_in("fr-CA", |f| f.is_supported()));
+    assert!(!format_in("xx", |f| f.is_supported()));
+    // Without a fallback, unsupported locales are formatted like English
+    assert_eq!(format_in("xx", |f| f.number(1234.5)), "1,234.5");
+    assert_eq!(
+        format_in("xx", |f| f.with_fallback("de-AT").unwrap().number(1234.5)),
+        "1.234,5"
+    );
+    assert_eq!(
+        format_in("en", |f| f.with_fallback("xx").unwrap_err()),
+        UnsupportedLocale("xx".to_string())
+    );
+}
+
+#[test]
+fn formats_dates_and_times() {
+    // 2024-01-15 15:04:00 UTC
+    let time = UNIX_EPOCH + Duration::from_secs(1_705_331_040);
+
+    assert_eq!(
+        format_in("en-US", |f| f.date(time, DateStyle::Short)),
+        "1/15/24"
+    );
+    assert_eq!(
+        format_in("en-US", |f| f.date(time, DateStyle::Medium)),
+        "Jan 15, 2024"
+    );
+    assert_eq!(
+        format_in("en-US", |f| f.date(time, DateStyle::Long)),
+        "January 15, 2024"
+    );
+    assert_eq!(format_in("en-US", |f| f.time(time)), "3:04\u{202f}PM");
+    assert_eq!(
+        format_in("en-US", |f| f.date_time(time, DateStyle::Medium)),
+        "Jan 15, 2024, 3:04\u{202f}PM"
+    );
+    assert_eq!(
+        format_in("fr", |f| f.date(time, DateStyle::Short)),
+        "15/01/2024"
+    );
+    assert_eq!(
+        format_in("fr", |f| f.date(time, DateStyle::Medium)),
+        "15 janv. 2024"
+    );
+    assert_eq!(
+        format_in("de", |f| f.date(time, DateStyle::Long)),
+        "15. Januar 2024"
+    );
+    assert_eq!(format_in("de", |f| f.time(time)), "15:04");
+    assert_eq!(
+        format_in("es", |f| f.date(time, DateStyle::Long)),
+        "15 de enero de 2024"
+    );
+
+    // An offset moves the time into the next day
+    assert_eq!(
+        format_in("en-US", |f| f
+            .with_utc_offset(9 * 3600)
+            .date_time(time, DateStyle::Short)),
+        "1/16/24, 12:04\u{202f}AM"
+    );
+    // Dates before the epoch work too
+    let moon = UNIX_EPOCH - Duration::from_secs(14_182_940);
+    assert_eq!(
+        format_in("en-US", |f| f.date(moon, DateStyle::Long)),
+        "July 20, 1969"
+    );
+}
+
+#[test]
+fn formats_relative_times() {
+    assert_eq!(
+        format_in("en-US", |f| f.relative_time(-30)),
+        "30 seconds ago"
+    );
+    assert_eq!(format_in("en-US", |f| f.relative_time(3600)), "in 1 hour");
+    assert_eq!(
+        format_in("en-US", |f| f.relative_time(3 * 86400)),
+        "in 3 days"
+    );
+    assert_eq!(
+        format_in("en-US", |f| f.relative_time(-400 * 86400)),
+        "1 year ago"
+    );
+    assert_eq!(
+        format_in("fr", |f| f.relative_time(-3 * 86400)),
+        "il y a 3 jours"
+    );
+    assert_eq!(format_in("fr", |f| f.relative_time(60)), "dans 1 minute");
+    assert_eq!(
+        format_in("de", |f| f.relative_time(-3 * 86400)),
+        "vor 3 Tagen"
+    );
+    assert_eq!(
+        format_in("es", |f| f.relative_time(14 * 86400)),
+        "dentro de 2 semanas"
+    );
+
+    let now = UNIX_EPOCH + Duration::from_secs(1_705_331_040);
+    let then = now - Duration::from_secs(2 * 3600);
+    assert_eq!(
+        format_in("en-US", |f| f.relative_to(then, now)),
+        "2 hours ago"
+    );
+}
+
+#[test]
+fn follows_the_locale_signal() {
+    fn app() -> Element {
+        use_init_i18n("en-US", "en-US", Vec::new);
+        let format = use_formatter();
+        let mut locale = use_locale();
+        use_hook(move || {
+            spawn(async move { locale.set("de".to_string()) });
+        });
+        rsx! { {format.number(1234.5)} }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert_eq!(dioxus_ssr::render(&dom), "1,234.5");
+
+    tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .unwrap()
+        .block_on(async {
+            _ = tokio::time::timeout(Duration::from_millis(50), dom.wait_for_work()).await;
+        });
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&dom), "1.234,5");
+}
@@ -0,0 +1,109 @@
+use dioxus::prelude::*;
+use dioxus_i18n::prelude::*;
+
+fn bundles() -> Vec<Bundle> {
+    vec![
+        Bundle::from_fluent("en-US", include_str!("../locales/en-US.ftl")).unwrap(),
+        Bundle::from_json("fr", include_str!("../locales/fr.json")).unwrap(),
+    ]
+}
+
+fn render(locale: &'static str) -> String {
+    let mut dom = VirtualDom::new_with_props(
+        |locale: &'static str| {
+            use_init_i18n(locale, "en-US", bundles);
+            let name = "Dioxus";
+            rsx! {
+                p { {t!("greeting", name)} }
+                p { {t!("unread", count = 2 + 1)} }
+            }
+        },
+        locale,
+    );
+    dom.rebuild_in_place();
+    dioxus_ssr::render(&dom)
+}
+
+#[test]
+fn translates_fluent_and_json() {
+    assert_eq!(
+        render("en-US"),
+        "<p>Hello Dioxus!</p><p>You have 3 unread messages</p>"
+    );
+    assert_eq!(
+        render("fr"),
+        "<p>Bonjour Dioxus !</p><p>Vous avez 3 messages non lus</p>"
+    );
+}
+
+#[test]
+fn missing_messages_fall_back() {
+    let mut dom = VirtualDom::new(|| {
+        use_init_i18n("fr", "en-US", || {
+            vec![
+                Bundle::new("en-US", [("only-english", "Only in English")]),
+                Bundle::new("fr", [("greeting", "Bonjour")]),
+            ]
+        });
+        rsx! {
+            p { {translate("only-english", &[])} }
+            p { {translate("missing", &[])} }
+        }
+    });
+    dom.rebuild_in_place();
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        "<p>Only in English</p><p>missing</p>"
+    );
+}
+
+#[test]
+fn parses_multiline_fluent_messages() {
+    let bundle = Bundle::from_fluent("en-US", include_str!("../locales/en-US.ftl")).unwrap();
+    assert_eq!(bundle.message("farewell"), Some("Goodbye,\nsee you soon"));
+    assert_eq!(bundle.message("greeting"), Some("Hello {name}!"));
+
+    let nested = Bundle::from_json("en-US", r#"{ "menu": { "home": "Home" } }"#).unwrap();
+    assert_eq!(nested.message("menu.home"), Some("Home"));
+
+    assert!(Bundle::from_fluent("en-US", "no equals sign").is_err());
+}
+
+#[test]
+fn unclosed_braces_are_kept() {
+    let bundle = Bundle::from_fluent("en-US", "open = Hello { $name").unwrap();
+    assert_eq!(bundle.message("open"), Some("Hello { $name"));
+    assert_eq!(
+        bundle.format("open", &[("name", &"Dioxus")]).as_deref(),
+        Some("Hello { $name")
+    );
+
+    let bundle = Bundle::from_json("en-US", r#"{ "open": "Hello {name} and {" }"#).unwrap();
+    assert_eq!(
+        bundle.format("open", &[("name", &"Dioxus")]).as_deref(),
+        Some("Hello Dioxus and {")
+    );
+}
+
+#[tokio::test]
+async fn switching_the_locale_rerenders() {
+    let mut dom = VirtualDom::new(|| {
+        use_init_i18n("en-US", "en-US", bundles);
+        rsx! {
+            p { {t!("farewell")} }
+        }
+    });
+    dom.rebuild_in_place();
+    assert_eq!(dioxus_ssr::render(&dom), "<p>Goodbye,\nsee you soon</p>");
+
+    let mut i18n = dom.in_runtime(|| ScopeId::APP.consume_context::<I18n>().unwrap());
+    dom.in_runtime(|| i18n.set_locale("fr"));
+
+    tokio::select! {
+        _ = dom.wait_for_work() => {}
+        _ = tokio::time::sleep(std::time::Duration::from_millis(1000)) => panic!("timed out")
+    }
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+
+    assert_eq!(dioxus_ssr::render(&dom), "<p>Au revoir</p>");
+}
@@ -1 +1 @@
-window.hydrate_queue=[];window.dx_hydrate=(id,data)=>{const decoded=atob(data),bytes=Uint8Array.from(decoded,(c)=>c.charCodeAt(0));if(window.hydration_callback)window.hydration_callback(id,bytes);else window.hydrate_queue.push([id,bytes])};
+window.hydrate_queue=[];window.dx_hydrate=(id,data)=>{const decoded=atob(data),bytes=Uint8Array.from(decoded,(c)=>c.charCodeAt(0));if(window.hydration_callback)window.hydration_callback(id,bytes);else window.hydrate_queue.push([id,bytes])};var recordedEvents=["click","dblclick","input","change","submit","keydown","keyup"];window.dx_pre_hydration_events=[];var recordEvent=(event)=>{if(event.type==="submit")event.preventDefault();window.dx_pre_hydration_events?.push(event)};for(let name of recordedEvents)document.addEventListener(name,recordEvent,{capture:!0});window.dx_take_pre_hydration_events=()=>{for(let name of recordedEvents)document.removeEventListener(name,recordEvent,{capture:!0});const events=window.dx_pre_hydration_events??[];return window.dx_pre_hydration_events=void 0,events};
@@ -8,7 +8,14 @@ export type NodeId = number;
 export class BaseInterpreter {
   // non bubbling events listen at the element the listener was created at
   global: {
-    [key: string]: { active: number; callback: EventListener };
+    [key: string]: {
+      active: number;
+      // the number of active listeners that asked to be passive
+      passive: number;
+      // whether the listener at the root is registered as passive
+      registered?: boolean;
+      callback: EventListener;
+    };
   };
   // bubbling events can listen at the root element
   local: {
@@ -73,20 +80,29 @@ export class BaseInterpreter {
     }
   }
 
-  createListener(event_name: string, element: HTMLElement, bubbles: boolean) {
-    if (bubbles) {
+  // The flags of a listener are packed into a number:
+  // bit 0 is set if the event bubbles, bit 1 if the listener sets passive, and bit 2 is the value of passive
+  createListener(event_name: string, element: HTMLElement, flags: number) {
+    const passive = listenerPassive(flags);
+    if (flags & 1) {
       if (this.global[event_name] === undefined) {
-        this.global[event_name] = { active: 1, callback: this.handler };
-        this.root.addEventListener(event_name, this.handler);
-      } else {
-        this.global[event_name].active++;
+        this.global[event_name] = {
+          active: 0,
+          passive: 0,
+          callback: this.handler,
+        };
+      }
+      this.global[event_name].active++;
+      if (passive === true) {
+        this.global[event_name].passive++;
       }
+      this.updateBubblingListener(event_name);
     } else {
       const id = element.getAttribute("data-dioxus-id");
       if (!this.local[id]) {
         this.local[id] = {};
       }
-      element.addEventListener(event_name, this.handler);
+      element.addEventListener(event_name, this.handler, { passive });
     }
 
     if (event_name == "resize") {
@@ -94,23 +110,39 @@ export class BaseInterpreter {
     }
   }
 
-  removeListener(element: HTMLElement, event_name: string, bubbles: boolean) {
-    if (bubbles) {
-      this.removeBubblingListener(event_name);
+  removeListener(element: HTMLElement, event_name: string, flags: number) {
+    if (flags & 1) {
+      this.removeBubblingListener(event_name, listenerPassive(flags));
     } else {
       this.removeNonBubblingListener(element, event_name);
     }
   }
 
-  removeBubblingListener(event_name: string) {
+  removeBubblingListener(event_name: string, passive?: boolean) {
     this.global[event_name].active--;
-    if (this.global[event_name].active === 0) {
-      this.root.removeEventListener(
-        event_name,
-        this.global[event_name].callback
-      );
+    if (passive === true) {
+      this.global[event_name].passive--;
+    }
+    this.updateBubblingListener(event_name);
+  }
+
+  // Every listener of a bubbling event shares one listener at the root. That listener is only passive if all of the
+  // listeners asked to be passive, so it is registered again when that changes
+  updateBubblingListener(event_name: string) {
+    const listener = this.global[event_name];
+    const passive = listener.active === listener.passive;
+    if (listener.active > 0 && listener.registered === passive) {
+      return;
+    }
+    if (listener.registered !== undefined) {
+      this.root.removeEventListener(event_name, listener.callback);
+    }
+    if (listener.active === 0) {
       delete this.global[event_name];
+      return;
     }
+    this.root.addEventListener(event_name, listener.callback, { passive });
+    listener.registered = passive;
   }
 
   removeNonBubblingListener(element: HTMLElement, event_name: string) {
@@ -177,8 +209,8 @@ export class BaseInterpreter {
         const listener = split[j];
         const split2 = listener.split(":");
         const event_name = split2[0];
-        const bubbles = split2[1] === "1";
-        this.createListener(event_name, hydrateNode, bubbles);
+        const flags = parseInt(split2[1]);
+        this.createListener(event_name, hydrateNode, flags);
       }
     }
   }
@@ -270,3 +302,8 @@ export class BaseInterpreter {
     setAttributeInner(node, field, value, ns);
   }
 }
+
+// Read the passive option from the flags of a listener, or undefined to use the default of the browser
+function listenerPassive(flags: number): boolean | undefined {
+  return flags & 2 ? (flags & 4) !== 0 : undefined;
+}
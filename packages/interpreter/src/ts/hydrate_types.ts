@@ -4,5 +4,7 @@ declare global {
   interface Window {
     hydrate_queue: [number[], Uint8Array][];
     hydration_callback: null | ((id: number[], data: Uint8Array) => void);
+    dx_pre_hydration_events: Event[] | undefined;
+    dx_take_pre_hydration_events: () => Event[];
   }
 }
@@ -14,3 +14,27 @@ window.dx_hydrate = (id: number[], data: string) => {
     window.hydrate_queue.push([id, bytes]);
   }
 };
+
+// Events the user fires before the wasm has loaded and hydrated the page are recorded so they can be replayed once the listeners are attached
+const recordedEvents = ["click", "dblclick", "input", "change", "submit", "keydown", "keyup"];
+window.dx_pre_hydration_events = [];
+const recordEvent = (event: Event) => {
+  // Submitting a form would navigate away before the app can handle it
+  if (event.type === "submit") {
+    event.preventDefault();
+  }
+  window.dx_pre_hydration_events?.push(event);
+};
+for (const name of recordedEvents) {
+  document.addEventListener(name, recordEvent, { capture: true });
+}
+
+// Stop recording and return the events that were recorded
+window.dx_take_pre_hydration_events = () => {
+  for (const name of recordedEvents) {
+    document.removeEventListener(name, recordEvent, { capture: true });
+  }
+  const events = window.dx_pre_hydration_events ?? [];
+  window.dx_pre_hydration_events = undefined;
+  return events;
+};
@@ -267,9 +267,32 @@ function serializeKeyboardEvent(event: KeyboardEvent): SerializedEvent {
     repeat: event.repeat,
     which: event.which,
     code: event.code,
+    modifier_state: serializeModifierState(event),
   };
 }
 
+// The modifiers `getModifierState` knows about, including lock keys that don't have an `*Key` property
+const MODIFIER_NAMES = [
+  "Alt",
+  "AltGraph",
+  "CapsLock",
+  "Control",
+  "Fn",
+  "FnLock",
+  "Meta",
+  "NumLock",
+  "ScrollLock",
+  "Shift",
+  "Symbol",
+  "SymbolLock",
+  "Hyper",
+  "Super",
+];
+
+function serializeModifierState(event: KeyboardEvent): string[] {
+  return MODIFIER_NAMES.filter((name) => event.getModifierState(name));
+}
+
 function serializeAnimationEvent(event: AnimationEvent): SerializedEvent {
   return {
     animation_name: event.animationName,
@@ -19,8 +19,7 @@ export function setAttributeInner(node: HTMLElement, field: string, value: strin
     case "value":
       // @ts-ignore
       if (node.value !== value) {
-        // @ts-ignore
-        node.value = value;
+        setValue(node, value);
       }
       break;
 
@@ -65,6 +64,43 @@ export function setAttributeInner(node: HTMLElement, field: string, value: strin
   }
 }
 
+// Writing the value of an input moves the cursor to the end. If the user is typing, the cursor is kept at the same
+// distance from the end, so reformatting the value (masks, uppercase, ...) doesn't make it jump. Writes during an IME
+// composition would cancel it, so they are deferred until the composition ends.
+function setValue(node: any, value: string) {
+  if (!node.dxComposition) {
+    node.dxComposition = { composing: false, pending: null };
+    node.addEventListener("compositionstart", () => {
+      node.dxComposition.composing = true;
+    });
+    node.addEventListener("compositionend", () => {
+      const composition = node.dxComposition;
+      composition.composing = false;
+      if (composition.pending !== null && node.value !== composition.pending) {
+        setValue(node, composition.pending);
+      }
+      composition.pending = null;
+    });
+  }
+  if (node.dxComposition.composing) {
+    node.dxComposition.pending = value;
+    return;
+  }
+
+  let fromEnd: number | null = null;
+  try {
+    if (document.activeElement === node && typeof node.selectionEnd === "number") {
+      fromEnd = node.value.length - node.selectionEnd;
+    }
+  } catch {
+    // Inputs like number and email don't have a selection
+  }
+  node.value = value;
+  if (fromEnd !== null) {
+    const cursor = Math.max(0, value.length - fromEnd);
+    node.setSelectionRange(cursor, cursor);
+  }
+}
 
 function truthy(val: string | boolean) {
   return val === "true" || val === true;
@@ -78,16 +78,16 @@ mod js {
         "{let node = document.createComment('placeholder'); this.stack.push(node); this.nodes[$id$] = node;}"
     }
 
-    fn new_event_listener(event_name: &str<u8, evt>, id: u32, bubbles: u8) {
+    fn new_event_listener(event_name: &str<u8, evt>, id: u32, flags: u8) {
         r#"
             const node = this.nodes[id];
             if(node.listening){node.listening += 1;}else{node.listening = 1;}
             node.setAttribute('data-dioxus-id', `\${id}`);
-            this.createListener($event_name$, node, $bubbles$);
+            this.createListener($event_name$, node, $flags$);
         "#
     }
-    fn remove_event_listener(event_name: &str<u8, evt>, id: u32, bubbles: u8) {
-        "{let node = this.nodes[$id$]; node.listening -= 1; node.removeAttribute('data-dioxus-id'); this.removeListener(node, $event_name$, $bubbles$);}"
+    fn remove_event_listener(event_name: &str<u8, evt>, id: u32, flags: u8) {
+        "{let node = this.nodes[$id$]; node.listening -= 1; node.removeAttribute('data-dioxus-id'); this.removeListener(node, $event_name$, $flags$);}"
     }
     fn set_text(id: u32, text: &str) {
         "{this.nodes[$id$].textContent = $text$;}"
@@ -171,9 +171,9 @@ mod js {
     }
 
     #[cfg(feature = "binary-protocol")]
-    fn foreign_event_listener(event: &str<u8, evt>, id: u32, bubbles: u8) {
+    fn foreign_event_listener(event: &str<u8, evt>, id: u32, flags: u8) {
         r#"
-    bubbles = bubbles == 1;
+    const bubbles = (flags & 1) == 1;
     let this_node = this.nodes[id];
     if(this_node.listening){
         this_node.listening += 1;
@@ -194,7 +194,7 @@ mod js {
             })
         );
     } else {
-        this.createListener(event_name, this_node, bubbles, (event) => {
+        this.createListener(event_name, this_node, flags, (event) => {
             this.handler(event, event_name, bubbles);
         });
     }"#
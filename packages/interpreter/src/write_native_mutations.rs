@@ -1,13 +1,14 @@
 use crate::unified_bindings::Interpreter as Channel;
-use dioxus_core::{Template, TemplateAttribute, TemplateNode, WriteMutations};
-use dioxus_core_types::event_bubbles;
-use rustc_hash::FxHashMap;
+use dioxus_core::{
+    ListenerOptions, Template, TemplateAttribute, TemplateNode, TemplateRegistry, WriteMutations,
+};
+use dioxus_core_types::listener_flags;
 
 /// The state needed to apply mutations to a channel. This state should be kept across all mutations for the app
 #[derive(Default)]
 pub struct MutationState {
     /// The currently registered templates with the template ids
-    templates: FxHashMap<Template, u16>,
+    templates: TemplateRegistry,
 
     /// The channel that we are applying mutations to
     channel: Channel,
@@ -96,19 +97,15 @@ impl WriteMutations for MutationState {
 
     fn load_template(&mut self, template: Template, index: usize, id: dioxus_core::ElementId) {
         // Get the template or create it if we haven't seen it before
-        let tmpl_id = self.templates.get(&template).cloned().unwrap_or_else(|| {
-            let tmpl_id = self.templates.len() as u16;
-            self.templates.insert(template, tmpl_id);
-
+        let (tmpl_id, new) = self.templates.register(template);
+        if new {
             for root in template.roots.iter() {
                 self.create_template_node(root);
             }
 
             let len = template.roots.len() as u16;
             self.channel.add_templates(tmpl_id, len);
-
-            tmpl_id
-        });
+        }
 
         self.channel
             .load_template(tmpl_id, index as u16, id.0 as u32);
@@ -173,15 +170,39 @@ impl WriteMutations for MutationState {
     }
 
     fn create_event_listener(&mut self, name: &'static str, id: dioxus_core::ElementId) {
+        self.create_event_listener_with_options(name, id, ListenerOptions::default());
+    }
+
+    fn remove_event_listener(&mut self, name: &'static str, id: dioxus_core::ElementId) {
+        self.remove_event_listener_with_options(name, id, ListenerOptions::default());
+    }
+
+    fn create_event_listener_with_options(
+        &mut self,
+        name: &'static str,
+        id: dioxus_core::ElementId,
+        options: ListenerOptions,
+    ) {
         // note that we use the foreign event listener here instead of the native one
         // the native method assumes we have direct access to the dom, which we don't.
-        self.channel
-            .foreign_event_listener(name, id.0 as u32, event_bubbles(name) as u8);
+        self.channel.foreign_event_listener(
+            name,
+            id.0 as u32,
+            listener_flags(name, options.passive),
+        );
     }
 
-    fn remove_event_listener(&mut self, name: &'static str, id: dioxus_core::ElementId) {
-        self.channel
-            .remove_event_listener(name, id.0 as u32, event_bubbles(name) as u8);
+    fn remove_event_listener_with_options(
+        &mut self,
+        name: &'static str,
+        id: dioxus_core::ElementId,
+        options: ListenerOptions,
+    ) {
+        self.channel.remove_event_listener(
+            name,
+            id.0 as u32,
+            listener_flags(name, options.passive),
+        );
     }
 
     fn remove_node(&mut self, id: dioxus_core::ElementId) {
@@ -93,6 +93,7 @@ impl IncrementalRendererConfig {
             ),
             memory_cache: InMemoryCache::new(self.memory_cache_limit, self.invalidate_after),
             invalidate_after: self.invalidate_after,
+            tags: Default::default(),
         };
 
         if self.clear_cache {
@@ -60,9 +60,10 @@ impl FileSystemCache {
     }
 
     pub fn invalidate(&mut self, route: &str) {
-        let file_path = self.find_file(route).unwrap().full_path;
-        if let Err(err) = std::fs::remove_file(file_path) {
-            tracing::error!("Failed to remove file: {}", err);
+        if let Some(file_path) = self.find_file(route) {
+            if let Err(err) = std::fs::remove_file(file_path.full_path) {
+                tracing::error!("Failed to remove file: {}", err);
+            }
         }
     }
 
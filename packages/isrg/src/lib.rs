@@ -13,6 +13,7 @@ use std::time::Duration;
 use chrono::Utc;
 pub use config::*;
 pub use freshness::*;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use self::memory_cache::InMemoryCache;
 
@@ -32,6 +33,8 @@ pub struct IncrementalRenderer {
     #[cfg(not(target_arch = "wasm32"))]
     pub(crate) file_system_cache: fs_cache::FileSystemCache,
     invalidate_after: Option<Duration>,
+    /// A map from cache tags to the routes that were rendered with that tag
+    tags: FxHashMap<String, FxHashSet<String>>,
 }
 
 impl IncrementalRenderer {
@@ -45,6 +48,33 @@ impl IncrementalRenderer {
         self.memory_cache.invalidate(route);
         #[cfg(not(target_arch = "wasm32"))]
         self.file_system_cache.invalidate(route);
+        for routes in self.tags.values_mut() {
+            routes.remove(route);
+        }
+    }
+
+    /// Remove every route that was cached with the given tag.
+    ///
+    /// ```rust
+    /// # use dioxus_isrg::IncrementalRenderer;
+    /// # let static_dir = std::env::temp_dir().join(format!("dioxus-isrg-doctest-{}", std::process::id()));
+    /// # let mut renderer = IncrementalRenderer::builder().static_dir(static_dir).build();
+    /// renderer.cache_with_tags("/posts/1".to_string(), b"post 1".to_vec(), ["posts"]).unwrap();
+    /// renderer.cache_with_tags("/posts/2".to_string(), b"post 2".to_vec(), ["posts"]).unwrap();
+    /// renderer.cache("/about".to_string(), b"about".to_vec()).unwrap();
+    ///
+    /// renderer.invalidate_tag("posts");
+    ///
+    /// assert!(renderer.get("/posts/1").unwrap().is_none());
+    /// assert!(renderer.get("/posts/2").unwrap().is_none());
+    /// assert!(renderer.get("/about").unwrap().is_some());
+    /// ```
+    pub fn invalidate_tag(&mut self, tag: &str) {
+        if let Some(routes) = self.tags.remove(tag) {
+            for route in routes {
+                self.invalidate(&route);
+            }
+        }
     }
 
     /// Remove all routes from the cache.
@@ -52,13 +82,15 @@ impl IncrementalRenderer {
         self.memory_cache.clear();
         #[cfg(not(target_arch = "wasm32"))]
         self.file_system_cache.clear();
+        self.tags.clear();
     }
 
     /// Cache a rendered response.
     ///
     /// ```rust
     /// # use dioxus_isrg::IncrementalRenderer;
-    /// # let mut renderer = IncrementalRenderer::builder().build();
+    /// # let static_dir = std::env::temp_dir().join(format!("dioxus-isrg-doctest-{}", std::process::id()));
+    /// # let mut renderer = IncrementalRenderer::builder().static_dir(static_dir).build();
     /// let route = "/index".to_string();
     /// let response = b"<html><body>Hello world</body></html>";
     /// renderer.cache(route, response).unwrap();
@@ -68,6 +100,38 @@ impl IncrementalRenderer {
         route: String,
         html: impl Into<Vec<u8>>,
     ) -> Result<RenderFreshness, IncrementalRendererError> {
+        self.cache_with_tags(route, html, std::iter::empty::<String>())
+    }
+
+    /// Cache a rendered response with a set of tags. All routes cached with a tag can be removed at once with [`IncrementalRenderer::invalidate_tag`].
+    ///
+    /// The tags replace the tags the route was cached with before, so a route that is cached again without a tag is
+    /// no longer removed with that tag.
+    ///
+    /// ```rust
+    /// # use dioxus_isrg::IncrementalRenderer;
+    /// # let static_dir = std::env::temp_dir().join(format!("dioxus-isrg-doctest-{}", std::process::id()));
+    /// # let mut renderer = IncrementalRenderer::builder().static_dir(static_dir).build();
+    /// let route = "/blog/hello-world".to_string();
+    /// let response = b"<html><body>Hello world</body></html>";
+    /// renderer.cache_with_tags(route, response, ["blog", "author:jane"]).unwrap();
+    /// ```
+    pub fn cache_with_tags(
+        &mut self,
+        route: String,
+        html: impl Into<Vec<u8>>,
+        tags: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Result<RenderFreshness, IncrementalRendererError> {
+        self.tags.retain(|_, routes| {
+            routes.remove(&route);
+            !routes.is_empty()
+        });
+        for tag in tags {
+            self.tags
+                .entry(tag.into())
+                .or_default()
+                .insert(route.clone());
+        }
         let timestamp = Utc::now();
         let html = html.into();
         #[cfg(not(target_arch = "wasm32"))]
@@ -84,7 +148,8 @@ impl IncrementalRenderer {
     ///
     /// ```rust
     /// # use dioxus_isrg::IncrementalRenderer;
-    /// # let mut renderer = IncrementalRenderer::builder().build();
+    /// # let static_dir = std::env::temp_dir().join(format!("dioxus-isrg-doctest-{}", std::process::id()));
+    /// # let mut renderer = IncrementalRenderer::builder().static_dir(static_dir).build();
     /// # let route = "/index".to_string();
     /// # let response = b"<html><body>Hello world</body></html>";
     /// # renderer.cache(route, response).unwrap();
@@ -97,7 +162,8 @@ impl IncrementalRenderer {
     ///
     /// ```rust
     /// # use dioxus_isrg::IncrementalRenderer;
-    /// # let mut renderer = IncrementalRenderer::builder().build();
+    /// # let static_dir = std::env::temp_dir().join(format!("dioxus-isrg-doctest-{}", std::process::id()));
+    /// # let mut renderer = IncrementalRenderer::builder().static_dir(static_dir).build();
     /// let route = "/index";
     /// let response = renderer.get(route).unwrap();
     /// assert!(response.is_none());
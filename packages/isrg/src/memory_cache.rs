@@ -51,11 +51,11 @@ impl InMemoryCache {
             let (timestamp, _) = memory_cache.try_get_or_insert(route.to_string(), or_insert)?;
 
             let now = Utc::now();
-            let elapsed = timestamp.signed_duration_since(now);
-            let age = elapsed.num_seconds();
+            let elapsed = now.signed_duration_since(*timestamp);
+            let age = elapsed.num_seconds().max(0);
             // The cache entry is out of date, so we need to remove it.
             if let Some(invalidate_after) = self.invalidate_after {
-                if elapsed.to_std().unwrap() > invalidate_after {
+                if elapsed.to_std().unwrap_or_default() > invalidate_after {
                     tracing::trace!("memory cache out of date");
                     memory_cache.pop(route);
                     return Ok(None);
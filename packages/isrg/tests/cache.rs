@@ -0,0 +1,137 @@
+use dioxus_isrg::{IncrementalRenderer, IncrementalRendererConfig, RenderFreshness};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// A static directory that is only used by one test
+fn static_dir(test: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("dioxus-isrg-{test}-{}", std::process::id()))
+}
+
+fn renderer(test: &str) -> IncrementalRendererConfig {
+    IncrementalRenderer::builder().static_dir(static_dir(test))
+}
+
+#[test]
+fn freshness_is_written_to_the_headers() {
+    let mut headers = http::HeaderMap::new();
+    RenderFreshness::now(Some(Duration::from_secs(60))).write(&mut headers);
+    assert_eq!(headers[http::header::AGE], "0");
+    assert_eq!(headers[http::header::CACHE_CONTROL], "max-age=60");
+
+    // Without a max age the response can be cached forever
+    let mut headers = http::HeaderMap::new();
+    RenderFreshness::now(None).write(&mut headers);
+    assert_eq!(headers[http::header::AGE], "0");
+    assert!(!headers.contains_key(http::header::CACHE_CONTROL));
+}
+
+#[test]
+fn cached_routes_report_their_freshness() {
+    let mut renderer = renderer("freshness")
+        .invalidate_after(Duration::from_secs(60))
+        .build();
+    let freshness = renderer.cache("/".to_string(), b"home".to_vec()).unwrap();
+    assert_eq!(freshness.age(), 0);
+    assert_eq!(freshness.max_age(), Some(60));
+
+    let cached = renderer.get("/").unwrap().unwrap();
+    assert_eq!(cached.response, b"home");
+    assert_eq!(cached.freshness.age(), 0);
+    assert_eq!(cached.freshness.max_age(), Some(60));
+
+    renderer.invalidate_all();
+}
+
+#[test]
+fn routes_are_revalidated_after_they_expire() {
+    let mut renderer = renderer("expire")
+        .invalidate_after(Duration::from_millis(50))
+        .build();
+    renderer.cache("/".to_string(), b"home".to_vec()).unwrap();
+    assert!(renderer.get("/").unwrap().is_some());
+
+    std::thread::sleep(Duration::from_millis(100));
+    assert!(renderer.get("/").unwrap().is_none());
+
+    // Rendering the route again makes it fresh
+    renderer
+        .cache("/".to_string(), b"new home".to_vec())
+        .unwrap();
+    assert_eq!(renderer.get("/").unwrap().unwrap().response, b"new home");
+
+    renderer.invalidate_all();
+}
+
+#[test]
+fn evicted_routes_are_read_from_the_file_cache() {
+    let mut renderer = renderer("evicted")
+        .memory_cache_limit(1)
+        .invalidate_after(Duration::from_secs(60))
+        .build();
+    renderer
+        .cache("/first".to_string(), b"first".to_vec())
+        .unwrap();
+    renderer
+        .cache("/second".to_string(), b"second".to_vec())
+        .unwrap();
+
+    let cached = renderer.get("/first").unwrap().unwrap();
+    assert_eq!(cached.response, b"first");
+    assert_eq!(cached.freshness.max_age(), Some(60));
+
+    renderer.invalidate("/first");
+    assert!(renderer.get("/first").unwrap().is_none());
+
+    renderer.invalidate_all();
+}
+
+#[test]
+fn tags_only_invalidate_the_routes_cached_with_them() {
+    let mut renderer = renderer("tags").build();
+    renderer
+        .cache_with_tags("/posts/1".to_string(), b"post 1".to_vec(), ["posts"])
+        .unwrap();
+    renderer
+        .cache_with_tags(
+            "/posts/2".to_string(),
+            b"post 2".to_vec(),
+            ["posts", "jane"],
+        )
+        .unwrap();
+    renderer
+        .cache("/about".to_string(), b"about".to_vec())
+        .unwrap();
+
+    renderer.invalidate_tag("jane");
+    assert!(renderer.get("/posts/1").unwrap().is_some());
+    assert!(renderer.get("/posts/2").unwrap().is_none());
+    assert!(renderer.get("/about").unwrap().is_some());
+
+    // A route that is rendered again without the tag is no longer invalidated by it
+    renderer.invalidate("/posts/1");
+    renderer
+        .cache("/posts/1".to_string(), b"post 1".to_vec())
+        .unwrap();
+    renderer.invalidate_tag("posts");
+    assert!(renderer.get("/posts/1").unwrap().is_some());
+
+    renderer.invalidate_all();
+}
+
+#[test]
+fn caching_a_route_again_replaces_its_tags() {
+    let mut renderer = renderer("retag").build();
+    renderer
+        .cache_with_tags("/posts/1".to_string(), b"draft".to_vec(), ["drafts"])
+        .unwrap();
+    renderer
+        .cache_with_tags("/posts/1".to_string(), b"post 1".to_vec(), ["posts"])
+        .unwrap();
+
+    renderer.invalidate_tag("drafts");
+    assert!(renderer.get("/posts/1").unwrap().is_some());
+    renderer.invalidate_tag("posts");
+    assert!(renderer.get("/posts/1").unwrap().is_none());
+
+    renderer.invalidate_all();
+}
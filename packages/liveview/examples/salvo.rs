@@ -0,0 +1,27 @@
+use dioxus::prelude::*;
+use dioxus_liveview::LiveviewRouter;
+use salvo::Router;
+
+fn app() -> Element {
+    let mut num = use_signal(|| 0);
+
+    rsx! {
+        div {
+            "hello salvo! {num}"
+            button { onclick: move |_| num += 1, "Increment" }
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    pretty_env_logger::init();
+
+    let addr: std::net::SocketAddr = ([127, 0, 0, 1], 3030).into();
+
+    let router = Router::new().with_app("/", app);
+
+    println!("Listening on http://{addr}");
+
+    router.start(addr).await;
+}
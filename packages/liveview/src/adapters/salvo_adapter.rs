@@ -0,0 +1,181 @@
+use std::sync::Arc;
+
+use crate::{interpreter_glue, LiveViewError, LiveViewPool, LiveViewSocket, LiveviewRouter};
+use futures_util::{SinkExt, StreamExt};
+use salvo::{
+    async_trait,
+    http::StatusError,
+    prelude::*,
+    websocket::{Message, WebSocket, WebSocketUpgrade},
+};
+
+/// Convert a Salvo WebSocket into a `LiveViewSocket`.
+///
+/// This is required to launch a LiveView app using the Salvo web framework.
+pub fn salvo_socket(ws: WebSocket) -> impl LiveViewSocket {
+    ws.map(transform_rx)
+        .with(transform_tx)
+        .sink_map_err(|_| LiveViewError::SendingFailed)
+}
+
+fn transform_rx(message: Result<Message, salvo::Error>) -> Result<Vec<u8>, LiveViewError> {
+    let message = message.map_err(|_| LiveViewError::SendingFailed)?;
+    let text = message.to_str().map_err(|_| LiveViewError::SendingFailed)?;
+    Ok(text.as_bytes().to_vec())
+}
+
+async fn transform_tx(message: Vec<u8>) -> Result<Message, salvo::Error> {
+    Ok(Message::binary(message))
+}
+
+/// A Salvo handler that upgrades the connection to a WebSocket and runs a new VirtualDom over it.
+struct LiveViewSocketHandler {
+    view: LiveViewPool,
+    app: Arc<dyn Fn() -> dioxus_core::prelude::VirtualDom + Send + Sync>,
+}
+
+#[async_trait]
+impl Handler for LiveViewSocketHandler {
+    async fn handle(
+        &self,
+        req: &mut Request,
+        _depot: &mut Depot,
+        res: &mut Response,
+        _ctrl: &mut FlowCtrl,
+    ) {
+        let view = self.view.clone();
+        let app = self.app.clone();
+        let upgrade = WebSocketUpgrade::new()
+            .upgrade(req, res, move |socket| async move {
+                _ = view
+                    .launch_virtualdom(salvo_socket(socket), move || app())
+                    .await;
+            })
+            .await;
+        if let Err(err) = upgrade {
+            res.render(StatusError::bad_request().brief(err.to_string()));
+        }
+    }
+}
+
+/// A Salvo handler that serves the index page with the interpreter glue for a LiveView app.
+struct LiveViewIndexHandler {
+    index: String,
+}
+
+#[async_trait]
+impl Handler for LiveViewIndexHandler {
+    async fn handle(
+        &self,
+        _req: &mut Request,
+        _depot: &mut Depot,
+        res: &mut Response,
+        _ctrl: &mut FlowCtrl,
+    ) {
+        res.render(Text::Html(self.index.clone()));
+    }
+}
+
+impl LiveviewRouter for Router {
+    fn create_default_liveview_router() -> Self {
+        Router::new()
+    }
+
+    fn with_virtual_dom(
+        self,
+        route: &str,
+        app: impl Fn() -> dioxus_core::prelude::VirtualDom + Send + Sync + 'static,
+    ) -> Self {
+        let view = LiveViewPool::new();
+
+        let route = route.trim_matches('/');
+        // Salvo paths don't start with a slash, and the root route is empty
+        let ws_path = match route {
+            "" => "ws".to_string(),
+            route => format!("{route}/ws"),
+        };
+        let title = crate::app_title();
+        let glue = interpreter_glue(&format!("/{ws_path}"));
+
+        let index = format!(
+            r#"
+        <!DOCTYPE html>
+        <html>
+            <head><title>{title}</title></head>
+            <body><div id="main"></div></body>
+            {glue}
+        </html>
+        "#,
+        );
+
+        self.push(Router::with_path(&ws_path).get(LiveViewSocketHandler {
+            view,
+            app: Arc::new(app),
+        }))
+        .push(Router::with_path(route).get(LiveViewIndexHandler { index }))
+    }
+
+    async fn start(self, address: impl Into<std::net::SocketAddr>) {
+        let acceptor = TcpListener::new(address.into()).bind().await;
+        Server::new(acceptor).serve(self).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dioxus_core::prelude::*;
+    use salvo::test::{ResponseExt, TestClient};
+
+    fn app() -> Element {
+        VNode::empty()
+    }
+
+    fn service() -> Service {
+        Service::new(Router::new().with_app("/app", app))
+    }
+
+    #[tokio::test]
+    async fn index_serves_the_interpreter_glue() {
+        let mut res = TestClient::get("http://127.0.0.1:8080/app")
+            .send(&service())
+            .await;
+        assert_eq!(res.status_code, Some(StatusCode::OK));
+        let body = res.take_string().await.unwrap();
+        assert!(body.contains(r#"<div id="main"></div>"#));
+        assert!(body.contains(r#"__dioxusGetWsUrl("/app/ws")"#));
+    }
+
+    #[tokio::test]
+    async fn root_route_serves_the_socket_at_the_root() {
+        let service = Service::new(Router::new().with_app("/", app));
+        let mut res = TestClient::get("http://127.0.0.1:8080/")
+            .send(&service)
+            .await;
+        assert_eq!(res.status_code, Some(StatusCode::OK));
+        let body = res.take_string().await.unwrap();
+        assert!(body.contains(r#"__dioxusGetWsUrl("/ws")"#));
+
+        let res = TestClient::get("http://127.0.0.1:8080/ws")
+            .send(&service)
+            .await;
+        assert_eq!(res.status_code, Some(StatusCode::BAD_REQUEST));
+    }
+
+    #[tokio::test]
+    async fn socket_route_rejects_requests_without_an_upgrade() {
+        let res = TestClient::get("http://127.0.0.1:8080/app/ws")
+            .send(&service())
+            .await;
+        assert_eq!(res.status_code, Some(StatusCode::BAD_REQUEST));
+    }
+
+    #[test]
+    fn text_messages_are_forwarded_to_the_virtual_dom() {
+        assert_eq!(
+            transform_rx(Ok(Message::text("{\"type\":\"event\"}"))).unwrap(),
+            b"{\"type\":\"event\"}"
+        );
+        assert!(transform_rx(Ok(Message::binary(vec![0xff]))).is_err());
+    }
+}
@@ -133,6 +133,8 @@ pub async fn run(mut vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), Li
     vdom.in_runtime(|| {
         ScopeId::ROOT.provide_context(query_engine.clone());
         init_eval();
+        #[cfg(feature = "focus")]
+        dioxus_html::focus::init_autofocus();
     });
 
     // pin the futures so we can use select!
@@ -146,6 +148,17 @@ pub async fn run(mut vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), Li
         ws.send(edits).await?;
     }
 
+    // Stream the component tree to the component inspector
+    #[cfg(all(feature = "inspector", debug_assertions))]
+    let mut inspector = dioxus_devtools::Inspector::from_env();
+    #[cfg(all(feature = "inspector", debug_assertions))]
+    if let Some(inspector) = &mut inspector {
+        inspector.update(&vdom);
+    }
+
+    #[cfg(debug_assertions)]
+    let mut recorder = dioxus_html::replay::EventRecorder::from_env();
+
     // desktop uses this wrapper struct thing around the actual event itself
     // this is sorta driven by tao/wry
     #[derive(serde::Deserialize, Debug)]
@@ -177,6 +190,11 @@ pub async fn run(mut vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), Li
                         if let Ok(message) = serde_json::from_str::<IpcMessage>(&String::from_utf8_lossy(evt)) {
                             match message {
                                 IpcMessage::Event(evt) => {
+                                    #[cfg(debug_assertions)]
+                                    if let Some(recorder) = &mut recorder {
+                                        recorder.record(&evt);
+                                    }
+
                                     // Intercept the mounted event and insert a custom element type
                                     let event = if let EventData::Mounted = &evt.data {
                                         let element = LiveviewElement::new(evt.element, query_engine.clone());
@@ -243,6 +261,11 @@ pub async fn run(mut vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), Li
         // render the vdom
         vdom.render_immediate(&mut mutations);
 
+        #[cfg(all(feature = "inspector", debug_assertions))]
+        if let Some(inspector) = &mut inspector {
+            inspector.update(&vdom);
+        }
+
         if let Some(edits) = take_edits(&mut mutations) {
             ws.send(edits).await?;
         }
@@ -33,3 +33,27 @@ test("hydration", async ({ page }) => {
   const errors = page.locator("#errors");
   await expect(errors).toContainText("Hmm, something went wrong.");
 });
+
+test("events fired before hydration are replayed", async ({ page }) => {
+  // Hold back the wasm bundle so the page stays unhydrated until the button was clicked
+  let loadWasm;
+  const wasmRequested = new Promise((resolve) => (loadWasm = resolve));
+  await page.route("**/*.wasm", async (route) => {
+    await wasmRequested;
+    await route.continue();
+  });
+  await page.goto("http://localhost:3333");
+
+  const main = page.locator("#main");
+  await expect(main).toContainText("hello axum! 12345");
+
+  // Click the increment button before the listeners are attached
+  let button = page.locator("button.increment-button");
+  await button.click();
+  await expect(main).toContainText("hello axum! 12345");
+
+  // Once the page hydrates, the recorded click is handled
+  loadWasm();
+  await expect(main).toContainText("hello axum! 12346");
+  await expect(page).toHaveTitle("hello axum! 12346");
+});
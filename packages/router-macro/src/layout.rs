@@ -1,8 +1,11 @@
 use proc_macro2::TokenStream;
-use quote::quote;
-use syn::Path;
+use quote::{format_ident, quote};
+use syn::{Ident, Path};
 
-use crate::nest::{Nest, NestId};
+use crate::{
+    nest::{Nest, NestId},
+    segment::RouteSegment,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct LayoutId(pub usize);
@@ -14,19 +17,50 @@ pub struct Layout {
 }
 
 impl Layout {
-    pub fn routable_match(&self, nests: &[Nest]) -> TokenStream {
-        let comp_name = &self.comp;
+    pub fn routable_match(&self, id: LayoutId, nests: &[Nest]) -> TokenStream {
+        let render_fn = Self::render_fn_name(id);
         let dynamic_segments = self
             .active_nests
             .iter()
             .flat_map(|id| nests[id.0].dynamic_segments());
 
         quote! {
-            rsx! {
-                #comp_name { #(#dynamic_segments: #dynamic_segments,)* }
+            #render_fn(#(#dynamic_segments,)*)
+        }
+    }
+
+    /// Every route in a layout renders the layout through the same function. Each call to rsx creates a new template,
+    /// so rendering the layout in the match arm of each route would remount the layout when navigating between them.
+    pub fn render_fn(&self, id: LayoutId, nests: &[Nest]) -> TokenStream {
+        let comp_name = &self.comp;
+        let render_fn = Self::render_fn_name(id);
+        let segments: Vec<_> = self
+            .active_nests
+            .iter()
+            .flat_map(|id| nests[id.0].segments.iter())
+            .filter_map(|segment| match segment {
+                RouteSegment::Dynamic(name, ty) | RouteSegment::CatchAll(name, ty) => {
+                    Some((name, ty))
+                }
+                RouteSegment::Static(_) => None,
+            })
+            .collect();
+        let names = segments.iter().map(|(name, _)| name);
+        let args = segments.iter().map(|(name, ty)| quote! { #name: #ty });
+
+        quote! {
+            #[allow(non_snake_case, unused)]
+            fn #render_fn(#(#args,)*) -> dioxus_core::Element {
+                rsx! {
+                    #comp_name { #(#names: #names,)* }
+                }
             }
         }
     }
+
+    fn render_fn_name(id: LayoutId) -> Ident {
+        format_ident!("__render_layout_{}", id.0)
+    }
 }
 
 impl Layout {
@@ -4,6 +4,7 @@
 extern crate proc_macro;
 
 use layout::Layout;
+use meta::RouteMeta;
 use nest::{Nest, NestId};
 use proc_macro::TokenStream;
 use quote::{__private::Span, format_ident, quote, ToTokens};
@@ -18,6 +19,7 @@ use crate::{layout::LayoutId, route_tree::ParseRouteTree};
 
 mod hash;
 mod layout;
+mod meta;
 mod nest;
 mod query;
 mod redirect;
@@ -238,10 +240,42 @@ mod segment;
 /// # #[component]
 /// # fn Home() -> Element { VNode::empty() }
 /// ```
+///
+/// # `#[meta(..)]`
+///
+/// The `#[meta]` attribute declares the metadata the `Router` writes into the head of the page when the route is
+/// active. It takes a list of `key = "value"` pairs:
+/// - `title`: The title of the page
+/// - `description`: The content of `<meta name="description">`
+/// - `og_*`: An Open Graph property. `og_image = ".."` is written into `<meta property="og:image">`
+///
+/// The values can contain the fields of the variant like a format string. On the enum, the attribute sets the
+/// defaults of every route, and it also accepts `title_template`, which formats the titles of the routes.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq, Routable)]
+/// #[meta(title_template = "{title} | Blog", description = "My blog", og_site_name = "Blog")]
+/// enum Route {
+///     // The title of this route is "Home | Blog"
+///     #[route("/")]
+///     #[meta(title = "Home")]
+///     Home {},
+///     // The title and the og:image property are formatted with the id
+///     #[route("/post/:id")]
+///     #[meta(title = "Post {id}", og_image = "/covers/{id}.png")]
+///     Post { id: usize },
+/// }
+/// # #[component]
+/// # fn Home() -> Element { VNode::empty() }
+/// # #[component]
+/// # fn Post(id: usize) -> Element { VNode::empty() }
+/// ```
 #[doc(alias = "route")]
 #[proc_macro_derive(
     Routable,
-    attributes(route, nest, end_nest, layout, end_layout, redirect, child)
+    attributes(route, nest, end_nest, layout, end_layout, redirect, child, meta)
 )]
 pub fn routable(input: TokenStream) -> TokenStream {
     let routes_enum = parse_macro_input!(input as syn::ItemEnum);
@@ -274,11 +308,13 @@ struct RouteEnum {
     nests: Vec<Nest>,
     layouts: Vec<Layout>,
     site_map: Vec<SiteMapSegment>,
+    meta: Option<RouteMeta>,
 }
 
 impl RouteEnum {
     fn parse(data: syn::ItemEnum) -> syn::Result<Self> {
         let name = &data.ident;
+        let meta = RouteMeta::from_attrs(&data.attrs)?;
 
         let mut site_map = Vec::new();
         let mut site_map_stack: Vec<Vec<SiteMapSegment>> = Vec::new();
@@ -471,6 +507,7 @@ impl RouteEnum {
             nests,
             layouts,
             site_map,
+            meta,
         };
 
         // If we're on the web, only the URL history is preserved between navigation. We need to warn the user that the segment is not present in the URL.
@@ -739,6 +776,14 @@ impl RouteEnum {
             }
         }
 
+        let layout_render_fns = self
+            .layouts
+            .iter()
+            .enumerate()
+            .map(|(id, layout)| layout.render_fn(LayoutId(id), &self.nests));
+
+        let metadata_fn = self.metadata_fn();
+
         quote! {
             impl dioxus_router::routable::Routable for #name where Self: Clone {
                 const SITE_MAP: &'static [dioxus_router::routable::SiteMapSegment] = &[
@@ -746,15 +791,55 @@ impl RouteEnum {
                 ];
 
                 fn render(&self, level: usize) -> dioxus_core::Element {
+                    #(#layout_render_fns)*
+
                     let myself = self.clone();
                     match (level, myself) {
                         #(#matches)*
                         _ => VNode::empty()
                     }
                 }
+
+                #metadata_fn
             }
         }
     }
+
+    fn metadata_fn(&self) -> Option<TokenStream2> {
+        let matches = self
+            .endpoints
+            .iter()
+            .filter_map(|endpoint| match endpoint {
+                RouteEndpoint::Route(route) => route.metadata_match(),
+                RouteEndpoint::Redirect(_) => None,
+            })
+            .collect::<Vec<_>>();
+        if self.meta.is_none() && matches.is_empty() {
+            return None;
+        }
+
+        let title_template = self
+            .meta
+            .as_ref()
+            .and_then(|meta| meta.title_template.as_ref())
+            .map(|template| quote! { .with_title_template(#template) });
+        let defaults = self.meta.as_ref().map(|meta| {
+            let defaults = meta.construct();
+            quote! { .or(#defaults) }
+        });
+
+        Some(quote! {
+            #[allow(clippy::useless_format)]
+            fn metadata(&self) -> dioxus_router::routable::RouteMetadata {
+                #[allow(unreachable_patterns)]
+                let metadata = match self {
+                    #(#matches)*
+                    _ => dioxus_router::routable::RouteMetadata::default(),
+                };
+                metadata #title_template #defaults
+            }
+        })
+    }
 }
 
 enum RouteEndpoint {
@@ -0,0 +1,77 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{Ident, LitStr, Token};
+
+/// The metadata from a `#[meta(..)]` attribute
+#[derive(Debug, Default)]
+pub(crate) struct RouteMeta {
+    pub title: Option<LitStr>,
+    pub title_template: Option<LitStr>,
+    pub description: Option<LitStr>,
+    pub properties: Vec<(String, LitStr)>,
+}
+
+impl Parse for RouteMeta {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut meta = RouteMeta::default();
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            let _ = input.parse::<Token![=]>()?;
+            let value: LitStr = input.parse()?;
+            let name = key.to_string();
+            if name == "title" {
+                meta.title = Some(value);
+            } else if name == "title_template" {
+                meta.title_template = Some(value);
+            } else if name == "description" {
+                meta.description = Some(value);
+            } else if let Some(property) = name.strip_prefix("og_") {
+                meta.properties.push((format!("og:{property}"), value));
+            } else {
+                return Err(syn::Error::new_spanned(
+                    key,
+                    "Unknown metadata. Expected `title`, `title_template`, `description` or an Open Graph property like `og_image`",
+                ));
+            }
+            if !input.is_empty() {
+                let _ = input.parse::<Token![,]>()?;
+            }
+        }
+        Ok(meta)
+    }
+}
+
+impl RouteMeta {
+    /// Parse the `#[meta(..)]` attribute from a list of attributes
+    pub fn from_attrs(attrs: &[syn::Attribute]) -> syn::Result<Option<Self>> {
+        attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("meta"))
+            .map(|attr| attr.parse_args())
+            .transpose()
+    }
+
+    /// Create the `RouteMetadata`. The strings are formatted, so they can contain any of the fields that are in scope.
+    pub fn construct(&self) -> TokenStream2 {
+        let title = match &self.title {
+            Some(title) => quote! { Some(format!(#title)) },
+            None => quote! { None },
+        };
+        let description = match &self.description {
+            Some(description) => quote! { Some(format!(#description)) },
+            None => quote! { None },
+        };
+        let properties = self
+            .properties
+            .iter()
+            .map(|(property, content)| quote! { (#property.to_string(), format!(#content)) });
+        quote! {
+            dioxus_router::routable::RouteMetadata {
+                title: #title,
+                description: #description,
+                properties: vec![#(#properties),*],
+            }
+        }
+    }
+}
@@ -12,6 +12,7 @@ use proc_macro2::TokenStream as TokenStream2;
 use crate::hash::HashFragment;
 use crate::layout::Layout;
 use crate::layout::LayoutId;
+use crate::meta::RouteMeta;
 use crate::nest::Nest;
 use crate::nest::NestId;
 use crate::query::QuerySegment;
@@ -60,6 +61,7 @@ pub(crate) struct Route {
     pub hash: Option<HashFragment>,
     pub nests: Vec<NestId>,
     pub layouts: Vec<LayoutId>,
+    pub meta: Option<RouteMeta>,
     fields: Vec<(Ident, Type)>,
 }
 
@@ -146,6 +148,14 @@ impl Route {
             _ => Vec::new(),
         };
 
+        let meta = RouteMeta::from_attrs(&variant.attrs)?;
+        if let Some(template) = meta.as_ref().and_then(|meta| meta.title_template.as_ref()) {
+            return Err(syn::Error::new_spanned(
+                template,
+                "`title_template` can only be set in the `#[meta(..)]` attribute of the enum",
+            ));
+        }
+
         let (route_segments, query, hash) = {
             parse_route_segments(
                 variant.ident.span(),
@@ -163,6 +173,7 @@ impl Route {
             hash,
             nests,
             layouts,
+            meta,
             fields,
         })
     }
@@ -218,7 +229,7 @@ impl Route {
 
         // First match all layouts
         for (idx, layout_id) in self.layouts.iter().copied().enumerate() {
-            let render_layout = layouts[layout_id.0].routable_match(nests);
+            let render_layout = layouts[layout_id.0].routable_match(layout_id, nests);
             let dynamic_segments = self.dynamic_segments();
             let mut field_name = None;
             if let RouteType::Child(field) = &self.ty {
@@ -265,6 +276,33 @@ impl Route {
         tokens
     }
 
+    pub fn metadata_match(&self) -> Option<TokenStream2> {
+        let name = &self.route_name;
+        let dynamic_segments = self.dynamic_segments();
+        match &self.ty {
+            RouteType::Child(field) => {
+                let child = field.ident.as_ref().unwrap();
+                let metadata = match &self.meta {
+                    Some(meta) => meta.construct(),
+                    None => quote! { dioxus_router::routable::RouteMetadata::default() },
+                };
+                Some(quote! {
+                    #[allow(unused)]
+                    Self::#name { #(#dynamic_segments,)* #child } => {
+                        #metadata.or(dioxus_router::routable::Routable::metadata(#child))
+                    }
+                })
+            }
+            RouteType::Leaf { .. } => {
+                let metadata = self.meta.as_ref()?.construct();
+                Some(quote! {
+                    #[allow(unused)]
+                    Self::#name { #(#dynamic_segments,)* } => #metadata,
+                })
+            }
+        }
+    }
+
     fn dynamic_segments(&self) -> impl Iterator<Item = TokenStream2> + '_ {
         self.fields.iter().map(|(name, _)| {
             quote! {#name}
@@ -0,0 +1,186 @@
+use dioxus_lib::prelude::*;
+
+use crate::{
+    contexts::outlet::{use_outlet_context, OutletContext},
+    routable::Routable,
+    utils::use_router_internal::use_router_internal,
+};
+
+/// The props for [`KeepAliveOutlet`].
+#[derive(Props)]
+pub struct KeepAliveOutletProps<R: Routable> {
+    /// Routes that are rendered offscreen before they are visited, so they show up instantly on the first
+    /// navigation.
+    #[props(default)]
+    pub prerender: Vec<R>,
+
+    /// The maximum number of inactive routes that are kept alive. When there are more, the route that was active
+    /// least recently is dropped. By default every visited route is kept alive.
+    #[props(default)]
+    pub max_inactive: Option<usize>,
+}
+
+impl<R: Routable> Clone for KeepAliveOutletProps<R> {
+    fn clone(&self) -> Self {
+        Self {
+            prerender: self.prerender.clone(),
+            max_inactive: self.max_inactive,
+        }
+    }
+}
+
+impl<R: Routable> PartialEq for KeepAliveOutletProps<R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.max_inactive == other.max_inactive
+            && self.prerender.len() == other.prerender.len()
+            && self
+                .prerender
+                .iter()
+                .zip(&other.prerender)
+                .all(|(a, b)| a.to_string() == b.to_string())
+    }
+}
+
+/// An [`Outlet`](crate::prelude::Outlet) that keeps the routes it rendered alive when they become inactive.
+///
+/// Inactive routes are hidden with `display: none` instead of being dropped, so their components keep their state,
+/// their scroll position and the values of their form fields. Navigating back to a route that is kept alive swaps it
+/// in without rendering it again. The active route is wrapped in an element with `display: contents` so the wrapper
+/// doesn't change the layout.
+///
+/// Routes that are kept alive are identified by their url, so `/post/1` and `/post/2` are kept alive separately.
+/// Inactive routes keep running: their signals, effects and futures still update. [`use_route`](crate::prelude::use_route)
+/// returns the current route of the router in every route.
+///
+/// # Example
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_router::prelude::*;
+/// #[derive(Clone, Routable)]
+/// #[rustfmt::skip]
+/// enum Route {
+///     #[layout(Tabs)]
+///         #[route("/")]
+///         Inbox {},
+///         #[route("/drafts")]
+///         Drafts {},
+/// }
+///
+/// #[component]
+/// fn Tabs() -> Element {
+///     rsx! {
+///         Link { to: Route::Inbox {}, "Inbox" }
+///         Link { to: Route::Drafts {}, "Drafts" }
+///         // Switching tabs keeps the draft that is being written
+///         KeepAliveOutlet::<Route> { prerender: vec![Route::Drafts {}] }
+///     }
+/// }
+///
+/// #[component]
+/// fn Inbox() -> Element {
+///     rsx! { "Inbox" }
+/// }
+///
+/// #[component]
+/// fn Drafts() -> Element {
+///     let mut draft = use_signal(String::new);
+///     rsx! { textarea { value: "{draft}", oninput: move |e| draft.set(e.value()) } }
+/// }
+/// #
+/// # #[component]
+/// # fn App() -> Element {
+/// #     rsx! { Router::<Route> {} }
+/// # }
+/// #
+/// # let mut vdom = VirtualDom::new(App);
+/// # vdom.rebuild_in_place();
+/// # assert_eq!(
+/// #     dioxus_ssr::render(&vdom),
+/// #     r#"<a href="/" aria-current="page">Inbox</a><a href="/drafts">Drafts</a><div style="display: none"><textarea value=""></textarea></div><div style="display: contents">Inbox</div>"#
+/// # );
+/// ```
+pub fn KeepAliveOutlet<R: Routable + Clone>(props: KeepAliveOutletProps<R>) -> Element {
+    let router = use_router_internal().expect("KeepAliveOutlet must be inside of a router");
+    let outlet: OutletContext<R> = use_outlet_context();
+    let level = outlet.current_level;
+
+    // The routes that are kept alive, ordered from the least to the most recently active
+    let mut kept = use_hook(|| CopyValue::new(props.prerender.clone()));
+
+    if let Some(error) = router.render_error() {
+        return if level == 0 { error } else { VNode::empty() };
+    }
+
+    let current = outlet.route.unwrap_or_else(|| router.current::<R>());
+    let current_url = current.to_string();
+    let routes = {
+        let mut kept = kept.write();
+        kept.retain(|route| route.to_string() != current_url);
+        if let Some(max_inactive) = props.max_inactive {
+            let evicted = kept.len().saturating_sub(max_inactive);
+            kept.drain(..evicted);
+        }
+        kept.push(current);
+        kept.clone()
+    };
+
+    rsx! {
+        for route in routes {
+            KeptRoute::<R> {
+                key: "{route}",
+                active: route.to_string() == current_url,
+                route,
+                level,
+            }
+        }
+    }
+}
+
+#[derive(Props)]
+struct KeptRouteProps<R: Routable> {
+    route: R,
+    level: usize,
+    active: bool,
+}
+
+impl<R: Routable> Clone for KeptRouteProps<R> {
+    fn clone(&self) -> Self {
+        Self {
+            route: self.route.clone(),
+            level: self.level,
+            active: self.active,
+        }
+    }
+}
+
+impl<R: Routable> PartialEq for KeptRouteProps<R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.level == other.level
+            && self.active == other.active
+            && self.route.to_string() == other.route.to_string()
+    }
+}
+
+/// A route that is kept alive. Outlets inside of it render the kept route instead of the current route.
+fn KeptRoute<R: Routable>(props: KeptRouteProps<R>) -> Element {
+    let KeptRouteProps {
+        route,
+        level,
+        active,
+    } = props;
+
+    provide_context(OutletContext::<R> {
+        current_level: level + 1,
+        route: Some(route.clone()),
+    });
+
+    let display = if active {
+        "display: contents"
+    } else {
+        "display: none"
+    };
+
+    rsx! {
+        div { style: display, {route.render(level)} }
+    }
+}
@@ -1,11 +1,11 @@
 use dioxus_lib::prelude::*;
 
-use std::str::FromStr;
+use std::{cell::RefCell, rc::Rc, str::FromStr};
 
 use crate::{
-    prelude::{provide_router_context, Outlet},
-    routable::Routable,
-    router_cfg::RouterConfig,
+    prelude::{provide_router_context, router, use_route, Outlet},
+    routable::{Routable, RouteMetadata},
+    router_cfg::{RouterConfig, ScrollRestoration},
 };
 
 /// The props for [`Router`].
@@ -45,17 +45,172 @@ where
 {
     use crate::prelude::{outlet::OutletContext, RouterContext};
 
-    use_hook(|| {
-        provide_router_context(RouterContext::new(
-            props.config.call(()),
-            schedule_update_any(),
-        ));
+    let restore_scroll = use_hook(|| {
+        let config = props.config.call(());
+        let restoration = config.scroll_restoration.clone();
+        provide_router_context(RouterContext::new(config, schedule_update_any()));
 
         provide_context(OutletContext::<R> {
             current_level: 0,
-            _marker: std::marker::PhantomData,
+            route: None,
         });
+
+        restoration.map(provide_context).is_some()
     });
 
-    rsx! { Outlet::<R> {} }
+    rsx! {
+        RouteHead::<R> {}
+        Outlet::<R> {}
+        if restore_scroll {
+            RouteScroll::<R> {}
+        }
+    }
+}
+
+const SCROLL_SCRIPT: &str = r#"
+if ("scrollRestoration" in history) history.scrollRestoration = "manual";
+const positions = new Map();
+let current = null;
+const save = () => {
+    if (current !== null) positions.set(current, [window.scrollX, window.scrollY]);
+};
+window.addEventListener("scroll", save, { passive: true });
+while (true) {
+    // The route that is left, the new route, or null once the router is dropped
+    const message = await dioxus.recv();
+    if (!message) break;
+    if (message.leave) {
+        // Keep the position the route was left at. The scroll events of the next route are ignored until it is
+        // rendered
+        save();
+        current = null;
+        continue;
+    }
+    current = message.route;
+    if (message.scroll === "top") {
+        window.scrollTo(0, 0);
+    } else if (message.scroll === "restore") {
+        const [x, y] = positions.get(current) || [0, 0];
+        window.scrollTo(x, y);
+    }
+    const target = message.focus && document.querySelector(message.focus);
+    if (target) {
+        if (!target.hasAttribute("tabindex")) target.setAttribute("tabindex", "-1");
+        target.focus({ preventScroll: true });
+    }
+    save();
+}
+window.removeEventListener("scroll", save);
+"#;
+
+/// Restore the scroll position and move the focus after the route changes
+fn RouteScroll<R: Routable + Clone>() -> Element {
+    let route = use_route::<R>();
+    let restoration = use_context::<ScrollRestoration<R>>();
+    let mut rendered = use_hook(|| CopyValue::new(None::<String>));
+    let eval = use_hook(|| {
+        document::UseEval::new(document::document().new_evaluator(SCROLL_SCRIPT.to_string()))
+    });
+    // The position of the route that is left is recorded before the history changes, so scroll events fired while
+    // the next route renders are not recorded for it
+    let mut left = use_hook(|| CopyValue::new(false));
+    let leave = move || {
+        let mut left = left;
+        if !*left.peek() {
+            left.set(true);
+            _ = eval.send(serde_json::json!({ "leave": rendered.peek().clone() }));
+        }
+    };
+    use_hook(|| router().set_leave_listener(Some(Rc::new(leave))));
+    use_drop(move || {
+        router().set_leave_listener(None);
+        _ = eval.send(serde_json::Value::Null);
+    });
+
+    let path = route.to_string();
+    if rendered.peek().as_ref() == Some(&path) {
+        return VNode::empty();
+    }
+    // Routes changed by the history itself, like the buttons of the browser, are left once they are rendered
+    if rendered.peek().is_some() {
+        leave();
+    }
+    left.set(false);
+    // The first route keeps the scroll position and the focus the page loaded with
+    let first = rendered.peek().is_none();
+    let pushed = router().take_pushed();
+    let (scroll, focus) = if first || restoration.skips(&route) {
+        ("none", None)
+    } else if pushed {
+        ("top", restoration.focus.clone())
+    } else {
+        ("restore", None)
+    };
+    rendered.set(Some(path.clone()));
+
+    // Wait for the new route to be rendered before scrolling
+    queue_effect(move || {
+        _ = eval.send(serde_json::json!({
+            "route": path,
+            "scroll": scroll,
+            "focus": focus,
+        }));
+    });
+
+    VNode::empty()
+}
+
+/// Apply the [`RouteMetadata`] of the current route to the head of the page
+fn RouteHead<R: Routable + Clone>() -> Element {
+    let metadata = use_route::<R>().metadata();
+    let document = use_hook(document::document);
+    let applied = use_hook(|| Rc::new(RefCell::new(RouteMetadata::default())));
+
+    let mut applied = applied.borrow_mut();
+    if metadata == *applied {
+        return VNode::empty();
+    }
+
+    // The title is kept when the new route doesn't have one
+    if let Some(title) = metadata
+        .title
+        .as_ref()
+        .filter(|title| applied.title.as_ref() != Some(*title))
+    {
+        document.set_title(title.clone());
+    }
+
+    let mut meta_tags = metadata
+        .meta_tags()
+        .map(|(attribute, key, content)| (attribute, key, Some(content)))
+        .collect::<Vec<_>>();
+    // Remove the meta tags of the previous route that the new route doesn't have
+    for (attribute, key, _) in applied.meta_tags() {
+        if !meta_tags.iter().any(|(_, k, _)| *k == key) {
+            meta_tags.push((attribute, key, None));
+        }
+    }
+    for (attribute, key, content) in meta_tags {
+        let unchanged = applied
+            .meta_tags()
+            .any(|(_, k, c)| k == key && Some(c) == content);
+        if unchanged {
+            continue;
+        }
+        let key = Some(key.to_string());
+        let (name, property) = match attribute {
+            "name" => (key, None),
+            _ => (None, key),
+        };
+        document.set_meta(MetaProps {
+            name,
+            property,
+            charset: None,
+            http_equiv: None,
+            content: content.map(str::to_string),
+        });
+    }
+
+    *applied = metadata;
+    VNode::empty()
 }
@@ -4,23 +4,24 @@ use crate::{routable::Routable, utils::use_router_internal::use_router_internal}
 
 pub(crate) struct OutletContext<R> {
     pub current_level: usize,
-    pub _marker: std::marker::PhantomData<R>,
+    /// The route outlets render instead of the current route. This is set for routes a [`KeepAliveOutlet`](crate::prelude::KeepAliveOutlet) keeps alive.
+    pub route: Option<R>,
 }
 
-impl<R> Clone for OutletContext<R> {
+impl<R: Clone> Clone for OutletContext<R> {
     fn clone(&self) -> Self {
         OutletContext {
             current_level: self.current_level,
-            _marker: std::marker::PhantomData,
+            route: self.route.clone(),
         }
     }
 }
 
-pub(crate) fn use_outlet_context<R: 'static>() -> OutletContext<R> {
+pub(crate) fn use_outlet_context<R: Clone + 'static>() -> OutletContext<R> {
     use_hook(|| {
         try_consume_context().unwrap_or(OutletContext::<R> {
             current_level: 1,
-            _marker: std::marker::PhantomData,
+            route: None,
         })
     })
 }
@@ -36,7 +37,7 @@ impl<R> OutletContext<R> {
         provide_context({
             OutletContext::<R> {
                 current_level: current_level + 1,
-                _marker: std::marker::PhantomData,
+                route: outlet.route.clone(),
             }
         });
 
@@ -48,6 +49,9 @@ impl<R> OutletContext<R> {
             };
         }
 
-        router.current::<R>().render(current_level)
+        outlet
+            .route
+            .unwrap_or_else(|| router.current::<R>())
+            .render(current_level)
     }
 }
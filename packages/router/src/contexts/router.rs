@@ -1,6 +1,9 @@
 use std::{
     any::Any,
+    cell::RefCell,
     collections::HashSet,
+    future::Future,
+    pin::Pin,
     rc::Rc,
     sync::{Arc, RwLock},
 };
@@ -50,6 +53,64 @@ pub(crate) type RoutingCallback<R> =
 pub(crate) type AnyRoutingCallback =
     Arc<dyn Fn(RouterContext) -> Option<NavigationTarget<Rc<dyn Any>>>>;
 
+type AnyNavigationGuard = Rc<
+    RefCell<
+        dyn FnMut(
+            NavigationTarget<Rc<dyn Any>>,
+        ) -> Pin<Box<dyn Future<Output = NavigationDecision<Rc<dyn Any>>>>>,
+    >,
+>;
+
+/// What a navigation guard decides to do with a navigation. See [`RouterContext::on_before_navigate`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum NavigationDecision<R> {
+    /// Continue with the navigation
+    Continue,
+    /// Stay on the current route
+    Cancel,
+    /// Navigate to a different target instead
+    Redirect(NavigationTarget<R>),
+}
+
+/// A guard that was added with [`RouterContext::on_before_navigate`]
+#[derive(Clone, Copy)]
+pub struct NavigationGuard {
+    router: RouterContext,
+    id: usize,
+}
+
+impl NavigationGuard {
+    /// Stop running the guard before navigations
+    pub fn remove(self) {
+        self.router
+            .inner
+            .write_unchecked()
+            .guards
+            .retain(|(id, _)| *id != self.id);
+    }
+}
+
+fn downcast_target<R: Routable>(target: NavigationTarget<Rc<dyn Any>>) -> NavigationTarget<R> {
+    match target {
+        NavigationTarget::Internal(route) => NavigationTarget::Internal(
+            route
+                .downcast_ref::<R>()
+                .expect("Route is not of the expected type")
+                .clone(),
+        ),
+        NavigationTarget::External(url) => NavigationTarget::External(url),
+    }
+}
+
+fn upcast_target<R: Routable>(target: NavigationTarget<R>) -> NavigationTarget<Rc<dyn Any>> {
+    match target {
+        NavigationTarget::Internal(route) => {
+            NavigationTarget::Internal(Rc::new(route) as Rc<dyn Any>)
+        }
+        NavigationTarget::External(url) => NavigationTarget::External(url),
+    }
+}
+
 struct RouterContextInner {
     /// The current prefix.
     prefix: Option<String>,
@@ -62,6 +123,15 @@ struct RouterContextInner {
     subscriber_update: Arc<dyn Fn(ScopeId)>,
     routing_callback: Option<AnyRoutingCallback>,
 
+    guards: Vec<(usize, AnyNavigationGuard)>,
+    next_guard: usize,
+    /// Incremented on every navigation, so guards that finish after a newer navigation started are ignored
+    navigation: usize,
+    /// If the current route was pushed or replaced by the router instead of reached by moving through the history
+    pushed: bool,
+    /// Called right before the router changes the current route
+    leave_listener: Option<Rc<dyn Fn()>>,
+
     failure_external_navigation: fn() -> Element,
 
     any_route_to_string: fn(&dyn Any) -> String,
@@ -122,16 +192,17 @@ impl RouterContext {
                         inner: ctx,
                         _marker: std::marker::PhantomData,
                     };
-                    update(ctx).map(|t| match t {
-                        NavigationTarget::Internal(r) => {
-                            NavigationTarget::Internal(Rc::new(r) as Rc<dyn Any>)
-                        }
-                        NavigationTarget::External(s) => NavigationTarget::External(s),
-                    })
+                    update(ctx).map(upcast_target)
                 })
                     as Arc<dyn Fn(RouterContext) -> Option<NavigationTarget<Rc<dyn Any>>>>
             }),
 
+            guards: Vec::new(),
+            next_guard: 0,
+            navigation: 0,
+            pushed: false,
+            leave_listener: None,
+
             failure_external_navigation: cfg.failure_external_navigation,
 
             any_route_to_string: |route| {
@@ -198,8 +269,13 @@ impl RouterContext {
     ///
     /// Will fail silently if there is no previous location to go to.
     pub fn go_back(&self) {
+        if self.can_go_back() {
+            self.leave();
+        }
         {
-            self.inner.write_unchecked().history.go_back();
+            let mut write = self.inner.write_unchecked();
+            write.history.go_back();
+            write.pushed = false;
         }
 
         self.change_route();
@@ -209,8 +285,13 @@ impl RouterContext {
     ///
     /// Will fail silently if there is no next location to go to.
     pub fn go_forward(&self) {
+        if self.can_go_forward() {
+            self.leave();
+        }
         {
-            self.inner.write_unchecked().history.go_forward();
+            let mut write = self.inner.write_unchecked();
+            write.history.go_forward();
+            write.pushed = false;
         }
 
         self.change_route();
@@ -220,50 +301,190 @@ impl RouterContext {
         &self,
         target: NavigationTarget<Rc<dyn Any>>,
     ) -> Option<ExternalNavigationFailure> {
-        {
-            let mut write = self.inner.write_unchecked();
-            match target {
-                NavigationTarget::Internal(p) => write.history.push(p),
-                NavigationTarget::External(e) => return write.external(e),
-            }
-        }
-
-        self.change_route()
+        self.navigate(target, false)
     }
 
     /// Push a new location.
     ///
     /// The previous location will be available to go back to.
+    ///
+    /// If any [navigation guards](RouterContext::on_before_navigate) are registered, the navigation happens once
+    /// they allow it and this returns [`None`].
     pub fn push(&self, target: impl Into<IntoRoutable>) -> Option<ExternalNavigationFailure> {
         let target = self.resolve_into_routable(target.into());
-        {
-            let mut write = self.inner.write_unchecked();
-            match target {
-                NavigationTarget::Internal(p) => write.history.push(p),
-                NavigationTarget::External(e) => return write.external(e),
-            }
-        }
-
-        self.change_route()
+        self.navigate(target, false)
     }
 
     /// Replace the current location.
     ///
     /// The previous location will **not** be available to go back to.
+    ///
+    /// If any [navigation guards](RouterContext::on_before_navigate) are registered, the navigation happens once
+    /// they allow it and this returns [`None`].
     pub fn replace(&self, target: impl Into<IntoRoutable>) -> Option<ExternalNavigationFailure> {
         let target = self.resolve_into_routable(target.into());
+        self.navigate(target, true)
+    }
+
+    /// Run a guard before every navigation with [`push`](RouterContext::push) or
+    /// [`replace`](RouterContext::replace), including the navigations of [`Link`](crate::components::Link)s.
+    ///
+    /// The guard receives the target of the navigation and decides if the router should continue, stay on the
+    /// current route or redirect to a different target. Guards run one after another in the order they were added.
+    /// After a redirect, the remaining guards receive the new target. If another navigation starts while the guards
+    /// run, the older navigation is dropped.
+    ///
+    /// Going back and forward is not guarded, because the browser changes the URL before the router is notified.
+    ///
+    /// Use [`use_before_navigate`](crate::hooks::use_before_navigate) to add a guard while a component is rendered.
+    ///
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// # use dioxus_router::prelude::*;
+    /// # #[component]
+    /// # fn Home() -> Element { VNode::empty() }
+    /// # #[component]
+    /// # fn Admin() -> Element { VNode::empty() }
+    /// # #[component]
+    /// # fn Login() -> Element { VNode::empty() }
+    /// # async fn logged_in() -> bool { false }
+    /// #[derive(Routable, Clone, PartialEq, Debug)]
+    /// enum Route {
+    ///     #[layout(Guarded)]
+    ///         #[route("/")]
+    ///         Home {},
+    ///         #[route("/admin")]
+    ///         Admin {},
+    ///         #[route("/login")]
+    ///         Login {},
+    /// }
+    ///
+    /// #[component]
+    /// fn Guarded() -> Element {
+    ///     use_hook(|| {
+    ///         router().on_before_navigate(|target: NavigationTarget<Route>| async move {
+    ///             match target {
+    ///                 NavigationTarget::Internal(Route::Admin {}) if !logged_in().await => {
+    ///                     NavigationDecision::Redirect(Route::Login {}.into())
+    ///                 }
+    ///                 _ => NavigationDecision::Continue,
+    ///             }
+    ///         })
+    ///     });
+    ///     rsx! { Outlet::<Route> {} }
+    /// }
+    /// ```
+    pub fn on_before_navigate<R: Routable, F: Future<Output = NavigationDecision<R>> + 'static>(
+        &self,
+        mut guard: impl FnMut(NavigationTarget<R>) -> F + 'static,
+    ) -> NavigationGuard {
+        let guard = Rc::new(RefCell::new(
+            move |target: NavigationTarget<Rc<dyn Any>>| {
+                let decision = guard(downcast_target(target));
+                Box::pin(async move {
+                    match decision.await {
+                        NavigationDecision::Continue => NavigationDecision::Continue,
+                        NavigationDecision::Cancel => NavigationDecision::Cancel,
+                        NavigationDecision::Redirect(target) => {
+                            NavigationDecision::Redirect(upcast_target(target))
+                        }
+                    }
+                }) as Pin<Box<dyn Future<Output = NavigationDecision<Rc<dyn Any>>>>>
+            },
+        )) as AnyNavigationGuard;
 
+        let mut inner = self.inner.write_unchecked();
+        let id = inner.next_guard;
+        inner.next_guard += 1;
+        inner.guards.push((id, guard));
+        NavigationGuard { router: *self, id }
+    }
+
+    fn navigate(
+        &self,
+        target: NavigationTarget<Rc<dyn Any>>,
+        replace: bool,
+    ) -> Option<ExternalNavigationFailure> {
+        let (navigation, guards) = {
+            let mut inner = self.inner.write_unchecked();
+            inner.navigation += 1;
+            let guards: Vec<_> = inner
+                .guards
+                .iter()
+                .map(|(_, guard)| guard.clone())
+                .collect();
+            (inner.navigation, guards)
+        };
+        if guards.is_empty() {
+            return self.apply_navigation(target, replace);
+        }
+
+        let myself = *self;
+        // The navigation continues even if the component that started it is dropped
+        spawn_forever(async move {
+            let mut target = target;
+            for guard in guards {
+                if myself.inner.read().navigation != navigation {
+                    return;
+                }
+                let decision = (guard.borrow_mut())(target.clone());
+                match decision.await {
+                    NavigationDecision::Continue => {}
+                    NavigationDecision::Cancel => return,
+                    NavigationDecision::Redirect(redirect) => target = redirect,
+                }
+            }
+            if myself.inner.read().navigation == navigation {
+                myself.apply_navigation(target, replace);
+            }
+        });
+        None
+    }
+
+    fn apply_navigation(
+        &self,
+        target: NavigationTarget<Rc<dyn Any>>,
+        replace: bool,
+    ) -> Option<ExternalNavigationFailure> {
+        if let NavigationTarget::Internal(route) = &target {
+            if self.any_route_to_string(&**route) != self.current_route_string() {
+                self.leave();
+            }
+        }
         {
-            let mut state = self.inner.write_unchecked();
+            let mut write = self.inner.write_unchecked();
+            write.pushed = matches!(target, NavigationTarget::Internal(_));
             match target {
-                NavigationTarget::Internal(p) => state.history.replace(p),
-                NavigationTarget::External(e) => return state.external(e),
+                NavigationTarget::Internal(p) if replace => write.history.replace(p),
+                NavigationTarget::Internal(p) => write.history.push(p),
+                NavigationTarget::External(e) => return write.external(e),
             }
         }
 
         self.change_route()
     }
 
+    /// Check if the current route was pushed or replaced by the router since the last call. Routes reached by going
+    /// back or forward in the history, including with the buttons of the browser, are not.
+    pub(crate) fn take_pushed(&self) -> bool {
+        std::mem::take(&mut self.inner.write_unchecked().pushed)
+    }
+
+    /// Set the function that is called right before the router changes the current route with
+    /// [`push`](RouterContext::push), [`replace`](RouterContext::replace), [`go_back`](RouterContext::go_back) or
+    /// [`go_forward`](RouterContext::go_forward). Routes changed by the history itself, like the buttons of the
+    /// browser, are not reported.
+    pub(crate) fn set_leave_listener(&self, listener: Option<Rc<dyn Fn()>>) {
+        self.inner.write_unchecked().leave_listener = listener;
+    }
+
+    fn leave(&self) {
+        let listener = self.inner.read().leave_listener.clone();
+        if let Some(listener) = listener {
+            listener();
+        }
+    }
+
     /// The route that is currently active.
     pub fn current<R: Routable>(&self) -> R {
         self.inner
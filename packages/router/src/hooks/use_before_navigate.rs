@@ -0,0 +1,47 @@
+use std::future::Future;
+
+use dioxus_lib::prelude::{use_drop, use_hook};
+
+use crate::prelude::{router, NavigationDecision, NavigationTarget, Routable};
+
+/// A hook that runs a guard before every navigation while the component is rendered. See
+/// [`RouterContext::on_before_navigate`](crate::prelude::RouterContext::on_before_navigate).
+///
+/// The guard from the first render is kept until the component is dropped.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_router::prelude::*;
+/// # async fn confirm(message: &str) -> bool { true }
+/// #[derive(Routable, Clone, PartialEq, Debug)]
+/// enum Route {
+///     #[route("/")]
+///     Editor {},
+///     #[route("/settings")]
+///     Settings {},
+/// }
+///
+/// #[component]
+/// fn Editor() -> Element {
+///     let mut unsaved = use_signal(|| false);
+///     use_before_navigate(move |_: NavigationTarget<Route>| async move {
+///         if unsaved() && !confirm("Leave without saving?").await {
+///             NavigationDecision::Cancel
+///         } else {
+///             NavigationDecision::Continue
+///         }
+///     });
+///     rsx! {
+///         textarea { oninput: move |_| unsaved.set(true) }
+///         Link { to: Route::Settings {}, "Settings" }
+///     }
+/// }
+/// # #[component]
+/// # fn Settings() -> Element { VNode::empty() }
+/// ```
+pub fn use_before_navigate<R: Routable, F: Future<Output = NavigationDecision<R>> + 'static>(
+    guard: impl FnMut(NavigationTarget<R>) -> F + 'static,
+) {
+    let guard = use_hook(|| router().on_before_navigate(guard));
+    use_drop(move || guard.remove());
+}
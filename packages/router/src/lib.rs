@@ -16,6 +16,9 @@ pub mod components {
     mod history_buttons;
     pub use history_buttons::*;
 
+    mod keep_alive;
+    pub use keep_alive::*;
+
     mod link;
     pub use link::*;
 
@@ -32,7 +35,7 @@ mod contexts {
     pub(crate) mod router;
     pub use navigator::*;
     pub(crate) use router::*;
-    pub use router::{root_router, RouterContext};
+    pub use router::{root_router, NavigationDecision, NavigationGuard, RouterContext};
 }
 
 mod router_cfg;
@@ -49,6 +52,9 @@ pub mod hooks {
 
     mod use_navigator;
     pub use use_navigator::*;
+
+    mod use_before_navigate;
+    pub use use_before_navigate::*;
 }
 
 pub use hooks::router;
@@ -61,7 +67,7 @@ pub mod prelude {
     pub use crate::hooks::*;
     pub use crate::navigation::*;
     pub use crate::routable::*;
-    pub use crate::router_cfg::RouterConfig;
+    pub use crate::router_cfg::{RouterConfig, ScrollRestoration};
     pub use dioxus_router_macro::Routable;
 
     #[doc(hidden)]
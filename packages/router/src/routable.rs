@@ -599,6 +599,12 @@ pub trait Routable: FromStr + Display + Clone + 'static {
     /// Render the route at the given level
     fn render(&self, level: usize) -> Element;
 
+    /// The metadata the route declares with the `#[meta(..)]` attribute. The [`Router`](crate::prelude::Router)
+    /// applies it to the head of the page every time the route changes.
+    fn metadata(&self) -> RouteMetadata {
+        RouteMetadata::default()
+    }
+
     /// Checks if this route is a child of the given route.
     ///
     /// # Example
@@ -709,6 +715,74 @@ pub trait Routable: FromStr + Display + Clone + 'static {
     }
 }
 
+/// The metadata of a route that is written into the head of the page.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+///
+/// #[derive(Routable, Clone, PartialEq, Debug)]
+/// #[meta(title_template = "{title} | My blog", description = "A blog about Rust")]
+/// enum Route {
+///     #[route("/")]
+///     #[meta(title = "Home")]
+///     Home {},
+///     #[route("/post/:id")]
+///     #[meta(title = "Post {id}", og_type = "article")]
+///     Post { id: usize },
+/// }
+///
+/// let metadata = Route::Post { id: 1 }.metadata();
+/// assert_eq!(metadata.title.as_deref(), Some("Post 1 | My blog"));
+/// assert_eq!(metadata.description.as_deref(), Some("A blog about Rust"));
+/// assert_eq!(metadata.properties, [("og:type".to_string(), "article".to_string())]);
+/// # #[component]
+/// # fn Home() -> Element { VNode::empty() }
+/// # #[component]
+/// # fn Post(id: usize) -> Element { VNode::empty() }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RouteMetadata {
+    /// The title of the page
+    pub title: Option<String>,
+    /// The description of the page, written into `<meta name="description">`
+    pub description: Option<String>,
+    /// Open Graph properties like `og:image`, written into `<meta property="og:image">`
+    pub properties: Vec<(String, String)>,
+}
+
+impl RouteMetadata {
+    /// Fill in the title, the description and the properties that are missing from `defaults`
+    pub fn or(mut self, defaults: RouteMetadata) -> Self {
+        self.title = self.title.or(defaults.title);
+        self.description = self.description.or(defaults.description);
+        for (property, content) in defaults.properties {
+            if !self.properties.iter().any(|(p, _)| *p == property) {
+                self.properties.push((property, content));
+            }
+        }
+        self
+    }
+
+    /// Format the title with a template. `{title}` in the template is replaced with the title.
+    pub fn with_title_template(mut self, template: &str) -> Self {
+        self.title = self.title.map(|title| template.replace("{title}", &title));
+        self
+    }
+
+    /// The meta tags of the metadata, as `(attribute, key, content)`
+    pub(crate) fn meta_tags(&self) -> impl Iterator<Item = (&'static str, &str, &str)> {
+        let description = self
+            .description
+            .as_deref()
+            .map(|description| ("name", "description", description));
+        let properties = self
+            .properties
+            .iter()
+            .map(|(property, content)| ("property", property.as_str(), content.as_str()));
+        description.into_iter().chain(properties)
+    }
+}
+
 /// A type erased map of the site structure.
 #[derive(Debug, Clone, PartialEq)]
 pub struct SiteMapSegment {
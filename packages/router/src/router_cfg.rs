@@ -1,5 +1,6 @@
 use crate::prelude::*;
 use dioxus_lib::prelude::*;
+use std::rc::Rc;
 use std::sync::Arc;
 
 /// Global configuration options for the router.
@@ -24,6 +25,7 @@ pub struct RouterConfig<R> {
     pub(crate) history: Option<Box<dyn AnyHistoryProvider>>,
     pub(crate) on_update: Option<RoutingCallback<R>>,
     pub(crate) initial_route: Option<R>,
+    pub(crate) scroll_restoration: Option<ScrollRestoration<R>>,
 }
 
 impl<R> Default for RouterConfig<R> {
@@ -33,6 +35,7 @@ impl<R> Default for RouterConfig<R> {
             history: None,
             on_update: None,
             initial_route: None,
+            scroll_restoration: None,
         }
     }
 }
@@ -48,7 +51,7 @@ where
                 let initial_route = self.initial_route.clone().unwrap_or_else(|| "/".parse().unwrap_or_else(|err|
                     panic!("index route does not exist:\n{}\n use MemoryHistory::with_initial_path or RouterConfig::initial_route to set a custom path", err)
                 ));
-                default_history(initial_route)
+                default_history(initial_route, self.scroll_restoration.is_some())
     })
     }
 }
@@ -108,20 +111,125 @@ where
             ..self
         }
     }
+
+    /// Restore the scroll position and move the focus when the route changes.
+    ///
+    /// See [`ScrollRestoration`] for more details. Defaults to [`None`].
+    pub fn scroll_restoration(self, restoration: ScrollRestoration<R>) -> Self {
+        Self {
+            scroll_restoration: Some(restoration),
+            ..self
+        }
+    }
+}
+
+/// Scroll and focus management for the router.
+///
+/// - When the user goes back or forward in the history, the scroll position the page had when it was left is restored.
+/// - When a new route is pushed, the page is scrolled to the top and the focus moves to the main content of the page, so
+///   screen readers and keyboard users start at the new content instead of the link they clicked.
+///
+/// The scroll positions are kept per route for as long as the router is rendered. Unlike the scroll restoration of
+/// `WebHistory`, this works with every renderer that can evaluate javascript. The default `WebHistory` disables its own
+/// scroll restoration when this is enabled. If you pass a `WebHistory` to [`RouterConfig::history`], create it with
+/// `WebHistory::new(prefix, false)` so both don't restore the scroll position.
+///
+/// ```rust,no_run
+/// # use dioxus_router::prelude::*;
+/// # use dioxus::prelude::*;
+/// # #[component]
+/// # fn Index() -> Element { VNode::empty() }
+/// # #[component]
+/// # fn Tab(name: String) -> Element { VNode::empty() }
+/// #[derive(Clone, Routable)]
+/// enum Route {
+///     #[route("/")]
+///     Index {},
+///     #[route("/tabs/:name")]
+///     Tab { name: String },
+/// }
+///
+/// let cfg = RouterConfig::default().scroll_restoration(
+///     ScrollRestoration::default()
+///         .focus("#content")
+///         // Switching tabs keeps the scroll position and the focus
+///         .skip(|route| matches!(route, Route::Tab { .. })),
+/// );
+/// ```
+pub struct ScrollRestoration<R> {
+    pub(crate) focus: Option<String>,
+    pub(crate) skip: Option<Rc<dyn Fn(&R) -> bool>>,
+}
+
+impl<R> Default for ScrollRestoration<R> {
+    fn default() -> Self {
+        Self {
+            focus: Some("main".to_string()),
+            skip: None,
+        }
+    }
+}
+
+impl<R> Clone for ScrollRestoration<R> {
+    fn clone(&self) -> Self {
+        Self {
+            focus: self.focus.clone(),
+            skip: self.skip.clone(),
+        }
+    }
+}
+
+impl<R> ScrollRestoration<R> {
+    /// The CSS selector of the element that receives the focus when a new route is pushed. Elements that can't be
+    /// focused are given a `tabindex` of `-1`.
+    ///
+    /// Defaults to `main`.
+    pub fn focus(self, selector: impl Into<String>) -> Self {
+        Self {
+            focus: Some(selector.into()),
+            ..self
+        }
+    }
+
+    /// Keep the focus where it is when a new route is pushed. The page is still scrolled to the top.
+    pub fn without_focus(self) -> Self {
+        Self {
+            focus: None,
+            ..self
+        }
+    }
+
+    /// Opt routes out of the scroll and focus management. Navigating to a route the function returns `true` for
+    /// keeps the scroll position and the focus as they are.
+    pub fn skip(self, skip: impl Fn(&R) -> bool + 'static) -> Self {
+        Self {
+            skip: Some(Rc::new(skip)),
+            ..self
+        }
+    }
+
+    pub(crate) fn skips(&self, route: &R) -> bool {
+        self.skip.as_ref().is_some_and(|skip| skip(route))
+    }
 }
 
 /// Get the default history provider for the current platform.
 #[allow(unreachable_code, unused)]
-fn default_history<R: Routable + Clone>(initial_route: R) -> Box<dyn AnyHistoryProvider>
+fn default_history<R: Routable + Clone>(
+    initial_route: R,
+    scroll_restoration: bool,
+) -> Box<dyn AnyHistoryProvider>
 where
     <R as std::str::FromStr>::Err: std::fmt::Display,
 {
     // If we're on the web and have wasm, use the web history provider
 
+    // The scroll restoration of the router replaces the one of the web history
     #[cfg(all(target_arch = "wasm32", feature = "web"))]
-    return Box::new(AnyHistoryProviderImplWrapper::new(
-        WebHistory::<R>::default(),
-    ));
+    return Box::new(AnyHistoryProviderImplWrapper::new(WebHistory::<R>::new(
+        None,
+        !scroll_restoration,
+    )));
 
     // If we're using fullstack and server side rendering, use the memory history provider
     #[cfg(all(feature = "fullstack", feature = "ssr"))]
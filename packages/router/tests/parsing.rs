@@ -75,3 +75,27 @@ fn without_trailing_slashes_parse() {
         RouteWithoutTrailingSlash::Dynamic { id: 123 }
     );
 }
+
+#[component]
+fn BlogPost(id: u64, slug: String) -> Element {
+    unimplemented!()
+}
+
+#[test]
+fn typed_segments_round_trip() {
+    #[derive(Routable, Clone, PartialEq, Debug)]
+    enum Route {
+        #[route("/blog/:id/:slug")]
+        BlogPost { id: u64, slug: String },
+    }
+
+    let route = Route::BlogPost {
+        id: 42,
+        slug: "hello world".to_string(),
+    };
+    assert_eq!(route.to_string(), "/blog/42/hello%20world");
+    assert_eq!(Route::from_str("/blog/42/hello%20world").unwrap(), route);
+
+    // Segments that don't parse as the type of the field don't match the route
+    assert!(Route::from_str("/blog/first/hello").is_err());
+}
@@ -0,0 +1,72 @@
+use dioxus::prelude::*;
+use dioxus_testing::TestDom;
+
+#[derive(Routable, Clone, PartialEq, Debug)]
+#[rustfmt::skip]
+enum Route {
+    #[layout(Guarded)]
+        #[route("/")]
+        Home {},
+        #[route("/admin")]
+        Admin {},
+        #[route("/login")]
+        Login {},
+        #[route("/draft")]
+        Draft {},
+}
+
+#[component]
+fn Guarded() -> Element {
+    use_before_navigate(|target: NavigationTarget<Route>| async move {
+        match target {
+            NavigationTarget::Internal(Route::Admin {}) => {
+                NavigationDecision::Redirect(Route::Login {}.into())
+            }
+            NavigationTarget::Internal(Route::Draft {}) => NavigationDecision::Cancel,
+            _ => NavigationDecision::Continue,
+        }
+    });
+    rsx! { Outlet::<Route> {} }
+}
+
+#[component]
+fn Home() -> Element {
+    rsx! { "home" }
+}
+
+#[component]
+fn Admin() -> Element {
+    rsx! { "admin" }
+}
+
+#[component]
+fn Login() -> Element {
+    rsx! { "login" }
+}
+
+#[component]
+fn Draft() -> Element {
+    rsx! { "draft" }
+}
+
+async fn navigate(dom: &mut TestDom, route: Route) -> String {
+    dom.dom().in_runtime(|| {
+        root_router().unwrap().push(route);
+    });
+    dom.settle().await;
+    dioxus_ssr::render(dom.dom())
+}
+
+#[tokio::test]
+async fn guards_can_redirect_or_cancel_navigations() {
+    let mut dom = TestDom::new(|| {
+        rsx! {
+            Router::<Route> { config: |_| RouterConfig::default().history(MemoryHistory::default()) }
+        }
+    });
+    assert_eq!(dioxus_ssr::render(dom.dom()), "home");
+
+    assert_eq!(navigate(&mut dom, Route::Admin {}).await, "login");
+    assert_eq!(navigate(&mut dom, Route::Draft {}).await, "login");
+    assert_eq!(navigate(&mut dom, Route::Home {}).await, "home");
+}
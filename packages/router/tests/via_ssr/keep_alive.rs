@@ -0,0 +1,124 @@
+#![allow(unused)]
+
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Routable, Clone, PartialEq)]
+#[rustfmt::skip]
+enum Route {
+    #[layout(Tabs)]
+        #[route("/")]
+        Home {},
+        #[route("/counter")]
+        Counter {},
+        #[nest("/settings")]
+            #[layout(Settings)]
+                #[route("/")]
+                SettingsIndex {},
+}
+
+#[derive(Clone, Copy, PartialEq)]
+struct MaxInactive(Option<usize>);
+
+#[component]
+fn App(max_inactive: Option<usize>) -> Element {
+    use_context_provider(|| MaxInactive(max_inactive));
+    rsx! { Router::<Route> {} }
+}
+
+#[component]
+fn Tabs() -> Element {
+    let MaxInactive(max_inactive) = use_context();
+    rsx! {
+        KeepAliveOutlet::<Route> { max_inactive }
+    }
+}
+
+#[component]
+fn Home() -> Element {
+    rsx! { "home" }
+}
+
+static COUNTERS_CREATED: AtomicUsize = AtomicUsize::new(0);
+
+#[component]
+fn Counter() -> Element {
+    let mut count = use_signal(|| {
+        COUNTERS_CREATED.fetch_add(1, Ordering::SeqCst);
+        0
+    });
+    use_hook(move || count += 1);
+    rsx! { "count {count}" }
+}
+
+#[component]
+fn Settings() -> Element {
+    rsx! {
+        "settings "
+        Outlet::<Route> {}
+    }
+}
+
+#[component]
+fn SettingsIndex() -> Element {
+    rsx! { "index" }
+}
+
+fn navigate(vdom: &mut VirtualDom, route: Route) -> String {
+    vdom.in_runtime(|| {
+        root_router().unwrap().push(route);
+    });
+    vdom.render_immediate(&mut dioxus_core::NoOpMutations);
+    dioxus_ssr::render(vdom)
+}
+
+#[test]
+fn keeps_inactive_routes_alive() {
+    let mut vdom = VirtualDom::new_with_props(App, AppProps { max_inactive: None });
+    vdom.rebuild_in_place();
+    assert_eq!(
+        dioxus_ssr::render(&vdom),
+        r#"<div style="display: contents">home</div>"#
+    );
+
+    let created = COUNTERS_CREATED.load(Ordering::SeqCst);
+    assert_eq!(
+        navigate(&mut vdom, Route::Counter {}),
+        r#"<div style="display: none">home</div><div style="display: contents">count 1</div>"#
+    );
+
+    // Nested outlets render the kept route, not the current route
+    assert_eq!(
+        navigate(&mut vdom, Route::SettingsIndex {}),
+        r#"<div style="display: none">home</div><div style="display: none">count 1</div><div style="display: contents">settings index</div>"#
+    );
+
+    // The counter is swapped back in with its state
+    assert_eq!(
+        navigate(&mut vdom, Route::Counter {}),
+        r#"<div style="display: none">home</div><div style="display: none">settings index</div><div style="display: contents">count 1</div>"#
+    );
+    assert_eq!(COUNTERS_CREATED.load(Ordering::SeqCst), created + 1);
+}
+
+#[test]
+fn drops_least_recently_active_routes() {
+    let mut vdom = VirtualDom::new_with_props(
+        App,
+        AppProps {
+            max_inactive: Some(1),
+        },
+    );
+    vdom.rebuild_in_place();
+
+    navigate(&mut vdom, Route::Counter {});
+    assert_eq!(
+        navigate(&mut vdom, Route::SettingsIndex {}),
+        r#"<div style="display: none">count 1</div><div style="display: contents">settings index</div>"#
+    );
+    assert_eq!(
+        navigate(&mut vdom, Route::Home {}),
+        r#"<div style="display: none">settings index</div><div style="display: contents">home</div>"#
+    );
+}
@@ -1,4 +1,8 @@
+mod guards;
+mod keep_alive;
 mod link;
+mod metadata;
 mod outlet;
 mod redirect;
+mod scroll;
 mod without_index;
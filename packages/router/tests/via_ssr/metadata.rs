@@ -0,0 +1,137 @@
+use dioxus::prelude::document::{Evaluator, NoOpDocument};
+use dioxus::prelude::*;
+use generational_box::GenerationalBox;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+thread_local! {
+    static HEAD: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+#[derive(Routable, Clone, PartialEq, Debug)]
+#[meta(title_template = "{title} | Blog", description = "A blog")]
+enum Route {
+    #[route("/")]
+    #[meta(title = "Home")]
+    Home {},
+    #[route("/post/:id")]
+    #[meta(
+        title = "Post {id}",
+        description = "Post {id} of the blog",
+        og_image = "/covers/{id}.png"
+    )]
+    Post { id: usize },
+    #[route("/about")]
+    About {},
+}
+
+#[component]
+fn Home() -> Element {
+    rsx! { "home" }
+}
+
+#[component]
+fn Post(id: usize) -> Element {
+    rsx! { "post {id}" }
+}
+
+#[component]
+fn About() -> Element {
+    rsx! { "about" }
+}
+
+/// A document that records the changes to the head
+struct HeadDocument;
+
+impl Document for HeadDocument {
+    fn new_evaluator(&self, js: String) -> GenerationalBox<Box<dyn Evaluator>> {
+        NoOpDocument.new_evaluator(js)
+    }
+
+    fn set_title(&self, title: String) {
+        HEAD.with(|head| head.borrow_mut().push(format!("title {title}")));
+    }
+
+    fn set_meta(&self, props: MetaProps) {
+        let (_, key) = props.key().unwrap();
+        let content = props.content.as_deref().unwrap_or("removed");
+        HEAD.with(|head| head.borrow_mut().push(format!("{key} {content}")));
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[component]
+fn App(path: Route) -> Element {
+    use_context_provider(|| Rc::new(HeadDocument) as Rc<dyn Document>);
+    rsx! {
+        Router::<Route> {
+            config: move |_| RouterConfig::default().history(MemoryHistory::with_initial_path(path.clone()))
+        }
+    }
+}
+
+fn take_head() -> Vec<String> {
+    HEAD.with(|head| std::mem::take(&mut *head.borrow_mut()))
+}
+
+#[test]
+fn metadata_is_formatted_with_the_route() {
+    assert_eq!(
+        Route::Post { id: 1 }.metadata(),
+        RouteMetadata {
+            title: Some("Post 1 | Blog".to_string()),
+            description: Some("Post 1 of the blog".to_string()),
+            properties: vec![("og:image".to_string(), "/covers/1.png".to_string())],
+        }
+    );
+    assert_eq!(
+        Route::About {}.metadata(),
+        RouteMetadata {
+            title: None,
+            description: Some("A blog".to_string()),
+            properties: vec![],
+        }
+    );
+}
+
+#[test]
+fn router_applies_metadata_on_navigation() {
+    let mut vdom = VirtualDom::new_with_props(
+        App,
+        AppProps {
+            path: Route::Post { id: 1 },
+        },
+    );
+    vdom.rebuild_in_place();
+    assert_eq!(
+        take_head(),
+        [
+            "title Post 1 | Blog",
+            "description Post 1 of the blog",
+            "og:image /covers/1.png",
+        ]
+    );
+
+    vdom.in_runtime(|| {
+        root_router().unwrap().push(Route::Home {});
+    });
+    vdom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(
+        take_head(),
+        [
+            "title Home | Blog",
+            "description A blog",
+            "og:image removed"
+        ]
+    );
+
+    // The title of the previous route is kept
+    vdom.in_runtime(|| {
+        root_router().unwrap().push(Route::About {});
+    });
+    vdom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(take_head(), Vec::<String>::new());
+}
@@ -133,3 +133,54 @@ fn parameter_fixed() {
         "<h1>App</h1><h2>Parameter 18</h2><h3>Parameter - Fixed</h3>"
     );
 }
+
+#[test]
+fn layout_is_kept_when_navigating_between_children() {
+    use std::cell::Cell;
+
+    thread_local! {
+        static LAYOUT_MOUNTS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[derive(Routable, Clone, PartialEq)]
+    #[rustfmt::skip]
+    enum Route {
+        #[layout(Shell)]
+            #[route("/")]
+            Inbox {},
+            #[route("/sent")]
+            Sent {},
+    }
+
+    #[component]
+    fn Shell() -> Element {
+        use_hook(|| LAYOUT_MOUNTS.with(|mounts| mounts.set(mounts.get() + 1)));
+        rsx! {
+            nav { "Mail" }
+            Outlet::<Route> {}
+        }
+    }
+
+    #[component]
+    fn Inbox() -> Element {
+        rsx! { "Inbox" }
+    }
+
+    #[component]
+    fn Sent() -> Element {
+        rsx! { "Sent" }
+    }
+
+    let mut vdom = VirtualDom::new(
+        || rsx! { Router::<Route> { config: |_| RouterConfig::default().history(MemoryHistory::default()) } },
+    );
+    vdom.rebuild_in_place();
+    assert_eq!(dioxus_ssr::render(&vdom), "<nav>Mail</nav>Inbox");
+
+    vdom.in_runtime(|| {
+        root_router().unwrap().push(Route::Sent {});
+    });
+    vdom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&vdom), "<nav>Mail</nav>Sent");
+    assert_eq!(LAYOUT_MOUNTS.with(|mounts| mounts.get()), 1);
+}
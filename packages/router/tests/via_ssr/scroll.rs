@@ -0,0 +1,209 @@
+use dioxus::prelude::document::{EvalError, Evaluator};
+use dioxus::prelude::*;
+use dioxus_testing::TestDom;
+use generational_box::{GenerationalBox, UnsyncStorage};
+use serde_json::{json, Value};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+
+thread_local! {
+    static SENT: RefCell<Vec<Value>> = const { RefCell::new(Vec::new()) };
+    static PAGE: RefCell<Page> = RefCell::new(Page::default());
+}
+
+/// The scroll position of the page, tracked like the scroll script does
+#[derive(Default)]
+struct Page {
+    scroll: f64,
+    current: Option<String>,
+    positions: HashMap<String, f64>,
+}
+
+impl Page {
+    fn receive(&mut self, message: &Value) {
+        if let Some(left) = message.get("leave") {
+            assert_eq!(left.as_str(), self.current.as_deref());
+            if let Some(current) = self.current.take() {
+                self.positions.insert(current, self.scroll);
+            }
+            return;
+        }
+        self.current = message["route"].as_str().map(str::to_string);
+        match message["scroll"].as_str() {
+            Some("top") => self.scroll = 0.0,
+            Some("restore") => {
+                let route = self.current.as_ref().unwrap();
+                self.scroll = self.positions.get(route).copied().unwrap_or_default();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Scroll the page and record the position for the current route like the scroll listener of the script
+fn scroll_to(scroll: f64) {
+    PAGE.with_borrow_mut(|page| {
+        page.scroll = scroll;
+        if let Some(current) = page.current.clone() {
+            page.positions.insert(current, scroll);
+        }
+    })
+}
+
+fn scroll() -> f64 {
+    PAGE.with_borrow(|page| page.scroll)
+}
+
+#[derive(Routable, Clone, PartialEq, Debug)]
+enum Route {
+    #[route("/")]
+    Home {},
+    #[route("/posts")]
+    Posts {},
+    #[route("/posts/:tab")]
+    Tab { tab: String },
+}
+
+#[component]
+fn Home() -> Element {
+    rsx! { main { "home" } }
+}
+
+#[component]
+fn Posts() -> Element {
+    rsx! { main { "posts" } }
+}
+
+#[component]
+fn Tab(tab: String) -> Element {
+    rsx! { main { "{tab}" } }
+}
+
+/// An evaluator that records the messages sent to the script
+struct RecordingEvaluator;
+
+impl Evaluator for RecordingEvaluator {
+    fn send(&self, data: Value) -> Result<(), EvalError> {
+        PAGE.with_borrow_mut(|page| page.receive(&data));
+        SENT.with(|sent| sent.borrow_mut().push(data));
+        Ok(())
+    }
+
+    fn poll_recv(&mut self, _context: &mut Context<'_>) -> Poll<Result<Value, EvalError>> {
+        Poll::Pending
+    }
+
+    fn poll_join(&mut self, _context: &mut Context<'_>) -> Poll<Result<Value, EvalError>> {
+        Poll::Pending
+    }
+}
+
+struct RecordingDocument;
+
+impl Document for RecordingDocument {
+    fn new_evaluator(&self, _js: String) -> GenerationalBox<Box<dyn Evaluator>> {
+        current_owner::<UnsyncStorage>().insert(Box::new(RecordingEvaluator) as Box<dyn Evaluator>)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+async fn navigate(dom: &mut TestDom, navigate: impl FnOnce(RouterContext)) -> Vec<Value> {
+    dom.dom().in_runtime(|| navigate(root_router().unwrap()));
+    dom.settle().await;
+    SENT.with(|sent| sent.take())
+}
+
+#[tokio::test]
+async fn scroll_is_restored_on_back_and_focus_moves_on_push() {
+    let mut dom = TestDom::new(|| {
+        use_context_provider(|| Rc::new(RecordingDocument) as Rc<dyn Document>);
+        rsx! {
+            Router::<Route> {
+                config: |_| {
+                    RouterConfig::default()
+                        .history(MemoryHistory::default())
+                        .scroll_restoration(
+                            ScrollRestoration::default()
+                                .skip(|route| matches!(route, Route::Tab { .. })),
+                        )
+                }
+            }
+        }
+    });
+    dom.settle().await;
+    assert_eq!(
+        SENT.with(|sent| sent.take()),
+        [json!({ "route": "/", "scroll": "none", "focus": null })]
+    );
+
+    let sent = navigate(&mut dom, |router| _ = router.push(Route::Posts {})).await;
+    assert_eq!(
+        sent,
+        [
+            json!({ "leave": "/" }),
+            json!({ "route": "/posts", "scroll": "top", "focus": "main" })
+        ]
+    );
+
+    let sent = navigate(&mut dom, |router| {
+        _ = router.push(Route::Tab {
+            tab: "drafts".to_string(),
+        })
+    })
+    .await;
+    assert_eq!(
+        sent,
+        [
+            json!({ "leave": "/posts" }),
+            json!({ "route": "/posts/drafts", "scroll": "none", "focus": null })
+        ]
+    );
+
+    let sent = navigate(&mut dom, |router| router.go_back()).await;
+    assert_eq!(
+        sent,
+        [
+            json!({ "leave": "/posts/drafts" }),
+            json!({ "route": "/posts", "scroll": "restore", "focus": null })
+        ]
+    );
+}
+
+#[tokio::test]
+async fn scroll_position_is_kept_when_the_next_route_scrolls_the_page() {
+    let mut dom = TestDom::new(|| {
+        use_context_provider(|| Rc::new(RecordingDocument) as Rc<dyn Document>);
+        rsx! {
+            Router::<Route> {
+                config: |_| {
+                    RouterConfig::default()
+                        .history(MemoryHistory::default())
+                        .scroll_restoration(ScrollRestoration::default())
+                }
+            }
+        }
+    });
+    dom.settle().await;
+    scroll_to(300.0);
+
+    dom.dom()
+        .in_runtime(|| _ = root_router().unwrap().push(Route::Posts {}));
+    // The shorter page of the next route scrolls the page up before the router scrolls to the top
+    scroll_to(100.0);
+    dom.settle().await;
+    assert_eq!(scroll(), 0.0);
+    scroll_to(50.0);
+
+    dom.dom().in_runtime(|| root_router().unwrap().go_back());
+    dom.settle().await;
+    assert_eq!(scroll(), 300.0);
+
+    dom.dom().in_runtime(|| root_router().unwrap().go_forward());
+    dom.settle().await;
+    assert_eq!(scroll(), 50.0);
+}
@@ -2,7 +2,8 @@
 //!
 //! There's a few details that I wish we could've gotten right but we can revisit later:
 //!
-//! - Expanding an if chain is not possible - only its contents can be hot reloaded
+//! - Expanding an if chain is not possible - only its contents can be hot reloaded. Branches can be removed from the end
+//!   of a chain because the removed branches can render the new `else` branch (or nothing) instead.
 //!
 //! - Components that don't start with children can't be hot reloaded - IE going from `Comp {}` to `Comp { "foo" }`
 //!   is not possible. We could in theory allow this by seeding all Components with a `children` field.
@@ -192,11 +193,17 @@ impl HotReloadResult {
 
         let key = self.hot_reload_key(new)?;
 
-        let roots: Vec<_> = new
-            .roots
-            .iter()
-            .map(|node| to_template_node::<Ctx>(node))
-            .collect();
+        let mut new_dynamic_nodes = new_dynamic_nodes;
+        let roots: Vec<_> = if new.is_empty() {
+            // Core expects at least one node in the template. Just like rsx, we fill empty bodies with a placeholder
+            new_dynamic_nodes.push(HotReloadDynamicNode::Placeholder);
+            vec![dioxus_core::TemplateNode::Dynamic { id: 0 }]
+        } else {
+            new.roots
+                .iter()
+                .map(|node| to_template_node::<Ctx>(node))
+                .collect()
+        };
         let roots: &[dioxus_core::TemplateNode] = intern(&*roots);
 
         let template = HotReloadedTemplate::new(
@@ -407,6 +414,11 @@ impl HotReloadResult {
             return None;
         }
 
+        // The fallback is rendered inside of a closure we can't hot reload
+        if new_component.fallback != old_component.fallback {
+            return None;
+        }
+
         // Then check if the fields are the same
         let new_non_key_fields: Vec<_> = new_component.component_props().collect();
         let old_non_key_fields: Vec<_> = old_component.component_props().collect();
@@ -546,22 +558,45 @@ impl HotReloadResult {
                 (None, None) => {
                     break;
                 }
-                _ => return None,
+                // The rest of the old branches were removed. Their conditions are still compiled in, so each of them
+                // renders whatever the new chain renders after its last condition: the new else branch or nothing
+                (Some(old), None) => {
+                    let fallback = Self::fallback_branch(new_chain);
+                    let mut removed = Some(&**old);
+                    while let Some(old) = removed {
+                        let template = Self::new::<Ctx>(&old.then_branch, &fallback, name.clone())?;
+                        chain_templates.push(template);
+                        old_chain = old;
+                        removed = old.else_if_branch.as_deref();
+                    }
+                    break;
+                }
+                // Adding a new condition requires a rebuild
+                (None, Some(_)) => return None,
             }
         }
-        // Make sure the else branches match
+        // Make sure the else branches match. An else branch can be removed, but not added
         match (&old_chain.else_branch, &new_chain.else_branch) {
-            (Some(old), Some(new)) => {
-                let template = Self::new::<Ctx>(old, new, name.clone())?;
+            (Some(old), _) => {
+                let fallback = Self::fallback_branch(new_chain);
+                let template = Self::new::<Ctx>(old, &fallback, name.clone())?;
                 chain_templates.push(template);
             }
             (None, None) => {}
-            _ => return None,
+            (None, Some(_)) => return None,
         }
 
         Some(chain_templates)
     }
 
+    /// The body an if chain renders after its last condition fails
+    fn fallback_branch(chain: &IfChain) -> TemplateBody {
+        chain
+            .else_branch
+            .clone()
+            .unwrap_or_else(|| TemplateBody::new(vec![]))
+    }
+
     /// Take a new template body and return the attributes that can be hot reloaded from the last build
     ///
     /// IE if we shuffle attributes, remove attributes or add new attributes with the same dynamic segments, around we should be able to hot reload them.
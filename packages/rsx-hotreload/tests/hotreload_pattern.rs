@@ -1261,3 +1261,123 @@ fn valid_spread() {
 
     assert!(valid);
 }
+
+// Bodies that no longer render anything are filled with a placeholder instead of an empty template
+#[test]
+fn valid_empty_component_body() {
+    let templates = hot_reload_from_tokens(
+        quote! {
+            Comp { "{text}" }
+        },
+        quote! {
+            Comp {}
+        },
+    )
+    .unwrap();
+
+    let body = templates.get(&1).unwrap();
+    assert_eq!(body.roots, &[TemplateNode::Dynamic { id: 0 }]);
+    assert_eq!(body.dynamic_nodes, &[HotReloadDynamicNode::Placeholder]);
+}
+
+// Removing the else branch of an if chain makes the old else branch render nothing
+#[test]
+fn valid_remove_else_branch() {
+    let templates = hot_reload_from_tokens(
+        quote! {
+            if cond {
+                "foo"
+            } else {
+                "{bar}"
+            }
+        },
+        quote! {
+            if cond {
+                "baz"
+            }
+        },
+    )
+    .unwrap();
+
+    let placeholders = templates
+        .values()
+        .filter(|template| template.dynamic_nodes == [HotReloadDynamicNode::Placeholder])
+        .count();
+    assert_eq!(placeholders, 1);
+}
+
+// Removing else if branches makes each removed branch render the new else branch
+#[test]
+fn valid_remove_else_if_branches() {
+    let templates = hot_reload_from_tokens(
+        quote! {
+            if first {
+                "first"
+            } else if second {
+                "second"
+            } else if third {
+                "third"
+            } else {
+                "fallback"
+            }
+        },
+        quote! {
+            if first {
+                "first"
+            } else {
+                "new fallback"
+            }
+        },
+    )
+    .unwrap();
+
+    let fallbacks = templates
+        .values()
+        .filter(|template| {
+            template.roots
+                == [TemplateNode::Text {
+                    text: "new fallback",
+                }]
+        })
+        .count();
+    // The second, third and else branches all render the new else branch
+    assert_eq!(fallbacks, 3);
+}
+
+// Adding a branch to an if chain requires compiling the new condition
+#[test]
+fn invalid_add_if_chain_branches() {
+    assert!(!can_hotreload(
+        quote! {
+            if cond {
+                "foo"
+            }
+        },
+        quote! {
+            if cond {
+                "foo"
+            } else {
+                "bar"
+            }
+        },
+    ));
+
+    assert!(!can_hotreload(
+        quote! {
+            if cond {
+                "foo"
+            } else {
+                "bar"
+            }
+        },
+        quote! {
+            if cond {
+                "foo"
+            } else if other {
+                "baz"
+            } else {
+                "bar"
+            }
+        },
+    ));
+}
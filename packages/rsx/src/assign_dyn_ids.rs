@@ -135,7 +135,13 @@ impl TemplateBody {
     /// wiring of ifmt literals need to be done at the callbody level since those final IDs need to
     /// be unique to the entire app.
     pub(crate) fn assign_paths_inner(&mut self, nodes: &[BodyNode]) {
+        // The key of the body lives outside of the nodes, so we assign its formatted segments after the children
+        let key = self.key.take();
         let mut visitor = DynIdVisitor::new(self);
         visitor.visit_children(nodes);
+        if let Some(AttributeValue::AttrLiteral(HotLiteral::Fmted(fmted))) = &key {
+            visitor.assign_formatted_segment(fmted);
+        }
+        self.key = key;
     }
 }
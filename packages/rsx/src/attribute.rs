@@ -17,13 +17,14 @@
 use super::literal::HotLiteral;
 use crate::{innerlude::*, partial_closure::PartialClosure};
 
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::{quote, quote_spanned, ToTokens, TokenStreamExt};
 use std::fmt::Display;
 use syn::{
     ext::IdentExt,
     parse::{Parse, ParseStream},
     parse_quote,
+    punctuated::Punctuated,
     spanned::Spanned,
     Block, Expr, ExprClosure, ExprIf, Ident, Lit, LitBool, LitFloat, LitInt, LitStr, Token,
 };
@@ -216,6 +217,7 @@ impl Attribute {
                 | AttributeValue::AttrExpr(_)
                 | AttributeValue::Shorthand(_)
                 | AttributeValue::IfExpr { .. }
+                | AttributeValue::ClassList(_)
                     if is_not_event =>
                 {
                     let name = &self.name;
@@ -237,23 +239,49 @@ impl Attribute {
                     AttributeName::BuiltIn(name) => {
                         let event_tokens_is_closure =
                             syn::parse2::<ExprClosure>(tokens.to_token_stream()).is_ok();
-                        let function_name =
-                            quote_spanned! { tokens.span() => dioxus_elements::events::#name };
+                        let function_name = event_handler(tokens.span()).to_token_stream();
                         let function = if event_tokens_is_closure {
                             // If we see an explicit closure, we can call the `call_with_explicit_closure` version of the event for better type inference
                             quote_spanned! { tokens.span() => #function_name::call_with_explicit_closure }
                         } else {
                             function_name
                         };
+                        let import = import_event(name);
+                        let clones = tokens.clone_captures();
+                        let options = tokens.listener_options().map(|options| {
+                            quote! { .with_listener_options(#options) }
+                        });
                         quote_spanned! { tokens.span() =>
-                            #function(#tokens)
+                            {
+                                #import
+                                #clones
+                                #function(#tokens)#options
+                            }
                         }
                     }
                     AttributeName::Custom(_) => unreachable!("Handled elsewhere in the macro"),
                     AttributeName::Spread(_) => unreachable!("Handled elsewhere in the macro"),
                 },
+                _ if self.assignment_handler().is_some() => {
+                    let handler = self.assignment_handler().unwrap();
+                    let import = import_event(name);
+                    let function_name = event_handler(value.span());
+                    quote_spanned! { value.span() =>
+                        {
+                            #import
+                            #function_name::call_with_explicit_closure(#handler)
+                        }
+                    }
+                }
                 _ => {
-                    quote_spanned! { value.span() => dioxus_elements::events::#name(#value) }
+                    let import = import_event(name);
+                    let function_name = event_handler(value.span());
+                    quote_spanned! { value.span() =>
+                        {
+                            #import
+                            #function_name(#value)
+                        }
+                    }
                 }
             }
         };
@@ -270,6 +298,38 @@ impl Attribute {
         .to_token_stream()
     }
 
+    /// Event handlers that only assign to a value can skip the closure: `onclick: count += 1` is expanded to
+    /// `onclick: move |_| { count += 1; }`
+    pub(crate) fn assignment_handler(&self) -> Option<TokenStream2> {
+        use syn::BinOp;
+
+        if !self.name.is_likely_event() {
+            return None;
+        }
+        let AttributeValue::AttrExpr(expr) = &self.value else {
+            return None;
+        };
+        let expr = expr.as_expr().ok()?;
+        let is_assignment = match &expr {
+            Expr::Assign(_) => true,
+            Expr::Binary(binary) => matches!(
+                binary.op,
+                BinOp::AddAssign(_)
+                    | BinOp::SubAssign(_)
+                    | BinOp::MulAssign(_)
+                    | BinOp::DivAssign(_)
+                    | BinOp::RemAssign(_)
+                    | BinOp::BitXorAssign(_)
+                    | BinOp::BitAndAssign(_)
+                    | BinOp::BitOrAssign(_)
+                    | BinOp::ShlAssign(_)
+                    | BinOp::ShrAssign(_)
+            ),
+            _ => false,
+        };
+        is_assignment.then(|| quote_spanned! { expr.span() => move |_| { #expr; } })
+    }
+
     pub fn can_be_shorthand(&self) -> bool {
         // If it's a shorthand...
         if matches!(self.value, AttributeValue::Shorthand(_)) {
@@ -341,6 +401,22 @@ impl Attribute {
     }
 }
 
+/// Import the event handler through a `use` so an unknown event is reported as an unresolved import at the name of the
+/// event. Rust suggests similarly named events for unresolved imports and doesn't report the later uses of the import.
+fn import_event(name: &impl ToTokens) -> TokenStream2 {
+    let function_name = event_handler(name.span());
+    quote_spanned! { name.span() =>
+        #[allow(unused_imports)]
+        use dioxus_elements::events::#name as #function_name;
+    }
+}
+
+/// The name the event handler is imported as. It is only visible to the macro so it never shadows or is shadowed by
+/// user code. The span is still located at the user code so unresolved imports name the unknown event.
+fn event_handler(location: Span) -> Ident {
+    Ident::new("__event", Span::mixed_site().located_at(location))
+}
+
 #[derive(PartialEq, Eq, Clone, Debug, Hash)]
 pub enum AttributeName {
     Spread(Token![..]),
@@ -436,6 +512,9 @@ pub enum AttributeValue {
     /// we want to go with it
     IfExpr(IfAttributeValue),
 
+    /// attribute: { "active": is_active, "disabled": !enabled }
+    ClassList(ClassList),
+
     /// attribute: some_expr
     /// attribute: {some_expr} ?
     AttrExpr(PartialExpr),
@@ -448,8 +527,14 @@ impl Parse for AttributeValue {
             return Ok(Self::IfExpr(content.parse::<IfAttributeValue>()?));
         }
 
-        // Use the move and/or bars as an indicator that we have an event handler
-        if content.peek(Token![move]) || content.peek(Token![|]) {
+        // A braced map of string literals to conditions is a class list
+        if ClassList::peek(content) {
+            return Ok(Self::ClassList(content.parse()?));
+        }
+
+        // Use the move and/or bars as an indicator that we have an event handler. A closure can also start with
+        // `#[clone(...)]`
+        if content.peek(Token![move]) || content.peek(Token![|]) || content.peek(Token![#]) {
             let value = content.parse()?;
             return Ok(AttributeValue::EventTokens(value));
         }
@@ -479,8 +564,16 @@ impl ToTokens for AttributeValue {
             Self::Shorthand(ident) => ident.to_tokens(tokens),
             Self::AttrLiteral(ifmt) => ifmt.to_tokens(tokens),
             Self::IfExpr(if_expr) => if_expr.to_tokens(tokens),
+            Self::ClassList(list) => list.to_tokens(tokens),
             Self::AttrExpr(expr) => expr.to_tokens(tokens),
-            Self::EventTokens(closure) => closure.to_tokens(tokens),
+            Self::EventTokens(closure) => {
+                let clones = closure.clone_captures();
+                if clones.is_empty() {
+                    closure.to_tokens(tokens)
+                } else {
+                    tokens.append_all(quote! { { #clones #closure } })
+                }
+            }
         }
     }
 }
@@ -491,6 +584,7 @@ impl AttributeValue {
             Self::Shorthand(ident) => ident.span(),
             Self::AttrLiteral(ifmt) => ifmt.span(),
             Self::IfExpr(if_expr) => if_expr.span(),
+            Self::ClassList(list) => list.span(),
             Self::AttrExpr(expr) => expr.span(),
             Self::EventTokens(closure) => closure.span(),
         }
@@ -698,6 +792,124 @@ impl ToTokens for IfAttributeValue {
     }
 }
 
+/// A map of class names to the conditions that enable them
+///
+/// ```rust, ignore
+/// class: { "active": is_active, "disabled": !enabled }
+/// ```
+///
+/// Entries with a literal `true` or `false` condition are resolved at compile time. If every condition is a
+/// literal, the whole list becomes a static string in the template.
+#[derive(PartialEq, Eq, Clone, Debug, Hash)]
+pub struct ClassList {
+    pub brace: syn::token::Brace,
+    pub entries: Punctuated<ClassListEntry, Token![,]>,
+}
+
+/// A single `"name": condition` entry in a [`ClassList`]
+#[derive(PartialEq, Eq, Clone, Debug, Hash)]
+pub struct ClassListEntry {
+    pub name: LitStr,
+    pub colon: Token![:],
+    pub condition: Expr,
+}
+
+impl ClassList {
+    /// Check if the braced content looks like a class list instead of a block expression
+    pub(crate) fn peek(input: ParseStream) -> bool {
+        fn starts_with_entry(input: ParseStream) -> syn::Result<bool> {
+            let content;
+            syn::braced!(content in input);
+            Ok(content.peek(LitStr) && content.peek2(Token![:]))
+        }
+
+        input.peek(syn::token::Brace) && starts_with_entry(&input.fork()).unwrap_or(false)
+    }
+
+    /// Get the classes if every condition is known at compile time
+    pub fn static_value(&self) -> Option<String> {
+        let mut classes = Vec::new();
+        for entry in &self.entries {
+            if Self::literal_condition(&entry.condition)? {
+                classes.push(entry.name.value());
+            }
+        }
+        Some(classes.join(" "))
+    }
+
+    fn literal_condition(condition: &Expr) -> Option<bool> {
+        match condition {
+            Expr::Lit(syn::ExprLit {
+                lit: Lit::Bool(value),
+                ..
+            }) => Some(value.value),
+            _ => None,
+        }
+    }
+
+    /// Convert the class list to an expression that returns a string of the enabled classes separated by spaces
+    pub(crate) fn quote_as_string(&self) -> Expr {
+        let pushes = self
+            .entries
+            .iter()
+            .filter(|entry| Self::literal_condition(&entry.condition) != Some(false))
+            .map(|entry| {
+                let name = &entry.name;
+                match Self::literal_condition(&entry.condition) {
+                    Some(_) => quote! { __classes.push(#name); },
+                    None => {
+                        let condition = &entry.condition;
+                        quote! {
+                            if #condition {
+                                __classes.push(#name);
+                            }
+                        }
+                    }
+                }
+            });
+
+        parse_quote! {
+            {
+                let mut __classes: ::std::vec::Vec<&str> = ::std::vec::Vec::new();
+                #(#pushes)*
+                __classes.join(" ")
+            }
+        }
+    }
+
+    pub fn span(&self) -> proc_macro2::Span {
+        self.brace.span.join()
+    }
+}
+
+impl Parse for ClassList {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let brace = syn::braced!(content in input);
+        let entries = content.parse_terminated(ClassListEntry::parse, Token![,])?;
+        Ok(Self { brace, entries })
+    }
+}
+
+impl Parse for ClassListEntry {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            name: input.parse()?,
+            colon: input.parse()?,
+            condition: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for ClassList {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        match self.static_value() {
+            Some(value) => LitStr::new(&value, self.span()).to_tokens(tokens),
+            None => self.quote_as_string().to_tokens(tokens),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -19,12 +19,12 @@
 use crate::innerlude::*;
 use proc_macro2::TokenStream as TokenStream2;
 use proc_macro2_diagnostics::SpanDiagnosticExt;
-use quote::{quote, ToTokens, TokenStreamExt};
+use quote::{quote, quote_spanned, ToTokens, TokenStreamExt};
 use std::{collections::HashSet, vec};
 use syn::{
     parse::{Parse, ParseStream},
     spanned::Spanned,
-    token, AngleBracketedGenericArguments, Expr, PathArguments, Result,
+    token, AngleBracketedGenericArguments, Expr, Pat, PathArguments, Result, Token,
 };
 
 #[derive(PartialEq, Eq, Clone, Debug)]
@@ -36,8 +36,12 @@ pub struct Component {
     pub spreads: Vec<Spread>,
     pub brace: Option<token::Brace>,
     pub children: TemplateBody,
+    /// The `else` block after the body of the component that fills its `fallback` prop
+    pub fallback: Option<Box<ComponentFallback>>,
     pub dyn_idx: DynIdx,
     pub diagnostics: Diagnostics,
+    /// If this component is a root of the children of another component and needs to check the parent accepts it
+    pub checked_child: bool,
 }
 
 impl Parse for Component {
@@ -63,9 +67,16 @@ impl Parse for Component {
             .count();
         let component_literal_dyn_idx = vec![DynIdx::default(); literal_properties_count];
 
+        let fallback = if input.peek(Token![else]) {
+            Some(Box::new(input.parse::<ComponentFallback>()?))
+        } else {
+            None
+        };
+
         let mut component = Self {
             dyn_idx: DynIdx::default(),
             children: TemplateBody::new(children),
+            fallback,
             name,
             generics,
             fields,
@@ -73,13 +84,20 @@ impl Parse for Component {
             component_literal_dyn_idx,
             spreads,
             diagnostics,
+            checked_child: false,
         };
 
+        // Components with manual props don't go through the builder, so we can't check their children
+        if component.spreads.is_empty() {
+            mark_checked_children(&mut component.children);
+        }
+
         // We've received a valid rsx block, but it's not necessarily a valid component
         // validating it will dump diagnostics into the output
         component.validate_component_path();
         component.validate_fields();
         component.validate_component_spread();
+        component.validate_fallback();
 
         Ok(component)
     }
@@ -95,6 +113,13 @@ impl ToTokens for Component {
         // Make sure we emit any errors
         let diagnostics = &self.diagnostics;
 
+        // Make sure the parent accepts this component as a child
+        let children_check = self.checked_child.then(|| {
+            quote_spanned! { name.span() =>
+                __children_check.accept(&__props);
+            }
+        });
+
         tokens.append_all(quote! {
             dioxus_core::DynamicNode::Component({
 
@@ -102,9 +127,11 @@ impl ToTokens for Component {
                 // we want to avoid importing traits
                 // use dioxus_core::prelude::Properties;
                 use dioxus_core::prelude::Properties;
-                let __comp = ({
+                let __props = {
                     #props
-                }).into_vcomponent(
+                };
+                #children_check
+                let __comp = __props.into_vcomponent(
                     #name #generics,
                 );
                 #diagnostics
@@ -170,6 +197,22 @@ impl Component {
         }
     }
 
+    // Fallbacks are set through the builder, so they can't be combined with manual props
+    fn validate_fallback(&mut self) {
+        if let (Some(fallback), Some(spread)) = (&self.fallback, self.spreads.first()) {
+            self.diagnostics.push(
+                fallback
+                    .else_token
+                    .span()
+                    .error("An `else` block can't be used with manual props.")
+                    .span_help(
+                        spread.expr.span(),
+                        "Set the `fallback` prop in the manual props instead",
+                    ),
+            );
+        }
+    }
+
     pub fn get_key(&self) -> Option<&AttributeValue> {
         self.fields
             .iter()
@@ -230,12 +273,29 @@ impl Component {
             }
         }
 
+        if let Some(fallback) = self.fallback.as_ref().filter(|_| manual_props.is_none()) {
+            tokens.append_all(quote! { .fallback(#fallback) })
+        }
+
         if !self.children.is_empty() {
             let children = &self.children;
             if manual_props.is_some() {
                 tokens.append_all(quote! { __manual_props.children = { #children }; })
             } else {
-                tokens.append_all(quote! { .children( { #children } ) })
+                // Find out which children the builder accepts so the roots of the children can check themselves
+                let mut node_checks = Vec::new();
+                check_non_component_children(&self.children, &mut node_checks);
+                return quote! {
+                    {
+                        let __builder = #tokens;
+                        let __children_check = {
+                            use dioxus_core::internal::{TypedChildrenChecker as _, UntypedChildrenChecker as _};
+                            (&dioxus_core::internal::ChildrenChecker(&__builder)).children_check()
+                        };
+                        #(#node_checks)*
+                        __builder.children( { #children } ).build()
+                    }
+                };
             }
         }
 
@@ -263,7 +323,9 @@ impl Component {
         let mut dynamic_literal_index = 0;
         self.component_props()
             .map(|attribute| {
-                let release_value = attribute.value.to_token_stream();
+                let release_value = attribute
+                    .assignment_handler()
+                    .unwrap_or_else(|| attribute.value.to_token_stream());
 
             // In debug mode, we try to grab the value from the dynamic literal pool if possible
             let value = if let AttributeValue::AttrLiteral(literal) = &attribute.value {
@@ -305,9 +367,106 @@ impl Component {
             fields: vec![],
             spreads: vec![],
             children: TemplateBody::new(vec![]),
+            fallback: None,
             component_literal_dyn_idx: vec![],
             dyn_idx: DynIdx::default(),
             diagnostics,
+            checked_child: false,
+        }
+    }
+}
+
+/// The block rendered instead of the children of a boundary component, written after the body of the component
+///
+/// ```rust, ignore
+/// SuspenseBoundary {
+///     Child {}
+/// } else {
+///     "Loading..."
+/// }
+///
+/// ErrorBoundary {
+///     Child {}
+/// } else |errors| {
+///     "Something went wrong: {errors:?}"
+/// }
+/// ```
+///
+/// The block fills the `fallback` prop of the component with a closure that renders the block.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ComponentFallback {
+    pub else_token: Token![else],
+    /// The argument of the fallback closure. If it is left out, the argument is ignored.
+    pub input: Option<(Token![|], Pat, Token![|])>,
+    pub brace: token::Brace,
+    pub body: TemplateBody,
+}
+
+impl Parse for ComponentFallback {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let else_token = input.parse()?;
+        let closure_input = if input.peek(Token![|]) {
+            Some((input.parse()?, Pat::parse_single(input)?, input.parse()?))
+        } else {
+            None
+        };
+        let content;
+        let brace = syn::braced!(content in input);
+        Ok(Self {
+            else_token,
+            input: closure_input,
+            brace,
+            body: content.parse()?,
+        })
+    }
+}
+
+impl ToTokens for ComponentFallback {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let body = &self.body;
+        let input = match &self.input {
+            Some((_, pat, _)) => pat.to_token_stream(),
+            None => quote! { _ },
+        };
+        tokens.append_all(quote_spanned! { self.else_token.span() =>
+            move |#input| { #body }
+        })
+    }
+}
+
+/// Mark the components at the roots of a component's children, looking through loops and if chains
+fn mark_checked_children(body: &mut TemplateBody) {
+    for root in body.roots.iter_mut() {
+        match root {
+            BodyNode::Component(component) => component.checked_child = true,
+            BodyNode::ForLoop(forloop) => mark_checked_children(&mut forloop.body),
+            BodyNode::IfChain(chain) => {
+                let mut chain = Some(chain);
+                while let Some(link) = chain {
+                    mark_checked_children(&mut link.then_branch);
+                    if let Some(else_branch) = link.else_branch.as_mut() {
+                        mark_checked_children(else_branch);
+                    }
+                    chain = link.else_if_branch.as_deref_mut();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Components check themselves against their parent, but the other roots of the children need to be checked by the parent
+fn check_non_component_children(body: &TemplateBody, checks: &mut Vec<TokenStream2>) {
+    for root in body.roots.iter() {
+        match root {
+            BodyNode::Component(_) => {}
+            BodyNode::ForLoop(forloop) => check_non_component_children(&forloop.body, checks),
+            BodyNode::IfChain(chain) => {
+                chain.for_each_branch(&mut |branch| check_non_component_children(branch, checks))
+            }
+            _ => checks.push(quote_spanned! { root.span() =>
+                __children_check.accept_node();
+            }),
         }
     }
 }
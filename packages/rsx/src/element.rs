@@ -5,6 +5,7 @@ use quote::{quote, ToTokens, TokenStreamExt};
 use std::fmt::{Display, Formatter};
 use syn::{
     parse::{Parse, ParseStream},
+    parse_quote,
     punctuated::Punctuated,
     spanned::Spanned,
     token::Brace,
@@ -73,6 +74,13 @@ impl Parse for Element {
             attr.el_name = Some(name.clone());
         }
 
+        // Let any attribute plugins validate or transform the attributes before they are merged
+        crate::plugin::apply_attribute_plugins(
+            &name,
+            &mut block.attributes,
+            &mut block.diagnostics,
+        );
+
         // Assemble the new element from the contents of the block
         let mut element = Element {
             brace,
@@ -223,6 +231,7 @@ impl Element {
     /// div {
     ///     class: "abc-def",
     ///     class: if some_expr { "abc" },
+    ///     class: { "active": is_active, "disabled": !enabled },
     /// }
     /// ```
     fn merge_attributes(&mut self) {
@@ -249,7 +258,8 @@ impl Element {
                 .collect::<Vec<_>>();
 
             // if there's only one attribute with this name, then we don't need to merge anything
-            if matching_attrs.len() == 1 {
+            // Class lists are always merged so they can become part of the static template if possible
+            if matching_attrs.len() == 1 && !matches!(attr.value, AttributeValue::ClassList(_)) {
                 self.merged_attributes.push(attr.clone());
                 continue;
             }
@@ -261,6 +271,43 @@ impl Element {
             let mut out = IfmtInput::new(attr.span());
 
             for (idx, matching_attr) in matching_attrs.iter().enumerate() {
+                // Merge `if cond { "abc" } else if ...` into the output
+                // The delimiter is part of the conditional so a condition that doesn't match doesn't leave a stray delimiter behind
+                let conditional_value = match &matching_attr.value {
+                    AttributeValue::IfExpr(value) => {
+                        Some(value.quote_as_string(&mut self.diagnostics))
+                    }
+                    // Class lists that are known at compile time are merged like any other literal
+                    AttributeValue::ClassList(list) => match list.static_value() {
+                        Some(value) => {
+                            if idx != 0 && !value.is_empty() {
+                                out.push_raw_str(" ".to_string());
+                            }
+                            out.push_raw_str(value);
+                            continue;
+                        }
+                        None => Some(list.quote_as_string()),
+                    },
+                    _ => None,
+                };
+                if let Some(value) = conditional_value {
+                    if idx == 0 {
+                        out.push_expr(value);
+                    } else {
+                        out.push_expr(parse_quote! {
+                            {
+                                let __value: ::std::string::String = #value;
+                                if __value.is_empty() {
+                                    __value
+                                } else {
+                                    ::std::format!(" {}", __value)
+                                }
+                            }
+                        });
+                    }
+                    continue;
+                }
+
                 // If this is the first attribute, then we don't need to add a delimiter
                 if idx != 0 {
                     // FIXME: I don't want to special case anything - but our delimiter is special cased to a space
@@ -275,12 +322,6 @@ impl Element {
                     continue;
                 }
 
-                // Merge `if cond { "abc" } else if ...` into the output
-                if let AttributeValue::IfExpr(value) = &matching_attr.value {
-                    out.push_expr(value.quote_as_string(&mut self.diagnostics));
-                    continue;
-                }
-
                 Self::add_merging_non_string_diagnostic(
                     &mut self.diagnostics,
                     matching_attr.span(),
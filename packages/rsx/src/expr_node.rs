@@ -1,6 +1,7 @@
 use crate::{DynIdx, PartialExpr};
+use proc_macro2::TokenStream as TokenStream2;
 use quote::{quote, ToTokens, TokenStreamExt};
-use syn::parse::Parse;
+use syn::{parse::Parse, Expr, ExprIf, Stmt};
 
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct ExprNode {
@@ -25,6 +26,14 @@ impl Parse for ExprNode {
 
 impl ToTokens for ExprNode {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        if let Some(if_chain) = self.unterminated_if() {
+            let if_chain = quote_terminated_if(if_chain);
+            tokens.append_all(quote! {
+                { let ___nodes = #if_chain; ___nodes }
+            });
+            return;
+        }
+
         let expr = &self.expr;
         tokens.append_all(quote! {
             { let ___nodes = (#expr).into_dyn_node(); ___nodes }
@@ -32,6 +41,53 @@ impl ToTokens for ExprNode {
     }
 }
 
+impl ExprNode {
+    /// Find an `if` without an `else` in an expression like `{if show { rsx! { "hello" } }}`. Rust types the missing
+    /// `else` as `()`, so the branches need to be converted into nodes before they can render nothing.
+    fn unterminated_if(&self) -> Option<ExprIf> {
+        let Ok(Expr::Block(block)) = self.expr.as_expr() else {
+            return None;
+        };
+        let [Stmt::Expr(Expr::If(if_expr), None)] = block.block.stmts.as_slice() else {
+            return None;
+        };
+
+        let mut chain = if_expr;
+        loop {
+            match chain.else_branch.as_ref().map(|(_, branch)| &**branch) {
+                Some(Expr::If(next)) => chain = next,
+                Some(_) => return None,
+                None => return Some(if_expr.clone()),
+            }
+        }
+    }
+}
+
+/// Convert each branch of an if chain into a dynamic node and render a placeholder if no branch matches
+fn quote_terminated_if(if_expr: ExprIf) -> TokenStream2 {
+    let ExprIf {
+        attrs,
+        if_token,
+        cond,
+        then_branch,
+        else_branch,
+    } = if_expr;
+    let else_branch = match else_branch {
+        Some((else_token, branch)) => match *branch {
+            Expr::If(next) => {
+                let next = quote_terminated_if(next);
+                quote! { #else_token #next }
+            }
+            branch => quote! { #else_token { (#branch).into_dyn_node() } },
+        },
+        None => quote! { else { dioxus_core::DynamicNode::default() } },
+    };
+    quote! {
+        #(#attrs)*
+        #if_token #cond { (#then_branch).into_dyn_node() } #else_branch
+    }
+}
+
 #[test]
 fn no_commas() {
     use prettier_please::PrettyUnparse;
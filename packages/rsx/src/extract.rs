@@ -0,0 +1,133 @@
+//! Extract the literal strings from rsx so translation and content audit tools can find every piece of text in an app.
+//!
+//! Tools parse the source files themselves and walk every rsx! call they find:
+//!
+//! ```rust, ignore
+//! use dioxus_rsx::CallBody;
+//!
+//! let body: CallBody = syn::parse_str(r#"div { title: "Greeting", "Hello {name}!" }"#).unwrap();
+//! for string in body.extract_strings() {
+//!     println!("{:?} {:?}", string.source, string.text);
+//! }
+//! ```
+//!
+//! Spans only contain line and column information when proc-macro2 is built with the `span-locations` feature outside
+//! of a proc macro.
+
+use crate::innerlude::*;
+use crate::CallBody;
+use proc_macro2::Span;
+use quote::ToTokens;
+
+/// A string literal written in rsx
+#[derive(Debug, Clone)]
+pub struct ExtractedString {
+    /// Where the string was found
+    pub source: StringSource,
+
+    /// The contents of the string. Formatted segments are kept as they are written, like `Hello {name}!`
+    pub text: String,
+
+    /// The span of the string literal
+    pub span: Span,
+}
+
+/// The part of the rsx a string was written in
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StringSource {
+    /// A text node: `"Hello"`
+    Text,
+
+    /// The value of an attribute of an element: `input { placeholder: "Name" }`
+    Attribute {
+        /// The name of the element
+        element: String,
+        /// The name of the attribute
+        name: String,
+    },
+
+    /// The value of a prop of a component: `Button { label: "Save" }`
+    Prop {
+        /// The path of the component as it is written
+        component: String,
+        /// The name of the prop
+        name: String,
+    },
+}
+
+impl CallBody {
+    /// Collect every literal text node, attribute value and component prop in the body, including the strings in
+    /// the bodies of for loops, if chains and components. Keys and strings inside raw rust expressions are not included.
+    pub fn extract_strings(&self) -> Vec<ExtractedString> {
+        let mut strings = Vec::new();
+        extract_from_nodes(&self.body.roots, &mut strings);
+        strings
+    }
+}
+
+fn extract_from_nodes(nodes: &[BodyNode], strings: &mut Vec<ExtractedString>) {
+    for node in nodes {
+        match node {
+            BodyNode::Text(text) => strings.push(ExtractedString {
+                source: StringSource::Text,
+                text: text.input.formatted_input.source.value(),
+                span: text.input.span(),
+            }),
+            BodyNode::Element(element) => {
+                let attributes = element.raw_attributes.iter();
+                for attribute in attributes.filter(|attr| !attr.name.is_likely_key()) {
+                    let source = || StringSource::Attribute {
+                        element: element.name.to_string(),
+                        name: attribute.name.to_string(),
+                    };
+                    extract_from_value(&attribute.value, &source, strings);
+                }
+                extract_from_nodes(&element.children, strings);
+            }
+            BodyNode::Component(component) => {
+                let name = component
+                    .name
+                    .to_token_stream()
+                    .to_string()
+                    .replace(' ', "");
+                for attribute in component.component_props() {
+                    let source = || StringSource::Prop {
+                        component: name.clone(),
+                        name: attribute.name.to_string(),
+                    };
+                    extract_from_value(&attribute.value, &source, strings);
+                }
+                extract_from_nodes(&component.children.roots, strings);
+                if let Some(fallback) = &component.fallback {
+                    extract_from_nodes(&fallback.body.roots, strings);
+                }
+            }
+            BodyNode::ForLoop(forloop) => extract_from_nodes(&forloop.body.roots, strings),
+            BodyNode::IfChain(chain) => {
+                chain.for_each_branch(&mut |branch| extract_from_nodes(&branch.roots, strings))
+            }
+            BodyNode::RawExpr(_) => {}
+        }
+    }
+}
+
+fn extract_from_value(
+    value: &AttributeValue,
+    source: &impl Fn() -> StringSource,
+    strings: &mut Vec<ExtractedString>,
+) {
+    match value {
+        AttributeValue::AttrLiteral(HotLiteral::Fmted(text)) => strings.push(ExtractedString {
+            source: source(),
+            text: text.formatted_input.source.value(),
+            span: text.span(),
+        }),
+        AttributeValue::IfExpr(if_value) => {
+            extract_from_value(&if_value.then_value, source, strings);
+            if let Some(else_value) = &if_value.else_value {
+                extract_from_value(else_value, source, strings);
+            }
+        }
+        _ => {}
+    }
+}
@@ -1,7 +1,8 @@
 use super::*;
 use location::DynIdx;
 use proc_macro2::TokenStream as TokenStream2;
-use syn::{braced, token::Brace, Expr, Pat};
+use quote::quote_spanned;
+use syn::{braced, spanned::Spanned, token::Brace, Expr, Ident, Pat};
 
 #[non_exhaustive]
 #[derive(PartialEq, Eq, Clone, Debug)]
@@ -24,9 +25,27 @@ impl Parse for ForLoop {
         let in_token = input.parse()?;
         let expr = input.call(Expr::parse_without_eager_brace)?;
 
+        // The loop may set the key for each iteration directly: `for item in items key: "{item}" { }`
+        let key = if input.peek(Ident) && input.peek2(Token![:]) {
+            let key_ident = input.parse::<Ident>()?;
+            if key_ident != "key" {
+                return Err(syn::Error::new(
+                    key_ident.span(),
+                    "Expected `key: \"{value}\"` after the for loop expression",
+                ));
+            }
+            input.parse::<Token![:]>()?;
+            Some(AttributeValue::AttrLiteral(input.parse::<HotLiteral>()?))
+        } else {
+            None
+        };
+
         let content;
         let brace = braced!(content in input);
-        let body = content.parse()?;
+        let children = RsxBlock::parse_children(&content)?;
+        let mut body = TemplateBody::new_with_key(children.children, key);
+        body.diagnostics
+            .extend(children.diagnostics.into_diagnostics());
 
         Ok(Self {
             for_token,
@@ -46,9 +65,12 @@ impl ToTokens for ForLoop {
             pat, expr, body, ..
         } = self;
 
+        let missing_key_warning = self.missing_key_warning();
+
         // the temporary is important so we create a lifetime binding
         tokens.append_all(quote! {
             {
+                #missing_key_warning
                 let ___nodes = (#expr).into_iter().map(|#pat| { #body }).into_dyn_node();
                 ___nodes
             }
@@ -56,6 +78,39 @@ impl ToTokens for ForLoop {
     }
 }
 
+impl ForLoop {
+    /// Iterating over components without a key makes core diff them by position, which moves the state
+    /// of each component to a different item when the list is reordered.
+    ///
+    /// proc-macro2-diagnostics emits warnings as errors on stable, so we use a deprecated item to emit
+    /// a real warning pointing at the `for` token instead. The warning is opt-in with the `unkeyed-lint`
+    /// feature so it doesn't break builds that deny warnings.
+    fn missing_key_warning(&self) -> TokenStream2 {
+        if !cfg!(feature = "unkeyed-lint") {
+            return TokenStream2::new();
+        }
+
+        let has_component_root = self
+            .body
+            .roots
+            .iter()
+            .any(|root| matches!(root, BodyNode::Component(_)));
+        if !has_component_root || self.body.implicit_key().is_some() {
+            return TokenStream2::new();
+        }
+
+        quote_spanned! { self.for_token.span() =>
+            {
+                #[deprecated(
+                    note = "Components rendered in a for loop should have a key so their state follows the item they render. Add `key: \"{value}\"` to the loop or to the root of its body"
+                )]
+                fn for_loop_without_key() {}
+                for_loop_without_key();
+            }
+        }
+    }
+}
+
 #[test]
 fn parses_for_loop() {
     let toks = quote! {
@@ -69,3 +124,29 @@ fn parses_for_loop() {
     let for_loop: ForLoop = syn::parse2(toks).unwrap();
     assert!(for_loop.body.roots.len() == 3);
 }
+
+#[test]
+fn parses_for_loop_key() {
+    let toks = quote! {
+        for item in items.iter() key: "{item}" {
+            "cool-{item}"
+        }
+    };
+
+    let for_loop: ForLoop = syn::parse2(toks).unwrap();
+    assert!(for_loop.body.key.is_some());
+    assert!(for_loop.body.implicit_key().is_some());
+    assert!(for_loop.body.diagnostics.is_empty());
+}
+
+#[test]
+fn for_loop_key_conflicts_with_root_key() {
+    let toks = quote! {
+        for item in items key: "{item}" {
+            div { key: "{item}" }
+        }
+    };
+
+    let for_loop: ForLoop = syn::parse2(toks).unwrap();
+    assert_eq!(for_loop.body.diagnostics.len(), 1);
+}
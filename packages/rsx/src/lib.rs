@@ -53,9 +53,11 @@ mod assign_dyn_ids;
 mod attribute;
 mod component;
 mod element;
+mod extract;
 mod forloop;
 mod ifchain;
 mod node;
+mod plugin;
 mod raw_expr;
 mod rsx_block;
 mod rsx_call;
@@ -72,9 +74,11 @@ mod util;
 
 // Re-export the namespaces into each other
 pub use diagnostics::Diagnostics;
+pub use extract::{ExtractedString, StringSource};
 pub use ifmt::*;
 pub use node::*;
 pub use partial_closure::PartialClosure;
+pub use plugin::AttributePlugin;
 pub use rsx_call::*;
 pub use template_body::TemplateBody;
 
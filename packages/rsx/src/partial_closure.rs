@@ -7,7 +7,7 @@ use syn::{
     punctuated::Punctuated,
     Attribute, Expr, Pat, PatType, Result, ReturnType, Token, Type,
 };
-use syn::{BoundLifetimes, ExprClosure};
+use syn::{BoundLifetimes, ExprClosure, Ident, LitBool};
 
 /// A closure whose body might not be valid rust code but we want to interpret it regardless.
 /// This lets us provide expansions in way more cases than normal closures at the expense of an
@@ -15,10 +15,12 @@ use syn::{BoundLifetimes, ExprClosure};
 ///
 /// We do our best to reuse the same logic from partial exprs for the body of the PartialClosure.
 /// The code here is simply stolen from `syn::ExprClosure` and lightly modified to work with
-/// PartialExprs. We only removed the attrs field and changed the body to be a PartialExpr.
+/// PartialExprs. We only limited the attrs to `#[clone(...)]` and changed the body to be a PartialExpr.
 /// Otherwise, it's a direct copy of the original.
 #[derive(Debug, Clone)]
 pub struct PartialClosure {
+    /// `#[clone(a, b)]` attributes that list the values to clone before they are moved into the closure
+    pub attrs: Vec<Attribute>,
     pub lifetimes: Option<BoundLifetimes>,
     pub constness: Option<Token![const]>,
     pub movability: Option<Token![static]>,
@@ -33,6 +35,10 @@ pub struct PartialClosure {
 
 impl Parse for PartialClosure {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        for attr in &attrs {
+            check_closure_attr(attr)?;
+        }
         let lifetimes: Option<BoundLifetimes> = input.parse()?;
         let constness: Option<Token![const]> = input.parse()?;
         let movability: Option<Token![static]> = input.parse()?;
@@ -67,6 +73,7 @@ impl Parse for PartialClosure {
         let body = PartialExpr::parse(input)?;
 
         Ok(PartialClosure {
+            attrs,
             lifetimes,
             constness,
             movability,
@@ -81,6 +88,7 @@ impl Parse for PartialClosure {
     }
 }
 
+// The attrs are left out since rust doesn't allow attributes on closures. Use `clone_captures` to expand them
 impl ToTokens for PartialClosure {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         self.lifetimes.to_tokens(tokens);
@@ -98,7 +106,8 @@ impl ToTokens for PartialClosure {
 
 impl PartialEq for PartialClosure {
     fn eq(&self, other: &Self) -> bool {
-        self.lifetimes == other.lifetimes
+        self.attrs == other.attrs
+            && self.lifetimes == other.lifetimes
             && self.constness == other.constness
             && self.movability == other.movability
             && self.asyncness == other.asyncness
@@ -114,6 +123,7 @@ impl PartialEq for PartialClosure {
 impl Eq for PartialClosure {}
 impl Hash for PartialClosure {
     fn hash<H: Hasher>(&self, state: &mut H) {
+        self.attrs.hash(state);
         self.lifetimes.hash(state);
         self.constness.hash(state);
         self.movability.hash(state);
@@ -132,7 +142,7 @@ impl PartialClosure {
     /// Returns err if the internal tokens can't be parsed as a closure
     pub fn as_expr(&self) -> Result<Expr> {
         let expr_closure = ExprClosure {
-            attrs: Vec::new(),
+            attrs: self.attrs.clone(),
             asyncness: self.asyncness,
             capture: self.capture,
             inputs: self.inputs.clone(),
@@ -149,6 +159,71 @@ impl PartialClosure {
 
         Ok(Expr::Closure(expr_closure))
     }
+
+    /// The values listed in the `#[clone(...)]` attributes of the closure
+    pub fn clones(&self) -> Vec<Ident> {
+        self.attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("clone"))
+            .filter_map(|attr| parse_clone_attr(attr).ok())
+            .flatten()
+            .collect()
+    }
+
+    /// The `ListenerOptions` set with the `#[capture]` and `#[passive(...)]` attributes of the closure, or `None` if
+    /// the closure has neither
+    pub fn listener_options(&self) -> Option<TokenStream> {
+        let capture = self
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident("capture"));
+        let passive = self
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("passive"))
+            .find_map(|attr| attr.parse_args::<LitBool>().ok());
+        if !capture && passive.is_none() {
+            return None;
+        }
+
+        let passive = match passive {
+            Some(passive) => quote::quote! { Some(#passive) },
+            None => quote::quote! { None },
+        };
+        Some(quote::quote! {
+            dioxus_core::ListenerOptions { capture: #capture, passive: #passive }
+        })
+    }
+
+    /// Shadow each value listed in `#[clone(...)]` with a clone so the closure can move it without taking ownership
+    /// of the original
+    pub fn clone_captures(&self) -> TokenStream {
+        self.clones()
+            .into_iter()
+            .map(|ident| quote::quote! { let #ident = ::std::clone::Clone::clone(&#ident); })
+            .collect()
+    }
+}
+
+fn parse_clone_attr(attr: &Attribute) -> Result<Punctuated<Ident, Token![,]>> {
+    attr.parse_args_with(Punctuated::parse_terminated)
+}
+
+/// Closures in rsx accept `#[clone(...)]`, and the listener options `#[capture]` and `#[passive(true|false)]`
+fn check_closure_attr(attr: &Attribute) -> Result<()> {
+    let path = attr.path();
+    if path.is_ident("clone") {
+        parse_clone_attr(attr).map(drop)
+    } else if path.is_ident("capture") {
+        attr.meta.require_path_only().map(drop)
+    } else if path.is_ident("passive") {
+        attr.parse_args::<LitBool>().map(drop)
+    } else {
+        Err(syn::Error::new_spanned(
+            attr,
+            "Only `#[clone(...)]`, `#[capture]` and `#[passive(...)]` attributes are supported on closures in rsx",
+        ))
+    }
 }
 
 /// This might look complex but it is just a ripoff of the `syn::ExprClosure` implementation. AFAIK
@@ -241,6 +316,58 @@ mod tests {
         parses.unwrap().as_expr().unwrap();
     }
 
+    #[test]
+    fn parses_clones() {
+        let parses: PartialClosure = syn::parse2(quote! {
+            #[clone(name, url)]
+            move |_| println!("{name} {url}")
+        })
+        .unwrap();
+        assert_eq!(parses.clones(), ["name", "url"]);
+
+        // The attribute isn't part of the closure rust sees
+        assert!(syn::parse2::<ExprClosure>(parses.to_token_stream()).is_ok());
+
+        // Other attributes are rejected
+        let doesnt_parse: Result<PartialClosure> = syn::parse2(quote! {
+            #[inline]
+            move |_| {}
+        });
+        assert!(doesnt_parse.is_err());
+    }
+
+    #[test]
+    fn parses_listener_options() {
+        let parses: PartialClosure = syn::parse2(quote! {
+            #[capture]
+            #[passive(false)]
+            #[clone(name)]
+            move |_| println!("{name}")
+        })
+        .unwrap();
+        assert_eq!(parses.clones(), ["name"]);
+        assert_eq!(
+            parses.listener_options().unwrap().to_string(),
+            quote! { dioxus_core::ListenerOptions { capture: true, passive: Some(false) } }
+                .to_string()
+        );
+
+        let plain: PartialClosure = syn::parse2(quote! { move |_| {} }).unwrap();
+        assert!(plain.listener_options().is_none());
+
+        // Passive needs a boolean and capture doesn't take arguments
+        let doesnt_parse: Result<PartialClosure> = syn::parse2(quote! {
+            #[passive]
+            move |_| {}
+        });
+        assert!(doesnt_parse.is_err());
+        let doesnt_parse: Result<PartialClosure> = syn::parse2(quote! {
+            #[capture(true)]
+            move |_| {}
+        });
+        assert!(doesnt_parse.is_err());
+    }
+
     #[test]
     fn partial_eqs() {
         let a: PartialClosure = syn::parse2(quote! {
@@ -0,0 +1,94 @@
+//! Attribute plugins let macros built on top of rsx validate or transform attributes at compile time.
+//!
+//! Proc macros can't load code from other crates, so plugins are applied by a wrapper macro that parses its input with
+//! [`CallBody::parse_with_plugins`] instead of the plain rsx parser:
+//!
+//! ```rust, ignore
+//! use dioxus_rsx::{Attribute, AttributePlugin, CallBody, ElementName};
+//! use quote::ToTokens;
+//!
+//! struct TailwindClasses;
+//!
+//! impl AttributePlugin for TailwindClasses {
+//!     fn process(&self, _element: &ElementName, attribute: &mut Attribute) -> syn::Result<()> {
+//!         if attribute.name.to_string() != "class" {
+//!             return Ok(());
+//!         }
+//!         if let Some((_, classes)) = attribute.as_static_str_literal() {
+//!             for class in classes.to_static().unwrap().split_whitespace() {
+//!                 if !is_tailwind_class(class) {
+//!                     return Err(syn::Error::new(
+//!                         attribute.value.span(),
+//!                         format!("`{class}` is not a tailwind class"),
+//!                     ));
+//!                 }
+//!             }
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//!
+//! #[proc_macro]
+//! pub fn tw_rsx(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+//!     match CallBody::parse_with_plugins(tokens.into(), vec![Box::new(TailwindClasses)]) {
+//!         Err(err) => err.to_compile_error().into(),
+//!         Ok(body) => body.into_token_stream().into(),
+//!     }
+//! }
+//! ```
+
+use crate::innerlude::*;
+use proc_macro2_diagnostics::SpanDiagnosticExt;
+use std::cell::RefCell;
+
+/// A compile-time hook that validates or transforms the attributes of elements in rsx.
+pub trait AttributePlugin {
+    /// Validate or transform an attribute of an element. This is called for every attribute before the attributes of
+    /// the element are merged, so the attribute can be changed freely.
+    ///
+    /// Errors are reported at their span, but the rest of the macro still expands so the user gets every error and
+    /// keeps autocomplete.
+    fn process(&self, element: &ElementName, attribute: &mut Attribute) -> syn::Result<()>;
+}
+
+thread_local! {
+    /// The plugins used by the parse that is currently running on this thread
+    static ACTIVE_PLUGINS: RefCell<Vec<Box<dyn AttributePlugin>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Run a parse with a set of attribute plugins active
+pub(crate) fn with_attribute_plugins<O>(
+    plugins: Vec<Box<dyn AttributePlugin>>,
+    parse: impl FnOnce() -> O,
+) -> O {
+    // Restore the previous plugins even if parsing panics
+    struct ResetPlugins(Vec<Box<dyn AttributePlugin>>);
+    impl Drop for ResetPlugins {
+        fn drop(&mut self) {
+            let previous = std::mem::take(&mut self.0);
+            ACTIVE_PLUGINS.with_borrow_mut(|plugins| *plugins = previous);
+        }
+    }
+
+    let _reset = ResetPlugins(ACTIVE_PLUGINS.replace(plugins));
+    parse()
+}
+
+/// Run the active plugins on the attributes of an element
+pub(crate) fn apply_attribute_plugins(
+    element: &ElementName,
+    attributes: &mut [Attribute],
+    diagnostics: &mut Diagnostics,
+) {
+    ACTIVE_PLUGINS.with_borrow(|plugins| {
+        for attribute in attributes.iter_mut() {
+            for plugin in plugins {
+                if let Err(err) = plugin.process(element, attribute) {
+                    for err in err {
+                        diagnostics.push(err.span().error(err.to_string()));
+                    }
+                }
+            }
+        }
+    })
+}
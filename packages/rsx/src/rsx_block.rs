@@ -313,6 +313,8 @@ impl RsxBlock {
     }
 }
 
+// Items only live while the block is parsed, so the size of the child variant doesn't matter
+#[allow(clippy::large_enum_variant)]
 pub enum RsxItem {
     Attribute(Attribute),
     Spread(Spread),
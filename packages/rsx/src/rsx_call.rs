@@ -7,11 +7,11 @@ use proc_macro2::TokenStream as TokenStream2;
 use quote::ToTokens;
 use std::{cell::Cell, fmt::Debug};
 use syn::{
-    parse::{Parse, ParseStream},
+    parse::{Parse, ParseStream, Parser},
     Result,
 };
 
-use crate::{BodyNode, TemplateBody};
+use crate::{AttributePlugin, BodyNode, TemplateBody};
 
 /// The Callbody is the contents of the rsx! macro
 ///
@@ -66,6 +66,16 @@ impl CallBody {
         Self::parse(input)
     }
 
+    /// Parse the contents of a rsx macro while applying attribute plugins to the attributes of every element
+    ///
+    /// This lets other crates build their own rsx macros that validate or transform attributes at compile time.
+    pub fn parse_with_plugins(
+        tokens: TokenStream2,
+        plugins: Vec<Box<dyn AttributePlugin>>,
+    ) -> Result<Self> {
+        crate::plugin::with_attribute_plugins(plugins, || Self::parse.parse2(tokens))
+    }
+
     /// With the entire knowledge of the macro call, wire up location information for anything hotreloading
     /// specific. It's a little bit simpler just to have a global id per callbody than to try and track it
     /// relative to each template, though we could do that if we wanted to.
@@ -99,6 +109,10 @@ impl CallBody {
                 BodyNode::Component(comp) => {
                     comp.children.template_idx.set(self.next_template_idx());
                     self.cascade_hotreload_info(&comp.children.roots);
+                    if let Some(fallback) = &comp.fallback {
+                        fallback.body.template_idx.set(self.next_template_idx());
+                        self.cascade_hotreload_info(&fallback.body.roots);
+                    }
                 }
 
                 BodyNode::ForLoop(floop) => {
@@ -81,6 +81,9 @@ pub struct TemplateBody {
     pub attr_paths: Vec<(AttributePath, usize)>,
     pub dynamic_text_segments: Vec<FormattedSegment>,
     pub diagnostics: Diagnostics,
+
+    /// A key that applies to the whole body instead of its first root. This is set by `for x in y key: "{x}" { }`
+    pub key: Option<AttributeValue>,
 }
 
 impl Parse for TemplateBody {
@@ -215,6 +218,13 @@ impl TemplateBody {
     /// This will fill in all the necessary path information for the nodes in the template and will
     /// overwrite data like dynamic indexes.
     pub fn new(nodes: Vec<BodyNode>) -> Self {
+        Self::new_with_key(nodes, None)
+    }
+
+    /// Create a new TemplateBody from a set of nodes with a key that applies to the whole body
+    ///
+    /// This is used for keys that are set on a for loop instead of the root of its body.
+    pub fn new_with_key(nodes: Vec<BodyNode>, key: Option<AttributeValue>) -> Self {
         let mut body = Self {
             roots: vec![],
             template_idx: DynIdx::default(),
@@ -222,6 +232,7 @@ impl TemplateBody {
             attr_paths: Vec::new(),
             dynamic_text_segments: Vec::new(),
             diagnostics: Diagnostics::new(),
+            key,
         };
 
         // Assign paths to all nodes in the template
@@ -230,6 +241,7 @@ impl TemplateBody {
 
         // And then save the roots
         body.roots = nodes;
+        body.validate_single_key();
 
         body
     }
@@ -239,6 +251,11 @@ impl TemplateBody {
     }
 
     pub fn implicit_key(&self) -> Option<&AttributeValue> {
+        self.key.as_ref().or_else(|| self.root_key())
+    }
+
+    /// Get the key set on the first root of this body
+    fn root_key(&self) -> Option<&AttributeValue> {
         match self.roots.first() {
             Some(BodyNode::Element(el)) => el.key(),
             Some(BodyNode::Component(comp)) => comp.get_key(),
@@ -270,6 +287,16 @@ impl TemplateBody {
         }
     }
 
+    /// Ensure the key isn't set on both the for loop and the root of its body
+    fn validate_single_key(&mut self) {
+        if let (Some(_), Some(root_key)) = (&self.key, self.root_key()) {
+            let diagnostic = root_key.span().error(
+                "This node already has a key from the surrounding for loop. Remove one of the keys",
+            );
+            self.diagnostics.push(diagnostic);
+        }
+    }
+
     pub fn get_dyn_node(&self, path: &[u8]) -> &BodyNode {
         let mut node = self.roots.get(path[0] as usize).unwrap();
         for idx in path.iter().skip(1) {
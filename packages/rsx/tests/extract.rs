@@ -0,0 +1,53 @@
+use dioxus_rsx::{CallBody, StringSource};
+use quote::quote;
+
+#[test]
+fn extracts_text_attributes_and_props() {
+    let body: CallBody = syn::parse2(quote! {
+        div {
+            title: "Greeting",
+            class: if active { "active" } else { "inactive" },
+            onclick: move |_| {},
+            "Hello {name}!"
+            for item in items {
+                li { key: "{item}", "Item" }
+            }
+            if show {
+                span { "Shown" }
+            }
+            Button { label: "Save", disabled: true, "Click" }
+            {raw_expression("ignored")}
+        }
+    })
+    .unwrap();
+
+    let attribute = |name: &str| StringSource::Attribute {
+        element: "div".to_string(),
+        name: name.to_string(),
+    };
+    let strings: Vec<_> = body
+        .extract_strings()
+        .into_iter()
+        .map(|string| (string.source, string.text))
+        .collect();
+
+    assert_eq!(
+        strings,
+        vec![
+            (attribute("title"), "Greeting".to_string()),
+            (attribute("class"), "active".to_string()),
+            (attribute("class"), "inactive".to_string()),
+            (StringSource::Text, "Hello {name}!".to_string()),
+            (StringSource::Text, "Item".to_string()),
+            (StringSource::Text, "Shown".to_string()),
+            (
+                StringSource::Prop {
+                    component: "Button".to_string(),
+                    name: "label".to_string(),
+                },
+                "Save".to_string()
+            ),
+            (StringSource::Text, "Click".to_string()),
+        ]
+    );
+}
@@ -0,0 +1,58 @@
+use dioxus_rsx::{Attribute, AttributePlugin, AttributeValue, CallBody, ElementName};
+use quote::{quote, ToTokens};
+
+/// Rejects the `bad` class and expands the `btn` class
+struct ClassPlugin;
+
+impl AttributePlugin for ClassPlugin {
+    fn process(&self, _element: &ElementName, attribute: &mut Attribute) -> syn::Result<()> {
+        if attribute.name.to_string() != "class" {
+            return Ok(());
+        }
+        let Some((_, value)) = attribute.as_static_str_literal() else {
+            return Ok(());
+        };
+        let classes = value.to_static().unwrap();
+        if classes.split_whitespace().any(|class| class == "bad") {
+            return Err(syn::Error::new(
+                attribute.value.span(),
+                "the bad class isn't allowed",
+            ));
+        }
+        if classes == "btn" {
+            attribute.value = AttributeValue::AttrLiteral(syn::parse_quote!("btn btn-primary"));
+        }
+        Ok(())
+    }
+}
+
+fn expand(tokens: proc_macro2::TokenStream) -> String {
+    CallBody::parse_with_plugins(tokens, vec![Box::new(ClassPlugin)])
+        .unwrap()
+        .into_token_stream()
+        .to_string()
+}
+
+#[test]
+fn plugins_transform_attributes() {
+    let out = expand(quote! {
+        button { class: "btn", "Click me" }
+    });
+    assert!(out.contains("\"btn btn-primary\""));
+    assert!(!out.contains("compile_error"));
+}
+
+#[test]
+fn plugins_report_errors() {
+    let out = expand(quote! {
+        div { class: "bad", id: "main" }
+    });
+    assert!(out.contains("the bad class isn't allowed"));
+}
+
+#[test]
+fn plugins_only_apply_to_their_parse() {
+    _ = expand(quote! { div { class: "btn" } });
+    let body: CallBody = syn::parse2(quote! { div { class: "btn" } }).unwrap();
+    assert!(!body.into_token_stream().to_string().contains("btn-primary"));
+}
@@ -35,7 +35,8 @@ use syn::__private::ToTokens;
 ///   relative to the prefix (defaults to the function name followed by unique hash)
 /// - `input`: the encoding for the arguments (defaults to `PostUrl`)
 /// - `output`: the encoding for the response (defaults to `Json`)
-/// - `client`: a custom `Client` implementation that will be used for this server fn
+/// - `client`: a custom `Client` implementation that will be used for this server fn (defaults to
+///   `dioxus_fullstack::prelude::ServerFnClient`, which can be configured with `set_server_fn_client`)
 /// - `encoding`: (legacy, may be deprecated in future) specifies the encoding, which may be one
 ///   of the following (not case sensitive)
 ///     - `"Url"`: `POST` request with URL-encoded arguments and JSON response
@@ -71,8 +72,8 @@ use syn::__private::ToTokens;
 #[proc_macro_attribute]
 pub fn server(args: proc_macro::TokenStream, s: TokenStream) -> TokenStream {
     match server_macro_impl(
-        args.into(),
-        s.into(),
+        with_default_client(args.into()),
+        apply_error_status(s.into()),
         Some(syn::parse_quote!(server_fn)),
         "/api",
         None,
@@ -82,3 +83,55 @@ pub fn server(args: proc_macro::TokenStream, s: TokenStream) -> TokenStream {
         Ok(s) => s.to_token_stream().into(),
     }
 }
+
+/// Set the status code of the response to the status code of a `CustomError` the server function returns. The body
+/// of the server function only runs on the server, so it is only wrapped with the server feature.
+fn apply_error_status(body: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    if !cfg!(feature = "server") {
+        return body;
+    }
+    let Ok(mut function) = syn::parse2::<syn::ItemFn>(body.clone()) else {
+        return body;
+    };
+    let syn::ReturnType::Type(_, output) = &function.sig.output else {
+        return body;
+    };
+    let block = &function.block;
+    function.block = syn::parse_quote! {{
+        #[allow(unused_imports)]
+        use dioxus_fullstack::{ApplyErrorStatus as _, IgnoreErrorStatus as _};
+        let result = dioxus_fullstack::server_fn_output::<#output, _>(async move #block).await;
+        (&dioxus_fullstack::ReturnedResult(&result)).apply_error_status();
+        result
+    }};
+    function.into_token_stream()
+}
+
+/// Use the configurable dioxus client for the server function unless the user passed a `client` argument. The path
+/// is resolved through `dioxus_fullstack` like the `dioxus_core` paths rsx expands to, so it works with the prelude
+/// of either `dioxus` or `dioxus_fullstack`.
+fn with_default_client(args: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let mut tokens = args.clone().into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        if let proc_macro2::TokenTree::Ident(ident) = &token {
+            let followed_by_eq = matches!(
+                tokens.peek(),
+                Some(proc_macro2::TokenTree::Punct(punct)) if punct.as_char() == '='
+            );
+            if ident == "client" && followed_by_eq {
+                return args;
+            }
+        }
+    }
+
+    let ends_with_comma = matches!(
+        args.clone().into_iter().last(),
+        Some(proc_macro2::TokenTree::Punct(punct)) if punct.as_char() == ','
+    );
+    let client = quote::quote! { dioxus_fullstack::prelude::ServerFnClient };
+    if args.is_empty() || ends_with_comma {
+        quote::quote! { #args client = #client }
+    } else {
+        quote::quote! { #args, client = #client }
+    }
+}
@@ -0,0 +1,87 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+use dioxus_core::prelude::ReactiveContext;
+
+thread_local! {
+    static BATCH: RefCell<Option<Batch>> = const { RefCell::new(None) };
+}
+
+#[derive(Default)]
+struct Batch {
+    depth: usize,
+    seen: HashSet<ReactiveContext>,
+    pending: Vec<ReactiveContext>,
+}
+
+/// Run a closure and wait to notify the subscribers of any signals written inside it until the closure exits.
+///
+/// Every subscriber is only marked dirty once, no matter how many of the signals it reads were written. Batches
+/// can be nested: subscribers are notified when the outermost batch ends.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// fn app() -> Element {
+///     let mut first = use_signal(|| "Jane".to_string());
+///     let mut last = use_signal(|| "Doe".to_string());
+///     let mut age = use_signal(|| 30);
+///
+///     rsx! {
+///         "{first} {last} is {age}"
+///         button {
+///             onclick: move |_| {
+///                 // Memos and effects that read all three signals only rerun once
+///                 batch(|| {
+///                     first.set("John".to_string());
+///                     last.set("Smith".to_string());
+///                     age += 1;
+///                 })
+///             },
+///             "Update"
+///         }
+///     }
+/// }
+/// ```
+pub fn batch<O>(f: impl FnOnce() -> O) -> O {
+    struct Guard;
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            let pending = BATCH.with(|batch| {
+                let mut batch = batch.borrow_mut();
+                let current = batch.as_mut().expect("a batch is running");
+                current.depth -= 1;
+                if current.depth == 0 {
+                    batch.take().map(|batch| batch.pending)
+                } else {
+                    None
+                }
+            });
+            for reactive_context in pending.into_iter().flatten() {
+                reactive_context.mark_dirty();
+            }
+        }
+    }
+
+    BATCH.with(|batch| batch.borrow_mut().get_or_insert_with(Batch::default).depth += 1);
+    let _guard = Guard;
+    f()
+}
+
+/// Queue the reactive context to be marked dirty when the current batch ends. Returns false if no batch is running.
+pub(crate) fn defer_mark_dirty(reactive_context: ReactiveContext) -> bool {
+    BATCH.with(|batch| match &mut *batch.borrow_mut() {
+        Some(batch) => {
+            if batch.seen.insert(reactive_context) {
+                batch.pending.push(reactive_context);
+            }
+            true
+        }
+        None => false,
+    })
+}
+
+/// Check if the signal writes on this thread are currently being batched.
+pub(crate) fn is_batching() -> bool {
+    BATCH.with(|batch| batch.borrow().is_some())
+}
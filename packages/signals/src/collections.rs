@@ -0,0 +1,502 @@
+use crate::{CopyValue, Readable, Signal, Writable};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The most deltas a collection remembers. Readers that fall further behind need to read the whole collection again.
+const MAX_DELTAS: usize = 256;
+
+/// A structural change to a [`SignalVec`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VecDelta {
+    /// An item was inserted at an index
+    Insert {
+        /// The index of the new item
+        index: usize,
+        /// The key of the new item
+        key: u64,
+    },
+    /// The item at an index was removed
+    Remove {
+        /// The index the item had before it was removed
+        index: usize,
+        /// The key of the removed item
+        key: u64,
+    },
+    /// An item moved from one index to another
+    Move {
+        /// The index the item had before it moved
+        from: usize,
+        /// The index of the item after it moved
+        to: usize,
+        /// The key of the moved item
+        key: u64,
+    },
+    /// All items were removed
+    Clear,
+}
+
+/// A structural change to a [`SignalMap`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MapDelta<K> {
+    /// An entry with a new key was inserted
+    Insert(K),
+    /// The entry with a key was removed
+    Remove(K),
+    /// All entries were removed
+    Clear,
+}
+
+/// A bounded log of the structural changes to a collection
+struct DeltaLog<D> {
+    start_version: u64,
+    deltas: Vec<D>,
+}
+
+impl<D: Clone> DeltaLog<D> {
+    fn new() -> Self {
+        Self {
+            start_version: 0,
+            deltas: Vec::new(),
+        }
+    }
+
+    fn version(&self) -> u64 {
+        self.start_version + self.deltas.len() as u64
+    }
+
+    fn push(&mut self, delta: D) {
+        if self.deltas.len() == MAX_DELTAS {
+            self.deltas.remove(0);
+            self.start_version += 1;
+        }
+        self.deltas.push(delta);
+    }
+
+    fn since(&self, version: u64) -> Option<Vec<D>> {
+        let skip = version.checked_sub(self.start_version)?;
+        self.deltas.get(skip as usize..).map(<[D]>::to_vec)
+    }
+}
+
+struct VecInner<T: 'static> {
+    items: Vec<(u64, Signal<T>)>,
+    next_key: u64,
+    log: DeltaLog<VecDelta>,
+}
+
+/// A list where every item is its own [`Signal`].
+///
+/// Storing a `Vec` in a [`Signal`] reruns everything that reads the list when any item changes. A `SignalVec` splits
+/// the list into its structure and its items:
+/// - Reading the length, the items or their keys subscribes to the structure of the list. Only inserting, removing
+///   and moving items rerun those readers.
+/// - Reading an item subscribes to only that item. Writing to the signal of an item only reruns its readers.
+///
+/// Every item gets a key that stays the same when the item moves. Pass the key and the signal of each item to a child
+/// component in a keyed list to rerender only the children of changed items:
+///
+/// ```rust
+/// use dioxus::prelude::*;
+///
+/// fn app() -> Element {
+///     let todos = use_hook(|| SignalVec::from_iter(["Write docs".to_string(), "Ship it".to_string()]));
+///     rsx! {
+///         button { onclick: move |_| todos.push("New todo".to_string()), "Add" }
+///         for (key, todo) in todos.items() {
+///             // Editing a todo only reruns its Todo component
+///             Todo { key: "{key}", todo }
+///         }
+///     }
+/// }
+///
+/// #[component]
+/// fn Todo(todo: Signal<String>) -> Element {
+///     rsx! { input { value: "{todo}", oninput: move |event| todo.set(event.value()) } }
+/// }
+/// ```
+///
+/// Readers that keep their own view of the list, like a virtualized list, can apply the [`VecDelta`]s since the
+/// version they last saw with [`SignalVec::deltas_since`] instead of reading the whole list again.
+///
+/// The signals of removed items are dropped. Reading them after the item is removed panics.
+pub struct SignalVec<T: 'static> {
+    inner: CopyValue<VecInner<T>>,
+    structure: Signal<()>,
+}
+
+impl<T: 'static> SignalVec<T> {
+    /// Create an empty list in the current scope
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            inner: CopyValue::new(VecInner {
+                items: Vec::new(),
+                next_key: 0,
+                log: DeltaLog::new(),
+            }),
+            structure: Signal::new(()),
+        }
+    }
+
+    /// Get the number of items in the list. This subscribes to the structure of the list.
+    pub fn len(&self) -> usize {
+        self.structure.read();
+        self.inner.read().items.len()
+    }
+
+    /// Check if the list is empty. This subscribes to the structure of the list.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the signal of the item at an index. This subscribes to the structure of the list, but not to the item.
+    pub fn get(&self, index: usize) -> Option<Signal<T>> {
+        self.structure.read();
+        self.inner.read().items.get(index).map(|(_, item)| *item)
+    }
+
+    /// Get the key of the item at an index. This subscribes to the structure of the list.
+    pub fn key(&self, index: usize) -> Option<u64> {
+        self.structure.read();
+        self.inner.read().items.get(index).map(|(key, _)| *key)
+    }
+
+    /// Get the key and signal of every item. This subscribes to the structure of the list, but not to the items.
+    pub fn items(&self) -> Vec<(u64, Signal<T>)> {
+        self.structure.read();
+        self.inner.read().items.clone()
+    }
+
+    /// Get the version of the structure of the list. The version increases with every structural change.
+    pub fn version(&self) -> u64 {
+        self.structure.read();
+        self.inner.read().log.version()
+    }
+
+    /// Get the structural changes since a version, or `None` if the changes are too old to be remembered. This
+    /// subscribes to the structure of the list.
+    pub fn deltas_since(&self, version: u64) -> Option<Vec<VecDelta>> {
+        self.structure.read();
+        self.inner.read().log.since(version)
+    }
+
+    /// Add an item to the end of the list
+    pub fn push(&self, value: T) {
+        let len = self.inner.peek().items.len();
+        self.insert(len, value);
+    }
+
+    /// Insert an item at an index, shifting the items after it
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is greater than the length of the list
+    pub fn insert(&self, index: usize, value: T) {
+        let scope = self.inner.origin_scope();
+        let item = Signal::new_in_scope(value, scope);
+        self.change(|inner| {
+            let key = inner.next_key;
+            inner.next_key += 1;
+            inner.items.insert(index, (key, item));
+            VecDelta::Insert { index, key }
+        });
+    }
+
+    /// Remove the item at an index and return its value, or `None` if the index is out of bounds
+    pub fn remove(&self, index: usize) -> Option<T> {
+        if index >= self.inner.peek().items.len() {
+            return None;
+        }
+        let mut removed = None;
+        self.change(|inner| {
+            let (key, item) = inner.items.remove(index);
+            removed = Some(item);
+            VecDelta::Remove { index, key }
+        });
+        removed.and_then(|item| item.manually_drop())
+    }
+
+    /// Remove the last item and return its value
+    pub fn pop(&self) -> Option<T> {
+        let len = self.inner.peek().items.len();
+        len.checked_sub(1).and_then(|last| self.remove(last))
+    }
+
+    /// Move the item at one index to another index. The item keeps its key and signal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds
+    pub fn move_item(&self, from: usize, to: usize) {
+        if from == to {
+            return;
+        }
+        self.change(|inner| {
+            let item = inner.items.remove(from);
+            let key = item.0;
+            inner.items.insert(to, item);
+            VecDelta::Move { from, to, key }
+        });
+    }
+
+    /// Set the value of the item at an index. This only reruns the readers of that item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds
+    pub fn set(&self, index: usize, value: T) {
+        let mut item = self.inner.peek().items[index].1;
+        item.set(value);
+    }
+
+    /// Remove all items
+    pub fn clear(&self) {
+        let mut removed = Vec::new();
+        self.change(|inner| {
+            removed = std::mem::take(&mut inner.items);
+            VecDelta::Clear
+        });
+        for (_, item) in removed {
+            item.manually_drop();
+        }
+    }
+
+    /// Remove every item the closure returns `false` for
+    pub fn retain(&self, mut f: impl FnMut(&T) -> bool) {
+        let mut index = 0;
+        while index < self.inner.peek().items.len() {
+            let item = self.inner.peek().items[index].1;
+            if f(&item.peek()) {
+                index += 1;
+            } else {
+                self.remove(index);
+            }
+        }
+    }
+
+    /// Change the structure of the list, record the delta and notify the readers of the structure
+    fn change(&self, f: impl FnOnce(&mut VecInner<T>) -> VecDelta) {
+        let mut inner = self.inner;
+        {
+            let mut inner = inner.write();
+            let delta = f(&mut inner);
+            inner.log.push(delta);
+        }
+        let mut structure = self.structure;
+        structure.set(());
+    }
+}
+
+impl<T: 'static> Default for SignalVec<T> {
+    #[track_caller]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: 'static> FromIterator<T> for SignalVec<T> {
+    #[track_caller]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let list = Self::new();
+        {
+            let scope = list.inner.origin_scope();
+            let mut inner = list.inner.write_unchecked();
+            for value in iter {
+                let key = inner.next_key;
+                inner.next_key += 1;
+                inner.items.push((key, Signal::new_in_scope(value, scope)));
+            }
+        }
+        list
+    }
+}
+
+impl<T: 'static> Clone for SignalVec<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for SignalVec<T> {}
+
+impl<T: 'static> PartialEq for SignalVec<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+struct MapInner<K: 'static, V: 'static> {
+    keys: Vec<K>,
+    entries: HashMap<K, Signal<V>>,
+    log: DeltaLog<MapDelta<K>>,
+}
+
+/// A map where the value of every entry is its own [`Signal`].
+///
+/// Like [`SignalVec`], reading the keys or checking if a key exists subscribes to the structure of the map and
+/// reading a value subscribes to only that value. Inserting a value for a key that already exists only reruns the
+/// readers of that value. Entries are kept in the order they were inserted in.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+///
+/// fn app() -> Element {
+///     let scores = use_hook(SignalMap::<String, u32>::new);
+///     rsx! {
+///         button { onclick: move |_| { scores.insert("Ferris".to_string(), 10); }, "Add score" }
+///         for (name, score) in scores.entries() {
+///             p { key: "{name}", "{name}: {score}" }
+///         }
+///     }
+/// }
+/// ```
+pub struct SignalMap<K: 'static, V: 'static> {
+    inner: CopyValue<MapInner<K, V>>,
+    structure: Signal<()>,
+}
+
+impl<K: Eq + Hash + Clone + 'static, V: 'static> SignalMap<K, V> {
+    /// Create an empty map in the current scope
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            inner: CopyValue::new(MapInner {
+                keys: Vec::new(),
+                entries: HashMap::new(),
+                log: DeltaLog::new(),
+            }),
+            structure: Signal::new(()),
+        }
+    }
+
+    /// Get the number of entries. This subscribes to the structure of the map.
+    pub fn len(&self) -> usize {
+        self.structure.read();
+        self.inner.read().keys.len()
+    }
+
+    /// Check if the map is empty. This subscribes to the structure of the map.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Check if the map contains a key. This subscribes to the structure of the map.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.structure.read();
+        self.inner.read().entries.contains_key(key)
+    }
+
+    /// Get the signal of the value for a key. This subscribes to the structure of the map, but not to the value.
+    pub fn get(&self, key: &K) -> Option<Signal<V>> {
+        self.structure.read();
+        self.inner.read().entries.get(key).copied()
+    }
+
+    /// Get the keys in insertion order. This subscribes to the structure of the map.
+    pub fn keys(&self) -> Vec<K> {
+        self.structure.read();
+        self.inner.read().keys.clone()
+    }
+
+    /// Get the keys and value signals in insertion order. This subscribes to the structure of the map, but not to the
+    /// values.
+    pub fn entries(&self) -> Vec<(K, Signal<V>)> {
+        self.structure.read();
+        let inner = self.inner.read();
+        inner
+            .keys
+            .iter()
+            .map(|key| (key.clone(), inner.entries[key]))
+            .collect()
+    }
+
+    /// Get the version of the structure of the map. The version increases with every structural change.
+    pub fn version(&self) -> u64 {
+        self.structure.read();
+        self.inner.read().log.version()
+    }
+
+    /// Get the structural changes since a version, or `None` if the changes are too old to be remembered. This
+    /// subscribes to the structure of the map.
+    pub fn deltas_since(&self, version: u64) -> Option<Vec<MapDelta<K>>> {
+        self.structure.read();
+        self.inner.read().log.since(version)
+    }
+
+    /// Insert a value for a key and return the previous value. If the key already exists, only the readers of its
+    /// value rerun.
+    pub fn insert(&self, key: K, value: V) -> Option<V> {
+        let existing = self.inner.peek().entries.get(&key).copied();
+        if let Some(mut existing) = existing {
+            return Some(std::mem::replace(&mut *existing.write(), value));
+        }
+        let scope = self.inner.origin_scope();
+        let value = Signal::new_in_scope(value, scope);
+        self.change(|inner| {
+            inner.keys.push(key.clone());
+            inner.entries.insert(key.clone(), value);
+            MapDelta::Insert(key)
+        });
+        None
+    }
+
+    /// Remove the entry for a key and return its value
+    pub fn remove(&self, key: &K) -> Option<V> {
+        if !self.inner.peek().entries.contains_key(key) {
+            return None;
+        }
+        let mut removed = None;
+        self.change(|inner| {
+            inner.keys.retain(|existing| existing != key);
+            removed = inner.entries.remove(key);
+            MapDelta::Remove(key.clone())
+        });
+        removed.and_then(|value| value.manually_drop())
+    }
+
+    /// Remove all entries
+    pub fn clear(&self) {
+        let mut removed = HashMap::new();
+        self.change(|inner| {
+            inner.keys.clear();
+            removed = std::mem::take(&mut inner.entries);
+            MapDelta::Clear
+        });
+        for value in removed.into_values() {
+            value.manually_drop();
+        }
+    }
+
+    /// Change the structure of the map, record the delta and notify the readers of the structure
+    fn change(&self, f: impl FnOnce(&mut MapInner<K, V>) -> MapDelta<K>) {
+        let mut inner = self.inner;
+        {
+            let mut inner = inner.write();
+            let delta = f(&mut inner);
+            inner.log.push(delta);
+        }
+        let mut structure = self.structure;
+        structure.set(());
+    }
+}
+
+impl<K: Eq + Hash + Clone + 'static, V: 'static> Default for SignalMap<K, V> {
+    #[track_caller]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: 'static, V: 'static> Clone for SignalMap<K, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K: 'static, V: 'static> Copy for SignalMap<K, V> {}
+
+impl<K: 'static, V: 'static> PartialEq for SignalMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
@@ -1,8 +1,8 @@
 #![allow(clippy::unnecessary_operation)]
 #![allow(clippy::no_effect)]
 
-use generational_box::UnsyncStorage;
 use generational_box::{BorrowResult, GenerationalBoxId};
+use generational_box::{SyncStorage, UnsyncStorage};
 use std::ops::Deref;
 
 use dioxus_core::prelude::*;
@@ -24,6 +24,11 @@ pub struct CopyValue<T: 'static, S: Storage<T> = UnsyncStorage> {
     pub(crate) origin_scope: ScopeId,
 }
 
+/// A [`CopyValue`] that can be shared between threads. Create one with [`CopyValue::new_maybe_sync`].
+///
+/// Unlike a [`SyncSignal`](crate::SyncSignal), writing to the value doesn't rerun anything that reads it.
+pub type SyncCopyValue<T> = CopyValue<T, SyncStorage>;
+
 #[cfg(feature = "serialize")]
 impl<T: 'static, Store: Storage<T>> serde::Serialize for CopyValue<T, Store>
 where
@@ -79,7 +84,8 @@ impl<T: 'static, S: Storage<T>> CopyValue<T, S> {
 
         Self {
             value: owner.insert_with_caller(value, caller),
-            origin_scope: current_scope_id().expect("in a virtual dom"),
+            // Values created with an owner from `with_owner` outside of a virtual dom belong to the root scope
+            origin_scope: current_scope_id().unwrap_or(ScopeId::ROOT),
         }
     }
 
@@ -0,0 +1,397 @@
+//! Record the history of signals for devtools.
+//!
+//! Every signal creation, write and new subscription is recorded as a [`SignalEvent`]. Signals marked with
+//! [`Signal::inspect`] also record their old and new values and can be rewound to a past state with
+//! [`SignalDevtools::replay`]. Combined with the [`SignalGraph`], this is enough for a devtools client to show a live
+//! signal graph and step through past states.
+//!
+//! Like the [`SignalGraph`], the history is only recorded in debug builds. Events are recorded per thread, so signals
+//! written from other threads show up in the history of that thread.
+
+// The recorder is only used in debug builds
+#![cfg_attr(not(debug_assertions), allow(unused))]
+
+use crate::{Readable, Signal, SignalData, SignalGraph, Writable};
+use dioxus_core::prelude::ReactiveContext;
+use generational_box::{GenerationalBoxId, Storage};
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Debug;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, Weak};
+
+/// The number of events that are kept by default
+const DEFAULT_CAPACITY: usize = 1000;
+
+/// Something that happened to a signal
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignalEvent {
+    /// The position of the event in the history. Indexes keep increasing even when old events are dropped.
+    pub index: u64,
+    /// The id of the signal, matching [`SignalNode::id`](crate::SignalNode::id) in the [`SignalGraph`]
+    pub signal: String,
+    /// What happened to the signal
+    pub kind: SignalEventKind,
+}
+
+/// The kind of a [`SignalEvent`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum SignalEventKind {
+    /// The signal was created
+    Created {
+        /// The location the signal was created at
+        created_at: Option<String>,
+        /// The scope that owns the signal
+        scope: usize,
+    },
+    /// The signal was written to
+    Written {
+        /// The location of the write
+        at: Option<String>,
+        /// The value before the write if the signal is inspected with [`Signal::inspect`]
+        old: Option<String>,
+        /// The value after the write if the signal is inspected with [`Signal::inspect`]
+        new: Option<String>,
+    },
+    /// A reactive context subscribed to the signal
+    Subscribed {
+        /// The id of the reactive context, matching [`SubscriberNode::id`](crate::SubscriberNode::id) in the
+        /// [`SignalGraph`]
+        subscriber: String,
+    },
+}
+
+/// A signal whose values are recorded
+struct Inspected {
+    // The closures are shared so they can be called without borrowing the recorder. Formatting the value may read
+    // other signals, which records new events
+    format: Rc<dyn Fn() -> Option<String>>,
+    snapshot: Rc<dyn Fn() -> Option<Box<dyn Any>>>,
+    restore: Box<dyn Fn(&dyn Any)>,
+    last: Option<String>,
+    /// Snapshots of the value after each recorded event, oldest first
+    history: VecDeque<(u64, Box<dyn Any>)>,
+    /// The subscribers of the signal, which are dropped with the signal
+    subscribers: Weak<Mutex<HashSet<ReactiveContext>>>,
+}
+
+struct Recorder {
+    capacity: usize,
+    next_index: u64,
+    events: VecDeque<SignalEvent>,
+    inspected: HashMap<GenerationalBoxId, Inspected>,
+    // The number of inspected signals at which dropped signals are forgotten next
+    prune_at: usize,
+    replaying: bool,
+}
+
+impl Default for Recorder {
+    fn default() -> Self {
+        Self {
+            capacity: DEFAULT_CAPACITY,
+            next_index: 0,
+            events: VecDeque::new(),
+            inspected: HashMap::new(),
+            prune_at: 0,
+            replaying: false,
+        }
+    }
+}
+
+impl Recorder {
+    fn push(&mut self, signal: GenerationalBoxId, kind: SignalEventKind) -> u64 {
+        let index = self.next_index;
+        self.next_index += 1;
+        self.events.push_back(SignalEvent {
+            index,
+            signal: format!("{signal:?}"),
+            kind,
+        });
+        self.trim();
+        index
+    }
+
+    /// Forget inspected signals that have been dropped
+    fn prune(&mut self) {
+        self.inspected
+            .retain(|_, inspected| inspected.subscribers.strong_count() > 0);
+        self.prune_at = (self.inspected.len() * 2).max(64);
+    }
+
+    fn trim(&mut self) {
+        while self.events.len() > self.capacity {
+            self.events.pop_front();
+        }
+        let Some(oldest) = self.events.front().map(|event| event.index) else {
+            return;
+        };
+        // Keep the newest snapshot from before the oldest event so the state at that event can still be replayed
+        for inspected in self.inspected.values_mut() {
+            while inspected.history.len() > 1 && inspected.history[1].0 <= oldest {
+                inspected.history.pop_front();
+            }
+        }
+    }
+}
+
+thread_local! {
+    static RECORDER: RefCell<Recorder> = RefCell::new(Recorder::default());
+}
+
+/// Record that a signal was created
+pub(crate) fn record_created<T: 'static, S: Storage<SignalData<T>>>(signal: &Signal<T, S>) {
+    #[cfg(debug_assertions)]
+    RECORDER.with_borrow_mut(|recorder| {
+        let kind = SignalEventKind::Created {
+            created_at: signal.inner.value().created_at().map(ToString::to_string),
+            scope: signal.origin_scope().0,
+        };
+        recorder.push(signal.id(), kind);
+    });
+}
+
+/// Record that a reactive context subscribed to a signal if it wasn't subscribed yet
+pub(crate) fn record_subscribed(
+    signal: GenerationalBoxId,
+    subscribers: &Arc<Mutex<HashSet<ReactiveContext>>>,
+    subscriber: ReactiveContext,
+) {
+    #[cfg(debug_assertions)]
+    {
+        if subscribers.lock().unwrap().contains(&subscriber) {
+            return;
+        }
+        RECORDER.with_borrow_mut(|recorder| {
+            let kind = SignalEventKind::Subscribed {
+                subscriber: format!("{:?}", subscriber.id()),
+            };
+            recorder.push(signal, kind);
+        });
+    }
+}
+
+/// Record that a signal was written to
+pub(crate) fn record_written(
+    signal: GenerationalBoxId,
+    at: Option<&'static std::panic::Location<'static>>,
+) {
+    #[cfg(debug_assertions)]
+    {
+        let Some(inspected) = RECORDER.with_borrow(|recorder| {
+            (!recorder.replaying).then(|| {
+                recorder
+                    .inspected
+                    .get(&signal)
+                    .map(|inspected| (inspected.format.clone(), inspected.snapshot.clone()))
+            })
+        }) else {
+            return;
+        };
+        // Format the value before borrowing the recorder because the Debug impl may read signals
+        let (new, snapshot) = match inspected {
+            Some((format, snapshot)) => (format(), snapshot()),
+            None => (None, None),
+        };
+        RECORDER.with_borrow_mut(|recorder| {
+            let old = match recorder.inspected.get_mut(&signal) {
+                Some(inspected) => std::mem::replace(&mut inspected.last, new.clone()),
+                None => None,
+            };
+            let kind = SignalEventKind::Written {
+                at: at.map(ToString::to_string),
+                old,
+                new,
+            };
+            let index = recorder.push(signal, kind);
+            if let (Some(snapshot), Some(inspected)) =
+                (snapshot, recorder.inspected.get_mut(&signal))
+            {
+                inspected.history.push_back((index, snapshot));
+            }
+        });
+    }
+}
+
+impl<T: Debug + Clone + 'static, S: Storage<SignalData<T>>> Signal<T, S> {
+    /// Record the values of this signal in the [`SignalDevtools`] history. Writes to the signal include the old and
+    /// new value, and the signal can be rewound with [`SignalDevtools::replay`]. This only has an effect in debug
+    /// builds.
+    ///
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// fn app() -> Element {
+    ///     let count = use_signal(|| 0).inspect();
+    ///     rsx! { "{count}" }
+    /// }
+    /// ```
+    pub fn inspect(self) -> Self {
+        #[cfg(debug_assertions)]
+        {
+            if RECORDER.with_borrow(|recorder| recorder.inspected.contains_key(&self.id())) {
+                return self;
+            }
+            let Ok(subscribers) = self
+                .inner
+                .try_peek()
+                .map(|inner| Arc::downgrade(&inner.subscribers))
+            else {
+                return self;
+            };
+            let signal = self;
+            let format = move || signal.try_peek().ok().map(|value| format!("{:?}", *value));
+            let snapshot = move || {
+                signal
+                    .try_peek()
+                    .ok()
+                    .map(|value| Box::new(value.clone()) as Box<dyn Any>)
+            };
+            let restore = move |value: &dyn Any| {
+                let mut signal = signal;
+                if let Some(value) = value.downcast_ref::<T>() {
+                    if let Ok(mut current) = signal.try_write() {
+                        *current = value.clone();
+                    }
+                }
+            };
+            // Read the value before borrowing the recorder because the Debug impl may read signals
+            let last = format();
+            let initial = snapshot();
+            RECORDER.with_borrow_mut(|recorder| {
+                if recorder.inspected.len() >= recorder.prune_at {
+                    recorder.prune();
+                }
+                let mut history = VecDeque::new();
+                // The initial value is the state before any event that is recorded from now on
+                if let Some(initial) = initial {
+                    history.push_back((recorder.next_index.saturating_sub(1), initial));
+                }
+                recorder.inspected.insert(
+                    self.id(),
+                    Inspected {
+                        last,
+                        format: Rc::new(format),
+                        snapshot: Rc::new(snapshot),
+                        restore: Box::new(restore),
+                        history,
+                        subscribers,
+                    },
+                );
+            });
+        }
+        self
+    }
+}
+
+/// Access the history of signals recorded on the current thread.
+///
+/// A devtools client can poll [`SignalDevtools::events_since`] for new events, show the current
+/// [`SignalDevtools::graph`], and rewind the app with [`SignalDevtools::replay`].
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// fn app() -> Element {
+///     let mut count = use_signal(|| 0).inspect();
+///     rsx! {
+///         button { onclick: move |_| count += 1, "{count}" }
+///         button {
+///             // Go back to the state before the last write
+///             onclick: move |_| {
+///                 let writes: Vec<_> = SignalDevtools::events()
+///                     .into_iter()
+///                     .filter(|event| matches!(event.kind, SignalEventKind::Written { .. }))
+///                     .collect();
+///                 if let [.., previous, _] = writes.as_slice() {
+///                     SignalDevtools::replay(previous.index);
+///                 }
+///             },
+///             "Undo"
+///         }
+///     }
+/// }
+/// ```
+pub struct SignalDevtools;
+
+impl SignalDevtools {
+    /// Get every recorded event that is still in the history, oldest first
+    pub fn events() -> Vec<SignalEvent> {
+        RECORDER.with_borrow(|recorder| recorder.events.iter().cloned().collect())
+    }
+
+    /// Get the recorded events with an index greater than `index`, oldest first
+    pub fn events_since(index: u64) -> Vec<SignalEvent> {
+        RECORDER.with_borrow(|recorder| {
+            recorder
+                .events
+                .iter()
+                .filter(|event| event.index > index)
+                .cloned()
+                .collect()
+        })
+    }
+
+    /// Capture the current [`SignalGraph`]
+    pub fn graph() -> SignalGraph {
+        SignalGraph::capture()
+    }
+
+    /// Set the number of events that are kept in the history. Older events are dropped. Defaults to 1000.
+    pub fn set_capacity(capacity: usize) {
+        RECORDER.with_borrow_mut(|recorder| {
+            recorder.capacity = capacity;
+            recorder.trim();
+        });
+    }
+
+    /// Drop every recorded event. Inspected signals stay inspected.
+    pub fn clear() {
+        RECORDER.with_borrow_mut(|recorder| {
+            recorder.events.clear();
+            let index = recorder.next_index.saturating_sub(1);
+            for inspected in recorder.inspected.values_mut() {
+                let current = inspected.history.pop_back();
+                inspected.history.clear();
+                if let Some((_, value)) = current {
+                    inspected.history.push_back((index, value));
+                }
+            }
+        });
+    }
+
+    /// Set every inspected signal back to the value it had right after the event at `index`. The writes made by the
+    /// replay are not recorded, so the history can be replayed again at any index. Returns the number of signals that
+    /// were restored.
+    pub fn replay(index: u64) -> usize {
+        // Take the inspected signals out of the recorder so the writes below can access it
+        let mut inspected = RECORDER.with_borrow_mut(|recorder| {
+            recorder.replaying = true;
+            std::mem::take(&mut recorder.inspected)
+        });
+        // Forget signals that have been dropped
+        inspected.retain(|_, inspected| inspected.subscribers.strong_count() > 0);
+
+        let mut restored = 0;
+        for inspected in inspected.values_mut() {
+            let snapshot = inspected
+                .history
+                .iter()
+                .rev()
+                .find(|(snapshot, _)| *snapshot <= index);
+            if let Some((_, value)) = snapshot {
+                (inspected.restore)(&**value);
+                inspected.last = (inspected.format)();
+                restored += 1;
+            }
+        }
+
+        RECORDER.with_borrow_mut(|recorder| {
+            recorder.replaying = false;
+            // Keep signals that were inspected while replaying
+            inspected.extend(recorder.inspected.drain());
+            recorder.inspected = inspected;
+        });
+        restored
+    }
+}
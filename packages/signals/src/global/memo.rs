@@ -2,14 +2,22 @@ use super::{Global, InitializeFromFunction};
 use crate::read::Readable;
 use crate::read_impls;
 use crate::Memo;
+use dioxus_core::Runtime;
 
 impl<T: PartialEq> InitializeFromFunction<T> for Memo<T> {
+    #[track_caller]
     fn initialize_from_function(f: fn() -> T) -> Self {
+        if Runtime::current().is_err() {
+            panic!("A GlobalMemo can only be resolved inside of a virtual dom. Memos recompute in a task of the app, so they can't be used from tests or threads outside of an app.");
+        }
         Memo::new(f)
     }
 }
 
 /// A memo that can be accessed from anywhere in the application and created in a static
+///
+/// Unlike a [`GlobalSignal`](super::GlobalSignal), a global memo recomputes in a task of the app, so reading it
+/// outside of a virtual dom panics.
 pub type GlobalMemo<T> = Global<Memo<T>, T>;
 
 impl<T: PartialEq + 'static> GlobalMemo<T> {
@@ -1,6 +1,10 @@
-use dioxus_core::ScopeId;
-use generational_box::BorrowResult;
-use std::{any::Any, cell::RefCell, collections::HashMap, ops::Deref, panic::Location, rc::Rc};
+use dioxus_core::prelude::with_owner;
+use dioxus_core::{Runtime, ScopeId};
+use generational_box::{AnyStorage, BorrowResult, Owner, UnsyncStorage};
+use std::{
+    any::Any, cell::RefCell, collections::HashMap, mem::ManuallyDrop, ops::Deref, panic::Location,
+    rc::Rc,
+};
 
 mod memo;
 pub use memo::*;
@@ -151,8 +155,16 @@ where
     }
 
     /// Resolve the global value. This will try to get the existing value from the current virtual dom, and if it doesn't exist, it will create a new one.
+    ///
+    /// Outside of a virtual dom, like in a test or on a thread that doesn't run an app, the value is resolved in a
+    /// store for the current thread instead. That value is separate from the value in every virtual dom and from the
+    /// value on every other thread. [`GlobalMemo`]s can't be resolved outside of a virtual dom and panic.
     // NOTE: This is not called "get" or "value" because those methods overlap with Readable and Writable
     pub fn resolve(&self) -> T {
+        if Runtime::current().is_err() {
+            return self.resolve_detached();
+        }
+
         let key = self.key();
 
         let context = get_global_context();
@@ -174,12 +186,37 @@ where
         signal
     }
 
+    /// Resolve the global value in the store of the current thread
+    fn resolve_detached(&self) -> T {
+        let key = self.key();
+        DETACHED.with(|(context, owner)| {
+            if let Some(signal) = context.map.borrow().get(&key) {
+                return signal.downcast_ref::<T>().cloned().unwrap();
+            }
+            let signal = with_owner(Owner::clone(owner), || {
+                T::initialize_from_function(self.constructor)
+            });
+            context
+                .map
+                .borrow_mut()
+                .insert(key, Box::new(signal.clone()));
+            signal
+        })
+    }
+
     /// Get the scope the signal was created in.
     pub fn origin_scope(&self) -> ScopeId {
         ScopeId::ROOT
     }
 }
 
+thread_local! {
+    /// The globals that were resolved outside of a virtual dom and the owner of their values. The owner is never
+    /// dropped because the storage it would recycle the values into may already be gone when the thread exits.
+    static DETACHED: (GlobalLazyContext, ManuallyDrop<Owner<UnsyncStorage>>) =
+        (GlobalLazyContext::default(), ManuallyDrop::new(UnsyncStorage::owner()));
+}
+
 /// The context for global signals
 #[derive(Clone, Default)]
 pub struct GlobalLazyContext {
@@ -10,6 +10,10 @@ impl<T> InitializeFromFunction<T> for Signal<T> {
 }
 
 /// A signal that can be accessed from anywhere in the application and created in a static
+///
+/// Outside of a virtual dom, the signal resolves to a value in a store for the current thread. That value is
+/// separate from the value in each app, and every thread gets its own value, so writes from a background thread are
+/// not seen by the app or by other threads. Send the new value to the app, for example with a channel, instead.
 pub type GlobalSignal<T> = Global<Signal<T>, T>;
 
 impl<T: 'static> GlobalSignal<T> {
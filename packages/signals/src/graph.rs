@@ -0,0 +1,242 @@
+//! Export the graph of signals and the reactive contexts that subscribe to them.
+//!
+//! Signals are added to the graph the first time a reactive context subscribes to them or when they are given a name
+//! with [`Signal::with_name`]. The graph is only tracked in debug builds. In release builds [`SignalGraph::capture`]
+//! always returns an empty graph.
+
+use crate::{Readable, Signal, SignalData};
+use dioxus_core::prelude::{ReactiveContext, ScopeId};
+use generational_box::{GenerationalBoxId, Storage};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use std::sync::{Arc, Mutex, Weak};
+
+type Subscribers = Mutex<HashSet<ReactiveContext>>;
+
+pub(crate) struct TrackedSignal {
+    name: Option<String>,
+    created_at: Option<&'static std::panic::Location<'static>>,
+    scope: ScopeId,
+    subscribers: Weak<Subscribers>,
+    writer: Option<ReactiveContext>,
+}
+
+static SIGNALS: once_cell::sync::Lazy<Mutex<TrackedSignals>> =
+    once_cell::sync::Lazy::new(Default::default);
+
+#[derive(Default)]
+struct TrackedSignals {
+    signals: HashMap<GenerationalBoxId, TrackedSignal>,
+    // The number of signals at which dropped signals are forgotten next
+    prune_at: usize,
+}
+
+impl TrackedSignals {
+    /// Forget signals that have been dropped
+    fn prune(&mut self) {
+        self.signals
+            .retain(|_, tracked| tracked.subscribers.strong_count() > 0);
+        self.prune_at = (self.signals.len() * 2).max(64);
+    }
+}
+
+/// Start tracking a signal if it isn't already tracked and update it
+#[allow(unused)]
+pub(crate) fn track<T: 'static, S: Storage<SignalData<T>>>(
+    signal: &Signal<T, S>,
+    subscribers: &Arc<Subscribers>,
+    update: impl FnOnce(&mut TrackedSignal),
+) {
+    #[cfg(debug_assertions)]
+    {
+        let mut signals = SIGNALS.lock().unwrap();
+        // Apps that never capture the graph still need to forget dropped signals. Pruning when the map doubled in
+        // size keeps the cost of tracking constant per signal
+        if signals.signals.len() >= signals.prune_at {
+            signals.prune();
+        }
+        let tracked = signals
+            .signals
+            .entry(signal.id())
+            .or_insert_with(|| TrackedSignal {
+                name: None,
+                created_at: signal.inner.value().created_at(),
+                scope: signal.origin_scope(),
+                subscribers: Arc::downgrade(subscribers),
+                writer: None,
+            });
+        update(tracked);
+    }
+}
+
+impl<T: 'static, S: Storage<SignalData<T>>> Signal<T, S> {
+    /// Give the signal a name that is shown in the [`SignalGraph`]. This only has an effect in debug builds.
+    ///
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// fn app() -> Element {
+    ///     let count = use_signal(|| 0).with_name("count");
+    ///     rsx! { "{count}" }
+    /// }
+    /// ```
+    pub fn with_name(self, name: impl ToString) -> Self {
+        if let Ok(data) = self.inner.try_read() {
+            track(&self, &data.subscribers, |tracked| {
+                tracked.name = Some(name.to_string())
+            });
+        }
+        self
+    }
+
+    /// Mark the reactive context that computes the value of this signal, like the context of a memo
+    pub(crate) fn set_writer(&self, writer: ReactiveContext) {
+        if let Ok(data) = self.inner.try_read() {
+            track(self, &data.subscribers, |tracked| {
+                tracked.writer = Some(writer)
+            });
+        }
+    }
+}
+
+/// A snapshot of the signals in the app and the reactive contexts that subscribe to them.
+///
+/// The graph can be exported as [DOT](https://graphviz.org/doc/info/lang.html) with [`SignalGraph::to_dot`], or as
+/// json with serde when the `serialize` feature is enabled.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignalGraph {
+    /// Every tracked signal that is still alive
+    pub signals: Vec<SignalNode>,
+    /// Every reactive context that subscribes to a tracked signal
+    pub subscribers: Vec<SubscriberNode>,
+}
+
+/// A signal in the [`SignalGraph`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignalNode {
+    /// A unique id for the signal in the graph
+    pub id: String,
+    /// The name given to the signal with [`Signal::with_name`]
+    pub name: Option<String>,
+    /// The location the signal was created at
+    pub created_at: Option<String>,
+    /// The scope that owns the signal
+    pub scope: usize,
+    /// The ids of the reactive contexts that are notified when the signal is written to
+    pub subscribers: Vec<String>,
+    /// The id of the reactive context that computes the value of this signal if it is a memo
+    pub written_by: Option<String>,
+}
+
+/// A reactive context that subscribes to signals in the [`SignalGraph`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct SubscriberNode {
+    /// A unique id for the reactive context in the graph
+    pub id: String,
+    /// The name of the component this context re-renders, if it renders a component
+    pub component: Option<String>,
+    /// The location the context was created at
+    pub created_at: Option<String>,
+    /// The scope that owns the context
+    pub scope: usize,
+}
+
+impl SignalGraph {
+    /// Capture the current graph of signals and subscribers. This must be called inside the runtime of the app to
+    /// include the names of components.
+    pub fn capture() -> Self {
+        let mut signals = SIGNALS.lock().unwrap();
+        signals.prune();
+
+        let mut graph = SignalGraph::default();
+        let mut seen_subscribers = HashSet::new();
+        let mut add_subscriber = |graph: &mut SignalGraph, context: &ReactiveContext| {
+            let id = format!("{:?}", context.id());
+            if seen_subscribers.insert(context.id()) {
+                graph.subscribers.push(SubscriberNode {
+                    id: id.clone(),
+                    component: context
+                        .rendered_scope()
+                        .and_then(|scope| scope.name())
+                        .map(str::to_string),
+                    created_at: context.origin().map(ToString::to_string),
+                    scope: context.origin_scope().0,
+                });
+            }
+            id
+        };
+
+        for (id, tracked) in signals.signals.iter() {
+            let Some(subscribers) = tracked.subscribers.upgrade() else {
+                continue;
+            };
+            let mut subscriber_ids: Vec<_> = subscribers
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|context| add_subscriber(&mut graph, context))
+                .collect();
+            subscriber_ids.sort();
+            let written_by = tracked
+                .writer
+                .as_ref()
+                .map(|writer| add_subscriber(&mut graph, writer));
+
+            graph.signals.push(SignalNode {
+                id: format!("{id:?}"),
+                name: tracked.name.clone(),
+                created_at: tracked.created_at.map(ToString::to_string),
+                scope: tracked.scope.0,
+                subscribers: subscriber_ids,
+                written_by,
+            });
+        }
+
+        graph.signals.sort_by(|a, b| a.id.cmp(&b.id));
+        graph.subscribers.sort_by(|a, b| a.id.cmp(&b.id));
+        graph
+    }
+
+    /// Export the graph in the [DOT](https://graphviz.org/doc/info/lang.html) format. Signals are drawn as ellipses
+    /// and subscribers as boxes. An edge from a signal to a subscriber means a write to the signal notifies the
+    /// subscriber.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph signals {\n");
+        for signal in &self.signals {
+            let label = signal
+                .name
+                .clone()
+                .or_else(|| signal.created_at.clone())
+                .unwrap_or_else(|| signal.id.clone());
+            _ = writeln!(
+                dot,
+                "    {:?} [label={:?}, shape=ellipse];",
+                signal.id, label
+            );
+        }
+        for subscriber in &self.subscribers {
+            let label = match (&subscriber.component, &subscriber.created_at) {
+                (Some(component), _) => component.clone(),
+                (None, Some(created_at)) => created_at.clone(),
+                (None, None) => subscriber.id.clone(),
+            };
+            _ = writeln!(
+                dot,
+                "    {:?} [label={:?}, shape=box];",
+                subscriber.id, label
+            );
+        }
+        for signal in &self.signals {
+            for subscriber in &signal.subscribers {
+                _ = writeln!(dot, "    {:?} -> {:?};", signal.id, subscriber);
+            }
+            if let Some(writer) = &signal.written_by {
+                _ = writeln!(dot, "    {:?} -> {:?} [style=dashed];", writer, signal.id);
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
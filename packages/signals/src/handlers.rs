@@ -0,0 +1,27 @@
+//! Shorthands for event handlers that only write to signals
+
+use crate::write::Writable;
+
+/// Create an event handler that sets a signal to a value every time it is called.
+///
+/// This is a shorthand for handlers that only set a signal, like `move |_| count.set(value.clone())`. It is not
+/// exported from the prelude, so call it through its module like `handlers::set`.
+///
+/// # Example
+/// ```rust
+/// # use dioxus::prelude::*;
+/// fn app() -> Element {
+///     let mut count = use_signal(|| 0);
+///     rsx! {
+///         "{count}"
+///         button { onclick: handlers::set(count, 0), "Reset" }
+///     }
+/// }
+/// ```
+pub fn set<W, E>(mut signal: W, value: W::Target) -> impl FnMut(E) + 'static
+where
+    W: Writable + 'static,
+    W::Target: Clone + Sized,
+{
+    move |_| signal.set(value.clone())
+}
@@ -16,15 +16,33 @@ pub use read_only_signal::*;
 mod map;
 pub use map::*;
 
+mod select;
+pub use select::*;
+
 mod set_compare;
 pub use set_compare::*;
 
+mod batch;
+pub use batch::*;
+
 mod memo;
 pub use memo::*;
 
 mod global;
 pub use global::*;
 
+mod graph;
+pub use graph::*;
+
+mod devtools;
+pub use devtools::*;
+
+mod store;
+pub use store::*;
+
+mod collections;
+pub use collections::*;
+
 mod impls;
 
 pub use generational_box::{
@@ -40,4 +58,6 @@ pub use write::*;
 mod props;
 pub use props::*;
 
+pub mod handlers;
+
 pub mod warnings;
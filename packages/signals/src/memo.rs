@@ -36,7 +36,32 @@ where
 }
 
 impl<T: 'static> Memo<T> {
-    /// Create a new memo
+    /// Create a new memo outside of a hook. The memo is owned by the current scope.
+    ///
+    /// The memo runs the closure once immediately and tracks every signal or memo the closure reads. The closure only
+    /// reruns after one of those dependencies changes, and readers of the memo only rerun if the new value is
+    /// different from the old value. `use_memo` wraps this in a hook so the memo is only
+    /// created once per component.
+    ///
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// fn app() -> Element {
+    ///     let mut items = use_signal(|| vec![3, 1, 2]);
+    ///     // Only sorts again when the items change
+    ///     let sorted = use_hook(|| {
+    ///         Memo::new(move || {
+    ///             let mut sorted = items.read().clone();
+    ///             sorted.sort();
+    ///             sorted
+    ///         })
+    ///     });
+    ///
+    ///     rsx! {
+    ///         "{sorted:?}"
+    ///         button { onclick: move |_| items.push(0), "Add item" }
+    ///     }
+    /// }
+    /// ```
     #[track_caller]
     pub fn new(f: impl FnMut() -> T + 'static) -> Self
     where
@@ -75,6 +100,7 @@ impl<T: 'static> Memo<T> {
             callback: recompute,
         });
         let state: Signal<T> = Signal::new_with_caller(value, location);
+        state.set_writer(rc);
 
         let memo = Memo {
             inner: state,
@@ -198,6 +224,15 @@ where
     /// If the signal has been dropped, this will panic.
     #[track_caller]
     fn try_peek_unchecked(&self) -> BorrowResult<ReadableRef<'static, Self>> {
+        // Bring the value up to date if a dependency changed since the last read, but don't subscribe to it
+        let needs_update = self
+            .update
+            .read()
+            .dirty
+            .swap(false, std::sync::atomic::Ordering::Relaxed);
+        if needs_update {
+            self.recompute();
+        }
         self.inner.try_peek_unchecked()
     }
 }
@@ -2,7 +2,7 @@ use std::{mem::MaybeUninit, ops::Index, rc::Rc};
 
 use generational_box::AnyStorage;
 
-use crate::MappedSignal;
+use crate::{MappedSignal, SelectedSignal};
 
 /// A reference to a value that can be read from.
 #[allow(type_alias_bounds)]
@@ -43,7 +43,7 @@ pub trait Readable {
 
     /// Map the readable type to a new type. This lets you provide a view into a readable type without needing to clone the inner value.
     ///
-    /// Anything that subscribes to the readable value will be rerun whenever the original value changes, even if the view does not change. If you want to memorize the view, you can use a [`crate::Memo`] instead.
+    /// Anything that subscribes to the readable value will be rerun whenever the original value changes, even if the view does not change. If you want to memorize the view, you can use [`Readable::select`] or a [`crate::Memo`] instead.
     ///
     /// # Example
     /// ```rust
@@ -99,6 +99,48 @@ pub trait Readable {
         MappedSignal::new(try_read, try_peek)
     }
 
+    /// Select part of the readable type. Like [`Readable::map`], this provides a view into the readable type without
+    /// cloning it when you read the view, but anything that reads the view only reruns when the selected value
+    /// changes.
+    ///
+    /// The selected value is compared with [`PartialEq`] every time the original value changes, so it is cloned once
+    /// per change. Selecting creates a signal and a task in the current scope, so it should be called inside of a hook.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// #[derive(Clone, PartialEq)]
+    /// struct User {
+    ///     name: String,
+    ///     last_seen: u64,
+    /// }
+    ///
+    /// #[component]
+    /// fn Profile(user: Signal<User>) -> Element {
+    ///     let name = use_hook(|| user.select(|user| &user.name));
+    ///     rsx! {
+    ///         // Updating the last seen time of the user doesn't rerender the name
+    ///         Name { name }
+    ///     }
+    /// }
+    ///
+    /// #[component]
+    /// fn Name(name: SelectedSignal<String>) -> Element {
+    ///     rsx! { h1 { "{name}" } }
+    /// }
+    /// ```
+    #[track_caller]
+    fn select<O>(
+        self,
+        f: impl Fn(&Self::Target) -> &O + 'static,
+    ) -> SelectedSignal<O, Self::Storage>
+    where
+        Self: Clone + Sized + 'static,
+        O: PartialEq + Clone + 'static,
+    {
+        SelectedSignal::new(self, f)
+    }
+
     /// Get the current value of the state. If this is a signal, this will subscribe the current scope to the signal.
     /// If the value has been dropped, this will panic. Calling this on a Signal is the same as
     /// using the signal() syntax to read and subscribe to its value
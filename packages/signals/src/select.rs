@@ -0,0 +1,120 @@
+use std::ops::Deref;
+
+use crate::{read::Readable, read_impls, MappedSignal, ReadableRef, Signal, Writable};
+use dioxus_core::prelude::*;
+use futures_util::StreamExt;
+use generational_box::{AnyStorage, BorrowResult, UnsyncStorage};
+
+/// A read only view into part of a signal created with [`Readable::select`].
+///
+/// Unlike a [`MappedSignal`], reading a selected signal doesn't subscribe to the whole signal it was selected from.
+/// Readers only rerun when the selected value changes.
+pub struct SelectedSignal<O: 'static, S: AnyStorage = UnsyncStorage> {
+    value: MappedSignal<O, S>,
+    changed: Signal<()>,
+}
+
+impl<O: 'static, S: AnyStorage> Clone for SelectedSignal<O, S> {
+    fn clone(&self) -> Self {
+        SelectedSignal {
+            value: self.value.clone(),
+            changed: self.changed,
+        }
+    }
+}
+
+impl<O, S> SelectedSignal<O, S>
+where
+    O: PartialEq + Clone + 'static,
+    S: AnyStorage,
+{
+    /// Create a new selected signal. The selected value is cloned to compare it with the next value after the
+    /// source changes.
+    #[track_caller]
+    pub(crate) fn new<R>(source: R, select: impl Fn(&R::Target) -> &O + 'static) -> Self
+    where
+        R: Readable<Storage = S> + Clone + 'static,
+    {
+        let value = source.map(select);
+        let mut changed = Signal::new(());
+
+        let (rc, mut updates) = ReactiveContext::new();
+        let mut current = rc.reset_and_run_in(|| value.with(O::clone));
+        spawn_isomorphic({
+            let value = value.clone();
+            async move {
+                while updates.next().await.is_some() {
+                    // Remove any pending updates
+                    while updates.try_next().is_ok() {}
+                    let new = rc.reset_and_run_in(|| value.with(O::clone));
+                    if new != current {
+                        current = new;
+                        changed.set(());
+                    }
+                }
+            }
+        });
+
+        SelectedSignal { value, changed }
+    }
+}
+
+impl<O, S> Readable for SelectedSignal<O, S>
+where
+    O: 'static,
+    S: AnyStorage,
+{
+    type Target = O;
+    type Storage = S;
+
+    #[track_caller]
+    fn try_read_unchecked(
+        &self,
+    ) -> Result<ReadableRef<'static, Self>, generational_box::BorrowError> {
+        // Only subscribe to changes of the selected value, not the whole source
+        drop(self.changed.try_read_unchecked()?);
+        self.value.try_peek_unchecked()
+    }
+
+    #[track_caller]
+    fn try_peek_unchecked(&self) -> BorrowResult<ReadableRef<'static, Self>> {
+        self.value.try_peek_unchecked()
+    }
+}
+
+impl<O, S> IntoAttributeValue for SelectedSignal<O, S>
+where
+    O: Clone + IntoAttributeValue,
+    S: AnyStorage,
+{
+    fn into_value(self) -> dioxus_core::AttributeValue {
+        self.with(|f| f.clone().into_value())
+    }
+}
+
+impl<O, S> PartialEq for SelectedSignal<O, S>
+where
+    O: 'static,
+    S: AnyStorage,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.changed == other.changed
+    }
+}
+
+/// Allow calling a signal with signal() syntax
+///
+/// Currently only limited to copy types, though could probably specialize for string/arc/rc
+impl<O, S> Deref for SelectedSignal<O, S>
+where
+    O: Clone,
+    S: AnyStorage + 'static,
+{
+    type Target = dyn Fn() -> O;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { Readable::deref_impl(self) }
+    }
+}
+
+read_impls!(SelectedSignal<T, S: AnyStorage>);
@@ -85,6 +85,9 @@ impl<T: 'static> Signal<T> {
     /// Global signals are generally not recommended for use in libraries because it makes it more difficult to allow multiple instances of components you define in your library.
     ///
     /// </div>
+    ///
+    /// Global signals can also be read and written outside of a dioxus app, like in tests. Outside of an app, the
+    /// signal is created once per thread and is separate from the signal in every app.
     #[track_caller]
     pub const fn global(constructor: fn() -> T) -> GlobalSignal<T> {
         Global::new(constructor)
@@ -149,12 +152,14 @@ impl<T: 'static, S: Storage<SignalData<T>>> Signal<T, S> {
     #[track_caller]
     #[tracing::instrument(skip(value))]
     pub fn new_maybe_sync(value: T) -> Self {
-        Self {
+        let signal = Self {
             inner: CopyValue::<SignalData<T>, S>::new_maybe_sync(SignalData {
                 subscribers: Default::default(),
                 value,
             }),
-        }
+        };
+        crate::devtools::record_created(&signal);
+        signal
     }
 
     /// Creates a new Signal with an explicit caller. Signals are a Copy state management solution with automatic dependency tracking.
@@ -171,7 +176,7 @@ impl<T: 'static, S: Storage<SignalData<T>>> Signal<T, S> {
     /// }
     /// ```
     pub fn new_with_caller(value: T, caller: &'static std::panic::Location<'static>) -> Self {
-        Self {
+        let signal = Self {
             inner: CopyValue::new_with_caller(
                 SignalData {
                     subscribers: Default::default(),
@@ -179,7 +184,9 @@ impl<T: 'static, S: Storage<SignalData<T>>> Signal<T, S> {
                 },
                 caller,
             ),
-        }
+        };
+        crate::devtools::record_created(&signal);
+        signal
     }
 
     /// Create a new signal with a custom owner scope. The signal will be dropped when the owner scope is dropped instead of the current scope.
@@ -196,7 +203,7 @@ impl<T: 'static, S: Storage<SignalData<T>>> Signal<T, S> {
         owner: ScopeId,
         caller: &'static std::panic::Location<'static>,
     ) -> Self {
-        Self {
+        let signal = Self {
             inner: CopyValue::<SignalData<T>, S>::new_maybe_sync_in_scope_with_caller(
                 SignalData {
                     subscribers: Default::default(),
@@ -205,7 +212,9 @@ impl<T: 'static, S: Storage<SignalData<T>>> Signal<T, S> {
                 owner,
                 caller,
             ),
-        }
+        };
+        crate::devtools::record_created(&signal);
+        signal
     }
 
     /// Drop the value out of the signal, invalidating the signal in the process.
@@ -222,6 +231,13 @@ impl<T: 'static, S: Storage<SignalData<T>>> Signal<T, S> {
         {
             let inner = self.inner.read();
 
+            if crate::batch::is_batching() {
+                for reactive_context in inner.subscribers.lock().unwrap().iter() {
+                    crate::batch::defer_mark_dirty(*reactive_context);
+                }
+                return;
+            }
+
             // We cannot hold the subscribers lock while calling mark_dirty, because mark_dirty can run user code which may cause a new subscriber to be added. If we hold the lock, we will deadlock.
             #[allow(clippy::mutable_key_type)]
             let mut subscribers = std::mem::take(&mut *inner.subscribers.lock().unwrap());
@@ -368,7 +384,9 @@ impl<T, S: Storage<SignalData<T>>> Readable for Signal<T, S> {
 
         if let Some(reactive_context) = ReactiveContext::current() {
             tracing::trace!("Subscribing to the reactive context {}", reactive_context);
+            crate::devtools::record_subscribed(self.id(), &inner.subscribers, reactive_context);
             reactive_context.subscribe(inner.subscribers.clone());
+            crate::graph::track(self, &inner.subscribers, |_| {});
         }
 
         Ok(S::map(inner, |v| &v.value))
@@ -568,6 +586,7 @@ impl<T: 'static, S: Storage<SignalData<T>>> Drop for SignalSubscriberDrop<T, S>
                 self.origin,
                 self.signal,
             );
+            crate::devtools::record_written(self.signal.id(), Some(self.origin));
         }
         self.signal.update_subscribers();
     }
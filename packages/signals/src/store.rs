@@ -0,0 +1,251 @@
+use crate::read_impls;
+use crate::{read::Readable, CopyValue, Global, ReadableRef, Signal, UnsyncStorage, Writable};
+use generational_box::BorrowResult;
+use std::any::Any;
+use std::fmt::Debug;
+use std::ops::Deref;
+use std::rc::Rc;
+
+/// Middleware that runs around every action dispatched to a [`Store`]. Middleware can log actions, drop them, or
+/// connect the store to external tools like the Redux DevTools.
+///
+/// Middleware runs in the order it was added to the store.
+pub trait StoreMiddleware<T: 'static, A: 'static>: 'static {
+    /// Called once when the middleware is added to a store
+    fn attach(&self, store: Store<T, A>) {
+        _ = store;
+    }
+
+    /// Called before the reducers handle an action. Return `false` to drop the action. Later middleware and the
+    /// reducers don't see dropped actions.
+    fn before_dispatch(&self, store: Store<T, A>, action: &A) -> bool {
+        _ = (store, action);
+        true
+    }
+
+    /// Called after the reducers handled an action. The store contains the new state.
+    fn after_dispatch(&self, store: Store<T, A>, action: &A) {
+        _ = (store, action);
+    }
+}
+
+/// Middleware that logs every action and the new state of the store with `tracing`. Actions are logged at the info
+/// level and states at the debug level.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ActionLogger;
+
+impl<T: Debug + 'static, A: Debug + 'static> StoreMiddleware<T, A> for ActionLogger {
+    fn after_dispatch(&self, store: Store<T, A>, action: &A) {
+        let name = store.name();
+        tracing::info!("{name}: {action:?}");
+        tracing::debug!("{name} state: {:?}", &*store.peek());
+    }
+}
+
+struct StoreInner<T: 'static, A: 'static> {
+    name: String,
+    reducers: Vec<Rc<dyn Fn(&mut T, &A)>>,
+    middleware: Vec<Rc<dyn StoreMiddleware<T, A>>>,
+}
+
+/// State that only changes when an action is dispatched to it.
+///
+/// Every action runs through the middleware of the store and is then handled by each reducer in the order they were
+/// added. Reading the store subscribes to the state like reading a signal.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_signals::{ActionLogger, Store};
+///
+/// #[derive(Debug)]
+/// enum CounterAction {
+///     Increment,
+///     Reset,
+/// }
+///
+/// fn app() -> Element {
+///     let counter = use_hook(|| {
+///         Store::new("counter", 0)
+///             .with_reducer(|count, action: &CounterAction| match action {
+///                 CounterAction::Increment => *count += 1,
+///                 CounterAction::Reset => *count = 0,
+///             })
+///             .with_middleware(ActionLogger)
+///     });
+///
+///     rsx! {
+///         button { onclick: move |_| counter.dispatch(CounterAction::Increment), "{counter}" }
+///         button { onclick: move |_| counter.dispatch(CounterAction::Reset), "Reset" }
+///     }
+/// }
+/// ```
+pub struct Store<T: 'static, A: 'static> {
+    state: Signal<T>,
+    inner: CopyValue<StoreInner<T, A>>,
+}
+
+impl<T: 'static, A: 'static> Store<T, A> {
+    /// Create a new store without reducers in the current scope. The name identifies the store in logs and devtools.
+    #[track_caller]
+    pub fn new(name: impl ToString, state: T) -> Self {
+        Self {
+            state: Signal::new(state),
+            inner: CopyValue::new(StoreInner {
+                name: name.to_string(),
+                reducers: Vec::new(),
+                middleware: Vec::new(),
+            }),
+        }
+    }
+
+    /// Add a reducer that handles every action dispatched to the store
+    pub fn with_reducer(self, reducer: impl Fn(&mut T, &A) + 'static) -> Self {
+        self.add_reducer(reducer);
+        self
+    }
+
+    /// Add a middleware that runs around every action dispatched to the store
+    pub fn with_middleware(self, middleware: impl StoreMiddleware<T, A>) -> Self {
+        self.add_middleware(middleware);
+        self
+    }
+
+    /// Add a reducer that handles every action dispatched to the store
+    pub fn add_reducer(&self, reducer: impl Fn(&mut T, &A) + 'static) {
+        self.inner.write_unchecked().reducers.push(Rc::new(reducer));
+    }
+
+    /// Add a middleware that runs around every action dispatched to the store
+    pub fn add_middleware(&self, middleware: impl StoreMiddleware<T, A>) {
+        let middleware = Rc::new(middleware);
+        self.inner
+            .write_unchecked()
+            .middleware
+            .push(middleware.clone());
+        middleware.attach(*self);
+    }
+
+    /// The name of the store
+    pub fn name(&self) -> String {
+        self.inner.read().name.clone()
+    }
+
+    /// Dispatch an action to the middleware and reducers of the store.
+    ///
+    /// # Panics
+    ///
+    /// Reducers run while the state is borrowed mutably, so dispatching an action from a reducer will panic.
+    #[track_caller]
+    pub fn dispatch(&self, action: A) {
+        let (reducers, middleware) = {
+            let inner = self.inner.read();
+            (inner.reducers.clone(), inner.middleware.clone())
+        };
+
+        if !middleware.iter().all(|m| m.before_dispatch(*self, &action)) {
+            return;
+        }
+
+        {
+            let mut state = self.state.write_unchecked();
+            for reducer in &reducers {
+                reducer(&mut state, &action);
+            }
+        }
+
+        for middleware in &middleware {
+            middleware.after_dispatch(*self, &action);
+        }
+    }
+
+    /// Replace the state without running the reducers or middleware. This is used by devtools to jump between
+    /// recorded states.
+    pub fn replace_state(&self, state: T) {
+        *self.state.write_unchecked() = state;
+    }
+
+    /// Get the state of the store as a read only signal
+    pub fn state(&self) -> crate::ReadOnlySignal<T> {
+        self.state.into()
+    }
+}
+
+impl<T: 'static, A: 'static> Readable for Store<T, A> {
+    type Target = T;
+    type Storage = UnsyncStorage;
+
+    #[track_caller]
+    fn try_read_unchecked(
+        &self,
+    ) -> Result<ReadableRef<'static, Self>, generational_box::BorrowError> {
+        self.state.try_read_unchecked()
+    }
+
+    #[track_caller]
+    fn try_peek_unchecked(&self) -> BorrowResult<ReadableRef<'static, Self>> {
+        self.state.try_peek_unchecked()
+    }
+}
+
+impl<T: 'static, A: 'static> Clone for Store<T, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static, A: 'static> Copy for Store<T, A> {}
+
+impl<T: 'static, A: 'static> PartialEq for Store<T, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl<T: Clone + 'static, A: 'static> Deref for Store<T, A> {
+    type Target = dyn Fn() -> T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { Readable::deref_impl(self) }
+    }
+}
+
+read_impls!(Store<T, A: Any>);
+
+/// A store that can be accessed from anywhere in the application and created in a static
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_signals::{GlobalStore, Store};
+///
+/// enum TodoAction {
+///     Add(String),
+/// }
+///
+/// static TODOS: GlobalStore<Vec<String>, TodoAction> = GlobalStore::new(|| {
+///     Store::new("todos", Vec::new()).with_reducer(|todos, action| match action {
+///         TodoAction::Add(todo) => todos.push(todo.clone()),
+///     })
+/// });
+///
+/// fn app() -> Element {
+///     rsx! {
+///         button { onclick: move |_| TODOS.dispatch(TodoAction::Add("Write docs".into())), "Add" }
+///         "{TODOS.store().len()} todos"
+///     }
+/// }
+/// ```
+pub type GlobalStore<T, A> = Global<Store<T, A>>;
+
+impl<T: 'static, A: 'static> GlobalStore<T, A> {
+    /// Resolve the global store. This will try to get the existing store from the current virtual dom, and if it
+    /// doesn't exist, it will create a new one.
+    pub fn store(&self) -> Store<T, A> {
+        self.resolve()
+    }
+
+    /// Dispatch an action to the global store
+    #[track_caller]
+    pub fn dispatch(&self, action: A) {
+        self.resolve().dispatch(action)
+    }
+}
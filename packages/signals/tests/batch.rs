@@ -0,0 +1,74 @@
+use dioxus::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Create signals and a reactive context that reads all of them and counts how many times it is marked dirty
+fn setup() -> (Vec<Signal<i32>>, Arc<AtomicUsize>) {
+    let signals: Vec<_> = (0..3).map(Signal::new).collect();
+    let notified = Arc::new(AtomicUsize::new(0));
+    let reactive_context = ReactiveContext::new_with_callback(
+        {
+            let notified = notified.clone();
+            move || {
+                notified.fetch_add(1, Ordering::SeqCst);
+            }
+        },
+        current_scope_id().unwrap(),
+        std::panic::Location::caller(),
+    );
+    reactive_context.run_in(|| {
+        for signal in &signals {
+            signal.read();
+        }
+    });
+    (signals, notified)
+}
+
+#[test]
+fn batched_writes_notify_subscribers_once() {
+    let mut dom = VirtualDom::new(|| rsx! {});
+    dom.rebuild_in_place();
+
+    dom.in_runtime(|| {
+        ScopeId::ROOT.in_runtime(|| {
+            let (mut signals, notified) = setup();
+
+            let sum = batch(|| {
+                for signal in &mut signals {
+                    *signal.write() += 1;
+                }
+                // Nothing is notified until the batch ends
+                assert_eq!(notified.load(Ordering::SeqCst), 0);
+                signals.iter().map(|signal| signal()).sum::<i32>()
+            });
+            assert_eq!(sum, 6);
+            assert_eq!(notified.load(Ordering::SeqCst), 1);
+
+            // Without a batch, each write notifies the subscribers
+            for signal in &mut signals {
+                *signal.write() += 1;
+            }
+            assert_eq!(notified.load(Ordering::SeqCst), 4);
+        })
+    });
+}
+
+#[test]
+fn nested_batches_notify_when_the_outer_batch_ends() {
+    let mut dom = VirtualDom::new(|| rsx! {});
+    dom.rebuild_in_place();
+
+    dom.in_runtime(|| {
+        ScopeId::ROOT.in_runtime(|| {
+            let (mut signals, notified) = setup();
+
+            batch(|| {
+                signals[0].set(10);
+                batch(|| signals[1].set(20));
+                assert_eq!(notified.load(Ordering::SeqCst), 0);
+                signals[2].set(30);
+            });
+            assert_eq!(notified.load(Ordering::SeqCst), 1);
+        })
+    });
+}
@@ -0,0 +1,112 @@
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+thread_local! {
+    static LIST: Cell<Option<SignalVec<String>>> = const { Cell::new(None) };
+    static RENDERS: RefCell<HashMap<String, usize>> = RefCell::new(HashMap::new());
+}
+
+fn renders(name: &str) -> usize {
+    RENDERS.with(|renders| renders.borrow().get(name).copied().unwrap_or_default())
+}
+
+fn rendered(name: &str) {
+    RENDERS.with(|renders| *renders.borrow_mut().entry(name.to_string()).or_default() += 1);
+}
+
+fn app() -> Element {
+    let list = use_hook(|| SignalVec::from_iter(["a".to_string(), "b".to_string()]));
+    LIST.with(|cell| cell.set(Some(list)));
+    rendered("list");
+    rsx! {
+        for (key, item) in list.items() {
+            Item { key: "{key}", id: key, item }
+        }
+    }
+}
+
+#[component]
+fn Item(id: u64, item: Signal<String>) -> Element {
+    rendered(&format!("item {id}"));
+    rsx! { "{item}" }
+}
+
+#[test]
+fn item_writes_only_rerun_item_readers() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let list = LIST.with(|cell| cell.get()).unwrap();
+    assert_eq!(
+        (renders("list"), renders("item 0"), renders("item 1")),
+        (1, 1, 1)
+    );
+
+    dom.in_runtime(|| list.set(1, "B".to_string()));
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(
+        (renders("list"), renders("item 0"), renders("item 1")),
+        (1, 1, 2)
+    );
+
+    // Structural changes rerun the list, but the existing items keep their keys and don't rerender
+    let version = dom.in_runtime(|| list.version());
+    dom.in_runtime(|| {
+        list.push("c".to_string());
+        list.move_item(2, 0);
+        assert_eq!(list.remove(2).as_deref(), Some("B"));
+    });
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(
+        (
+            renders("list"),
+            renders("item 0"),
+            renders("item 1"),
+            renders("item 2")
+        ),
+        (2, 1, 2, 1)
+    );
+
+    dom.in_runtime(|| {
+        let values: Vec<String> = list.items().iter().map(|(_, item)| item()).collect();
+        assert_eq!(values, ["c", "a"]);
+        assert_eq!(
+            list.deltas_since(version).unwrap(),
+            [
+                VecDelta::Insert { index: 2, key: 2 },
+                VecDelta::Move {
+                    from: 2,
+                    to: 0,
+                    key: 2
+                },
+                VecDelta::Remove { index: 2, key: 1 },
+            ]
+        );
+        assert_eq!(list.deltas_since(list.version()).unwrap(), []);
+    });
+}
+
+#[test]
+fn map_value_writes_dont_change_the_structure() {
+    let mut dom = VirtualDom::new(|| rsx! {});
+    dom.rebuild_in_place();
+
+    dom.in_runtime(|| {
+        ScopeId::ROOT.in_runtime(|| {
+            let map = SignalMap::<&str, i32>::new();
+            assert_eq!(map.insert("a", 1), None);
+            assert_eq!(map.insert("b", 2), None);
+            let version = map.version();
+
+            assert_eq!(map.insert("a", 10), Some(1));
+            assert_eq!(map.version(), version);
+            assert_eq!(map.get(&"a").map(|value| value()), Some(10));
+
+            assert_eq!(map.remove(&"a"), Some(10));
+            assert_eq!(map.keys(), ["b"]);
+            assert_eq!(map.deltas_since(version).unwrap(), [MapDelta::Remove("a")]);
+            assert_eq!(map.deltas_since(0).unwrap().len(), 3);
+        })
+    });
+}
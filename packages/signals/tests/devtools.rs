@@ -0,0 +1,167 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use std::cell::Cell;
+
+thread_local! {
+    static COUNT: Cell<Option<Signal<i32>>> = const { Cell::new(None) };
+    static RENDERED: Cell<i32> = const { Cell::new(-1) };
+}
+
+fn app() -> Element {
+    let count = use_signal(|| 0).inspect();
+    COUNT.with(|cell| cell.set(Some(count)));
+    rsx! { Child { count } }
+}
+
+#[component]
+fn Child(count: Signal<i32>) -> Element {
+    RENDERED.with(|cell| cell.set(count()));
+    rsx! { "{count}" }
+}
+
+#[cfg(debug_assertions)]
+fn writes() -> Vec<SignalEvent> {
+    SignalDevtools::events()
+        .into_iter()
+        .filter(|event| matches!(event.kind, SignalEventKind::Written { .. }))
+        .collect()
+}
+
+// The history is only recorded in debug builds
+#[cfg(debug_assertions)]
+#[test]
+fn records_creations_subscriptions_and_writes() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let mut count = COUNT.with(|cell| cell.get()).unwrap();
+    let id = format!("{:?}", count.id());
+
+    let events: Vec<_> = SignalDevtools::events()
+        .into_iter()
+        .filter(|event| event.signal == id)
+        .collect();
+    match &events[0].kind {
+        SignalEventKind::Created { created_at, .. } => {
+            assert!(created_at.as_ref().unwrap().contains("devtools.rs"))
+        }
+        kind => panic!("expected the creation first, found {kind:?}"),
+    }
+    assert!(matches!(events[1].kind, SignalEventKind::Subscribed { .. }));
+
+    let last = SignalDevtools::events().last().unwrap().index;
+    dom.in_runtime(|| count.set(5));
+    let new_events = SignalDevtools::events_since(last);
+    assert_eq!(new_events.len(), 1);
+    match &new_events[0].kind {
+        SignalEventKind::Written { at, old, new } => {
+            assert!(at.as_ref().unwrap().contains("devtools.rs"));
+            assert_eq!(old.as_deref(), Some("0"));
+            assert_eq!(new.as_deref(), Some("5"));
+        }
+        kind => panic!("expected a write, found {kind:?}"),
+    }
+}
+
+// The history is only recorded in debug builds
+#[cfg(debug_assertions)]
+#[test]
+fn replay_restores_past_values() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let mut count = COUNT.with(|cell| cell.get()).unwrap();
+
+    dom.in_runtime(|| {
+        count.set(1);
+        count.set(2);
+        count.set(3);
+    });
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(RENDERED.with(|cell| cell.get()), 3);
+
+    let history = writes();
+    assert_eq!(history.len(), 3);
+
+    dom.in_runtime(|| assert_eq!(SignalDevtools::replay(history[0].index), 1));
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(RENDERED.with(|cell| cell.get()), 1);
+
+    // Replaying doesn't change the history, so it can move forward again
+    assert_eq!(writes(), history);
+    dom.in_runtime(|| SignalDevtools::replay(history[2].index));
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(RENDERED.with(|cell| cell.get()), 3);
+
+    // Or back to before the first write
+    dom.in_runtime(|| SignalDevtools::replay(history[0].index - 1));
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(RENDERED.with(|cell| cell.get()), 0);
+}
+
+// The history is only recorded in debug builds
+#[cfg(debug_assertions)]
+#[test]
+fn old_events_are_dropped_past_the_capacity() {
+    SignalDevtools::set_capacity(2);
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let mut count = COUNT.with(|cell| cell.get()).unwrap();
+    dom.in_runtime(|| {
+        for i in 1..=5 {
+            count.set(i);
+        }
+    });
+
+    let history = writes();
+    assert_eq!(history.len(), 2);
+    dom.in_runtime(|| SignalDevtools::replay(history[0].index));
+    assert_eq!(dom.in_runtime(|| count.peek().to_owned()), 4);
+}
+
+/// A value whose Debug impl reads another signal
+#[cfg(debug_assertions)]
+#[derive(Clone)]
+struct Label(Signal<i32>);
+
+#[cfg(debug_assertions)]
+impl std::fmt::Debug for Label {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Label({})", self.0.read())
+    }
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn inspected_values_can_read_signals() {
+    let mut dom = VirtualDom::new(|| rsx! {});
+    dom.rebuild_in_place();
+
+    dom.in_runtime(|| {
+        ScopeId::ROOT.in_runtime(|| {
+            let count = Signal::new(1);
+            let mut label = Signal::new(Label(count)).inspect();
+            // Formatting the label inside a reactive context subscribes to the count while the write is recorded
+            let (context, _) = ReactiveContext::new();
+            context.run_in(|| label.set(Label(count)));
+        })
+    });
+
+    match &writes().last().unwrap().kind {
+        SignalEventKind::Written { old, new, .. } => {
+            assert_eq!(old.as_deref(), Some("Label(1)"));
+            assert_eq!(new.as_deref(), Some("Label(1)"));
+        }
+        kind => panic!("expected a write, found {kind:?}"),
+    }
+}
+
+#[cfg(not(debug_assertions))]
+#[test]
+fn nothing_is_recorded_in_release_builds() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let mut count = COUNT.with(|cell| cell.get()).unwrap();
+    dom.in_runtime(|| count.set(5));
+    assert_eq!(SignalDevtools::events(), vec![]);
+}
@@ -0,0 +1,57 @@
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use std::cell::Cell;
+
+#[test]
+fn globals_can_be_used_outside_of_a_virtual_dom() {
+    static COUNT: GlobalSignal<i32> = Signal::global(|| 1);
+    static ITEMS: GlobalSignal<Vec<&str>> = Signal::global(Vec::new);
+
+    assert_eq!(COUNT(), 1);
+    *COUNT.write() += 1;
+    assert_eq!(*COUNT.read(), 2);
+    assert_eq!(*COUNT.peek(), 2);
+
+    ITEMS.write().push("first");
+    assert_eq!(ITEMS.len(), 1);
+    assert_eq!(COUNT.signal(), COUNT.signal());
+
+    // Values on other threads are separate
+    std::thread::spawn(|| assert_eq!(COUNT(), 1))
+        .join()
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "A GlobalMemo can only be resolved inside of a virtual dom")]
+fn global_memos_panic_outside_of_a_virtual_dom() {
+    static DOUBLED: GlobalMemo<i32> = Memo::global(|| 2);
+
+    DOUBLED();
+}
+
+#[test]
+fn values_outside_of_a_virtual_dom_are_separate_from_the_app() {
+    static NAME: GlobalSignal<&str> = Signal::global(|| "initial");
+
+    thread_local! {
+        static RENDERED: Cell<&'static str> = const { Cell::new("") };
+    }
+
+    fn app() -> Element {
+        RENDERED.with(|rendered| rendered.set(NAME()));
+        rsx! { "{NAME}" }
+    }
+
+    *NAME.write() = "outside";
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert_eq!(RENDERED.with(Cell::get), "initial");
+
+    // Components still subscribe to the value of their app
+    dom.in_runtime(|| *NAME.write() = "inside");
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(RENDERED.with(Cell::get), "inside");
+    assert_eq!(NAME(), "outside");
+}
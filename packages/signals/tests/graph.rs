@@ -0,0 +1,73 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+
+#[test]
+fn graph_contains_subscribers() {
+    fn app() -> Element {
+        let count = use_signal(|| 0).with_name("count");
+        let doubled = use_memo(move || count() * 2);
+
+        rsx! {
+            Child { count }
+            "{doubled}"
+        }
+    }
+
+    #[component]
+    fn Child(count: Signal<i32>) -> Element {
+        rsx! { "{count}" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    dom.render_immediate(&mut NoOpMutations);
+
+    let graph = dom.in_runtime(SignalGraph::capture);
+    // The graph is only tracked with debug assertions
+    if !cfg!(debug_assertions) {
+        assert_eq!(graph, SignalGraph::default());
+        return;
+    }
+
+    let count = graph
+        .signals
+        .iter()
+        .find(|signal| signal.name.as_deref() == Some("count"))
+        .expect("named signals are tracked");
+    assert!(count.created_at.as_ref().unwrap().contains("graph.rs"));
+
+    // The child component and the memo both subscribe to the signal
+    let subscribers: Vec<_> = graph
+        .subscribers
+        .iter()
+        .filter(|subscriber| count.subscribers.contains(&subscriber.id))
+        .collect();
+    assert_eq!(subscribers.len(), 2);
+    assert!(subscribers.iter().any(|subscriber| subscriber
+        .component
+        .as_deref()
+        .is_some_and(|name| name.ends_with("Child"))));
+
+    // The memo's signal is written by the memo's reactive context and read by the app
+    let memo = graph
+        .signals
+        .iter()
+        .find(|signal| signal.written_by.is_some())
+        .expect("memos are tracked");
+    assert!(count
+        .subscribers
+        .contains(memo.written_by.as_ref().unwrap()));
+    assert_eq!(memo.subscribers.len(), 1);
+
+    let dot = graph.to_dot();
+    assert!(dot.starts_with("digraph signals {"));
+    assert!(dot.contains("[label=\"count\", shape=ellipse]"));
+    assert!(dot.contains(&format!("{:?} -> {:?};", count.id, subscribers[0].id)));
+    assert!(dot.contains(&format!(
+        "{:?} -> {:?} [style=dashed];",
+        memo.written_by.as_ref().unwrap(),
+        memo.id
+    )));
+}
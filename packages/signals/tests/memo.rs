@@ -146,3 +146,73 @@
 //         assert_eq!(current_counter.memo, 3);
 //     }
 // }
+
+mod memo_new {
+    use dioxus::prelude::*;
+    use dioxus_core::NoOpMutations;
+    use std::cell::Cell;
+
+    thread_local! {
+        static RUNS: Cell<usize> = const { Cell::new(0) };
+        static HANDLES: Cell<Option<Handles>> = const { Cell::new(None) };
+    }
+
+    #[derive(Clone, Copy)]
+    struct Handles {
+        dependency: Signal<i32>,
+        unrelated: Signal<i32>,
+        doubled: Memo<i32>,
+    }
+
+    fn app() -> Element {
+        let handles = use_hook(|| {
+            let dependency = Signal::new(1);
+            let unrelated = Signal::new(0);
+            let doubled = Memo::new(move || {
+                RUNS.with(|runs| runs.set(runs.get() + 1));
+                dependency() * 2
+            });
+            let handles = Handles {
+                dependency,
+                unrelated,
+                doubled,
+            };
+            HANDLES.with(|cell| cell.set(Some(handles)));
+            handles
+        });
+        rsx! { "{handles.doubled}" }
+    }
+
+    #[test]
+    fn only_recomputes_when_dependencies_change() {
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let Handles {
+            mut dependency,
+            mut unrelated,
+            doubled,
+        } = HANDLES.with(|cell| cell.get()).unwrap();
+        let runs = || RUNS.with(|runs| runs.get());
+
+        dom.in_runtime(|| {
+            assert_eq!(doubled(), 2);
+            assert_eq!(runs(), 1);
+
+            unrelated.set(1);
+            assert_eq!(doubled(), 2);
+            assert_eq!(runs(), 1);
+
+            dependency.set(2);
+            assert_eq!(*doubled.read(), 4);
+            assert_eq!(runs(), 2);
+
+            // Peeking also sees the new value, without subscribing to the memo
+            dependency.set(3);
+            assert_eq!(*doubled.peek(), 6);
+            assert_eq!(runs(), 3);
+            assert_eq!(doubled(), 6);
+            assert_eq!(runs(), 3);
+        });
+        dom.render_immediate(&mut NoOpMutations);
+    }
+}
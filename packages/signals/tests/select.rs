@@ -0,0 +1,65 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use std::cell::{Cell, RefCell};
+use std::time::Duration;
+
+#[derive(Clone, PartialEq)]
+struct User {
+    name: String,
+    last_seen: u64,
+}
+
+thread_local! {
+    static USER: Cell<Option<Signal<User>>> = const { Cell::new(None) };
+    static NAME_RENDERS: Cell<usize> = const { Cell::new(0) };
+    static RENDERED_NAME: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+fn app() -> Element {
+    let user = use_signal(|| User {
+        name: "Ferris".to_string(),
+        last_seen: 0,
+    });
+    USER.with(|cell| cell.set(Some(user)));
+    let name = use_hook(|| user.select(|user| &user.name));
+    rsx! {
+        Name { name }
+    }
+}
+
+#[component]
+fn Name(name: SelectedSignal<String>) -> Element {
+    NAME_RENDERS.with(|renders| renders.set(renders.get() + 1));
+    RENDERED_NAME.with(|rendered| *rendered.borrow_mut() = name());
+    rsx! { "{name}" }
+}
+
+async fn settle(dom: &mut VirtualDom) {
+    _ = tokio::time::timeout(Duration::from_millis(100), dom.wait_for_work()).await;
+    dom.render_immediate(&mut NoOpMutations);
+}
+
+#[tokio::test]
+async fn readers_only_rerun_when_the_selection_changes() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let mut user = USER.with(|cell| cell.get()).unwrap();
+    let renders = || NAME_RENDERS.with(|renders| renders.get());
+    assert_eq!(renders(), 1);
+
+    dom.in_runtime(|| user.write().last_seen = 1);
+    settle(&mut dom).await;
+    assert_eq!(renders(), 1);
+    assert_eq!(rendered_name(), "Ferris");
+
+    dom.in_runtime(|| user.write().name = "Corro".to_string());
+    settle(&mut dom).await;
+    assert_eq!(renders(), 2);
+    assert_eq!(rendered_name(), "Corro");
+}
+
+fn rendered_name() -> String {
+    RENDERED_NAME.with(|rendered| rendered.borrow().clone())
+}
@@ -0,0 +1,88 @@
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Action {
+    Add(i32),
+    Reset,
+}
+
+fn counter() -> Store<i32, Action> {
+    Store::new("counter", 0).with_reducer(|count, action| match action {
+        Action::Add(amount) => *count += amount,
+        Action::Reset => *count = 0,
+    })
+}
+
+/// Records the actions it sees and drops negative additions
+#[derive(Clone, Default)]
+struct Audit(Rc<RefCell<Vec<String>>>);
+
+impl StoreMiddleware<i32, Action> for Audit {
+    fn attach(&self, store: Store<i32, Action>) {
+        self.0.borrow_mut().push(format!("attach {}", store.name()));
+    }
+
+    fn before_dispatch(&self, _: Store<i32, Action>, action: &Action) -> bool {
+        !matches!(action, Action::Add(amount) if *amount < 0)
+    }
+
+    fn after_dispatch(&self, store: Store<i32, Action>, action: &Action) {
+        self.0
+            .borrow_mut()
+            .push(format!("{action:?} -> {}", store.peek()));
+    }
+}
+
+#[test]
+fn reducers_and_middleware_handle_actions() {
+    let mut dom = VirtualDom::new(|| rsx! {});
+    dom.rebuild_in_place();
+
+    dom.in_runtime(|| {
+        ScopeId::APP.in_runtime(|| {
+            let audit = Audit::default();
+            let store = counter()
+                .with_reducer(|count, _| *count *= 2)
+                .with_middleware(audit.clone())
+                .with_middleware(ActionLogger);
+
+            store.dispatch(Action::Add(3));
+            assert_eq!(store(), 6);
+            store.dispatch(Action::Add(-1));
+            assert_eq!(store(), 6);
+            store.dispatch(Action::Reset);
+            assert_eq!(store(), 0);
+
+            assert_eq!(
+                *audit.0.borrow(),
+                ["attach counter", "Add(3) -> 6", "Reset -> 0"]
+            );
+
+            store.replace_state(10);
+            assert_eq!(*store.state().read(), 10);
+            assert_eq!(audit.0.borrow().len(), 3);
+        })
+    });
+}
+
+#[test]
+fn dispatching_rerenders_readers() {
+    static COUNTER: GlobalStore<i32, Action> = GlobalStore::new(counter);
+
+    let renders = Rc::new(RefCell::new(Vec::new()));
+    let mut dom = VirtualDom::new_with_props(
+        |renders: Rc<RefCell<Vec<i32>>>| {
+            renders.borrow_mut().push(COUNTER.store()());
+            rsx! {}
+        },
+        renders.clone(),
+    );
+    dom.rebuild_in_place();
+    dom.in_runtime(|| ScopeId::APP.in_runtime(|| COUNTER.dispatch(Action::Add(2))));
+    dom.render_immediate(&mut NoOpMutations);
+
+    assert_eq!(*renders.borrow(), [0, 2]);
+}
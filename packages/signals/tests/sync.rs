@@ -0,0 +1,41 @@
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_signals::SyncCopyValue;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+#[tokio::test]
+async fn writes_from_other_threads_rerun_readers() {
+    static RENDERS: AtomicUsize = AtomicUsize::new(0);
+
+    fn app() -> Element {
+        let mut count = use_signal_sync(|| 0);
+        let mut written_from: SyncCopyValue<Option<String>> =
+            use_hook(|| CopyValue::new_maybe_sync(None));
+        // Subscribe to the signal before the other thread can write to it
+        let value = count();
+        use_hook(|| {
+            std::thread::Builder::new()
+                .name("writer".to_string())
+                .spawn(move || {
+                    *written_from.write() = std::thread::current().name().map(str::to_string);
+                    count += 1;
+                })
+                .unwrap();
+        });
+        RENDERS.fetch_add(1, Ordering::SeqCst);
+        if value == 1 {
+            assert_eq!(written_from.read().as_deref(), Some("writer"));
+        }
+        rsx! { "{count}" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    tokio::time::timeout(Duration::from_secs(5), dom.wait_for_work())
+        .await
+        .expect("the write from the other thread should wake the virtual dom");
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(RENDERS.load(Ordering::SeqCst), 2);
+}
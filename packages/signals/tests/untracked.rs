@@ -0,0 +1,57 @@
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use std::cell::Cell;
+
+#[test]
+fn untracked_reads_dont_subscribe() {
+    thread_local! {
+        static SIGNALS: Cell<Option<(Signal<i32>, Signal<i32>)>> = const { Cell::new(None) };
+        static SUM: Cell<Option<Memo<i32>>> = const { Cell::new(None) };
+        static RUNS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn app() -> Element {
+        let tracked = use_signal(|| 1);
+        let config = use_signal(|| 10);
+        SIGNALS.with(|cell| cell.set(Some((tracked, config))));
+        let sum = use_memo(move || {
+            RUNS.with(|runs| runs.set(runs.get() + 1));
+            tracked() + untracked(|| *config.read())
+        });
+        SUM.with(|cell| cell.set(Some(sum)));
+        rsx! { "{sum}" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let (mut tracked, mut config) = SIGNALS.with(|cell| cell.get()).unwrap();
+    let sum = SUM.with(|cell| cell.get()).unwrap();
+    assert_eq!(RUNS.with(|runs| runs.get()), 1);
+
+    // Writing to the untracked signal doesn't rerun the memo
+    dom.in_runtime(|| config.set(20));
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(RUNS.with(|runs| runs.get()), 1);
+    assert_eq!(dom.in_runtime(|| *sum.read()), 11);
+
+    // Writing to the tracked signal does, and picks up the latest value of the untracked signal
+    dom.in_runtime(|| tracked.set(2));
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(RUNS.with(|runs| runs.get()), 2);
+    assert_eq!(dom.in_runtime(|| *sum.read()), 22);
+}
+
+#[test]
+fn the_reactive_context_is_restored_after_a_panic() {
+    let dom = VirtualDom::new(|| rsx! {});
+    dom.in_runtime(|| {
+        ScopeId::ROOT.in_runtime(|| {
+            let (context, _rx) = ReactiveContext::new();
+            context.run_in(|| {
+                let result = std::panic::catch_unwind(|| untracked(|| panic!("untracked panic")));
+                assert!(result.is_err());
+                assert!(ReactiveContext::current() == Some(context));
+            });
+        })
+    });
+}
@@ -37,6 +37,8 @@ use crate::renderer::{str_truthy, BOOL_ATTRS};
 #[derive(Debug)]
 pub(crate) struct StringCache {
     pub segments: Vec<Segment>,
+    /// The content addressed id of the template this cache was created from
+    pub stable_id: u64,
 }
 
 #[derive(Default)]
@@ -132,6 +134,7 @@ impl StringCache {
 
         Ok(Self {
             segments: chain.segments,
+            stable_id: template.template.stable_id(),
         })
     }
 }
@@ -1 +1,55 @@
+//! Configuration for the [`Renderer`](crate::Renderer)
 
+/// How the renderer formats the HTML it writes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputMode {
+    /// Write the HTML exactly as the templates produce it. This is the fastest mode.
+    #[default]
+    Compact,
+
+    /// Collapse runs of whitespace in text into a single space, drop the quotes around attribute values that don't
+    /// need them and the trailing slash of void elements like `<br/>`.
+    ///
+    /// The contents of `pre`, `textarea`, `script`, `style` and `title` elements are written as is.
+    Minified,
+
+    /// Write every element, text and comment on its own line, indented by its depth. Elements that are empty or only
+    /// contain text stay on one line.
+    ///
+    /// The output only depends on the structure of the page, which makes it useful for snapshot tests and diffs.
+    /// Whitespace around the lines is added and whitespace in text is collapsed, so the page may render differently
+    /// than the compact output.
+    Pretty,
+}
+
+/// Configuration for a [`Renderer`](crate::Renderer)
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// use dioxus_ssr::config::{OutputMode, RendererConfig};
+///
+/// let mut renderer = dioxus_ssr::Renderer::with_config(RendererConfig {
+///     output: OutputMode::Pretty,
+///     ..Default::default()
+/// });
+/// let html = renderer.render_element(rsx! {
+///     ul {
+///         li { "one" }
+///         li { "two" }
+///     }
+/// });
+/// assert_eq!(html, "<ul>\n  <li>one</li>\n  <li>two</li>\n</ul>");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RendererConfig {
+    /// Write ElementIDs into elements so the page can be hydrated later on
+    pub pre_render: bool,
+
+    /// Audit every VirtualDom the renderer renders for accessibility problems and log them as warnings. The audit
+    /// only runs in debug builds and requires the `a11y` feature.
+    #[cfg(feature = "a11y")]
+    pub a11y_audit: bool,
+
+    /// How the HTML is formatted
+    pub output: OutputMode,
+}
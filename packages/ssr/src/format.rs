@@ -0,0 +1,330 @@
+//! Reformat rendered HTML for the [`OutputMode`]s of the renderer
+
+use crate::config::OutputMode;
+
+/// Elements whose contents are written as is
+const RAW_TEXT_ELEMENTS: &[&str] = &["pre", "textarea", "script", "style", "title"];
+
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+#[derive(Debug, PartialEq)]
+enum Token<'a> {
+    Start {
+        name: &'a str,
+        attributes: Vec<(&'a str, Option<&'a str>)>,
+        self_closing: bool,
+    },
+    End(&'a str),
+    Text(&'a str),
+    /// The contents of a raw text element
+    Raw(&'a str),
+    /// A comment including the `<!--` and `-->`
+    Comment(&'a str),
+}
+
+fn is_space(byte: u8) -> bool {
+    byte.is_ascii_whitespace()
+}
+
+/// Parse the start tag at the beginning of the html and return it with its length
+fn parse_start(html: &str) -> (Token<'_>, usize) {
+    let bytes = html.as_bytes();
+    let mut i = 1;
+    while i < bytes.len() && !matches!(bytes[i], b'>' | b'/') && !is_space(bytes[i]) {
+        i += 1;
+    }
+    let name = &html[1..i];
+
+    let mut attributes = Vec::new();
+    let mut self_closing = false;
+    while i < bytes.len() {
+        while i < bytes.len() && is_space(bytes[i]) {
+            i += 1;
+        }
+        if html[i..].starts_with("/>") {
+            self_closing = true;
+            i += 2;
+            break;
+        }
+        if i >= bytes.len() || bytes[i] == b'>' {
+            i += 1;
+            break;
+        }
+
+        let name_start = i;
+        while i < bytes.len() && !matches!(bytes[i], b'=' | b'>' | b'/') && !is_space(bytes[i]) {
+            i += 1;
+        }
+        let attribute = &html[name_start..i];
+        if attribute.is_empty() {
+            // A stray character like a `/` that doesn't end the tag
+            i += 1;
+            continue;
+        }
+
+        let mut value = None;
+        if bytes.get(i) == Some(&b'=') {
+            i += 1;
+            match bytes.get(i) {
+                Some(&quote @ (b'"' | b'\'')) => {
+                    let end = html[i + 1..]
+                        .find(quote as char)
+                        .map_or(bytes.len(), |end| i + 1 + end);
+                    value = Some(&html[i + 1..end]);
+                    i = (end + 1).min(bytes.len());
+                }
+                _ => {
+                    let start = i;
+                    while i < bytes.len() && bytes[i] != b'>' && !is_space(bytes[i]) {
+                        i += 1;
+                    }
+                    value = Some(&html[start..i]);
+                }
+            }
+        }
+        attributes.push((attribute, value));
+    }
+
+    let token = Token::Start {
+        name,
+        attributes,
+        self_closing,
+    };
+    (token, i.min(bytes.len()))
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            let end = comment.find("-->").map_or(comment.len(), |end| end + 3);
+            tokens.push(Token::Comment(&rest[..4 + end]));
+            rest = &comment[end..];
+        } else if let Some(tag) = rest.strip_prefix("</") {
+            let end = tag.find('>').unwrap_or(tag.len());
+            tokens.push(Token::End(tag[..end].trim()));
+            rest = tag.get(end + 1..).unwrap_or_default();
+        } else if rest.starts_with('<') && rest[1..].starts_with(|c: char| c.is_ascii_alphabetic())
+        {
+            let (start, len) = parse_start(rest);
+            rest = &rest[len..];
+            let raw = match &start {
+                Token::Start {
+                    name,
+                    self_closing: false,
+                    ..
+                } if RAW_TEXT_ELEMENTS.contains(name) => Some(*name),
+                _ => None,
+            };
+            tokens.push(start);
+            if let Some(name) = raw {
+                let end = rest.find(&format!("</{name}")).unwrap_or(rest.len());
+                tokens.push(Token::Raw(&rest[..end]));
+                rest = &rest[end..];
+            }
+        } else {
+            let first = rest.chars().next().map_or(1, char::len_utf8);
+            let end = rest[first..]
+                .find('<')
+                .map_or(rest.len(), |end| end + first);
+            tokens.push(Token::Text(&rest[..end]));
+            rest = &rest[end..];
+        }
+    }
+    tokens
+}
+
+/// Replace every run of whitespace with a single space
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    let mut in_space = false;
+    for c in text.chars() {
+        if c.is_ascii_whitespace() {
+            if !in_space {
+                collapsed.push(' ');
+            }
+            in_space = true;
+        } else {
+            collapsed.push(c);
+            in_space = false;
+        }
+    }
+    collapsed
+}
+
+fn needs_quotes(value: &str) -> bool {
+    value.is_empty()
+        || value
+            .chars()
+            .any(|c| c.is_ascii_whitespace() || matches!(c, '"' | '\'' | '=' | '<' | '>' | '`'))
+}
+
+fn write_start(out: &mut String, token: &Token, mode: OutputMode) {
+    let Token::Start {
+        name,
+        attributes,
+        self_closing,
+    } = token
+    else {
+        return;
+    };
+    out.push('<');
+    out.push_str(name);
+    for (attribute, value) in attributes {
+        out.push(' ');
+        out.push_str(attribute);
+        match value {
+            Some(value) if mode == OutputMode::Minified && !needs_quotes(value) => {
+                out.push('=');
+                out.push_str(value);
+            }
+            Some(value) => {
+                out.push_str("=\"");
+                out.push_str(value);
+                out.push('"');
+            }
+            None => {}
+        }
+    }
+    if *self_closing && !(mode == OutputMode::Minified && VOID_ELEMENTS.contains(name)) {
+        out.push_str("/>");
+    } else {
+        out.push('>');
+    }
+}
+
+fn minify(tokens: &[Token]) -> String {
+    let mut out = String::new();
+    for token in tokens {
+        match token {
+            Token::Start { .. } => write_start(&mut out, token, OutputMode::Minified),
+            Token::End(name) => {
+                out.push_str("</");
+                out.push_str(name);
+                out.push('>');
+            }
+            Token::Text(text) => out.push_str(&collapse_whitespace(text)),
+            Token::Raw(text) | Token::Comment(text) => out.push_str(text),
+        }
+    }
+    out
+}
+
+fn new_line(out: &mut String, depth: usize) {
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    for _ in 0..depth {
+        out.push_str("  ");
+    }
+}
+
+fn pretty(tokens: &[Token]) -> String {
+    let mut out = String::new();
+    let mut depth = 0;
+    let mut index = 0;
+    while index < tokens.len() {
+        let token = &tokens[index];
+        index += 1;
+        match token {
+            Token::Start {
+                name, self_closing, ..
+            } => {
+                new_line(&mut out, depth);
+                write_start(&mut out, token, OutputMode::Pretty);
+                if *self_closing || VOID_ELEMENTS.contains(name) {
+                    continue;
+                }
+
+                // Keep elements that are empty or only contain text on one line
+                let contents = match (tokens.get(index), tokens.get(index + 1)) {
+                    (Some(Token::End(_)), _) => Some(String::new()),
+                    (Some(Token::Text(text)), Some(Token::End(_))) => {
+                        Some(collapse_whitespace(text).trim().to_string())
+                    }
+                    (Some(Token::Raw(text)), Some(Token::End(_))) => Some(text.to_string()),
+                    _ => None,
+                };
+                match contents {
+                    Some(contents) => {
+                        out.push_str(&contents);
+                        if let Some(Token::Text(_) | Token::Raw(_)) = tokens.get(index) {
+                            index += 1;
+                        }
+                        if let Some(Token::End(end)) = tokens.get(index) {
+                            out.push_str("</");
+                            out.push_str(end);
+                            out.push('>');
+                            index += 1;
+                        }
+                    }
+                    None => depth += 1,
+                }
+            }
+            Token::End(name) => {
+                depth = depth.saturating_sub(1);
+                new_line(&mut out, depth);
+                out.push_str("</");
+                out.push_str(name);
+                out.push('>');
+            }
+            Token::Text(text) => {
+                let text = collapse_whitespace(text);
+                let text = text.trim();
+                if !text.is_empty() {
+                    new_line(&mut out, depth);
+                    out.push_str(text);
+                }
+            }
+            Token::Raw(text) | Token::Comment(text) => {
+                new_line(&mut out, depth);
+                out.push_str(text);
+            }
+        }
+    }
+    out
+}
+
+/// Format rendered HTML for an output mode
+pub(crate) fn format(html: &str, mode: OutputMode) -> String {
+    match mode {
+        OutputMode::Compact => html.to_string(),
+        OutputMode::Minified => minify(&tokenize(html)),
+        OutputMode::Pretty => pretty(&tokenize(html)),
+    }
+}
+
+#[test]
+fn tokenizes_rendered_html() {
+    assert_eq!(
+        tokenize(
+            r#"<div class="a b" hidden=true><br/>text<!--#--><script>if (a < b) {}</script></div>"#
+        ),
+        vec![
+            Token::Start {
+                name: "div",
+                attributes: vec![("class", Some("a b")), ("hidden", Some("true"))],
+                self_closing: false,
+            },
+            Token::Start {
+                name: "br",
+                attributes: vec![],
+                self_closing: true,
+            },
+            Token::Text("text"),
+            Token::Comment("<!--#-->"),
+            Token::Start {
+                name: "script",
+                attributes: vec![],
+                self_closing: false,
+            },
+            Token::Raw("if (a < b) {}"),
+            Token::End("script"),
+            Token::End("div"),
+        ]
+    );
+}
@@ -4,10 +4,13 @@
 
 mod cache;
 pub mod config;
+mod format;
 pub mod renderer;
+mod stream;
 pub mod template;
 
-use dioxus_core::{Element, VirtualDom};
+use dioxus_core::{ComponentFunction, Element, VirtualDom};
+use futures_util::Stream;
 
 pub use crate::renderer::Renderer;
 
@@ -18,6 +21,17 @@ pub fn render_element(element: Element) -> String {
     Renderer::new().render_element(element)
 }
 
+/// A convenience function to render a component with the given props to a string
+///
+/// The component is rendered in a fresh VirtualDom, so this is safe to call from inside a running app (e.g. to
+/// build an email body or "copy as HTML").
+pub fn render_component<P: Clone + 'static, M: 'static>(
+    component: impl ComponentFunction<P, M>,
+    props: P,
+) -> String {
+    Renderer::new().render_component(component, props)
+}
+
 /// A convenience function to render an existing VirtualDom to a string
 ///
 /// We generally recommend creating a new `Renderer` to take advantage of template caching.
@@ -33,3 +47,11 @@ pub fn pre_render(dom: &VirtualDom) -> String {
     renderer.pre_render = true;
     renderer.render(dom)
 }
+
+/// A convenience function to render an existing VirtualDom as a stream of HTML chunks. Suspense boundaries are
+/// streamed in order as they resolve.
+///
+/// See [`Renderer::render_to_stream`] for more details.
+pub fn render_to_stream(dom: VirtualDom) -> impl Stream<Item = String> {
+    Renderer::new().render_to_stream(dom)
+}
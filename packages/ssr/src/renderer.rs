@@ -1,5 +1,6 @@
 use super::cache::Segment;
 use crate::cache::StringCache;
+use crate::config::{OutputMode, RendererConfig};
 
 use dioxus_core::{prelude::*, AttributeValue, DynamicNode};
 use rustc_hash::FxHashMap;
@@ -16,6 +17,19 @@ pub struct Renderer {
     /// Choose to write ElementIDs into elements so the page can be re-hydrated later on
     pub pre_render: bool,
 
+    /// Audit every VirtualDom the renderer renders for accessibility problems and log them as warnings. The audit
+    /// only runs in debug builds and requires the `a11y` feature.
+    #[cfg(feature = "a11y")]
+    pub a11y_audit: bool,
+
+    /// How the HTML is formatted. Only the HTML of whole VirtualDoms is formatted, so [`Renderer::render_scope`] and
+    /// [`Renderer::render_to_stream`] always write compact HTML.
+    pub output: OutputMode,
+
+    /// The issues the accessibility audit already reported
+    #[cfg(all(feature = "a11y", debug_assertions))]
+    a11y_auditor: dioxus_html::a11y::A11yAuditor,
+
     /// A callback used to render components. You can set this callback to control what components are rendered and add wrappers around components that are not present in CSR
     render_components: Option<ComponentRenderCallback>,
 
@@ -24,6 +38,9 @@ pub struct Renderer {
 
     /// The current dynamic node id for hydration
     dynamic_node_id: usize,
+
+    /// A fingerprint of every template rendered since the hydration state was reset
+    hydration_fingerprint: u64,
 }
 
 impl Renderer {
@@ -31,6 +48,17 @@ impl Renderer {
         Self::default()
     }
 
+    /// Create a new renderer with a configuration
+    pub fn with_config(config: RendererConfig) -> Self {
+        Self {
+            pre_render: config.pre_render,
+            #[cfg(feature = "a11y")]
+            a11y_audit: config.a11y_audit,
+            output: config.output,
+            ..Self::default()
+        }
+    }
+
     /// Set the callback that the renderer uses to render components
     pub fn set_render_components(
         &mut self,
@@ -58,8 +86,19 @@ impl Renderer {
         buf: &mut W,
         dom: &VirtualDom,
     ) -> std::fmt::Result {
+        #[cfg(all(feature = "a11y", debug_assertions))]
+        if self.a11y_audit {
+            self.a11y_auditor.report(dom);
+        }
         self.reset_hydration();
-        self.render_scope(buf, dom, ScopeId::ROOT)
+        match self.output {
+            OutputMode::Compact => self.render_scope(buf, dom, ScopeId::ROOT),
+            mode => {
+                let mut html = String::new();
+                self.render_scope(&mut html, dom, ScopeId::ROOT)?;
+                buf.write_str(&crate::format::format(&html, mode))
+            }
+        }
     }
 
     /// Render an element to a string
@@ -78,7 +117,32 @@ impl Renderer {
         fn lazy_app(props: Element) -> Element {
             props
         }
-        let mut dom = VirtualDom::new_with_props(lazy_app, element);
+        self.render_component_to(buf, lazy_app, element)
+    }
+
+    /// Render a component with the given props to a string
+    ///
+    /// The component is rendered in its own VirtualDom, so this can be called from inside a running app or a
+    /// server handler without touching the main VirtualDom.
+    pub fn render_component<P: Clone + 'static, M: 'static>(
+        &mut self,
+        component: impl ComponentFunction<P, M>,
+        props: P,
+    ) -> String {
+        let mut buf = String::new();
+        self.render_component_to(&mut buf, component, props)
+            .unwrap();
+        buf
+    }
+
+    /// Render a component with the given props to the buffer
+    pub fn render_component_to<W: Write + ?Sized, P: Clone + 'static, M: 'static>(
+        &mut self,
+        buf: &mut W,
+        component: impl ComponentFunction<P, M>,
+        props: P,
+    ) -> std::fmt::Result {
+        let mut dom = VirtualDom::new_with_props(component, props);
         dom.rebuild_in_place();
         self.render_to(buf, &dom)
     }
@@ -86,6 +150,16 @@ impl Renderer {
     /// Reset the renderer hydration state
     pub fn reset_hydration(&mut self) {
         self.dynamic_node_id = 0;
+        self.hydration_fingerprint = 0;
+    }
+
+    /// Get a fingerprint of the templates rendered since the hydration state was last reset.
+    ///
+    /// The fingerprint is built from the content addressed [`Template::stable_id`] of each template in the order they were
+    /// rendered, so a client built from the same `rsx!` calls will compute the same fingerprint while hydrating, even if the
+    /// server and client binaries were built separately. This is only tracked while pre-rendering.
+    pub fn hydration_fingerprint(&self) -> u64 {
+        self.hydration_fingerprint
     }
 
     pub fn render_scope<W: Write + ?Sized>(
@@ -112,6 +186,12 @@ impl Renderer {
             .or_insert_with(move || Arc::new(StringCache::from_template(template).unwrap()))
             .clone();
 
+        if self.pre_render {
+            // This must stay in sync with the fingerprint dioxus-web computes while hydrating
+            self.hydration_fingerprint =
+                self.hydration_fingerprint.rotate_left(5) ^ entry.stable_id;
+        }
+
         let mut inner_html = None;
 
         // We need to keep track of the dynamic styles so we can insert them into the right place
@@ -139,7 +219,10 @@ impl Renderer {
                         if attr.name == "dangerous_inner_html" {
                             inner_html = Some(attr);
                         } else if attr.namespace == Some("style") {
-                            accumulated_dynamic_styles.push(attr);
+                            // Optional styles that are None are omitted entirely
+                            if !matches!(attr.value, AttributeValue::None) {
+                                accumulated_dynamic_styles.push(attr);
+                            }
                         } else if BOOL_ATTRS.contains(&attr.name) {
                             if truthy(&attr.value) {
                                 write_attribute(buf, attr)?;
@@ -152,7 +235,8 @@ impl Renderer {
                             if let AttributeValue::Listener(_) = &attr.value {
                                 // The onmounted event doesn't need a DOM listener
                                 if attr.name != "onmounted" {
-                                    accumulated_listeners.push(attr.name);
+                                    accumulated_listeners
+                                        .push((attr.name, attr.listener_options()));
                                 }
                             }
                         }
@@ -241,12 +325,12 @@ impl Renderer {
                     write!(buf, "{}", self.dynamic_node_id)?;
                     self.dynamic_node_id += 1;
                     // then write any listeners
-                    for name in accumulated_listeners.drain(..) {
+                    for (name, options) in accumulated_listeners.drain(..) {
                         write!(buf, ",{}:", &name[2..])?;
                         write!(
                             buf,
                             "{}",
-                            dioxus_core_types::event_bubbles(&name[2..]) as u8
+                            dioxus_core_types::listener_flags(&name[2..], options.passive)
                         )?;
                     }
                 }
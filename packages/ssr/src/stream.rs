@@ -0,0 +1,156 @@
+//! Render a VirtualDom as a stream of HTML chunks.
+//!
+//! The stream renders everything up to the first suspense boundary that is still loading and yields it. Once the
+//! consumer asks for more, it waits for that boundary to resolve, renders it in place and continues with the rest of
+//! the page. Suspense boundaries are streamed in order, so the page works without javascript and the fallbacks are
+//! never sent.
+
+use crate::Renderer;
+use dioxus_core::prelude::*;
+use futures_util::Stream;
+use std::collections::VecDeque;
+use std::fmt::Write;
+use std::sync::{Arc, Mutex};
+
+/// A piece of the page in the order it is sent
+enum Chunk {
+    Html(String),
+    /// A suspense boundary that was loading when the html around it was rendered
+    Suspended(ScopeId),
+}
+
+/// Collects the html the renderer writes and splits it at suspended boundaries
+#[derive(Default)]
+struct Chunks {
+    chunks: Vec<Chunk>,
+    html: String,
+}
+
+impl Chunks {
+    fn flush(&mut self) {
+        if !self.html.is_empty() {
+            self.chunks
+                .push(Chunk::Html(std::mem::take(&mut self.html)));
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct ChunkWriter(Arc<Mutex<Chunks>>);
+
+impl Write for ChunkWriter {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.0.lock().unwrap().html.push_str(s);
+        Ok(())
+    }
+}
+
+impl ChunkWriter {
+    /// Render a scope and return the chunks it was split into
+    fn render(&self, renderer: &mut Renderer, dom: &VirtualDom, scope: ScopeId) -> Vec<Chunk> {
+        // Writing to a string can't fail
+        _ = renderer.render_scope(&mut self.clone(), dom, scope);
+        let mut chunks = self.0.lock().unwrap();
+        chunks.flush();
+        std::mem::take(&mut chunks.chunks)
+    }
+}
+
+fn is_suspended(dom: &VirtualDom, scope: ScopeId) -> bool {
+    SuspenseContext::downcast_suspense_boundary_from_scope(&dom.runtime(), scope)
+        .is_some_and(|suspense| suspense.has_suspended_tasks())
+}
+
+struct StreamState {
+    dom: VirtualDom,
+    renderer: Renderer,
+    writer: ChunkWriter,
+    pending: VecDeque<Chunk>,
+}
+
+impl StreamState {
+    async fn next(&mut self) -> Option<String> {
+        loop {
+            match self.pending.pop_front()? {
+                Chunk::Html(html) => return Some(html),
+                Chunk::Suspended(scope) => {
+                    while is_suspended(&self.dom, scope) && self.dom.suspended_tasks_remaining() {
+                        self.dom.wait_for_suspense_work().await;
+                        self.dom.render_suspense_immediate().await;
+                    }
+                    // The boundary may have been removed while it was loading
+                    if self.dom.get_scope(scope).is_none() {
+                        continue;
+                    }
+                    let chunks = self.writer.render(&mut self.renderer, &self.dom, scope);
+                    for chunk in chunks.into_iter().rev() {
+                        self.pending.push_front(chunk);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Renderer {
+    /// Render a VirtualDom as a stream of HTML chunks that can be sent before the whole page is rendered.
+    ///
+    /// The VirtualDom must already be rebuilt. The first chunk contains everything up to the first suspense boundary
+    /// that is still loading. The stream only renders and waits for suspense when it is polled, so a slow consumer
+    /// is never buffered against.
+    ///
+    /// The stream owns the VirtualDom, which is not `Send`. To use it as the body of a multithreaded server, poll it
+    /// on a local task and forward the chunks through a channel.
+    ///
+    /// The renderer uses its own component render callback while streaming, so any callback set with
+    /// [`Renderer::set_render_components`] is ignored.
+    ///
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// # use futures_util::StreamExt;
+    /// fn app() -> Element {
+    ///     rsx! { div { "hello world!" } }
+    /// }
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let mut dom = VirtualDom::new(app);
+    /// dom.rebuild_in_place();
+    /// let html = dioxus_ssr::Renderer::new()
+    ///     .render_to_stream(dom)
+    ///     .collect::<String>()
+    ///     .await;
+    /// assert_eq!(html, "<div>hello world!</div>");
+    /// # }
+    /// ```
+    pub fn render_to_stream(mut self, dom: VirtualDom) -> impl Stream<Item = String> {
+        let writer = ChunkWriter::default();
+        {
+            let writer = writer.clone();
+            self.set_render_components(move |renderer, to, dom, scope| {
+                if is_suspended(dom, scope) {
+                    let mut chunks = writer.0.lock().unwrap();
+                    chunks.flush();
+                    chunks.chunks.push(Chunk::Suspended(scope));
+                    Ok(())
+                } else {
+                    renderer.render_scope(to, dom, scope)
+                }
+            });
+        }
+
+        self.reset_hydration();
+        let pending = writer.render(&mut self, &dom, ScopeId::ROOT).into();
+        let state = StreamState {
+            dom,
+            renderer: self,
+            writer,
+            pending,
+        };
+
+        futures_util::stream::unfold(state, |mut state| async move {
+            let html = state.next().await?;
+            Some((html, state))
+        })
+    }
+}
@@ -195,3 +195,38 @@ fn hello_world_hydrates() {
         r#"<h1 data-node-hydration="0"><!--node-id1-->High-Five counter: 0<!--#--></h1><button data-node-hydration="2,click:1">Up high!</button><button data-node-hydration="3,click:1">Down low!</button>"#
     );
 }
+
+#[test]
+fn hydration_fingerprint() {
+    fn app() -> Element {
+        rsx! {
+            div {
+                for i in 0..3 {
+                    Child { i }
+                }
+            }
+        }
+    }
+
+    #[component]
+    fn Child(i: i32) -> Element {
+        rsx! { p { "{i}" } }
+    }
+
+    fn other_app() -> Element {
+        rsx! { div { "hello" } }
+    }
+
+    let fingerprint = |app: fn() -> Element| {
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild(&mut dioxus_core::NoOpMutations);
+        let mut renderer = dioxus_ssr::Renderer::new();
+        renderer.pre_render = true;
+        renderer.render(&dom);
+        renderer.hydration_fingerprint()
+    };
+
+    assert_ne!(fingerprint(app), 0);
+    assert_eq!(fingerprint(app), fingerprint(app));
+    assert_ne!(fingerprint(app), fingerprint(other_app));
+}
@@ -0,0 +1,62 @@
+use dioxus::prelude::*;
+use dioxus_ssr::config::{OutputMode, RendererConfig};
+use dioxus_ssr::Renderer;
+
+fn renderer(output: OutputMode) -> Renderer {
+    Renderer::with_config(RendererConfig {
+        output,
+        ..Default::default()
+    })
+}
+
+#[test]
+fn minified_output() {
+    let html = renderer(OutputMode::Minified).render_element(rsx! {
+        div { class: "card", id: "main",
+            p { "  hello   world  " }
+            input { r#type: "text", value: "" }
+            pre { "  keep  " }
+        }
+    });
+    assert_eq!(
+        html,
+        r#"<div class=card id=main><p> hello world </p><input type=text value=""><pre>  keep  </pre></div>"#
+    );
+}
+
+#[test]
+fn pretty_output() {
+    let html = renderer(OutputMode::Pretty).render_element(rsx! {
+        div { class: "card",
+            h1 { "Title" }
+            ul {
+                li { "one" }
+                li {
+                    b { "two" }
+                    " items"
+                }
+            }
+            br {}
+        }
+    });
+    assert_eq!(
+        html,
+        r#"<div class="card">
+  <h1>Title</h1>
+  <ul>
+    <li>one</li>
+    <li>
+      <b>two</b>
+      items
+    </li>
+  </ul>
+  <br/>
+</div>"#
+    );
+}
+
+#[test]
+fn compact_output_is_unchanged() {
+    let html = renderer(OutputMode::Compact).render_element(rsx! { div { "  spaced  " } });
+    assert_eq!(html, "<div>  spaced  </div>");
+}
@@ -84,3 +84,109 @@ fn fragments() {
         "<div></div>"
     );
 }
+
+#[test]
+fn render_component_with_props() {
+    #[derive(Props, Clone, PartialEq)]
+    struct GreetingProps {
+        name: String,
+    }
+
+    fn Greeting(GreetingProps { name }: GreetingProps) -> Element {
+        rsx! { p { "Hello {name}" } }
+    }
+
+    assert_eq!(
+        dioxus_ssr::render_component(
+            Greeting,
+            GreetingProps {
+                name: "world".to_string()
+            }
+        ),
+        "<p>Hello world</p>"
+    );
+}
+
+#[test]
+fn render_component_inside_running_app() {
+    fn Email() -> Element {
+        let count = use_signal(|| 3);
+        rsx! { span { "You have {count} new messages" } }
+    }
+
+    fn App() -> Element {
+        let html = use_hook(|| dioxus_ssr::render_component(Email, ()));
+        rsx! { pre { "{html}" } }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        "<pre>&lt;span&gt;You have 3 new messages&lt;/span&gt;</pre>"
+    );
+}
+
+#[test]
+fn conditional_attributes() {
+    fn render(active: bool) -> String {
+        let count = 3;
+        dioxus_ssr::render_element(rsx! {
+            button {
+                disabled: if active { true },
+                class: if active { "on" },
+                "data-count": if active { "{count}" },
+                "hello"
+            }
+            div { class: "btn", class: if active { "on" } else if count > 5 { "big" } }
+        })
+    }
+
+    assert_eq!(
+        render(true),
+        "<button disabled=true class=\"on\" data-count=\"3\">hello</button><div class=\"btn on\"></div>"
+    );
+    assert_eq!(
+        render(false),
+        "<button>hello</button><div class=\"btn\"></div>"
+    );
+}
+
+#[test]
+fn optional_attributes() {
+    let title: Option<String> = None;
+    let id = Some("main");
+    let tabindex: Option<i64> = Some(1);
+
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            div { title, id, tabindex }
+        }),
+        r#"<div id="main" tabindex=1></div>"#
+    );
+}
+
+#[test]
+fn class_lists() {
+    let is_active = true;
+    let enabled = true;
+
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            div {
+                class: "base",
+                class: { "active": is_active, "disabled": !enabled },
+                class: { "static": true, "never": false }
+            }
+        }),
+        r#"<div class="base active static"></div>"#
+    );
+
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            div { class: { "active": !is_active, "disabled": !enabled } }
+        }),
+        r#"<div class=""></div>"#
+    );
+}
@@ -0,0 +1,42 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+
+#[component]
+fn Card(
+    #[props(slot)] header: Element,
+    #[props(slot)] footer: Element,
+    children: Element,
+) -> Element {
+    rsx! {
+        div {
+            header { {header} }
+            section { {children} }
+            footer { {footer} }
+        }
+    }
+}
+
+#[test]
+fn fill_named_slots() {
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            Card {
+                header: rsx! { h1 { "Title" } },
+                footer: rsx! { "Footer" },
+                "Body"
+            }
+        }),
+        "<div><header><h1>Title</h1></header><section>Body</section><footer>Footer</footer></div>"
+    );
+}
+
+#[test]
+fn unfilled_slots_render_nothing() {
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            Card { footer: rsx! { "Footer" } }
+        }),
+        "<div><header></header><section></section><footer>Footer</footer></div>"
+    );
+}
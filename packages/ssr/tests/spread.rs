@@ -0,0 +1,76 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+
+#[test]
+fn spread_attributes_on_element() {
+    fn app() -> Element {
+        let attributes = vec![
+            Attribute::new("id", "spread", None, false),
+            Attribute::new("class", "a", None, false),
+        ];
+        rsx! { div { title: "static", ..attributes } }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        r#"<div title="static" id="spread" class="a"></div>"#
+    );
+}
+
+#[test]
+fn forward_attributes_through_component() {
+    #[derive(Props, PartialEq, Clone)]
+    struct WrapperProps {
+        #[props(extends = GlobalAttributes, extends = a)]
+        attributes: Vec<Attribute>,
+        children: Element,
+    }
+
+    fn Wrapper(props: WrapperProps) -> Element {
+        rsx! { a { ..props.attributes, {props.children} } }
+    }
+
+    fn app() -> Element {
+        rsx! {
+            Wrapper { href: "/home", class: "link", "Home" }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        r#"<a href="/home" class="link">Home</a>"#
+    );
+}
+
+#[test]
+fn spread_manual_props_into_component() {
+    #[derive(Props, PartialEq, Clone)]
+    struct CounterProps {
+        count: i32,
+        label: String,
+    }
+
+    fn Counter(props: CounterProps) -> Element {
+        rsx! { "{props.label}: {props.count}" }
+    }
+
+    fn app() -> Element {
+        let base = CounterProps {
+            count: 1,
+            label: "count".to_string(),
+        };
+        rsx! { Counter { count: 2, ..base } }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    assert_eq!(dioxus_ssr::render(&dom), "count: 2");
+}
@@ -0,0 +1,53 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use futures_util::StreamExt;
+use std::time::Duration;
+
+#[component]
+fn Slow(name: String, delay: u64) -> Element {
+    let name = use_resource(move || {
+        let name = name.clone();
+        async move {
+            tokio::time::sleep(Duration::from_millis(delay)).await;
+            name
+        }
+    })
+    .suspend()?;
+    rsx! { "{name}" }
+}
+
+#[tokio::test]
+async fn suspense_boundaries_are_streamed_in_order() {
+    fn app() -> Element {
+        rsx! {
+            "header "
+            SuspenseBoundary { fallback: |_| rsx! { "loading" },
+                Slow { name: "slow ", delay: 50 }
+            }
+            SuspenseBoundary { fallback: |_| rsx! { "loading" },
+                Slow { name: "fast ", delay: 10 }
+            }
+            "footer"
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let chunks = dioxus_ssr::render_to_stream(dom).collect::<Vec<_>>().await;
+
+    assert_eq!(chunks, ["header ", "slow ", "fast ", "footer"]);
+}
+
+#[tokio::test]
+async fn pages_without_suspense_are_a_single_chunk() {
+    fn app() -> Element {
+        rsx! { div { "hello" } }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let chunks = dioxus_ssr::render_to_stream(dom).collect::<Vec<_>>().await;
+
+    assert_eq!(chunks, ["<div>hello</div>"]);
+}
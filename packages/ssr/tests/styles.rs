@@ -38,3 +38,53 @@ fn dynamic_styles() {
         r#"<div style="width:123px;"></div>"#
     );
 }
+
+#[test]
+fn optional_styles() {
+    let width: Option<&str> = None;
+    let height = Some("10px");
+
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            div { width, height }
+        }),
+        r#"<div style="height:10px;"></div>"#
+    );
+
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            div { width }
+        }),
+        r#"<div></div>"#
+    );
+}
+
+#[test]
+fn inline_style_builder() {
+    let style = InlineStyle::new()
+        .width("10px")
+        .background_color("red")
+        .property("--accent", "blue");
+
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            div { ..style }
+        }),
+        r#"<div style="width:10px;background-color:red;--accent:blue;"></div>"#
+    );
+}
+
+#[test]
+fn css_custom_properties() {
+    const ACCENT: CssVar = CssVar::new("--accent");
+
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            div { ..css_var("--gap", "4px"),
+                p { color: ACCENT.var_or("black"), ..ACCENT.set("tomato") }
+            }
+        }),
+        r#"<div style="--gap:4px;"><p style="color:var(--accent, black);--accent:tomato;"></p></div>"#
+    );
+    assert_eq!(ACCENT.var(), "var(--accent)");
+}
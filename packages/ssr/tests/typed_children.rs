@@ -0,0 +1,54 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+
+#[component]
+fn Tab(title: String, children: Element) -> Element {
+    rsx! {
+        section { {children} }
+    }
+}
+
+#[component]
+fn Tabs(children: ChildrenOf<TabProps>) -> Element {
+    rsx! {
+        nav {
+            for tab in children.iter() {
+                button { "{tab.title}" }
+            }
+        }
+        {children}
+    }
+}
+
+#[test]
+fn typed_children_render() {
+    let titles = ["Two", "Three"];
+    let show_last = true;
+
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            Tabs {
+                Tab { title: "One", "First" }
+                for title in titles {
+                    Tab { key: "{title}", title, "{title}" }
+                }
+                if show_last {
+                    Tab { title: "Last", "Last" }
+                }
+            }
+        }),
+        "<nav><button>One</button><button>Two</button><button>Three</button><button>Last</button></nav>\
+        <section>First</section><section>Two</section><section>Three</section><section>Last</section>"
+    );
+}
+
+#[test]
+fn typed_children_default_to_empty() {
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            Tabs {}
+        }),
+        "<nav></nav>"
+    );
+}
@@ -132,6 +132,31 @@ impl Config {
         self
     }
 
+    /// Pre-render the given routes in addition to the static routes in the router. Routes with dynamic segments can't
+    /// be found automatically, so list the values they should be rendered with here:
+    ///
+    /// ```rust, no_run
+    /// # use dioxus::prelude::*;
+    /// # use dioxus_router::prelude::*;
+    /// # use dioxus_static_site_generation::Config;
+    /// #[derive(Clone, Routable, Debug, PartialEq)]
+    /// enum Route {
+    ///     #[route("/blog/:id")]
+    ///     BlogPost { id: usize },
+    /// }
+    /// # #[component]
+    /// # fn BlogPost(id: usize) -> Element { VNode::empty() }
+    ///
+    /// let config = Config::new().routes((1..=3).map(|id| Route::BlogPost { id }));
+    /// ```
+    #[allow(unused)]
+    pub fn routes<R: dioxus_router::prelude::Routable>(
+        self,
+        routes: impl IntoIterator<Item = R>,
+    ) -> Self {
+        self.additional_routes(routes.into_iter().map(|route| route.to_string()).collect())
+    }
+
     /// A preset for github pages. This will output your files in the `/docs` directory and set up a `404.html` file.
     pub fn github_pages(self) -> Self {
         #[allow(unused_mut)]
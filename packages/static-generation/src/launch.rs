@@ -86,3 +86,89 @@ pub fn launch(
         }
     }
 }
+
+/// Prerender the static routes of an app into the output directory without serving them.
+///
+/// This can be used to prerender a client side rendered app at build time. Run it from a native build step, then
+/// launch the web app with hydration enabled so the wasm resumes from the prerendered HTML instead of rendering
+/// from scratch:
+///
+/// ```rust, ignore
+/// fn main() {
+///     #[cfg(feature = "server")]
+///     tokio::runtime::Runtime::new()
+///         .unwrap()
+///         .block_on(dioxus_static_site_generation::launch::prerender(
+///             app,
+///             dioxus_static_site_generation::Config::new(),
+///         ))
+///         .unwrap();
+///
+///     #[cfg(feature = "web")]
+///     dioxus_web::launch::launch(app, vec![], dioxus_web::Config::new().hydrate(true));
+/// }
+/// ```
+#[cfg(feature = "server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "server")))]
+pub async fn prerender(
+    root: fn() -> Element,
+    platform_config: Config,
+) -> Result<(), dioxus_isrg::IncrementalRendererError> {
+    crate::ssg::generate_static_site(root, platform_config).await
+}
+
+#[cfg(feature = "server")]
+#[tokio::test(flavor = "multi_thread")]
+async fn prerender_writes_pages_the_client_can_resume_from() {
+    use dioxus::prelude::*;
+
+    #[derive(Clone, Routable, Debug, PartialEq)]
+    enum Route {
+        #[route("/")]
+        Home {},
+        #[route("/about")]
+        About {},
+    }
+
+    fn Home() -> Element {
+        rsx! { "Home" }
+    }
+
+    fn About() -> Element {
+        rsx! { "About" }
+    }
+
+    fn app() -> Element {
+        rsx! { Router::<Route> {} }
+    }
+
+    let dir = std::env::temp_dir().join(format!("dioxus-prerender-{}", std::process::id()));
+    let output_dir = dir.join("static");
+    // Files from the web build are copied next to the prerendered pages
+    let public_dir = dir.join("dist").join("public");
+    std::fs::create_dir_all(&public_dir).unwrap();
+    std::fs::write(public_dir.join("main.css"), "body {}").unwrap();
+    std::env::set_var(dioxus_cli_config::OUT_DIR, dir.join("dist"));
+
+    prerender(
+        app,
+        Config::new()
+            .index_html(r#"<html><head></head><body><div id="main"></div></body></html>"#.into())
+            .output_dir(output_dir.clone()),
+    )
+    .await
+    .unwrap();
+
+    for (path, content) in [("index.html", "Home"), ("about/index.html", "About")] {
+        let html = std::fs::read_to_string(output_dir.join(path)).unwrap();
+        assert!(html.contains(content));
+        // The web renderer only resumes from pages that contain the hydration data
+        assert!(html.contains("window.initial_dioxus_hydration_data"));
+    }
+    assert_eq!(
+        std::fs::read_to_string(output_dir.join("main.css")).unwrap(),
+        "body {}"
+    );
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
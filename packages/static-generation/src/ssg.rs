@@ -108,7 +108,7 @@ async fn prerender_route(
     use dioxus_fullstack::prelude::*;
 
     let context = server_context_for_route(&route);
-    let wrapper = config.fullstack_template();
+    let wrapper = config.fullstack_template().with_route(&route);
     let mut virtual_dom = VirtualDom::new(app);
     let document = std::rc::Rc::new(dioxus_fullstack::document::ServerDocument::default());
     virtual_dom.provide_root_context(document.clone() as std::rc::Rc<dyn Document>);
@@ -123,6 +123,7 @@ async fn prerender_route(
     wrapper.render_head(&mut wrapped, &virtual_dom)?;
 
     renderer.render_to(&mut wrapped, &virtual_dom)?;
+    wrapper.render_hydration_fingerprint(&mut wrapped, renderer)?;
 
     wrapper.render_after_main(&mut wrapped, &virtual_dom)?;
     wrapper.render_after_body(&mut wrapped)?;
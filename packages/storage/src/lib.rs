@@ -0,0 +1,16 @@
+#![doc = include_str!("../README.md")]
+#![doc(html_logo_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![doc(html_favicon_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![deny(missing_docs)]
+
+mod storage;
+pub use storage::*;
+
+mod persistent;
+pub use persistent::*;
+
+/// A collection of useful items most applications might need.
+pub mod prelude {
+    pub use crate::persistent::use_persistent_signal;
+    pub use crate::storage::{use_indexed_db, UseStore};
+}
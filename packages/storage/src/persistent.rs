@@ -0,0 +1,216 @@
+//! Signals that keep their value across restarts of the app.
+//!
+//! [`use_persistent_signal`] saves the value of a signal every time it changes and restores it the next time the app
+//! starts. On the web the value is stored in [`localStorage`](https://developer.mozilla.org/en-US/docs/Web/API/Window/localStorage)
+//! with the [`LocalStorageBackend`]. On other platforms it is stored in a JSON file in the config directory of the
+//! user with the [`FileBackend`]. Any other [`StorageBackend`] can be used with [`provide_persistence_backend`].
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::storage::FileBackend;
+use crate::storage::{run_script, StorageBackend, StorageFuture};
+use dioxus_core::prelude::*;
+use dioxus_hooks::use_effect;
+use dioxus_html::document::{document, Document};
+use dioxus_signals::{CopyValue, Readable, Signal, Writable};
+use futures_util::FutureExt;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+use std::rc::Rc;
+
+/// The store every persistent signal is saved in
+const STORE: &str = "signals";
+
+const LOCAL_STORAGE_SCRIPT: &str = r#"
+const { op, store, key, value } = await dioxus.recv();
+const prefix = "dioxus-" + store + "/";
+try {
+    switch (op) {
+        case "get": {
+            const found = localStorage.getItem(prefix + key);
+            return { ok: found === null ? null : [JSON.parse(found)] };
+        }
+        case "put":
+            localStorage.setItem(prefix + key, JSON.stringify(value));
+            return { ok: null };
+        case "delete":
+            localStorage.removeItem(prefix + key);
+            return { ok: null };
+        case "entries": {
+            const entries = [];
+            for (let i = 0; i < localStorage.length; i++) {
+                const name = localStorage.key(i);
+                if (name.startsWith(prefix)) {
+                    entries.push([name.slice(prefix.length), JSON.parse(localStorage.getItem(name))]);
+                }
+            }
+            return { ok: entries };
+        }
+    }
+} catch (error) {
+    return { error: String(error) };
+}
+"#;
+
+/// A [`StorageBackend`] that stores values in the `localStorage` of the browser or webview. Each value is stored as
+/// JSON under the key `dioxus-{store}/{key}`.
+#[derive(Clone)]
+pub struct LocalStorageBackend {
+    document: Rc<dyn Document>,
+}
+
+impl LocalStorageBackend {
+    /// Create a backend that uses the [`Document`] of the current renderer to access `localStorage`
+    pub fn new() -> Self {
+        Self {
+            document: document(),
+        }
+    }
+
+    fn run<T: DeserializeOwned + 'static>(&self, command: Value) -> StorageFuture<T> {
+        run_script(&self.document, LOCAL_STORAGE_SCRIPT, command)
+    }
+}
+
+impl Default for LocalStorageBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StorageBackend for LocalStorageBackend {
+    fn get(&self, store: &str, key: &str) -> StorageFuture<Option<Value>> {
+        let found =
+            self.run::<Option<(Value,)>>(json!({ "op": "get", "store": store, "key": key }));
+        Box::pin(async move { Ok(found.await?.map(|(value,)| value)) })
+    }
+
+    fn put(&self, store: &str, key: &str, value: Value) -> StorageFuture<()> {
+        self.run(json!({ "op": "put", "store": store, "key": key, "value": value }))
+    }
+
+    fn delete(&self, store: &str, key: &str) -> StorageFuture<()> {
+        self.run(json!({ "op": "delete", "store": store, "key": key }))
+    }
+
+    fn entries(&self, store: &str) -> StorageFuture<Vec<(String, Value)>> {
+        self.run(json!({ "op": "entries", "store": store }))
+    }
+}
+
+/// The backend persistent signals are saved in
+#[derive(Clone)]
+struct PersistenceBackend(Rc<dyn StorageBackend>);
+
+/// Use a different [`StorageBackend`] for every persistent signal in the app. Call this before creating any persistent
+/// signals, for example in a `use_hook` in the root component.
+pub fn provide_persistence_backend(backend: impl StorageBackend + 'static) {
+    provide_root_context(PersistenceBackend(Rc::new(backend)));
+}
+
+#[cfg(target_arch = "wasm32")]
+fn default_backend() -> Rc<dyn StorageBackend> {
+    Rc::new(LocalStorageBackend::new())
+}
+
+/// Store the values in `{config dir}/{executable name}/signals.json`
+#[cfg(not(target_arch = "wasm32"))]
+fn default_backend() -> Rc<dyn StorageBackend> {
+    let app = std::env::current_exe()
+        .ok()
+        .and_then(|exe| Some(exe.file_stem()?.to_string_lossy().into_owned()))
+        .unwrap_or_else(|| "dioxus".to_string());
+    let dir = dirs::config_dir().unwrap_or_else(std::env::temp_dir);
+    Rc::new(FileBackend::new(dir.join(app)))
+}
+
+/// Create a signal that is saved every time it changes and restored the next time the app starts. The signal starts
+/// with the saved value for `key`, or the value returned by `init` if nothing was saved yet.
+///
+/// Backends that read the saved value synchronously, like the [`FileBackend`], restore the value before the first
+/// render. Other backends, like the [`LocalStorageBackend`], restore the value shortly after the first render.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_storage::use_persistent_signal;
+///
+/// fn app() -> Element {
+///     let mut count = use_persistent_signal("count", || 0);
+///     rsx! {
+///         button { onclick: move |_| count += 1, "Clicked {count} times" }
+///     }
+/// }
+/// ```
+pub fn use_persistent_signal<T: Serialize + DeserializeOwned + 'static>(
+    key: impl Into<String>,
+    init: impl FnOnce() -> T,
+) -> Signal<T> {
+    let (signal, key, backend, restored) = use_hook(|| {
+        let key = key.into();
+        let backend = try_consume_context::<PersistenceBackend>()
+            .map(|backend| backend.0)
+            .unwrap_or_else(default_backend);
+        let mut load = backend.get(STORE, &key);
+        let (signal, restored) = match (&mut load).now_or_never() {
+            Some(saved) => {
+                let value = saved
+                    .ok()
+                    .flatten()
+                    .and_then(|value| decode(&key, value))
+                    .unwrap_or_else(init);
+                (Signal::new(value), CopyValue::new(true))
+            }
+            None => {
+                let mut signal = Signal::new(init());
+                let mut restored = CopyValue::new(false);
+                let key = key.clone();
+                spawn(async move {
+                    if let Ok(Some(value)) = load.await {
+                        if let Some(value) = decode(&key, value) {
+                            signal.set(value);
+                        }
+                    }
+                    restored.set(true);
+                });
+                (signal, restored)
+            }
+        };
+        (
+            signal,
+            CopyValue::new(key),
+            CopyValue::new(backend),
+            restored,
+        )
+    });
+
+    use_effect(move || {
+        let value = serde_json::to_value(&*signal.read());
+        // Don't overwrite the saved value with the initial value before it is restored
+        if !*restored.peek() {
+            return;
+        }
+        let value = match value {
+            Ok(value) => value,
+            Err(err) => {
+                tracing::error!(
+                    "Failed to serialize persistent signal {:?}: {err}",
+                    key.peek()
+                );
+                return;
+            }
+        };
+        let save = backend.peek().put(STORE, &key.peek(), value);
+        spawn(async move {
+            if let Err(err) = save.await {
+                tracing::error!("Failed to save persistent signal {:?}: {err}", key.peek());
+            }
+        });
+    });
+
+    signal
+}
+
+fn decode<T: DeserializeOwned>(key: &str, value: Value) -> Option<T> {
+    serde_json::from_value(value)
+        .inspect_err(|err| tracing::warn!("Ignoring the saved value of {key:?}: {err}"))
+        .ok()
+}
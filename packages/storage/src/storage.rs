@@ -0,0 +1,414 @@
+//! Store large amounts of client data for offline first apps.
+//!
+//! [`use_indexed_db`] opens a typed store of values. On the web and in webviews the values are stored in
+//! [IndexedDB](https://developer.mozilla.org/en-US/docs/Web/API/IndexedDB_API). Other platforms can store the values
+//! somewhere else by providing a different [`StorageBackend`] with [`provide_storage_backend`], like the
+//! [`FileBackend`] that stores each store in a JSON file.
+
+use dioxus_core::prelude::*;
+use dioxus_html::document::{document, Document, UseEval};
+use dioxus_signals::{CopyValue, Readable, Signal};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::rc::Rc;
+
+/// The future returned by the methods of a [`StorageBackend`]
+pub type StorageFuture<T> = Pin<Box<dyn Future<Output = Result<T, StorageError>>>>;
+
+/// An error reading from or writing to a store
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum StorageError {
+    /// The backend failed to read or write the value
+    Backend(String),
+    /// The value couldn't be converted to or from JSON
+    Serialization(String),
+}
+
+impl Display for StorageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StorageError::Backend(err) => write!(f, "storage backend error: {err}"),
+            StorageError::Serialization(err) => write!(f, "failed to (de)serialize value: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for StorageError {}
+
+/// A place to store JSON values by store name and key. [`use_indexed_db`] uses the backend provided with
+/// [`provide_storage_backend`], or an [`IndexedDbBackend`] if no backend was provided.
+pub trait StorageBackend {
+    /// Get the value for a key, or `None` if the store doesn't contain the key
+    fn get(&self, store: &str, key: &str) -> StorageFuture<Option<Value>>;
+
+    /// Set the value for a key
+    fn put(&self, store: &str, key: &str, value: Value) -> StorageFuture<()>;
+
+    /// Remove the value for a key
+    fn delete(&self, store: &str, key: &str) -> StorageFuture<()>;
+
+    /// Get every key and value in the store
+    fn entries(&self, store: &str) -> StorageFuture<Vec<(String, Value)>>;
+}
+
+const INDEXED_DB_SCRIPT: &str = r#"
+const { op, store, key, value } = await dioxus.recv();
+try {
+    const db = await new Promise((resolve, reject) => {
+        const request = indexedDB.open("dioxus-" + store, 1);
+        request.onupgradeneeded = () => request.result.createObjectStore("values");
+        request.onsuccess = () => resolve(request.result);
+        request.onerror = () => reject(request.error);
+    });
+    const run = (mode, f) => new Promise((resolve, reject) => {
+        const transaction = db.transaction("values", mode);
+        const request = f(transaction.objectStore("values"));
+        transaction.oncomplete = () => resolve(request.result);
+        transaction.onerror = () => reject(transaction.error);
+    });
+    try {
+        switch (op) {
+            case "get": {
+                const found = await run("readonly", (values) => values.get(key));
+                return { ok: found === undefined ? null : [found] };
+            }
+            case "put":
+                await run("readwrite", (values) => values.put(value, key));
+                return { ok: null };
+            case "delete":
+                await run("readwrite", (values) => values.delete(key));
+                return { ok: null };
+            case "entries": {
+                const keys = await run("readonly", (values) => values.getAllKeys());
+                const found = await run("readonly", (values) => values.getAll());
+                return { ok: keys.map((key, i) => [String(key), found[i]]) };
+            }
+        }
+    } finally {
+        db.close();
+    }
+} catch (error) {
+    return { error: String(error) };
+}
+"#;
+
+/// A [`StorageBackend`] that stores values in the IndexedDB of the browser or webview. Each store is a separate
+/// database named `dioxus-{store}`.
+#[derive(Clone)]
+pub struct IndexedDbBackend {
+    document: Rc<dyn Document>,
+}
+
+impl IndexedDbBackend {
+    /// Create a backend that uses the [`Document`] of the current renderer to access IndexedDB
+    pub fn new() -> Self {
+        Self {
+            document: document(),
+        }
+    }
+
+    fn run<T: DeserializeOwned + 'static>(&self, command: Value) -> StorageFuture<T> {
+        run_script(&self.document, INDEXED_DB_SCRIPT, command)
+    }
+}
+
+/// Send a command to a storage script and decode the `{ ok }` or `{ error }` it returns
+pub(crate) fn run_script<T: DeserializeOwned + 'static>(
+    document: &Rc<dyn Document>,
+    script: &str,
+    command: Value,
+) -> StorageFuture<T> {
+    let eval = UseEval::new(document.new_evaluator(script.to_string()));
+    Box::pin(async move {
+        eval.send(command)
+            .map_err(|err| StorageError::Backend(err.to_string()))?;
+        let mut result = eval
+            .join()
+            .await
+            .map_err(|err| StorageError::Backend(err.to_string()))?;
+        if let Some(error) = result.get("error").and_then(Value::as_str) {
+            return Err(StorageError::Backend(error.to_string()));
+        }
+        serde_json::from_value(result["ok"].take())
+            .map_err(|err| StorageError::Serialization(err.to_string()))
+    })
+}
+
+impl Default for IndexedDbBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StorageBackend for IndexedDbBackend {
+    fn get(&self, store: &str, key: &str) -> StorageFuture<Option<Value>> {
+        let found =
+            self.run::<Option<(Value,)>>(json!({ "op": "get", "store": store, "key": key }));
+        Box::pin(async move { Ok(found.await?.map(|(value,)| value)) })
+    }
+
+    fn put(&self, store: &str, key: &str, value: Value) -> StorageFuture<()> {
+        self.run(json!({ "op": "put", "store": store, "key": key, "value": value }))
+    }
+
+    fn delete(&self, store: &str, key: &str) -> StorageFuture<()> {
+        self.run(json!({ "op": "delete", "store": store, "key": key }))
+    }
+
+    fn entries(&self, store: &str) -> StorageFuture<Vec<(String, Value)>> {
+        self.run(json!({ "op": "entries", "store": store }))
+    }
+}
+
+/// A [`StorageBackend`] that stores each store in a JSON file in a directory. This is useful on platforms without
+/// IndexedDB, and for desktop apps that want their data in the app's data directory instead of the webview.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// use dioxus_storage::{provide_storage_backend, FileBackend};
+///
+/// fn app() -> Element {
+///     use_hook(|| provide_storage_backend(FileBackend::new("./data")));
+///     rsx! {}
+/// }
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone)]
+pub struct FileBackend {
+    dir: std::path::PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileBackend {
+    /// Create a backend that stores files in a directory. The directory is created when the first value is written.
+    pub fn new(dir: impl Into<std::path::PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn path(&self, store: &str) -> Result<std::path::PathBuf, StorageError> {
+        if store.is_empty() || store.contains(['/', '\\']) || store.starts_with('.') {
+            return Err(StorageError::Backend(format!(
+                "{store:?} is not a valid store name"
+            )));
+        }
+        Ok(self.dir.join(format!("{store}.json")))
+    }
+
+    fn read(&self, store: &str) -> Result<serde_json::Map<String, Value>, StorageError> {
+        match std::fs::read(self.path(store)?) {
+            Ok(bytes) => serde_json::from_slice(&bytes)
+                .map_err(|err| StorageError::Serialization(err.to_string())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Default::default()),
+            Err(err) => Err(StorageError::Backend(err.to_string())),
+        }
+    }
+
+    fn update(
+        &self,
+        store: &str,
+        f: impl FnOnce(&mut serde_json::Map<String, Value>),
+    ) -> Result<(), StorageError> {
+        let mut values = self.read(store)?;
+        f(&mut values);
+        let bytes = serde_json::to_vec(&values)
+            .map_err(|err| StorageError::Serialization(err.to_string()))?;
+        std::fs::create_dir_all(&self.dir).map_err(|err| StorageError::Backend(err.to_string()))?;
+        // Write to a temporary file first so a crash doesn't leave a half written store
+        let path = self.path(store)?;
+        let temporary = path.with_extension("json.tmp");
+        std::fs::write(&temporary, bytes)
+            .and_then(|_| std::fs::rename(&temporary, &path))
+            .map_err(|err| StorageError::Backend(err.to_string()))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl StorageBackend for FileBackend {
+    fn get(&self, store: &str, key: &str) -> StorageFuture<Option<Value>> {
+        let result = self.read(store).map(|mut values| values.remove(key));
+        Box::pin(std::future::ready(result))
+    }
+
+    fn put(&self, store: &str, key: &str, value: Value) -> StorageFuture<()> {
+        let result = self.update(store, |values| {
+            values.insert(key.to_string(), value);
+        });
+        Box::pin(std::future::ready(result))
+    }
+
+    fn delete(&self, store: &str, key: &str) -> StorageFuture<()> {
+        let result = self.update(store, |values| {
+            values.remove(key);
+        });
+        Box::pin(std::future::ready(result))
+    }
+
+    fn entries(&self, store: &str) -> StorageFuture<Vec<(String, Value)>> {
+        let result = self.read(store).map(|values| values.into_iter().collect());
+        Box::pin(std::future::ready(result))
+    }
+}
+
+/// Use a different [`StorageBackend`] for every store opened with [`use_indexed_db`] in the app. Call this before
+/// opening any stores, for example in a `use_hook` in the root component.
+pub fn provide_storage_backend(backend: impl StorageBackend + 'static) {
+    provide_root_context(Rc::new(backend) as Rc<dyn StorageBackend>);
+}
+
+/// The version of every store, shared by all handles to the same store
+#[derive(Clone, Default)]
+struct StoreVersions(Rc<RefCell<HashMap<String, Signal<u64>>>>);
+
+/// A typed handle to a store created with [`use_indexed_db`].
+///
+/// Reading from the store subscribes to it: a [`use_resource`](dioxus_hooks::use_resource) that reads from the store
+/// reruns when any handle to the same store writes to it.
+pub struct UseStore<T: 'static> {
+    name: CopyValue<String>,
+    backend: CopyValue<Rc<dyn StorageBackend>>,
+    version: Signal<u64>,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T: Serialize + DeserializeOwned + 'static> UseStore<T> {
+    /// Get the name of the store
+    pub fn name(&self) -> String {
+        self.name.read().clone()
+    }
+
+    /// Get the number of writes to the store since the app started. This subscribes to the store.
+    pub fn version(&self) -> u64 {
+        (self.version)()
+    }
+
+    /// Get the value for a key, or `None` if the store doesn't contain the key. This subscribes to the store.
+    pub async fn get(&self, key: &str) -> Result<Option<T>, StorageError> {
+        self.version.read();
+        let value = self.backend().get(&self.name(), key).await?;
+        value.map(deserialize).transpose()
+    }
+
+    /// Set the value for a key
+    pub async fn put(&self, key: &str, value: &T) -> Result<(), StorageError> {
+        let value = serde_json::to_value(value)
+            .map_err(|err| StorageError::Serialization(err.to_string()))?;
+        self.backend().put(&self.name(), key, value).await?;
+        self.invalidate();
+        Ok(())
+    }
+
+    /// Remove the value for a key
+    pub async fn delete(&self, key: &str) -> Result<(), StorageError> {
+        self.backend().delete(&self.name(), key).await?;
+        self.invalidate();
+        Ok(())
+    }
+
+    /// Get every key and value in the store. This subscribes to the store.
+    pub async fn entries(&self) -> Result<Vec<(String, T)>, StorageError> {
+        self.query(|_| true).await
+    }
+
+    /// Get the keys and values the filter returns `true` for. This subscribes to the store.
+    pub async fn query(
+        &self,
+        mut filter: impl FnMut(&T) -> bool,
+    ) -> Result<Vec<(String, T)>, StorageError> {
+        self.version.read();
+        let mut entries = Vec::new();
+        for (key, value) in self.backend().entries(&self.name()).await? {
+            let value = deserialize(value)?;
+            if filter(&value) {
+                entries.push((key, value));
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Rerun everything that reads from the store, for example after the store was changed in another tab
+    pub fn invalidate(&self) {
+        let mut version = self.version;
+        version += 1;
+    }
+
+    fn backend(&self) -> Rc<dyn StorageBackend> {
+        self.backend.read().clone()
+    }
+}
+
+impl<T: 'static> Clone for UseStore<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for UseStore<T> {}
+
+fn deserialize<T: DeserializeOwned>(value: Value) -> Result<T, StorageError> {
+    serde_json::from_value(value).map_err(|err| StorageError::Serialization(err.to_string()))
+}
+
+/// Open a store of typed values. The values are stored in IndexedDB, or in the backend provided with
+/// [`provide_storage_backend`].
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_storage::use_indexed_db;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, Clone, PartialEq)]
+/// struct Note {
+///     text: String,
+///     pinned: bool,
+/// }
+///
+/// fn app() -> Element {
+///     let notes = use_indexed_db::<Note>("notes");
+///     // Reruns whenever a note is saved
+///     let pinned = use_resource(move || async move {
+///         notes.query(|note| note.pinned).await.unwrap_or_default()
+///     });
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| async move {
+///                 let note = Note { text: "Buy milk".to_string(), pinned: true };
+///                 _ = notes.put("milk", &note).await;
+///             },
+///             "Save"
+///         }
+///         for (key, note) in pinned.cloned().unwrap_or_default() {
+///             p { key: "{key}", "{note.text}" }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_indexed_db<T: Serialize + DeserializeOwned + 'static>(
+    store_name: impl Into<String>,
+) -> UseStore<T> {
+    let store_name = store_name.into();
+    use_hook(move || {
+        let backend = try_consume_context::<Rc<dyn StorageBackend>>()
+            .unwrap_or_else(|| Rc::new(IndexedDbBackend::new()));
+        let versions = try_consume_context::<StoreVersions>()
+            .unwrap_or_else(|| provide_root_context(StoreVersions::default()));
+        let version = *versions
+            .0
+            .borrow_mut()
+            .entry(store_name.clone())
+            .or_insert_with(|| Signal::new_in_scope(0, ScopeId::ROOT));
+        UseStore {
+            name: CopyValue::new(store_name),
+            backend: CopyValue::new(backend),
+            version,
+            phantom: PhantomData,
+        }
+    })
+}
@@ -0,0 +1,194 @@
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_storage::{
+    provide_persistence_backend, use_persistent_signal, FileBackend, StorageBackend, StorageFuture,
+};
+use serde_json::{json, Value};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::time::Duration;
+
+thread_local! {
+    static DIR: Cell<Option<&'static PathBuf>> = const { Cell::new(None) };
+    static COUNT: Cell<Option<Signal<i32>>> = const { Cell::new(None) };
+}
+
+fn app() -> Element {
+    use_hook(|| {
+        provide_persistence_backend(FileBackend::new(DIR.with(|cell| cell.get()).unwrap()))
+    });
+    rsx! { Counter {} }
+}
+
+#[component]
+fn Counter() -> Element {
+    let count = use_persistent_signal("count", || 1);
+    COUNT.with(|cell| cell.set(Some(count)));
+    rsx! { "{count}" }
+}
+
+async fn settle(dom: &mut VirtualDom) {
+    while tokio::time::timeout(Duration::from_millis(50), dom.wait_for_work())
+        .await
+        .is_ok()
+    {
+        dom.render_immediate(&mut NoOpMutations);
+    }
+}
+
+/// Use a fresh directory as the backend of the app
+fn use_temp_dir(name: &str) -> &'static PathBuf {
+    let dir = std::env::temp_dir().join(format!("dioxus-persistent-{name}-{}", std::process::id()));
+    _ = std::fs::remove_dir_all(&dir);
+    let dir: &'static PathBuf = Box::leak(Box::new(dir));
+    DIR.with(|cell| cell.set(Some(dir)));
+    dir
+}
+
+fn saved(dir: &Path) -> Value {
+    serde_json::from_slice(&std::fs::read(dir.join("signals.json")).unwrap()).unwrap()
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn values_are_restored_on_the_next_start() {
+    let dir = use_temp_dir("restored");
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    settle(&mut dom).await;
+    let mut count = COUNT.with(|cell| cell.get()).unwrap();
+    dom.in_runtime(|| {
+        assert_eq!(count(), 1);
+        count.set(5);
+    });
+    settle(&mut dom).await;
+    drop(dom);
+
+    // A new app starts with the saved value before the first render
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert_eq!(dioxus_ssr::render(&dom), "5");
+
+    _ = std::fs::remove_dir_all(dir);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn missing_files_start_with_the_initial_value() {
+    let dir = use_temp_dir("missing");
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert_eq!(dioxus_ssr::render(&dom), "1");
+    settle(&mut dom).await;
+    // The initial value is saved so the next start doesn't run `init` again
+    assert_eq!(saved(dir), json!({ "count": 1 }));
+
+    let mut count = COUNT.with(|cell| cell.get()).unwrap();
+    dom.in_runtime(|| count.set(2));
+    settle(&mut dom).await;
+    assert_eq!(saved(dir), json!({ "count": 2 }));
+
+    _ = std::fs::remove_dir_all(dir);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn invalid_saved_values_fall_back_to_the_initial_value() {
+    let dir = use_temp_dir("invalid");
+    std::fs::create_dir_all(dir).unwrap();
+
+    // A value of the wrong type is ignored and replaced the next time the signal is saved
+    std::fs::write(dir.join("signals.json"), r#"{ "count": "five" }"#).unwrap();
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert_eq!(dioxus_ssr::render(&dom), "1");
+    settle(&mut dom).await;
+    let mut count = COUNT.with(|cell| cell.get()).unwrap();
+    dom.in_runtime(|| count.set(3));
+    settle(&mut dom).await;
+    assert_eq!(saved(dir), json!({ "count": 3 }));
+    drop(dom);
+
+    // A corrupt file is left alone, but the signal still works
+    std::fs::write(dir.join("signals.json"), "{ not json").unwrap();
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert_eq!(dioxus_ssr::render(&dom), "1");
+    let mut count = COUNT.with(|cell| cell.get()).unwrap();
+    dom.in_runtime(|| count.set(4));
+    settle(&mut dom).await;
+    assert_eq!(dioxus_ssr::render(&dom), "4");
+    assert_eq!(
+        std::fs::read_to_string(dir.join("signals.json")).unwrap(),
+        "{ not json"
+    );
+
+    _ = std::fs::remove_dir_all(dir);
+}
+
+/// A backend that stores values in memory and, like `localStorage`, only finishes reading them after the first
+/// render
+#[derive(Clone, Default)]
+struct SlowBackend(Rc<RefCell<HashMap<String, Value>>>);
+
+impl StorageBackend for SlowBackend {
+    fn get(&self, _: &str, key: &str) -> StorageFuture<Option<Value>> {
+        let values = self.0.clone();
+        let key = key.to_string();
+        Box::pin(async move {
+            tokio::task::yield_now().await;
+            Ok(values.borrow().get(&key).cloned())
+        })
+    }
+
+    fn put(&self, _: &str, key: &str, value: Value) -> StorageFuture<()> {
+        self.0.borrow_mut().insert(key.to_string(), value);
+        Box::pin(std::future::ready(Ok(())))
+    }
+
+    fn delete(&self, _: &str, key: &str) -> StorageFuture<()> {
+        self.0.borrow_mut().remove(key);
+        Box::pin(std::future::ready(Ok(())))
+    }
+
+    fn entries(&self, _: &str) -> StorageFuture<Vec<(String, Value)>> {
+        let entries = self
+            .0
+            .borrow()
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        Box::pin(std::future::ready(Ok(entries)))
+    }
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn slow_backends_restore_the_value_after_the_first_render() {
+    thread_local! {
+        static BACKEND: RefCell<SlowBackend> = RefCell::new(SlowBackend::default());
+    }
+
+    fn slow_app() -> Element {
+        use_hook(|| provide_persistence_backend(BACKEND.with(|cell| cell.borrow().clone())));
+        rsx! { Counter {} }
+    }
+
+    let backend = SlowBackend::default();
+    backend.0.borrow_mut().insert("count".to_string(), json!(7));
+    BACKEND.with(|cell| *cell.borrow_mut() = backend.clone());
+
+    let mut dom = VirtualDom::new(slow_app);
+    dom.rebuild_in_place();
+    assert_eq!(dioxus_ssr::render(&dom), "1");
+    // The initial value doesn't overwrite the saved value before it is restored
+    assert_eq!(backend.0.borrow().get("count"), Some(&json!(7)));
+
+    settle(&mut dom).await;
+    assert_eq!(dioxus_ssr::render(&dom), "7");
+
+    let mut count = COUNT.with(|cell| cell.get()).unwrap();
+    dom.in_runtime(|| count.set(8));
+    settle(&mut dom).await;
+    assert_eq!(backend.0.borrow().get("count"), Some(&json!(8)));
+}
@@ -0,0 +1,209 @@
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_storage::{provide_storage_backend, use_indexed_db, FileBackend, UseStore};
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+struct Note {
+    text: String,
+    pinned: bool,
+}
+
+fn note(text: &str, pinned: bool) -> Note {
+    Note {
+        text: text.to_string(),
+        pinned,
+    }
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("dioxus-storage-{name}-{}", std::process::id()));
+    _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn readers_rerun_when_the_store_changes() {
+    thread_local! {
+        static DIR: Cell<Option<&'static PathBuf>> = const { Cell::new(None) };
+        static NOTES: Cell<Option<UseStore<Note>>> = const { Cell::new(None) };
+        static PINNED: Cell<usize> = const { Cell::new(0) };
+    }
+    let dir: &'static PathBuf = Box::leak(Box::new(temp_dir("readers")));
+    DIR.with(|cell| cell.set(Some(dir)));
+
+    fn app() -> Element {
+        use_hook(|| {
+            provide_storage_backend(FileBackend::new(DIR.with(|cell| cell.get()).unwrap()))
+        });
+        rsx! { Notes {} }
+    }
+
+    #[component]
+    fn Notes() -> Element {
+        let notes = use_indexed_db::<Note>("notes");
+        NOTES.with(|cell| cell.set(Some(notes)));
+        let pinned =
+            use_resource(move || async move { notes.query(|note| note.pinned).await.unwrap() });
+        if let Some(pinned) = &*pinned.read() {
+            PINNED.with(|cell| cell.set(pinned.len()));
+        }
+        rsx! {}
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let notes = NOTES.with(|cell| cell.get()).unwrap();
+
+    async fn settle(dom: &mut VirtualDom) {
+        while tokio::time::timeout(Duration::from_millis(50), dom.wait_for_work())
+            .await
+            .is_ok()
+        {
+            dom.render_immediate(&mut NoOpMutations);
+        }
+    }
+
+    settle(&mut dom).await;
+    assert_eq!(PINNED.with(|cell| cell.get()), 0);
+
+    dom.in_runtime(|| {
+        ScopeId::ROOT.in_runtime(|| {
+            spawn(async move {
+                notes.put("milk", &note("Buy milk", true)).await.unwrap();
+                notes.put("eggs", &note("Buy eggs", false)).await.unwrap();
+            });
+        })
+    });
+    settle(&mut dom).await;
+    assert_eq!(PINNED.with(|cell| cell.get()), 1);
+
+    // The values are stored in the backend
+    let backend = FileBackend::new(dir);
+    let stored = dioxus_storage::StorageBackend::get(&backend, "notes", "eggs")
+        .await
+        .unwrap();
+    assert_eq!(
+        stored,
+        Some(serde_json::json!({ "text": "Buy eggs", "pinned": false }))
+    );
+
+    _ = std::fs::remove_dir_all(dir);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn file_backend_rejects_paths_as_store_names() {
+    use dioxus_storage::StorageBackend;
+
+    let dir = temp_dir("names");
+    let backend = FileBackend::new(&dir);
+    assert!(backend.get("../escape", "key").await.is_err());
+
+    backend.put("values", "a", 1.into()).await.unwrap();
+    backend.delete("values", "a").await.unwrap();
+    assert_eq!(backend.entries("values").await.unwrap(), []);
+    _ = std::fs::remove_dir_all(dir);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn file_backend_round_trips_values() {
+    use dioxus_storage::StorageBackend;
+
+    let dir = temp_dir("round-trip");
+    let values = [
+        (
+            "note",
+            serde_json::json!({ "text": "Buy milk", "pinned": true }),
+        ),
+        ("count", serde_json::json!(3)),
+        ("tags", serde_json::json!(["home", "groceries"])),
+        ("empty", serde_json::Value::Null),
+    ];
+    let backend = FileBackend::new(&dir);
+    for (key, value) in &values {
+        backend.put("values", key, value.clone()).await.unwrap();
+    }
+
+    // A new backend reads the values back from the file
+    let backend = FileBackend::new(&dir);
+    for (key, value) in &values {
+        assert_eq!(
+            backend.get("values", key).await.unwrap().as_ref(),
+            Some(value)
+        );
+    }
+    let mut entries = backend.entries("values").await.unwrap();
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    let mut expected: Vec<_> = values
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.clone()))
+        .collect();
+    expected.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(entries, expected);
+
+    // Overwriting a value replaces it
+    backend.put("values", "count", 4.into()).await.unwrap();
+    assert_eq!(
+        backend.get("values", "count").await.unwrap(),
+        Some(4.into())
+    );
+    // Stores are kept in separate files
+    assert_eq!(backend.get("other", "count").await.unwrap(), None);
+    _ = std::fs::remove_dir_all(dir);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn file_backend_treats_missing_files_as_empty_stores() {
+    use dioxus_storage::StorageBackend;
+
+    // The directory doesn't exist until the first value is written
+    let dir = temp_dir("missing").join("nested");
+    let backend = FileBackend::new(&dir);
+    assert_eq!(backend.get("values", "a").await.unwrap(), None);
+    assert_eq!(backend.entries("values").await.unwrap(), []);
+    assert!(!dir.exists());
+
+    backend.put("values", "a", 1.into()).await.unwrap();
+    assert!(dir.join("values.json").exists());
+
+    // Deleting the file empties the store
+    std::fs::remove_file(dir.join("values.json")).unwrap();
+    assert_eq!(backend.get("values", "a").await.unwrap(), None);
+    _ = std::fs::remove_dir_all(dir.parent().unwrap());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn file_backend_reports_corrupt_files() {
+    use dioxus_storage::{StorageBackend, StorageError};
+
+    let dir = temp_dir("corrupt");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("values.json"), "{ not json").unwrap();
+    let backend = FileBackend::new(&dir);
+
+    assert!(matches!(
+        backend.get("values", "a").await,
+        Err(StorageError::Serialization(_))
+    ));
+    assert!(matches!(
+        backend.entries("values").await,
+        Err(StorageError::Serialization(_))
+    ));
+    // Writes fail instead of replacing the corrupt file, so nothing else in it is lost
+    assert!(matches!(
+        backend.put("values", "a", 1.into()).await,
+        Err(StorageError::Serialization(_))
+    ));
+    assert_eq!(
+        std::fs::read_to_string(dir.join("values.json")).unwrap(),
+        "{ not json"
+    );
+
+    // Other stores in the same directory still work
+    backend.put("other", "a", 1.into()).await.unwrap();
+    assert_eq!(backend.get("other", "a").await.unwrap(), Some(1.into()));
+    _ = std::fs::remove_dir_all(dir);
+}
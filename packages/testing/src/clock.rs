@@ -0,0 +1,111 @@
+use dioxus_hooks::Clock;
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::{Rc, Weak};
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+
+#[derive(Default)]
+struct TimerState {
+    done: bool,
+    waker: Option<Waker>,
+}
+
+struct Timer {
+    deadline: Duration,
+    state: Weak<RefCell<TimerState>>,
+}
+
+#[derive(Default)]
+struct ClockState {
+    now: Duration,
+    timers: Vec<Timer>,
+}
+
+/// A [`Clock`] that only moves forward when it is told to.
+///
+/// Every [`TestDom`](crate::TestDom) provides a mock clock to the app it mounts, so the timers in
+/// [`sleep`](dioxus_hooks::time::sleep), [`use_interval`](dioxus_hooks::use_interval) and
+/// [`use_debounce`](dioxus_hooks::use_debounce) only fire when the test calls [`TestDom::advance`](crate::TestDom::advance).
+/// Clones of a mock clock share the same time.
+#[derive(Clone, Default)]
+pub struct MockClock {
+    state: Rc<RefCell<ClockState>>,
+}
+
+impl MockClock {
+    /// Create a new mock clock that starts at zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The earliest deadline of the timers that are still waiting
+    pub fn next_deadline(&self) -> Option<Duration> {
+        let mut state = self.state.borrow_mut();
+        state.timers.retain(|timer| timer.state.strong_count() > 0);
+        state.timers.iter().map(|timer| timer.deadline).min()
+    }
+
+    /// Set the time of the clock and wake every timer whose deadline has passed. The clock never moves backwards.
+    pub fn set_now(&self, now: Duration) {
+        let mut state = self.state.borrow_mut();
+        state.now = state.now.max(now);
+        let now = state.now;
+
+        let mut woken = Vec::new();
+        state.timers.retain(|timer| {
+            if timer.deadline > now {
+                return timer.state.strong_count() > 0;
+            }
+            if let Some(timer) = timer.state.upgrade() {
+                let mut timer = timer.borrow_mut();
+                timer.done = true;
+                woken.extend(timer.waker.take());
+            }
+            false
+        });
+        drop(state);
+
+        for waker in woken {
+            waker.wake();
+        }
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Duration {
+        self.state.borrow().now
+    }
+
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()>>> {
+        let mut clock = self.state.borrow_mut();
+        let timer = Rc::new(RefCell::new(TimerState {
+            done: duration.is_zero(),
+            waker: None,
+        }));
+        let deadline = clock.now + duration;
+        clock.timers.push(Timer {
+            deadline,
+            state: Rc::downgrade(&timer),
+        });
+        Box::pin(MockSleep { timer })
+    }
+}
+
+struct MockSleep {
+    timer: Rc<RefCell<TimerState>>,
+}
+
+impl Future for MockSleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut timer = self.timer.borrow_mut();
+        if timer.done {
+            return Poll::Ready(());
+        }
+        timer.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
@@ -0,0 +1,233 @@
+use crate::{node::snapshot, MockClock, Queries, TestElement, TestNode};
+use dioxus_core::prelude::{ComponentFunction, Element, Event};
+use dioxus_core::{NoOpMutations, VirtualDom};
+use dioxus_hooks::{Clock, ClockContext};
+use dioxus_html::{
+    geometry::{ClientPoint, Coordinates, ElementPoint, PagePoint, ScreenPoint},
+    input_data::{MouseButton, MouseButtonSet},
+    prelude::{Code, Key, Location, Modifiers},
+    replay::{replay_event, EventRecording},
+    EventData, FormValue, SerializedFormData, SerializedHtmlEventConverter, SerializedKeyboardData,
+    SerializedMouseData,
+};
+use futures_util::FutureExt;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// The number of times [`TestDom::flush`] renders before it assumes the app is stuck in a render loop
+const MAX_FLUSHES: usize = 1000;
+
+/// A VirtualDom mounted headlessly for tests.
+///
+/// The dom renders into a tree that can be searched with [`Queries`], and events can be fired at the elements the
+/// queries return. All work that is ready after an event is rendered before the event method returns, so the tree
+/// can be checked right away. Async work can be awaited with [`TestDom::settle`] and [`TestDom::wait_for`].
+///
+/// The app runs with a [`MockClock`], so timers from dioxus hooks only fire when the test calls
+/// [`TestDom::advance`].
+pub struct TestDom {
+    pub(crate) dom: VirtualDom,
+    clock: MockClock,
+}
+
+impl TestDom {
+    /// Mount a component without props and render it
+    pub fn new(app: fn() -> Element) -> Self {
+        Self::from_dom(VirtualDom::new(app))
+    }
+
+    /// Mount a component with props and render it
+    pub fn new_with_props<P: Clone + 'static, M: 'static>(
+        root: impl ComponentFunction<P, M>,
+        root_props: P,
+    ) -> Self {
+        Self::from_dom(VirtualDom::new_with_props(root, root_props))
+    }
+
+    /// Mount a VirtualDom that has already been configured, for example with root contexts, and render it
+    pub fn from_dom(mut dom: VirtualDom) -> Self {
+        dioxus_html::set_event_converter(Box::new(SerializedHtmlEventConverter));
+        let clock = MockClock::new();
+        dom.provide_root_context(ClockContext::new(clock.clone()));
+        dom.rebuild_in_place();
+        let mut test_dom = Self { dom, clock };
+        test_dom.flush();
+        test_dom
+    }
+
+    /// The VirtualDom the test dom renders
+    pub fn dom(&mut self) -> &mut VirtualDom {
+        &mut self.dom
+    }
+
+    /// The clock the timers in the app use
+    pub fn clock(&self) -> &MockClock {
+        &self.clock
+    }
+
+    /// Move the clock forward and run everything that was waiting for the time to pass.
+    ///
+    /// Timers fire in the order of their deadlines, and the app is flushed after each deadline, so an interval that
+    /// fires every second runs five times when the clock moves forward five seconds. Timers from other sources, like
+    /// `tokio::time`, still use the real time.
+    pub fn advance(&mut self, duration: Duration) {
+        let target = self.clock.now() + duration;
+        self.flush();
+        while let Some(deadline) = self
+            .clock
+            .next_deadline()
+            .filter(|deadline| *deadline <= target)
+        {
+            self.clock.set_now(deadline);
+            self.flush();
+        }
+        self.clock.set_now(target);
+        self.flush();
+    }
+
+    /// Replay events recorded with an [`EventRecorder`](dioxus_html::replay::EventRecorder), for example from a
+    /// desktop app that ran with `DIOXUS_RECORD_EVENTS`.
+    ///
+    /// The clock moves forward by the time between the recorded events, so timers fire between the events like they
+    /// did when the events were recorded. The app is flushed after each event.
+    pub fn replay(&mut self, recording: &EventRecording) {
+        let start = self.clock.now();
+        for recorded in &recording.events {
+            let at = start + recorded.at();
+            self.advance(at.saturating_sub(self.clock.now()));
+            replay_event(&self.dom, recorded.event.clone());
+            self.flush();
+        }
+    }
+
+    /// Render the current tree as html. This is useful to debug failing queries.
+    pub fn html(&self) -> String {
+        self.nodes().iter().map(ToString::to_string).collect()
+    }
+
+    /// Run every task and render every scope that is ready without waiting. This is called after every event.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the app is still dirty after many renders, which usually means a component writes to a signal it reads.
+    pub fn flush(&mut self) {
+        for _ in 0..MAX_FLUSHES {
+            if self.dom.wait_for_work().now_or_never().is_none() {
+                return;
+            }
+            self.dom.render_immediate(&mut NoOpMutations);
+        }
+        panic!("The app did not settle after {MAX_FLUSHES} renders. Is a component writing to a signal it reads?");
+    }
+
+    /// Wait until every suspended component has resolved, then render any work that is ready.
+    ///
+    /// Tasks that are not awaited by a suspense boundary, like an infinite loop in a `use_future`, are not waited for.
+    pub async fn settle(&mut self) {
+        self.flush();
+        if self.dom.suspended_tasks_remaining() {
+            self.dom.wait_for_suspense().await;
+            self.flush();
+        }
+    }
+
+    /// Wait for async work until the check returns a value. This never times out, so wrap it in a timeout from your
+    /// async runtime if the check may never succeed.
+    ///
+    /// ```rust, ignore
+    /// let loaded = dom.wait_for(|dom| dom.find_by_text("Loaded").ok()).await;
+    /// ```
+    pub async fn wait_for<T>(&mut self, mut check: impl FnMut(&Self) -> Option<T>) -> T {
+        loop {
+            self.flush();
+            if let Some(value) = check(self) {
+                return value;
+            }
+            self.dom.wait_for_work().await;
+            self.dom.render_immediate(&mut NoOpMutations);
+        }
+    }
+
+    /// Fire an event at an element. Events that bubble are handled by the closest element with a listener.
+    ///
+    /// The name is the name of the event without the `on` prefix, like `click`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the event cannot reach a mounted element. Static elements are only mounted when they or one of
+    /// their parents have a dynamic attribute, so add a listener to the element (or a parent for bubbling events).
+    pub fn fire(&mut self, element: &TestElement, name: &str, data: EventData) {
+        let bubbles = dioxus_core_types::event_bubbles(name);
+        let target = if bubbles { element.target } else { element.id };
+        let Some(target) = target else {
+            panic!("Cannot fire `{name}` at {element}: neither the element nor its parents are mounted with an id");
+        };
+        let event = Event::new(data.into_any(), bubbles);
+        self.dom.runtime().handle_event(name, event, target);
+        self.flush();
+    }
+
+    /// Click an element with the primary mouse button
+    pub fn click(&mut self, element: &TestElement) {
+        let data = SerializedMouseData::new(
+            Some(MouseButton::Primary),
+            MouseButtonSet::default(),
+            Coordinates::new(
+                ScreenPoint::zero(),
+                ClientPoint::zero(),
+                ElementPoint::zero(),
+                PagePoint::zero(),
+            ),
+            Modifiers::empty(),
+        );
+        self.fire(element, "click", EventData::Mouse(data));
+    }
+
+    /// Set the value of an input and fire an `input` event
+    pub fn input(&mut self, element: &TestElement, value: impl ToString) {
+        let data = SerializedFormData::new(value.to_string(), HashMap::new());
+        self.fire(element, "input", EventData::Form(data));
+    }
+
+    /// Set the value of an input and fire a `change` event
+    pub fn change(&mut self, element: &TestElement, value: impl ToString) {
+        let data = SerializedFormData::new(value.to_string(), HashMap::new());
+        self.fire(element, "change", EventData::Form(data));
+    }
+
+    /// Submit a form with the values of its fields
+    pub fn submit<K: ToString, V: ToString>(
+        &mut self,
+        element: &TestElement,
+        values: impl IntoIterator<Item = (K, V)>,
+    ) {
+        let values = values
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), FormValue(vec![value.to_string()])))
+            .collect();
+        let data = SerializedFormData::new(String::new(), values);
+        self.fire(element, "submit", EventData::Form(data));
+    }
+
+    /// Press a key while an element is focused. This fires a `keydown` event followed by a `keyup` event.
+    pub fn key_press(&mut self, element: &TestElement, key: Key) {
+        for name in ["keydown", "keyup"] {
+            let data = SerializedKeyboardData::new(
+                key.clone(),
+                Code::Unidentified,
+                Location::Standard,
+                false,
+                Modifiers::empty(),
+                false,
+            );
+            self.fire(element, name, EventData::Keyboard(data));
+        }
+    }
+}
+
+impl Queries for TestDom {
+    fn nodes(&self) -> Cow<'_, [TestNode]> {
+        Cow::Owned(snapshot(&self.dom))
+    }
+}
@@ -0,0 +1,25 @@
+#![doc = include_str!("../README.md")]
+#![doc(html_logo_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![doc(html_favicon_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![warn(missing_docs)]
+
+mod clock;
+pub use clock::*;
+
+mod dom;
+pub use dom::*;
+
+mod node;
+pub use node::{TestElement, TestNode};
+
+mod query;
+pub use query::*;
+
+pub mod snapshot;
+
+/// The types you need to write tests
+pub mod prelude {
+    pub use crate::{
+        assert_rsx_snapshot, MockClock, Queries, QueryError, TestDom, TestElement, TestNode,
+    };
+}
@@ -0,0 +1,250 @@
+use dioxus_core::{
+    AttributeValue, DynamicNode, ElementId, ScopeId, TemplateAttribute, TemplateNode, VNode,
+    VirtualDom,
+};
+use std::fmt::{Display, Formatter, Write};
+
+/// A node in a snapshot of the rendered tree
+#[derive(Debug, Clone, PartialEq)]
+pub enum TestNode {
+    /// An element
+    Element(TestElement),
+    /// A text node
+    Text(String),
+}
+
+impl TestNode {
+    /// The text content of the node and all of its descendants
+    pub fn text_content(&self) -> String {
+        match self {
+            TestNode::Element(element) => element.text_content(),
+            TestNode::Text(text) => text.clone(),
+        }
+    }
+}
+
+/// An element in a snapshot of the rendered tree.
+///
+/// Elements are snapshots. They don't change when the app re-renders, so query for the element again after an event
+/// to see the new state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestElement {
+    pub(crate) id: Option<ElementId>,
+    pub(crate) target: Option<ElementId>,
+    pub(crate) tag: &'static str,
+    pub(crate) attributes: Vec<(&'static str, String)>,
+    pub(crate) listeners: Vec<&'static str>,
+    pub(crate) children: Vec<TestNode>,
+}
+
+impl TestElement {
+    /// The tag of the element, like `div`
+    pub fn tag(&self) -> &'static str {
+        self.tag
+    }
+
+    /// The value of an attribute of the element
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(attribute, _)| *attribute == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Every attribute of the element in the order they are written
+    pub fn attributes(&self) -> &[(&'static str, String)] {
+        &self.attributes
+    }
+
+    /// Check if the element has a listener for an event, like `click`
+    pub fn has_listener(&self, event: &str) -> bool {
+        self.listeners.contains(&event)
+    }
+
+    /// The children of the element
+    pub fn children(&self) -> &[TestNode] {
+        &self.children
+    }
+
+    /// The text content of the element and all of its descendants
+    pub fn text_content(&self) -> String {
+        let mut text = String::new();
+        for child in &self.children {
+            text.push_str(&child.text_content());
+        }
+        text
+    }
+
+    /// The ARIA role of the element. This is the `role` attribute if it is set, or the implicit role of the tag.
+    pub fn role(&self) -> Option<&str> {
+        if let Some(role) = self.attribute("role") {
+            return Some(role);
+        }
+        let role = match self.tag {
+            "button" => "button",
+            "a" if self.attribute("href").is_some() => "link",
+            "input" => match self.attribute("type").unwrap_or("text") {
+                "button" | "submit" | "reset" | "image" => "button",
+                "checkbox" => "checkbox",
+                "radio" => "radio",
+                "range" => "slider",
+                "number" => "spinbutton",
+                "search" => "searchbox",
+                "hidden" => return None,
+                _ => "textbox",
+            },
+            "textarea" => "textbox",
+            "select" => "combobox",
+            "option" => "option",
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => "heading",
+            "ul" | "ol" => "list",
+            "li" => "listitem",
+            "img" => "img",
+            "nav" => "navigation",
+            "main" => "main",
+            "header" => "banner",
+            "footer" => "contentinfo",
+            "form" => "form",
+            "dialog" => "dialog",
+            "table" => "table",
+            "tr" => "row",
+            "td" => "cell",
+            "th" => "columnheader",
+            "progress" => "progressbar",
+            _ => return None,
+        };
+        Some(role)
+    }
+}
+
+impl Display for TestNode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TestNode::Element(element) => element.fmt(f),
+            TestNode::Text(text) => f.write_str(text),
+        }
+    }
+}
+
+impl Display for TestElement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<{}", self.tag)?;
+        for (name, value) in &self.attributes {
+            write!(f, " {name}={value:?}")?;
+        }
+        f.write_char('>')?;
+        for child in &self.children {
+            child.fmt(f)?;
+        }
+        write!(f, "</{}>", self.tag)
+    }
+}
+
+/// Take a snapshot of everything the VirtualDom rendered
+pub(crate) fn snapshot(dom: &VirtualDom) -> Vec<TestNode> {
+    let mut nodes = Vec::new();
+    if let Some(scope) = dom.get_scope(ScopeId::ROOT) {
+        snapshot_vnode(dom, scope.root_node(), None, &mut nodes);
+    }
+    nodes
+}
+
+fn snapshot_vnode(
+    dom: &VirtualDom,
+    vnode: &VNode,
+    target: Option<ElementId>,
+    nodes: &mut Vec<TestNode>,
+) {
+    for root in vnode.template.roots {
+        snapshot_template_node(dom, vnode, root, target, nodes);
+    }
+}
+
+fn snapshot_template_node(
+    dom: &VirtualDom,
+    vnode: &VNode,
+    node: &TemplateNode,
+    target: Option<ElementId>,
+    nodes: &mut Vec<TestNode>,
+) {
+    match node {
+        TemplateNode::Element {
+            tag,
+            attrs,
+            children,
+            ..
+        } => {
+            let mut element = TestElement {
+                id: None,
+                target,
+                tag,
+                attributes: Vec::new(),
+                listeners: Vec::new(),
+                children: Vec::new(),
+            };
+            let mut style = String::new();
+            for attr in attrs.iter() {
+                match attr {
+                    TemplateAttribute::Static {
+                        name,
+                        value,
+                        namespace,
+                    } => match namespace {
+                        Some("style") => _ = write!(style, "{name}:{value};"),
+                        _ => element.attributes.push((name, value.to_string())),
+                    },
+                    TemplateAttribute::Dynamic { id } => {
+                        element.id = element.id.or(vnode.mounted_dynamic_attribute(*id, dom));
+                        for attribute in vnode.dynamic_attrs[*id].iter() {
+                            let value = match &attribute.value {
+                                AttributeValue::Text(value) => value.clone(),
+                                AttributeValue::Float(value) => value.to_string(),
+                                AttributeValue::Int(value) => value.to_string(),
+                                // Like in html, false boolean attributes are left out instead of rendered as "false"
+                                AttributeValue::Bool(false) => continue,
+                                AttributeValue::Bool(true) => "true".to_string(),
+                                AttributeValue::Listener(_) => {
+                                    let name = attribute.name;
+                                    element
+                                        .listeners
+                                        .push(name.strip_prefix("on").unwrap_or(name));
+                                    continue;
+                                }
+                                AttributeValue::Any(_) | AttributeValue::None => continue,
+                            };
+                            match attribute.namespace {
+                                Some("style") => _ = write!(style, "{}:{value};", attribute.name),
+                                _ => element.attributes.push((attribute.name, value)),
+                            }
+                        }
+                    }
+                }
+            }
+            if !style.is_empty() {
+                element.attributes.push(("style", style));
+            }
+
+            // Events on elements without listeners bubble to the closest element that was mounted with an id
+            element.target = element.id.or(target);
+            for child in children.iter() {
+                snapshot_template_node(dom, vnode, child, element.target, &mut element.children);
+            }
+            nodes.push(TestNode::Element(element));
+        }
+        TemplateNode::Text { text } => nodes.push(TestNode::Text(text.to_string())),
+        TemplateNode::Dynamic { id } => match &vnode.dynamic_nodes[*id] {
+            DynamicNode::Text(text) => nodes.push(TestNode::Text(text.value.clone())),
+            DynamicNode::Placeholder(_) => {}
+            DynamicNode::Fragment(fragment) => {
+                for child in fragment {
+                    snapshot_vnode(dom, child, target, nodes);
+                }
+            }
+            DynamicNode::Component(component) => {
+                if let Some(scope) = component.mounted_scope(*id, vnode, dom) {
+                    snapshot_vnode(dom, scope.root_node(), target, nodes);
+                }
+            }
+        },
+    }
+}
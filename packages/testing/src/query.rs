@@ -0,0 +1,132 @@
+use crate::{TestElement, TestNode};
+use std::borrow::Cow;
+
+/// An error returned when a query doesn't match exactly one element
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum QueryError {
+    /// No element matched the query
+    #[error("Unable to find an element {query} in:\n{html}")]
+    NotFound {
+        /// A description of the query
+        query: String,
+        /// The html of the tree that was searched
+        html: String,
+    },
+
+    /// More than one element matched the query
+    #[error("Found {count} elements {query}. Use a find_all_by query if this is expected")]
+    Multiple {
+        /// A description of the query
+        query: String,
+        /// The number of elements that matched
+        count: usize,
+    },
+}
+
+/// Find the elements in the tree that match a predicate, in document order
+fn find_all<'a>(
+    nodes: &'a [TestNode],
+    matches: &impl Fn(&TestElement) -> bool,
+) -> Vec<&'a TestElement> {
+    let mut found = Vec::new();
+    for node in nodes {
+        if let TestNode::Element(element) = node {
+            if matches(element) {
+                found.push(element);
+            }
+            found.extend(find_all(&element.children, matches));
+        }
+    }
+    found
+}
+
+/// Find the only element in the tree that matches a predicate
+fn find_one(
+    nodes: &[TestNode],
+    query: impl FnOnce() -> String,
+    matches: impl Fn(&TestElement) -> bool,
+) -> Result<TestElement, QueryError> {
+    let found = find_all(nodes, &matches);
+    match found.as_slice() {
+        [element] => Ok((*element).clone()),
+        [] => Err(QueryError::NotFound {
+            query: query(),
+            html: nodes.iter().map(ToString::to_string).collect(),
+        }),
+        _ => Err(QueryError::Multiple {
+            query: query(),
+            count: found.len(),
+        }),
+    }
+}
+
+/// Check if the text nodes directly inside the element match the text, ignoring whitespace around the text
+fn own_text_matches(element: &TestElement, text: &str) -> bool {
+    let own_text: String = element
+        .children
+        .iter()
+        .filter_map(|child| match child {
+            TestNode::Text(text) => Some(text.as_str()),
+            TestNode::Element(_) => None,
+        })
+        .collect();
+    own_text.trim() == text.trim()
+}
+
+/// Queries that find elements in a rendered tree. This is implemented for the whole [`TestDom`](crate::TestDom) and
+/// for [`TestElement`] to search inside an element.
+pub trait Queries {
+    /// The nodes the queries search, including their descendants
+    fn nodes(&self) -> Cow<'_, [TestNode]>;
+
+    /// Find the element whose own text is the text, ignoring whitespace around the text. Text inside child elements
+    /// is not included, so this finds the innermost element that contains the text.
+    fn find_by_text(&self, text: &str) -> Result<TestElement, QueryError> {
+        find_one(
+            &self.nodes(),
+            || format!("with the text {text:?}"),
+            |element| own_text_matches(element, text),
+        )
+    }
+
+    /// Find every element whose own text is the text
+    fn find_all_by_text(&self, text: &str) -> Vec<TestElement> {
+        find_all(&self.nodes(), &|element| own_text_matches(element, text))
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Find the element with a role, like `button` or `textbox`. Elements have the role in their `role` attribute,
+    /// or the implicit role of their tag.
+    fn find_by_role(&self, role: &str) -> Result<TestElement, QueryError> {
+        find_one(
+            &self.nodes(),
+            || format!("with the role {role:?}"),
+            |element| element.role() == Some(role),
+        )
+    }
+
+    /// Find every element with a role
+    fn find_all_by_role(&self, role: &str) -> Vec<TestElement> {
+        find_all(&self.nodes(), &|element| element.role() == Some(role))
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Find the element with a `data-testid` attribute
+    fn find_by_testid(&self, id: &str) -> Result<TestElement, QueryError> {
+        find_one(
+            &self.nodes(),
+            || format!("with the data-testid {id:?}"),
+            |element| element.attribute("data-testid") == Some(id),
+        )
+    }
+}
+
+impl Queries for TestElement {
+    fn nodes(&self) -> Cow<'_, [TestNode]> {
+        Cow::Borrowed(&self.children)
+    }
+}
@@ -0,0 +1,203 @@
+//! Compare rendered html against snapshots stored next to your tests.
+//!
+//! Snapshots are rendered with dioxus-ssr and formatted with one tag or text node per line so changes show up as
+//! readable line diffs. They are stored in `tests/snapshots/<name>.html` in the crate that runs the test.
+//!
+//! - If a snapshot doesn't exist yet, it is written and the assertion passes. When the `CI` environment variable is
+//!   set, a missing snapshot fails the assertion instead.
+//! - If a snapshot doesn't match, the assertion fails with a diff. Run the tests with `DIOXUS_UPDATE_SNAPSHOTS=1` to
+//!   accept the new output.
+
+use crate::TestDom;
+use dioxus_core::{prelude::Element, VirtualDom};
+use dioxus_ssr::Renderer;
+use std::path::Path;
+
+/// Something that can be rendered into a snapshot
+pub trait RenderSnapshot {
+    /// Render the html with a renderer
+    fn render_html(&self, renderer: &mut Renderer) -> String;
+}
+
+impl RenderSnapshot for VirtualDom {
+    fn render_html(&self, renderer: &mut Renderer) -> String {
+        renderer.render(self)
+    }
+}
+
+impl RenderSnapshot for TestDom {
+    fn render_html(&self, renderer: &mut Renderer) -> String {
+        renderer.render(&self.dom)
+    }
+}
+
+impl RenderSnapshot for Element {
+    fn render_html(&self, renderer: &mut Renderer) -> String {
+        renderer.render_element(self.clone())
+    }
+}
+
+impl<T: RenderSnapshot + ?Sized> RenderSnapshot for &T {
+    fn render_html(&self, renderer: &mut Renderer) -> String {
+        (**self).render_html(renderer)
+    }
+}
+
+/// Render a snapshot of the html without hydration ids
+pub fn snapshot_html(source: &impl RenderSnapshot) -> String {
+    format_html(&source.render_html(&mut Renderer::new()))
+}
+
+/// Render a snapshot of the html with the hydration ids the server sends to the client. A fresh renderer is used for
+/// every snapshot, so the ids are the same every time the snapshot is rendered.
+pub fn pre_rendered_snapshot_html(source: &impl RenderSnapshot) -> String {
+    let mut renderer = Renderer::new();
+    renderer.pre_render = true;
+    format_html(&source.render_html(&mut renderer))
+}
+
+/// Format html with one tag, comment or text node per line, indented by depth. Text that is only whitespace is
+/// dropped.
+pub fn format_html(html: &str) -> String {
+    fn line(formatted: &mut String, depth: usize, text: &str) {
+        for _ in 0..depth {
+            formatted.push_str("  ");
+        }
+        formatted.push_str(text);
+        formatted.push('\n');
+    }
+
+    let mut formatted = String::new();
+    let mut depth: usize = 0;
+
+    let mut rest = html;
+    while !rest.is_empty() {
+        if rest.starts_with("<!--") {
+            let end = rest.find("-->").map_or(rest.len(), |end| end + 3);
+            line(&mut formatted, depth, &rest[..end]);
+            rest = &rest[end..];
+        } else if rest.starts_with('<') {
+            let end = rest.find('>').map_or(rest.len(), |end| end + 1);
+            let tag = &rest[..end];
+            if tag.starts_with("</") {
+                depth = depth.saturating_sub(1);
+                line(&mut formatted, depth, tag);
+            } else {
+                line(&mut formatted, depth, tag);
+                if !tag.ends_with("/>") {
+                    depth += 1;
+                }
+            }
+            rest = &rest[end..];
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            let text = &rest[..end];
+            if !text.trim().is_empty() {
+                line(&mut formatted, depth, text);
+            }
+            rest = &rest[end..];
+        }
+    }
+    formatted
+}
+
+/// Compare html against the snapshot with a name in `<manifest_dir>/tests/snapshots`. This is called by
+/// [`assert_rsx_snapshot!`](crate::assert_rsx_snapshot).
+///
+/// # Panics
+///
+/// Panics with a diff if the snapshot doesn't match.
+#[track_caller]
+pub fn assert_snapshot(manifest_dir: &str, name: &str, html: &str) {
+    let path = Path::new(manifest_dir)
+        .join("tests")
+        .join("snapshots")
+        .join(format!("{name}.html"));
+    let update = std::env::var_os("DIOXUS_UPDATE_SNAPSHOTS").is_some();
+
+    let write = || {
+        std::fs::create_dir_all(path.parent().unwrap())
+            .and_then(|_| std::fs::write(&path, html))
+            .unwrap_or_else(|err| panic!("Failed to write snapshot {}: {err}", path.display()));
+    };
+
+    match std::fs::read_to_string(&path) {
+        Ok(expected) if expected == html => {}
+        Ok(_) if update => write(),
+        Ok(expected) => panic!(
+            "Snapshot {name} doesn't match {}\n{}\nRun with DIOXUS_UPDATE_SNAPSHOTS=1 to accept the new snapshot",
+            path.display(),
+            diff_lines(&expected, html)
+        ),
+        Err(_) if is_ci() && !update => panic!(
+            "Snapshot {name} is missing at {}. Run the tests locally to create it\n{html}",
+            path.display()
+        ),
+        Err(_) => {
+            write();
+            tracing::info!("Created snapshot {}", path.display());
+        }
+    }
+}
+
+/// Check if the tests are running in CI, where snapshots should never be created
+fn is_ci() -> bool {
+    std::env::var("CI").is_ok_and(|ci| !ci.is_empty() && ci != "false")
+}
+
+/// Show the lines that were removed from the expected text with `-` and the lines added in the actual text with `+`
+fn diff_lines(expected: &str, actual: &str) -> String {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+
+    let mut out = String::new();
+    for line in diff::slice(&old, &new) {
+        let (prefix, text) = match line {
+            diff::Result::Both(text, _) => (' ', *text),
+            diff::Result::Left(text) => ('-', *text),
+            diff::Result::Right(text) => ('+', *text),
+        };
+        out.push(prefix);
+        out.push(' ');
+        out.push_str(text);
+        out.push('\n');
+    }
+    out
+}
+
+/// Assert that the html of a [`TestDom`](crate::TestDom), [`VirtualDom`](dioxus_core::VirtualDom) or rsx matches a snapshot stored in
+/// `tests/snapshots/<name>.html`.
+///
+/// ```rust, ignore
+/// let mut dom = TestDom::new(counter);
+/// assert_rsx_snapshot!("counter_initial", dom);
+///
+/// let button = dom.find_by_role("button").unwrap();
+/// dom.click(&button);
+/// assert_rsx_snapshot!("counter_clicked", dom);
+///
+/// assert_rsx_snapshot!("header", rsx! { h1 { "Hello" } });
+/// ```
+///
+/// Pass `pre_render` to include the hydration ids the server sends to the client:
+///
+/// ```rust, ignore
+/// assert_rsx_snapshot!("counter_hydration", dom, pre_render);
+/// ```
+#[macro_export]
+macro_rules! assert_rsx_snapshot {
+    ($name:expr, $source:expr) => {
+        $crate::snapshot::assert_snapshot(
+            env!("CARGO_MANIFEST_DIR"),
+            $name,
+            &$crate::snapshot::snapshot_html(&$source),
+        )
+    };
+    ($name:expr, $source:expr, pre_render) => {
+        $crate::snapshot::assert_snapshot(
+            env!("CARGO_MANIFEST_DIR"),
+            $name,
+            &$crate::snapshot::pre_rendered_snapshot_html(&$source),
+        )
+    };
+}
@@ -0,0 +1,93 @@
+use dioxus::html::{
+    replay::{EventRecorder, EventRecording, RecordedEvent},
+    EventData, HtmlEvent, SerializedMouseData,
+};
+use dioxus::prelude::*;
+use dioxus_core::ElementId;
+use dioxus_testing::prelude::*;
+use std::time::Duration;
+
+fn app() -> Element {
+    let mut clicks = use_signal(|| 0);
+    let mut ticks = use_signal(|| 0);
+    use_interval(Duration::from_secs(1), move || ticks += 1);
+    rsx! {
+        button { onclick: move |_| clicks += 1, "Click" }
+        p { "Clicks: {clicks} Ticks: {ticks}" }
+    }
+}
+
+fn click() -> HtmlEvent {
+    HtmlEvent {
+        element: ElementId(1),
+        name: "click".to_string(),
+        bubbles: true,
+        data: EventData::Mouse(SerializedMouseData::default()),
+    }
+}
+
+#[test]
+fn recorded_events_are_written_one_per_line() {
+    let path = std::env::temp_dir().join(format!("dioxus-replay-{}.jsonl", std::process::id()));
+    let mut recorder = EventRecorder::create(&path).unwrap();
+    recorder.record(&click());
+    recorder.record(&click());
+    drop(recorder);
+
+    let recording = EventRecording::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(recording.events.len(), 2);
+    assert_eq!(recording.events[0].event, click());
+    assert!(recording.events[0].at_ms <= recording.events[1].at_ms);
+}
+
+#[test]
+fn recorders_from_the_environment_share_one_file() {
+    let path = std::env::temp_dir().join(format!("dioxus-replay-env-{}.jsonl", std::process::id()));
+    std::env::set_var(dioxus::html::replay::RECORD_EVENTS_ENV, &path);
+
+    // Like two liveview sessions of the same server
+    let mut first = EventRecorder::from_env().unwrap();
+    first.record(&click());
+    let mut second = EventRecorder::from_env().unwrap();
+    second.record(&click());
+    first.record(&click());
+    drop((first, second));
+
+    let recording = EventRecording::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(recording.events.len(), 3);
+}
+
+#[test]
+fn replay_waits_for_the_recorded_time_between_events() {
+    let recording = EventRecording {
+        events: vec![
+            RecordedEvent {
+                at_ms: 0,
+                event: click(),
+            },
+            RecordedEvent {
+                at_ms: 2500,
+                event: click(),
+            },
+        ],
+    };
+    assert_eq!(recording.events[1].at(), Duration::from_millis(2500));
+
+    let mut written = Vec::new();
+    recording.write_to(&mut written).unwrap();
+    assert_eq!(
+        EventRecording::from_reader(&written[..]).unwrap(),
+        recording
+    );
+
+    let mut dom = TestDom::new(app);
+    dom.replay(&recording);
+    assert!(
+        dom.find_by_text("Clicks: 2 Ticks: 2").is_ok(),
+        "{}",
+        dom.html()
+    );
+    assert_eq!(dom.clock().now(), Duration::from_millis(2500));
+}
@@ -0,0 +1,55 @@
+use dioxus::prelude::*;
+use dioxus_testing::prelude::*;
+use dioxus_testing::snapshot::{assert_snapshot, format_html, snapshot_html};
+
+fn counter() -> Element {
+    let mut count = use_signal(|| 0);
+    rsx! {
+        div { class: "counter",
+            p { "Count: {count}" }
+            button { onclick: move |_| count += 1, "Increment" }
+            input { value: "{count}" }
+        }
+    }
+}
+
+#[test]
+fn snapshots_match() {
+    let mut dom = TestDom::new(counter);
+    assert_rsx_snapshot!("counter_initial", dom);
+
+    let button = dom.find_by_role("button").unwrap();
+    dom.click(&button);
+    assert_rsx_snapshot!("counter_clicked", dom);
+    assert_rsx_snapshot!("counter_pre_rendered", dom, pre_render);
+
+    assert_rsx_snapshot!("header", rsx! { h1 { "Hello" } });
+}
+
+#[test]
+fn format_html_indents_tags() {
+    assert_eq!(
+        format_html(r#"<div class="a"><p>Hello <b>world</b></p><br/><!--comment--></div>"#),
+        "<div class=\"a\">\n  <p>\n    Hello \n    <b>\n      world\n    </b>\n  </p>\n  <br/>\n  <!--comment-->\n</div>\n"
+    );
+}
+
+#[test]
+fn mismatched_snapshots_show_a_diff() {
+    let dir = std::env::temp_dir().join(format!("dioxus-snapshot-{}", std::process::id()));
+    let snapshots = dir.join("tests").join("snapshots");
+    std::fs::create_dir_all(&snapshots).unwrap();
+    std::fs::write(snapshots.join("counter.html"), "<p>\n  Count: 1\n</p>\n").unwrap();
+
+    let html = snapshot_html(&rsx! { p { "Count: 0" } });
+    let panic =
+        std::panic::catch_unwind(|| assert_snapshot(dir.to_str().unwrap(), "counter", &html))
+            .unwrap_err();
+    let message = panic.downcast_ref::<String>().unwrap();
+    assert!(
+        message.contains("  <p>\n-   Count: 1\n+   Count: 0\n  </p>\n"),
+        "{message}"
+    );
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,192 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use dioxus_testing::prelude::*;
+
+fn counter() -> Element {
+    let mut count = use_signal(|| 0);
+    rsx! {
+        h1 { "Counter" }
+        p { "data-testid": "count", "Count: {count}" }
+        button { onclick: move |_| count += 1,
+            span { "Increment" }
+        }
+        button { role: "menuitem", onclick: move |_| count.set(0), "Reset" }
+    }
+}
+
+#[test]
+fn queries_find_elements() {
+    let dom = TestDom::new(counter);
+
+    assert_eq!(
+        dom.find_by_role("heading").unwrap().text_content(),
+        "Counter"
+    );
+    assert_eq!(dom.find_by_testid("count").unwrap().tag(), "p");
+    assert_eq!(dom.find_by_text("Increment").unwrap().tag(), "span");
+    assert_eq!(
+        dom.find_by_role("menuitem").unwrap().text_content(),
+        "Reset"
+    );
+    assert_eq!(dom.find_all_by_role("button").len(), 1);
+
+    assert!(matches!(
+        dom.find_by_text("Missing"),
+        Err(QueryError::NotFound { .. })
+    ));
+    assert!(matches!(
+        dom.find_by_role("button"),
+        Ok(button) if button.has_listener("click")
+    ));
+}
+
+#[test]
+fn clicks_bubble_to_listeners() {
+    let mut dom = TestDom::new(counter);
+
+    // The span has no listener, so the click bubbles to the button
+    let label = dom.find_by_text("Increment").unwrap();
+    dom.click(&label);
+    dom.click(&label);
+    assert_eq!(
+        dom.find_by_testid("count").unwrap().text_content(),
+        "Count: 2"
+    );
+
+    let reset = dom.find_by_role("menuitem").unwrap();
+    dom.click(&reset);
+    assert!(dom.find_by_text("Count: 0").is_ok());
+}
+
+#[test]
+#[should_panic(expected = "Cannot fire `click` at <h1>Counter</h1>")]
+fn firing_at_unmounted_elements_panics() {
+    fn app() -> Element {
+        rsx! { h1 { "Counter" } }
+    }
+
+    let mut dom = TestDom::new(app);
+    let heading = dom.find_by_role("heading").unwrap();
+    dom.click(&heading);
+}
+
+#[test]
+fn false_boolean_attributes_are_omitted() {
+    fn app() -> Element {
+        let disabled = false;
+        let checked = true;
+        rsx! {
+            button { disabled, "Save" }
+            input { r#type: "checkbox", checked }
+        }
+    }
+
+    let dom = TestDom::new(app);
+    assert_eq!(
+        dom.find_by_role("button").unwrap().attribute("disabled"),
+        None
+    );
+    assert_eq!(
+        dom.find_by_role("checkbox").unwrap().attribute("checked"),
+        Some("true")
+    );
+}
+
+#[test]
+fn input_and_submit() {
+    fn app() -> Element {
+        let mut name = use_signal(String::new);
+        let mut submitted = use_signal(String::new);
+        rsx! {
+            form {
+                onsubmit: move |event| submitted.set(event.values()["name"].as_value()),
+                input { r#type: "text", value: "{name}", oninput: move |event| name.set(event.value()) }
+            }
+            p { "data-testid": "preview", "Hello {name}" }
+            p { "data-testid": "submitted", "{submitted}" }
+        }
+    }
+
+    let mut dom = TestDom::new(app);
+    let input = dom.find_by_role("textbox").unwrap();
+    dom.input(&input, "Ferris");
+    assert_eq!(
+        dom.find_by_testid("preview").unwrap().text_content(),
+        "Hello Ferris"
+    );
+    assert_eq!(
+        dom.find_by_role("textbox").unwrap().attribute("value"),
+        Some("Ferris")
+    );
+
+    let form = dom.find_by_role("form").unwrap();
+    dom.submit(&form, [("name", "Crab")]);
+    assert_eq!(
+        dom.find_by_testid("submitted").unwrap().text_content(),
+        "Crab"
+    );
+}
+
+#[test]
+fn scoped_queries() {
+    fn app() -> Element {
+        rsx! {
+            ul { "data-testid": "first", li { "One" } }
+            ul { "data-testid": "second", li { "One" } li { "Two" } }
+        }
+    }
+
+    let dom = TestDom::new(app);
+    assert!(matches!(
+        dom.find_by_text("One"),
+        Err(QueryError::Multiple { count: 2, .. })
+    ));
+    let second = dom.find_by_testid("second").unwrap();
+    assert_eq!(second.find_all_by_role("listitem").len(), 2);
+    assert!(dom.html().contains("<li>Two</li>"));
+}
+
+#[tokio::test]
+async fn waits_for_async_work() {
+    fn app() -> Element {
+        let user = use_resource(|| async {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            "Ferris"
+        });
+        match user() {
+            Some(user) => rsx! { p { "data-testid": "user", "{user}" } },
+            None => rsx! { p { "Loading" } },
+        }
+    }
+
+    let mut dom = TestDom::new(app);
+    assert!(dom.find_by_text("Loading").is_ok());
+
+    let user = dom.wait_for(|dom| dom.find_by_testid("user").ok()).await;
+    assert_eq!(user.text_content(), "Ferris");
+}
+
+#[tokio::test]
+async fn settles_suspense() {
+    fn app() -> Element {
+        rsx! {
+            SuspenseBoundary { fallback: |_| rsx! { "Loading" },
+                Loaded {}
+            }
+        }
+    }
+
+    fn Loaded() -> Element {
+        let value = use_resource(|| async {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            42
+        })
+        .suspend()?;
+        rsx! { "The answer is {value}" }
+    }
+
+    let mut dom = TestDom::new(app);
+    dom.settle().await;
+    assert!(dom.html().contains("The answer is 42"));
+}
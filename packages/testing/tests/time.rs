@@ -0,0 +1,152 @@
+#![allow(non_snake_case)]
+
+use dioxus::hooks::time::sleep;
+use dioxus::prelude::*;
+use dioxus_testing::prelude::*;
+use std::time::Duration;
+
+#[test]
+fn intervals_fire_when_the_clock_advances() {
+    fn app() -> Element {
+        let mut ticks = use_signal(|| 0);
+        use_interval(Duration::from_secs(1), move || ticks += 1);
+        rsx! { p { "Ticks: {ticks}" } }
+    }
+
+    let mut dom = TestDom::new(app);
+    dom.advance(Duration::from_millis(999));
+    assert!(dom.find_by_text("Ticks: 0").is_ok());
+
+    dom.advance(Duration::from_millis(1));
+    assert!(dom.find_by_text("Ticks: 1").is_ok());
+
+    dom.advance(Duration::from_secs(5));
+    assert!(dom.find_by_text("Ticks: 6").is_ok());
+    assert_eq!(dom.clock().now(), Duration::from_secs(6));
+}
+
+#[test]
+fn intervals_use_the_latest_period_and_callback() {
+    fn app() -> Element {
+        let mut ticks = use_signal(|| 0);
+        let mut step = use_signal(|| 1);
+        use_interval(Duration::from_secs(step()), move || ticks += step());
+        rsx! {
+            button { onclick: move |_| step.set(2), "Slow down" }
+            p { "Ticks: {ticks}" }
+        }
+    }
+
+    let mut dom = TestDom::new(app);
+    dom.advance(Duration::from_secs(1));
+    assert!(dom.find_by_text("Ticks: 1").is_ok());
+
+    let button = dom.find_by_text("Slow down").unwrap();
+    dom.click(&button);
+    dom.advance(Duration::from_secs(1));
+    assert!(dom.find_by_text("Ticks: 3").is_ok());
+
+    dom.advance(Duration::from_secs(1));
+    assert!(dom.find_by_text("Ticks: 3").is_ok());
+    dom.advance(Duration::from_secs(1));
+    assert!(dom.find_by_text("Ticks: 5").is_ok());
+}
+
+#[test]
+fn debounce_only_fires_after_the_last_call() {
+    fn app() -> Element {
+        let mut query = use_signal(String::new);
+        let mut search = use_debounce(Duration::from_millis(300), move |text: String| {
+            query.set(text)
+        });
+        rsx! {
+            input { oninput: move |event| search.action(event.value()) }
+            p { "Query: {query}" }
+        }
+    }
+
+    let mut dom = TestDom::new(app);
+    let input = dom.find_by_role("textbox").unwrap();
+    dom.input(&input, "d");
+    dom.advance(Duration::from_millis(200));
+    dom.input(&input, "di");
+    dom.advance(Duration::from_millis(200));
+    dom.input(&input, "dio");
+    dom.advance(Duration::from_millis(200));
+    assert!(dom.find_by_text("Query:").is_ok());
+
+    dom.advance(Duration::from_millis(100));
+    assert!(dom.find_by_text("Query: dio").is_ok());
+}
+
+#[test]
+fn throttled_callbacks_drop_calls_during_the_interval() {
+    fn app() -> Element {
+        let mut clicks = use_signal(|| 0);
+        let onclick = use_hook(|| {
+            Callback::new(move |_: MouseEvent| clicks += 1).throttled(Duration::from_secs(1))
+        });
+        rsx! {
+            button { onclick, "Click" }
+            p { "Clicks: {clicks}" }
+        }
+    }
+
+    let mut dom = TestDom::new(app);
+    let button = dom.find_by_role("button").unwrap();
+    dom.click(&button);
+    dom.click(&button);
+    dom.advance(Duration::from_millis(500));
+    dom.click(&button);
+    assert!(dom.find_by_text("Clicks: 1").is_ok());
+
+    dom.advance(Duration::from_millis(500));
+    dom.click(&button);
+    assert!(dom.find_by_text("Clicks: 2").is_ok());
+}
+
+#[test]
+fn suspense_resolves_when_the_clock_advances() {
+    fn app() -> Element {
+        rsx! {
+            SuspenseBoundary { fallback: |_| rsx! { "Loading" },
+                Delayed {}
+            }
+        }
+    }
+
+    fn Delayed() -> Element {
+        let message = use_resource(|| async {
+            sleep(Duration::from_secs(2)).await;
+            "Loaded"
+        })
+        .suspend()?;
+        rsx! { p { "{message}" } }
+    }
+
+    let mut dom = TestDom::new(app);
+    assert!(dom.html().contains("Loading"));
+
+    dom.advance(Duration::from_secs(1));
+    assert!(dom.html().contains("Loading"));
+
+    dom.advance(Duration::from_secs(1));
+    assert!(dom.find_by_text("Loaded").is_ok());
+}
+
+#[test]
+fn use_future_sleeps_on_the_mock_clock() {
+    fn app() -> Element {
+        let mut status = use_signal(|| "waiting");
+        use_future(move || async move {
+            sleep(Duration::from_secs(60)).await;
+            status.set("done");
+        });
+        rsx! { p { "{status}" } }
+    }
+
+    let mut dom = TestDom::new(app);
+    assert!(dom.find_by_text("waiting").is_ok());
+    dom.advance(Duration::from_secs(60));
+    assert!(dom.find_by_text("done").is_ok());
+}
@@ -11,6 +11,8 @@ pub struct Config {
     pub(crate) hydrate: bool,
     pub(crate) root: ConfigRoot,
     pub(crate) default_panic_hook: bool,
+    #[cfg(feature = "a11y")]
+    pub(crate) a11y_audit: bool,
 }
 
 pub(crate) enum ConfigRoot {
@@ -33,6 +35,8 @@ impl Config {
     /// work and suspended nodes.
     ///
     /// Dioxus will load up all the elements with the `dio_el` data attribute into memory when the page is loaded.
+    ///
+    /// If the page was not pre-rendered by the server or the static site generator, Dioxus will render the app from scratch instead.
     pub fn hydrate(mut self, f: bool) -> Self {
         self.hydrate = f;
         self
@@ -63,6 +67,16 @@ impl Config {
         self.default_panic_hook = f;
         self
     }
+
+    /// Audit the app for accessibility problems like images without alt text or inputs without a label after every
+    /// render, and log each problem as a warning with the component that rendered it.
+    ///
+    /// The audit only runs in debug builds and requires the `a11y` feature. It is disabled by default.
+    #[cfg(feature = "a11y")]
+    pub fn with_a11y_audit(mut self, f: bool) -> Self {
+        self.a11y_audit = f;
+        self
+    }
 }
 
 impl Default for Config {
@@ -71,6 +85,8 @@ impl Default for Config {
             hydrate: false,
             root: ConfigRoot::RootName("main".to_string()),
             default_panic_hook: true,
+            #[cfg(feature = "a11y")]
+            a11y_audit: false,
         }
     }
 }
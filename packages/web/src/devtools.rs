@@ -26,12 +26,47 @@ pub(crate) fn init() -> UnboundedReceiver<HotReloadMsg> {
     // Create the tx/rx pair that we'll use for the top-level future in the dioxus loop
     let (tx, rx) = unbounded();
 
+    // Restore the state the page saved before the last full reload
+    restore_state();
+
     // Wire up the websocket to the devserver
     make_ws(tx, POLL_INTERVAL_MIN, false);
 
     rx
 }
 
+/// The session storage key the state of the app is saved under while the page reloads
+const STATE_SNAPSHOT_KEY: &str = "dioxus-hot-state";
+
+/// Save the snapshot signals in the app to session storage so they survive the page reload
+fn save_state() {
+    let snapshot = dioxus_devtools::take_snapshot();
+    let Some(storage) = window().and_then(|w| w.session_storage().ok().flatten()) else {
+        return;
+    };
+    if snapshot.is_empty() {
+        _ = storage.remove_item(STATE_SNAPSHOT_KEY);
+        return;
+    }
+    if let Ok(json) = serde_json::to_string(&snapshot) {
+        _ = storage.set_item(STATE_SNAPSHOT_KEY, &json);
+    }
+}
+
+/// Restore the snapshot saved before the last reload. It is removed so a manual reload starts with a fresh state
+fn restore_state() {
+    let Some(storage) = window().and_then(|w| w.session_storage().ok().flatten()) else {
+        return;
+    };
+    let Ok(Some(json)) = storage.get_item(STATE_SNAPSHOT_KEY) else {
+        return;
+    };
+    _ = storage.remove_item(STATE_SNAPSHOT_KEY);
+    if let Ok(snapshot) = serde_json::from_str(&json) {
+        dioxus_devtools::restore_snapshot(snapshot);
+    }
+}
+
 fn make_ws(tx: UnboundedSender<HotReloadMsg>, poll_interval: i32, reload: bool) {
     // Get the location of the devserver, using the current location plus the /_dioxus path
     // The idea here being that the devserver is always located on the /_dioxus behind a proxy
@@ -96,6 +131,7 @@ fn make_ws(tx: UnboundedSender<HotReloadMsg>, poll_interval: i32, reload: bool)
                         TOAST_TIMEOUT,
                         true,
                     );
+                    save_state();
                     window().unwrap().location().reload().unwrap()
                 }
 
@@ -9,9 +9,8 @@
 use std::{any::Any, rc::Rc};
 
 use dioxus_core::Runtime;
-use dioxus_core::{ElementId, Template};
+use dioxus_core::{ElementId, TemplateRegistry};
 use dioxus_interpreter_js::unified_bindings::Interpreter;
-use rustc_hash::FxHashMap;
 use wasm_bindgen::{closure::Closure, JsCast};
 use web_sys::{Document, Element, Event, Node};
 
@@ -21,7 +20,7 @@ pub struct WebsysDom {
     #[allow(dead_code)]
     pub(crate) root: Element,
     pub(crate) document: Document,
-    pub(crate) templates: FxHashMap<Template, u16>,
+    pub(crate) templates: TemplateRegistry,
     pub(crate) interpreter: Interpreter,
 
     #[cfg(feature = "mounted")]
@@ -117,13 +116,14 @@ impl WebsysDom {
         );
 
         dioxus_html::set_event_converter(Box::new(WebEventConverter));
+        listen_to_portal_targets(&root, handler.as_ref().unchecked_ref());
         handler.forget();
 
         Self {
             document,
             root,
             interpreter,
-            templates: FxHashMap::default(),
+            templates: TemplateRegistry::default(),
             #[cfg(feature = "mounted")]
             runtime,
             #[cfg(feature = "mounted")]
@@ -140,6 +140,42 @@ impl WebsysDom {
     }
 }
 
+/// Portals move their children into elements outside of the root. Bubbling events from those children never reach
+/// the listeners on the root, so the same handler listens for them on the targets of the portals.
+fn listen_to_portal_targets(root: &Element, handler: &js_sys::Function) {
+    let listener: Closure<dyn FnMut(&web_sys::CustomEvent)> = Closure::wrap(Box::new({
+        let root = root.clone();
+        let handler = handler.clone();
+        move |event: &web_sys::CustomEvent| {
+            let detail = event.detail();
+            let Some(target) = js_sys::Reflect::get(&detail, &"target".into())
+                .ok()
+                .and_then(|target| target.dyn_into::<Element>().ok())
+            else {
+                return;
+            };
+            if root.contains(Some(&target)) {
+                return;
+            }
+            let Ok(events) = js_sys::Reflect::get(&detail, &"events".into()) else {
+                return;
+            };
+            for name in js_sys::Array::from(&events).iter() {
+                if let Some(name) = name.as_string() {
+                    _ = target.add_event_listener_with_callback(&name, &handler);
+                }
+            }
+        }
+    }));
+    if let Some(window) = web_sys::window() {
+        _ = window.add_event_listener_with_callback(
+            "dioxus-portal-target",
+            listener.as_ref().unchecked_ref(),
+        );
+    }
+    listener.forget();
+}
+
 fn walk_event_for_id(event: &web_sys::Event) -> Option<(ElementId, web_sys::Element)> {
     let target = event
         .target()
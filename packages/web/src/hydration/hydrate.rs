@@ -10,6 +10,7 @@ use crate::with_server_data;
 use crate::HTMLDataCursor;
 use dioxus_core::prelude::*;
 use dioxus_core::AttributeValue;
+use dioxus_core::ErrorSource;
 use dioxus_core::{DynamicNode, ElementId};
 use futures_channel::mpsc::UnboundedReceiver;
 use RehydrationError::*;
@@ -102,6 +103,11 @@ impl WebsysDom {
     pub fn rehydrate_streaming(&mut self, message: SuspenseMessage, dom: &mut VirtualDom) {
         if let Err(err) = self.rehydrate_streaming_inner(message, dom) {
             tracing::error!("Rehydration failed. {:?}", err);
+            dom.runtime().report_error(
+                ErrorSource::Hydration,
+                format!("Rehydration failed. {err:?}"),
+                None,
+            );
         }
     }
 
@@ -216,6 +222,19 @@ impl WebsysDom {
         dioxus_interpreter_js::minimal_bindings::register_rehydrate_chunk_for_streaming(&closure);
         closure.forget();
 
+        // Make sure the server rendered the same templates we are about to hydrate. If the server and client were built
+        // from different code, the hydration ids will point to the wrong nodes
+        if let Some(server_fingerprint) = server_hydration_fingerprint() {
+            let mut client_fingerprint = 0;
+            hydration_fingerprint_scope(vdom.base_scope(), vdom, &mut client_fingerprint)?;
+            if server_fingerprint != client_fingerprint {
+                let message = "The templates rendered on the server do not match the templates on the client. Make sure the server and client are built from the same version of your app. Hydration may attach to the wrong elements.";
+                tracing::error!("{message}");
+                vdom.runtime()
+                    .report_error(ErrorSource::Hydration, message.to_string(), None);
+            }
+        }
+
         // Rehydrate the root scope that was rendered on the server. We will likely run into suspense boundaries.
         // Any suspense boundaries we run into are stored for hydration later.
         self.start_hydration_at_scope(vdom.base_scope(), vdom, vec![self.root.clone().into()])?;
@@ -354,6 +373,66 @@ impl WebsysDom {
     }
 }
 
+/// Read the fingerprint of the templates the server rendered from the page
+fn server_hydration_fingerprint() -> Option<u64> {
+    let window = web_sys::window()?;
+    let fingerprint = js_sys::Reflect::get(&window, &"dx_hydration_fingerprint".into()).ok()?;
+    u64::from_str_radix(&fingerprint.as_string()?, 16).ok()
+}
+
+/// Fold the content addressed ids of every template under a scope into a fingerprint. This walks templates in the same
+/// order dioxus-ssr renders them so the result matches the fingerprint the server sends down.
+fn hydration_fingerprint_scope(
+    scope: &ScopeState,
+    dom: &VirtualDom,
+    fingerprint: &mut u64,
+) -> Result<(), RehydrationError> {
+    hydration_fingerprint_vnode(scope.root_node(), dom, fingerprint)
+}
+
+fn hydration_fingerprint_vnode(
+    vnode: &VNode,
+    dom: &VirtualDom,
+    fingerprint: &mut u64,
+) -> Result<(), RehydrationError> {
+    *fingerprint = fingerprint.rotate_left(5) ^ vnode.template.stable_id();
+    for root in vnode.template.roots {
+        hydration_fingerprint_template_node(root, vnode, dom, fingerprint)?;
+    }
+    Ok(())
+}
+
+fn hydration_fingerprint_template_node(
+    node: &TemplateNode,
+    vnode: &VNode,
+    dom: &VirtualDom,
+    fingerprint: &mut u64,
+) -> Result<(), RehydrationError> {
+    match node {
+        TemplateNode::Element { children, .. } => {
+            for child in *children {
+                hydration_fingerprint_template_node(child, vnode, dom, fingerprint)?;
+            }
+        }
+        TemplateNode::Dynamic { id } => match &vnode.dynamic_nodes[*id] {
+            DynamicNode::Component(comp) => {
+                let scope = comp
+                    .mounted_scope(*id, vnode, dom)
+                    .ok_or(VNodeNotInitialized)?;
+                hydration_fingerprint_scope(scope, dom, fingerprint)?;
+            }
+            DynamicNode::Fragment(fragment) => {
+                for vnode in fragment {
+                    hydration_fingerprint_vnode(vnode, dom, fingerprint)?;
+                }
+            }
+            DynamicNode::Text(_) | DynamicNode::Placeholder(_) => {}
+        },
+        TemplateNode::Text { .. } => {}
+    }
+    Ok(())
+}
+
 fn write_comma_separated(id: &[u32], into: &mut String) {
     let mut iter = id.iter();
     if let Some(first) = iter.next() {
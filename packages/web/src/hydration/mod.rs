@@ -2,12 +2,16 @@
 mod deserialize;
 #[cfg(feature = "hydrate")]
 mod hydrate;
+#[cfg(feature = "hydrate")]
+mod replay;
 
 #[cfg(feature = "hydrate")]
 pub use deserialize::*;
 #[cfg(feature = "hydrate")]
 #[allow(unused)]
 pub use hydrate::*;
+#[cfg(feature = "hydrate")]
+pub(crate) use replay::*;
 
 /// The message sent from the server to the client to hydrate a suspense boundary
 #[derive(Debug)]
@@ -0,0 +1,31 @@
+//! Replay the events the user fired before the page was hydrated.
+//!
+//! The streaming script the server writes into the head records clicks, input and key events while the wasm bundle
+//! loads. Once hydration has attached the listeners, the recorded events are dispatched again on their targets so
+//! the first interactions with a pre-rendered page are not lost.
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+#[wasm_bindgen(inline_js = r#"
+export function replay_pre_hydration_events() {
+    if (!window.dx_take_pre_hydration_events) {
+        return;
+    }
+    for (const event of window.dx_take_pre_hydration_events()) {
+        const target = event.target;
+        // The element may have been removed while the app hydrated
+        if (!(target instanceof Node) || !target.isConnected) {
+            continue;
+        }
+        // The browser already toggled checkboxes and radio buttons. Clicking them again would undo it, so only their change and input events are replayed
+        if (event.type === "click" && target instanceof HTMLInputElement && (target.type === "checkbox" || target.type === "radio")) {
+            continue;
+        }
+        target.dispatchEvent(new event.constructor(event.type, event));
+    }
+}
+"#)]
+extern "C" {
+    /// Stop recording events and dispatch every event that was recorded before hydration again
+    pub(crate) fn replay_pre_hydration_events();
+}
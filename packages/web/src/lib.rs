@@ -46,6 +46,11 @@ mod hydration;
 #[allow(unused)]
 pub use hydration::*;
 
+#[cfg(feature = "redux-devtools")]
+mod redux_devtools;
+#[cfg(feature = "redux-devtools")]
+pub use redux_devtools::ReduxDevtools;
+
 /// Runs the app as a future that can be scheduled around the main thread.
 ///
 /// Polls futures internal to the VirtualDOM, hence the async nature of this function.
@@ -60,7 +65,11 @@ pub async fn run(mut virtual_dom: VirtualDom, web_config: Config) -> ! {
     tracing::info!("Starting up");
 
     #[cfg(feature = "document")]
-    virtual_dom.in_runtime(document::init_document);
+    virtual_dom.in_runtime(|| {
+        document::init_document();
+        #[cfg(feature = "focus")]
+        dioxus_html::focus::init_autofocus();
+    });
 
     #[cfg(feature = "panic_hook")]
     if web_config.default_panic_hook {
@@ -74,6 +83,16 @@ pub async fn run(mut virtual_dom: VirtualDom, web_config: Config) -> ! {
 
     let should_hydrate = web_config.hydrate;
 
+    // Only hydrate if the page was actually pre-rendered. This lets an app resume from HTML that was prerendered at
+    // build time while still rendering from scratch on pages that were not prerendered
+    #[cfg(feature = "hydrate")]
+    let should_hydrate = should_hydrate && has_initial_hydration_data();
+
+    #[cfg(all(feature = "a11y", debug_assertions))]
+    let mut a11y_auditor = web_config
+        .a11y_audit
+        .then(dioxus_html::a11y::A11yAuditor::new);
+
     let mut websys_dom = WebsysDom::new(web_config, runtime);
 
     let mut hydration_receiver: Option<futures_channel::mpsc::UnboundedReceiver<SuspenseMessage>> =
@@ -104,7 +123,18 @@ pub async fn run(mut virtual_dom: VirtualDom, web_config: Config) -> ! {
             });
             websys_dom.skip_mutations = false;
 
-            let rx = websys_dom.rehydrate(&virtual_dom).unwrap();
+            let rx = match websys_dom.rehydrate(&virtual_dom) {
+                Ok(rx) => rx,
+                Err(err) => {
+                    let message = format!("Rehydration failed. {err:?}");
+                    virtual_dom.runtime().report_error(
+                        dioxus_core::ErrorSource::Hydration,
+                        message.clone(),
+                        None,
+                    );
+                    panic!("{message}");
+                }
+            };
             hydration_receiver = Some(rx);
         }
         #[cfg(not(feature = "hydrate"))]
@@ -120,6 +150,17 @@ pub async fn run(mut virtual_dom: VirtualDom, web_config: Config) -> ! {
     // the mutations come back with nothing - we need to actually mount them
     websys_dom.mount();
 
+    // Now that the listeners are attached, handle the events the user fired while the page was hydrating
+    #[cfg(feature = "hydrate")]
+    if should_hydrate {
+        hydration::replay_pre_hydration_events();
+    }
+
+    #[cfg(all(feature = "a11y", debug_assertions))]
+    if let Some(auditor) = &mut a11y_auditor {
+        auditor.report(&virtual_dom);
+    }
+
     loop {
         // if virtual dom has nothing, wait for it to have something before requesting idle time
         // if there is work then this future resolves immediately.
@@ -206,5 +247,21 @@ pub async fn run(mut virtual_dom: VirtualDom, web_config: Config) -> ! {
         // work_loop.wait_for_raf().await;
 
         websys_dom.flush_edits();
+
+        #[cfg(all(feature = "a11y", debug_assertions))]
+        if let Some(auditor) = &mut a11y_auditor {
+            auditor.report(&virtual_dom);
+        }
     }
 }
+
+/// Check if the page contains the hydration data the server or static site generator writes into pre-rendered pages
+#[cfg(feature = "hydrate")]
+fn has_initial_hydration_data() -> bool {
+    let Some(window) = web_sys::window() else {
+        return false;
+    };
+    js_sys::Reflect::get(&window, &"initial_dioxus_hydration_data".into())
+        .map(|data| data.is_string())
+        .unwrap_or(false)
+}
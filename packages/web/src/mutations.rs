@@ -1,8 +1,8 @@
 use crate::dom::WebsysDom;
 use dioxus_core::prelude::*;
 use dioxus_core::WriteMutations;
-use dioxus_core::{AttributeValue, ElementId};
-use dioxus_core_types::event_bubbles;
+use dioxus_core::{AttributeValue, ElementId, ListenerOptions};
+use dioxus_core_types::listener_flags;
 use dioxus_interpreter_js::minimal_bindings;
 use wasm_bindgen::JsCast;
 use wasm_bindgen::JsValue;
@@ -127,16 +127,14 @@ impl WriteMutations for WebsysDom {
         if self.skip_mutations() {
             return;
         }
-        let tmpl_id = self.templates.get(&template).cloned().unwrap_or_else(|| {
+        let (tmpl_id, new) = self.templates.register(template);
+        if new {
             let mut roots = vec![];
             for root in template.roots {
                 roots.push(self.create_template_node(root))
             }
-            let id = self.templates.len() as u16;
-            self.templates.insert(template, id);
-            self.interpreter.base().save_template(roots, id);
-            id
-        });
+            self.interpreter.base().save_template(roots, tmpl_id);
+        }
 
         self.interpreter
             .load_template(tmpl_id, index as u16, id.0 as u32)
@@ -220,6 +218,19 @@ impl WriteMutations for WebsysDom {
     }
 
     fn create_event_listener(&mut self, name: &'static str, id: ElementId) {
+        self.create_event_listener_with_options(name, id, ListenerOptions::default());
+    }
+
+    fn remove_event_listener(&mut self, name: &'static str, id: ElementId) {
+        self.remove_event_listener_with_options(name, id, ListenerOptions::default());
+    }
+
+    fn create_event_listener_with_options(
+        &mut self,
+        name: &'static str,
+        id: ElementId,
+        options: ListenerOptions,
+    ) {
         if self.skip_mutations() {
             return;
         }
@@ -230,11 +241,19 @@ impl WriteMutations for WebsysDom {
             return;
         }
 
-        self.interpreter
-            .new_event_listener(name, id.0 as u32, event_bubbles(name) as u8);
+        self.interpreter.new_event_listener(
+            name,
+            id.0 as u32,
+            listener_flags(name, options.passive),
+        );
     }
 
-    fn remove_event_listener(&mut self, name: &'static str, id: ElementId) {
+    fn remove_event_listener_with_options(
+        &mut self,
+        name: &'static str,
+        id: ElementId,
+        options: ListenerOptions,
+    ) {
         if self.skip_mutations() {
             return;
         }
@@ -242,8 +261,11 @@ impl WriteMutations for WebsysDom {
             return;
         }
 
-        self.interpreter
-            .remove_event_listener(name, id.0 as u32, event_bubbles(name) as u8);
+        self.interpreter.remove_event_listener(
+            name,
+            id.0 as u32,
+            listener_flags(name, options.passive),
+        );
     }
 
     fn remove_node(&mut self, id: ElementId) {
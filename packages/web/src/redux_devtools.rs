@@ -0,0 +1,130 @@
+//! Connect stores to the [Redux DevTools](https://github.com/reduxjs/redux-devtools) browser extension
+
+use dioxus_signals::{Readable, Store, StoreMiddleware};
+use serde::{de::DeserializeOwned, Serialize};
+use std::cell::RefCell;
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+
+/// Middleware that sends every action dispatched to a [`Store`] and the new state to the Redux DevTools browser
+/// extension. Jumping to an earlier state in the devtools replaces the state of the store.
+///
+/// The middleware does nothing if the extension is not installed.
+///
+/// ```rust, ignore
+/// let todos = use_hook(|| {
+///     Store::new("todos", Todos::default())
+///         .with_reducer(Todos::reduce)
+///         .with_middleware(ReduxDevtools::new())
+/// });
+/// ```
+#[derive(Default)]
+pub struct ReduxDevtools {
+    connection: RefCell<Option<JsValue>>,
+    on_message: RefCell<Option<MessageHandler>>,
+}
+
+type MessageHandler = Closure<dyn FnMut(JsValue)>;
+
+impl ReduxDevtools {
+    /// Create the middleware. Each store needs its own middleware.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn call(&self, method: &str, args: &[JsValue]) -> Option<JsValue> {
+        let connection = self.connection.borrow();
+        let connection = connection.as_ref()?;
+        let function: js_sys::Function = js_sys::Reflect::get(connection, &method.into())
+            .ok()?
+            .dyn_into()
+            .ok()?;
+        let args: js_sys::Array = args.iter().collect();
+        function.apply(connection, &args).ok()
+    }
+}
+
+impl<T, A> StoreMiddleware<T, A> for ReduxDevtools
+where
+    T: Serialize + DeserializeOwned + 'static,
+    A: Serialize + 'static,
+{
+    fn attach(&self, store: Store<T, A>) {
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+        let extension = js_sys::Reflect::get(&window, &"__REDUX_DEVTOOLS_EXTENSION__".into())
+            .unwrap_or(JsValue::UNDEFINED);
+        if extension.is_undefined() || extension.is_null() {
+            return;
+        }
+        let Ok(connect) = js_sys::Reflect::get(&extension, &"connect".into())
+            .and_then(|connect| connect.dyn_into::<js_sys::Function>())
+        else {
+            return;
+        };
+
+        let options = js_sys::Object::new();
+        _ = js_sys::Reflect::set(&options, &"name".into(), &store.name().into());
+        let Ok(connection) = connect.call1(&extension, &options) else {
+            return;
+        };
+        self.connection.replace(Some(connection));
+        self.call("init", &[to_js(&*store.peek())]);
+
+        // Time travel in the devtools sends the state to jump to as json
+        let on_message = Closure::new(move |message: JsValue| {
+            let field = |value: &JsValue, name: &str| {
+                js_sys::Reflect::get(value, &name.into()).unwrap_or(JsValue::UNDEFINED)
+            };
+            if field(&message, "type").as_string().as_deref() != Some("DISPATCH") {
+                return;
+            }
+            let payload = field(&message, "payload");
+            let kind = field(&payload, "type").as_string();
+            if !matches!(kind.as_deref(), Some("JUMP_TO_STATE" | "JUMP_TO_ACTION")) {
+                return;
+            }
+            let Some(state) = field(&message, "state").as_string() else {
+                return;
+            };
+            match serde_json::from_str(&state) {
+                Ok(state) => store.replace_state(state),
+                Err(err) => {
+                    tracing::error!("Failed to load the state from the redux devtools: {err}")
+                }
+            }
+        });
+        self.call("subscribe", &[on_message.as_ref().clone()]);
+        self.on_message.replace(Some(on_message));
+    }
+
+    fn after_dispatch(&self, store: Store<T, A>, action: &A) {
+        if self.connection.borrow().is_none() {
+            return;
+        }
+        self.call("send", &[action_to_js(action), to_js(&*store.peek())]);
+    }
+}
+
+/// Convert a value into a javascript object through json
+fn to_js(value: &impl Serialize) -> JsValue {
+    serde_json::to_string(value)
+        .ok()
+        .and_then(|json| js_sys::JSON::parse(&json).ok())
+        .unwrap_or(JsValue::UNDEFINED)
+}
+
+/// Redux actions are objects with a `type`. Unit variants of an enum serialize to a string that is used as the type,
+/// and other variants serialize to an object with one key that is used as the type.
+fn action_to_js(action: &impl Serialize) -> JsValue {
+    let json = match serde_json::to_value(action) {
+        Ok(serde_json::Value::String(kind)) => serde_json::json!({ "type": kind }),
+        Ok(serde_json::Value::Object(fields)) if fields.len() == 1 => {
+            let (kind, payload) = fields.into_iter().next().unwrap();
+            serde_json::json!({ "type": kind, "payload": payload })
+        }
+        Ok(payload) => serde_json::json!({ "type": "action", "payload": payload }),
+        Err(_) => return JsValue::UNDEFINED,
+    };
+    to_js(&json)
+}